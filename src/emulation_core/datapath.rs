@@ -1,5 +1,7 @@
 //! Module for the API of a generic datapath.
 
+use std::ops::Range;
+
 /// A generic datapath.
 ///
 /// This has the ability to execute instructions, and to interface with
@@ -54,6 +56,30 @@ pub trait Datapath {
 
     /// Restore the datapath to its default state.
     fn reset(&mut self);
+
+    /// Returns the address of the instruction fetched by the next call to
+    /// [`Self::execute_stage()`], or [`None`] if that stage does not fetch
+    /// an instruction. Debuggers stop here for breakpoints.
+    fn next_fetch_address(&self) -> Option<Self::RegisterData>;
+
+    /// Returns whether the most recent call to [`Self::execute_stage()`]
+    /// finished an instruction. Debuggers use this to step by instruction.
+    fn finished_instruction(&self) -> bool;
+
+    /// Returns the memory read or written by a load or store during the most
+    /// recent call to [`Self::execute_stage()`], if any. Debuggers use this
+    /// for memory watchpoints.
+    fn last_memory_access(&self) -> Option<MemoryAccess<Self::RegisterData>>;
+}
+
+/// An access to memory made by a load or store.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemoryAccess<T> {
+    /// The addresses of the bytes accessed.
+    pub addresses: Range<T>,
+
+    /// Whether memory was written to, rather than read from.
+    pub is_write: bool,
 }
 
 /// A datapath that supports a visual diagram component.
@@ -1,12 +1,24 @@
 //! All facets of this project's implementation of the MIPS64 ISA, including
 //! the datapath, control signals, registers, and memory.
 
+pub mod branch_predictor;
+pub mod cache;
 pub mod constants;
 pub mod control_signals;
 pub mod coprocessor;
 pub mod datapath;
+pub mod datapath_model;
 pub mod datapath_signals;
+pub mod debugger;
+pub mod floating_point;
+pub mod history;
 pub mod instruction;
 pub mod line_info;
 pub mod memory;
+pub mod mmio;
+pub mod multi_cycle_datapath;
+pub mod performance;
+pub mod pipelined_datapath;
 pub mod registers;
+pub mod syscall;
+pub mod system_coprocessor;
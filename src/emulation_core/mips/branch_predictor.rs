@@ -0,0 +1,378 @@
+//! Branch predictor simulation.
+//!
+//! A branch predictor attached to the datapath is asked for a prediction each
+//! time a branch or jump reaches the point where the datapath decides whether
+//! it is taken. The prediction is then compared with the actual outcome, and
+//! the predictor is updated. Execution is not affected by the predictions, so
+//! a program behaves the same with or without a branch predictor.
+//!
+//! Conditional branches are predicted by the direction predictor chosen in
+//! [`BranchPredictorConfig::kind`]. If a branch target buffer (BTB) is used,
+//! the targets of taken branches and jumps are also predicted.
+
+use std::collections::BTreeMap;
+
+/// The direction predictors that can be simulated.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum PredictorKind {
+    /// Predict that no branch is taken.
+    StaticNotTaken,
+
+    /// Predict that backward branches are taken and forward branches are not
+    /// ("backward taken, forward not taken").
+    StaticBtfn,
+
+    /// A table of 1-bit entries, each holding the last outcome of the
+    /// branches that map to it.
+    OneBit,
+
+    /// A table of 2-bit saturating counters. A branch must be mispredicted
+    /// twice in a row before the prediction changes.
+    #[default]
+    TwoBit,
+
+    /// A table of 2-bit saturating counters, indexed by the address of the
+    /// branch XORed with the global history of branch outcomes.
+    Gshare,
+}
+
+/// The shape of a branch predictor.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BranchPredictorConfig {
+    pub kind: PredictorKind,
+
+    /// The number of entries in the prediction table. This is unused by the
+    /// static predictors.
+    pub table_size: usize,
+
+    /// The number of branch outcomes kept in the global history register.
+    /// This is only used by [`PredictorKind::Gshare`].
+    pub history_bits: u32,
+
+    /// The number of entries in the branch target buffer, or [`None`] for no
+    /// branch target buffer.
+    pub btb_size: Option<usize>,
+}
+
+impl Default for BranchPredictorConfig {
+    fn default() -> Self {
+        Self {
+            kind: PredictorKind::default(),
+            table_size: 64,
+            history_bits: 6,
+            btb_size: Some(16),
+        }
+    }
+}
+
+/// Counts of predictions and their outcomes.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BranchStatistics {
+    /// The number of conditional branches predicted.
+    pub branches: u64,
+
+    /// The number of conditional branches that were taken.
+    pub taken: u64,
+
+    /// The number of conditional branches predicted correctly.
+    pub correct: u64,
+
+    /// The number of taken branches and jumps looked up in the branch target
+    /// buffer.
+    pub btb_lookups: u64,
+
+    /// The number of lookups in the branch target buffer that gave the
+    /// correct target.
+    pub btb_hits: u64,
+}
+
+impl BranchStatistics {
+    /// The fraction of conditional branches predicted correctly, or `0.0` if
+    /// there were none.
+    pub fn accuracy(&self) -> f64 {
+        if self.branches == 0 {
+            0.0
+        } else {
+            self.correct as f64 / self.branches as f64
+        }
+    }
+
+    /// The fraction of branch target buffer lookups that gave the correct
+    /// target, or `0.0` if there were none.
+    pub fn btb_hit_rate(&self) -> f64 {
+        if self.btb_lookups == 0 {
+            0.0
+        } else {
+            self.btb_hits as f64 / self.btb_lookups as f64
+        }
+    }
+
+    /// Add the counts of `other` to these counts.
+    fn add(&mut self, other: &Self) {
+        self.branches += other.branches;
+        self.taken += other.taken;
+        self.correct += other.correct;
+        self.btb_lookups += other.btb_lookups;
+        self.btb_hits += other.btb_hits;
+    }
+}
+
+/// An entry of the branch target buffer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BtbEntry {
+    /// The address of the branch or jump.
+    pub address: u64,
+
+    /// The address the branch or jump last went to.
+    pub target: u64,
+}
+
+/// A simulated branch predictor.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BranchPredictor {
+    config: BranchPredictorConfig,
+
+    /// The prediction table. 1-bit predictors use `0` and `1`, and 2-bit
+    /// predictors use `0` to `3`, where `2` and up predict taken.
+    table: Vec<u8>,
+
+    /// The outcomes of the most recent branches, newest in the lowest bit.
+    global_history: u64,
+
+    btb: Vec<Option<BtbEntry>>,
+
+    pub statistics: BranchStatistics,
+
+    /// The statistics of each branch or jump, by its address.
+    pub site_statistics: BTreeMap<u64, BranchStatistics>,
+}
+
+impl BranchPredictor {
+    /// Create a branch predictor with no history. If the configuration does
+    /// not describe a valid predictor, an [`Err`] is returned.
+    ///
+    /// The prediction table and branch target buffer must have a power of
+    /// two number of entries.
+    pub fn new(config: BranchPredictorConfig) -> Result<Self, String> {
+        if !config.table_size.is_power_of_two() {
+            return Err(format!(
+                "Prediction table size `{}` is not a power of two",
+                config.table_size
+            ));
+        }
+
+        if config.history_bits > 32 {
+            return Err(format!(
+                "Global history of `{}` bits is longer than 32 bits",
+                config.history_bits
+            ));
+        }
+
+        if let Some(btb_size) = config.btb_size {
+            if !btb_size.is_power_of_two() {
+                return Err(format!(
+                    "Branch target buffer size `{btb_size}` is not a power of two"
+                ));
+            }
+        }
+
+        let mut predictor = Self {
+            table: Vec::new(),
+            global_history: 0,
+            btb: Vec::new(),
+            statistics: BranchStatistics::default(),
+            site_statistics: BTreeMap::new(),
+            config,
+        };
+        predictor.reset();
+
+        Ok(predictor)
+    }
+
+    pub fn config(&self) -> &BranchPredictorConfig {
+        &self.config
+    }
+
+    pub fn btb(&self) -> &[Option<BtbEntry>] {
+        &self.btb
+    }
+
+    /// Forget all history, and clear the statistics.
+    pub fn reset(&mut self) {
+        // 2-bit counters start out weakly not taken.
+        let initial_entry = match self.config.kind {
+            PredictorKind::TwoBit | PredictorKind::Gshare => 1,
+            _ => 0,
+        };
+
+        self.table = vec![initial_entry; self.config.table_size];
+        self.global_history = 0;
+        self.btb = vec![None; self.config.btb_size.unwrap_or(0)];
+        self.statistics = BranchStatistics::default();
+        self.site_statistics.clear();
+    }
+
+    /// Predict whether the conditional branch at `address`, which goes to
+    /// `target` if taken, is taken.
+    pub fn predict(&self, address: u64, target: u64) -> bool {
+        match self.config.kind {
+            PredictorKind::StaticNotTaken => false,
+            PredictorKind::StaticBtfn => target <= address,
+            PredictorKind::OneBit => self.table[self.table_index(address)] == 1,
+            PredictorKind::TwoBit | PredictorKind::Gshare => {
+                self.table[self.table_index(address)] >= 2
+            }
+        }
+    }
+
+    /// Record the outcome of the conditional branch at `address`, which goes
+    /// to `target` if taken. Returns whether the prediction was correct.
+    pub fn update_branch(&mut self, address: u64, target: u64, taken: bool) -> bool {
+        let correct = self.predict(address, target) == taken;
+
+        let index = self.table_index(address);
+        let entry = &mut self.table[index];
+        *entry = match self.config.kind {
+            PredictorKind::StaticNotTaken | PredictorKind::StaticBtfn => *entry,
+            PredictorKind::OneBit => taken as u8,
+            PredictorKind::TwoBit | PredictorKind::Gshare => {
+                if taken {
+                    (*entry + 1).min(3)
+                } else {
+                    entry.saturating_sub(1)
+                }
+            }
+        };
+
+        let history_mask = (1u64 << self.config.history_bits) - 1;
+        self.global_history = ((self.global_history << 1) | taken as u64) & history_mask;
+
+        let mut counts = BranchStatistics {
+            branches: 1,
+            taken: taken as u64,
+            correct: correct as u64,
+            ..Default::default()
+        };
+
+        if taken {
+            self.lookup_target(address, target, &mut counts);
+        }
+
+        self.record(address, &counts);
+
+        correct
+    }
+
+    /// Record the target of the jump at `address`. Jumps are always taken, so
+    /// only their target is predicted.
+    pub fn update_jump(&mut self, address: u64, target: u64) {
+        let mut counts = BranchStatistics::default();
+        self.lookup_target(address, target, &mut counts);
+        self.record(address, &counts);
+    }
+
+    /// Add up the statistics of each source line, given the line of each
+    /// instruction address as in `ProgramInfo::address_to_line_number` and
+    /// the address of the first instruction, `text_address`. Addresses with
+    /// no known line are left out.
+    pub fn line_statistics(
+        &self,
+        address_to_line_number: &[usize],
+        text_address: u64,
+    ) -> BTreeMap<usize, BranchStatistics> {
+        let mut lines: BTreeMap<usize, BranchStatistics> = BTreeMap::new();
+
+        for (address, statistics) in &self.site_statistics {
+            let line = address
+                .checked_sub(text_address)
+                .and_then(|offset| address_to_line_number.get((offset / 4) as usize));
+            if let Some(&line) = line {
+                lines.entry(line).or_default().add(statistics);
+            }
+        }
+
+        lines
+    }
+
+    /// Describe the configuration and statistics of the predictor, with the
+    /// statistics of each branch listed by source line.
+    pub fn generate_report(&self, address_to_line_number: &[usize], text_address: u64) -> String {
+        let config = &self.config;
+        let statistics = &self.statistics;
+
+        let mut report = format!("Predictor: {:?}", config.kind);
+        match config.kind {
+            PredictorKind::OneBit | PredictorKind::TwoBit => {
+                report.push_str(&format!(", {} entries", config.table_size));
+            }
+            PredictorKind::Gshare => report.push_str(&format!(
+                ", {} entries, {} history bits",
+                config.table_size, config.history_bits
+            )),
+            _ => (),
+        }
+        match config.btb_size {
+            Some(size) => report.push_str(&format!(", {size}-entry BTB\n")),
+            None => report.push_str(", no BTB\n"),
+        }
+
+        report.push_str(&format!(
+            "Branches: {}  Taken: {}  Correct: {}  Accuracy: {:.2}%  BTB lookups: {}  BTB hits: {}\n\n",
+            statistics.branches,
+            statistics.taken,
+            statistics.correct,
+            statistics.accuracy() * 100.0,
+            statistics.btb_lookups,
+            statistics.btb_hits,
+        ));
+
+        report.push_str("Line\tBranches\tTaken\tCorrect\tAccuracy\tBTB hits\n");
+        for (line, statistics) in self.line_statistics(address_to_line_number, text_address) {
+            report.push_str(&format!(
+                "{}\t{}\t\t{}\t{}\t{:.2}%\t\t{}/{}\n",
+                line + 1,
+                statistics.branches,
+                statistics.taken,
+                statistics.correct,
+                statistics.accuracy() * 100.0,
+                statistics.btb_hits,
+                statistics.btb_lookups,
+            ));
+        }
+
+        report
+    }
+
+    /// The index in the prediction table used for the branch at `address`.
+    fn table_index(&self, address: u64) -> usize {
+        let mut index = address >> 2;
+        if self.config.kind == PredictorKind::Gshare {
+            index ^= self.global_history;
+        }
+
+        (index as usize) & (self.config.table_size - 1)
+    }
+
+    /// Look up the target of the taken branch or jump at `address` in the
+    /// branch target buffer, then store its actual target.
+    fn lookup_target(&mut self, address: u64, target: u64, counts: &mut BranchStatistics) {
+        if self.btb.is_empty() {
+            return;
+        }
+
+        let index = ((address >> 2) as usize) & (self.btb.len() - 1);
+        let new_entry = BtbEntry { address, target };
+
+        counts.btb_lookups += 1;
+        if self.btb[index] == Some(new_entry) {
+            counts.btb_hits += 1;
+        }
+
+        self.btb[index] = Some(new_entry);
+    }
+
+    fn record(&mut self, address: u64, counts: &BranchStatistics) {
+        self.statistics.add(counts);
+        self.site_statistics.entry(address).or_default().add(counts);
+    }
+}
@@ -0,0 +1,467 @@
+//! Cache simulator placed in front of [`Memory`](super::memory::Memory).
+//!
+//! The caches only keep track of which blocks they hold. Data is always read
+//! from and written to memory, so a program behaves the same with or without
+//! caches. What changes is the record of hits, misses, evictions, and
+//! write-backs that would have taken place.
+
+use std::collections::VecDeque;
+
+/// The number of accesses kept in the log of each cache. Older accesses are
+/// dropped first.
+pub const LOG_CAPACITY: usize = 1024;
+
+/// The seed used for random replacement, so that runs are repeatable.
+const RANDOM_SEED: u64 = 0x2545_F491_4F6C_DD1D;
+
+/// The shape and policies of a cache.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CacheConfig {
+    /// The capacity of the cache in bytes.
+    pub size: usize,
+
+    /// The size of each block in bytes.
+    pub block_size: usize,
+
+    /// The number of blocks in each set. `1` gives a direct-mapped cache, and
+    /// `size / block_size` gives a fully associative one.
+    pub associativity: usize,
+
+    pub replacement_policy: ReplacementPolicy,
+    pub write_policy: WritePolicy,
+    pub write_miss_policy: WriteMissPolicy,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            size: 1024,
+            block_size: 16,
+            associativity: 1,
+            replacement_policy: ReplacementPolicy::default(),
+            write_policy: WritePolicy::default(),
+            write_miss_policy: WriteMissPolicy::default(),
+        }
+    }
+}
+
+/// Decides which block in a full set is evicted.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ReplacementPolicy {
+    /// Evict the least recently used block.
+    #[default]
+    Lru,
+
+    /// Evict the block that was brought in first.
+    Fifo,
+
+    /// Evict a block at random.
+    Random,
+}
+
+/// Decides when a write to a block in the cache reaches memory.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum WritePolicy {
+    /// Writes only mark the block as dirty. The block is written to memory
+    /// when it is evicted.
+    #[default]
+    WriteBack,
+
+    /// Writes go to memory immediately.
+    WriteThrough,
+}
+
+/// Decides what happens on a write to a block not in the cache.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum WriteMissPolicy {
+    /// Bring the block into the cache, then write to it.
+    #[default]
+    WriteAllocate,
+
+    /// Write to memory without bringing the block into the cache.
+    NoWriteAllocate,
+}
+
+/// The kind of a memory access.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AccessKind {
+    InstructionFetch,
+    Read,
+    Write,
+}
+
+/// A block slot in a cache.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CacheLine {
+    pub valid: bool,
+    pub dirty: bool,
+    pub tag: u64,
+
+    /// The time of the last access to this block, used for LRU replacement.
+    last_used: u64,
+
+    /// The time this block was brought in, used for FIFO replacement.
+    filled: u64,
+}
+
+/// Counts of the accesses made to a cache and their outcomes.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CacheStatistics {
+    pub reads: u64,
+    pub writes: u64,
+    pub hits: u64,
+    pub misses: u64,
+
+    /// The number of valid blocks replaced to make room for another.
+    pub evictions: u64,
+
+    /// The number of dirty blocks written to memory on eviction.
+    pub write_backs: u64,
+}
+
+impl CacheStatistics {
+    /// The fraction of accesses that hit, or `0.0` if there were none.
+    pub fn hit_rate(&self) -> f64 {
+        let accesses = self.hits + self.misses;
+        if accesses == 0 {
+            0.0
+        } else {
+            self.hits as f64 / accesses as f64
+        }
+    }
+}
+
+/// A record of a single access to a cache.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CacheAccess {
+    pub kind: AccessKind,
+    pub address: u64,
+    pub set: usize,
+    pub tag: u64,
+    pub hit: bool,
+
+    /// The address of the first byte of the block evicted by this access.
+    pub evicted: Option<u64>,
+}
+
+/// A single level of cache.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cache {
+    config: CacheConfig,
+    sets: Vec<Vec<CacheLine>>,
+
+    /// Counts accesses, and serves as the time for the replacement policies.
+    clock: u64,
+    random_state: u64,
+
+    pub statistics: CacheStatistics,
+
+    /// The most recent accesses, oldest first. At most [`LOG_CAPACITY`]
+    /// accesses are kept.
+    pub log: VecDeque<CacheAccess>,
+}
+
+impl Cache {
+    /// Create an empty cache. If the configuration does not describe a valid
+    /// cache, an [`Err`] is returned.
+    ///
+    /// The block size must be a power of two of at least 8 bytes, so that no
+    /// access spans two blocks. The number of sets must be a power of two.
+    pub fn new(config: CacheConfig) -> Result<Self, String> {
+        if config.block_size < 8 || !config.block_size.is_power_of_two() {
+            return Err(format!(
+                "Block size `{}` is not a power of two of at least 8 bytes",
+                config.block_size
+            ));
+        }
+
+        if config.associativity == 0 {
+            return Err(String::from("Associativity must be at least 1"));
+        }
+
+        let set_size = config.block_size * config.associativity;
+        if config.size == 0 || config.size % set_size != 0 {
+            return Err(format!(
+                "Cache size `{}` is not a multiple of the block size times the associativity ({set_size})",
+                config.size
+            ));
+        }
+
+        let set_count = config.size / set_size;
+        if !set_count.is_power_of_two() {
+            return Err(format!(
+                "Number of sets `{set_count}` is not a power of two"
+            ));
+        }
+
+        Ok(Self {
+            sets: vec![vec![CacheLine::default(); config.associativity]; set_count],
+            config,
+            clock: 0,
+            random_state: RANDOM_SEED,
+            statistics: CacheStatistics::default(),
+            log: VecDeque::new(),
+        })
+    }
+
+    pub fn config(&self) -> &CacheConfig {
+        &self.config
+    }
+
+    pub fn sets(&self) -> &[Vec<CacheLine>] {
+        &self.sets
+    }
+
+    /// Invalidate every block, and clear the statistics and log.
+    pub fn reset(&mut self) {
+        for line in self.sets.iter_mut().flatten() {
+            *line = CacheLine::default();
+        }
+
+        self.clock = 0;
+        self.random_state = RANDOM_SEED;
+        self.statistics = CacheStatistics::default();
+        self.log.clear();
+    }
+
+    /// Access the byte at `address`, updating the contents of the cache, the
+    /// statistics, and the log.
+    pub fn access(&mut self, address: u64, kind: AccessKind) -> CacheAccess {
+        let block_size = self.config.block_size as u64;
+        let set_count = self.sets.len() as u64;
+        let block = address / block_size;
+        let set = (block % set_count) as usize;
+        let tag = block / set_count;
+
+        self.clock += 1;
+        let clock = self.clock;
+        let is_write = kind == AccessKind::Write;
+        let write_back = self.config.write_policy == WritePolicy::WriteBack;
+
+        let mut evicted = None;
+        let hit = match self.sets[set]
+            .iter_mut()
+            .find(|line| line.valid && line.tag == tag)
+        {
+            Some(line) => {
+                line.last_used = clock;
+                line.dirty |= is_write && write_back;
+                true
+            }
+            None => {
+                let allocate =
+                    !is_write || self.config.write_miss_policy == WriteMissPolicy::WriteAllocate;
+
+                if allocate {
+                    let way = self.choose_victim(set);
+                    let line = &mut self.sets[set][way];
+
+                    if line.valid {
+                        evicted = Some((line.tag * set_count + set as u64) * block_size);
+                        self.statistics.evictions += 1;
+                        if line.dirty {
+                            self.statistics.write_backs += 1;
+                        }
+                    }
+
+                    *line = CacheLine {
+                        valid: true,
+                        dirty: is_write && write_back,
+                        tag,
+                        last_used: clock,
+                        filled: clock,
+                    };
+                }
+
+                false
+            }
+        };
+
+        if is_write {
+            self.statistics.writes += 1;
+        } else {
+            self.statistics.reads += 1;
+        }
+
+        if hit {
+            self.statistics.hits += 1;
+        } else {
+            self.statistics.misses += 1;
+        }
+
+        let access = CacheAccess {
+            kind,
+            address,
+            set,
+            tag,
+            hit,
+            evicted,
+        };
+
+        if self.log.len() == LOG_CAPACITY {
+            self.log.pop_front();
+        }
+        self.log.push_back(access.clone());
+
+        access
+    }
+
+    /// Choose the way of `set` that a new block is placed in. Empty ways are
+    /// used first.
+    fn choose_victim(&mut self, set: usize) -> usize {
+        let lines = &self.sets[set];
+
+        if let Some(way) = lines.iter().position(|line| !line.valid) {
+            return way;
+        }
+
+        match self.config.replacement_policy {
+            ReplacementPolicy::Lru => (0..lines.len())
+                .min_by_key(|&way| lines[way].last_used)
+                .unwrap_or(0),
+            ReplacementPolicy::Fifo => (0..lines.len())
+                .min_by_key(|&way| lines[way].filled)
+                .unwrap_or(0),
+            ReplacementPolicy::Random => {
+                // xorshift64
+                self.random_state ^= self.random_state << 13;
+                self.random_state ^= self.random_state >> 7;
+                self.random_state ^= self.random_state << 17;
+                (self.random_state % lines.len() as u64) as usize
+            }
+        }
+    }
+
+    /// Describe the configuration, statistics, and log of the cache.
+    pub fn generate_report(&self, name: &str) -> String {
+        let config = &self.config;
+        let statistics = &self.statistics;
+
+        let associativity = match config.associativity {
+            1 => String::from("direct-mapped"),
+            n if n * config.block_size == config.size => String::from("fully associative"),
+            n => format!("{n}-way set associative"),
+        };
+
+        let mut report = format!(
+            "{name}: {} bytes, {}-byte blocks, {associativity}, {:?}, {:?}, {:?}\n",
+            config.size,
+            config.block_size,
+            config.replacement_policy,
+            config.write_policy,
+            config.write_miss_policy,
+        );
+        report.push_str(&format!(
+            "Reads: {}  Writes: {}  Hits: {}  Misses: {}  Hit rate: {:.2}%  Evictions: {}  Write-backs: {}\n\n",
+            statistics.reads,
+            statistics.writes,
+            statistics.hits,
+            statistics.misses,
+            statistics.hit_rate() * 100.0,
+            statistics.evictions,
+            statistics.write_backs,
+        ));
+
+        report.push_str("Kind\t\tAddress\t\tSet\tTag\tResult\tEvicted\n");
+        for access in self.log.iter() {
+            report.push_str(&format!(
+                "{:?}\t{}0x{:04x}\t\t{}\t0x{:x}\t{}\t{}\n",
+                access.kind,
+                if access.kind == AccessKind::InstructionFetch {
+                    ""
+                } else {
+                    "\t"
+                },
+                access.address,
+                access.set,
+                access.tag,
+                if access.hit { "hit" } else { "miss" },
+                access
+                    .evicted
+                    .map(|address| format!("0x{address:04x}"))
+                    .unwrap_or_default(),
+            ));
+        }
+
+        report
+    }
+}
+
+/// The caches placed in front of memory.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CacheHierarchy {
+    /// Separate L1 instruction and data caches. Either cache may be left out.
+    Split {
+        instruction: Option<Cache>,
+        data: Option<Cache>,
+    },
+
+    /// A single L1 cache for both instructions and data.
+    Unified(Cache),
+}
+
+impl Default for CacheHierarchy {
+    /// Separate instruction and data caches, each with the default
+    /// [`CacheConfig`].
+    fn default() -> Self {
+        Self::new(CacheConfig::default(), false).unwrap()
+    }
+}
+
+impl CacheHierarchy {
+    /// Create either separate instruction and data caches or a single
+    /// unified cache, each described by `config`. If the configuration does
+    /// not describe a valid cache, an [`Err`] is returned.
+    pub fn new(config: CacheConfig, unified: bool) -> Result<Self, String> {
+        let cache = Cache::new(config)?;
+
+        Ok(if unified {
+            Self::Unified(cache)
+        } else {
+            Self::Split {
+                instruction: Some(cache.clone()),
+                data: Some(cache),
+            }
+        })
+    }
+
+    /// Access the byte at `address` in the cache that handles `kind`, if any.
+    pub fn access(&mut self, address: u64, kind: AccessKind) -> Option<CacheAccess> {
+        let cache = match self {
+            Self::Split { instruction, data } => match kind {
+                AccessKind::InstructionFetch => instruction.as_mut(),
+                AccessKind::Read | AccessKind::Write => data.as_mut(),
+            },
+            Self::Unified(cache) => Some(cache),
+        };
+
+        cache.map(|cache| cache.access(address, kind))
+    }
+
+    /// Invalidate every block of every cache, and clear their statistics and logs.
+    pub fn reset(&mut self) {
+        match self {
+            Self::Split { instruction, data } => {
+                for cache in [instruction, data].into_iter().flatten() {
+                    cache.reset();
+                }
+            }
+            Self::Unified(cache) => cache.reset(),
+        }
+    }
+
+    /// Describe the configuration, statistics, and log of every cache.
+    pub fn generate_report(&self) -> String {
+        match self {
+            Self::Split { instruction, data } => [
+                ("L1 instruction cache", instruction),
+                ("L1 data cache", data),
+            ]
+            .into_iter()
+            .filter_map(|(name, cache)| cache.as_ref().map(|cache| cache.generate_report(name)))
+            .collect::<Vec<_>>()
+            .join("\n"),
+            Self::Unified(cache) => cache.generate_report("L1 unified cache"),
+        }
+    }
+}
@@ -1,13 +1,21 @@
 use super::control_signals::RegWidth;
 
 pub const FUNCT_SYSCALL: u8 = 0b001100;
+pub const FUNCT_BREAK: u8 = 0b001101;
 
 pub const FUNCT_SLL: u8 = 0b000000;
+pub const FUNCT_SRL: u8 = 0b000010;
+pub const FUNCT_SRA: u8 = 0b000011;
+pub const FUNCT_SLLV: u8 = 0b000100;
+pub const FUNCT_SRLV: u8 = 0b000110;
+pub const FUNCT_SRAV: u8 = 0b000111;
 pub const FUNCT_ADD: u8 = 0b100000;
 pub const FUNCT_ADDU: u8 = 0b100001;
 pub const FUNCT_SUB: u8 = 0b100010;
 pub const FUNCT_AND: u8 = 0b100100;
 pub const FUNCT_OR: u8 = 0b100101;
+pub const FUNCT_XOR: u8 = 0b100110;
+pub const FUNCT_NOR: u8 = 0b100111;
 pub const FUNCT_SLT: u8 = 0b101010;
 pub const FUNCT_SLTU: u8 = 0b101011;
 
@@ -16,9 +24,27 @@
 pub const FUNCT_DSUB: u8 = 0b101110;
 pub const FUNCT_DSUBU: u8 = 0b101111;
 
+pub const FUNCT_DSLLV: u8 = 0b010100;
+pub const FUNCT_DSRLV: u8 = 0b010110;
+pub const FUNCT_DSRAV: u8 = 0b010111;
+pub const FUNCT_DSLL: u8 = 0b111000;
+pub const FUNCT_DSRL: u8 = 0b111010;
+pub const FUNCT_DSRA: u8 = 0b111011;
+pub const FUNCT_DSLL32: u8 = 0b111100;
+pub const FUNCT_DSRL32: u8 = 0b111110;
+pub const FUNCT_DSRA32: u8 = 0b111111;
+
 pub const FUNCT_JALR: u8 = 0b001001;
 pub const FUNCT_JR: u8 = FUNCT_JALR;
 
+/// Used for `MOVF` and `MOVT`, which hold the condition code and the
+/// true/false bit in the `rt` field.
+pub const FUNCT_MOVCI: u8 = 0b000001;
+pub const FUNCT_MOVZ: u8 = 0b001010;
+pub const FUNCT_MOVN: u8 = 0b001011;
+pub const FUNCT_SELEQZ: u8 = 0b110101;
+pub const FUNCT_SELNEZ: u8 = 0b110111;
+
 /// Used for `MUL` and `MUH`.
 pub const FUNCT_SOP30: u8 = 0b011000;
 
@@ -45,26 +71,38 @@
 
 /// Used for many R-type instructions, like `ADD`, `SUB`, `MUL`, and `DIV`.
 pub const OPCODE_SPECIAL: u8 = 0b000000;
-/// Used for register-immediate instructions, like `DAHI` and `DATI`.
+/// Used for register-immediate instructions, like `DAHI`, `DATI`, and `BLTZ`.
 pub const OPCODE_REGIMM: u8 = 0b000001;
 
 pub const OPCODE_ORI: u8 = 0b001101;
 pub const OPCODE_ANDI: u8 = 0b001100;
+pub const OPCODE_XORI: u8 = 0b001110;
 
 pub const OPCODE_ADDI: u8 = 0b001000;
 pub const OPCODE_ADDIU: u8 = 0b001001;
 pub const OPCODE_DADDI: u8 = 0b011000;
 pub const OPCODE_DADDIU: u8 = 0b011001;
 
+pub const OPCODE_COP0: u8 = 0b010000;
 pub const OPCODE_COP1: u8 = 0b010001;
 pub const OPCODE_LUI: u8 = 0b001111;
 pub const OPCODE_AUI: u8 = OPCODE_LUI;
 
 // Loading and Storing
+pub const OPCODE_LB: u8 = 0b100000;
+pub const OPCODE_LBU: u8 = 0b100100;
+pub const OPCODE_LH: u8 = 0b100001;
+pub const OPCODE_LHU: u8 = 0b100101;
 pub const OPCODE_LW: u8 = 0b100011;
+pub const OPCODE_LD: u8 = 0b110111;
+pub const OPCODE_SB: u8 = 0b101000;
+pub const OPCODE_SH: u8 = 0b101001;
 pub const OPCODE_SW: u8 = 0b101011;
+pub const OPCODE_SD: u8 = 0b111111;
 pub const OPCODE_LWC1: u8 = 0b110001;
 pub const OPCODE_SWC1: u8 = 0b111001;
+pub const OPCODE_LDC1: u8 = 0b110101;
+pub const OPCODE_SDC1: u8 = 0b111101;
 
 // Jump opcodes:
 pub const OPCODE_J: u8 = 0b000010;
@@ -73,6 +111,26 @@
 // Branch OPCODE's
 pub const OPCODE_BEQ: u8 = 0b000100;
 pub const OPCODE_BNE: u8 = 0b000101;
+pub const OPCODE_BLEZ: u8 = 0b000110;
+pub const OPCODE_BGTZ: u8 = 0b000111;
+
+// Compact branch opcodes (MIPS64 version 6):
+/// Used for `BGEC` (also `BLEZC`, `BGEZC`, which are not supported).
+pub const OPCODE_POP26: u8 = 0b010110;
+/// Used for `BLTC` (also `BGTZC`, `BLTZC`, which are not supported).
+pub const OPCODE_POP27: u8 = 0b010111;
+pub const OPCODE_BC: u8 = 0b110010;
+pub const OPCODE_BALC: u8 = 0b111010;
+/// Used for `BEQZC` (also `JIC`, which is not supported).
+pub const OPCODE_POP66: u8 = 0b110110;
+/// Used for `BNEZC` (also `JIALC`, which is not supported).
+pub const OPCODE_POP76: u8 = 0b111110;
+/// Used for `BOVC`, `BEQZALC`, and `BEQC` in release 6 mode, where it
+/// replaces `ADDI`.
+pub const OPCODE_POP10: u8 = 0b001000;
+/// Used for `BNVC`, `BNEZALC`, and `BNEC` in release 6 mode, where it
+/// replaces `DADDI`.
+pub const OPCODE_POP30: u8 = 0b011000;
 
 // "ENC" is short for encoding. There is no formal name for this field
 // in the MIPS64 specification, other than the "shamt"/"sa" field that it
@@ -85,17 +143,64 @@
 pub const ENC_DMULU: u8 = 0b00010;
 pub const ENC_DDIV: u8 = 0b00010;
 pub const ENC_DDIVU: u8 = 0b00010;
+pub const ENC_MUH: u8 = 0b00011;
+pub const ENC_MUHU: u8 = 0b00011;
+pub const ENC_MOD: u8 = 0b00011;
+pub const ENC_MODU: u8 = 0b00011;
+pub const ENC_DMUH: u8 = 0b00011;
+pub const ENC_DMUHU: u8 = 0b00011;
+pub const ENC_DMOD: u8 = 0b00011;
+pub const ENC_DMODU: u8 = 0b00011;
 
 // "RMSUB" is short for register immediate subcode. There is no formal name
 // for this field in the MIPS64 specification, other than the "rt" field that
 // it replaces, so this was chosen as a mnemonic for this project.
 pub const RMSUB_DAHI: u8 = 0b00110;
 pub const RMSUB_DATI: u8 = 0b11110;
+pub const RMSUB_BLTZ: u8 = 0b00000;
+pub const RMSUB_BGEZ: u8 = 0b00001;
+pub const RMSUB_BLTZAL: u8 = 0b10000;
+pub const RMSUB_BGEZAL: u8 = 0b10001;
 
 pub const FUNCTION_ADD: u8 = 0b000000;
 pub const FUNCTION_SUB: u8 = 0b000001;
 pub const FUNCTION_MUL: u8 = 0b000010;
 pub const FUNCTION_DIV: u8 = 0b000011;
+pub const FUNCTION_SQRT: u8 = 0b000100;
+pub const FUNCTION_ABS: u8 = 0b000101;
+pub const FUNCTION_MOV: u8 = 0b000110;
+pub const FUNCTION_NEG: u8 = 0b000111;
+pub const FUNCTION_RECIP: u8 = 0b010101;
+pub const FUNCTION_RSQRT: u8 = 0b010110;
+pub const FUNCTION_MIN: u8 = 0b011100;
+pub const FUNCTION_MAX: u8 = 0b011110;
+
+// Conditional moves and selects. `movf.fmt` and `movt.fmt` share the MOVCF
+// function code, holding the condition code and the true/false bit in the
+// `ft` field.
+pub const FUNCTION_SEL: u8 = 0b010000;
+pub const FUNCTION_MOVCF: u8 = 0b010001;
+pub const FUNCTION_MOVZ: u8 = 0b010010;
+pub const FUNCTION_MOVN: u8 = 0b010011;
+pub const FUNCTION_SELEQZ: u8 = 0b010100;
+pub const FUNCTION_SELNEZ: u8 = 0b010111;
+
+// Floating-point to fixed-point conversions with an explicit rounding direction.
+pub const FUNCTION_ROUND_L: u8 = 0b001000;
+pub const FUNCTION_TRUNC_L: u8 = 0b001001;
+pub const FUNCTION_CEIL_L: u8 = 0b001010;
+pub const FUNCTION_FLOOR_L: u8 = 0b001011;
+pub const FUNCTION_ROUND_W: u8 = 0b001100;
+pub const FUNCTION_TRUNC_W: u8 = 0b001101;
+pub const FUNCTION_CEIL_W: u8 = 0b001110;
+pub const FUNCTION_FLOOR_W: u8 = 0b001111;
+
+// The cvt.*.fmt instructions encode the destination format in the
+// function field and the source format in the fmt field.
+pub const FUNCTION_CVT_S: u8 = 0b100000;
+pub const FUNCTION_CVT_D: u8 = 0b100001;
+pub const FUNCTION_CVT_W: u8 = 0b100100;
+pub const FUNCTION_CVT_L: u8 = 0b100101;
 
 // All floating-point c.cond.fmt instructions begin the
 // function field with 11.
@@ -105,9 +210,20 @@
 pub const FUNCTION_C_LE: u8 = 0b111110;
 pub const FUNCTION_C_NGT: u8 = 0b111111;
 
+// The cmp.cond.fmt instructions use the "w" and "l" fmts for single and
+// double precision, and hold the condition in the function field instead.
+// See the `CONDITION_*` constants in the floating_point module.
+
+/// Used for `ERET`, under the `CO` subcode of COP0.
+pub const FUNCTION_ERET: u8 = 0b011000;
+
 // "SUB" is short for operation subcode. Bits 25..21 of some instructions.
 /// Floating-point branch conditional.
 pub const SUB_BC: u8 = 0b01000;
+/// Branch if the lowest bit of a floating-point register is zero.
+pub const SUB_BC1EQZ: u8 = 0b01001;
+/// Branch if the lowest bit of a floating-point register is not zero.
+pub const SUB_BC1NEZ: u8 = 0b01101;
 /// Move word from floating point.
 pub const SUB_MF: u8 = 0b00000;
 /// Move word to floating point.
@@ -116,9 +232,21 @@
 pub const SUB_DMF: u8 = 0b00001;
 /// Doubleword move to floating point.
 pub const SUB_DMT: u8 = 0b00101;
+/// Move control word from floating point.
+pub const SUB_CF: u8 = 0b00010;
+/// Move control word to floating point.
+pub const SUB_CT: u8 = 0b00110;
+/// Coprocessor 0 operation, such as `ERET`. Only the leading bit is set.
+pub const SUB_CO: u8 = 0b10000;
 
 pub const FMT_SINGLE: u8 = 16;
 pub const FMT_DOUBLE: u8 = 17;
+/// 32-bit fixed-point (integer) values. Used as the source of a conversion,
+/// and for single-precision values in `cmp.cond.fmt`.
+pub const FMT_WORD: u8 = 20;
+/// 64-bit fixed-point (integer) values. Used as the source of a conversion,
+/// and for double-precision values in `cmp.cond.fmt`.
+pub const FMT_LONG: u8 = 21;
 
 /// Return the register width associated to an instruction
 /// with the given `funct` code.
@@ -126,14 +254,22 @@
 /// Returns [`None`] if the `funct` code is not supported.
 pub fn reg_width_by_funct(funct: u8) -> Option<RegWidth> {
     match funct {
-        // `syscall` does not have a register width associated with it,
-        // but is set for the purposes of a default signal value.
-        FUNCT_SYSCALL => Some(RegWidth::DoubleWord),
-        FUNCT_ADD | FUNCT_ADDU | FUNCT_SUB | FUNCT_SLL => Some(RegWidth::Word),
-        FUNCT_AND | FUNCT_OR | FUNCT_SLT | FUNCT_SLTU => Some(RegWidth::DoubleWord),
+        // `syscall` and `break` do not have a register width associated with them,
+        // but are set for the purposes of a default signal value.
+        FUNCT_SYSCALL | FUNCT_BREAK => Some(RegWidth::DoubleWord),
+        FUNCT_ADD | FUNCT_ADDU | FUNCT_SUB => Some(RegWidth::Word),
+        FUNCT_SLL | FUNCT_SRL | FUNCT_SRA => Some(RegWidth::Word),
+        FUNCT_SLLV | FUNCT_SRLV | FUNCT_SRAV => Some(RegWidth::Word),
+        FUNCT_AND | FUNCT_OR | FUNCT_XOR | FUNCT_NOR => Some(RegWidth::DoubleWord),
+        FUNCT_SLT | FUNCT_SLTU => Some(RegWidth::DoubleWord),
+        FUNCT_DSLL | FUNCT_DSRL | FUNCT_DSRA => Some(RegWidth::DoubleWord),
+        FUNCT_DSLL32 | FUNCT_DSRL32 | FUNCT_DSRA32 => Some(RegWidth::DoubleWord),
+        FUNCT_DSLLV | FUNCT_DSRLV | FUNCT_DSRAV => Some(RegWidth::DoubleWord),
         FUNCT_DADD | FUNCT_DSUB => Some(RegWidth::DoubleWord),
         FUNCT_DADDU | FUNCT_DSUBU => Some(RegWidth::DoubleWord),
         FUNCT_JALR => Some(RegWidth::DoubleWord),
+        FUNCT_MOVCI | FUNCT_MOVZ | FUNCT_MOVN => Some(RegWidth::DoubleWord),
+        FUNCT_SELEQZ | FUNCT_SELNEZ => Some(RegWidth::DoubleWord),
         FUNCT_SOP30 | FUNCT_SOP31 | FUNCT_SOP32 | FUNCT_SOP33 => Some(RegWidth::Word),
         FUNCT_SOP34 | FUNCT_SOP35 | FUNCT_SOP36 | FUNCT_SOP37 => Some(RegWidth::DoubleWord),
         _ => None,
@@ -1,5 +1,7 @@
 //! Internal datapath control signals.
 
+use super::super::datapath::MemoryAccess;
+
 /// Full collection of control signals.
 #[derive(Clone, Default, PartialEq)]
 pub struct ControlSignals {
@@ -10,8 +12,10 @@ pub struct ControlSignals {
     pub branch_type: BranchType,
     pub imm_shift: ImmShift,
     pub jump: Jump,
+    pub mem_extend: MemExtend,
     pub mem_read: MemRead,
     pub mem_to_reg: MemToReg,
+    pub mem_width: MemWidth,
     pub mem_write: MemWrite,
     pub mem_write_src: MemWriteSrc,
     pub reg_dst: RegDst,
@@ -19,6 +23,25 @@ pub struct ControlSignals {
     pub reg_write: RegWrite,
 }
 
+impl ControlSignals {
+    /// The memory an instruction with these signals accesses when the ALU
+    /// gives `address`, if it is a load or store.
+    pub fn memory_access(&self, address: u64) -> Option<MemoryAccess<u64>> {
+        let is_write = if self.mem_write == MemWrite::YesWrite {
+            true
+        } else if self.mem_read == MemRead::YesRead {
+            false
+        } else {
+            return None;
+        };
+
+        Some(MemoryAccess {
+            addresses: address..address.saturating_add(self.mem_width.bytes()),
+            is_write,
+        })
+    }
+}
+
 /// The output of the ALU control unit that directly controls the ALU.
 ///
 /// This is not to be confused with the [`AluOp`] signal. ALUControl is
@@ -67,8 +90,41 @@ pub enum AluControl {
     /// `_1011` (11) - Perform unsigned integer division. (Returns the integer quotient.)
     DivisionUnsigned,
 
-    /// `_1100` (12) - Perform a shift left logical operation by `shamt` bits.
+    /// `_1100` (12) - Perform a shift left logical operation by the given number of bits.
     ShiftLeftLogical(u32),
+
+    /// `_1101` (13) - Perform a shift right logical operation by the given number of bits.
+    ShiftRightLogical(u32),
+
+    /// `_1110` (14) - Perform a shift right arithmetic operation by the given number of bits.
+    ShiftRightArithmetic(u32),
+
+    /// `_1111` (15) - Perform a bitwise "XOR" operation.
+    Xor,
+
+    /// `1_0000` (16) - Perform a bitwise "NOR" operation.
+    Nor,
+
+    /// `1_0001` (17) - Pass the first input through unchanged. (Used in conditional moves.)
+    Move,
+
+    /// `1_0010` (18) - Select the first input if the second input is zero. Otherwise, the result is zero.
+    SelectIfZero,
+
+    /// `1_0011` (19) - Select the first input if the second input is not zero. Otherwise, the result is zero.
+    SelectIfNotZero,
+
+    /// `1_0100` (20) - Perform signed multiplication. (Returns the upper half of the product.)
+    MultiplicationHighSigned,
+
+    /// `1_0101` (21) - Perform unsigned multiplication. (Returns the upper half of the product.)
+    MultiplicationHighUnsigned,
+
+    /// `1_0110` (22) - Perform signed integer division. (Returns the remainder.)
+    ModuloSigned,
+
+    /// `1_0111` (23) - Perform unsigned integer division. (Returns the remainder.)
+    ModuloUnsigned,
 }
 
 /// This determines the operation sent to the ALU control unit.
@@ -109,6 +165,8 @@ pub enum AluOp {
     /// field alone does not provide the full description of those
     /// instructions.)
     UseFunctField = 7,
+    /// `1000` (8) - Perform a binary "XOR" operation.
+    Xor = 8,
 }
 
 /// Determines the second source of the ALU.
@@ -142,19 +200,48 @@ pub enum Branch {
     YesBranch = 1,
 }
 
-/// Determines, given [`Branch`] is set, whether to branch when the [`AluZ`](super::datapath_signals::AluZ) signal is set,
-/// or when [`AluZ`](super::datapath_signals::AluZ) is not set.
+/// Determines, given [`Branch`] is set, the condition on which to branch.
 ///
-/// In effect, this decides whether or not to invert the [`AluZ`](super::datapath_signals::AluZ) signal, which is
-/// used between the `beq` and `bne` instructions.
+/// For `beq` and `bne`, this decides whether or not to invert the
+/// [`AluZ`](super::datapath_signals::AluZ) signal. The other conditions
+/// compare the values read from the registers as signed integers.
 #[derive(Clone, Default, PartialEq)]
 pub enum BranchType {
-    /// Branch based on [`AluZ`](super::datapath_signals::AluZ). (Used in `beq`.)
+    /// Branch based on [`AluZ`](super::datapath_signals::AluZ). (Used in `beq` and `beqzc`.)
     #[default]
     OnEqual = 0,
 
-    /// Branch based on the inverse of [`AluZ`](super::datapath_signals::AluZ). (Used in `bne`.)
+    /// Branch based on the inverse of [`AluZ`](super::datapath_signals::AluZ). (Used in `bne` and `bnezc`.)
     OnNotEqual = 1,
+
+    /// Branch if `rs` is less than zero. (Used in `bltz` and `bltzal`.)
+    OnLessThanZero = 2,
+
+    /// Branch if `rs` is less than or equal to zero. (Used in `blez`.)
+    OnLessThanOrEqualZero = 3,
+
+    /// Branch if `rs` is greater than zero. (Used in `bgtz`.)
+    OnGreaterThanZero = 4,
+
+    /// Branch if `rs` is greater than or equal to zero. (Used in `bgez` and `bgezal`.)
+    OnGreaterThanOrEqualZero = 5,
+
+    /// Branch if `rs` is less than `rt`. (Used in `bltc`.)
+    OnLessThan = 6,
+
+    /// Branch if `rs` is greater than or equal to `rt`. (Used in `bgec`.)
+    OnGreaterThanOrEqual = 7,
+
+    /// Branch unconditionally. (Used in `bc` and `balc`.)
+    Always = 8,
+
+    /// Branch if adding `rs` and `rt` as signed 32-bit values overflows, or
+    /// if either is not a sign-extended 32-bit value. (Used in `bovc`.)
+    OnOverflow = 9,
+
+    /// Branch if adding `rs` and `rt` as signed 32-bit values does not
+    /// overflow, and both are sign-extended 32-bit values. (Used in `bnvc`.)
+    OnNoOverflow = 10,
 }
 
 /// Determines the amount of bits to left-shift the immediate value before being passed to the ALU.
@@ -184,6 +271,20 @@ pub enum Jump {
     YesJumpJalr = 2,
 }
 
+/// Determines, given that [`MemRead`] is set, how data read from memory is
+/// extended to fill 64 bits. This does not apply to doublewords.
+#[derive(Clone, Default, PartialEq)]
+pub enum MemExtend {
+    /// Copy the leading bit of the data into the upper bits. This is used by
+    /// instructions like `lb` and `lh`.
+    #[default]
+    SignExtend = 0,
+
+    /// Fill the upper bits with zeros. This is used by instructions like
+    /// `lbu` and `lhu`.
+    ZeroExtend = 1,
+}
+
 /// Determines if memory should be read.
 ///
 /// This should not be set in combination with [`MemWrite`].
@@ -208,6 +309,40 @@ pub enum MemToReg {
     UseAlu = 0,
     UseMemory = 1,
     UsePcPlusFour = 2,
+
+    /// Used by linking instructions in place of `UsePcPlusFour` when
+    /// branch delay slots are enabled.
+    UsePcPlusEight = 3,
+}
+
+/// Determines, given that [`MemRead`] or [`MemWrite`] is set, the amount
+/// of data read from or written to memory.
+#[derive(Clone, Default, PartialEq)]
+pub enum MemWidth {
+    /// Use bytes (8 bits).
+    Byte = 0,
+
+    /// Use halfwords (16 bits).
+    HalfWord = 1,
+
+    /// Use words (32 bits).
+    Word = 2,
+
+    /// Use doublewords (64 bits).
+    #[default]
+    DoubleWord = 3,
+}
+
+impl MemWidth {
+    /// The number of bytes read or written.
+    pub fn bytes(&self) -> u64 {
+        match self {
+            Self::Byte => 1,
+            Self::HalfWord => 2,
+            Self::Word => 4,
+            Self::DoubleWord => 8,
+        }
+    }
 }
 
 /// Determines if memory should be written to.
@@ -270,11 +405,26 @@ pub enum RegWidth {
 }
 
 /// Determines if the register file should be written to.
+///
+/// The conditional moves only write to the register file if their
+/// condition holds. The condition is checked in the EX stage, and the
+/// decision is given by the [`CondMove`](super::datapath_signals::CondMove)
+/// signal.
 #[derive(Clone, Default, Eq, PartialEq)]
 pub enum RegWrite {
     #[default]
     NoWrite = 0,
     YesWrite = 1,
+
+    /// Write if the data read from register `rt` is zero. (Used in `movz`.)
+    IfZero = 2,
+
+    /// Write if the data read from register `rt` is not zero. (Used in `movn`.)
+    IfNotZero = 3,
+
+    /// Write if the floating-point condition code selected by the `rt` field
+    /// matches its true/false bit. (Used in `movf` and `movt`.)
+    IfConditionCode = 4,
 }
 
 pub mod floating_point {
@@ -282,31 +432,75 @@ pub mod floating_point {
 
     #[derive(Clone, Default, PartialEq)]
     pub struct FpuControlSignals {
+        pub cause_write: CauseWrite,
         pub cc: Cc,
         pub cc_write: CcWrite,
         pub data_src: DataSrc,
         pub data_write: DataWrite,
+        pub fcr_write: FcrWrite,
         pub fpu_alu_op: FpuAluOp,
         pub fpu_branch: FpuBranch,
+        pub fpu_branch_src: FpuBranchSrc,
         pub fpu_mem_to_reg: FpuMemToReg,
         pub fpu_reg_dst: FpuRegDst,
         pub fpu_reg_width: FpuRegWidth,
         pub fpu_reg_write: FpuRegWrite,
+        pub fpu_src_type: FpuSrcType,
         pub fpu_take_branch: FpuTakeBranch,
     }
 
+    /// Determines if the IEEE 754 exceptions raised by the floating-point ALU or
+    /// comparator are written to the `Cause` field of the FCSR.
+    ///
+    /// If any of these exceptions are enabled in the FCSR, the instruction traps.
+    /// Otherwise, they are also added to the sticky `Flags` field of the FCSR.
+    #[derive(Clone, Default, PartialEq)]
+    pub enum CauseWrite {
+        /// Leave the FCSR as-is. Used by instructions that are not arithmetic.
+        #[default]
+        NoWrite = 0,
+
+        /// Write the exceptions raised to the FCSR.
+        YesWrite = 1,
+    }
+
     /// Determines, given that [`CcWrite`] is set, which condition code register
     /// should be written to or read from for a given operation.
     ///
-    /// For the sake of this project, it will usually be assumed that this will
-    /// be 0, however the functionality is available to be extended.
-    #[derive(Clone, Default, PartialEq)]
+    /// This is given by the `cc` field of a `c.cond.fmt`, `bc1t`, `bc1f`,
+    /// `movf.fmt`, or `movt.fmt` instruction, which is 0 unless the condition
+    /// code is given explicitly.
+    #[derive(Clone, Copy, Default, PartialEq)]
     pub enum Cc {
         /// Use condition code register 0. Default in most operations. Can be
         /// additionally used in the case where the condition code register is
         /// irrelevant to the current instruction.
         #[default]
         Cc0 = 0,
+        Cc1 = 1,
+        Cc2 = 2,
+        Cc3 = 3,
+        Cc4 = 4,
+        Cc5 = 5,
+        Cc6 = 6,
+        Cc7 = 7,
+    }
+
+    impl Cc {
+        /// Get the corresponding [`Cc`] control signal based on the `cc`
+        /// field in an instruction. Only the lowest three bits are used.
+        pub fn from_cc(cc: u8) -> Self {
+            match cc & 0b111 {
+                0 => Self::Cc0,
+                1 => Self::Cc1,
+                2 => Self::Cc2,
+                3 => Self::Cc3,
+                4 => Self::Cc4,
+                5 => Self::Cc5,
+                6 => Self::Cc6,
+                _ => Self::Cc7,
+            }
+        }
     }
 
     /// Determines if the condition code register file should be written to.
@@ -332,6 +526,10 @@ pub enum DataSrc {
         /// from a given instruction.
         #[default]
         FloatingPointUnit = 1,
+
+        /// Use data from the floating-point control register `fs` from a given
+        /// instruction, such as the FCSR.
+        ControlRegister = 2,
     }
 
     /// Determines whether to write to the `Data` register in the floating-point unit.
@@ -362,6 +560,18 @@ pub enum DataWrite {
         YesWrite = 1,
     }
 
+    /// Determines if the floating-point control register `fs` from a given instruction
+    /// should be written to with the contents of the `Data` register.
+    #[derive(Clone, Default, PartialEq)]
+    pub enum FcrWrite {
+        /// Do not write to the floating-point control registers.
+        #[default]
+        NoWrite = 0,
+
+        /// Write to the floating-point control register `fs`.
+        YesWrite = 1,
+    }
+
     /// This doubly determines the operations sent to the floating-point ALU and the
     /// floating-point comparator.
     ///
@@ -372,7 +582,8 @@ pub enum DataWrite {
     /// This fifth bit is determined by [`FpuRegWidth`].
     ///
     /// *Implementation note:* The bits set for the comparator are intended to match
-    /// the bits used in the `cond` field of a `c.cond.fmt` instruction.
+    /// the bits used in the `cond` field of a `c.cond.fmt` instruction. Operations
+    /// that only use the ALU and do not fit in those four bits are numbered from 16.
     #[derive(Clone, Debug, Default, PartialEq)]
     pub enum FpuAluOp {
         #[default]
@@ -416,12 +627,151 @@ pub enum FpuAluOp {
         /// `_1111` (15):
         /// - Comparator: Set if not greater than.
         Sngt = 15,
+
+        /// (16):
+        /// - ALU: Take the square root of the first input.
+        SquareRoot = 16,
+
+        /// (17):
+        /// - ALU: Clear the sign bit of the first input.
+        AbsoluteValue = 17,
+
+        /// (18):
+        /// - ALU: Pass the first input through unchanged.
+        Move = 18,
+
+        /// (19):
+        /// - ALU: Flip the sign bit of the first input.
+        Negation = 19,
+
+        /// (20):
+        /// - ALU: Take the reciprocal of the first input.
+        Reciprocal = 20,
+
+        /// (21):
+        /// - ALU: Take the reciprocal of the square root of the first input.
+        ReciprocalSquareRoot = 21,
+
+        /// (22):
+        /// - ALU: Select the smaller of the two inputs.
+        Minimum = 22,
+
+        /// (23):
+        /// - ALU: Select the larger of the two inputs.
+        Maximum = 23,
+
+        /// (24):
+        /// - ALU: Convert the first input to a single-precision value.
+        ConvertToSingle = 24,
+
+        /// (25):
+        /// - ALU: Convert the first input to a double-precision value.
+        ConvertToDouble = 25,
+
+        /// (26):
+        /// - ALU: Convert the first input to a 32-bit fixed-point value, rounding
+        ///   to the nearest value.
+        ConvertToWord = 26,
+
+        /// (27):
+        /// - ALU: Convert the first input to a 64-bit fixed-point value, rounding
+        ///   to the nearest value.
+        ConvertToLong = 27,
+
+        /// (28):
+        /// - ALU: Convert the first input to a 32-bit fixed-point value, rounding
+        ///   toward zero.
+        TruncateToWord = 28,
+
+        /// (29):
+        /// - ALU: Convert the first input to a 64-bit fixed-point value, rounding
+        ///   toward zero.
+        TruncateToLong = 29,
+
+        /// (30):
+        /// - ALU: Convert the first input to a 32-bit fixed-point value, rounding
+        ///   to the nearest value.
+        RoundToWord = 30,
+
+        /// (31):
+        /// - ALU: Convert the first input to a 64-bit fixed-point value, rounding
+        ///   to the nearest value.
+        RoundToLong = 31,
+
+        /// (32):
+        /// - ALU: Convert the first input to a 32-bit fixed-point value, rounding
+        ///   toward positive infinity.
+        CeilingToWord = 32,
+
+        /// (33):
+        /// - ALU: Convert the first input to a 64-bit fixed-point value, rounding
+        ///   toward positive infinity.
+        CeilingToLong = 33,
+
+        /// (34):
+        /// - ALU: Convert the first input to a 32-bit fixed-point value, rounding
+        ///   toward negative infinity.
+        FloorToWord = 34,
+
+        /// (35):
+        /// - ALU: Convert the first input to a 64-bit fixed-point value, rounding
+        ///   toward negative infinity.
+        FloorToLong = 35,
+
+        /// (36):
+        /// - ALU: Compare the inputs using the condition in the function field of a
+        ///   `cmp.cond.fmt` instruction, giving all ones if it holds and all zeros
+        ///   otherwise.
+        Compare = 36,
+
+        /// (37):
+        /// - ALU: Select the second input if the lowest bit of the data read from
+        ///   register `fd` is set. Otherwise, select the first input.
+        Select = 37,
+
+        /// (38):
+        /// - ALU: Select the first input if the lowest bit of the second input is
+        ///   clear. Otherwise, the result is zero.
+        SelectIfZero = 38,
+
+        /// (39):
+        /// - ALU: Select the first input if the lowest bit of the second input is
+        ///   set. Otherwise, the result is zero.
+        SelectIfNotZero = 39,
     }
 
     impl FpuAluOp {
         /// Get the corresponding control signal given a function code.
         pub fn from_function(function: u8) -> Result<Self, String> {
             match function {
+                FUNCTION_ADD => Ok(Self::Addition),
+                FUNCTION_SUB => Ok(Self::Subtraction),
+                FUNCTION_MUL => Ok(Self::MultiplicationOrEqual),
+                FUNCTION_DIV => Ok(Self::Division),
+                FUNCTION_SQRT => Ok(Self::SquareRoot),
+                FUNCTION_ABS => Ok(Self::AbsoluteValue),
+                FUNCTION_MOV => Ok(Self::Move),
+                FUNCTION_NEG => Ok(Self::Negation),
+                FUNCTION_RECIP => Ok(Self::Reciprocal),
+                FUNCTION_RSQRT => Ok(Self::ReciprocalSquareRoot),
+                FUNCTION_MIN => Ok(Self::Minimum),
+                FUNCTION_MAX => Ok(Self::Maximum),
+                FUNCTION_MOVCF | FUNCTION_MOVZ | FUNCTION_MOVN => Ok(Self::Move),
+                FUNCTION_SEL => Ok(Self::Select),
+                FUNCTION_SELEQZ => Ok(Self::SelectIfZero),
+                FUNCTION_SELNEZ => Ok(Self::SelectIfNotZero),
+                FUNCTION_CVT_S => Ok(Self::ConvertToSingle),
+                FUNCTION_CVT_D => Ok(Self::ConvertToDouble),
+                FUNCTION_CVT_W => Ok(Self::ConvertToWord),
+                FUNCTION_CVT_L => Ok(Self::ConvertToLong),
+                FUNCTION_TRUNC_W => Ok(Self::TruncateToWord),
+                FUNCTION_TRUNC_L => Ok(Self::TruncateToLong),
+                FUNCTION_ROUND_W => Ok(Self::RoundToWord),
+                FUNCTION_ROUND_L => Ok(Self::RoundToLong),
+                FUNCTION_CEIL_W => Ok(Self::CeilingToWord),
+                FUNCTION_CEIL_L => Ok(Self::CeilingToLong),
+                FUNCTION_FLOOR_W => Ok(Self::FloorToWord),
+                FUNCTION_FLOOR_L => Ok(Self::FloorToLong),
                 FUNCTION_C_EQ => Ok(Self::MultiplicationOrEqual),
                 FUNCTION_C_LT => Ok(Self::Slt),
                 FUNCTION_C_NGE => Ok(Self::Snge),
@@ -447,6 +797,19 @@ pub enum FpuBranch {
         YesBranch = 1,
     }
 
+    /// Determines, given that [`FpuBranch`] is set, where the condition a
+    /// floating-point branch tests comes from.
+    #[derive(Clone, Default, PartialEq)]
+    pub enum FpuBranchSrc {
+        /// Use the condition code register selected by [`Cc`], as done by
+        /// `bc1t` and `bc1f`.
+        #[default]
+        ConditionCode = 0,
+
+        /// Use the lowest bit of register `ft`, as done by `bc1eqz` and `bc1nez`.
+        Register = 1,
+    }
+
     /// Determines, given that [`FpuRegWrite`] is set, what the source of a floating-point
     /// register's new data will be.
     ///
@@ -495,13 +858,38 @@ impl FpuRegWidth {
         /// the `fmt` field in an instruction.
         pub fn from_fmt(fmt: u8) -> Result<Self, String> {
             match fmt {
-                FMT_SINGLE => Ok(Self::Word),
-                FMT_DOUBLE => Ok(Self::DoubleWord),
+                FMT_SINGLE | FMT_WORD => Ok(Self::Word),
+                FMT_DOUBLE | FMT_LONG => Ok(Self::DoubleWord),
                 _ => Err(format!("`{fmt}` is an invalid fmt value")),
             }
         }
     }
 
+    /// Determines how the floating-point ALU interprets the data read from the
+    /// floating-point register file. The width of this data is still determined
+    /// by [`FpuRegWidth`].
+    #[derive(Clone, Default, PartialEq)]
+    pub enum FpuSrcType {
+        /// Interpret the data as a floating-point value.
+        #[default]
+        FloatingPoint = 0,
+
+        /// Interpret the data as a signed fixed-point (integer) value. Only used
+        /// as the source of a conversion.
+        FixedPoint = 1,
+    }
+
+    impl FpuSrcType {
+        /// Get the corresponding [`FpuSrcType`] control signal based on
+        /// the `fmt` field in an instruction.
+        pub fn from_fmt(fmt: u8) -> Self {
+            match fmt {
+                FMT_WORD | FMT_LONG => Self::FixedPoint,
+                _ => Self::FloatingPoint,
+            }
+        }
+    }
+
     /// Determines if the floating-point register file should be written to.
     #[derive(Clone, Default, PartialEq)]
     pub enum FpuRegWrite {
@@ -511,6 +899,21 @@ pub enum FpuRegWrite {
 
         /// Write to the floating-point register file.
         YesWrite = 1,
+
+        /// Write to the floating-point register file if the data from the main
+        /// processor, read from general-purpose register `rt`, is zero. (Used in
+        /// `movz.fmt`.)
+        IfZero = 2,
+
+        /// Write to the floating-point register file if the data from the main
+        /// processor, read from general-purpose register `rt`, is not zero. (Used
+        /// in `movn.fmt`.)
+        IfNotZero = 3,
+
+        /// Write to the floating-point register file if the condition code
+        /// selected by [`Cc`] matches the true/false bit of the instruction. (Used
+        /// in `movf.fmt` and `movt.fmt`.)
+        IfConditionCode = 4,
     }
 
     /// After checking the [`FpuBranch`] and condition code, this signal determines whether
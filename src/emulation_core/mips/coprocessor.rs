@@ -2,8 +2,51 @@
 
 use super::constants::*;
 use super::control_signals::floating_point::*;
+use super::floating_point::{self, RoundingMode, FLAG_INVALID};
 use super::instruction::Instruction;
 
+/// Register number of the Floating-Point Implementation Register (`FIR`).
+pub const FCR_FIR: u8 = 0;
+/// Register number of the Floating-Point Condition Codes Register (`FCCR`),
+/// a view of the condition codes in the FCSR.
+pub const FCR_FCCR: u8 = 25;
+/// Register number of the Floating-Point Exceptions Register (`FEXR`), a view
+/// of the `Cause` and `Flags` fields of the FCSR.
+pub const FCR_FEXR: u8 = 26;
+/// Register number of the Floating-Point Enables Register (`FENR`), a view of
+/// the `Enables` and `RM` fields of the FCSR.
+pub const FCR_FENR: u8 = 28;
+/// Register number of the Floating-Point Control and Status Register (`FCSR`).
+pub const FCR_FCSR: u8 = 31;
+
+/// The contents of the read-only `FIR`. This reports support for 64-bit
+/// registers (`F64`), the `L`, `W`, `D`, and `S` formats, and IEEE 754-2008
+/// behavior (`Has2008`).
+pub const FIR_VALUE: u32 = (1 << 23) | (1 << 22) | (1 << 21) | (1 << 20) | (1 << 17) | (1 << 16);
+
+/// The rounding mode (`RM`) field of the FCSR, in bits 1..0.
+pub const FCSR_RM_MASK: u32 = 0b11;
+/// The lowest bit of the sticky `Flags` field of the FCSR, in bits 6..2.
+pub const FCSR_FLAGS_SHIFT: u32 = 2;
+/// The lowest bit of the `Enables` field of the FCSR, in bits 11..7.
+pub const FCSR_ENABLES_SHIFT: u32 = 7;
+/// The lowest bit of the `Cause` field of the FCSR, in bits 17..12.
+pub const FCSR_CAUSE_SHIFT: u32 = 12;
+/// A mask for one five-bit exception field of the FCSR, before shifting.
+/// The bits match the `FLAG_*` constants in [`floating_point`].
+pub const FCSR_EXCEPTIONS_MASK: u32 = 0x1F;
+/// The bits of the FCSR stored in [`MipsFpCoprocessor::fcsr`]: `RM`, `Flags`,
+/// `Enables`, and `Cause`, including its unimplemented operation (`E`) bit.
+pub const FCSR_WRITABLE_MASK: u32 = 0x3_FFFF;
+/// The read-only `ABS2008` and `NAN2008` bits of the FCSR. `abs.fmt` and
+/// `neg.fmt` never signal exceptions, and NaNs follow IEEE 754-2008.
+pub const FCSR_2008: u32 = (1 << 18) | (1 << 19);
+/// The floating-point condition code 0 (`FCC0`) bit of the FCSR.
+pub const FCSR_FCC0: u32 = 1 << 23;
+/// The lowest bit of the condition codes 1 to 7 (`FCC1` to `FCC7`) in the
+/// FCSR, in bits 31..25.
+pub const FCSR_FCC_SHIFT: u32 = 25;
+
 /// An implementation of a floating-point coprocessor for the MIPS64 ISA.
 ///
 /// Different from the main processor, much of the functionality of the coprocessor
@@ -14,10 +57,18 @@ pub struct MipsFpCoprocessor {
     pub signals: FpuControlSignals,
     pub state: FpuState,
     pub is_halted: bool,
+    /// The message given by the error that halted the coprocessor, if any.
+    pub error_message: Option<String>,
 
     pub fpr: [u64; 32],
+    /// The eight condition codes, where bit `n` holds condition code `n`.
     pub condition_code: u64,
     pub data: u64,
+
+    /// The `RM`, `Flags`, `Enables`, and `Cause` fields of the FCSR. The
+    /// condition codes are kept in [`Self::condition_code`] instead. Use
+    /// [`Self::read_control_register`] for the full contents of the FCSR.
+    pub fcsr: u32,
 }
 
 #[derive(Clone, Default, PartialEq)]
@@ -45,6 +96,9 @@ pub struct FpuState {
     pub fp_register_data_from_main_processor: u64,
     pub read_data_1: u64,
     pub read_data_2: u64,
+    /// The data read from register `fd`. Only used by `sel.fmt`, which
+    /// selects between `fs` and `ft` using its lowest bit.
+    pub read_data_3: u64,
     pub register_write_data: u64,
     pub register_write_mux_to_mux: u64,
     pub sign_extend_data: u64,
@@ -56,6 +110,13 @@ pub struct FpuState {
 
     pub alu_result: u64,
     pub comparator_result: u64,
+
+    /// The IEEE 754 exceptions raised by the ALU or comparator, as a
+    /// combination of the `FLAG_*` constants in [`floating_point`].
+    pub exceptions: u32,
+    /// Set if one of `exceptions` is enabled in the FCSR, in which case the
+    /// instruction raises a floating-point exception.
+    pub trap: bool,
 }
 
 impl MipsFpCoprocessor {
@@ -69,6 +130,8 @@ pub fn stage_instruction_decode(&mut self) {
     pub fn stage_execute(&mut self) {
         self.alu();
         self.comparator();
+        self.check_unordered_comparison();
+        self.write_cause();
         self.write_condition_code();
         self.write_fp_register_to_memory();
         self.set_condition_code_line();
@@ -86,7 +149,8 @@ pub fn stage_writeback(&mut self) {
 
     // ===================== General Functions =====================
     /// Handle an otherwise irrecoverable error within the datapath.
-    pub fn error(&mut self, _message: &str) {
+    pub fn error(&mut self, message: &str) {
+        self.error_message = Some(message.to_string());
         self.is_halted = true;
     }
 
@@ -121,6 +185,61 @@ pub fn set_fp_register_data_from_main_processor(&mut self, data: u64) {
         self.state.fp_register_data_from_main_processor = data;
     }
 
+    /// Get the value of the condition code selected by `cc`. This is used by
+    /// the `movf` and `movt` instructions in the main processor.
+    pub fn get_condition_code(&self, cc: u8) -> bool {
+        (self.condition_code >> cc) & 1 == 1
+    }
+
+    /// Get the rounding mode selected in the FCSR.
+    pub fn rounding_mode(&self) -> RoundingMode {
+        RoundingMode::from_bits(self.fcsr & FCSR_RM_MASK)
+    }
+
+    /// Read the floating-point control register selected by `register`, as
+    /// done by `cfc1`. Reading any unimplemented register returns 0.
+    pub fn read_control_register(&self, register: u8) -> u32 {
+        let exceptions_mask =
+            (FCSR_EXCEPTIONS_MASK << FCSR_FLAGS_SHIFT) | (0x3F << FCSR_CAUSE_SHIFT);
+        let enables_mask = (FCSR_EXCEPTIONS_MASK << FCSR_ENABLES_SHIFT) | FCSR_RM_MASK;
+        let fcc = (self.condition_code & 0xFF) as u32;
+
+        match register {
+            FCR_FIR => FIR_VALUE,
+            FCR_FCCR => fcc,
+            FCR_FEXR => self.fcsr & exceptions_mask,
+            FCR_FENR => self.fcsr & enables_mask,
+            FCR_FCSR => {
+                let fcc_bits = ((fcc & 1) * FCSR_FCC0) | ((fcc >> 1) << FCSR_FCC_SHIFT);
+                self.fcsr | FCSR_2008 | fcc_bits
+            }
+            _ => 0,
+        }
+    }
+
+    /// Write `value` to the floating-point control register selected by
+    /// `register`, as done by `ctc1`. Writing to `FIR`, to read-only bits,
+    /// or to any unimplemented register has no effect.
+    pub fn write_control_register(&mut self, register: u8, value: u32) {
+        let exceptions_mask =
+            (FCSR_EXCEPTIONS_MASK << FCSR_FLAGS_SHIFT) | (0x3F << FCSR_CAUSE_SHIFT);
+        let enables_mask = (FCSR_EXCEPTIONS_MASK << FCSR_ENABLES_SHIFT) | FCSR_RM_MASK;
+
+        match register {
+            FCR_FCCR => self.condition_code = (value & 0xFF) as u64,
+            FCR_FEXR => {
+                self.fcsr = (self.fcsr & !exceptions_mask) | (value & exceptions_mask);
+            }
+            FCR_FENR => self.fcsr = (self.fcsr & !enables_mask) | (value & enables_mask),
+            FCR_FCSR => {
+                self.fcsr = value & FCSR_WRITABLE_MASK;
+                let fcc0 = (value & FCSR_FCC0 != 0) as u32;
+                self.condition_code = (fcc0 | ((value >> FCSR_FCC_SHIFT) << 1)) as u64;
+            }
+            _ => (),
+        }
+    }
+
     /// Gets the contents of the data line that goes from `Read Data 2` to the multiplexer
     /// in the main processor controlled by [`MemWriteSrc`](super::control_signals::MemWriteSrc).
     pub fn get_fp_register_to_memory(&mut self) -> u64 {
@@ -140,6 +259,12 @@ fn instruction_decode(&mut self) {
                 self.state.ft = r.ft as u32;
                 self.state.fd = r.fd as u32;
                 self.state.function = r.function as u32;
+
+                // movf.fmt and movt.fmt hold whether to move on true or
+                // false in the lowest bit of the `ft` field.
+                if matches!(r.fmt, FMT_SINGLE | FMT_DOUBLE) && r.function == FUNCTION_MOVCF {
+                    self.state.branch_flag = r.ft & 1 == 1;
+                }
             }
             Instruction::FpuIType(i) => {
                 self.state.ft = i.ft as u32;
@@ -161,13 +286,21 @@ fn instruction_decode(&mut self) {
             Instruction::FpuBranchType(b) => {
                 self.state.op = b.op as u32;
                 self.state.fmt = b.bcc1 as u32;
-                self.state.branch_flag = b.tf == 1;
+                self.state.branch_flag = match b.bcc1 {
+                    SUB_BC1EQZ => false,
+                    SUB_BC1NEZ => true,
+                    _ => b.tf == 1,
+                };
+                // Only used by bc1eqz and bc1nez.
+                self.state.ft = b.ft as u32;
             }
             // These types do not use the floating-point unit so they can be ignored.
             Instruction::RType(_)
             | Instruction::IType(_)
             | Instruction::JType(_)
-            | Instruction::SyscallType(_) => (),
+            | Instruction::SyscallType(_)
+            | Instruction::Cp0Type(_)
+            | Instruction::CompactBranchType(_) => (),
         }
     }
 
@@ -178,6 +311,34 @@ fn set_control_signals(&mut self) {
             Instruction::FpuRType(r) => {
                 match r.op {
                     OPCODE_COP1 => match r.function {
+                        // cmp.cond.fmt holds the condition in the function field.
+                        // Its single and double precision formats use the "w" and
+                        // "l" fmts, which are otherwise only used by cvt.s and cvt.d.
+                        condition
+                            if matches!(r.fmt, FMT_WORD | FMT_LONG)
+                                && floating_point::is_cmp_condition(condition) =>
+                        {
+                            self.signals = FpuControlSignals {
+                                cause_write: CauseWrite::YesWrite,
+                                cc_write: CcWrite::NoWrite,
+                                data_src: DataSrc::FloatingPointUnit,
+                                data_write: DataWrite::NoWrite,
+                                fpu_alu_op: FpuAluOp::Compare,
+                                fpu_branch: FpuBranch::NoBranch,
+                                fpu_mem_to_reg: FpuMemToReg::UseDataWrite,
+                                fpu_reg_dst: FpuRegDst::Reg3,
+                                fpu_reg_width: match FpuRegWidth::from_fmt(r.fmt) {
+                                    Ok(width) => width,
+                                    Err(message) => {
+                                        self.error(&message);
+                                        FpuRegWidth::default()
+                                    }
+                                },
+                                fpu_reg_write: FpuRegWrite::YesWrite,
+                                fpu_src_type: FpuSrcType::FloatingPoint,
+                                ..Default::default()
+                            }
+                        }
                         FUNCTION_ADD => {
                             self.signals.cc = Cc::Cc0;
                             self.signals.cc_write = CcWrite::NoWrite;
@@ -195,6 +356,9 @@ fn set_control_signals(&mut self) {
                                 }
                             };
                             self.signals.fpu_reg_write = FpuRegWrite::YesWrite;
+                            self.signals.fpu_src_type = FpuSrcType::FloatingPoint;
+                            self.signals.cause_write = CauseWrite::YesWrite;
+                            self.signals.fcr_write = FcrWrite::NoWrite;
                         }
                         FUNCTION_SUB => {
                             self.signals.cc = Cc::Cc0;
@@ -213,6 +377,9 @@ fn set_control_signals(&mut self) {
                                 }
                             };
                             self.signals.fpu_reg_write = FpuRegWrite::YesWrite;
+                            self.signals.fpu_src_type = FpuSrcType::FloatingPoint;
+                            self.signals.cause_write = CauseWrite::YesWrite;
+                            self.signals.fcr_write = FcrWrite::NoWrite;
                         }
                         FUNCTION_MUL => {
                             self.signals.cc = Cc::Cc0;
@@ -231,6 +398,9 @@ fn set_control_signals(&mut self) {
                                 }
                             };
                             self.signals.fpu_reg_write = FpuRegWrite::YesWrite;
+                            self.signals.fpu_src_type = FpuSrcType::FloatingPoint;
+                            self.signals.cause_write = CauseWrite::YesWrite;
+                            self.signals.fcr_write = FcrWrite::NoWrite;
                         }
                         FUNCTION_DIV => {
                             self.signals.cc = Cc::Cc0;
@@ -249,6 +419,93 @@ fn set_control_signals(&mut self) {
                                 }
                             };
                             self.signals.fpu_reg_write = FpuRegWrite::YesWrite;
+                            self.signals.fpu_src_type = FpuSrcType::FloatingPoint;
+                            self.signals.cause_write = CauseWrite::YesWrite;
+                            self.signals.fcr_write = FcrWrite::NoWrite;
+                        }
+                        FUNCTION_SQRT | FUNCTION_ABS | FUNCTION_MOV | FUNCTION_NEG
+                        | FUNCTION_RECIP | FUNCTION_RSQRT | FUNCTION_MIN | FUNCTION_MAX
+                        | FUNCTION_CVT_S | FUNCTION_CVT_D | FUNCTION_CVT_W | FUNCTION_CVT_L
+                        | FUNCTION_TRUNC_W | FUNCTION_TRUNC_L | FUNCTION_ROUND_W
+                        | FUNCTION_ROUND_L | FUNCTION_CEIL_W | FUNCTION_CEIL_L
+                        | FUNCTION_FLOOR_W | FUNCTION_FLOOR_L => {
+                            self.signals = FpuControlSignals {
+                                // Moving a value and changing its sign are not
+                                // arithmetic, so they never raise exceptions.
+                                cause_write: match r.function {
+                                    FUNCTION_MOV | FUNCTION_ABS | FUNCTION_NEG => {
+                                        CauseWrite::NoWrite
+                                    }
+                                    _ => CauseWrite::YesWrite,
+                                },
+                                cc_write: CcWrite::NoWrite,
+                                data_src: DataSrc::FloatingPointUnit,
+                                data_write: DataWrite::NoWrite,
+                                fpu_alu_op: match FpuAluOp::from_function(r.function) {
+                                    Ok(op) => op,
+                                    Err(message) => {
+                                        self.error(&message);
+                                        FpuAluOp::default()
+                                    }
+                                },
+                                fpu_branch: FpuBranch::NoBranch,
+                                fpu_mem_to_reg: FpuMemToReg::UseDataWrite,
+                                fpu_reg_dst: FpuRegDst::Reg3,
+                                // The width and type of the source operands. The width
+                                // of the result is determined by the operation.
+                                fpu_reg_width: match FpuRegWidth::from_fmt(r.fmt) {
+                                    Ok(width) => width,
+                                    Err(message) => {
+                                        self.error(&message);
+                                        FpuRegWidth::default()
+                                    }
+                                },
+                                fpu_reg_write: FpuRegWrite::YesWrite,
+                                fpu_src_type: FpuSrcType::from_fmt(r.fmt),
+                                ..Default::default()
+                            }
+                        }
+                        FUNCTION_MOVCF | FUNCTION_MOVZ | FUNCTION_MOVN | FUNCTION_SEL
+                        | FUNCTION_SELEQZ | FUNCTION_SELNEZ => {
+                            self.signals = FpuControlSignals {
+                                // Moves and selects are not arithmetic, so they
+                                // never raise exceptions.
+                                cause_write: CauseWrite::NoWrite,
+                                // movf.fmt and movt.fmt hold the condition code in
+                                // the upper three bits of the `ft` field.
+                                cc: match r.function {
+                                    FUNCTION_MOVCF => Cc::from_cc(r.ft >> 2),
+                                    _ => Cc::Cc0,
+                                },
+                                cc_write: CcWrite::NoWrite,
+                                data_src: DataSrc::FloatingPointUnit,
+                                data_write: DataWrite::NoWrite,
+                                fpu_alu_op: match FpuAluOp::from_function(r.function) {
+                                    Ok(op) => op,
+                                    Err(message) => {
+                                        self.error(&message);
+                                        FpuAluOp::default()
+                                    }
+                                },
+                                fpu_branch: FpuBranch::NoBranch,
+                                fpu_mem_to_reg: FpuMemToReg::UseDataWrite,
+                                fpu_reg_dst: FpuRegDst::Reg3,
+                                fpu_reg_width: match FpuRegWidth::from_fmt(r.fmt) {
+                                    Ok(width) => width,
+                                    Err(message) => {
+                                        self.error(&message);
+                                        FpuRegWidth::default()
+                                    }
+                                },
+                                fpu_reg_write: match r.function {
+                                    FUNCTION_MOVCF => FpuRegWrite::IfConditionCode,
+                                    FUNCTION_MOVZ => FpuRegWrite::IfZero,
+                                    FUNCTION_MOVN => FpuRegWrite::IfNotZero,
+                                    _ => FpuRegWrite::YesWrite,
+                                },
+                                fpu_src_type: FpuSrcType::FloatingPoint,
+                                ..Default::default()
+                            }
                         }
                         // Unrecognized format code. Perform no operation.
                         _ => self.error(&format!(
@@ -286,6 +543,28 @@ fn set_control_signals(&mut self) {
                         ..Default::default()
                     }
                 }
+                OPCODE_SDC1 => {
+                    self.signals = FpuControlSignals {
+                        cc_write: CcWrite::NoWrite,
+                        data_write: DataWrite::NoWrite,
+                        fpu_branch: FpuBranch::NoBranch,
+                        fpu_reg_width: FpuRegWidth::DoubleWord,
+                        fpu_reg_write: FpuRegWrite::NoWrite,
+                        ..Default::default()
+                    }
+                }
+                OPCODE_LDC1 => {
+                    self.signals = FpuControlSignals {
+                        cc_write: CcWrite::NoWrite,
+                        data_write: DataWrite::NoWrite,
+                        fpu_branch: FpuBranch::NoBranch,
+                        fpu_mem_to_reg: FpuMemToReg::UseMemory,
+                        fpu_reg_dst: FpuRegDst::Reg1,
+                        fpu_reg_width: FpuRegWidth::DoubleWord,
+                        fpu_reg_write: FpuRegWrite::YesWrite,
+                        ..Default::default()
+                    }
+                }
                 _ => self.error(&format!(
                     "Unsupported opcode `{}` for FPU I-type instruction",
                     i.op
@@ -340,6 +619,29 @@ fn set_control_signals(&mut self) {
                         ..Default::default()
                     }
                 }
+                SUB_CT => {
+                    self.signals = FpuControlSignals {
+                        cc_write: CcWrite::NoWrite,
+                        data_src: DataSrc::MainProcessorUnit,
+                        data_write: DataWrite::YesWrite,
+                        fcr_write: FcrWrite::YesWrite,
+                        fpu_branch: FpuBranch::NoBranch,
+                        fpu_reg_width: FpuRegWidth::Word,
+                        fpu_reg_write: FpuRegWrite::NoWrite,
+                        ..Default::default()
+                    }
+                }
+                SUB_CF => {
+                    self.signals = FpuControlSignals {
+                        cc_write: CcWrite::NoWrite,
+                        data_src: DataSrc::ControlRegister,
+                        data_write: DataWrite::YesWrite,
+                        fpu_branch: FpuBranch::NoBranch,
+                        fpu_reg_width: FpuRegWidth::Word,
+                        fpu_reg_write: FpuRegWrite::NoWrite,
+                        ..Default::default()
+                    }
+                }
                 _ => self.error(&format!(
                     "Unsupported sub code `{}` for FPU register-immediate instruction",
                     i.sub
@@ -347,11 +649,8 @@ fn set_control_signals(&mut self) {
             },
             Instruction::FpuCompareType(c) => {
                 self.signals = FpuControlSignals {
-                    // All floating-point branch instructions are forced to use the same
-                    // one condition code register, regardless of the CC field in the
-                    // instruction. It should be noted that this differs from the
-                    // real-world MIPS specification.
-                    cc: Cc::Cc0,
+                    cause_write: CauseWrite::YesWrite,
+                    cc: Cc::from_cc(c.cc),
                     cc_write: CcWrite::YesWrite,
                     data_write: DataWrite::NoWrite,
                     fpu_alu_op: match FpuAluOp::from_function(c.function) {
@@ -373,14 +672,14 @@ fn set_control_signals(&mut self) {
                     ..Default::default()
                 }
             }
-            Instruction::FpuBranchType(_) => {
+            Instruction::FpuBranchType(b) => {
                 self.signals = FpuControlSignals {
-                    // All floating-point branch instructions are forced to use the same
-                    // one condition code register, regardless of the CC field in the
-                    // instruction. It should be noted that this differs from the
-                    // real-world MIPS specification.
-                    cc: Cc::Cc0,
+                    cc: Cc::from_cc(b.cc),
                     fpu_branch: FpuBranch::YesBranch,
+                    fpu_branch_src: match b.bcc1 {
+                        SUB_BC1EQZ | SUB_BC1NEZ => FpuBranchSrc::Register,
+                        _ => FpuBranchSrc::ConditionCode,
+                    },
                     ..Default::default()
                 }
             }
@@ -388,7 +687,9 @@ fn set_control_signals(&mut self) {
             Instruction::RType(_)
             | Instruction::IType(_)
             | Instruction::JType(_)
-            | Instruction::SyscallType(_) => self.signals = FpuControlSignals::default(),
+            | Instruction::SyscallType(_)
+            | Instruction::Cp0Type(_)
+            | Instruction::CompactBranchType(_) => self.signals = FpuControlSignals::default(),
         }
     }
 
@@ -397,19 +698,23 @@ fn set_control_signals(&mut self) {
     fn read_registers(&mut self) {
         let reg1 = self.state.fs as usize;
         let reg2 = self.state.ft as usize;
+        let reg3 = self.state.fd as usize;
 
         self.state.read_data_1 = self.fpr[reg1];
         self.state.read_data_2 = self.fpr[reg2];
+        self.state.read_data_3 = self.fpr[reg3];
 
         // Truncate the variable data if a 32-bit word is requested.
         if let FpuRegWidth::Word = self.signals.fpu_reg_width {
             self.state.read_data_1 = self.fpr[reg1] as u32 as u64;
             self.state.read_data_2 = self.fpr[reg2] as u32 as u64;
+            self.state.read_data_3 = self.fpr[reg3] as u32 as u64;
         }
     }
 
     // ======================= Execute (EX) =======================
-    /// Perform an ALU operation.
+    /// Perform an ALU operation, recording the IEEE 754 exceptions it raises
+    /// in `self.state.exceptions`.
     fn alu(&mut self) {
         let input1 = self.state.read_data_1;
         let input2 = self.state.read_data_2;
@@ -428,45 +733,176 @@ fn alu(&mut self) {
             input2_f64 = f64::from_bits(input2);
         }
 
-        self.state.alu_result = match self.signals.fpu_alu_op {
+        let mode = self.rounding_mode();
+        let single = |(value, flags): (f32, u32)| (f32::to_bits(value) as u64, flags);
+        let double = |(value, flags): (f64, u32)| (f64::to_bits(value), flags);
+
+        let (result, exceptions) = match self.signals.fpu_alu_op {
             FpuAluOp::Addition => match self.signals.fpu_reg_width {
-                FpuRegWidth::Word => f32::to_bits(input1_f32 + input2_f32) as u64,
-                FpuRegWidth::DoubleWord => f64::to_bits(input1_f64 + input2_f64),
+                FpuRegWidth::Word => single(floating_point::add(input1_f32, input2_f32, mode)),
+                FpuRegWidth::DoubleWord => {
+                    double(floating_point::add(input1_f64, input2_f64, mode))
+                }
             },
             FpuAluOp::Subtraction => match self.signals.fpu_reg_width {
-                FpuRegWidth::Word => f32::to_bits(input1_f32 - input2_f32) as u64,
-                FpuRegWidth::DoubleWord => f64::to_bits(input1_f64 - input2_f64),
+                FpuRegWidth::Word => single(floating_point::sub(input1_f32, input2_f32, mode)),
+                FpuRegWidth::DoubleWord => {
+                    double(floating_point::sub(input1_f64, input2_f64, mode))
+                }
             },
             FpuAluOp::MultiplicationOrEqual => match self.signals.fpu_reg_width {
-                FpuRegWidth::Word => f32::to_bits(input1_f32 * input2_f32) as u64,
-                FpuRegWidth::DoubleWord => f64::to_bits(input1_f64 * input2_f64),
+                FpuRegWidth::Word => single(floating_point::mul(input1_f32, input2_f32, mode)),
+                FpuRegWidth::DoubleWord => {
+                    double(floating_point::mul(input1_f64, input2_f64, mode))
+                }
             },
             FpuAluOp::Division => match self.signals.fpu_reg_width {
-                FpuRegWidth::Word => {
-                    if input2_f32 == 0f32 {
-                        f32::to_bits(0f32) as u64
-                    } else {
-                        f32::to_bits(input1_f32 / input2_f32) as u64
-                    }
-                }
+                FpuRegWidth::Word => single(floating_point::div(input1_f32, input2_f32, mode)),
                 FpuRegWidth::DoubleWord => {
-                    if input2_f64 == 0.0 {
-                        f64::to_bits(0.0)
-                    } else {
-                        f64::to_bits(input1_f64 / input2_f64)
-                    }
+                    double(floating_point::div(input1_f64, input2_f64, mode))
                 }
             },
+            FpuAluOp::SquareRoot => match self.signals.fpu_reg_width {
+                FpuRegWidth::Word => single(floating_point::sqrt(input1_f32, mode)),
+                FpuRegWidth::DoubleWord => double(floating_point::sqrt(input1_f64, mode)),
+            },
+            // The sign bit is changed directly, so NaN inputs keep their payload.
+            FpuAluOp::AbsoluteValue => match self.signals.fpu_reg_width {
+                FpuRegWidth::Word => (input1 & !(1 << 31), 0),
+                FpuRegWidth::DoubleWord => (input1 & !(1 << 63), 0),
+            },
+            FpuAluOp::Move => (input1, 0),
+            FpuAluOp::Negation => match self.signals.fpu_reg_width {
+                FpuRegWidth::Word => (input1 ^ (1 << 31), 0),
+                FpuRegWidth::DoubleWord => (input1 ^ (1 << 63), 0),
+            },
+            FpuAluOp::Reciprocal => match self.signals.fpu_reg_width {
+                FpuRegWidth::Word => single(floating_point::recip(input1_f32, mode)),
+                FpuRegWidth::DoubleWord => double(floating_point::recip(input1_f64, mode)),
+            },
+            FpuAluOp::ReciprocalSquareRoot => match self.signals.fpu_reg_width {
+                FpuRegWidth::Word => single(floating_point::rsqrt(input1_f32, mode)),
+                FpuRegWidth::DoubleWord => double(floating_point::rsqrt(input1_f64, mode)),
+            },
+            // If only one input is a NaN, the other input is selected.
+            FpuAluOp::Minimum => match self.signals.fpu_reg_width {
+                FpuRegWidth::Word => single((input1_f32.min(input2_f32), 0)),
+                FpuRegWidth::DoubleWord => double((input1_f64.min(input2_f64), 0)),
+            },
+            FpuAluOp::Maximum => match self.signals.fpu_reg_width {
+                FpuRegWidth::Word => single((input1_f32.max(input2_f32), 0)),
+                FpuRegWidth::DoubleWord => double((input1_f64.max(input2_f64), 0)),
+            },
+            FpuAluOp::ConvertToSingle => match self.signals.fpu_src_type {
+                FpuSrcType::FloatingPoint => single(floating_point::to_single(input1_f64, mode)),
+                FpuSrcType::FixedPoint => single(floating_point::from_integer(
+                    self.fixed_point_input(input1),
+                    mode,
+                )),
+            },
+            FpuAluOp::ConvertToDouble => match self.signals.fpu_src_type {
+                // Single-precision values are always exactly representable as doubles.
+                FpuSrcType::FloatingPoint => double((input1_f32 as f64, 0)),
+                FpuSrcType::FixedPoint => double(floating_point::from_integer(
+                    self.fixed_point_input(input1),
+                    mode,
+                )),
+            },
+            FpuAluOp::ConvertToWord
+            | FpuAluOp::TruncateToWord
+            | FpuAluOp::RoundToWord
+            | FpuAluOp::CeilingToWord
+            | FpuAluOp::FloorToWord => {
+                let input = self.float_input(input1_f32, input1_f64);
+                let (value, flags) = floating_point::to_integer(
+                    input,
+                    self.conversion_rounding_mode(),
+                    i32::MIN as i64,
+                    i32::MAX as i64,
+                );
+                (value as i32 as u32 as u64, flags)
+            }
+            FpuAluOp::ConvertToLong
+            | FpuAluOp::TruncateToLong
+            | FpuAluOp::RoundToLong
+            | FpuAluOp::CeilingToLong
+            | FpuAluOp::FloorToLong => {
+                let input = self.float_input(input1_f32, input1_f64);
+                let (value, flags) = floating_point::to_integer(
+                    input,
+                    self.conversion_rounding_mode(),
+                    i64::MIN,
+                    i64::MAX,
+                );
+                (value as u64, flags)
+            }
+            FpuAluOp::Compare => {
+                let condition = self.state.function as u8;
+                let (holds, flags) = match self.signals.fpu_reg_width {
+                    FpuRegWidth::Word => floating_point::compare(input1_f32, input2_f32, condition),
+                    FpuRegWidth::DoubleWord => {
+                        floating_point::compare(input1_f64, input2_f64, condition)
+                    }
+                };
+                (if holds { u64::MAX } else { 0 }, flags)
+            }
+            // The values are selected as-is, so NaN inputs keep their payload.
+            FpuAluOp::Select => match self.state.read_data_3 & 1 {
+                0 => (input1, 0),
+                _ => (input2, 0),
+            },
+            FpuAluOp::SelectIfZero => match input2 & 1 {
+                0 => (input1, 0),
+                _ => (0, 0),
+            },
+            FpuAluOp::SelectIfNotZero => match input2 & 1 {
+                0 => (0, 0),
+                _ => (input1, 0),
+            },
             // No operation.
-            FpuAluOp::Slt | FpuAluOp::Snge | FpuAluOp::Sle | FpuAluOp::Sngt => 0,
+            FpuAluOp::Slt | FpuAluOp::Snge | FpuAluOp::Sle | FpuAluOp::Sngt => (0, 0),
             _ => {
                 self.error(&format!(
                     "Unsupported operation in FPU `{:?}`",
                     self.signals.fpu_alu_op
                 ));
-                0
+                (0, 0)
             }
         };
+
+        self.state.alu_result = result;
+        self.state.exceptions = exceptions;
+    }
+
+    /// Interpret the first ALU input as a signed fixed-point value of the width
+    /// given by the [`FpuRegWidth`] control signal.
+    fn fixed_point_input(&self, input: u64) -> i64 {
+        match self.signals.fpu_reg_width {
+            FpuRegWidth::Word => input as u32 as i32 as i64,
+            FpuRegWidth::DoubleWord => input as i64,
+        }
+    }
+
+    /// Get the first ALU input as a double, of the precision given by the
+    /// [`FpuRegWidth`] control signal. Single-precision values are always
+    /// exactly representable as doubles.
+    fn float_input(&self, input_f32: f32, input_f64: f64) -> f64 {
+        match self.signals.fpu_reg_width {
+            FpuRegWidth::Word => input_f32 as f64,
+            FpuRegWidth::DoubleWord => input_f64,
+        }
+    }
+
+    /// Get the rounding mode used by the current conversion to a fixed-point
+    /// value. Only `cvt.w.fmt` and `cvt.l.fmt` use the rounding mode in the FCSR.
+    fn conversion_rounding_mode(&self) -> RoundingMode {
+        match self.signals.fpu_alu_op {
+            FpuAluOp::TruncateToWord | FpuAluOp::TruncateToLong => RoundingMode::Zero,
+            FpuAluOp::RoundToWord | FpuAluOp::RoundToLong => RoundingMode::Nearest,
+            FpuAluOp::CeilingToWord | FpuAluOp::CeilingToLong => RoundingMode::PositiveInfinity,
+            FpuAluOp::FloorToWord | FpuAluOp::FloorToLong => RoundingMode::NegativeInfinity,
+            _ => self.rounding_mode(),
+        }
     }
 
     /// Perform a comparison.
@@ -500,7 +936,34 @@ fn comparator(&mut self) {
                 FpuRegWidth::Word => !input1_f32.ge(&input2_f32) as u64,
                 FpuRegWidth::DoubleWord => !input1_f64.ge(&input2_f64) as u64,
             },
-            FpuAluOp::Addition | FpuAluOp::Subtraction | FpuAluOp::Division => 0, // No operation
+            // No operation.
+            FpuAluOp::Addition
+            | FpuAluOp::Subtraction
+            | FpuAluOp::Division
+            | FpuAluOp::SquareRoot
+            | FpuAluOp::AbsoluteValue
+            | FpuAluOp::Move
+            | FpuAluOp::Negation
+            | FpuAluOp::Reciprocal
+            | FpuAluOp::ReciprocalSquareRoot
+            | FpuAluOp::Minimum
+            | FpuAluOp::Maximum
+            | FpuAluOp::ConvertToSingle
+            | FpuAluOp::ConvertToDouble
+            | FpuAluOp::ConvertToWord
+            | FpuAluOp::ConvertToLong
+            | FpuAluOp::TruncateToWord
+            | FpuAluOp::TruncateToLong
+            | FpuAluOp::RoundToWord
+            | FpuAluOp::RoundToLong
+            | FpuAluOp::CeilingToWord
+            | FpuAluOp::CeilingToLong
+            | FpuAluOp::FloorToWord
+            | FpuAluOp::FloorToLong
+            | FpuAluOp::Compare
+            | FpuAluOp::Select
+            | FpuAluOp::SelectIfZero
+            | FpuAluOp::SelectIfNotZero => 0,
             _ => {
                 self.error(&format!(
                     "Unsupported operation in comparator `{:?}`",
@@ -511,6 +974,30 @@ fn comparator(&mut self) {
         }
     }
 
+    /// Record an invalid operation for a comparison with a NaN input. Only
+    /// `c.eq.fmt` compares NaN inputs quietly.
+    fn check_unordered_comparison(&mut self) {
+        let unordered = match self.signals.fpu_reg_width {
+            FpuRegWidth::Word => {
+                f32::from_bits(self.state.read_data_1 as u32).is_nan()
+                    || f32::from_bits(self.state.read_data_2 as u32).is_nan()
+            }
+            FpuRegWidth::DoubleWord => {
+                f64::from_bits(self.state.read_data_1).is_nan()
+                    || f64::from_bits(self.state.read_data_2).is_nan()
+            }
+        };
+
+        if unordered
+            && matches!(
+                self.signals.fpu_alu_op,
+                FpuAluOp::Slt | FpuAluOp::Sle | FpuAluOp::Sngt | FpuAluOp::Snge
+            )
+        {
+            self.state.exceptions |= FLAG_INVALID;
+        }
+    }
+
     /// Write to the `Data` register. This register is used to transfer data between
     /// the main processor and the coprocessor.
     fn write_data(&mut self) {
@@ -521,13 +1008,44 @@ fn write_data(&mut self) {
         self.data = match self.signals.data_src {
             DataSrc::FloatingPointUnit => self.state.read_data_1,
             DataSrc::MainProcessorUnit => self.state.data_from_main_processor,
+            DataSrc::ControlRegister => self.read_control_register(self.state.fs as u8) as u64,
         };
     }
 
+    /// Write the IEEE 754 exceptions raised by the ALU or comparator to the
+    /// `Cause` field of the FCSR. If any of them are enabled, the instruction
+    /// traps. Otherwise, they are added to the sticky `Flags` field.
+    fn write_cause(&mut self) {
+        self.state.trap = false;
+        if let CauseWrite::NoWrite = self.signals.cause_write {
+            return;
+        }
+
+        let cause = self.state.exceptions;
+        let enables = (self.fcsr >> FCSR_ENABLES_SHIFT) & FCSR_EXCEPTIONS_MASK;
+        self.fcsr = (self.fcsr & !(0x3F << FCSR_CAUSE_SHIFT)) | (cause << FCSR_CAUSE_SHIFT);
+
+        if cause & enables != 0 {
+            self.state.trap = true;
+        } else {
+            self.fcsr |= cause << FCSR_FLAGS_SHIFT;
+        }
+    }
+
     /// Set the condition code (CC) register based on the result from the comparator.
+    /// The condition code is left as-is if the comparison traps.
     fn write_condition_code(&mut self) {
+        if self.state.trap {
+            return;
+        }
+
         if let CcWrite::YesWrite = self.signals.cc_write {
-            self.condition_code = self.state.comparator_result;
+            let bit = 1 << self.signals.cc as u64;
+            if self.state.comparator_result == 1 {
+                self.condition_code |= bit;
+            } else {
+                self.condition_code &= !bit;
+            }
         }
     }
 
@@ -538,13 +1056,15 @@ fn write_fp_register_to_memory(&mut self) {
     }
 
     // ======================= Memory (MEM) =======================
-    /// Set the data line that goes out of the condition code register file.
+    /// Set the data line that goes out of the condition code register file. For
+    /// `bc1eqz` and `bc1nez`, this is instead the lowest bit of register `ft`.
+    ///
+    /// Along with the FPU branches, `movf.fmt` and `movt.fmt` use this line to
+    /// decide whether to write to a register.
     fn set_condition_code_line(&mut self) {
-        // The MIPS architecture supports more than one condition code, but SWIM
-        // manually uses only one. This stubs the possible use of more than one
-        // for future development.
-        let selected_register_data = match self.signals.cc {
-            Cc::Cc0 => self.condition_code,
+        let selected_register_data = match self.signals.fpu_branch_src {
+            FpuBranchSrc::ConditionCode => self.condition_code >> self.signals.cc as u64,
+            FpuBranchSrc::Register => self.state.read_data_2,
         };
 
         // This only considers one bit of the selected condition code register.
@@ -595,7 +1115,20 @@ fn set_fpu_branch(&mut self) {
     // ====================== Writeback (WB) ======================
     /// Write data to the floating-point register file.
     fn register_write(&mut self) {
-        if let FpuRegWrite::NoWrite = self.signals.fpu_reg_write {
+        if let FcrWrite::YesWrite = self.signals.fcr_write {
+            self.write_control_register(self.state.fs as u8, self.data as u32);
+        }
+
+        // Conditional moves only write to the register file if their
+        // condition holds.
+        let condition_is_true = match self.signals.fpu_reg_write {
+            FpuRegWrite::NoWrite => false,
+            FpuRegWrite::YesWrite => true,
+            FpuRegWrite::IfZero => self.state.data_from_main_processor == 0,
+            FpuRegWrite::IfNotZero => self.state.data_from_main_processor != 0,
+            FpuRegWrite::IfConditionCode => self.state.condition_code_mux == 1,
+        };
+        if !condition_is_true {
             return;
         }
 
@@ -3,6 +3,11 @@
 //! It is assumed that while moving through stages, only one
 //! instruction will be active any any given point in time. Due to this,
 //! we consider the datapath to be a "pseudo-single-cycle datapath."
+//! For a datapath with several instructions in flight at once, see
+//! [`MipsPipelinedDatapath`](super::pipelined_datapath::MipsPipelinedDatapath).
+//! For one where instructions take a different number of clock cycles
+//! depending on their class, see
+//! [`MipsMultiCycleDatapath`](super::multi_cycle_datapath::MipsMultiCycleDatapath).
 //!
 //! For the most part, this datapath is an implementation of MIPS64 Version 6.
 //! (See below for exceptions.)
@@ -12,46 +17,83 @@
 //! It should be noted that this datapath chooses to diverge from the MIPS64
 //! version 6 specification for the sake of simplicity in a few places:
 //!
-//! - There is no exception handling, including that for integer overflow. (See
-//!   [`MipsDatapath::alu()`] and the following bullet.)
-//! - The `add`, `addi`, `dadd`, `daddi`, `sub`, and `dsub` instructions do not
-//!   follow the proper MIPS specification in terms of integer overflow/wraparound.
-//!   That is, if there is integer wraparound, the general-purpose register should
-//!   not be written to. In our implementation, the general-purpose register is
-//!   written to regardless.
-//! - 32-bit instructions are treated exclusively with 32 bits, and the upper 32
-//!   bits stored in a register are completely ignored in any of these cases. For
-//!   example, before an `add` instruction, it should be checked whether it is a
-//!   sign-extended 32-bit value stored in a 64-bit register. Instead, the upper
-//!   32 bits are ignored when being used for 32-bit instructions.
-//! - Instead of implementing the `cmp.condn.fmt` instructions, this datapath implements
-//!   the `c.cond.fmt` instructions from MIPS64 version 5.
-//! - Unlike MIPS specification, SWIM only uses 1 condition code register (`cc`), rather
-//!   than offering 8 condition code registers. The datapath will assume that the `cc`
-//!   field in a floating-point comparison or floating-point branch instruction is 0.
-//! - This datapath implements the `addi` instruction as it exists in MIPS64 version 5.
-//!   This instruction was deprecated in MIPS64 version 6 to allow for the `beqzalc`,
-//!   `bnezalc`, `beqc`, and `bovc` instructions.
-//! - This datapath implements `daddi` as it exists in MIPS64 version 5. This instruction
-//!   was deprecated in MIPS64 version 6.
-//! - Unlike the MIPS64 version 6 specification for the `jal` and `jalr` instructions,
-//!   `PC + 4` is stored in `GPR[31]`, *not* `PC + 8`, as there is no implementation of
-//!   branch delay slots.
+//! - Only a subset of exceptions are raised. (See [`ExceptionCode`].) There are
+//!   no interrupts, and rather than vectoring to a fixed address, an exception
+//!   vectors to [`MipsSystemCoprocessor::exception_handler`]. If no handler is
+//!   set, the exception halts the datapath instead.
+//! - By default, 32-bit instructions are treated exclusively with 32 bits, and the
+//!   upper 32 bits stored in a register are completely ignored in any of these
+//!   cases. For example, before an `add` instruction, it should be checked whether
+//!   it is a sign-extended 32-bit value stored in a 64-bit register. Instead, the
+//!   upper 32 bits are ignored when being used for 32-bit instructions. `lw` also
+//!   zero-extends the word it loads. With [`MipsDatapath::strict_32_bit`] set, `lw`
+//!   sign-extends instead, and a 32-bit instruction reading a register that does not
+//!   hold a sign-extended 32-bit value, whose result MIPS64 leaves UNPREDICTABLE, is
+//!   recorded in [`MipsDatapath::unpredictable_operands`].
+//! - In either mode, an instruction that raises an exception, such as an `add` that
+//!   overflows, does not write its destination register.
+//! - Alongside the `cmp.cond.fmt`, `bc1eqz`, and `bc1nez` instructions, this datapath
+//!   keeps the `c.cond.fmt`, `bc1t`, and `bc1f` instructions from MIPS64 version 5,
+//!   along with the 8 condition codes they use.
+//! - Alongside the `seleqz`, `selnez`, `sel.fmt`, `seleqz.fmt`, and `selnez.fmt`
+//!   instructions, this datapath keeps the conditional moves `movz`, `movn`, `movf`,
+//!   `movt`, `movz.fmt`, `movn.fmt`, `movf.fmt`, and `movt.fmt` from MIPS64 version 5.
+//! - By default, this datapath implements the `addi` instruction as it exists in MIPS64
+//!   version 5. This instruction was deprecated in MIPS64 version 6 to allow for the
+//!   `beqzalc`, `bnezalc`, `beqc`, and `bovc` instructions.
+//! - By default, this datapath implements `daddi` as it exists in MIPS64 version 5. This
+//!   instruction was deprecated in MIPS64 version 6 to allow for the `bnezalc`, `bnec`,
+//!   and `bnvc` instructions.
+//! - By default, there are no branch delay slots. Unlike the MIPS64 version 6
+//!   specification for the `jal` and `jalr` instructions, `PC + 4` is stored in
+//!   `GPR[31]`, *not* `PC + 8`. Delay slots can be enabled with
+//!   [`MipsDatapath::branch_delay_slots`], in which case `PC + 8` is stored.
+//! - This datapath implements `bltzal` and `bgezal` as they exist in MIPS64 version 5.
+//!   Like `jal`, these store the return address in `GPR[31]`.
+//! - As `addi` and `daddi` are kept by default, their opcodes cannot be shared with the
+//!   compact branches `beqc`, `bnec`, `beqzalc`, `bnezalc`, `bovc`, and `bnvc`. These
+//!   instructions are only supported with [`MipsDatapath::release_6`] set, which retires
+//!   `addi` and `daddi` instead. The other compact branches that compare two registers
+//!   (`bltc`, `bgec`) are always supported, along with `beqzc`, `bnezc`, `bc`, and `balc`.
 //!
 //! # Notes on `is_halted`
 //!
 //! - The datapath starts with the `is_halted` flag set.
 //! - [`MipsDatapath::initialize()`] should be used to un-set `is_halted`.
-//! - The `syscall` instruction simply performs a no-operation instruction, except for
-//!   setting the boolean flag `is_halted`.
-//! - Invalid instructions will cause the datapath to set the `is_halted` flag.
-
-use super::super::datapath::Datapath;
+//! - The `syscall` instruction performs the service selected by `$v0`. (See
+//!   [`SyscallService`].) The `exit` and `exit2` services set `is_halted`.
+//! - An exception sets `is_halted` if no exception handler is set. This
+//!   includes the system call exception raised for an unsupported service,
+//!   which keeps the `syscall` that the assembler places at the end of every
+//!   program acting as a halt.
+//! - Invalid instructions raise a reserved instruction exception, described
+//!   by [`MipsSystemCoprocessor::exception_message`].
+//! - An irrecoverable error, such as a system call with no input available,
+//!   sets `is_halted` and keeps its message in
+//!   [`MipsDatapath::error_message`].
+
+use super::super::datapath::{Datapath, MemoryAccess};
+use super::branch_predictor::BranchPredictor;
+use super::cache::{AccessKind, CacheHierarchy};
 use super::constants::*;
 use super::control_signals::{floating_point::*, *};
+use super::datapath_model::{syscall_writable_memory, MipsDatapathModel};
 use super::datapath_signals::*;
 use super::instruction::*;
-use super::{coprocessor::MipsFpCoprocessor, memory::Memory, registers::GpRegisters};
+use super::performance::{CycleCostModel, InstructionClass, PerformanceCounters};
+use super::registers::GpRegisterType;
+use super::syscall::{SyscallHost, SyscallHostHandle, SyscallService};
+use super::system_coprocessor::{ExceptionCode, MipsSystemCoprocessor};
+use super::{
+    coprocessor::MipsFpCoprocessor,
+    memory::{Endianness, Memory, MemoryFault, MemoryLayout},
+    registers::GpRegisters,
+};
+use std::cell::RefCell;
+use std::fmt;
+use std::ops::Range;
+use std::rc::Rc;
+use strum::IntoEnumIterator;
 
 /// An implementation of a datapath for the MIPS64 ISA.
 #[derive(Clone, PartialEq)]
@@ -59,6 +101,7 @@ pub struct MipsDatapath {
     pub registers: GpRegisters,
     pub memory: Memory,
     pub coprocessor: MipsFpCoprocessor,
+    pub system_coprocessor: MipsSystemCoprocessor,
 
     pub instruction: Instruction,
     pub signals: ControlSignals,
@@ -68,11 +111,86 @@ pub struct MipsDatapath {
     /// The currently-active stage in the datapath.
     pub current_stage: Stage,
 
+    /// The address of the first byte past the heap. Memory is allocated
+    /// from here by the `sbrk` system call.
+    pub heap_pointer: u64,
+
+    /// The exit code given to the `exit2` system call, if it was used to
+    /// halt the program.
+    pub exit_code: Option<u64>,
+
+    /// Whether branches and jumps have a delay slot, as in classic MIPS.
+    ///
+    /// If set, the instruction following a branch or jump is executed
+    /// before the branch or jump takes effect, and linking instructions
+    /// store `PC + 8` in `$ra`, skipping over the delay slot. Compact
+    /// branches never have a delay slot. This setting is kept when the
+    /// datapath is reset or initialized.
+    pub branch_delay_slots: bool,
+
+    /// The address to continue from once the instruction in the current
+    /// delay slot has finished. This is only set while executing the
+    /// delay slot of a taken branch or jump.
+    pub delay_slot_target: Option<u64>,
+
+    /// The caches in front of memory, if any. Instruction fetches, loads,
+    /// and stores are recorded with the caches.
+    ///
+    /// The caches are kept when the datapath is reset or initialized, but
+    /// their contents, statistics, and logs are cleared.
+    pub cache: Option<CacheHierarchy>,
+
+    /// The branch predictor attached to the datapath, if any. The outcome of
+    /// each branch and jump is checked against its prediction in the MEM
+    /// stage, once the datapath has decided whether it is taken.
+    ///
+    /// Like the caches, the branch predictor is kept when the datapath is
+    /// reset or initialized, but its history and statistics are cleared.
+    pub branch_predictor: Option<BranchPredictor>,
+
+    /// Counts of the instructions finished, by class, and of the stages run.
+    pub performance: PerformanceCounters,
+
+    /// The number of cycles each class of instruction is taken to need when
+    /// working out the CPI from [`Self::performance`]. This is kept when the
+    /// datapath is reset or initialized.
+    pub cost_model: CycleCostModel,
+
+    /// Whether 32-bit instructions follow MIPS64 strictly. If set, `lw`
+    /// sign-extends the word it loads, and 32-bit instructions reading a
+    /// register that does not hold a sign-extended 32-bit value are recorded
+    /// in [`Self::unpredictable_operands`]. This setting is kept when the
+    /// datapath is reset or initialized.
+    pub strict_32_bit: bool,
+
+    /// Whether the opcodes of `addi` and `daddi` are used for the MIPS64
+    /// version 6 compact branches `bovc`, `beqzalc`, and `beqc`, and `bnvc`,
+    /// `bnezalc`, and `bnec`, respectively. This retires `addi` and `daddi`.
+    /// This setting is kept when the datapath is reset or initialized.
+    pub release_6: bool,
+
+    /// The registers read by 32-bit instructions that did not hold a
+    /// sign-extended 32-bit value, in strict mode. Each instruction is
+    /// recorded once per register, with the first value it read.
+    pub unpredictable_operands: Vec<UnpredictableOperand>,
+
+    /// The most recent instruction fetch, load, or store that memory
+    /// refused, if it was made by the instruction currently in the datapath
+    /// or the datapath halted because of it.
+    pub memory_fault: Option<MemoryFault>,
+
+    /// The message given by the irrecoverable error that halted the
+    /// datapath, if any.
+    pub error_message: Option<String>,
+
+    /// The host that system calls perform input and output with.
+    syscall_host: SyscallHostHandle,
+
     /// Boolean value that states whether the datapath has halted.
     ///
-    /// This is set in the event of any `syscall` instruction. To unset this,
-    /// [`Self::initialize()`] should be used.
-    is_halted: bool,
+    /// This is set by the `exit` and `exit2` system calls, among other
+    /// events. To unset this, [`Self::initialize()`] should be used.
+    pub(super) is_halted: bool,
 }
 
 /// A collection of all the data lines and wires in the datapath.
@@ -158,10 +276,43 @@ pub struct DatapathState {
 
     /// *Data line.* The data that will be written to memory.
     pub write_data: u64,
+
+    /// *Data line.* In strict mode, the first register read by a 32-bit
+    /// instruction that does not hold a sign-extended 32-bit value, along
+    /// with the value it holds.
+    pub unpredictable_operand: Option<(u32, u64)>,
+}
+
+/// A register read by a 32-bit instruction that did not hold a sign-extended
+/// 32-bit value. MIPS64 leaves the result of such an instruction
+/// UNPREDICTABLE.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UnpredictableOperand {
+    /// The address of the instruction.
+    pub pc: u64,
+
+    /// The register that was read.
+    pub register: u32,
+
+    /// The value the register held.
+    pub value: u64,
+}
+
+impl fmt::Display for UnpredictableOperand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let register = GpRegisterType::iter()
+            .find(|register| *register as i32 == self.register as i32)
+            .unwrap_or(GpRegisterType::Zero);
+        write!(
+            f,
+            "${register} held {:#x}, which is not a sign-extended 32-bit value",
+            self.value
+        )
+    }
 }
 
 /// The possible stages the datapath could be in during execution.
-#[derive(Clone, Copy, Default, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum Stage {
     #[default]
     InstructionFetch,
@@ -191,17 +342,32 @@ fn default() -> Self {
             registers: GpRegisters::default(),
             memory: Memory::default(),
             coprocessor: MipsFpCoprocessor::default(),
+            system_coprocessor: MipsSystemCoprocessor::default(),
             instruction: Instruction::default(),
             signals: ControlSignals::default(),
             datapath_signals: DatapathSignals::default(),
             state: DatapathState::default(),
             current_stage: Stage::default(),
+            heap_pointer: 0,
+            exit_code: None,
+            branch_delay_slots: false,
+            delay_slot_target: None,
+            cache: None,
+            branch_predictor: None,
+            performance: PerformanceCounters::default(),
+            cost_model: CycleCostModel::default(),
+            strict_32_bit: false,
+            release_6: false,
+            unpredictable_operands: Vec::new(),
+            memory_fault: None,
+            error_message: None,
+            syscall_host: SyscallHostHandle::default(),
             is_halted: true,
         };
 
         // Set the stack pointer ($sp) to initially start at the end
         // of memory.
-        datapath.registers.gpr[29] = super::memory::CAPACITY_BYTES as u64;
+        datapath.registers.gpr[29] = datapath.memory.layout.stack_pointer();
 
         datapath
     }
@@ -244,7 +410,8 @@ fn execute_stage(&mut self) {
         }
 
         // If the FPU has halted, reflect this in the main unit.
-        if self.coprocessor.is_halted {
+        if self.coprocessor.is_halted && !self.is_halted {
+            self.error_message = self.coprocessor.error_message.clone();
             self.is_halted = true;
         }
 
@@ -263,8 +430,93 @@ fn is_halted(&self) -> bool {
         self.is_halted
     }
 
+    fn next_fetch_address(&self) -> Option<u64> {
+        (self.current_stage == Stage::InstructionFetch).then_some(self.registers.pc)
+    }
+
+    fn finished_instruction(&self) -> bool {
+        self.current_stage == Stage::InstructionFetch
+    }
+
+    /// Loads and stores access memory in the MEM stage, unless they have
+    /// raised an exception.
+    fn last_memory_access(&self) -> Option<MemoryAccess<u64>> {
+        if self.current_stage != Stage::WriteBack || self.system_coprocessor.exception.is_some() {
+            return None;
+        }
+
+        self.signals.memory_access(self.state.alu_result)
+    }
+
     fn reset(&mut self) {
-        std::mem::take(self);
+        // The system call host, the delay slot, strict, and release 6 mode
+        // settings, the caches, the branch predictor, the cost model, the
+        // memory layout and byte order, the memory-mapped devices, and
+        // whether self-modifying code is allowed belong to the environment
+        // around the datapath, so they are kept across resets.
+        let mut cache = self.cache.take();
+        if let Some(cache) = &mut cache {
+            cache.reset();
+        }
+
+        let mut branch_predictor = self.branch_predictor.take();
+        if let Some(branch_predictor) = &mut branch_predictor {
+            branch_predictor.reset();
+        }
+
+        self.memory.reset_devices();
+        let memory = Memory {
+            devices: std::mem::take(&mut self.memory.devices),
+            endianness: self.memory.endianness,
+            self_modifying_code: self.memory.self_modifying_code,
+            ..Memory::new(self.memory.layout)
+        };
+
+        *self = Self {
+            memory,
+            syscall_host: self.syscall_host.clone(),
+            branch_delay_slots: self.branch_delay_slots,
+            strict_32_bit: self.strict_32_bit,
+            release_6: self.release_6,
+            cache,
+            branch_predictor,
+            cost_model: self.cost_model.clone(),
+            ..Default::default()
+        };
+
+        // Set up the stack pointer ($sp) and global pointer ($gp) for the
+        // memory layout.
+        self.registers.gpr[28] = self.memory.layout.global_pointer();
+        self.registers.gpr[29] = self.memory.layout.stack_pointer();
+    }
+}
+
+impl MipsDatapathModel for MipsDatapath {
+    fn mips_datapath(&self) -> &MipsDatapath {
+        self
+    }
+
+    fn mips_datapath_mut(&mut self) -> &mut MipsDatapath {
+        self
+    }
+
+    /// System calls are performed in the ID stage, and stores write at most
+    /// a doubleword in the MEM stage, starting at the address given by the
+    /// ALU.
+    fn writable_memory(&self) -> Range<u64> {
+        match self.current_stage {
+            Stage::InstructionDecode => syscall_writable_memory(
+                self.state.instruction,
+                self.registers[GpRegisterType::V0],
+                self.registers[GpRegisterType::A0],
+                self.registers[GpRegisterType::A1],
+            ),
+            Stage::Memory if self.signals.mem_write == MemWrite::YesWrite => {
+                let start = self.state.alu_result;
+                start..start.saturating_add(8)
+            }
+            _ => 0..0,
+        }
     }
 }
 
@@ -272,35 +524,161 @@ impl MipsDatapath {
     // ===================== General Functions =====================
     /// Reset the datapath, load instructions into memory, and un-sets the `is_halted`
     /// flag. If the process fails, an [`Err`] is returned.
+    ///
+    /// The instructions are placed at the start of the text segment, and the
+    /// PC is set to point to the first of them. As they may be followed by
+    /// data, memory is not split into regions, and may be accessed in any
+    /// way. Use [`Self::initialize_program()`] to protect the instructions.
     pub fn initialize(&mut self, instructions: Vec<u32>) -> Result<(), String> {
+        self.load_program(instructions, Vec::new())
+    }
+
+    /// Reset the datapath, load the text and data of a program into memory
+    /// where the memory layout places them, and un-set the `is_halted` flag.
+    /// If the process fails, an [`Err`] is returned.
+    ///
+    /// In the flat layout, data follows straight after the text. Memory is
+    /// then split into regions, so that the text cannot be written to and
+    /// the data cannot be executed. (See [`Memory::protect_program()`].)
+    pub fn initialize_program(&mut self, text: Vec<u32>, data: Vec<u32>) -> Result<(), String> {
+        let text_end = self.memory.layout.text_address() + text.len() as u64 * 4;
+        self.load_program(text, data)?;
+        self.memory.protect_program(text_end);
+
+        Ok(())
+    }
+
+    /// Reset the datapath, load the text and data of a program into memory,
+    /// and un-set the `is_halted` flag, leaving memory unprotected.
+    fn load_program(&mut self, text: Vec<u32>, data: Vec<u32>) -> Result<(), String> {
         self.reset();
-        self.load_instructions(instructions)?;
+
+        let layout = self.memory.layout;
+        let text_address = layout.text_address();
+        let data_address = layout.data_address(text.len() as u64 * 4);
+        let data_end = data_address + data.len() as u64 * 4;
+
+        self.load_words(text_address, text)?;
+        self.load_words(data_address, data)?;
+        self.heap_pointer = layout.heap_address(data_end);
+        self.registers.pc = text_address;
         self.is_halted = false;
 
         Ok(())
     }
 
-    /// Load a vector of 32-bit instructions into memory. If the process fails,
-    /// from a lack of space or otherwise, an [`Err`] is returned.
-    fn load_instructions(&mut self, instructions: Vec<u32>) -> Result<(), String> {
-        for (i, data) in instructions.iter().enumerate() {
-            self.memory.store_word((i as u64) * 4, *data)?
+    /// Load a vector of 32-bit words into memory, starting at `address`. If
+    /// the process fails, from a lack of space or otherwise, an [`Err`] is
+    /// returned.
+    fn load_words(&mut self, address: u64, words: Vec<u32>) -> Result<(), String> {
+        for (i, data) in words.iter().enumerate() {
+            self.memory.store_word(address + (i as u64) * 4, *data)?
         }
 
         Ok(())
     }
 
+    /// Change the way memory is laid out, then reset the datapath.
+    ///
+    /// Like the caches, the memory layout is kept when the datapath is reset
+    /// or initialized.
+    pub fn set_memory_layout(&mut self, layout: MemoryLayout) {
+        self.memory.layout = layout;
+        self.reset();
+    }
+
+    /// Change the byte order of memory, then reset the datapath. Programs
+    /// should be assembled for the same byte order.
+    ///
+    /// Like the memory layout, the byte order is kept when the datapath is
+    /// reset or initialized.
+    pub fn set_endianness(&mut self, endianness: Endianness) {
+        self.memory.endianness = endianness;
+        self.reset();
+    }
+
+    /// Set the host that system calls perform input and output with.
+    ///
+    /// The host is kept when the datapath is reset or initialized.
+    pub fn set_syscall_host(&mut self, host: Rc<RefCell<dyn SyscallHost>>) {
+        self.syscall_host = SyscallHostHandle(host);
+    }
+
     /// Handle an otherwise irrecoverable error within the datapath.
-    pub fn error(&mut self, _message: &str) {
+    pub fn error(&mut self, message: &str) {
+        self.error_message = Some(message.to_string());
         self.is_halted = true;
     }
 
+    /// Raise an exception for the current instruction. `bad_vaddr` should be
+    /// provided for address errors.
+    ///
+    /// The instruction will not write to any registers or memory after this
+    /// point, and execution then continues from the exception handler. If no
+    /// exception handler is set, the datapath halts instead.
+    ///
+    /// An exception raised in a delay slot is recorded against the branch or
+    /// jump before it, so that returning from the exception repeats the branch.
+    pub fn raise_exception(&mut self, code: ExceptionCode, bad_vaddr: Option<u64>) {
+        let in_delay_slot = self.delay_slot_target.is_some();
+        let pc = if in_delay_slot {
+            self.registers.pc.wrapping_sub(4)
+        } else {
+            self.registers.pc
+        };
+
+        self.system_coprocessor
+            .raise_exception(code, pc, in_delay_slot, bad_vaddr);
+
+        if self.system_coprocessor.exception_handler.is_none() {
+            self.is_halted = true;
+        }
+    }
+
+    /// Handle an access to memory that was refused with `message`, by
+    /// recording the fault and raising an exception. Accesses refused by the
+    /// permissions of memory raise a TLB exception, and any others an
+    /// address error.
+    fn memory_fault(&mut self, address: u64, size: u64, kind: AccessKind, message: String) {
+        let denied = address % size == 0 && !self.memory.is_permitted(address, size, kind);
+        let code = match (kind, denied) {
+            (AccessKind::InstructionFetch, true) => ExceptionCode::TlbExecuteInhibit,
+            (AccessKind::Read, true) => ExceptionCode::TlbReadInhibit,
+            (AccessKind::Write, true) => ExceptionCode::TlbModified,
+            (AccessKind::Write, false) => ExceptionCode::AddressErrorStore,
+            (_, false) => ExceptionCode::AddressErrorLoad,
+        };
+
+        self.memory_fault = Some(MemoryFault {
+            pc: self.registers.pc,
+            address,
+            kind,
+            message,
+        });
+        self.raise_exception(code, Some(address));
+    }
+
+    /// Handle an instruction that is not supported by the datapath by raising
+    /// a reserved instruction exception, described by `message`.
+    fn reserved_instruction(&mut self, message: &str) {
+        if self.system_coprocessor.exception.is_none() {
+            self.system_coprocessor.exception_message = Some(message.to_string());
+        }
+        self.raise_exception(ExceptionCode::ReservedInstruction, None);
+    }
+
     // ========================== Stages ==========================
     /// Stage 1 of 5: Instruction Fetch (IF)
     ///
     /// Fetch the current instruction based on the given PC and load it
     /// into the datapath.
-    fn stage_instruction_fetch(&mut self) {
+    pub(super) fn stage_instruction_fetch(&mut self) {
+        // Any exception belongs to the previous instruction.
+        self.system_coprocessor.exception = None;
+        self.system_coprocessor.exception_message = None;
+        self.memory_fault = None;
+        self.performance.record_stage(Stage::InstructionFetch);
+
         self.instruction_fetch();
 
         // Upper part of datapath, PC calculation
@@ -313,9 +691,11 @@ fn stage_instruction_fetch(&mut self) {
     ///
     /// Parse the instruction, set control signals, and read registers.
     ///
-    /// If the instruction is determined to be a `syscall`, immediately
-    /// finish the instruction and set the `is_halted` flag.
-    fn stage_instruction_decode(&mut self) {
+    /// If the instruction is determined to be a `syscall`, the requested
+    /// service is performed immediately. Likewise, a `break` raises its
+    /// exception immediately.
+    pub(super) fn stage_instruction_decode(&mut self) {
+        self.performance.record_stage(Stage::InstructionDecode);
         self.instruction_decode();
         self.sign_extend();
         self.set_control_signals();
@@ -330,32 +710,52 @@ fn stage_instruction_decode(&mut self) {
         self.coprocessor
             .set_data_from_main_processor(self.state.read_data_2);
 
-        // Finish this instruction out of the datapath and halt if this is a syscall.
-        if let Instruction::SyscallType(_) = self.instruction {
-            self.is_halted = true;
+        // A branch or jump cannot be placed in a delay slot.
+        if self.delay_slot_target.is_some() && self.is_branch_or_jump() {
+            self.reserved_instruction("branch or jump in a delay slot");
+        }
+
+        if self.system_coprocessor.exception.is_none() {
+            if let Instruction::SyscallType(s) = self.instruction {
+                match s.funct {
+                    FUNCT_BREAK => self.raise_exception(ExceptionCode::Breakpoint, None),
+                    _ => self.syscall(),
+                }
+            }
         }
     }
 
     /// Stage 3 of 5: Execute (EX)
     ///
     /// Execute the current instruction with some arithmetic operation.
-    fn stage_execute(&mut self) {
+    pub(super) fn stage_execute(&mut self) {
+        self.performance.record_stage(Stage::Execute);
         self.alu();
         self.calc_relative_pc_branch();
         self.calc_cpu_branch_signal();
+        self.calc_cond_move_signal();
         self.coprocessor.stage_execute();
+
+        if self.coprocessor.state.trap {
+            self.raise_exception(ExceptionCode::FloatingPoint, None);
+        }
     }
 
     /// Stage 4 of 5: Memory (MEM)
     ///
-    /// Read or write to memory.
-    fn stage_memory(&mut self) {
-        if let MemRead::YesRead = self.signals.mem_read {
-            self.memory_read();
-        }
+    /// Read or write to memory. Memory is not accessed if the instruction
+    /// has raised an exception.
+    pub(super) fn stage_memory(&mut self) {
+        self.performance.record_stage(Stage::Memory);
+
+        if self.system_coprocessor.exception.is_none() {
+            if let MemRead::YesRead = self.signals.mem_read {
+                self.memory_read();
+            }
 
-        if let MemWrite::YesWrite = self.signals.mem_write {
-            self.memory_write();
+            if let MemWrite::YesWrite = self.signals.mem_write {
+                self.memory_write();
+            }
         }
 
         // Determine what data will be sent to the registers: either
@@ -364,6 +764,7 @@ fn stage_memory(&mut self) {
             MemToReg::UseAlu => self.state.alu_result,
             MemToReg::UseMemory => self.state.memory_data,
             MemToReg::UsePcPlusFour => self.state.pc_plus_4,
+            MemToReg::UsePcPlusEight => self.state.pc_plus_4 + 4,
         };
 
         self.coprocessor.stage_memory();
@@ -372,29 +773,49 @@ fn stage_memory(&mut self) {
         self.calc_general_branch_signal();
         self.pick_pc_plus_4_or_relative_branch_addr_mux1();
         self.set_new_pc_mux2();
+        self.check_branch_prediction();
     }
 
     /// Stage 5 of 5: Writeback (WB)
     ///
     /// Write the result of the instruction's operation to a register,
     /// if desired. Additionally, set the PC for the next instruction.
-    fn stage_writeback(&mut self) {
+    ///
+    /// Registers are not written to if the instruction has raised an
+    /// exception. Instead, the PC is set to the exception handler.
+    pub(super) fn stage_writeback(&mut self) {
+        self.performance.record_stage(Stage::WriteBack);
+        self.performance
+            .record_instruction(self.instruction_class());
+
         self.coprocessor
             .set_fp_register_data_from_main_processor(self.state.data_result);
         self.register_write();
+        self.system_coprocessor_access();
+
+        if self.system_coprocessor.exception.is_none() {
+            self.record_unpredictable_operand();
+        }
         self.set_pc();
-        self.coprocessor.stage_writeback();
+
+        if self.system_coprocessor.exception.is_none() {
+            self.coprocessor.stage_writeback();
+        }
     }
 
     // ================== Instruction Fetch (IF) ==================
     /// Load the raw binary instruction from memory and into the
     /// datapath. If there is an error with loading the word, assume
-    /// the instruction to be bitwise zero and error.
+    /// the instruction to be bitwise zero and raise an exception.
     fn instruction_fetch(&mut self) {
-        self.state.instruction = match self.memory.load_word(self.registers.pc) {
-            Ok(data) => data,
-            Err(e) => {
-                self.error(e.as_str());
+        let pc = self.registers.pc;
+        self.state.instruction = match self.memory.fetch_word(pc) {
+            Ok(data) => {
+                self.access_cache(pc, AccessKind::InstructionFetch);
+                data
+            }
+            Err(message) => {
+                self.memory_fault(pc, 4, AccessKind::InstructionFetch, message);
                 0
             }
         }
@@ -410,7 +831,7 @@ fn instruction_decode(&mut self) {
         match Instruction::try_from(self.state.instruction) {
             Ok(instruction) => self.instruction = instruction,
             Err(message) => {
-                self.error(&message);
+                self.reserved_instruction(&message);
                 return;
             }
         }
@@ -447,15 +868,20 @@ fn instruction_decode(&mut self) {
                 self.state.shamt = 0;
                 self.state.imm = 0;
             }
+            // `movz.fmt` and `movn.fmt` test the general-purpose register
+            // in the `ft` field, which is passed to the FPU. Otherwise,
             // R-type and comparison FPU instructions exclusively use the
             // FPU, so these data lines do not need to be used.
-            Instruction::FpuRType(_) | Instruction::FpuCompareType(_) => (),
+            Instruction::FpuRType(r) => self.state.rt = r.ft as u32,
+            Instruction::FpuCompareType(_) => (),
             Instruction::FpuIType(i) => {
                 self.state.rs = i.base as u32;
                 self.state.imm = i.offset as u32;
             }
             Instruction::JType(i) => {
                 self.state.lower_26 = i.addr;
+                // Compact branches (`bc`, `balc`) use this field as an offset.
+                self.state.imm = i.addr;
             }
             Instruction::FpuBranchType(b) => {
                 self.state.imm = b.offset as u32;
@@ -465,13 +891,39 @@ fn instruction_decode(&mut self) {
                 self.state.rd = 0; // Not applicable
                 self.state.shamt = 0; // Not applicable
             }
+            Instruction::Cp0Type(c) => {
+                self.state.rt = c.rt as u32;
+                self.state.rd = c.rd as u32;
+                self.state.funct = c.function as u32;
+                // Not applicable:
+                self.state.rs = 0;
+                self.state.shamt = 0;
+                self.state.imm = 0;
+            }
+            Instruction::CompactBranchType(b) => {
+                self.state.rs = b.rs as u32;
+                self.state.imm = b.offset;
+                // Not applicable:
+                self.state.rt = 0;
+                self.state.rd = 0;
+                self.state.shamt = 0;
+            }
         }
     }
 
-    /// Extend the sign of a 16-bit value to the other 48 bits of a
-    /// 64-bit value.
+    /// Extend the sign of the immediate value to the rest of a 64-bit
+    /// value. This is a 16-bit value for most instructions, but the
+    /// compact branches use 21-bit (`beqzc`, `bnezc`) and 26-bit (`bc`,
+    /// `balc`) offsets.
     fn sign_extend(&mut self) {
-        self.state.sign_extend = ((self.state.imm as i16) as i64) as u64;
+        let width = match self.instruction {
+            Instruction::JType(j) if matches!(j.op, OPCODE_BC | OPCODE_BALC) => 26,
+            Instruction::CompactBranchType(_) => 21,
+            _ => 16,
+        };
+        let shift = 32 - width;
+
+        self.state.sign_extend = (((self.state.imm << shift) as i32 >> shift) as i64) as u64;
     }
 
     /// Set the control signals for the datapath based on the
@@ -494,7 +946,8 @@ fn set_control_signals(&mut self) {
             Instruction::FpuRType(_)
             | Instruction::FpuCompareType(_)
             | Instruction::SyscallType(_)
-            | Instruction::FpuBranchType(_) => {
+            | Instruction::FpuBranchType(_)
+            | Instruction::Cp0Type(_) => {
                 self.signals = ControlSignals {
                     branch: Branch::NoBranch,
                     jump: Jump::NoJump,
@@ -507,9 +960,70 @@ fn set_control_signals(&mut self) {
             Instruction::FpuIType(i) => {
                 self.set_fpu_itype_control_signals(i);
             }
+            Instruction::CompactBranchType(b) => {
+                self.set_compact_branch_control_signals(b);
+            }
+        }
+
+        // With delay slots, linking skips over the instruction in the
+        // delay slot, as it has already been executed.
+        if self.branch_delay_slots
+            && self.signals.mem_to_reg == MemToReg::UsePcPlusFour
+            && self.has_delay_slot()
+        {
+            self.signals.mem_to_reg = MemToReg::UsePcPlusEight;
+        }
+    }
+
+    /// Determine whether the current instruction is a branch or jump,
+    /// including compact branches and floating-point branches.
+    pub(super) fn is_branch_or_jump(&self) -> bool {
+        self.signals.branch == Branch::YesBranch
+            || self.signals.jump != Jump::NoJump
+            || matches!(self.instruction, Instruction::FpuBranchType(_))
+    }
+
+    /// Determine the class the current instruction is counted in by the
+    /// performance counters.
+    fn instruction_class(&self) -> InstructionClass {
+        if self.signals.mem_read == MemRead::YesRead {
+            InstructionClass::Load
+        } else if self.signals.mem_write == MemWrite::YesWrite {
+            InstructionClass::Store
+        } else if self.signals.jump != Jump::NoJump {
+            InstructionClass::Jump
+        } else if self.is_branch_or_jump() {
+            match self.datapath_signals.general_branch {
+                GeneralBranch::YesBranch => InstructionClass::BranchTaken,
+                GeneralBranch::NoBranch => InstructionClass::BranchNotTaken,
+            }
+        } else {
+            match self.instruction {
+                Instruction::FpuRType(_)
+                | Instruction::FpuRegImmType(_)
+                | Instruction::FpuCompareType(_) => InstructionClass::Fpu,
+                Instruction::SyscallType(_) | Instruction::Cp0Type(_) => InstructionClass::System,
+                _ => InstructionClass::Alu,
+            }
         }
     }
 
+    /// Determine whether the current instruction is a branch or jump that
+    /// has a delay slot. Compact branches do not have a delay slot.
+    fn has_delay_slot(&self) -> bool {
+        let is_compact_branch = match self.instruction {
+            Instruction::IType(i) => {
+                matches!(i.op, OPCODE_POP26 | OPCODE_POP27)
+                    || (self.release_6 && matches!(i.op, OPCODE_POP10 | OPCODE_POP30))
+            }
+            Instruction::JType(j) => matches!(j.op, OPCODE_BC | OPCODE_BALC),
+            Instruction::CompactBranchType(_) => true,
+            _ => false,
+        };
+
+        self.is_branch_or_jump() && !is_compact_branch
+    }
+
     /// Set the control signals for the datapath, specifically in the
     /// case where the instruction is an R-type.
     fn set_rtype_control_signals(&mut self, r: RType) {
@@ -528,6 +1042,24 @@ fn set_rtype_control_signals(&mut self, r: RType) {
                         ..Default::default()
                     }
                 }
+                FUNCT_MOVZ | FUNCT_MOVN | FUNCT_MOVCI => {
+                    self.signals = ControlSignals {
+                        alu_op: AluOp::UseFunctField,
+                        alu_src: AluSrc::ReadRegister2,
+                        branch: Branch::NoBranch,
+                        jump: Jump::NoJump,
+                        mem_read: MemRead::NoRead,
+                        mem_to_reg: MemToReg::UseAlu,
+                        mem_write: MemWrite::NoWrite,
+                        reg_dst: RegDst::Reg3,
+                        reg_write: match r.funct {
+                            FUNCT_MOVZ => RegWrite::IfZero,
+                            FUNCT_MOVN => RegWrite::IfNotZero,
+                            _ => RegWrite::IfConditionCode,
+                        },
+                        ..Default::default()
+                    }
+                }
                 _ => {
                     self.signals = ControlSignals {
                         alu_op: AluOp::UseFunctField,
@@ -545,7 +1077,7 @@ fn set_rtype_control_signals(&mut self, r: RType) {
                     }
                 }
             },
-            _ => self.error(&format!("R-type instruction with opcode `{}`", r.op)),
+            _ => self.reserved_instruction(&format!("R-type instruction with opcode `{}`", r.op)),
         }
 
         // The RegWidth signal might differ depending on the
@@ -553,7 +1085,7 @@ fn set_rtype_control_signals(&mut self, r: RType) {
         self.signals.reg_width = match reg_width_by_funct(r.funct) {
             Some(width) => width,
             None => {
-                self.error(&format!(
+                self.reserved_instruction(&format!(
                     "funct code `{}` is unsupported for this opcode ({})",
                     r.funct, r.op
                 ));
@@ -566,6 +1098,41 @@ fn set_rtype_control_signals(&mut self, r: RType) {
     /// case where the instruction is an I-type.
     fn set_itype_control_signals(&mut self, i: IType) {
         match i.op {
+            // Compact branches sharing their opcodes with `addi` and `daddi`,
+            // which they replace in release 6 mode. The order of the `rs`
+            // and `rt` fields selects the instruction: `bovc` and `bnvc` if
+            // `rs` is not less than `rt`, or else `beqzalc` and `bnezalc` if
+            // `rs` is 0, or else `beqc` and `bnec`.
+            OPCODE_POP10 | OPCODE_POP30 if self.release_6 => {
+                let branch_type = match (i.op, i.rs >= i.rt) {
+                    (OPCODE_POP10, true) => BranchType::OnOverflow,
+                    (_, true) => BranchType::OnNoOverflow,
+                    (OPCODE_POP10, false) => BranchType::OnEqual,
+                    (_, false) => BranchType::OnNotEqual,
+                };
+                let link = i.rs == 0 && i.rt != 0;
+
+                // The "and link" variants always store the return address,
+                // whether or not the branch is taken.
+                self.signals = ControlSignals {
+                    alu_op: AluOp::Subtraction,
+                    alu_src: AluSrc::ReadRegister2,
+                    branch: Branch::YesBranch,
+                    branch_type,
+                    jump: Jump::NoJump,
+                    mem_read: MemRead::NoRead,
+                    mem_to_reg: MemToReg::UsePcPlusFour,
+                    mem_write: MemWrite::NoWrite,
+                    reg_dst: RegDst::ReturnRegister,
+                    reg_width: RegWidth::DoubleWord,
+                    reg_write: match link {
+                        true => RegWrite::YesWrite,
+                        false => RegWrite::NoWrite,
+                    },
+                    ..Default::default()
+                }
+            }
+
             // Register-immediate instructions are further defined
             // by the "rt" field.
             OPCODE_REGIMM => match i.rt {
@@ -601,14 +1168,42 @@ fn set_itype_control_signals(&mut self, i: IType) {
                         ..Default::default()
                     }
                 }
-                _ => self.error(&format!(
+                // Branches comparing `rs` with zero. The "and link" variants
+                // always store the return address, whether or not the branch
+                // is taken.
+                RMSUB_BLTZ | RMSUB_BGEZ | RMSUB_BLTZAL | RMSUB_BGEZAL => {
+                    self.signals = ControlSignals {
+                        alu_op: AluOp::Subtraction,
+                        alu_src: AluSrc::ReadRegister2,
+                        branch: Branch::YesBranch,
+                        branch_type: match i.rt {
+                            RMSUB_BLTZ | RMSUB_BLTZAL => BranchType::OnLessThanZero,
+                            _ => BranchType::OnGreaterThanOrEqualZero,
+                        },
+                        jump: Jump::NoJump,
+                        mem_read: MemRead::NoRead,
+                        mem_to_reg: MemToReg::UsePcPlusFour,
+                        mem_write: MemWrite::NoWrite,
+                        reg_dst: RegDst::ReturnRegister,
+                        reg_width: RegWidth::DoubleWord,
+                        reg_write: match i.rt {
+                            RMSUB_BLTZAL | RMSUB_BGEZAL => RegWrite::YesWrite,
+                            _ => RegWrite::NoWrite,
+                        },
+                        ..Default::default()
+                    }
+                }
+                _ => self.reserved_instruction(&format!(
                     "rt field value `{}` for I-type opcode {}",
                     i.rt, i.op
                 )),
             },
 
-            OPCODE_ORI => {
-                self.signals.alu_op = AluOp::Or;
+            OPCODE_ORI | OPCODE_XORI => {
+                self.signals.alu_op = match i.op {
+                    OPCODE_XORI => AluOp::Xor,
+                    _ => AluOp::Or,
+                };
                 self.signals.alu_src = AluSrc::ZeroExtendedImmediate;
                 self.signals.branch = Branch::NoBranch;
                 self.signals.imm_shift = ImmShift::Shift0;
@@ -637,22 +1232,36 @@ fn set_itype_control_signals(&mut self, i: IType) {
                 self.signals.reg_write = RegWrite::YesWrite;
             }
 
-            OPCODE_LW => {
+            OPCODE_LB | OPCODE_LBU | OPCODE_LH | OPCODE_LHU | OPCODE_LW | OPCODE_LD => {
                 self.signals.alu_op = AluOp::Addition;
                 self.signals.alu_src = AluSrc::SignExtendedImmediate; // may  be fishy
                 self.signals.branch = Branch::NoBranch;
                 self.signals.imm_shift = ImmShift::Shift0;
                 self.signals.jump = Jump::NoJump;
+                self.signals.mem_extend = match i.op {
+                    OPCODE_LB | OPCODE_LH => MemExtend::SignExtend,
+                    OPCODE_LW if self.strict_32_bit => MemExtend::SignExtend,
+                    _ => MemExtend::ZeroExtend,
+                };
                 self.signals.mem_read = MemRead::YesRead;
                 self.signals.mem_to_reg = MemToReg::UseMemory;
+                self.signals.mem_width = match i.op {
+                    OPCODE_LB | OPCODE_LBU => MemWidth::Byte,
+                    OPCODE_LH | OPCODE_LHU => MemWidth::HalfWord,
+                    OPCODE_LD => MemWidth::DoubleWord,
+                    _ => MemWidth::Word,
+                };
                 self.signals.mem_write = MemWrite::NoWrite;
                 self.signals.mem_write_src = MemWriteSrc::PrimaryUnit;
                 self.signals.reg_dst = RegDst::Reg2;
-                self.signals.reg_width = RegWidth::Word;
+                self.signals.reg_width = match i.op {
+                    OPCODE_LD => RegWidth::DoubleWord,
+                    _ => RegWidth::Word,
+                };
                 self.signals.reg_write = RegWrite::YesWrite;
             }
 
-            OPCODE_SW => {
+            OPCODE_SB | OPCODE_SH | OPCODE_SW | OPCODE_SD => {
                 self.signals.alu_op = AluOp::Addition;
                 self.signals.alu_src = AluSrc::SignExtendedImmediate; // may  be fishy
                 self.signals.branch = Branch::NoBranch;
@@ -660,10 +1269,19 @@ fn set_itype_control_signals(&mut self, i: IType) {
                 self.signals.jump = Jump::NoJump;
                 self.signals.mem_read = MemRead::NoRead;
                 self.signals.mem_to_reg = MemToReg::UseMemory; // don't care
+                self.signals.mem_width = match i.op {
+                    OPCODE_SB => MemWidth::Byte,
+                    OPCODE_SH => MemWidth::HalfWord,
+                    OPCODE_SD => MemWidth::DoubleWord,
+                    _ => MemWidth::Word,
+                };
                 self.signals.mem_write = MemWrite::YesWrite;
                 self.signals.mem_write_src = MemWriteSrc::PrimaryUnit;
                 self.signals.reg_dst = RegDst::Reg2;
-                self.signals.reg_width = RegWidth::Word;
+                self.signals.reg_width = match i.op {
+                    OPCODE_SD => RegWidth::DoubleWord,
+                    _ => RegWidth::Word,
+                };
                 self.signals.reg_write = RegWrite::NoWrite;
             }
 
@@ -774,7 +1392,66 @@ fn set_itype_control_signals(&mut self, i: IType) {
                 self.signals.reg_write = RegWrite::NoWrite;
             }
 
-            _ => self.error(&format!("I-type instruction with opcode `{}`", i.op)),
+            // Branches comparing `rs` with zero. With a non-zero `rt`
+            // field, these opcodes refer to the MIPS64 version 6 compact
+            // branches `blezalc`, `bgezalc`, `bgtzalc`, and `bltzalc`,
+            // which are not supported.
+            OPCODE_BLEZ | OPCODE_BGTZ => {
+                if i.rt != 0 {
+                    self.reserved_instruction(&format!(
+                        "rt field value `{}` for I-type opcode {}",
+                        i.rt, i.op
+                    ));
+                    return;
+                }
+
+                self.signals = ControlSignals {
+                    alu_op: AluOp::Subtraction,
+                    alu_src: AluSrc::ReadRegister2,
+                    branch: Branch::YesBranch,
+                    branch_type: match i.op {
+                        OPCODE_BLEZ => BranchType::OnLessThanOrEqualZero,
+                        _ => BranchType::OnGreaterThanZero,
+                    },
+                    jump: Jump::NoJump,
+                    mem_read: MemRead::NoRead,
+                    mem_write: MemWrite::NoWrite,
+                    reg_width: RegWidth::DoubleWord,
+                    reg_write: RegWrite::NoWrite,
+                    ..Default::default()
+                }
+            }
+
+            // Compact branches comparing two registers (`bgec`, `bltc`).
+            // Other combinations of `rs` and `rt` encode compact branches
+            // comparing with zero, which are not supported.
+            OPCODE_POP26 | OPCODE_POP27 => {
+                if i.rs == 0 || i.rt == 0 || i.rs == i.rt {
+                    self.reserved_instruction(&format!(
+                        "rs field value `{}` and rt field value `{}` for I-type opcode {}",
+                        i.rs, i.rt, i.op
+                    ));
+                    return;
+                }
+
+                self.signals = ControlSignals {
+                    alu_op: AluOp::Subtraction,
+                    alu_src: AluSrc::ReadRegister2,
+                    branch: Branch::YesBranch,
+                    branch_type: match i.op {
+                        OPCODE_POP26 => BranchType::OnGreaterThanOrEqual,
+                        _ => BranchType::OnLessThan,
+                    },
+                    jump: Jump::NoJump,
+                    mem_read: MemRead::NoRead,
+                    mem_write: MemWrite::NoWrite,
+                    reg_width: RegWidth::DoubleWord,
+                    reg_write: RegWrite::NoWrite,
+                    ..Default::default()
+                }
+            }
+
+            _ => self.reserved_instruction(&format!("I-type instruction with opcode `{}`", i.op)),
         }
     }
 
@@ -809,7 +1486,70 @@ fn set_jtype_control_signals(&mut self, j: JType) {
                 self.signals.reg_width = RegWidth::DoubleWord;
                 self.signals.reg_write = RegWrite::YesWrite;
             }
-            _ => self.error(&format!("J-type instruction with opcode `{}`", j.op)),
+            OPCODE_BC => {
+                self.signals.alu_op = AluOp::Addition;
+                self.signals.alu_src = AluSrc::ReadRegister2;
+                self.signals.branch = Branch::YesBranch;
+                self.signals.branch_type = BranchType::Always;
+                self.signals.imm_shift = ImmShift::Shift0;
+                self.signals.jump = Jump::NoJump;
+                self.signals.mem_read = MemRead::NoRead;
+                self.signals.mem_to_reg = MemToReg::UseAlu;
+                self.signals.mem_write = MemWrite::NoWrite;
+                self.signals.mem_write_src = MemWriteSrc::PrimaryUnit;
+                self.signals.reg_dst = RegDst::Reg2;
+                self.signals.reg_width = RegWidth::DoubleWord;
+                self.signals.reg_write = RegWrite::NoWrite;
+            }
+            OPCODE_BALC => {
+                self.signals.alu_op = AluOp::Addition;
+                self.signals.alu_src = AluSrc::ReadRegister2;
+                self.signals.branch = Branch::YesBranch;
+                self.signals.branch_type = BranchType::Always;
+                self.signals.imm_shift = ImmShift::Shift0;
+                self.signals.jump = Jump::NoJump;
+                self.signals.mem_read = MemRead::NoRead;
+                self.signals.mem_to_reg = MemToReg::UsePcPlusFour;
+                self.signals.mem_write = MemWrite::NoWrite;
+                self.signals.mem_write_src = MemWriteSrc::PrimaryUnit;
+                self.signals.reg_dst = RegDst::ReturnRegister;
+                self.signals.reg_width = RegWidth::DoubleWord;
+                self.signals.reg_write = RegWrite::YesWrite;
+            }
+            _ => self.reserved_instruction(&format!("J-type instruction with opcode `{}`", j.op)),
+        };
+    }
+
+    /// Set the control signals for the datapath, specifically in the
+    /// case where the instruction is a compact branch comparing with
+    /// zero (`beqzc` or `bnezc`).
+    ///
+    /// As `rt` is not part of these instructions, the ALU compares `rs`
+    /// against register `$zero`.
+    fn set_compact_branch_control_signals(&mut self, b: CompactBranchType) {
+        let branch_type = match b.op {
+            OPCODE_POP66 => BranchType::OnEqual,
+            OPCODE_POP76 => BranchType::OnNotEqual,
+            _ => {
+                self.reserved_instruction(&format!(
+                    "compact branch instruction with opcode `{}`",
+                    b.op
+                ));
+                return;
+            }
+        };
+
+        self.signals = ControlSignals {
+            alu_op: AluOp::Subtraction,
+            alu_src: AluSrc::ReadRegister2,
+            branch: Branch::YesBranch,
+            branch_type,
+            jump: Jump::NoJump,
+            mem_read: MemRead::NoRead,
+            mem_write: MemWrite::NoWrite,
+            reg_width: RegWidth::DoubleWord,
+            reg_write: RegWrite::NoWrite,
+            ..Default::default()
         };
     }
 
@@ -817,7 +1557,7 @@ fn set_jtype_control_signals(&mut self, j: JType) {
     /// case where the instruction is an FPU register-immediate type.
     fn set_fpu_reg_imm_control_signals(&mut self, i: FpuRegImmType) {
         match i.sub {
-            SUB_MT => {
+            SUB_MT | SUB_CT => {
                 self.signals = ControlSignals {
                     branch: Branch::NoBranch,
                     jump: Jump::NoJump,
@@ -839,7 +1579,7 @@ fn set_fpu_reg_imm_control_signals(&mut self, i: FpuRegImmType) {
                     ..Default::default()
                 }
             }
-            SUB_MF => {
+            SUB_MF | SUB_CF => {
                 self.signals = ControlSignals {
                     branch: Branch::NoBranch,
                     jump: Jump::NoJump,
@@ -863,7 +1603,7 @@ fn set_fpu_reg_imm_control_signals(&mut self, i: FpuRegImmType) {
                     ..Default::default()
                 }
             }
-            _ => self.error(&format!(
+            _ => self.reserved_instruction(&format!(
                 "FPU register-immediate instruction with sub code `{}`",
                 i.sub
             )),
@@ -882,6 +1622,7 @@ fn set_fpu_itype_control_signals(&mut self, i: FpuIType) {
                     imm_shift: ImmShift::Shift0,
                     jump: Jump::NoJump,
                     mem_read: MemRead::NoRead,
+                    mem_width: MemWidth::Word,
                     mem_write: MemWrite::YesWrite,
                     mem_write_src: MemWriteSrc::FloatingPointUnit,
                     reg_width: RegWidth::Word,
@@ -896,15 +1637,51 @@ fn set_fpu_itype_control_signals(&mut self, i: FpuIType) {
                     branch: Branch::NoBranch,
                     imm_shift: ImmShift::Shift0,
                     jump: Jump::NoJump,
+                    mem_extend: MemExtend::ZeroExtend,
                     mem_read: MemRead::YesRead,
                     mem_to_reg: MemToReg::UseMemory,
+                    mem_width: MemWidth::Word,
                     mem_write: MemWrite::NoWrite,
                     reg_width: RegWidth::Word,
                     reg_write: RegWrite::NoWrite,
                     ..Default::default()
                 }
             }
-            _ => self.error(&format!("FPU I-type instruction with opcode `{}`", i.op)),
+            OPCODE_SDC1 => {
+                self.signals = ControlSignals {
+                    alu_op: AluOp::Addition,
+                    alu_src: AluSrc::SignExtendedImmediate,
+                    branch: Branch::NoBranch,
+                    imm_shift: ImmShift::Shift0,
+                    jump: Jump::NoJump,
+                    mem_read: MemRead::NoRead,
+                    mem_width: MemWidth::DoubleWord,
+                    mem_write: MemWrite::YesWrite,
+                    mem_write_src: MemWriteSrc::FloatingPointUnit,
+                    reg_width: RegWidth::DoubleWord,
+                    reg_write: RegWrite::NoWrite,
+                    ..Default::default()
+                }
+            }
+            OPCODE_LDC1 => {
+                self.signals = ControlSignals {
+                    alu_op: AluOp::Addition,
+                    alu_src: AluSrc::SignExtendedImmediate,
+                    branch: Branch::NoBranch,
+                    imm_shift: ImmShift::Shift0,
+                    jump: Jump::NoJump,
+                    mem_read: MemRead::YesRead,
+                    mem_to_reg: MemToReg::UseMemory,
+                    mem_width: MemWidth::DoubleWord,
+                    mem_write: MemWrite::NoWrite,
+                    reg_width: RegWidth::DoubleWord,
+                    reg_write: RegWrite::NoWrite,
+                    ..Default::default()
+                }
+            }
+            _ => {
+                self.reserved_instruction(&format!("FPU I-type instruction with opcode `{}`", i.op))
+            }
         }
     }
 
@@ -913,6 +1690,7 @@ fn set_fpu_itype_control_signals(&mut self, i: FpuIType) {
     fn read_registers(&mut self) {
         self.state.read_data_1 = self.registers.gpr[self.state.rs as usize];
         self.state.read_data_2 = self.registers.gpr[self.state.rt as usize];
+        self.check_word_operands(self.state.read_data_1, self.state.read_data_2);
 
         // Truncate the variable data if a 32-bit word is requested.
         if let RegWidth::Word = self.signals.reg_width {
@@ -921,8 +1699,61 @@ fn read_registers(&mut self) {
         }
     }
 
+    /// In strict mode, check that the registers a 32-bit instruction reads
+    /// hold sign-extended 32-bit values, given the contents of `rs` and `rt`.
+    /// The first register that does not is kept on the
+    /// [`DatapathState::unpredictable_operand`] line.
+    pub(super) fn check_word_operands(&mut self, rs_value: u64, rt_value: u64) {
+        // Shifts only require their shifted value, in `rt`, to be a word.
+        let (check_rs, check_rt) = match self.instruction {
+            Instruction::RType(r) => match r.funct {
+                FUNCT_ADD | FUNCT_ADDU | FUNCT_SUB | FUNCT_SOP30 | FUNCT_SOP31 | FUNCT_SOP32
+                | FUNCT_SOP33 => (true, true),
+                FUNCT_SRL | FUNCT_SRA | FUNCT_SRLV | FUNCT_SRAV => (false, true),
+                _ => (false, false),
+            },
+            Instruction::IType(i) => (
+                i.op == OPCODE_ADDIU || (i.op == OPCODE_ADDI && !self.release_6),
+                false,
+            ),
+            _ => (false, false),
+        };
+
+        let is_word = |value: u64| value as i32 as u64 == value;
+        self.state.unpredictable_operand = if !self.strict_32_bit {
+            None
+        } else if check_rs && !is_word(rs_value) {
+            Some((self.state.rs, rs_value))
+        } else if check_rt && !is_word(rt_value) {
+            Some((self.state.rt, rt_value))
+        } else {
+            None
+        };
+    }
+
+    /// Record the operand on the [`DatapathState::unpredictable_operand`]
+    /// line, if any, in [`Self::unpredictable_operands`].
+    fn record_unpredictable_operand(&mut self) {
+        let Some((register, value)) = self.state.unpredictable_operand else {
+            return;
+        };
+
+        let pc = self.registers.pc;
+        if !self
+            .unpredictable_operands
+            .iter()
+            .any(|operand| operand.pc == pc && operand.register == register)
+        {
+            self.unpredictable_operands.push(UnpredictableOperand {
+                pc,
+                register,
+                value,
+            });
+        }
+    }
+
     /// Set the ALU control signal based on the [`AluOp`] signal.
-    fn set_alu_control(&mut self) {
+    pub(super) fn set_alu_control(&mut self) {
         self.signals.alu_control = match self.signals.alu_op {
             AluOp::Addition => AluControl::Addition,
             AluOp::Subtraction => AluControl::Subtraction,
@@ -931,51 +1762,87 @@ fn set_alu_control(&mut self) {
             AluOp::And => AluControl::And,
             AluOp::Or => AluControl::Or,
             AluOp::LeftShift16 => AluControl::LeftShift16,
+            AluOp::Xor => AluControl::Xor,
             AluOp::UseFunctField => {
                 match self.state.funct as u8 {
-                    // In the future if/when interrupts are implemented, unsigned adds should be
-                    // dealt with differently
                     FUNCT_ADD | FUNCT_ADDU | FUNCT_DADD | FUNCT_DADDU => AluControl::Addition,
                     FUNCT_SUB | FUNCT_DSUB | FUNCT_DSUBU => AluControl::Subtraction,
                     FUNCT_AND => AluControl::And,
                     FUNCT_OR => AluControl::Or,
-                    FUNCT_SLL => AluControl::ShiftLeftLogical(self.state.shamt),
+                    FUNCT_XOR => AluControl::Xor,
+                    FUNCT_NOR => AluControl::Nor,
+                    FUNCT_SLL | FUNCT_DSLL => AluControl::ShiftLeftLogical(self.state.shamt),
+                    FUNCT_SRL | FUNCT_DSRL => AluControl::ShiftRightLogical(self.state.shamt),
+                    FUNCT_SRA | FUNCT_DSRA => AluControl::ShiftRightArithmetic(self.state.shamt),
+                    FUNCT_DSLL32 => AluControl::ShiftLeftLogical(self.state.shamt + 32),
+                    FUNCT_DSRL32 => AluControl::ShiftRightLogical(self.state.shamt + 32),
+                    FUNCT_DSRA32 => AluControl::ShiftRightArithmetic(self.state.shamt + 32),
+                    // Variable shifts take the shift amount from the low bits of `rs`.
+                    FUNCT_SLLV => {
+                        AluControl::ShiftLeftLogical(self.state.read_data_1 as u32 & 0x1F)
+                    }
+                    FUNCT_SRLV => {
+                        AluControl::ShiftRightLogical(self.state.read_data_1 as u32 & 0x1F)
+                    }
+                    FUNCT_SRAV => {
+                        AluControl::ShiftRightArithmetic(self.state.read_data_1 as u32 & 0x1F)
+                    }
+                    FUNCT_DSLLV => {
+                        AluControl::ShiftLeftLogical(self.state.read_data_1 as u32 & 0x3F)
+                    }
+                    FUNCT_DSRLV => {
+                        AluControl::ShiftRightLogical(self.state.read_data_1 as u32 & 0x3F)
+                    }
+                    FUNCT_DSRAV => {
+                        AluControl::ShiftRightArithmetic(self.state.read_data_1 as u32 & 0x3F)
+                    }
                     FUNCT_SLT => AluControl::SetOnLessThanSigned,
                     FUNCT_SLTU => AluControl::SetOnLessThanUnsigned,
+                    FUNCT_MOVZ | FUNCT_MOVN | FUNCT_MOVCI => AluControl::Move,
+                    FUNCT_SELEQZ => AluControl::SelectIfZero,
+                    FUNCT_SELNEZ => AluControl::SelectIfNotZero,
                     FUNCT_SOP32 | FUNCT_SOP36 => match self.state.shamt as u8 {
                         // ENC_DIV == ENC_DDIV
                         ENC_DIV => AluControl::DivisionSigned,
+                        // ENC_MOD == ENC_DMOD
+                        ENC_MOD => AluControl::ModuloSigned,
                         _ => {
-                            self.error(&format!("MIPS Release 6 encoding `{}` unsupported for this function code ({})", self.state.shamt, self.state.funct));
+                            self.reserved_instruction(&format!("MIPS Release 6 encoding `{}` unsupported for this function code ({})", self.state.shamt, self.state.funct));
                             AluControl::default()
                         }
                     },
                     FUNCT_SOP33 | FUNCT_SOP37 => match self.state.shamt as u8 {
                         // ENC_DIVU == ENC_DDIVU
                         ENC_DIVU => AluControl::DivisionUnsigned,
+                        // ENC_MODU == ENC_DMODU
+                        ENC_MODU => AluControl::ModuloUnsigned,
                         _ => {
-                            self.error(&format!("MIPS Release 6 encoding `{}` unsupported for this function code ({})", self.state.shamt, self.state.funct));
+                            self.reserved_instruction(&format!("MIPS Release 6 encoding `{}` unsupported for this function code ({})", self.state.shamt, self.state.funct));
                             AluControl::default()
                         }
                     },
                     FUNCT_SOP30 | FUNCT_SOP34 => match self.state.shamt as u8 {
                         // ENC_MUL == ENC_DMUL
                         ENC_MUL => AluControl::MultiplicationSigned,
+                        // ENC_MUH == ENC_DMUH
+                        ENC_MUH => AluControl::MultiplicationHighSigned,
                         _ => {
-                            self.error(&format!("MIPS Release 6 encoding `{}` unsupported for this function code ({})", self.state.shamt, self.state.funct));
+                            self.reserved_instruction(&format!("MIPS Release 6 encoding `{}` unsupported for this function code ({})", self.state.shamt, self.state.funct));
                             AluControl::default()
                         }
                     },
                     FUNCT_SOP31 | FUNCT_SOP35 => match self.state.shamt as u8 {
                         // ENC_MULU == ENC_DMULU
                         ENC_MULU => AluControl::MultiplicationUnsigned,
+                        // ENC_MUHU == ENC_DMUHU
+                        ENC_MUHU => AluControl::MultiplicationHighUnsigned,
                         _ => {
-                            self.error(&format!("MIPS Release 6 encoding `{}` unsupported for this function code ({})", self.state.shamt, self.state.funct));
+                            self.reserved_instruction(&format!("MIPS Release 6 encoding `{}` unsupported for this function code ({})", self.state.shamt, self.state.funct));
                             AluControl::default()
                         }
                     },
                     _ => {
-                        self.error(&format!(
+                        self.reserved_instruction(&format!(
                             "funct code `{}` is unsupported on ALU",
                             self.state.funct
                         ));
@@ -995,11 +1862,125 @@ fn construct_jump_address(&mut self) {
             | self.state.lower_26_shifted_left_by_2 as u64;
     }
 
+    /// Perform the system call service selected by the value in `$v0`.
+    ///
+    /// If the service is not supported, a system call exception is raised.
+    fn syscall(&mut self) {
+        let service = match SyscallService::from_code(self.registers[GpRegisterType::V0]) {
+            Some(service) => service,
+            None => {
+                self.raise_exception(ExceptionCode::Syscall, None);
+                return;
+            }
+        };
+
+        let a0 = self.registers[GpRegisterType::A0];
+        let a1 = self.registers[GpRegisterType::A1];
+        let f12 = self.coprocessor.fpr[12];
+        let host = Rc::clone(&self.syscall_host.0);
+        let mut host = host.borrow_mut();
+
+        match service {
+            SyscallService::PrintInt => host.print(&(a0 as i64).to_string()),
+            SyscallService::PrintFloat => host.print(&f32::from_bits(f12 as u32).to_string()),
+            SyscallService::PrintDouble => host.print(&f64::from_bits(f12).to_string()),
+            SyscallService::PrintString => match self.read_string_from_memory(a0) {
+                Ok(text) => host.print(&text),
+                Err(message) => self.error(&message),
+            },
+            SyscallService::ReadInt => match host.read_int() {
+                Some(value) => self.registers[GpRegisterType::V0] = value as u64,
+                None => self.error("No integer was available for `read_int`"),
+            },
+            SyscallService::ReadString => match host.read_string() {
+                Some(text) => {
+                    if let Err(message) = self.write_string_to_memory(a0, a1, &text) {
+                        self.error(&message);
+                    }
+                }
+                None => self.error("No input was available for `read_string`"),
+            },
+            SyscallService::Sbrk => {
+                let amount = a0 as i64;
+                // Round the allocation up to keep the heap word-aligned.
+                let new_heap_pointer = self.heap_pointer.saturating_add(a0.saturating_add(3) & !3);
+
+                if amount < 0 || new_heap_pointer > self.registers[GpRegisterType::Sp] {
+                    self.error(&format!("`sbrk` could not allocate {amount} bytes"));
+                } else {
+                    self.registers[GpRegisterType::V0] = self.heap_pointer;
+                    self.heap_pointer = new_heap_pointer;
+                }
+            }
+            SyscallService::Exit => self.is_halted = true,
+            SyscallService::Exit2 => {
+                self.exit_code = Some(a0);
+                self.is_halted = true;
+            }
+        }
+    }
+
+    /// Read the null-terminated string starting at `address` in memory.
+    fn read_string_from_memory(&self, address: u64) -> Result<String, String> {
+        let mut string = String::new();
+
+        for address in address.. {
+            match self.memory.get_byte(address) {
+                Some(0) => return Ok(string),
+                Some(byte) => string.push(byte as char),
+                None => break,
+            }
+        }
+
+        Err(format!(
+            "String at address `{address}` is not null-terminated"
+        ))
+    }
+
+    /// Write a line of input into the buffer at `address` in memory, as
+    /// done by the `read_string` system call. At most `length - 1`
+    /// characters are written, including the newline that ends the line,
+    /// and the string is then null-terminated.
+    fn write_string_to_memory(
+        &mut self,
+        address: u64,
+        length: u64,
+        text: &str,
+    ) -> Result<(), String> {
+        if length == 0 {
+            return Ok(());
+        }
+
+        let mut bytes: Vec<u8> = format!("{text}\n").bytes().collect();
+        bytes.truncate(length as usize - 1);
+        bytes.push(0);
+
+        // Check the whole buffer fits before writing any of it.
+        let end = address.saturating_add(bytes.len() as u64);
+        if self.memory.get_byte(address).is_none() || self.memory.get_byte(end - 1).is_none() {
+            return Err(format!(
+                "Buffer at address `{address}` out of bounds of memory"
+            ));
+        }
+        let size = end - address;
+        if !self.memory.is_permitted(address, size, AccessKind::Write) {
+            return Err(format!(
+                "Buffer at address `{address:#x}` cannot be written to"
+            ));
+        }
+
+        for (i, byte) in bytes.into_iter().enumerate() {
+            self.memory.set_byte(address + i as u64, byte)?;
+        }
+
+        Ok(())
+    }
+
     // ======================= Execute (EX) =======================
     /// Perform an ALU operation.
     ///
-    /// **Implementation Note:** Unlike the MIPS64 specification, this ALU
-    /// does not handle exceptions due to integer overflow.
+    /// An integer overflow exception is raised if the result of an `add`,
+    /// `addi`, `dadd`, `daddi`, `sub`, or `dsub` instruction overflows.
     fn alu(&mut self) {
         // Left shift the immediate value based on the ImmShift control signal.
         let alu_immediate = match self.signals.imm_shift {
@@ -1040,9 +2021,28 @@ fn alu(&mut self) {
             }
             AluControl::And => self.state.alu_input1 & self.state.alu_input2,
             AluControl::Or => self.state.alu_input1 | self.state.alu_input2,
+            AluControl::Xor => self.state.alu_input1 ^ self.state.alu_input2,
+            AluControl::Nor => !(self.state.alu_input1 | self.state.alu_input2),
             AluControl::ShiftLeftLogical(shamt) => self.state.alu_input2 << shamt,
+            AluControl::ShiftRightLogical(shamt) => match self.signals.reg_width {
+                RegWidth::Word => ((self.state.alu_input2 as u32) >> shamt) as u64,
+                RegWidth::DoubleWord => self.state.alu_input2 >> shamt,
+            },
+            AluControl::ShiftRightArithmetic(shamt) => match self.signals.reg_width {
+                RegWidth::Word => ((self.state.alu_input2 as i32) >> shamt) as u64,
+                RegWidth::DoubleWord => ((self.state.alu_input2 as i64) >> shamt) as u64,
+            },
             AluControl::LeftShift16 => self.state.alu_input2 << 16,
             AluControl::Not => !self.state.alu_input1,
+            AluControl::Move => self.state.alu_input1,
+            AluControl::SelectIfZero => match self.state.alu_input2 {
+                0 => self.state.alu_input1,
+                _ => 0,
+            },
+            AluControl::SelectIfNotZero => match self.state.alu_input2 {
+                0 => 0,
+                _ => self.state.alu_input1,
+            },
             AluControl::MultiplicationSigned => {
                 ((self.state.alu_input1 as i128) * (self.state.alu_input2 as i128)) as u64
             }
@@ -1063,6 +2063,45 @@ fn alu(&mut self) {
                     self.state.alu_input1 / self.state.alu_input2
                 }
             }
+            AluControl::MultiplicationHighSigned => match self.signals.reg_width {
+                RegWidth::Word => {
+                    (((self.state.alu_input1 as i64) * (self.state.alu_input2 as i64)) >> 32) as u64
+                }
+                RegWidth::DoubleWord => {
+                    (((self.state.alu_input1 as i64 as i128)
+                        * (self.state.alu_input2 as i64 as i128))
+                        >> 64) as u64
+                }
+            },
+            AluControl::MultiplicationHighUnsigned => match self.signals.reg_width {
+                RegWidth::Word => {
+                    ((self.state.alu_input1 as u32 as u64) * (self.state.alu_input2 as u32 as u64))
+                        >> 32
+                }
+                RegWidth::DoubleWord => {
+                    (((self.state.alu_input1 as u128) * (self.state.alu_input2 as u128)) >> 64)
+                        as u64
+                }
+            },
+            // As with division, the remainder of a division by zero is
+            // UNPREDICTABLE, and is given as 0.
+            AluControl::ModuloSigned => {
+                if self.state.alu_input2 == 0 {
+                    0
+                } else {
+                    (self.state.alu_input1 as i64).wrapping_rem(self.state.alu_input2 as i64) as u64
+                }
+            }
+            AluControl::ModuloUnsigned => match self.signals.reg_width {
+                RegWidth::Word => match self.state.alu_input2 as u32 {
+                    0 => 0,
+                    divisor => ((self.state.alu_input1 as u32) % divisor) as u64,
+                },
+                RegWidth::DoubleWord => match self.state.alu_input2 {
+                    0 => 0,
+                    divisor => self.state.alu_input1 % divisor,
+                },
+            },
         };
 
         // Truncate and sign-extend the output if 32-bit operations are expected.
@@ -1070,6 +2109,10 @@ fn alu(&mut self) {
             self.state.alu_result = self.state.alu_result as i32 as i64 as u64;
         }
 
+        if self.traps_on_overflow() && self.alu_overflowed() {
+            self.raise_exception(ExceptionCode::IntegerOverflow, None);
+        }
+
         // Set the zero bit/signal.
         self.datapath_signals.alu_z = match self.state.alu_result {
             0 => AluZ::YesZero,
@@ -1077,6 +2120,42 @@ fn alu(&mut self) {
         };
     }
 
+    /// Determine if the current instruction raises an exception on integer
+    /// overflow. Their unsigned counterparts (such as `addu` and `addiu`)
+    /// instead wrap around.
+    fn traps_on_overflow(&self) -> bool {
+        match self.instruction {
+            Instruction::RType(r) => {
+                matches!(r.funct, FUNCT_ADD | FUNCT_SUB | FUNCT_DADD | FUNCT_DSUB)
+            }
+            Instruction::IType(i) => !self.release_6 && matches!(i.op, OPCODE_ADDI | OPCODE_DADDI),
+            _ => false,
+        }
+    }
+
+    /// Determine if the last addition or subtraction performed by the ALU
+    /// overflowed, as a signed operation of the width given by [`RegWidth`].
+    fn alu_overflowed(&self) -> bool {
+        let input1 = self.state.alu_input1;
+        let input2 = self.state.alu_input2;
+
+        match (&self.signals.alu_control, &self.signals.reg_width) {
+            (AluControl::Addition, RegWidth::Word) => {
+                (input1 as i32).checked_add(input2 as i32).is_none()
+            }
+            (AluControl::Addition, RegWidth::DoubleWord) => {
+                (input1 as i64).checked_add(input2 as i64).is_none()
+            }
+            (AluControl::Subtraction, RegWidth::Word) => {
+                (input1 as i32).checked_sub(input2 as i32).is_none()
+            }
+            (AluControl::Subtraction, RegWidth::DoubleWord) => {
+                (input1 as i64).checked_sub(input2 as i64).is_none()
+            }
+            _ => false,
+        }
+    }
+
     fn calc_relative_pc_branch(&mut self) {
         self.state.sign_extend_shift_left_by_2 = self.state.sign_extend << 2;
         self.state.relative_pc_branch = self
@@ -1096,10 +2175,22 @@ fn calc_cpu_branch_signal(&mut self) {
         // multiplexer.
         //
         // Depending on the branch type, this may use the ALU's Zero signal
-        // as-is or inverted.
+        // as-is or inverted. Other branch types compare the register data
+        // directly as signed values, either with each other or with zero.
+        let rs_value = self.state.read_data_1 as i64;
+        let rt_value = self.state.read_data_2 as i64;
         let condition_is_true = match self.signals.branch_type {
             BranchType::OnEqual => self.datapath_signals.alu_z == AluZ::YesZero,
             BranchType::OnNotEqual => self.datapath_signals.alu_z == AluZ::NoZero,
+            BranchType::OnLessThanZero => rs_value < 0,
+            BranchType::OnLessThanOrEqualZero => rs_value <= 0,
+            BranchType::OnGreaterThanZero => rs_value > 0,
+            BranchType::OnGreaterThanOrEqualZero => rs_value >= 0,
+            BranchType::OnLessThan => rs_value < rt_value,
+            BranchType::OnGreaterThanOrEqual => rs_value >= rt_value,
+            BranchType::Always => true,
+            BranchType::OnOverflow => word_addition_overflows(rs_value, rt_value),
+            BranchType::OnNoOverflow => !word_addition_overflows(rs_value, rt_value),
         };
 
         if self.signals.branch == Branch::YesBranch && condition_is_true {
@@ -1107,21 +2198,74 @@ fn calc_cpu_branch_signal(&mut self) {
         }
     }
 
+    /// Determine the value of the [`CondMove`] signal, checking the condition
+    /// of a conditional move.
+    fn calc_cond_move_signal(&mut self) {
+        let condition_is_true = match self.signals.reg_write {
+            RegWrite::IfZero => self.state.read_data_2 == 0,
+            RegWrite::IfNotZero => self.state.read_data_2 != 0,
+            // The `rt` field holds the condition code in its upper three
+            // bits, and whether to move on true or false in its lowest bit.
+            RegWrite::IfConditionCode => {
+                let cc = (self.state.rt >> 2) as u8;
+                let tf = self.state.rt & 1 == 1;
+                self.coprocessor.get_condition_code(cc) == tf
+            }
+            RegWrite::NoWrite | RegWrite::YesWrite => true,
+        };
+
+        self.datapath_signals.cond_move = match condition_is_true {
+            true => CondMove::YesMove,
+            false => CondMove::NoMove,
+        };
+    }
+
     // ======================= Memory (MEM) =======================
     /// Read from memory based on the address provided by the ALU in
     /// [`DatapathState::alu_result`]. Returns the result to [`DatapathState::memory_data`].
     /// Should the address be invalid or otherwise memory cannot be
     /// read at the given address, bitwise 0 will be used in lieu of
-    /// any data.
+    /// any data and an exception is raised.
     fn memory_read(&mut self) {
         let address = self.state.alu_result;
 
         // Load memory, first choosing the correct load function by the
-        // RegWidth control signal, then reading the result from this
-        // memory access.
-        self.state.memory_data = match self.signals.reg_width {
-            RegWidth::Word => self.memory.load_word(address).unwrap_or(0) as u64,
-            RegWidth::DoubleWord => self.memory.load_double_word(address).unwrap_or(0),
+        // MemWidth control signal, then extending the result of this
+        // memory access based on the MemExtend control signal.
+        let result = match (&self.signals.mem_width, &self.signals.mem_extend) {
+            (MemWidth::Byte, MemExtend::SignExtend) => {
+                self.memory.load_byte(address).map(|data| data as i8 as u64)
+            }
+            (MemWidth::Byte, MemExtend::ZeroExtend) => {
+                self.memory.load_byte(address).map(|data| data as u64)
+            }
+            (MemWidth::HalfWord, MemExtend::SignExtend) => self
+                .memory
+                .load_half_word(address)
+                .map(|data| data as i16 as u64),
+            (MemWidth::HalfWord, MemExtend::ZeroExtend) => {
+                self.memory.load_half_word(address).map(|data| data as u64)
+            }
+            (MemWidth::Word, MemExtend::SignExtend) => self
+                .memory
+                .load_word(address)
+                .map(|data| data as i32 as u64),
+            (MemWidth::Word, MemExtend::ZeroExtend) => {
+                self.memory.load_word(address).map(|data| data as u64)
+            }
+            (MemWidth::DoubleWord, _) => self.memory.load_double_word(address),
+        };
+
+        self.state.memory_data = match result {
+            Ok(data) => {
+                self.access_cache(address, AccessKind::Read);
+                data
+            }
+            Err(message) => {
+                let size = self.signals.mem_width.bytes();
+                self.memory_fault(address, size, AccessKind::Read, message);
+                0
+            }
         };
     }
 
@@ -1136,20 +2280,40 @@ fn memory_write(&mut self) {
             MemWriteSrc::FloatingPointUnit => self.coprocessor.get_fp_register_to_memory(),
         };
 
-        // Choose the correct store function based on the RegWidth
+        // Choose the correct store function based on the MemWidth
         // control signal.
-        match self.signals.reg_width {
-            RegWidth::Word => {
-                self.memory
-                    .store_word(address, self.state.write_data as u32)
-                    .ok();
-            }
-            RegWidth::DoubleWord => {
-                self.memory
-                    .store_double_word(address, self.state.write_data)
-                    .ok();
-            }
+        let result = match self.signals.mem_width {
+            MemWidth::Byte => self.memory.store_byte(address, self.state.write_data as u8),
+            MemWidth::HalfWord => self
+                .memory
+                .store_half_word(address, self.state.write_data as u16),
+            MemWidth::Word => self
+                .memory
+                .store_word(address, self.state.write_data as u32),
+            MemWidth::DoubleWord => self
+                .memory
+                .store_double_word(address, self.state.write_data),
         };
+
+        match result {
+            Ok(()) => self.access_cache(address, AccessKind::Write),
+            Err(message) => {
+                let size = self.signals.mem_width.bytes();
+                self.memory_fault(address, size, AccessKind::Write, message);
+            }
+        }
+    }
+
+    /// Record an access to memory with the caches, if any. Memory-mapped
+    /// devices are not cached.
+    fn access_cache(&mut self, address: u64, kind: AccessKind) {
+        if self.memory.is_device_address(address) {
+            return;
+        }
+
+        if let Some(cache) = &mut self.cache {
+            cache.access(address, kind);
+        }
     }
 
     fn calc_general_branch_signal(&mut self) {
@@ -1166,6 +2330,26 @@ fn calc_general_branch_signal(&mut self) {
         }
     }
 
+    /// Check the outcome of the current branch or jump against the branch
+    /// predictor, if there is one.
+    fn check_branch_prediction(&mut self) {
+        if !self.is_branch_or_jump() || self.system_coprocessor.exception.is_some() {
+            return;
+        }
+
+        let Some(predictor) = &mut self.branch_predictor else {
+            return;
+        };
+
+        let address = self.state.pc_plus_4.wrapping_sub(4);
+        if self.signals.jump == Jump::NoJump {
+            let is_taken = self.datapath_signals.general_branch == GeneralBranch::YesBranch;
+            predictor.update_branch(address, self.state.relative_pc_branch, is_taken);
+        } else {
+            predictor.update_jump(address, self.state.new_pc);
+        }
+    }
+
     fn pick_pc_plus_4_or_relative_branch_addr_mux1(&mut self) {
         if let GeneralBranch::YesBranch = self.datapath_signals.general_branch {
             self.state.mem_mux1_to_mem_mux2 = self.state.relative_pc_branch;
@@ -1184,7 +2368,8 @@ fn set_new_pc_mux2(&mut self) {
 
     // ====================== Writeback (WB) ======================
     /// Write to a register. This will only write if the RegWrite
-    /// control signal is set.
+    /// control signal is set, and for conditional moves, if the
+    /// [`CondMove`] signal is set.
     fn register_write(&mut self) {
         // Determine what data will be sent to the register: either
         // the result from the ALU, or data retrieved from memory.
@@ -1192,6 +2377,7 @@ fn register_write(&mut self) {
             MemToReg::UseAlu => self.state.alu_result,
             MemToReg::UseMemory => self.state.memory_data,
             MemToReg::UsePcPlusFour => self.state.pc_plus_4,
+            MemToReg::UsePcPlusEight => self.state.pc_plus_4 + 4,
         };
 
         // Decide to retrieve data either from the main processor or the coprocessor.
@@ -1200,8 +2386,13 @@ fn register_write(&mut self) {
             DataWrite::YesWrite => self.coprocessor.get_data_writeback(),
         };
 
-        // Abort if the RegWrite signal is not set.
-        if self.signals.reg_write == RegWrite::NoWrite {
+        // Abort if the RegWrite signal is not set, if the condition of a
+        // conditional move does not hold, or if the instruction has raised
+        // an exception.
+        if self.signals.reg_write == RegWrite::NoWrite
+            || self.datapath_signals.cond_move == CondMove::NoMove
+            || self.system_coprocessor.exception.is_some()
+        {
             return;
         }
 
@@ -1228,10 +2419,66 @@ fn register_write(&mut self) {
         self.registers.gpr[self.state.write_register_destination] = self.state.register_write_data;
     }
 
-    /// Update the program counter register.
+    /// Perform the operation of an instruction that accesses the system
+    /// control coprocessor (`mfc0`, `mtc0`, or `eret`).
+    fn system_coprocessor_access(&mut self) {
+        let c = match self.instruction {
+            Instruction::Cp0Type(c) if self.system_coprocessor.exception.is_none() => c,
+            _ => return,
+        };
+
+        match c.sub {
+            SUB_MF => {
+                let data = self.system_coprocessor.read_register(c.rd, c.sel);
+                if c.rt != 0 {
+                    self.registers.gpr[c.rt as usize] = data as i32 as u64;
+                }
+            }
+            SUB_MT => {
+                let data = self.state.read_data_2 as i32 as u64;
+                self.system_coprocessor.write_register(c.rd, c.sel, data);
+            }
+            SUB_CO => self.state.new_pc = self.system_coprocessor.exception_return(),
+            _ => (),
+        }
+    }
+
+    /// Update the program counter register. If the instruction has raised
+    /// an exception, the PC is set to the exception handler instead.
+    ///
+    /// With branch delay slots, a taken branch or jump first moves to its
+    /// delay slot, and only moves to its target after the instruction in
+    /// the delay slot has finished.
     ///
     /// This function is called from the WB stage.
     fn set_pc(&mut self) {
-        self.registers.pc = self.state.new_pc;
+        if let (Some(_), Some(handler)) = (
+            self.system_coprocessor.exception,
+            self.system_coprocessor.exception_handler,
+        ) {
+            self.delay_slot_target = None;
+            self.registers.pc = handler;
+            return;
+        }
+
+        let is_taken = self.datapath_signals.general_branch == GeneralBranch::YesBranch
+            || self.signals.jump != Jump::NoJump;
+
+        self.registers.pc = if let Some(target) = self.delay_slot_target.take() {
+            target
+        } else if self.branch_delay_slots && is_taken && self.has_delay_slot() {
+            self.delay_slot_target = Some(self.state.new_pc);
+            self.state.pc_plus_4
+        } else {
+            self.state.new_pc
+        };
     }
 }
+
+/// Determine whether adding `a` and `b` as signed 32-bit values overflows, as
+/// checked by `bovc` and `bnvc`. A value that is not a sign-extended 32-bit
+/// value counts as overflowing.
+fn word_addition_overflows(a: i64, b: i64) -> bool {
+    let is_word = |value: i64| value as i32 as i64 == value;
+    !is_word(a) || !is_word(b) || (a as i32).checked_add(b as i32).is_none()
+}
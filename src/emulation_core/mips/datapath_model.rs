@@ -0,0 +1,279 @@
+//! The models of the MIPS64 datapath, and what they have in common.
+//!
+//! Besides [`MipsDatapath`], which walks one instruction at a time through
+//! its five stages, there is a multi-cycle datapath
+//! ([`MipsMultiCycleDatapath`](super::multi_cycle_datapath::MipsMultiCycleDatapath))
+//! and a pipelined datapath
+//! ([`MipsPipelinedDatapath`](super::pipelined_datapath::MipsPipelinedDatapath)).
+//! Both are built around a [`MipsDatapath`], which holds their registers,
+//! memory, and coprocessors, and does the work of each stage.
+//!
+//! [`SelectedDatapath`] holds whichever of the three models is in use, so the
+//! interface can switch between them.
+
+use std::ops::Range;
+
+use super::super::datapath::{Datapath, MemoryAccess};
+use super::constants::{FUNCT_SYSCALL, OPCODE_SPECIAL};
+use super::datapath::{MipsDatapath, Stage};
+use super::memory::{Endianness, Memory, MemoryLayout};
+use super::multi_cycle_datapath::{MipsMultiCycleDatapath, MultiCycleState};
+use super::pipelined_datapath::MipsPipelinedDatapath;
+use super::registers::GpRegisterType;
+use super::syscall::SyscallService;
+
+/// A model of the MIPS64 datapath. This gives the execution history and the
+/// debugger what they need beyond the [`Datapath`] trait.
+pub trait MipsDatapathModel:
+    Datapath<RegisterData = u64, RegisterEnum = GpRegisterType, MemoryType = Memory> + Clone
+{
+    /// The datapath holding the registers, memory, and coprocessors.
+    fn mips_datapath(&self) -> &MipsDatapath;
+
+    /// The datapath holding the registers, memory, and coprocessors.
+    fn mips_datapath_mut(&mut self) -> &mut MipsDatapath;
+
+    /// The part of memory that the next call to
+    /// [`Datapath::execute_stage()`] may write to.
+    fn writable_memory(&self) -> Range<u64>;
+}
+
+/// The part of memory written to by `instruction` if it is a system call,
+/// given the values of `$v0`, `$a0`, and `$a1` when it is performed. Only
+/// `read_string` writes to memory, filling the buffer at `$a0` of length
+/// `$a1`.
+pub(super) fn syscall_writable_memory(instruction: u32, v0: u64, a0: u64, a1: u64) -> Range<u64> {
+    let is_syscall =
+        (instruction >> 26) as u8 == OPCODE_SPECIAL && (instruction & 0x3F) as u8 == FUNCT_SYSCALL;
+
+    if is_syscall && SyscallService::from_code(v0) == Some(SyscallService::ReadString) {
+        a0..a0.saturating_add(a1)
+    } else {
+        0..0
+    }
+}
+
+/// The models of the datapath that a program can be run on.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DatapathKind {
+    /// [`MipsDatapath`], where each instruction takes one clock cycle.
+    #[default]
+    SingleCycle,
+
+    /// [`MipsMultiCycleDatapath`], where a control unit walks each
+    /// instruction through a sequence of states.
+    MultiCycle,
+
+    /// [`MipsPipelinedDatapath`], where up to five instructions are in
+    /// flight at once.
+    Pipelined,
+}
+
+/// A datapath of whichever model has been chosen to run programs on.
+#[derive(Clone, PartialEq)]
+pub enum SelectedDatapath {
+    SingleCycle(MipsDatapath),
+    MultiCycle(MipsMultiCycleDatapath),
+    Pipelined(Box<MipsPipelinedDatapath>),
+}
+
+/// A datapath of any model, as a trait object.
+type DynMipsDatapath =
+    dyn Datapath<RegisterData = u64, RegisterEnum = GpRegisterType, MemoryType = Memory>;
+
+impl Default for SelectedDatapath {
+    fn default() -> Self {
+        Self::SingleCycle(MipsDatapath::default())
+    }
+}
+
+impl SelectedDatapath {
+    /// The model of the datapath.
+    pub fn kind(&self) -> DatapathKind {
+        match self {
+            Self::SingleCycle(_) => DatapathKind::SingleCycle,
+            Self::MultiCycle(_) => DatapathKind::MultiCycle,
+            Self::Pipelined(_) => DatapathKind::Pipelined,
+        }
+    }
+
+    /// Switch to another model of the datapath, then reset it. The settings
+    /// of the datapath, such as the memory layout and the caches, are kept,
+    /// but the program has to be loaded again.
+    pub fn set_kind(&mut self, kind: DatapathKind) {
+        if kind == self.kind() {
+            return;
+        }
+
+        let mut datapath = std::mem::take(self.mips_datapath_mut());
+        datapath.reset();
+
+        *self = match kind {
+            DatapathKind::SingleCycle => Self::SingleCycle(datapath),
+            DatapathKind::MultiCycle => Self::MultiCycle(MipsMultiCycleDatapath {
+                datapath,
+                ..Default::default()
+            }),
+            DatapathKind::Pipelined => Self::Pipelined(Box::new(MipsPipelinedDatapath {
+                datapath,
+                ..Default::default()
+            })),
+        };
+    }
+
+    /// Reset the datapath, load the text and data of a program into memory
+    /// where the memory layout places them, protect the text, and un-set the
+    /// `is_halted` flag. If the process fails, an [`Err`] is returned.
+    pub fn initialize_program(&mut self, text: Vec<u32>, data: Vec<u32>) -> Result<(), String> {
+        match self {
+            Self::SingleCycle(datapath) => datapath.initialize_program(text, data),
+            Self::MultiCycle(datapath) => datapath.initialize_program(text, data),
+            Self::Pipelined(datapath) => datapath.initialize_program(text, data),
+        }
+    }
+
+    /// Returns the address of the instruction finished by the next call to
+    /// [`Datapath::execute_instruction()`].
+    pub fn next_instruction_address(&self) -> u64 {
+        match self {
+            Self::SingleCycle(datapath) => datapath.registers.pc,
+            // The PC is advanced in the instruction fetch state.
+            Self::MultiCycle(datapath) => match datapath.control_state {
+                MultiCycleState::InstructionFetch => datapath.datapath.registers.pc,
+                _ => datapath.datapath.registers.pc.wrapping_sub(4),
+            },
+            // Instructions leave the pipeline in order, so the oldest one in
+            // flight is the next to finish.
+            Self::Pipelined(datapath) => [
+                &datapath.mem_wb,
+                &datapath.ex_mem,
+                &datapath.id_ex,
+                &datapath.if_id,
+            ]
+            .into_iter()
+            .find_map(|register| register.as_ref().map(|instruction| instruction.pc))
+            .unwrap_or(datapath.datapath.registers.pc),
+        }
+    }
+
+    /// Returns the number of clock cycles run since the datapath was
+    /// initialized, or [`None`] for the single-cycle datapath, whose clock
+    /// cycles are not modeled.
+    pub fn cycles(&self) -> Option<u64> {
+        match self {
+            Self::SingleCycle(_) => None,
+            Self::MultiCycle(datapath) => Some(datapath.cycles),
+            Self::Pipelined(datapath) => Some(datapath.cycles),
+        }
+    }
+
+    /// Returns the address of the instruction decoded by the next call to
+    /// [`Datapath::execute_stage()`], or [`None`] if that stage does not
+    /// decode an instruction.
+    pub fn next_decode_address(&self) -> Option<u64> {
+        match self {
+            Self::SingleCycle(datapath) => (datapath.current_stage == Stage::InstructionDecode)
+                .then_some(datapath.registers.pc),
+            Self::MultiCycle(datapath) => (datapath.control_state
+                == MultiCycleState::InstructionDecode)
+                .then(|| datapath.datapath.registers.pc.wrapping_sub(4)),
+            Self::Pipelined(datapath) => datapath.if_id.as_ref().map(|instruction| instruction.pc),
+        }
+    }
+
+    /// Change the way memory is laid out, then reset the datapath.
+    pub fn set_memory_layout(&mut self, layout: MemoryLayout) {
+        self.mips_datapath_mut().set_memory_layout(layout);
+        self.reset();
+    }
+
+    /// Change the byte order of memory, then reset the datapath.
+    pub fn set_endianness(&mut self, endianness: Endianness) {
+        self.mips_datapath_mut().set_endianness(endianness);
+        self.reset();
+    }
+
+    fn model(&self) -> &DynMipsDatapath {
+        match self {
+            Self::SingleCycle(datapath) => datapath,
+            Self::MultiCycle(datapath) => datapath,
+            Self::Pipelined(datapath) => datapath.as_ref(),
+        }
+    }
+
+    fn model_mut(&mut self) -> &mut DynMipsDatapath {
+        match self {
+            Self::SingleCycle(datapath) => datapath,
+            Self::MultiCycle(datapath) => datapath,
+            Self::Pipelined(datapath) => datapath.as_mut(),
+        }
+    }
+}
+
+impl Datapath for SelectedDatapath {
+    type RegisterData = u64;
+    type RegisterEnum = GpRegisterType;
+    type MemoryType = Memory;
+
+    fn execute_instruction(&mut self) {
+        self.model_mut().execute_instruction();
+    }
+
+    fn execute_stage(&mut self) {
+        self.model_mut().execute_stage();
+    }
+
+    fn get_register_by_enum(&self, register: GpRegisterType) -> u64 {
+        self.model().get_register_by_enum(register)
+    }
+
+    fn get_memory(&self) -> &Memory {
+        self.model().get_memory()
+    }
+
+    fn is_halted(&self) -> bool {
+        self.model().is_halted()
+    }
+
+    fn reset(&mut self) {
+        self.model_mut().reset();
+    }
+
+    fn next_fetch_address(&self) -> Option<u64> {
+        self.model().next_fetch_address()
+    }
+
+    fn finished_instruction(&self) -> bool {
+        self.model().finished_instruction()
+    }
+
+    fn last_memory_access(&self) -> Option<MemoryAccess<u64>> {
+        self.model().last_memory_access()
+    }
+}
+
+impl MipsDatapathModel for SelectedDatapath {
+    fn mips_datapath(&self) -> &MipsDatapath {
+        match self {
+            Self::SingleCycle(datapath) => datapath,
+            Self::MultiCycle(datapath) => &datapath.datapath,
+            Self::Pipelined(datapath) => &datapath.datapath,
+        }
+    }
+
+    fn mips_datapath_mut(&mut self) -> &mut MipsDatapath {
+        match self {
+            Self::SingleCycle(datapath) => datapath,
+            Self::MultiCycle(datapath) => &mut datapath.datapath,
+            Self::Pipelined(datapath) => &mut datapath.datapath,
+        }
+    }
+
+    fn writable_memory(&self) -> Range<u64> {
+        match self {
+            Self::SingleCycle(datapath) => datapath.writable_memory(),
+            Self::MultiCycle(datapath) => datapath.writable_memory(),
+            Self::Pipelined(datapath) => datapath.writable_memory(),
+        }
+    }
+}
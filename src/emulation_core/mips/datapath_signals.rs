@@ -3,6 +3,7 @@
 #[derive(Clone, Default, PartialEq)]
 pub struct DatapathSignals {
     pub alu_z: AluZ,
+    pub cond_move: CondMove,
     pub cpu_branch: CpuBranch,
     pub general_branch: GeneralBranch,
 }
@@ -25,7 +26,8 @@ pub enum AluZ {
 ///
 /// This signal uses as input the [`Branch`](super::control_signals::Branch),
 /// [`BranchType`](super::control_signals::BranchType), and [`AluZ`] signals to
-/// determine its value. This signal is set in the EX stage.
+/// determine its value. Branch types other than `OnEqual` and `OnNotEqual`
+/// use the register data instead of [`AluZ`]. This signal is set in the EX stage.
 #[derive(Clone, Default, PartialEq)]
 pub enum CpuBranch {
     /// Do not branch.
@@ -40,6 +42,25 @@ pub enum CpuBranch {
     YesBranch = 1,
 }
 
+/// Conditional move signal. Determines whether a conditional move writes
+/// to its destination register.
+///
+/// This signal uses as input the [`RegWrite`](super::control_signals::RegWrite)
+/// signal and either the data read from register `rt` or the floating-point
+/// condition code selected by the instruction. It is only considered if
+/// [`RegWrite`](super::control_signals::RegWrite) is one of the conditional
+/// writes. This signal is set in the EX stage.
+#[derive(Clone, Default, PartialEq)]
+pub enum CondMove {
+    /// Write to the register. Until the condition is checked in the EX stage,
+    /// the move is assumed to be made.
+    #[default]
+    YesMove = 0,
+
+    /// Do not write to the register.
+    NoMove = 1,
+}
+
 /// General branch signal. This is the final determined branch signal from
 /// the CPU and FPU combined.
 ///
@@ -0,0 +1,417 @@
+//! Breakpoints, watchpoints, and running a datapath until one of them is hit.
+//!
+//! The debugger works with any of the [`MipsDatapathModel`]s. It stops for
+//! breakpoints before the instruction is fetched, as given by
+//! [`Datapath::next_fetch_address()`], and checks watchpoints after every
+//! stage, using [`Datapath::last_memory_access()`] for memory.
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use super::super::datapath::Datapath;
+use super::datapath::MipsDatapath;
+use super::datapath_model::MipsDatapathModel;
+use super::history::ExecutionHistory;
+use super::memory::MemoryFault;
+use super::registers::GpRegisterType;
+
+/// The number of instructions [`Debugger::run()`] is usually given, so that a
+/// program stuck in a loop does not freeze the emulator.
+pub const DEFAULT_INSTRUCTION_BUDGET: u64 = 100_000;
+
+/// The most bytes of memory one watchpoint can watch. The watched bytes are
+/// read after every stage, so this keeps a mistyped length from slowing the
+/// emulator to a crawl.
+pub const MAX_WATCHPOINT_LENGTH: u64 = 4096;
+
+/// The amount of execution done by each call to [`Debugger::step()`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum StepUnit {
+    #[default]
+    Instruction,
+    Stage,
+}
+
+/// The kinds of memory access a memory watchpoint stops on.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum WatchKind {
+    /// Stop on loads from the watched memory.
+    Read,
+
+    /// Stop on stores to the watched memory, and on any other change to it,
+    /// such as by a system call.
+    #[default]
+    Write,
+
+    /// Stop on both reads and writes.
+    Access,
+}
+
+/// A condition that stops [`Debugger::run()`] once an instruction meets it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Watchpoint {
+    /// Stop when an instruction accesses any of the `length` bytes of memory
+    /// starting at `address`.
+    Memory {
+        address: u64,
+        length: u64,
+        kind: WatchKind,
+    },
+
+    /// Stop when the value of a register changes.
+    Register(GpRegisterType),
+}
+
+impl Watchpoint {
+    /// Parse a watchpoint as entered by the user. A register is given by its
+    /// name, such as `$t0`. Memory is given by its address in decimal or
+    /// hexadecimal, optionally followed by the number of bytes to watch, such
+    /// as `0x100 8`. A word is watched by default, and at most
+    /// [`MAX_WATCHPOINT_LENGTH`] bytes can be watched. Memory watchpoints
+    /// stop on `kind` of access.
+    pub fn parse(text: &str, kind: WatchKind) -> Result<Self, String> {
+        let text = text.trim();
+
+        if let Ok(register) = text.trim_start_matches('$').parse::<GpRegisterType>() {
+            return Ok(Self::Register(register));
+        }
+
+        let mut parts = text.split_whitespace();
+        let address = parts
+            .next()
+            .and_then(parse_number)
+            .ok_or_else(|| format!("`{text}` is not a register or an address"))?;
+        let length = match parts.next() {
+            Some(length) => parse_number(length)
+                .filter(|&length| length > 0)
+                .ok_or_else(|| format!("`{length}` is not a number of bytes"))?,
+            None => 4,
+        };
+
+        if length > MAX_WATCHPOINT_LENGTH {
+            return Err(format!(
+                "`{length}` bytes is more than the {MAX_WATCHPOINT_LENGTH} bytes a watchpoint can watch"
+            ));
+        }
+
+        if let Some(extra) = parts.next() {
+            return Err(format!("Unexpected `{extra}` after the number of bytes"));
+        }
+
+        Ok(Self::Memory {
+            address,
+            length,
+            kind,
+        })
+    }
+}
+
+impl fmt::Display for Watchpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Memory {
+                address, length, ..
+            } => write!(
+                f,
+                "memory {address:#x} to {:#x}",
+                address + length.saturating_sub(1)
+            ),
+            Self::Register(register) => write!(f, "register ${register}"),
+        }
+    }
+}
+
+impl fmt::Display for WatchKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read => write!(f, "read"),
+            Self::Write => write!(f, "write"),
+            Self::Access => write!(f, "access"),
+        }
+    }
+}
+
+/// The reason [`Debugger::run()`] stopped.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StopReason {
+    /// The instruction at this address has a breakpoint. It has not been
+    /// executed yet.
+    Breakpoint(u64),
+
+    /// The last instruction executed met this watchpoint.
+    Watchpoint(Watchpoint),
+
+    /// The datapath halted.
+    Halted,
+
+    /// The datapath halted because memory refused an access.
+    MemoryFault(MemoryFault),
+
+    /// The datapath halted because of an error or an exception, described
+    /// by this message.
+    Error(String),
+
+    /// The number of instructions given was executed without stopping for
+    /// another reason.
+    InstructionBudget,
+}
+
+impl fmt::Display for StopReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Breakpoint(address) => write!(f, "Stopped at breakpoint at address {address:#x}"),
+            Self::Watchpoint(watchpoint) => write!(f, "Stopped at watchpoint on {watchpoint}"),
+            Self::Halted => write!(f, "Stopped as the program halted"),
+            Self::MemoryFault(fault) => write!(f, "Stopped by a fault. {fault}"),
+            Self::Error(message) => write!(f, "Stopped by an error. {message}"),
+            Self::InstructionBudget => write!(f, "Stopped after the instruction limit was reached"),
+        }
+    }
+}
+
+/// The breakpoints and watchpoints set on a datapath.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Debugger {
+    /// The addresses of instructions to stop before.
+    pub breakpoints: BTreeSet<u64>,
+
+    pub watchpoints: Vec<Watchpoint>,
+}
+
+impl Debugger {
+    /// Set or remove a breakpoint on the first instruction assembled from
+    /// source line `line`, given the line of each instruction address as in
+    /// `ProgramInfo::address_to_line_number` and the address of the first
+    /// instruction, `text_address`. Lines are counted from `0`.
+    ///
+    /// Returns the address of the instruction and whether the breakpoint is
+    /// now set. If no instruction comes from the line, an [`Err`] is returned.
+    pub fn toggle_breakpoint_at_line(
+        &mut self,
+        line: usize,
+        address_to_line_number: &[usize],
+        text_address: u64,
+    ) -> Result<(u64, bool), String> {
+        let index = address_to_line_number
+            .iter()
+            .position(|&l| l == line)
+            .ok_or_else(|| format!("No instruction on line {}", line + 1))?;
+        let address = text_address + index as u64 * 4;
+
+        if self.breakpoints.remove(&address) {
+            Ok((address, false))
+        } else {
+            self.breakpoints.insert(address);
+            Ok((address, true))
+        }
+    }
+
+    /// The source lines with a breakpoint, given the line of each instruction
+    /// address as in `ProgramInfo::address_to_line_number` and the address
+    /// of the first instruction, `text_address`.
+    pub fn breakpoint_lines(
+        &self,
+        address_to_line_number: &[usize],
+        text_address: u64,
+    ) -> Vec<usize> {
+        self.breakpoints
+            .iter()
+            .filter_map(|address| {
+                let index = address.checked_sub(text_address)? / 4;
+                address_to_line_number.get(index as usize).copied()
+            })
+            .collect()
+    }
+
+    /// Execute instructions on `datapath` until a breakpoint or watchpoint is
+    /// hit, the datapath halts, or `max_instructions` instructions have been
+    /// executed. The stages run are kept in `history`.
+    ///
+    /// A breakpoint on the instruction the datapath starts at is ignored, so
+    /// that running again continues past it. If the datapath is partway
+    /// through an instruction, that instruction is finished first.
+    pub fn run<D: MipsDatapathModel>(
+        &self,
+        datapath: &mut D,
+        history: &mut ExecutionHistory<D>,
+        max_instructions: u64,
+    ) -> StopReason {
+        for count in 0..max_instructions {
+            if datapath.is_halted() {
+                return halt_reason(datapath.mips_datapath());
+            }
+
+            if count > 0 {
+                if let Some(reason) = self.check_breakpoint(datapath) {
+                    return reason;
+                }
+            }
+
+            if let Some(reason) = self.step(datapath, history, StepUnit::Instruction) {
+                return reason;
+            }
+        }
+
+        if datapath.is_halted() {
+            halt_reason(datapath.mips_datapath())
+        } else {
+            StopReason::InstructionBudget
+        }
+    }
+
+    /// Execute a single instruction or stage on `datapath`, keeping the
+    /// stages run in `history`. Returns the reason to stop, if a breakpoint
+    /// or watchpoint was hit or the datapath halted.
+    ///
+    /// A watchpoint hit partway through an instruction stops once that
+    /// instruction has finished. Breakpoints are only checked after the
+    /// first stage, so that stepping always moves forward. Use
+    /// [`Self::check_breakpoint()`] before stepping to stop on them. (In the
+    /// pipelined datapath, further instructions may be fetched before an
+    /// instruction finishes.)
+    pub fn step<D: MipsDatapathModel>(
+        &self,
+        datapath: &mut D,
+        history: &mut ExecutionHistory<D>,
+        unit: StepUnit,
+    ) -> Option<StopReason> {
+        let mut hit = None;
+        let mut is_first_stage = true;
+
+        while !datapath.is_halted() {
+            if !is_first_stage && hit.is_none() {
+                if let Some(reason) = self.check_breakpoint(datapath) {
+                    return Some(reason);
+                }
+            }
+            is_first_stage = false;
+
+            let watched_before = self.watched_values(datapath);
+            history.execute_stage(datapath);
+            hit = hit.or_else(|| self.hit_watchpoint(datapath, &watched_before));
+
+            if unit == StepUnit::Stage || datapath.finished_instruction() {
+                break;
+            }
+        }
+
+        if let Some(watchpoint) = hit {
+            Some(StopReason::Watchpoint(watchpoint.clone()))
+        } else if datapath.is_halted() {
+            Some(halt_reason(datapath.mips_datapath()))
+        } else {
+            None
+        }
+    }
+
+    /// If `datapath` is about to fetch an instruction with a breakpoint,
+    /// return the reason to stop.
+    pub fn check_breakpoint<D: Datapath<RegisterData = u64>>(
+        &self,
+        datapath: &D,
+    ) -> Option<StopReason> {
+        datapath
+            .next_fetch_address()
+            .filter(|address| self.breakpoints.contains(address))
+            .map(StopReason::Breakpoint)
+    }
+
+    /// Add a watchpoint, unless an identical one is already set. Returns
+    /// whether it was added.
+    pub fn add_watchpoint(&mut self, watchpoint: Watchpoint) -> bool {
+        if self.watchpoints.contains(&watchpoint) {
+            return false;
+        }
+
+        self.watchpoints.push(watchpoint);
+        true
+    }
+
+    /// The current values of the registers and memory each watchpoint
+    /// watches, in the same order as the watchpoints.
+    fn watched_values<D: MipsDatapathModel>(&self, datapath: &D) -> Vec<Vec<u8>> {
+        self.watchpoints
+            .iter()
+            .map(|watchpoint| match watchpoint {
+                Watchpoint::Memory {
+                    address, length, ..
+                } => (*address..address.saturating_add(*length))
+                    .map_while(|address| datapath.get_memory().get_byte(address))
+                    .collect(),
+                Watchpoint::Register(register) => datapath
+                    .get_register_by_enum(*register)
+                    .to_be_bytes()
+                    .to_vec(),
+            })
+            .collect()
+    }
+
+    /// Find the first watchpoint met by the stage just executed, given the
+    /// values watched before it was executed.
+    fn hit_watchpoint<D: MipsDatapathModel>(
+        &self,
+        datapath: &D,
+        watched_before: &[Vec<u8>],
+    ) -> Option<&Watchpoint> {
+        let watched_after = self.watched_values(datapath);
+
+        // The memory accessed by the stage, if it ran a load or store that
+        // did not raise an exception.
+        let access = datapath.last_memory_access();
+        let accessed = access
+            .as_ref()
+            .map_or(0..0, |access| access.addresses.clone());
+        let is_load = matches!(&access, Some(access) if !access.is_write);
+        let is_store = matches!(&access, Some(access) if access.is_write);
+
+        self.watchpoints
+            .iter()
+            .zip(watched_before.iter().zip(watched_after.iter()))
+            .find(|(watchpoint, (before, after))| {
+                let changed = before != after;
+
+                match watchpoint {
+                    Watchpoint::Memory {
+                        address,
+                        length,
+                        kind,
+                    } => {
+                        let overlaps = accessed.start < address.saturating_add(*length)
+                            && *address < accessed.end;
+                        let read = is_load && overlaps;
+                        let written = changed || (is_store && overlaps);
+
+                        match kind {
+                            WatchKind::Read => read,
+                            WatchKind::Write => written,
+                            WatchKind::Access => read || written,
+                        }
+                    }
+                    Watchpoint::Register(_) => changed,
+                }
+            })
+            .map(|(watchpoint, _)| watchpoint)
+    }
+}
+
+/// The reason a halted datapath stopped.
+pub fn halt_reason(datapath: &MipsDatapath) -> StopReason {
+    let message = datapath
+        .error_message
+        .as_ref()
+        .or(datapath.system_coprocessor.exception_message.as_ref());
+
+    match (&datapath.memory_fault, message) {
+        (Some(fault), _) => StopReason::MemoryFault(fault.clone()),
+        (None, Some(message)) => StopReason::Error(message.clone()),
+        (None, None) => StopReason::Halted,
+    }
+}
+
+/// Parse an unsigned number, in hexadecimal if it starts with `0x`.
+fn parse_number(text: &str) -> Option<u64> {
+    match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => text.parse().ok(),
+    }
+}
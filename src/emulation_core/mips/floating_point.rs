@@ -0,0 +1,478 @@
+//! IEEE 754 arithmetic for the floating-point coprocessor.
+//!
+//! The host only computes results rounded to the nearest value. The other
+//! rounding modes of the FCSR are supported by finding the sign of the
+//! rounding error of that result exactly, and moving to the neighbouring
+//! value when the nearest one lies on the wrong side of the exact result.
+//! Every operation also reports the IEEE 754 exceptions it raised.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// The inexact exception (`I`). The rounded result differs from the exact result.
+pub const FLAG_INEXACT: u32 = 1 << 0;
+/// The underflow exception (`U`). The result is inexact and too small to be normalized.
+pub const FLAG_UNDERFLOW: u32 = 1 << 1;
+/// The overflow exception (`O`). The result is too large to be represented.
+pub const FLAG_OVERFLOW: u32 = 1 << 2;
+/// The divide-by-zero exception (`Z`). A finite, nonzero value was divided by zero.
+pub const FLAG_DIVIDE_BY_ZERO: u32 = 1 << 3;
+/// The invalid operation exception (`V`). The operation has no meaningful
+/// result, such as `0 / 0` or the square root of a negative value.
+pub const FLAG_INVALID: u32 = 1 << 4;
+
+/// Each exception flag with the letter used for it in the MIPS specification,
+/// from the highest bit to the lowest.
+pub const FLAG_NAMES: [(u32, char); 5] = [
+    (FLAG_INVALID, 'V'),
+    (FLAG_DIVIDE_BY_ZERO, 'Z'),
+    (FLAG_OVERFLOW, 'O'),
+    (FLAG_UNDERFLOW, 'U'),
+    (FLAG_INEXACT, 'I'),
+];
+
+// A comparison condition, as held in the `cond` field of `c.cond.fmt` and
+// the function field of `cmp.cond.fmt`, is a combination of these bits.
+/// The condition holds if the operands are unordered (either is NaN).
+pub const CONDITION_UNORDERED: u8 = 1 << 0;
+/// The condition holds if the operands are equal.
+pub const CONDITION_EQUAL: u8 = 1 << 1;
+/// The condition holds if the first operand is less than the second.
+pub const CONDITION_LESS: u8 = 1 << 2;
+/// The comparison signals an invalid operation if the operands are unordered.
+pub const CONDITION_SIGNALING: u8 = 1 << 3;
+/// The condition is negated. Only used by `cmp.cond.fmt`, for the `or`,
+/// `une`, and `ne` conditions and their signaling versions.
+pub const CONDITION_NEGATED: u8 = 1 << 4;
+
+/// The direction results are rounded in, as stored in the `RM` field of the FCSR.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum RoundingMode {
+    /// Round to the nearest value, with ties going to the even value (`RN`).
+    #[default]
+    Nearest = 0,
+
+    /// Round toward zero (`RZ`).
+    Zero = 1,
+
+    /// Round toward positive infinity (`RP`).
+    PositiveInfinity = 2,
+
+    /// Round toward negative infinity (`RM`).
+    NegativeInfinity = 3,
+}
+
+impl RoundingMode {
+    /// Get the rounding mode stored in the lowest two bits of `bits`.
+    pub fn from_bits(bits: u32) -> Self {
+        match bits & 0b11 {
+            0 => Self::Nearest,
+            1 => Self::Zero,
+            2 => Self::PositiveInfinity,
+            _ => Self::NegativeInfinity,
+        }
+    }
+}
+
+impl fmt::Display for RoundingMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mnemonic = match self {
+            Self::Nearest => "RN",
+            Self::Zero => "RZ",
+            Self::PositiveInfinity => "RP",
+            Self::NegativeInfinity => "RM",
+        };
+        write!(f, "{mnemonic}")
+    }
+}
+
+/// A floating-point format supported by the coprocessor: single precision
+/// ([`f32`]) or double precision ([`f64`]).
+pub trait Float:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MAX: Self;
+    const MIN_POSITIVE: Self;
+    const INFINITY: Self;
+
+    /// A power of two used to move operands away from the subnormal range,
+    /// so the rounding error of a result can be found without underflowing.
+    /// Scaling a value below one by it never overflows.
+    const SCALE: Self;
+
+    /// The square root of [`Self::SCALE`].
+    const SCALE_SQRT: Self;
+
+    fn is_nan(self) -> bool;
+    fn is_finite(self) -> bool;
+    fn is_sign_negative(self) -> bool;
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn mul_add(self, a: Self, b: Self) -> Self;
+
+    /// Convert a signed integer, rounding to the nearest value.
+    fn from_i64(value: i64) -> Self;
+
+    /// Convert an integral value that fits in an [`i128`].
+    fn to_i128(self) -> i128;
+
+    /// The least value greater than `self`.
+    fn next_up(self) -> Self;
+
+    /// The greatest value less than `self`.
+    fn next_down(self) -> Self {
+        -(-self).next_up()
+    }
+}
+
+macro_rules! impl_float {
+    ($float:ty, $scale:expr, $scale_sqrt:expr) => {
+        impl Float for $float {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const MAX: Self = <$float>::MAX;
+            const MIN_POSITIVE: Self = <$float>::MIN_POSITIVE;
+            const INFINITY: Self = <$float>::INFINITY;
+            const SCALE: Self = $scale;
+            const SCALE_SQRT: Self = $scale_sqrt;
+
+            fn is_nan(self) -> bool {
+                <$float>::is_nan(self)
+            }
+
+            fn is_finite(self) -> bool {
+                <$float>::is_finite(self)
+            }
+
+            fn is_sign_negative(self) -> bool {
+                <$float>::is_sign_negative(self)
+            }
+
+            fn abs(self) -> Self {
+                <$float>::abs(self)
+            }
+
+            fn sqrt(self) -> Self {
+                <$float>::sqrt(self)
+            }
+
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                <$float>::mul_add(self, a, b)
+            }
+
+            fn from_i64(value: i64) -> Self {
+                value as $float
+            }
+
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+
+            fn next_up(self) -> Self {
+                if self.is_nan() || self == <$float>::INFINITY {
+                    self
+                } else if self == 0.0 {
+                    // The smallest subnormal value, for both +0 and -0.
+                    <$float>::from_bits(1)
+                } else if self > 0.0 {
+                    <$float>::from_bits(self.to_bits() + 1)
+                } else {
+                    <$float>::from_bits(self.to_bits() - 1)
+                }
+            }
+        }
+    };
+}
+
+// 2^64 and 2^32.
+impl_float!(f32, 18446744073709551616.0, 4294967296.0);
+// 2^128 and 2^64.
+impl_float!(
+    f64,
+    340282366920938463463374607431768211456.0,
+    18446744073709551616.0
+);
+
+/// Find the sign of `value`, treating NaN as zero.
+fn sign<F: Float>(value: F) -> Ordering {
+    value.partial_cmp(&F::ZERO).unwrap_or(Ordering::Equal)
+}
+
+/// Round `nearest`, the result of an operation rounded to the nearest value,
+/// in the direction given by `mode`. `error` is the ordering of the exact
+/// result relative to `nearest`.
+fn round<F: Float>(nearest: F, error: Ordering, mode: RoundingMode) -> (F, u32) {
+    if error == Ordering::Equal {
+        return (nearest, 0);
+    }
+
+    let value = match (mode, error) {
+        (RoundingMode::Zero, Ordering::Less) if nearest > F::ZERO => nearest.next_down(),
+        (RoundingMode::Zero, Ordering::Greater) if nearest < F::ZERO => nearest.next_up(),
+        (RoundingMode::PositiveInfinity, Ordering::Greater) => nearest.next_up(),
+        (RoundingMode::NegativeInfinity, Ordering::Less) => nearest.next_down(),
+        _ => nearest,
+    };
+
+    let flags = if !value.is_finite() {
+        FLAG_OVERFLOW
+    } else if value.abs() < F::MIN_POSITIVE {
+        FLAG_UNDERFLOW
+    } else {
+        0
+    };
+    (value, flags | FLAG_INEXACT)
+}
+
+/// Get the result of an operation on finite operands whose exact result is
+/// too large to be represented. Depending on the rounding mode, this is
+/// either infinity or the largest finite value.
+fn overflow<F: Float>(negative: bool, mode: RoundingMode) -> (F, u32) {
+    let magnitude = match (mode, negative) {
+        (RoundingMode::Nearest, _)
+        | (RoundingMode::PositiveInfinity, false)
+        | (RoundingMode::NegativeInfinity, true) => F::INFINITY,
+        _ => F::MAX,
+    };
+    let value = if negative { -magnitude } else { magnitude };
+    (value, FLAG_OVERFLOW | FLAG_INEXACT)
+}
+
+/// Handle a result that is not finite. A NaN result is invalid unless one of
+/// the operands was already NaN, and an infinite result overflowed unless one
+/// of the operands was already infinite.
+///
+/// Returns [`None`] if `nearest` is finite.
+fn non_finite<F: Float>(nearest: F, operands: &[F], mode: RoundingMode) -> Option<(F, u32)> {
+    if nearest.is_nan() {
+        let flags = if operands.iter().any(|operand| operand.is_nan()) {
+            0
+        } else {
+            FLAG_INVALID
+        };
+        Some((nearest, flags))
+    } else if !nearest.is_finite() {
+        if operands.iter().any(|operand| !operand.is_finite()) {
+            Some((nearest, 0))
+        } else {
+            Some(overflow(nearest.is_sign_negative(), mode))
+        }
+    } else {
+        None
+    }
+}
+
+/// Add `a` and `b`.
+pub fn add<F: Float>(a: F, b: F, mode: RoundingMode) -> (F, u32) {
+    let sum = a + b;
+    if let Some(result) = non_finite(sum, &[a, b], mode) {
+        return result;
+    }
+
+    // An exact zero sum is -0 when rounding toward negative infinity, unless
+    // both operands are +0.
+    if sum == F::ZERO && mode == RoundingMode::NegativeInfinity {
+        let both_positive_zero =
+            a == F::ZERO && b == F::ZERO && !a.is_sign_negative() && !b.is_sign_negative();
+        if !both_positive_zero {
+            return (-F::ZERO, 0);
+        }
+    }
+
+    // The rounding error of a sum is exactly representable, and is found
+    // with Knuth's TwoSum algorithm.
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    let error = (a - a_virtual) + (b - b_virtual);
+    round(sum, sign(error), mode)
+}
+
+/// Subtract `b` from `a`.
+pub fn sub<F: Float>(a: F, b: F, mode: RoundingMode) -> (F, u32) {
+    add(a, -b, mode)
+}
+
+/// Multiply `a` and `b`.
+pub fn mul<F: Float>(a: F, b: F, mode: RoundingMode) -> (F, u32) {
+    let product = a * b;
+    if let Some(result) = non_finite(product, &[a, b], mode) {
+        return result;
+    }
+
+    let error = if product == F::ZERO && a != F::ZERO && b != F::ZERO {
+        // The product is too small to even be found by a fused multiply-add.
+        if a.is_sign_negative() == b.is_sign_negative() {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        }
+    } else if product.abs() < F::ONE {
+        // The exact product of two values has twice as many digits, so the
+        // residual of a small product can underflow. Scaling the smaller
+        // operand keeps it exact without changing its sign.
+        let (small, large) = if a.abs() < b.abs() { (a, b) } else { (b, a) };
+        sign((small * F::SCALE).mul_add(large, -(product * F::SCALE)))
+    } else {
+        sign(a.mul_add(b, -product))
+    };
+    round(product, error, mode)
+}
+
+/// Divide `a` by `b`. Dividing a finite, nonzero value by zero results in
+/// an infinity of the appropriate sign.
+pub fn div<F: Float>(a: F, b: F, mode: RoundingMode) -> (F, u32) {
+    if b == F::ZERO && a.is_finite() && a != F::ZERO {
+        return (a / b, FLAG_DIVIDE_BY_ZERO);
+    }
+
+    let quotient = a / b;
+    if let Some(result) = non_finite(quotient, &[a, b], mode) {
+        return result;
+    }
+
+    let error = if quotient == F::ZERO && a != F::ZERO && b.is_finite() {
+        if a.is_sign_negative() == b.is_sign_negative() {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        }
+    } else {
+        // The exact quotient is above `quotient` when the remainder
+        // `a - quotient * b` has the same sign as `b`. As with `mul`, small
+        // values are scaled so the remainder does not underflow. Each
+        // case scales the remainder by a positive power of two at most.
+        let (a, b, quotient) = match (a.abs() < F::ONE, quotient.abs() < F::ONE) {
+            (true, true) => (a * F::SCALE, b, quotient * F::SCALE),
+            (true, false) => (a * F::SCALE, b * F::SCALE, quotient),
+            (false, true) => (a, b / F::SCALE, quotient * F::SCALE),
+            (false, false) => (a, b, quotient),
+        };
+        let remainder = (-quotient).mul_add(b, a);
+        if b.is_sign_negative() {
+            sign(remainder).reverse()
+        } else {
+            sign(remainder)
+        }
+    };
+    round(quotient, error, mode)
+}
+
+/// Take the square root of `a`.
+pub fn sqrt<F: Float>(a: F, mode: RoundingMode) -> (F, u32) {
+    let root = a.sqrt();
+    if let Some(result) = non_finite(root, &[a], mode) {
+        return result;
+    }
+
+    let error = if a < F::ONE {
+        // Scaling by an even power of two scales the root by half as much,
+        // which keeps the residual of a small root from underflowing.
+        let root = root * F::SCALE_SQRT;
+        (-root).mul_add(root, a * F::SCALE)
+    } else {
+        (-root).mul_add(root, a)
+    };
+    round(root, sign(error), mode)
+}
+
+/// Take the reciprocal of `a`.
+pub fn recip<F: Float>(a: F, mode: RoundingMode) -> (F, u32) {
+    div(F::ONE, a, mode)
+}
+
+/// Take the reciprocal of the square root of `a`. The square root is rounded
+/// before taking its reciprocal, which the MIPS specification allows.
+pub fn rsqrt<F: Float>(a: F, mode: RoundingMode) -> (F, u32) {
+    let (root, root_flags) = sqrt(a, mode);
+    let (value, flags) = recip(root, mode);
+    (value, root_flags | flags)
+}
+
+/// Convert a double-precision value to single precision.
+pub fn to_single(a: f64, mode: RoundingMode) -> (f32, u32) {
+    let nearest = a as f32;
+    if a.is_nan() || a.is_infinite() {
+        (nearest, 0)
+    } else if nearest.is_infinite() {
+        overflow(a.is_sign_negative(), mode)
+    } else {
+        let error = a.partial_cmp(&(nearest as f64)).unwrap_or(Ordering::Equal);
+        round(nearest, error, mode)
+    }
+}
+
+/// Convert a signed integer to a floating-point value.
+pub fn from_integer<F: Float>(value: i64, mode: RoundingMode) -> (F, u32) {
+    let nearest = F::from_i64(value);
+    round(nearest, (value as i128).cmp(&nearest.to_i128()), mode)
+}
+
+/// Determine whether `condition` is one of the conditions of `cmp.cond.fmt`.
+/// Only the `or`, `une`, and `ne` conditions and their signaling versions
+/// can be negated.
+pub fn is_cmp_condition(condition: u8) -> bool {
+    matches!(condition, 0..=15 | 17..=19 | 25..=27)
+}
+
+/// Compare `a` and `b`, returning whether `condition` holds. Comparing NaN
+/// with a signaling condition is an invalid operation.
+pub fn compare<F: Float>(a: F, b: F, condition: u8) -> (bool, u32) {
+    let holds = match a.partial_cmp(&b) {
+        None => condition & CONDITION_UNORDERED != 0,
+        Some(Ordering::Equal) => condition & CONDITION_EQUAL != 0,
+        Some(Ordering::Less) => condition & CONDITION_LESS != 0,
+        Some(Ordering::Greater) => false,
+    };
+    let holds = holds != (condition & CONDITION_NEGATED != 0);
+
+    let unordered = a.is_nan() || b.is_nan();
+    if unordered && condition & CONDITION_SIGNALING != 0 {
+        (holds, FLAG_INVALID)
+    } else {
+        (holds, 0)
+    }
+}
+
+/// Convert `a` to a signed integer between `min` and `max`, rounding in the
+/// direction given by `mode`.
+///
+/// NaN and values out of range are invalid. As in IEEE 754-2008, they are
+/// converted to 0 and the nearest limit, respectively.
+pub fn to_integer(a: f64, mode: RoundingMode, min: i64, max: i64) -> (i64, u32) {
+    let rounded = match mode {
+        RoundingMode::Nearest => {
+            let rounded = a.round();
+            // `round` breaks ties away from zero, so move them to the even value.
+            if (rounded - a).abs() == 0.5 && rounded % 2.0 != 0.0 {
+                rounded - a.signum()
+            } else {
+                rounded
+            }
+        }
+        RoundingMode::Zero => a.trunc(),
+        RoundingMode::PositiveInfinity => a.ceil(),
+        RoundingMode::NegativeInfinity => a.floor(),
+    };
+
+    // `min` is a negated power of two, so both limits are exact as doubles.
+    if a.is_nan() {
+        (0, FLAG_INVALID)
+    } else if rounded < min as f64 {
+        (min, FLAG_INVALID)
+    } else if rounded >= -(min as f64) {
+        (max, FLAG_INVALID)
+    } else if rounded != a {
+        (rounded as i64, FLAG_INEXACT)
+    } else {
+        (rounded as i64, 0)
+    }
+}
@@ -0,0 +1,185 @@
+//! A bounded history of execution, allowing a datapath to step back by stage
+//! or by instruction. This works with any of the [`MipsDatapathModel`]s.
+//!
+//! Before each stage is run, the state of the datapath is saved without its
+//! memory. Once the stage has finished, only the bytes of memory it changed
+//! are recorded, along with their previous values. Restoring a stage puts back
+//! the saved state and those bytes.
+//!
+//! Only two kinds of stage can change memory: the MEM stage of a store, which
+//! writes at most a doubleword, and the ID stage of a `read_string` system
+//! call, which writes to the buffer it is given. Memory is only compared for
+//! these, as given by [`MipsDatapathModel::writable_memory()`], so that long
+//! runs stay fast.
+//!
+//! Input and output that system calls performed with the host are not undone.
+
+use std::collections::VecDeque;
+
+use super::datapath::MipsDatapath;
+use super::datapath_model::MipsDatapathModel;
+
+/// The number of stages kept by default. Older stages are dropped first.
+pub const DEFAULT_CAPACITY: usize = 1000;
+
+/// The stages most recently run on a datapath, oldest first.
+#[derive(Clone, PartialEq)]
+pub struct ExecutionHistory<D = MipsDatapath> {
+    capacity: usize,
+    entries: VecDeque<HistoryEntry<D>>,
+}
+
+/// The information needed to undo a single stage.
+#[derive(Clone, PartialEq)]
+struct HistoryEntry<D> {
+    /// The datapath before the stage was run, with its memory left empty.
+    datapath: D,
+
+    /// The address and previous value of each byte of memory that the stage
+    /// changed.
+    memory_changes: Vec<(u64, u8)>,
+}
+
+impl<D> Default for ExecutionHistory<D> {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl<D> ExecutionHistory<D> {
+    /// Create an empty history keeping at most `capacity` stages.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: VecDeque::new(),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The number of stages that can be stepped back.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Forget every stage. This should be done whenever the datapath is
+    /// changed other than through this history, such as when it is reset.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl<D: MipsDatapathModel> ExecutionHistory<D> {
+    /// Run a single stage on `datapath`, keeping what is needed to undo it.
+    /// Nothing is done if the datapath is halted.
+    pub fn execute_stage(&mut self, datapath: &mut D) {
+        if datapath.is_halted() {
+            return;
+        }
+
+        if self.capacity == 0 {
+            datapath.execute_stage();
+            return;
+        }
+
+        // Save the datapath without its memory, then save only the part of
+        // memory this stage may write to.
+        let memory = &mut datapath.mips_datapath_mut().memory;
+        let main_memory = std::mem::take(&mut memory.memory);
+        let pages = std::mem::take(&mut memory.pages);
+        let saved = datapath.clone();
+        let memory = &mut datapath.mips_datapath_mut().memory;
+        memory.memory = main_memory;
+        memory.pages = pages;
+
+        let range = datapath.writable_memory();
+        let memory_before: Vec<Option<u8>> = range
+            .clone()
+            .map(|address| datapath.get_memory().get_byte(address))
+            .collect();
+
+        datapath.execute_stage();
+
+        let memory_changes = range
+            .zip(memory_before)
+            .filter_map(|(address, before)| {
+                let before = before?;
+                (datapath.get_memory().get_byte(address) != Some(before))
+                    .then_some((address, before))
+            })
+            .collect();
+
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(HistoryEntry {
+            datapath: saved,
+            memory_changes,
+        });
+    }
+
+    /// Run stages on `datapath` until the current instruction is finished,
+    /// keeping what is needed to undo each of them.
+    pub fn execute_instruction(&mut self, datapath: &mut D) {
+        loop {
+            if datapath.is_halted() {
+                break;
+            }
+
+            self.execute_stage(datapath);
+
+            if datapath.finished_instruction() {
+                break;
+            }
+        }
+    }
+
+    /// Undo the most recent stage run on `datapath`. Returns `false` if there
+    /// is no stage to undo.
+    pub fn step_back_stage(&mut self, datapath: &mut D) -> bool {
+        let Some(entry) = self.entries.pop_back() else {
+            return false;
+        };
+
+        let memory = &mut datapath.mips_datapath_mut().memory;
+        let main_memory = std::mem::take(&mut memory.memory);
+        let pages = std::mem::take(&mut memory.pages);
+
+        *datapath = entry.datapath;
+        let memory = &mut datapath.mips_datapath_mut().memory;
+        memory.memory = main_memory;
+        memory.pages = pages;
+
+        for (address, value) in entry.memory_changes {
+            // The address was in memory when the change was recorded.
+            let _ = memory.set_byte(address, value);
+        }
+
+        true
+    }
+
+    /// Undo stages run on `datapath` until it is back at the start of an
+    /// instruction. If the datapath is partway through an instruction, this
+    /// returns to the start of that instruction. Otherwise, it returns to the
+    /// start of the previous instruction. For the pipelined datapath, this is
+    /// the clock cycle just after the previous instruction left WB.
+    ///
+    /// Returns `false` if there is no stage to undo. If the start of the
+    /// instruction is older than the history, the oldest stage kept is
+    /// restored.
+    pub fn step_back_instruction(&mut self, datapath: &mut D) -> bool {
+        if !self.step_back_stage(datapath) {
+            return false;
+        }
+
+        while !datapath.finished_instruction() && self.step_back_stage(datapath) {}
+
+        true
+    }
+}
@@ -1,6 +1,7 @@
 //! Abstract representation of an instruction.
 
 use super::constants::*;
+use super::floating_point;
 
 /// Register (R-Type) Instruction
 ///
@@ -19,8 +20,8 @@
 /// - rd: CPU register - can be used as a destination for the result of executed instructions.
 /// - shamt: Shift amount. Also called "shamt". Determines the amount of bits to shift in those instructions
 ///   that shift bits. Depending on the instruction, this field may be repurposed as a tertiary field for
-///   determining the type of instruction executed (in `mul`, `dmul`, `dmulu`, `div`, `ddiv`,
-///   `ddivu`), or be used as a "hint" field for certain instructions (of note are `jr` and `jalr`).
+///   determining the type of instruction executed (in `mul`, `muh`, `dmul`, `dmuh`, `div`, `mod`,
+///   `ddiv`, `dmod`, and their unsigned variants), or be used as a "hint" field for certain instructions (of note are `jr` and `jalr`).
 /// - function: Secondary field for determining the type of instruction executed.
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct RType {
@@ -40,12 +41,52 @@ pub struct IType {
     pub immediate: u16,
 }
 
+/// Jump (J-Type) Instruction
+///
+/// ```text
+/// 31           26   25                                                            0
+/// ┌───────────────┬──────────────────────────────────────────────────────────────────┐
+/// │    opcode     │                             addr                                 │
+/// │               │                                                                  │
+/// └───────────────┴──────────────────────────────────────────────────────────────────┘
+///         6                                    26
+/// ```
+///
+/// - opcode: Determines the type of instruction executed.
+/// - addr: For `j` and `jal`, the lower 28 bits of the jump target, shifted
+///   right by 2. For the compact branches `bc` and `balc`, this is instead
+///   a signed word offset relative to the next instruction.
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct JType {
     pub op: u8,
     pub addr: u32,
 }
 
+/// Compact Branch Instruction (compare with zero)
+///
+/// Used for the `beqzc` and `bnezc` instructions, which have a wider
+/// offset field than other conditional branches.
+///
+/// ```text
+/// 31           26   25       21   20                                              0
+/// ┌───────────────┬─────────────┬────────────────────────────────────────────────────┐
+/// │    opcode     │     rs      │                      offset                        │
+/// │               │  (not 0)    │                                                    │
+/// └───────────────┴─────────────┴────────────────────────────────────────────────────┘
+///         6              5                              21
+/// ```
+///
+/// - opcode: POP66 (`110110`) for `beqzc`, or POP76 (`111110`) for `bnezc`.
+/// - rs: CPU register - used as a source to compare with zero. If this is 0,
+///   the opcode instead refers to `jic` or `jialc`, which are not supported.
+/// - offset: Signed word offset relative to the next instruction.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CompactBranchType {
+    pub op: u8,
+    pub rs: u8,
+    pub offset: u32,
+}
+
 /// Syscall ("System Call") Instruction
 ///
 /// ```text
@@ -60,6 +101,9 @@ pub struct JType {
 /// - opcode: SPECIAL (`000000`)
 /// - code: Available for use as software parameters.
 /// - funct: SYSCALL (`001100`)
+///
+/// The `break` instruction shares this format, using the BREAK (`001101`)
+/// funct code instead.
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct SyscallType {
     pub op: u8,
@@ -111,6 +155,36 @@ pub struct FpuRegImmType {
     pub fs: u8,
 }
 
+/// Coprocessor 0 Instruction
+///
+/// Used for instructions that access the system control coprocessor.
+///
+/// ```text
+/// 31           26   25       21   20       16   15       11   10          3   2    0
+/// ┌───────────────┬─────────────┬─────────────┬─────────────┬───────────────┬──────┐
+/// │ opcode = COP0 │     sub     │     rt      │     rd      │       0       │ sel  │
+/// │    010000     │             │             │             │               │      │
+/// └───────────────┴─────────────┴─────────────┴─────────────┴───────────────┴──────┘
+///         6              5             5             5               8           3
+/// ```
+///
+/// - opcode: COP0 (`010000`)
+/// - sub: Operation subcode field. If this is CO (`10000`), the instruction
+///   is instead determined by the lower 6 bits, the `function` field.
+/// - rt: CPU register - can be either source or destination.
+/// - rd: CP0 register - can be either source or destination.
+/// - sel: Selects between CP0 registers sharing the same `rd` number.
+/// - function: Determines the operation performed if `sub` is CO (`eret`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cp0Type {
+    pub op: u8,
+    pub sub: u8,
+    pub rt: u8,
+    pub rd: u8,
+    pub sel: u8,
+    pub function: u8,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct FpuCompareType {
     pub op: u8,
@@ -140,6 +214,21 @@ pub struct FpuCompareType {
 /// - nd: Nullify delay. If set, the branch is Likely, and the delay slot instruction is not executed. (Not necessary for this project.)
 /// - tf: True/False. The type of condition for a comparison.
 /// - offset: Signed offset field used in address calculations.
+///
+/// The `bc1eqz` and `bc1nez` instructions from MIPS64 version 6 use the
+/// BC1EQZ and BC1NEZ subcodes instead, and test a floating-point register:
+///
+/// ```text
+/// 31           26   25       21   20       16   15                              0
+/// ┌───────────────┬─────────────┬─────────────┬──────────────────────────────────┐
+/// │ opcode = COP1 │ BC1EQZ/NEZ  │     ft      │              offset              │
+/// │    010001     │             │             │                                  │
+/// └───────────────┴─────────────┴─────────────┴──────────────────────────────────┘
+///        6              5             5                       16
+/// ```
+///
+/// - ft: The register whose lowest bit is tested. For these instructions,
+///   the `cc`, `nd`, and `tf` fields are not applicable.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct FpuBranchType {
     pub op: u8,
@@ -147,6 +236,7 @@ pub struct FpuBranchType {
     pub cc: u8,
     pub nd: u8,
     pub tf: u8,
+    pub ft: u8,
     pub offset: u16,
 }
 
@@ -161,6 +251,8 @@ pub enum Instruction {
     FpuRegImmType(FpuRegImmType),
     FpuCompareType(FpuCompareType),
     FpuBranchType(FpuBranchType),
+    Cp0Type(Cp0Type),
+    CompactBranchType(CompactBranchType),
 }
 
 impl Default for Instruction {
@@ -181,20 +273,29 @@ fn try_from(value: u32) -> Result<Self, Self::Error> {
             // addu
             // dadd, dsub, dmul, ddiv
             // daddu, dsubu, dmulu, ddivu
+            // muh, muhu, mod, modu
+            // dmuh, dmuhu, dmod, dmodu
             // or, and, sll
             // slt, sltu
             // jalr, jr
+            // movz, movn, movf, movt
+            // seleqz, selnez
             //
-            // Includes syscall.
+            // Includes syscall and break.
             OPCODE_SPECIAL => {
                 let funct = (value & 0x3F) as u8;
 
                 match funct {
-                    FUNCT_SYSCALL => Ok(Instruction::SyscallType(SyscallType {
+                    FUNCT_SYSCALL | FUNCT_BREAK => Ok(Instruction::SyscallType(SyscallType {
                         op: ((value >> 26) & 0x3F) as u8,
                         code: ((value >> 6) & 0xFFFFF),
                         funct: (value & 0x3F) as u8,
                     })),
+                    // Bit 17 of movf and movt must be 0.
+                    FUNCT_MOVCI if (value >> 17) & 1 == 1 => Err(format!(
+                        "rt field value `{}` not supported for funct {funct}",
+                        (value >> 16) & 0x1F
+                    )),
                     _ => Ok(Instruction::RType(RType {
                         op: ((value >> 26) & 0x3F) as u8,
                         rs: ((value >> 21) & 0x1F) as u8,
@@ -206,6 +307,35 @@ fn try_from(value: u32) -> Result<Self, Self::Error> {
                 }
             }
 
+            // COP0 (coprocessor 0)
+            OPCODE_COP0 => {
+                let sub = ((value >> 21) & 0x1F) as u8;
+                let function = (value & 0x3F) as u8;
+
+                match sub {
+                    // Move word from coprocessor 0 (mfc0)
+                    // Move word to coprocessor 0 (mtc0)
+                    // Exception return (eret)
+                    SUB_MF | SUB_MT | SUB_CO => {
+                        if sub == SUB_CO && function != FUNCTION_ERET {
+                            return Err(format!(
+                                "function `{function}` not supported for opcode {op}"
+                            ));
+                        }
+
+                        Ok(Instruction::Cp0Type(Cp0Type {
+                            op: ((value >> 26) & 0x3F) as u8,
+                            sub,
+                            rt: ((value >> 16) & 0x1F) as u8,
+                            rd: ((value >> 11) & 0x1F) as u8,
+                            sel: (value & 0x7) as u8,
+                            function,
+                        }))
+                    }
+                    _ => Err(format!("sub code `{sub}` not supported for opcode {op}")),
+                }
+            }
+
             // COP1 (coprocessor 1)
             OPCODE_COP1 => {
                 // First break down the instruction by its `fmt`/`rs`/`bcc1` field.
@@ -217,17 +347,41 @@ fn try_from(value: u32) -> Result<Self, Self::Error> {
                     FMT_SINGLE | FMT_DOUBLE => {
                         let function = (value & 0x3F) as u8;
                         match function {
-                            // add.fmt, sub.fmt, mul.fmt, div.fmt
-                            FUNCTION_ADD | FUNCTION_SUB | FUNCTION_MUL | FUNCTION_DIV => {
-                                Ok(Instruction::FpuRType(FpuRType {
-                                    op: ((value >> 26) & 0x3F) as u8,
-                                    fmt: ((value >> 21) & 0x1F) as u8,
-                                    ft: ((value >> 16) & 0x1F) as u8,
-                                    fs: ((value >> 11) & 0x1F) as u8,
-                                    fd: ((value >> 6) & 0x1F) as u8,
-                                    function: (value & 0x3F) as u8,
-                                }))
+                            // cvt.s.s and cvt.d.d are not valid conversions.
+                            FUNCTION_CVT_S if sub == FMT_SINGLE => {
+                                Err(format!("function `{function}` not supported for fmt {sub}"))
                             }
+                            FUNCTION_CVT_D if sub == FMT_DOUBLE => {
+                                Err(format!("function `{function}` not supported for fmt {sub}"))
+                            }
+                            // Bit 17 of movf.fmt and movt.fmt must be 0.
+                            FUNCTION_MOVCF if (value >> 17) & 1 == 1 => Err(format!(
+                                "ft field value `{}` not supported for function {function}",
+                                (value >> 16) & 0x1F
+                            )),
+                            // add.fmt, sub.fmt, mul.fmt, div.fmt,
+                            // sqrt.fmt, abs.fmt, mov.fmt, neg.fmt,
+                            // recip.fmt, rsqrt.fmt, min.fmt, max.fmt,
+                            // cvt.s.fmt, cvt.d.fmt, cvt.w.fmt, cvt.l.fmt,
+                            // trunc/round/ceil/floor.w.fmt, trunc/round/ceil/floor.l.fmt,
+                            // movf.fmt, movt.fmt, movz.fmt, movn.fmt,
+                            // sel.fmt, seleqz.fmt, selnez.fmt
+                            FUNCTION_ADD | FUNCTION_SUB | FUNCTION_MUL | FUNCTION_DIV
+                            | FUNCTION_SQRT | FUNCTION_ABS | FUNCTION_MOV | FUNCTION_NEG
+                            | FUNCTION_RECIP | FUNCTION_RSQRT | FUNCTION_MIN | FUNCTION_MAX
+                            | FUNCTION_CVT_S | FUNCTION_CVT_D | FUNCTION_CVT_W | FUNCTION_CVT_L
+                            | FUNCTION_TRUNC_W | FUNCTION_TRUNC_L | FUNCTION_ROUND_W
+                            | FUNCTION_ROUND_L | FUNCTION_CEIL_W | FUNCTION_CEIL_L
+                            | FUNCTION_FLOOR_W | FUNCTION_FLOOR_L | FUNCTION_MOVCF
+                            | FUNCTION_MOVZ | FUNCTION_MOVN | FUNCTION_SEL | FUNCTION_SELEQZ
+                            | FUNCTION_SELNEZ => Ok(Instruction::FpuRType(FpuRType {
+                                op: ((value >> 26) & 0x3F) as u8,
+                                fmt: ((value >> 21) & 0x1F) as u8,
+                                ft: ((value >> 16) & 0x1F) as u8,
+                                fs: ((value >> 11) & 0x1F) as u8,
+                                fd: ((value >> 6) & 0x1F) as u8,
+                                function: (value & 0x3F) as u8,
+                            })),
                             // Comparison instructions:
                             // c.eq.fmt, c.lt.fmt, c.le.fmt, c.ngt.fmt, c.nge.fmt
                             FUNCTION_C_EQ | FUNCTION_C_LT | FUNCTION_C_NGE | FUNCTION_C_LE
@@ -245,11 +399,46 @@ fn try_from(value: u32) -> Result<Self, Self::Error> {
                         }
                     }
 
+                    // The "w" and "l" fmts are used as the source of
+                    // cvt.s.fmt and cvt.d.fmt, and as the single and double
+                    // precision formats of cmp.cond.fmt.
+                    FMT_WORD | FMT_LONG => {
+                        let function = (value & 0x3F) as u8;
+                        match function {
+                            FUNCTION_CVT_S | FUNCTION_CVT_D => {
+                                Ok(Instruction::FpuRType(FpuRType {
+                                    op: ((value >> 26) & 0x3F) as u8,
+                                    fmt: ((value >> 21) & 0x1F) as u8,
+                                    ft: ((value >> 16) & 0x1F) as u8,
+                                    fs: ((value >> 11) & 0x1F) as u8,
+                                    fd: ((value >> 6) & 0x1F) as u8,
+                                    function: (value & 0x3F) as u8,
+                                }))
+                            }
+                            // cmp.cond.s, cmp.cond.d
+                            condition if floating_point::is_cmp_condition(condition) => {
+                                Ok(Instruction::FpuRType(FpuRType {
+                                    op: ((value >> 26) & 0x3F) as u8,
+                                    fmt: ((value >> 21) & 0x1F) as u8,
+                                    ft: ((value >> 16) & 0x1F) as u8,
+                                    fs: ((value >> 11) & 0x1F) as u8,
+                                    fd: ((value >> 6) & 0x1F) as u8,
+                                    function: (value & 0x3F) as u8,
+                                }))
+                            }
+                            _ => Err(format!(
+                                "function `{function}` not supported for opcode {op}"
+                            )),
+                        }
+                    }
+
                     // Move word to coprocessor 1 (mtc1)
                     // Move doubleword to coprocessor 1 (dmtc1)
                     // Move word from coprocessor 1 (mfc1)
                     // Move doubleword from coprocessor 1 (dmfc1)
-                    SUB_MT | SUB_DMT | SUB_MF | SUB_DMF => {
+                    // Move control word to coprocessor 1 (ctc1)
+                    // Move control word from coprocessor 1 (cfc1)
+                    SUB_MT | SUB_DMT | SUB_MF | SUB_DMF | SUB_CT | SUB_CF => {
                         Ok(Instruction::FpuRegImmType(FpuRegImmType {
                             op: ((value >> 26) & 0x3F) as u8,
                             sub: ((value >> 21) & 0x1F) as u8,
@@ -260,43 +449,68 @@ fn try_from(value: u32) -> Result<Self, Self::Error> {
 
                     // Branch on coprocessor 1 true (bc1t)
                     // Branch on coprocessor 1 false (bc1f)
-                    SUB_BC => Ok(Instruction::FpuBranchType(FpuBranchType {
-                        op: ((value >> 26) & 0x3F) as u8,
-                        bcc1: ((value >> 21) & 0x1F) as u8,
-                        cc: ((value >> 18) & 0x7) as u8,
-                        nd: ((value >> 17) & 1) as u8,
-                        tf: ((value >> 16) & 1) as u8,
-                        offset: (value & 0xFFFF) as u16,
-                    })),
+                    // Branch on coprocessor 1 equal to zero (bc1eqz)
+                    // Branch on coprocessor 1 not equal to zero (bc1nez)
+                    SUB_BC | SUB_BC1EQZ | SUB_BC1NEZ => {
+                        Ok(Instruction::FpuBranchType(FpuBranchType {
+                            op: ((value >> 26) & 0x3F) as u8,
+                            bcc1: ((value >> 21) & 0x1F) as u8,
+                            cc: ((value >> 18) & 0x7) as u8,
+                            nd: ((value >> 17) & 1) as u8,
+                            tf: ((value >> 16) & 1) as u8,
+                            ft: ((value >> 16) & 0x1F) as u8,
+                            offset: (value & 0xFFFF) as u16,
+                        }))
+                    }
 
                     _ => Err(format!("sub code `{sub}` not supported for opcode {op}")),
                 }
             }
 
             // I-Type instructions:
-            OPCODE_ADDI | OPCODE_ADDIU | OPCODE_DADDI | OPCODE_DADDIU | OPCODE_LW | OPCODE_SW
-            | OPCODE_LUI | OPCODE_ORI | OPCODE_ANDI | OPCODE_REGIMM | OPCODE_BEQ | OPCODE_BNE => {
-                Ok(Instruction::IType(IType {
+            OPCODE_ADDI | OPCODE_ADDIU | OPCODE_DADDI | OPCODE_DADDIU | OPCODE_LB | OPCODE_LBU
+            | OPCODE_LH | OPCODE_LHU | OPCODE_LW | OPCODE_LD | OPCODE_SB | OPCODE_SH
+            | OPCODE_SW | OPCODE_SD | OPCODE_LUI | OPCODE_ORI | OPCODE_XORI | OPCODE_ANDI
+            | OPCODE_REGIMM | OPCODE_BEQ | OPCODE_BNE | OPCODE_BLEZ | OPCODE_BGTZ
+            | OPCODE_POP26 | OPCODE_POP27 => Ok(Instruction::IType(IType {
+                op: ((value >> 26) & 0x3F) as u8,
+                rs: ((value >> 21) & 0x1F) as u8,
+                rt: ((value >> 16) & 0x1F) as u8,
+                immediate: (value & 0xFFFF) as u16,
+            })),
+
+            // Store/load word and doubleword to Coprocessor 1
+            OPCODE_SWC1 | OPCODE_LWC1 | OPCODE_SDC1 | OPCODE_LDC1 => {
+                Ok(Instruction::FpuIType(FpuIType {
                     op: ((value >> 26) & 0x3F) as u8,
-                    rs: ((value >> 21) & 0x1F) as u8,
-                    rt: ((value >> 16) & 0x1F) as u8,
-                    immediate: (value & 0xFFFF) as u16,
+                    base: ((value >> 21) & 0x1F) as u8,
+                    ft: ((value >> 16) & 0x1F) as u8,
+                    offset: (value & 0xFFFF) as u16,
                 }))
             }
 
-            // Store/load word to Coprocessor 1
-            OPCODE_SWC1 | OPCODE_LWC1 => Ok(Instruction::FpuIType(FpuIType {
-                op: ((value >> 26) & 0x3F) as u8,
-                base: ((value >> 21) & 0x1F) as u8,
-                ft: ((value >> 16) & 0x1F) as u8,
-                offset: (value & 0xFFFF) as u16,
-            })),
-
-            OPCODE_J | OPCODE_JAL => Ok(Instruction::JType(JType {
+            OPCODE_J | OPCODE_JAL | OPCODE_BC | OPCODE_BALC => Ok(Instruction::JType(JType {
                 op: ((value >> 26) & 0x3F) as u8,
                 addr: value & 0x03ffffff,
             })),
 
+            // Branch if equal to zero, compact (beqzc)
+            // Branch if not equal to zero, compact (bnezc)
+            OPCODE_POP66 | OPCODE_POP76 => {
+                let rs = ((value >> 21) & 0x1F) as u8;
+
+                // With `rs` set to 0, these are `jic` and `jialc`.
+                if rs == 0 {
+                    return Err(format!("rs field value `0` not supported for opcode {op}"));
+                }
+
+                Ok(Instruction::CompactBranchType(CompactBranchType {
+                    op: ((value >> 26) & 0x3F) as u8,
+                    rs,
+                    offset: value & 0x1FFFFF,
+                }))
+            }
+
             _ => Err(format!("opcode `{op}` not supported")),
         }
     }
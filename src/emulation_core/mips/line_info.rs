@@ -49,25 +49,25 @@ fn visual_line_to_data(&self, variable: &str) -> LineInformation {
             },
             "fpu_branch_decision" => LineInformation {
                 title: String::from("FPU Branch Decision"),
-                description: String::from("Based on the true/false branch flag, determines whether to branch. (The FpuBranch control signal must also be set.)"),
+                description: String::from("Based on the true/false branch flag, determines whether to branch. (The FpuBranch control signal must also be set.) For MOVF.fmt and MOVT.fmt, this instead determines whether to write to the destination register."),
                 value: self.coprocessor.state.condition_code_mux as u64,
                 bits: 1,
             },
             "fpu_branch_flag" => LineInformation {
                 title: String::from("Instruction [16] (True/False Branch Flag)"),
-                description: String::from("The true/false branch flag of branching coprocessor instructions. This flag specifies whether a floating-point branch instruction is BC1T or BC1F."),
+                description: String::from("The true/false branch flag of branching coprocessor instructions. This flag specifies whether a floating-point branch instruction is BC1T or BC1F. (For BC1NEZ, it is set, and for BC1EQZ, it is not. MOVF.fmt and MOVT.fmt use the same flag.)"),
                 value: self.coprocessor.state.branch_flag as u64,
                 bits: 1,
             },
             "fpu_comparator_result" => LineInformation {
                 title: String::from("Floating-Point Comparator Result"),
-                description: String::from("The result of the comparison of two floating-point values. This is routed to the \"Condition Code\" (cc) register, and will be written there if the CcWrite control signal is set."),
+                description: String::from("The result of the comparison of two floating-point values. This is routed to the \"Condition Code\" (cc) register selected by the Cc control signal, and will be written there if the CcWrite control signal is set."),
                 value: self.coprocessor.state.comparator_result,
                 bits: 64,
             },
             "fpu_condition_code" => LineInformation {
                 title: String::from("Condition Code Value"),
-                description: String::from("Data retrieved from the \"Condition Code\" (cc) register. This specifies whether a previous conditional instruction was true or false."),
+                description: String::from("Data retrieved from the \"Condition Code\" (cc) register selected by the Cc control signal. This specifies whether a previous conditional instruction was true or false. For BC1EQZ and BC1NEZ, this is instead the lowest bit of register ft."),
                 value: self.coprocessor.state.condition_code_bit as u64,
                 bits: 1,
             },
@@ -211,7 +211,7 @@ fn visual_line_to_data(&self, variable: &str) -> LineInformation {
             },
             "memory_data" => LineInformation {
                 title: String::from("Memory Data"),
-                description: String::from("The data retrieved from memory, given that the MemRead control signal is set. This may be 32 bits or 64 bits, depending on the RegWidth control signal."),
+                description: String::from("The data retrieved from memory, given that the MemRead control signal is set. This may be 8, 16, 32, or 64 bits, depending on the MemWidth control signal."),
                 value: self.state.memory_data,
                 bits: 64,
             },
@@ -265,7 +265,7 @@ fn visual_line_to_data(&self, variable: &str) -> LineInformation {
             },
             "register_write_data" => LineInformation {
                 title: String::from("Register Write Data"),
-                description: String::from("Data that will be written to a general-purpose register, given that RegWrite is set."),
+                description: String::from("Data that will be written to a general-purpose register, given that RegWrite is set. For conditional moves, the data is only written if their condition holds."),
                 value: self.state.register_write_data,
                 bits: 64,
             },
@@ -1,18 +1,278 @@
 //! Data and instruction memory implementation and API.
+//!
+//! Memory is laid out in one of two ways, chosen by [`MemoryLayout`]. In the
+//! flat layout, main memory is a single block of [`CAPACITY_BYTES`] bytes
+//! starting at address 0. In the segmented layout, main memory covers the
+//! user part of the address space, from [`TEXT_ADDRESS`] up to
+//! [`SEGMENTED_END`]. As this is far larger than a program needs, it is stored
+//! in pages of [`PAGE_SIZE`] bytes, which are only allocated once they are
+//! written to.
+//!
+//! Once a program is loaded, main memory is split into [`Region`]s, each
+//! with its own read, write, and execute [`Permissions`]. The text of the
+//! program can be read and executed but not written to, and everything else
+//! can be read and written to but not executed. Accesses the permissions do
+//! not allow are refused, so that a program cannot overwrite its own code by
+//! mistake. Writing to the text can be allowed with
+//! [`Memory::self_modifying_code`].
+//!
+//! Values of more than one byte are stored in the byte order given by
+//! [`Endianness`], which is big-endian by default.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::rc::Rc;
+
+use super::cache::AccessKind;
+use super::mmio::{MappedDevice, MmioDevice, MmioDeviceHandle};
 
 // pub const CAPACITY_BYTES: usize = 2^12; // 4KB
 pub const CAPACITY_BYTES: usize = 64 * 1024; // 64 KB
 
+/// The address of the text segment in the segmented layout.
+pub const TEXT_ADDRESS: u64 = 0x0040_0000;
+
+/// The first address past the text segment, and the start of the data
+/// segment, in the segmented layout.
+pub const DATA_SEGMENT_ADDRESS: u64 = 0x1000_0000;
+
+/// The address static data is placed at in the segmented layout.
+pub const DATA_ADDRESS: u64 = 0x1001_0000;
+
+/// The address the heap starts at in the segmented layout.
+pub const HEAP_ADDRESS: u64 = 0x1004_0000;
+
+/// The initial value of `$gp` in the segmented layout.
+pub const GLOBAL_POINTER: u64 = 0x1000_8000;
+
+/// The initial value of `$sp` in the segmented layout. The stack grows down
+/// from here.
+pub const STACK_POINTER: u64 = 0x7FFF_EFFC;
+
+/// The first address past main memory in the segmented layout.
+pub const SEGMENTED_END: u64 = 0x8000_0000;
+
+/// The number of bytes in each page of memory in the segmented layout.
+pub const PAGE_SIZE: u64 = 4096;
+
+/// The way text, data, the heap, and the stack are placed in memory.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MemoryLayout {
+    /// A single block of [`CAPACITY_BYTES`] bytes. Text starts at address 0,
+    /// followed by data and then the heap, and the stack starts at the end.
+    #[default]
+    Flat,
+
+    /// The conventional MIPS layout, as used by SPIM and MARS. Text starts at
+    /// [`TEXT_ADDRESS`], data at [`DATA_ADDRESS`], the heap at
+    /// [`HEAP_ADDRESS`], and the stack at [`STACK_POINTER`].
+    Segmented,
+}
+
+impl MemoryLayout {
+    /// The address the first instruction of a program is placed at.
+    pub fn text_address(&self) -> u64 {
+        match self {
+            Self::Flat => 0,
+            Self::Segmented => TEXT_ADDRESS,
+        }
+    }
+
+    /// The address the data of a program with `text_size` bytes of
+    /// instructions is placed at.
+    pub fn data_address(&self, text_size: u64) -> u64 {
+        match self {
+            Self::Flat => text_size,
+            Self::Segmented => DATA_ADDRESS,
+        }
+    }
+
+    /// The address the heap starts at, given the first address past the data
+    /// of the program.
+    pub fn heap_address(&self, data_end: u64) -> u64 {
+        match self {
+            Self::Flat => data_end,
+            Self::Segmented => HEAP_ADDRESS.max((data_end + 7) & !7),
+        }
+    }
+
+    /// The initial value of `$sp`.
+    pub fn stack_pointer(&self) -> u64 {
+        match self {
+            Self::Flat => CAPACITY_BYTES as u64,
+            Self::Segmented => STACK_POINTER,
+        }
+    }
+
+    /// The initial value of `$gp`.
+    pub fn global_pointer(&self) -> u64 {
+        match self {
+            Self::Flat => 0,
+            Self::Segmented => GLOBAL_POINTER,
+        }
+    }
+}
+
+/// The order the bytes of a value are stored in memory.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Endianness {
+    /// The most significant byte is stored first, at the lowest address.
+    #[default]
+    Big,
+
+    /// The least significant byte is stored first, at the lowest address.
+    Little,
+}
+
+impl Endianness {
+    /// The low `size` bytes of `value`, in the order they are stored in
+    /// memory.
+    pub fn to_bytes(&self, value: u64, size: usize) -> Vec<u8> {
+        let bytes = (0..size).map(|i| (value >> (8 * i)) as u8);
+        match self {
+            Self::Big => bytes.rev().collect(),
+            Self::Little => bytes.collect(),
+        }
+    }
+
+    /// The value of `bytes`, given in the order they are stored in memory.
+    pub fn from_bytes(&self, bytes: &[u8]) -> u64 {
+        let fold = |value: u64, byte: &u8| (value << 8) | *byte as u64;
+        match self {
+            Self::Big => bytes.iter().fold(0, fold),
+            Self::Little => bytes.iter().rev().fold(0, fold),
+        }
+    }
+}
+
+/// The kinds of access allowed to a [`Region`] of memory.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Permissions {
+    pub read: bool,
+    pub write: bool,
+    pub execute: bool,
+}
+
+impl Permissions {
+    /// The permissions of the text of a program.
+    pub const READ_EXECUTE: Self = Self {
+        read: true,
+        write: false,
+        execute: true,
+    };
+
+    /// The permissions of the data, heap, and stack of a program.
+    pub const READ_WRITE: Self = Self {
+        read: true,
+        write: true,
+        execute: false,
+    };
+
+    /// Determines if an access of the given kind is allowed.
+    pub fn allows(&self, kind: AccessKind) -> bool {
+        match kind {
+            AccessKind::InstructionFetch => self.execute,
+            AccessKind::Read => self.read,
+            AccessKind::Write => self.write,
+        }
+    }
+}
+
+impl fmt::Display for Permissions {
+    /// Show the permissions as in `ls -l`, such as `r-x`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}",
+            if self.read { 'r' } else { '-' },
+            if self.write { 'w' } else { '-' },
+            if self.execute { 'x' } else { '-' },
+        )
+    }
+}
+
+/// A range of main memory with its own permissions.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Region {
+    /// The name of the region shown in faults, such as `"text"`.
+    pub name: &'static str,
+
+    /// The first address in the region.
+    pub start: u64,
+
+    /// The first address past the region.
+    pub end: u64,
+
+    pub permissions: Permissions,
+}
+
+impl Region {
+    /// Determines if any of the `size` bytes starting at `address` are in the
+    /// region.
+    pub fn overlaps(&self, address: u64, size: u64) -> bool {
+        self.start < address.saturating_add(size) && address < self.end
+    }
+}
+
+/// An instruction fetch, load, or store that memory refused.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemoryFault {
+    /// The address of the instruction that made the access.
+    pub pc: u64,
+
+    /// The address accessed.
+    pub address: u64,
+
+    pub kind: AccessKind,
+
+    /// Why the access was refused.
+    pub message: String,
+}
+
+impl fmt::Display for MemoryFault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Memory fault in the instruction at address {:#x}: {}",
+            self.pc, self.message
+        )
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Memory {
+    /// Main memory in the flat layout. This is empty in the segmented
+    /// layout.
     pub memory: Vec<u8>,
+
+    /// Main memory in the segmented layout, by the address of the first byte
+    /// of each page. Pages that have not been written to are left out, and
+    /// read as zeros.
+    pub pages: BTreeMap<u64, Vec<u8>>,
+
+    pub layout: MemoryLayout,
+
+    /// The byte order values are stored in.
+    pub endianness: Endianness,
+
+    /// The memory-mapped devices attached, which take up addresses outside
+    /// of main memory.
+    pub devices: Vec<MappedDevice>,
+
+    /// The regions main memory is split into. Loads, stores, and instruction
+    /// fetches are only allowed where the permissions of every region they
+    /// touch allow them. Addresses in no region may be accessed in any way.
+    pub regions: Vec<Region>,
+
+    /// Whether regions that can be executed can also be written to, so that
+    /// a program can change its own instructions.
+    pub self_modifying_code: bool,
 }
 
 impl Default for Memory {
     fn default() -> Self {
-        Self {
-            memory: vec![0; CAPACITY_BYTES],
-        }
+        Self::new(MemoryLayout::default())
     }
 }
 
@@ -20,7 +280,7 @@ impl ToString for Memory {
     fn to_string(&self) -> String {
         let mut output = String::new();
 
-        for byte in self.memory.iter() {
+        for byte in self.memory.iter().chain(self.pages.values().flatten()) {
             output.push_str(&format!("{byte:02x}"));
         }
 
@@ -29,100 +289,368 @@ fn to_string(&self) -> String {
 }
 
 impl Memory {
-    /// Determines if an address is valid in a given instance of Memory.
-    /// If invalid, returns an instance of Err describing the problem with
-    /// the address.
-    fn check_valid_address(&self, address: usize) -> Result<(), String> {
-        if address % 4 != 0 {
-            Err(format!("Address `{address}` is not word-aligned"))
-        } else if address >= self.memory.len() {
+    /// Create empty memory with the given layout and no devices.
+    pub fn new(layout: MemoryLayout) -> Self {
+        let memory = match layout {
+            MemoryLayout::Flat => vec![0; CAPACITY_BYTES],
+            MemoryLayout::Segmented => Vec::new(),
+        };
+
+        Self {
+            memory,
+            pages: BTreeMap::new(),
+            layout,
+            endianness: Endianness::default(),
+            devices: Vec::new(),
+            regions: Vec::new(),
+            self_modifying_code: false,
+        }
+    }
+
+    /// Split main memory into the text of a program, which ends at
+    /// `text_end`, and the rest of memory, which holds its data, heap, and
+    /// stack. In the segmented layout, the whole text segment is treated as
+    /// text.
+    pub fn protect_program(&mut self, text_end: u64) {
+        let (text_start, data_start, end) = match self.layout {
+            MemoryLayout::Flat => (0, text_end, self.memory.len() as u64),
+            MemoryLayout::Segmented => (TEXT_ADDRESS, DATA_SEGMENT_ADDRESS, SEGMENTED_END),
+        };
+
+        self.regions = vec![
+            Region {
+                name: "text",
+                start: text_start,
+                end: data_start,
+                permissions: Permissions::READ_EXECUTE,
+            },
+            Region {
+                name: "data",
+                start: data_start,
+                end,
+                permissions: Permissions::READ_WRITE,
+            },
+        ];
+    }
+
+    /// The permissions of `region`, allowing for
+    /// [`Self::self_modifying_code`].
+    fn permissions_of(&self, region: &Region) -> Permissions {
+        let mut permissions = region.permissions;
+        if self.self_modifying_code && permissions.execute {
+            permissions.write = true;
+        }
+        permissions
+    }
+
+    /// The first region that some of the `size` bytes starting at `address`
+    /// are in, and that does not allow access of the given kind.
+    fn denying_region(&self, address: u64, size: u64, kind: AccessKind) -> Option<&Region> {
+        self.regions.iter().find(|region| {
+            region.overlaps(address, size) && !self.permissions_of(region).allows(kind)
+        })
+    }
+
+    /// Determines if the regions of memory allow all `size` bytes starting
+    /// at `address` to be accessed in the given way.
+    pub fn is_permitted(&self, address: u64, size: u64, kind: AccessKind) -> bool {
+        self.denying_region(address, size, kind).is_none()
+    }
+
+    /// Attach `device` to memory, starting at address `base`. If the device
+    /// would overlap main memory or another device, an [`Err`] is returned.
+    pub fn attach_device(
+        &mut self,
+        base: u64,
+        device: Rc<RefCell<dyn MmioDevice>>,
+    ) -> Result<(), String> {
+        let size = device.borrow().size();
+        let end = base
+            .checked_add(size)
+            .ok_or_else(|| format!("Device at address `{base:#x}` does not fit in memory"))?;
+
+        let main_memory = match self.layout {
+            MemoryLayout::Flat => 0..CAPACITY_BYTES as u64,
+            MemoryLayout::Segmented => TEXT_ADDRESS..SEGMENTED_END,
+        };
+        if base < main_memory.end && main_memory.start < end {
+            return Err(format!(
+                "Device at address `{base:#x}` overlaps main memory"
+            ));
+        }
+
+        for mapped in &self.devices {
+            let other_end = mapped.base + mapped.device.0.borrow().size();
+            if base < other_end && mapped.base < end {
+                return Err(format!(
+                    "Device at address `{base:#x}` overlaps the device at address `{:#x}`",
+                    mapped.base
+                ));
+            }
+        }
+
+        self.devices.push(MappedDevice {
+            base,
+            device: MmioDeviceHandle(device),
+        });
+
+        Ok(())
+    }
+
+    /// Return every attached device to its initial state.
+    pub fn reset_devices(&self) {
+        for mapped in &self.devices {
+            mapped.device.0.borrow_mut().reset();
+        }
+    }
+
+    /// Determines if the byte at `address` belongs to an attached device.
+    pub fn is_device_address(&self, address: u64) -> bool {
+        self.devices
+            .iter()
+            .any(|mapped| mapped.offset_of(address, 1).is_some())
+    }
+
+    /// If all `size` bytes at `address` belong to an attached device, returns
+    /// the device and the offset of the address into it. Accesses to devices
+    /// must be aligned in the same way as accesses to main memory.
+    fn find_device(
+        &self,
+        address: u64,
+        size: u64,
+    ) -> Result<Option<(&MmioDeviceHandle, u64)>, String> {
+        let Some((mapped, offset)) = self
+            .devices
+            .iter()
+            .find_map(|mapped| Some((mapped, mapped.offset_of(address, size)?)))
+        else {
+            return Ok(None);
+        };
+
+        if address % size != 0 {
             Err(format!(
-                "Address `{}` out of bounds of memory of size {}",
-                address,
-                self.memory.len()
+                "Address `{address}` is not aligned to {size} bytes"
             ))
+        } else {
+            Ok(Some((&mapped.device, offset)))
+        }
+    }
+
+    /// Determines if all `size` bytes starting at `address` are in main
+    /// memory.
+    fn in_bounds(&self, address: u64, size: u64) -> bool {
+        let Some(end) = address.checked_add(size) else {
+            return false;
+        };
+
+        match self.layout {
+            MemoryLayout::Flat => end <= self.memory.len() as u64,
+            MemoryLayout::Segmented => TEXT_ADDRESS <= address && end <= SEGMENTED_END,
+        }
+    }
+
+    /// Determines if an address is valid for accessing `size` bytes in the
+    /// given way in a given instance of Memory. The address must be aligned
+    /// to `size` bytes, and the access must be allowed by the permissions of
+    /// its regions. If invalid, returns an instance of Err describing the
+    /// problem with the address.
+    fn check_valid_address(&self, address: u64, size: u64, kind: AccessKind) -> Result<(), String> {
+        if address % size != 0 {
+            return Err(format!(
+                "Address `{address}` is not aligned to {size} bytes"
+            ));
+        }
+
+        self.check_bounds(address, size)?;
+
+        match self.denying_region(address, size, kind) {
+            Some(region) => Err(format!(
+                "Address `{address:#x}` is in the {} region ({}), which cannot be {}",
+                region.name,
+                self.permissions_of(region),
+                match kind {
+                    AccessKind::InstructionFetch => "executed",
+                    AccessKind::Read => "read",
+                    AccessKind::Write => "written to",
+                }
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Determines if all `size` bytes starting at `address` are in main
+    /// memory. If not, returns an instance of Err describing the problem.
+    fn check_bounds(&self, address: u64, size: u64) -> Result<(), String> {
+        if !self.in_bounds(address, size) {
+            match self.layout {
+                MemoryLayout::Flat => Err(format!(
+                    "Address `{}` out of bounds of memory of size {}",
+                    address,
+                    self.memory.len()
+                )),
+                MemoryLayout::Segmented => Err(format!(
+                    "Address `{address:#x}` out of bounds of memory from {TEXT_ADDRESS:#x} to {SEGMENTED_END:#x}"
+                )),
+            }
         } else {
             Ok(())
         }
     }
 
-    // A word is 32 bits.
-    pub fn store_word(&mut self, address: u64, data: u32) -> Result<(), String> {
-        let address = address as usize;
+    /// Get the byte at `address` in main memory, without going through any
+    /// device. Returns [`None`] if the address is not in main memory.
+    pub fn get_byte(&self, address: u64) -> Option<u8> {
+        if !self.in_bounds(address, 1) {
+            return None;
+        }
+
+        match self.layout {
+            MemoryLayout::Flat => Some(self.memory[address as usize]),
+            MemoryLayout::Segmented => {
+                let page = self.pages.get(&(address - address % PAGE_SIZE));
+                Some(page.map_or(0, |page| page[(address % PAGE_SIZE) as usize]))
+            }
+        }
+    }
 
-        self.check_valid_address(address)?;
+    /// Set the byte at `address` in main memory, without going through any
+    /// device. If the address is not in main memory, an [`Err`] is returned.
+    pub fn set_byte(&mut self, address: u64, data: u8) -> Result<(), String> {
+        self.check_bounds(address, 1)?;
 
-        self.memory[address] = ((data >> 24) & 0b11111111) as u8;
-        self.memory[address + 1] = ((data >> 16) & 0b11111111) as u8;
-        self.memory[address + 2] = ((data >> 8) & 0b11111111) as u8;
-        self.memory[address + 3] = (data & 0b11111111) as u8;
+        match self.layout {
+            MemoryLayout::Flat => self.memory[address as usize] = data,
+            MemoryLayout::Segmented => {
+                let page = self
+                    .pages
+                    .entry(address - address % PAGE_SIZE)
+                    .or_insert_with(|| vec![0; PAGE_SIZE as usize]);
+                page[(address % PAGE_SIZE) as usize] = data;
+            }
+        }
 
         Ok(())
     }
 
-    pub fn store_double_word(&mut self, address: u64, data: u64) -> Result<(), String> {
-        // Storing a doubleword is the same as storing two words.
-        let data_upper = (data >> 32) as u32;
-        let data_lower = data as u32;
+    /// Load `size` bytes starting at `address`, in the byte order of
+    /// memory.
+    fn load(&self, address: u64, size: u64, kind: AccessKind) -> Result<u64, String> {
+        if let Some((device, offset)) = self.find_device(address, size)? {
+            return Ok(device.0.borrow_mut().load(offset, size, self.endianness));
+        }
 
-        self.store_word(address, data_upper)?;
-        self.store_word(address + 4, data_lower)?;
+        self.check_valid_address(address, size, kind)?;
+
+        let bytes: Vec<u8> = (address..address + size)
+            .map(|address| self.get_byte(address).unwrap_or_default())
+            .collect();
+        Ok(self.endianness.from_bytes(&bytes))
+    }
+
+    /// Store the low `size` bytes of `data` starting at `address`, in the
+    /// byte order of memory.
+    fn store(&mut self, address: u64, size: u64, data: u64) -> Result<(), String> {
+        if let Some((device, offset)) = self.find_device(address, size)? {
+            device
+                .0
+                .borrow_mut()
+                .store(offset, size, data, self.endianness);
+            return Ok(());
+        }
+
+        self.check_valid_address(address, size, AccessKind::Write)?;
+
+        let bytes = self.endianness.to_bytes(data, size as usize);
+        for (i, byte) in bytes.into_iter().enumerate() {
+            self.set_byte(address + i as u64, byte)?;
+        }
 
         Ok(())
     }
 
+    pub fn store_byte(&mut self, address: u64, data: u8) -> Result<(), String> {
+        self.store(address, 1, data as u64)
+    }
+
+    // A halfword is 16 bits.
+    pub fn store_half_word(&mut self, address: u64, data: u16) -> Result<(), String> {
+        self.store(address, 2, data as u64)
+    }
+
     // A word is 32 bits.
-    pub fn load_word(&self, address: u64) -> Result<u32, String> {
-        let address = address as usize;
+    pub fn store_word(&mut self, address: u64, data: u32) -> Result<(), String> {
+        self.store(address, 4, data as u64)
+    }
+
+    pub fn store_double_word(&mut self, address: u64, data: u64) -> Result<(), String> {
+        self.store(address, 8, data)
+    }
 
-        self.check_valid_address(address)?;
+    pub fn load_byte(&self, address: u64) -> Result<u8, String> {
+        self.load(address, 1, AccessKind::Read)
+            .map(|data| data as u8)
+    }
 
-        let mut result: u32 = 0;
-        result |= (self.memory[address] as u32) << 24;
-        result |= (self.memory[address + 1] as u32) << 16;
-        result |= (self.memory[address + 2] as u32) << 8;
-        result |= self.memory[address + 3] as u32;
+    // A halfword is 16 bits.
+    pub fn load_half_word(&self, address: u64) -> Result<u16, String> {
+        self.load(address, 2, AccessKind::Read)
+            .map(|data| data as u16)
+    }
 
-        Ok(result)
+    // A word is 32 bits.
+    pub fn load_word(&self, address: u64) -> Result<u32, String> {
+        self.load(address, 4, AccessKind::Read)
+            .map(|data| data as u32)
     }
 
     pub fn load_double_word(&self, address: u64) -> Result<u64, String> {
-        // Loading a doubleword is the same as loading two words.
-        let mut result: u64 = 0;
+        self.load(address, 8, AccessKind::Read)
+    }
 
-        // Get the upper 32 bits of the doubleword.
-        match self.load_word(address) {
-            Ok(upper_data) => {
-                result |= (upper_data as u64) << 32;
-            }
-            Err(e) => return Err(e),
-        }
+    /// Load the word at `address` as an instruction, which needs permission
+    /// to execute rather than to read.
+    pub fn fetch_word(&self, address: u64) -> Result<u32, String> {
+        self.load(address, 4, AccessKind::InstructionFetch)
+            .map(|data| data as u32)
+    }
 
-        // Get the lower 32 bits of the doubleword.
-        match self.load_word(address + 4) {
-            Ok(lower_data) => {
-                result |= lower_data as u64;
-            }
-            Err(e) => return Err(e),
+    /// Show the contents of main memory as rows of four words. In the
+    /// segmented layout, only the pages that have been written to are shown.
+    ///
+    /// Each word is shown as its value, read in the byte order of memory,
+    /// while the text alongside shows its bytes in the order of their
+    /// addresses.
+    pub fn generate_formatted_hex(&self) -> String {
+        match self.layout {
+            MemoryLayout::Flat => self.format_rows(0, self.memory.len() as u64),
+            MemoryLayout::Segmented => self
+                .pages
+                .keys()
+                .map(|&page| self.format_rows(page, page + PAGE_SIZE))
+                .collect(),
         }
-
-        Ok(result)
     }
 
-    pub fn generate_formatted_hex(&self) -> String {
+    /// Show the words from `start` up to `end` as rows of four words.
+    fn format_rows(&self, start: u64, end: u64) -> String {
         let mut string: String = "".to_string();
 
-        let mut base = 0;
-        while base < self.memory.len() {
-            string.push_str(&format!("0x{base:04x}:\t\t"));
+        let mut base = start;
+        while base < end {
+            match self.layout {
+                MemoryLayout::Flat => string.push_str(&format!("0x{base:04x}:\t\t")),
+                MemoryLayout::Segmented => string.push_str(&format!("0x{base:08x}:\t")),
+            }
             let mut char_version: String = "".to_string();
 
             for offset in 0..4 {
-                let word_address = base as u64 + (offset * 4);
-                if let Ok(word) = self.load_word(word_address) {
-                    string.push_str(&format!("{word:08x}\t"));
-                    char_version.push_str(&convert_word_to_chars(word))
-                };
+                let word_address = base + (offset * 4);
+                let bytes: Vec<u8> = (word_address..word_address + 4)
+                    .map(|address| self.get_byte(address).unwrap_or_default())
+                    .collect();
+                let word = self.endianness.from_bytes(&bytes);
+                string.push_str(&format!("{word:08x}\t"));
+                char_version.push_str(&convert_bytes_to_chars(&bytes));
             }
             string.push_str(&format!("{char_version}\n"));
             base += 16;
@@ -131,10 +659,9 @@ pub fn generate_formatted_hex(&self) -> String {
     }
 }
 
-fn convert_word_to_chars(word: u32) -> String {
+fn convert_bytes_to_chars(bytes: &[u8]) -> String {
     let mut chars = "".to_string();
-    for shift in (0..4).rev() {
-        let byte = (word >> (shift * 8)) as u8;
+    for &byte in bytes {
         if byte > 32 && byte < 127 {
             chars.push(byte as char);
         } else {
@@ -0,0 +1,284 @@
+//! Memory-mapped I/O (MMIO) devices.
+//!
+//! A device attached to [`Memory`](super::memory::Memory) takes up a range of
+//! addresses outside of main memory. Loads and stores within that range are
+//! passed to the device instead of main memory, so a program talks to it by
+//! polling and writing its registers.
+//!
+//! As in the MARS simulator, devices are placed from address `0xffff0000`.
+//! On a 64-bit datapath, `lui $t0, 0xffff` sign-extends this address to
+//! [`MMIO_BASE`], which is where the devices actually live.
+//!
+//! Like the system call host, devices are shared with the user interface and
+//! are not part of the execution history, so stepping back does not undo
+//! their effects.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::rc::Rc;
+
+use super::memory::Endianness;
+
+/// The first address of the memory-mapped I/O region.
+pub const MMIO_BASE: u64 = 0xFFFF_FFFF_FFFF_0000;
+
+/// The address [`KeyboardDisplay`] is usually attached at.
+pub const KEYBOARD_DISPLAY_ADDRESS: u64 = MMIO_BASE;
+
+/// The address [`BitmapDisplay`] is usually attached at.
+pub const BITMAP_DISPLAY_ADDRESS: u64 = MMIO_BASE + 0x1000;
+
+/// A device that a program accesses through loads and stores.
+pub trait MmioDevice {
+    /// The name shown to the user.
+    fn name(&self) -> &str;
+
+    /// The number of bytes of addresses the device takes up.
+    fn size(&self) -> u64;
+
+    /// Load `size` bytes starting `offset` bytes into the device. The value
+    /// is returned in the low bytes, read in the byte order of memory,
+    /// `endianness`.
+    fn load(&mut self, offset: u64, size: u64, endianness: Endianness) -> u64;
+
+    /// Store the low `size` bytes of `value`, starting `offset` bytes into
+    /// the device, in the byte order of memory, `endianness`.
+    fn store(&mut self, offset: u64, size: u64, value: u64, endianness: Endianness);
+
+    /// Return the device to its initial state. This is done whenever the
+    /// datapath is reset.
+    fn reset(&mut self);
+}
+
+/// A shared handle to a [`MmioDevice`].
+///
+/// Two handles are considered equal if they refer to the same device.
+#[derive(Clone)]
+pub struct MmioDeviceHandle(pub Rc<RefCell<dyn MmioDevice>>);
+
+impl PartialEq for MmioDeviceHandle {
+    fn eq(&self, other: &Self) -> bool {
+        // Only compare the addresses of the devices, not their vtables.
+        std::ptr::eq(
+            Rc::as_ptr(&self.0) as *const u8,
+            Rc::as_ptr(&other.0) as *const u8,
+        )
+    }
+}
+
+impl fmt::Debug for MmioDeviceHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MmioDeviceHandle")
+            .field(&self.0.borrow().name())
+            .finish()
+    }
+}
+
+/// A device attached to memory, and the address it starts at.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MappedDevice {
+    pub base: u64,
+    pub device: MmioDeviceHandle,
+}
+
+impl MappedDevice {
+    /// If all `size` bytes starting at `address` belong to the device, return
+    /// the offset of `address` into the device.
+    pub fn offset_of(&self, address: u64, size: u64) -> Option<u64> {
+        let offset = address.checked_sub(self.base)?;
+        let end = offset.checked_add(size)?;
+
+        if end <= self.device.0.borrow().size() {
+            Some(offset)
+        } else {
+            None
+        }
+    }
+}
+
+/// A keyboard and display controller, laid out as in MARS.
+///
+/// - `0x0`, receiver control: bit 0 is set while a key is waiting to be
+///   read, and bit 1 enables interrupts.
+/// - `0x4`, receiver data: loading this reads the next key waiting.
+/// - `0x8`, transmitter control: bit 0 is set while the display is ready, and
+///   bit 1 enables interrupts.
+/// - `0xc`, transmitter data: storing here shows the low byte on the display.
+///
+/// Each register is accessed at its own address, with a load or store of any
+/// width. Other addresses within a register read as `0`, and ignore stores.
+/// The display is always ready, and interrupts are not raised, though the
+/// interrupt enable bits can be set and read back.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KeyboardDisplay {
+    /// Keys typed that the program has yet to read, oldest first.
+    pub input: VecDeque<u8>,
+
+    /// Everything the program has shown on the display.
+    pub output: String,
+
+    /// The key most recently read, which the receiver data register keeps
+    /// holding until another key is read.
+    last_key: u8,
+
+    receiver_interrupt_enable: bool,
+    transmitter_interrupt_enable: bool,
+}
+
+impl KeyboardDisplay {
+    pub const RECEIVER_CONTROL: u64 = 0x0;
+    pub const RECEIVER_DATA: u64 = 0x4;
+    pub const TRANSMITTER_CONTROL: u64 = 0x8;
+    pub const TRANSMITTER_DATA: u64 = 0xc;
+
+    /// Queue a key for the program to read.
+    pub fn type_key(&mut self, key: u8) {
+        self.input.push_back(key);
+    }
+}
+
+impl MmioDevice for KeyboardDisplay {
+    fn name(&self) -> &str {
+        "Keyboard and display"
+    }
+
+    fn size(&self) -> u64 {
+        16
+    }
+
+    fn load(&mut self, offset: u64, _size: u64, _endianness: Endianness) -> u64 {
+        match offset {
+            Self::RECEIVER_CONTROL => {
+                !self.input.is_empty() as u64 | (self.receiver_interrupt_enable as u64) << 1
+            }
+            Self::RECEIVER_DATA => {
+                if let Some(key) = self.input.pop_front() {
+                    self.last_key = key;
+                }
+                self.last_key as u64
+            }
+            Self::TRANSMITTER_CONTROL => 1 | (self.transmitter_interrupt_enable as u64) << 1,
+            _ => 0,
+        }
+    }
+
+    fn store(&mut self, offset: u64, _size: u64, value: u64, _endianness: Endianness) {
+        match offset {
+            Self::RECEIVER_CONTROL => self.receiver_interrupt_enable = value & 0b10 != 0,
+            Self::TRANSMITTER_CONTROL => self.transmitter_interrupt_enable = value & 0b10 != 0,
+            Self::TRANSMITTER_DATA => self.output.push(value as u8 as char),
+            _ => (),
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// A framebuffer of `width` by `height` pixels.
+///
+/// Each pixel is a word holding its color as `0x00RRGGBB`. Pixels are stored
+/// row by row, starting from the top left, with their bytes in the same order
+/// as in main memory.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BitmapDisplay {
+    width: usize,
+    height: usize,
+    pixels: Vec<u32>,
+}
+
+impl Default for BitmapDisplay {
+    fn default() -> Self {
+        Self {
+            width: 64,
+            height: 64,
+            pixels: vec![0; 64 * 64],
+        }
+    }
+}
+
+impl BitmapDisplay {
+    /// Create a display with every pixel black. If either dimension is `0`,
+    /// an [`Err`] is returned.
+    pub fn new(width: usize, height: usize) -> Result<Self, String> {
+        if width == 0 || height == 0 {
+            return Err(format!(
+                "Bitmap display of {width}x{height} pixels has no pixels"
+            ));
+        }
+
+        Ok(Self {
+            width,
+            height,
+            pixels: vec![0; width * height],
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The color of the pixel in column `x` and row `y`, or [`None`] if it is
+    /// outside the display.
+    pub fn pixel(&self, x: usize, y: usize) -> Option<u32> {
+        if x < self.width && y < self.height {
+            Some(self.pixels[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// The color of every pixel, row by row.
+    pub fn pixels(&self) -> &[u32] {
+        &self.pixels
+    }
+
+    /// The shift of the byte `offset` bytes into the framebuffer within its
+    /// pixel, when pixels are stored in the byte order `endianness`.
+    fn byte_shift(offset: u64, endianness: Endianness) -> u32 {
+        let index = (offset % 4) as u32;
+        match endianness {
+            Endianness::Big => 8 * (3 - index),
+            Endianness::Little => 8 * index,
+        }
+    }
+}
+
+impl MmioDevice for BitmapDisplay {
+    fn name(&self) -> &str {
+        "Bitmap display"
+    }
+
+    fn size(&self) -> u64 {
+        self.pixels.len() as u64 * 4
+    }
+
+    fn load(&mut self, offset: u64, size: u64, endianness: Endianness) -> u64 {
+        let bytes: Vec<u8> = (offset..offset + size)
+            .map(|offset| {
+                let pixel = self.pixels[(offset / 4) as usize];
+                (pixel >> Self::byte_shift(offset, endianness)) as u8
+            })
+            .collect();
+        endianness.from_bytes(&bytes)
+    }
+
+    fn store(&mut self, offset: u64, size: u64, value: u64, endianness: Endianness) {
+        let bytes = endianness.to_bytes(value, size as usize);
+        for (offset, byte) in (offset..offset + size).zip(bytes) {
+            let shift = Self::byte_shift(offset, endianness);
+            let pixel = &mut self.pixels[(offset / 4) as usize];
+            *pixel = (*pixel & !(0xFF << shift)) | (byte as u32) << shift;
+        }
+    }
+
+    fn reset(&mut self) {
+        self.pixels.iter_mut().for_each(|pixel| *pixel = 0);
+    }
+}
@@ -0,0 +1,433 @@
+//! Implementation of a multi-cycle MIPS64 datapath.
+//!
+//! Like [`MipsDatapath`], this datapath has a single instruction active at any
+//! point in time. Unlike it, the number of clock cycles an instruction takes
+//! depends on its class, and each cycle is driven by the state of a finite
+//! state machine in the control unit:
+//!
+//! | Instruction class                     | States                    | Cycles |
+//! |---------------------------------------|---------------------------|--------|
+//! | Loads                                 | 0, 1, 2, 3, 4             | 5      |
+//! | Stores                                | 0, 1, 2, 5                | 4      |
+//! | R-type, I-type arithmetic, and others | 0, 1, 6, 7                | 4      |
+//! | Branches                              | 0, 1, 8                   | 3      |
+//! | Jumps and `eret`                      | 0, 1, 9                   | 3      |
+//!
+//! (See [`MultiCycleState`] for the states.)
+//!
+//! Between cycles, values are held in the instruction register (IR), the
+//! memory data register (MDR), the `A` and `B` registers read from the
+//! register file, and the `ALUOut` register. The work done in each state is
+//! the work of the matching stages of [`MipsDatapath`], which holds the
+//! registers, memory, and coprocessors of this datapath.
+//!
+//! Each state reads its inputs from these registers. Before the stages of
+//! [`MipsDatapath`] run, the lines they would otherwise take from the
+//! previous stage are driven from IR, MDR, `A`, `B`, and `ALUOut`, so a
+//! value changed between cycles is the one used. Each state then writes its
+//! results back to these registers:
+//!
+//! | State                           | Reads               | Writes             |
+//! |---------------------------------|---------------------|--------------------|
+//! | 0: Instruction fetch            |                     | IR                 |
+//! | 1: Instruction decode           | IR                  | `A`, `B`, `ALUOut` |
+//! | 2: Memory address computation   | `A`, `B`            | `ALUOut`           |
+//! | 3: Memory read                  | `ALUOut`            | MDR                |
+//! | 4: Memory read completion       | MDR                 |                    |
+//! | 5: Memory write                 | `ALUOut`, `B`       |                    |
+//! | 6: Execution                    | `A`, `B`            | `ALUOut`           |
+//! | 7: ALU completion               | `ALUOut`            |                    |
+//! | 8: Branch completion            | `A`, `B`, `ALUOut`  |                    |
+//! | 9: Jump completion              | `A`, `B`            |                    |
+//!
+//! # Differences Compared to the Textbook Multi-Cycle Datapath
+//!
+//! - The PC shows `PC + 4` from the end of the instruction fetch state onwards,
+//!   but only takes its final value once the instruction completes. Exceptions
+//!   are then taken the same way as in [`MipsDatapath`].
+//! - Floating-point instructions, system calls, and coprocessor 0 instructions
+//!   use the same states as R-type instructions, other than floating-point
+//!   loads, stores, and branches.
+
+use std::fmt;
+use std::ops::Range;
+
+use super::super::datapath::{Datapath, MemoryAccess};
+use super::constants::*;
+use super::control_signals::*;
+use super::datapath::MipsDatapath;
+use super::datapath_model::{syscall_writable_memory, MipsDatapathModel};
+use super::instruction::*;
+use super::memory::Memory;
+use super::registers::GpRegisterType;
+
+/// An implementation of a multi-cycle datapath for the MIPS64 ISA.
+#[derive(Clone, Default, PartialEq)]
+pub struct MipsMultiCycleDatapath {
+    /// The datapath holding the registers, memory, and coprocessors. The work
+    /// of each state is done on this datapath.
+    pub datapath: MipsDatapath,
+
+    /// The state of the control unit. This is the state the next clock cycle
+    /// will run in.
+    pub control_state: MultiCycleState,
+
+    /// The state the most recent clock cycle ran in.
+    pub previous_state: Option<MultiCycleState>,
+
+    /// The instruction register (IR), written in the instruction fetch state.
+    pub ir: u32,
+
+    /// The memory data register (MDR), written in the memory read state.
+    pub mdr: u64,
+
+    /// The `A` register, holding the value of `rs` read in the instruction
+    /// decode state.
+    pub a: u64,
+
+    /// The `B` register, holding the value of `rt` read in the instruction
+    /// decode state.
+    pub b: u64,
+
+    /// The `ALUOut` register, holding the last result of the ALU.
+    pub alu_out: u64,
+
+    /// The number of clock cycles run since the datapath was initialized.
+    pub cycles: u64,
+}
+
+/// The states of the control unit's finite state machine, numbered as in the
+/// textbook multi-cycle datapath.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MultiCycleState {
+    /// Fetch the instruction into the IR.
+    #[default]
+    InstructionFetch = 0,
+
+    /// Read `rs` and `rt` into `A` and `B`, and compute the branch target
+    /// ahead of time.
+    InstructionDecode = 1,
+
+    /// Compute the address of a load or store.
+    MemoryAddressComputation = 2,
+
+    /// Read memory for a load.
+    MemoryRead = 3,
+
+    /// Write the loaded data to a register.
+    MemoryReadCompletion = 4,
+
+    /// Write memory for a store.
+    MemoryWrite = 5,
+
+    /// Perform the operation of an arithmetic or logical instruction.
+    Execution = 6,
+
+    /// Write the result of an arithmetic or logical instruction to a register.
+    AluCompletion = 7,
+
+    /// Compare the operands of a branch, and take it if needed.
+    BranchCompletion = 8,
+
+    /// Set the PC to the target of a jump.
+    JumpCompletion = 9,
+}
+
+impl fmt::Display for MultiCycleState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::InstructionFetch => "instruction fetch",
+            Self::InstructionDecode => "instruction decode",
+            Self::MemoryAddressComputation => "memory address computation",
+            Self::MemoryRead => "memory read",
+            Self::MemoryReadCompletion => "memory read completion",
+            Self::MemoryWrite => "memory write",
+            Self::Execution => "execution",
+            Self::AluCompletion => "ALU completion",
+            Self::BranchCompletion => "branch completion",
+            Self::JumpCompletion => "jump completion",
+        };
+        write!(f, "{} ({name})", *self as u8)
+    }
+}
+
+impl MultiCycleState {
+    /// The register transfers performed in this state.
+    pub fn register_transfers(&self) -> &'static str {
+        match self {
+            Self::InstructionFetch => "IR <= Memory[PC]; PC <= PC + 4",
+            Self::InstructionDecode => {
+                "A <= Reg[IR[25:21]]; B <= Reg[IR[20:16]]; ALUOut <= PC + (sign-extend(IR[15:0]) << 2)"
+            }
+            Self::MemoryAddressComputation => "ALUOut <= A + sign-extend(IR[15:0])",
+            Self::MemoryRead => "MDR <= Memory[ALUOut]",
+            Self::MemoryReadCompletion => "Reg[IR[20:16]] <= MDR",
+            Self::MemoryWrite => "Memory[ALUOut] <= B",
+            Self::Execution => "ALUOut <= A op (B or sign-extend(IR[15:0]))",
+            Self::AluCompletion => "Reg[IR[15:11] or IR[20:16]] <= ALUOut",
+            Self::BranchCompletion => "if (condition on A and B) PC <= ALUOut",
+            Self::JumpCompletion => "PC <= {PC[63:28], IR[25:0], 00} or A",
+        }
+    }
+}
+
+impl Datapath for MipsMultiCycleDatapath {
+    type RegisterData = u64;
+    type RegisterEnum = GpRegisterType;
+    type MemoryType = Memory;
+
+    fn execute_instruction(&mut self) {
+        loop {
+            // Stop early if the datapath has halted.
+            if self.datapath.is_halted() {
+                break;
+            }
+
+            self.execute_stage();
+
+            // This instruction is finished when the control unit has
+            // returned to the instruction fetch state.
+            if self.control_state == MultiCycleState::InstructionFetch {
+                break;
+            }
+        }
+    }
+
+    /// Run a single clock cycle in the current state of the control unit.
+    fn execute_stage(&mut self) {
+        // If the datapath is halted, do nothing.
+        if self.datapath.is_halted() {
+            return;
+        }
+
+        self.cycles += 1;
+        let state = self.control_state;
+
+        // The stages of the inner datapath expect the PC to hold the address
+        // of the current instruction.
+        if state != MultiCycleState::InstructionFetch {
+            self.datapath.registers.pc = self.datapath.state.pc_plus_4.wrapping_sub(4);
+        }
+
+        let datapath = &mut self.datapath;
+        match state {
+            MultiCycleState::InstructionFetch => {
+                datapath.stage_instruction_fetch();
+                self.ir = datapath.state.instruction;
+            }
+            MultiCycleState::InstructionDecode => {
+                datapath.state.instruction = self.ir;
+                datapath.coprocessor.set_instruction(self.ir);
+                datapath.stage_instruction_decode();
+                self.a = datapath.state.read_data_1;
+                self.b = datapath.state.read_data_2;
+                self.alu_out = datapath
+                    .state
+                    .pc_plus_4
+                    .wrapping_add(datapath.state.sign_extend << 2);
+            }
+            MultiCycleState::MemoryAddressComputation | MultiCycleState::Execution => {
+                datapath.state.read_data_1 = self.a;
+                datapath.state.read_data_2 = self.b;
+                datapath.stage_execute();
+                self.alu_out = datapath.state.alu_result;
+            }
+            MultiCycleState::MemoryRead => {
+                datapath.state.alu_result = self.alu_out;
+                datapath.stage_memory();
+                self.mdr = datapath.state.memory_data;
+            }
+            MultiCycleState::MemoryReadCompletion => {
+                // The value written to the register is selected from MDR.
+                datapath.state.memory_data = self.mdr;
+                datapath.state.data_result = self.mdr;
+                datapath.stage_writeback();
+            }
+            MultiCycleState::MemoryWrite => {
+                datapath.state.alu_result = self.alu_out;
+                datapath.state.read_data_2 = self.b;
+                datapath.stage_memory();
+                datapath.stage_writeback();
+            }
+            MultiCycleState::AluCompletion => {
+                datapath.state.alu_result = self.alu_out;
+                datapath.stage_memory();
+                datapath.stage_writeback();
+            }
+            MultiCycleState::BranchCompletion | MultiCycleState::JumpCompletion => {
+                datapath.state.read_data_1 = self.a;
+                datapath.state.read_data_2 = self.b;
+                datapath.stage_execute();
+
+                // The branch target was computed into `ALUOut` in the
+                // instruction decode state, leaving the ALU free to compare
+                // the operands.
+                datapath.state.relative_pc_branch = self.alu_out;
+                datapath.stage_memory();
+                datapath.stage_writeback();
+            }
+        }
+
+        self.previous_state = Some(state);
+        self.control_state = self.next_state(state);
+
+        if self.control_state != MultiCycleState::InstructionFetch {
+            self.datapath.registers.pc = self.datapath.state.pc_plus_4;
+        }
+
+        // If the FPU has halted, reflect this in the main unit.
+        if self.datapath.coprocessor.is_halted {
+            self.datapath.is_halted = true;
+        }
+    }
+
+    fn get_register_by_enum(&self, register: Self::RegisterEnum) -> u64 {
+        self.datapath.registers[register]
+    }
+
+    fn get_memory(&self) -> &Self::MemoryType {
+        &self.datapath.memory
+    }
+
+    fn is_halted(&self) -> bool {
+        self.datapath.is_halted()
+    }
+
+    fn reset(&mut self) {
+        self.datapath.reset();
+        *self = Self {
+            datapath: std::mem::take(&mut self.datapath),
+            ..Default::default()
+        };
+    }
+
+    fn next_fetch_address(&self) -> Option<u64> {
+        (self.control_state == MultiCycleState::InstructionFetch)
+            .then_some(self.datapath.registers.pc)
+    }
+
+    fn finished_instruction(&self) -> bool {
+        self.control_state == MultiCycleState::InstructionFetch
+    }
+
+    /// Memory is read in the memory read state and written in the memory
+    /// write state, unless the instruction has raised an exception.
+    fn last_memory_access(&self) -> Option<MemoryAccess<u64>> {
+        let accessed_memory = matches!(
+            self.previous_state,
+            Some(MultiCycleState::MemoryRead | MultiCycleState::MemoryWrite)
+        );
+        if !accessed_memory || self.datapath.system_coprocessor.exception.is_some() {
+            return None;
+        }
+
+        self.datapath.signals.memory_access(self.alu_out)
+    }
+}
+
+impl MipsDatapathModel for MipsMultiCycleDatapath {
+    fn mips_datapath(&self) -> &MipsDatapath {
+        &self.datapath
+    }
+
+    fn mips_datapath_mut(&mut self) -> &mut MipsDatapath {
+        &mut self.datapath
+    }
+
+    /// System calls are performed in the instruction decode state, and
+    /// stores write at most a doubleword at `ALUOut` in the memory write
+    /// state.
+    fn writable_memory(&self) -> Range<u64> {
+        let registers = &self.datapath.registers;
+
+        match self.control_state {
+            MultiCycleState::InstructionDecode => syscall_writable_memory(
+                self.ir,
+                registers[GpRegisterType::V0],
+                registers[GpRegisterType::A0],
+                registers[GpRegisterType::A1],
+            ),
+            MultiCycleState::MemoryWrite => self.alu_out..self.alu_out.saturating_add(8),
+            _ => 0..0,
+        }
+    }
+}
+
+impl MipsMultiCycleDatapath {
+    /// Reset the datapath, load instructions into memory, and un-set the
+    /// `is_halted` flag. If the process fails, an [`Err`] is returned.
+    pub fn initialize(&mut self, instructions: Vec<u32>) -> Result<(), String> {
+        self.reset();
+        self.datapath.initialize(instructions)
+    }
+
+    /// Reset the datapath, load the text and data of a program into memory
+    /// where the memory layout places them, protect the text, and un-set the
+    /// `is_halted` flag. If the process fails, an [`Err`] is returned.
+    pub fn initialize_program(&mut self, text: Vec<u32>, data: Vec<u32>) -> Result<(), String> {
+        self.reset();
+        self.datapath.initialize_program(text, data)
+    }
+
+    /// Describe the state of the control unit and the contents of the
+    /// registers held between clock cycles after the most recent cycle.
+    pub fn generate_report(&self) -> String {
+        let mut report = format!("Control unit after cycle {}:\n", self.cycles);
+
+        if let Some(state) = self.previous_state {
+            report.push_str(&format!(
+                "Last state: {state}\t{}\n",
+                state.register_transfers()
+            ));
+        }
+        report.push_str(&format!(
+            "Next state: {}\t{}\n\n",
+            self.control_state,
+            self.control_state.register_transfers()
+        ));
+
+        report.push_str(&format!(
+            "IR: {:#010x}  MDR: {:#x}  A: {:#x}  B: {:#x}  ALUOut: {:#x}\n",
+            self.ir, self.mdr, self.a, self.b, self.alu_out
+        ));
+
+        report
+    }
+
+    /// Determine the state of the control unit following `state`.
+    fn next_state(&self, state: MultiCycleState) -> MultiCycleState {
+        let signals = &self.datapath.signals;
+
+        match state {
+            MultiCycleState::InstructionFetch => MultiCycleState::InstructionDecode,
+            MultiCycleState::InstructionDecode => {
+                let is_eret =
+                    matches!(self.datapath.instruction, Instruction::Cp0Type(c) if c.sub == SUB_CO);
+
+                if signals.mem_read == MemRead::YesRead || signals.mem_write == MemWrite::YesWrite {
+                    MultiCycleState::MemoryAddressComputation
+                } else if signals.jump != Jump::NoJump || is_eret {
+                    MultiCycleState::JumpCompletion
+                } else if self.datapath.is_branch_or_jump() {
+                    MultiCycleState::BranchCompletion
+                } else {
+                    MultiCycleState::Execution
+                }
+            }
+            MultiCycleState::MemoryAddressComputation => {
+                if signals.mem_read == MemRead::YesRead {
+                    MultiCycleState::MemoryRead
+                } else {
+                    MultiCycleState::MemoryWrite
+                }
+            }
+            MultiCycleState::MemoryRead => MultiCycleState::MemoryReadCompletion,
+            MultiCycleState::Execution => MultiCycleState::AluCompletion,
+            MultiCycleState::MemoryReadCompletion
+            | MultiCycleState::MemoryWrite
+            | MultiCycleState::AluCompletion
+            | MultiCycleState::BranchCompletion
+            | MultiCycleState::JumpCompletion => MultiCycleState::InstructionFetch,
+        }
+    }
+}
@@ -0,0 +1,273 @@
+//! Performance counters, and the cycles per instruction (CPI) they give under
+//! a cost model.
+//!
+//! The datapath counts each instruction as it finishes its WB stage, along
+//! with every stage it runs. The number of cycles is then worked out from the
+//! instruction mix and a [`CycleCostModel`], as in the performance equation:
+//!
+//! ```text
+//! cycles = sum over each class of (instructions in class * cycles for class)
+//! CPI = cycles / instructions
+//! ```
+//!
+//! The multi-cycle and pipelined datapaths count the clock cycles they run,
+//! so their CPI is measured instead, and the cost model is reported alongside
+//! it for comparison.
+
+use super::datapath::Stage;
+
+/// The classes of instructions counted separately.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InstructionClass {
+    /// Integer arithmetic, logical, shift, and comparison instructions.
+    Alu,
+
+    /// Loads, including floating-point loads.
+    Load,
+
+    /// Stores, including floating-point stores.
+    Store,
+
+    /// Branches whose condition was true, including floating-point branches.
+    BranchTaken,
+
+    /// Branches whose condition was false.
+    BranchNotTaken,
+
+    /// Jumps, including jumps to registers and linking jumps.
+    Jump,
+
+    /// Floating-point instructions, other than loads, stores, and branches.
+    Fpu,
+
+    /// System calls, breakpoints, and coprocessor 0 instructions.
+    System,
+}
+
+impl InstructionClass {
+    /// Every class, in the order they are reported.
+    pub const ALL: [Self; 8] = [
+        Self::Alu,
+        Self::Load,
+        Self::Store,
+        Self::BranchTaken,
+        Self::BranchNotTaken,
+        Self::Jump,
+        Self::Fpu,
+        Self::System,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Alu => "ALU",
+            Self::Load => "Load",
+            Self::Store => "Store",
+            Self::BranchTaken => "Branch (taken)",
+            Self::BranchNotTaken => "Branch (not taken)",
+            Self::Jump => "Jump",
+            Self::Fpu => "FPU",
+            Self::System => "System",
+        }
+    }
+}
+
+/// The number of clock cycles an instruction of each class takes.
+///
+/// The default costs are those of the multi-cycle datapath, with
+/// floating-point and system instructions taking as long as ALU instructions.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CycleCostModel {
+    pub alu: u64,
+    pub load: u64,
+    pub store: u64,
+    pub branch_taken: u64,
+    pub branch_not_taken: u64,
+    pub jump: u64,
+    pub fpu: u64,
+    pub system: u64,
+}
+
+impl Default for CycleCostModel {
+    fn default() -> Self {
+        Self {
+            alu: 4,
+            load: 5,
+            store: 4,
+            branch_taken: 3,
+            branch_not_taken: 3,
+            jump: 3,
+            fpu: 4,
+            system: 4,
+        }
+    }
+}
+
+impl CycleCostModel {
+    /// A cost model where every instruction takes a single cycle.
+    pub fn single_cycle() -> Self {
+        Self {
+            alu: 1,
+            load: 1,
+            store: 1,
+            branch_taken: 1,
+            branch_not_taken: 1,
+            jump: 1,
+            fpu: 1,
+            system: 1,
+        }
+    }
+
+    /// The number of cycles an instruction of `class` takes.
+    pub fn cost(&self, class: InstructionClass) -> u64 {
+        match class {
+            InstructionClass::Alu => self.alu,
+            InstructionClass::Load => self.load,
+            InstructionClass::Store => self.store,
+            InstructionClass::BranchTaken => self.branch_taken,
+            InstructionClass::BranchNotTaken => self.branch_not_taken,
+            InstructionClass::Jump => self.jump,
+            InstructionClass::Fpu => self.fpu,
+            InstructionClass::System => self.system,
+        }
+    }
+
+    /// Set the number of cycles an instruction of `class` takes.
+    pub fn set_cost(&mut self, class: InstructionClass, cycles: u64) {
+        let cost = match class {
+            InstructionClass::Alu => &mut self.alu,
+            InstructionClass::Load => &mut self.load,
+            InstructionClass::Store => &mut self.store,
+            InstructionClass::BranchTaken => &mut self.branch_taken,
+            InstructionClass::BranchNotTaken => &mut self.branch_not_taken,
+            InstructionClass::Jump => &mut self.jump,
+            InstructionClass::Fpu => &mut self.fpu,
+            InstructionClass::System => &mut self.system,
+        };
+        *cost = cycles;
+    }
+}
+
+/// Counts of the instructions and stages run by a datapath.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PerformanceCounters {
+    /// The number of instructions finished in each class, in the order of
+    /// [`InstructionClass::ALL`].
+    instructions: [u64; 8],
+
+    /// The number of times each stage was run, in the order of [`Stage`].
+    stages: [u64; 5],
+}
+
+impl PerformanceCounters {
+    /// Count an instruction of `class` as finished.
+    pub fn record_instruction(&mut self, class: InstructionClass) {
+        self.instructions[class as usize] += 1;
+    }
+
+    /// Count a run of `stage`.
+    pub fn record_stage(&mut self, stage: Stage) {
+        self.stages[stage as usize] += 1;
+    }
+
+    /// The number of instructions finished in `class`.
+    pub fn instructions(&self, class: InstructionClass) -> u64 {
+        self.instructions[class as usize]
+    }
+
+    /// The number of instructions finished in every class.
+    pub fn total_instructions(&self) -> u64 {
+        self.instructions.iter().sum()
+    }
+
+    /// The number of times `stage` was run.
+    pub fn stages(&self, stage: Stage) -> u64 {
+        self.stages[stage as usize]
+    }
+
+    /// The number of stages run in total.
+    pub fn total_stages(&self) -> u64 {
+        self.stages.iter().sum()
+    }
+
+    /// The number of cycles the instructions finished would take under
+    /// `model`.
+    pub fn cycles(&self, model: &CycleCostModel) -> u64 {
+        InstructionClass::ALL
+            .iter()
+            .map(|&class| self.instructions(class) * model.cost(class))
+            .sum()
+    }
+
+    /// The average number of cycles per instruction under `model`, or `0.0`
+    /// if no instruction has finished.
+    pub fn cpi(&self, model: &CycleCostModel) -> f64 {
+        self.measured_cpi(self.cycles(model))
+    }
+
+    /// The average number of cycles per instruction if the instructions
+    /// finished took `cycles` in total, or `0.0` if no instruction has
+    /// finished.
+    pub fn measured_cpi(&self, cycles: u64) -> f64 {
+        let instructions = self.total_instructions();
+        if instructions == 0 {
+            0.0
+        } else {
+            cycles as f64 / instructions as f64
+        }
+    }
+
+    /// Describe the instruction mix, stage counts, cycles, and CPI under
+    /// `model`. If the datapath counted the clock cycles it ran as
+    /// `measured_cycles`, the cycles and CPI are those measured, followed by
+    /// those under `model`.
+    pub fn generate_report(&self, model: &CycleCostModel, measured_cycles: Option<u64>) -> String {
+        let instructions = self.total_instructions();
+
+        let mut report = String::from("Class\t\t\tCount\tMix\tCycles each\n");
+        for class in InstructionClass::ALL {
+            let count = self.instructions(class);
+            let mix = if instructions == 0 {
+                0.0
+            } else {
+                count as f64 / instructions as f64
+            };
+
+            report.push_str(&format!(
+                "{:<20}\t{count}\t{:.1}%\t{}\n",
+                class.name(),
+                mix * 100.0,
+                model.cost(class)
+            ));
+        }
+
+        match measured_cycles {
+            Some(cycles) => {
+                report.push_str(&format!(
+                    "\nInstructions: {instructions}  Cycles: {cycles}  CPI: {:.2}\n",
+                    self.measured_cpi(cycles)
+                ));
+                report.push_str(&format!(
+                    "Under the cost model: Cycles: {}  CPI: {:.2}\n",
+                    self.cycles(model),
+                    self.cpi(model)
+                ));
+            }
+            None => report.push_str(&format!(
+                "\nInstructions: {instructions}  Cycles: {}  CPI: {:.2}\n",
+                self.cycles(model),
+                self.cpi(model)
+            )),
+        }
+        report.push_str(&format!(
+            "Stages run: {} (IF: {}, ID: {}, EX: {}, MEM: {}, WB: {})\n",
+            self.total_stages(),
+            self.stages(Stage::InstructionFetch),
+            self.stages(Stage::InstructionDecode),
+            self.stages(Stage::Execute),
+            self.stages(Stage::Memory),
+            self.stages(Stage::WriteBack),
+        ));
+
+        report
+    }
+}
@@ -0,0 +1,787 @@
+//! Implementation of a five-stage pipelined MIPS64 datapath.
+//!
+//! Unlike [`MipsDatapath`], which walks one instruction through all five
+//! stages before starting the next, this datapath keeps up to five
+//! instructions in flight at once. Each instruction moves between stages
+//! through the IF/ID, ID/EX, EX/MEM, and MEM/WB pipeline registers, and
+//! every stage does its work for a different instruction on each clock cycle.
+//!
+//! The work of each stage is the same as in [`MipsDatapath`]. An instruction
+//! is loaded into a [`MipsDatapath`] from its pipeline register, the stage is
+//! run, and the resulting data lines and control signals are latched into the
+//! next pipeline register. All instructions share the registers, memory, and
+//! coprocessors of that datapath.
+//!
+//! # Hazards
+//!
+//! - Registers are written in the first half of a cycle and read in the
+//!   second half, so an instruction in WB never conflicts with one in ID.
+//! - With [`MipsPipelinedDatapath::forwarding`] on, the forwarding unit
+//!   passes results from the EX/MEM and MEM/WB pipeline registers to the
+//!   ALU inputs. The hazard detection unit only stalls when a value is not
+//!   ready in time, such as a load followed by an instruction that uses the
+//!   loaded register.
+//! - With forwarding off, an instruction stalls in ID until every older
+//!   instruction that writes one of its source registers has reached WB.
+//! - Branches and jumps are resolved in the MEM stage, as they are in
+//!   [`MipsDatapath`]. When one is taken, the three instructions fetched
+//!   after it are flushed.
+//! - `syscall`, `break`, and instructions that use a coprocessor wait in ID
+//!   until all older instructions have left EX and MEM. These instructions
+//!   access state that is not covered by the forwarding unit. This includes
+//!   `movf` and `movt`, which read the floating-point condition codes.
+//! - A conditional move is assumed to write its destination register until
+//!   its condition is checked in EX.
+//!
+//! # Exceptions
+//!
+//! Exceptions are precise. An exception raised in any stage is recorded in
+//! the pipeline register and only taken once the instruction reaches WB,
+//! at which point all younger instructions are flushed. An exception raised
+//! by an instruction that is flushed, such as one fetched after a taken
+//! branch, is never taken.
+//!
+//! # Differences Compared to [`MipsDatapath`]
+//!
+//! - There are no branch delay slots. [`MipsDatapath::branch_delay_slots`]
+//!   is turned off when the datapath is initialized.
+//! - The `current_stage` of the inner datapath is not used. Each call to
+//!   [`Datapath::execute_stage()`] runs one clock cycle, advancing every stage.
+
+use std::fmt;
+use std::ops::Range;
+
+use super::super::datapath::{Datapath, MemoryAccess};
+use super::constants::*;
+use super::control_signals::{floating_point::*, *};
+use super::coprocessor::FpuState;
+use super::datapath::{DatapathState, MipsDatapath};
+use super::datapath_model::{syscall_writable_memory, MipsDatapathModel};
+use super::datapath_signals::*;
+use super::instruction::*;
+use super::memory::{Memory, MemoryFault};
+use super::registers::GpRegisterType;
+use super::system_coprocessor::ExceptionCode;
+
+/// An implementation of a five-stage pipelined datapath for the MIPS64 ISA.
+#[derive(Clone, PartialEq)]
+pub struct MipsPipelinedDatapath {
+    /// The datapath holding the registers, memory, and coprocessors shared by
+    /// all instructions in the pipeline. Each stage is run on this datapath.
+    ///
+    /// Between clock cycles, the PC of this datapath is the address of the
+    /// next instruction to fetch.
+    pub datapath: MipsDatapath,
+
+    /// Whether the forwarding unit is enabled. This setting is kept when the
+    /// datapath is reset or initialized.
+    pub forwarding: bool,
+
+    /// The IF/ID pipeline register.
+    pub if_id: Option<PipelineInstruction>,
+
+    /// The ID/EX pipeline register.
+    pub id_ex: Option<PipelineInstruction>,
+
+    /// The EX/MEM pipeline register.
+    pub ex_mem: Option<PipelineInstruction>,
+
+    /// The MEM/WB pipeline register.
+    pub mem_wb: Option<PipelineInstruction>,
+
+    /// The signals set by the hazard detection and forwarding units during
+    /// the most recent clock cycle.
+    pub hazard_signals: HazardSignals,
+
+    /// Whether an instruction left the WB stage during the most recent clock
+    /// cycle.
+    pub retired: bool,
+
+    /// The number of clock cycles run since the datapath was initialized.
+    pub cycles: u64,
+}
+
+/// An instruction held in a pipeline register, along with the data lines and
+/// control signals it carries into the next stage. An empty pipeline register
+/// (a bubble) is represented by [`None`].
+#[derive(Clone, Default, PartialEq)]
+pub struct PipelineInstruction {
+    /// The address of the instruction.
+    pub pc: u64,
+
+    pub instruction: Instruction,
+    pub signals: ControlSignals,
+    pub datapath_signals: DatapathSignals,
+    pub state: DatapathState,
+    pub fpu_signals: FpuControlSignals,
+    pub fpu_state: FpuState,
+
+    /// The exception raised by the instruction, if any, along with the bad
+    /// address for address errors. The exception is taken in the WB stage.
+    pub exception: Option<(ExceptionCode, Option<u64>)>,
+
+    /// A description of the exception raised by the instruction, if the
+    /// datapath gave one.
+    pub exception_message: Option<String>,
+
+    /// The access to memory refused for the instruction, if that is what
+    /// raised its exception.
+    pub memory_fault: Option<MemoryFault>,
+}
+
+/// The outputs of the hazard detection and forwarding units.
+#[derive(Clone, Default, PartialEq)]
+pub struct HazardSignals {
+    /// The instruction in ID was held back, and a bubble was sent to EX.
+    pub stall: bool,
+
+    /// The instructions in IF, ID, and EX were flushed due to a taken branch,
+    /// a jump, an exception, or `eret`.
+    pub flush: bool,
+
+    /// The source of the first ALU operand (`rs`) for the instruction in EX.
+    pub forward_a: ForwardingPath,
+
+    /// The source of the second ALU operand (`rt`) for the instruction in EX.
+    pub forward_b: ForwardingPath,
+}
+
+/// Where the forwarding unit takes a register value from.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ForwardingPath {
+    /// The value read from the register file in the ID stage.
+    #[default]
+    RegisterFile,
+
+    /// The result held in the EX/MEM pipeline register.
+    ExMem,
+
+    /// The result held in the MEM/WB pipeline register.
+    MemWb,
+}
+
+impl fmt::Display for ForwardingPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::RegisterFile => "register file",
+            Self::ExMem => "EX/MEM",
+            Self::MemWb => "MEM/WB",
+        })
+    }
+}
+
+impl Default for MipsPipelinedDatapath {
+    fn default() -> Self {
+        Self {
+            datapath: MipsDatapath::default(),
+            forwarding: true,
+            if_id: None,
+            id_ex: None,
+            ex_mem: None,
+            mem_wb: None,
+            hazard_signals: HazardSignals::default(),
+            retired: false,
+            cycles: 0,
+        }
+    }
+}
+
+impl Datapath for MipsPipelinedDatapath {
+    type RegisterData = u64;
+    type RegisterEnum = GpRegisterType;
+    type MemoryType = Memory;
+
+    /// Run clock cycles until an instruction leaves the WB stage.
+    fn execute_instruction(&mut self) {
+        while !self.datapath.is_halted() {
+            self.execute_stage();
+            if self.retired {
+                break;
+            }
+        }
+    }
+
+    /// Run a single clock cycle, advancing every stage of the pipeline.
+    fn execute_stage(&mut self) {
+        if !self.datapath.is_halted() {
+            self.retired = self.clock();
+        }
+    }
+
+    fn get_register_by_enum(&self, register: Self::RegisterEnum) -> u64 {
+        self.datapath.registers[register]
+    }
+
+    fn get_memory(&self) -> &Self::MemoryType {
+        &self.datapath.memory
+    }
+
+    fn is_halted(&self) -> bool {
+        self.datapath.is_halted()
+    }
+
+    fn reset(&mut self) {
+        // The forwarding setting belongs to the environment around the
+        // datapath, so it is kept across resets.
+        self.datapath.reset();
+        *self = Self {
+            datapath: std::mem::take(&mut self.datapath),
+            forwarding: self.forwarding,
+            ..Default::default()
+        };
+    }
+
+    /// The instruction at the PC is fetched on the next clock cycle, unless
+    /// the instruction in ID stalls. A branch resolved during that cycle may
+    /// still flush it.
+    fn next_fetch_address(&self) -> Option<u64> {
+        (!self.detect_hazard()).then_some(self.datapath.registers.pc)
+    }
+
+    fn finished_instruction(&self) -> bool {
+        self.retired
+    }
+
+    /// The instruction in the MEM/WB pipeline register is the one that was
+    /// in the MEM stage during the most recent clock cycle.
+    fn last_memory_access(&self) -> Option<MemoryAccess<u64>> {
+        let instruction = self.mem_wb.as_ref()?;
+        if instruction.exception.is_some() {
+            return None;
+        }
+
+        instruction
+            .signals
+            .memory_access(instruction.state.alu_result)
+    }
+}
+
+impl MipsDatapathModel for MipsPipelinedDatapath {
+    fn mips_datapath(&self) -> &MipsDatapath {
+        &self.datapath
+    }
+
+    fn mips_datapath_mut(&mut self) -> &mut MipsDatapath {
+        &mut self.datapath
+    }
+
+    /// A store in the EX/MEM pipeline register writes at most a doubleword
+    /// in the MEM stage. A system call in the IF/ID pipeline register is
+    /// performed in the ID stage, after the instruction in the MEM/WB
+    /// pipeline register has written its result. A system call waits in ID
+    /// until EX and MEM are empty, so both cannot happen in the same cycle.
+    fn writable_memory(&self) -> Range<u64> {
+        if let Some(store) = self.ex_mem.as_ref().filter(|instruction| {
+            instruction.exception.is_none() && instruction.signals.mem_write == MemWrite::YesWrite
+        }) {
+            let start = store.state.alu_result;
+            return start..start.saturating_add(8);
+        }
+
+        match &self.if_id {
+            Some(instruction) => syscall_writable_memory(
+                instruction.state.instruction,
+                self.register_after_writeback(GpRegisterType::V0),
+                self.register_after_writeback(GpRegisterType::A0),
+                self.register_after_writeback(GpRegisterType::A1),
+            ),
+            None => 0..0,
+        }
+    }
+}
+
+impl MipsPipelinedDatapath {
+    // ===================== General Functions =====================
+    /// Reset the datapath, load instructions into memory, and un-set the
+    /// `is_halted` flag. If the process fails, an [`Err`] is returned.
+    pub fn initialize(&mut self, instructions: Vec<u32>) -> Result<(), String> {
+        self.reset();
+        self.datapath.branch_delay_slots = false;
+        self.datapath.initialize(instructions)
+    }
+
+    /// Reset the datapath, load the text and data of a program into memory
+    /// where the memory layout places them, protect the text, and un-set the
+    /// `is_halted` flag. If the process fails, an [`Err`] is returned.
+    pub fn initialize_program(&mut self, text: Vec<u32>, data: Vec<u32>) -> Result<(), String> {
+        self.reset();
+        self.datapath.branch_delay_slots = false;
+        self.datapath.initialize_program(text, data)
+    }
+
+    /// Describe the contents of the pipeline registers and the outputs of
+    /// the hazard detection and forwarding units after the most recent clock
+    /// cycle.
+    pub fn generate_report(&self) -> String {
+        let mut report = format!("Pipeline registers after cycle {}:\n", self.cycles);
+
+        let registers = [
+            ("IF/ID", &self.if_id),
+            ("ID/EX", &self.id_ex),
+            ("EX/MEM", &self.ex_mem),
+            ("MEM/WB", &self.mem_wb),
+        ];
+        for (name, register) in registers {
+            let Some(instruction) = register else {
+                report.push_str(&format!("{name}\t(empty)\n"));
+                continue;
+            };
+
+            let state = &instruction.state;
+            report.push_str(&format!(
+                "{name}\tPC: {:#010x}  Instruction: {:#010x}",
+                instruction.pc, state.instruction
+            ));
+            match name {
+                "ID/EX" => report.push_str(&format!(
+                    "  A: {:#x}  B: {:#x}  Immediate: {:#x}",
+                    state.read_data_1, state.read_data_2, state.sign_extend
+                )),
+                "EX/MEM" => report.push_str(&format!(
+                    "  ALU result: {:#x}  Write data: {:#x}",
+                    state.alu_result, state.read_data_2
+                )),
+                "MEM/WB" => report.push_str(&format!(
+                    "  ALU result: {:#x}  Memory data: {:#x}",
+                    state.alu_result, state.memory_data
+                )),
+                _ => (),
+            }
+            if let Some((code, _)) = instruction.exception {
+                report.push_str(&format!("  Exception: {code:?}"));
+            }
+            report.push('\n');
+        }
+
+        let signals = &self.hazard_signals;
+        report.push_str(&format!(
+            "\nStall: {}  Flush: {}\n",
+            yes_or_no(signals.stall),
+            yes_or_no(signals.flush)
+        ));
+        if self.forwarding {
+            report.push_str(&format!(
+                "Forward A: {}  Forward B: {}\n",
+                signals.forward_a, signals.forward_b
+            ));
+        } else {
+            report.push_str("Forwarding: off\n");
+        }
+
+        report
+    }
+
+    /// Run a single clock cycle. Returns whether an instruction finished
+    /// the WB stage.
+    fn clock(&mut self) -> bool {
+        self.cycles += 1;
+
+        let fetch_pc = self.datapath.registers.pc;
+        let stall = self.detect_hazard();
+        let forward_a = self.forwarding_path(|i| i.state.rs);
+        let forward_b = self.forwarding_path(|i| i.state.rt);
+        let forwarded_a = self.forwarded_value(forward_a);
+        let forwarded_b = self.forwarded_value(forward_b);
+
+        self.hazard_signals = HazardSignals {
+            stall,
+            flush: false,
+            forward_a,
+            forward_b,
+        };
+
+        // Stage 5 of 5: Writeback (WB)
+        let mut redirect = None;
+        let mut retired = false;
+        if let Some(instruction) = self.mem_wb.take() {
+            redirect = self.writeback(instruction);
+            retired = true;
+        }
+
+        if self.datapath.is_halted() {
+            return retired;
+        }
+
+        // An exception or `eret` redirects execution once its instruction
+        // leaves the pipeline, so the instruction about to access memory is
+        // discarded along with the younger ones.
+        if redirect.is_some() {
+            self.ex_mem = None;
+            self.flush();
+        }
+
+        // Stage 4 of 5: Memory (MEM)
+        if let Some(mut instruction) = self.ex_mem.take() {
+            self.run_stage(&mut instruction, MipsDatapath::stage_memory);
+
+            let is_taken = instruction.datapath_signals.general_branch == GeneralBranch::YesBranch
+                || instruction.signals.jump != Jump::NoJump;
+            if is_taken && instruction.exception.is_none() {
+                redirect = Some(instruction.state.new_pc);
+                self.flush();
+            }
+
+            self.mem_wb = Some(instruction);
+        }
+
+        // Stage 3 of 5: Execute (EX)
+        if let Some(mut instruction) = self.id_ex.take() {
+            // The registers were read in ID, possibly before an older
+            // instruction wrote them, so the strict mode check is repeated
+            // with the operands as the ALU will see them.
+            let rs_value =
+                forwarded_a.unwrap_or(self.datapath.registers.gpr[instruction.state.rs as usize]);
+            let rt_value =
+                forwarded_b.unwrap_or(self.datapath.registers.gpr[instruction.state.rt as usize]);
+
+            if let Some(value) = forwarded_a {
+                instruction.state.read_data_1 = value;
+            }
+            if let Some(value) = forwarded_b {
+                instruction.state.read_data_2 = value;
+            }
+            if let RegWidth::Word = instruction.signals.reg_width {
+                instruction.state.read_data_1 = instruction.state.read_data_1 as u32 as u64;
+                instruction.state.read_data_2 = instruction.state.read_data_2 as u32 as u64;
+            }
+
+            self.run_stage(&mut instruction, |datapath| {
+                // Variable shifts take their shift amount from `rs`, which
+                // may have just been forwarded.
+                datapath.set_alu_control();
+                datapath.check_word_operands(rs_value, rt_value);
+                datapath.stage_execute();
+            });
+            self.ex_mem = Some(instruction);
+        }
+
+        // Stage 2 of 5: Instruction Decode (ID)
+        if !stall {
+            if let Some(mut instruction) = self.if_id.take() {
+                self.run_stage(&mut instruction, MipsDatapath::stage_instruction_decode);
+                self.id_ex = Some(instruction);
+            }
+        }
+
+        // A system call may halt the datapath in ID.
+        if self.datapath.is_halted() {
+            self.datapath.registers.pc = fetch_pc;
+            return retired;
+        }
+
+        // Stage 1 of 5: Instruction Fetch (IF)
+        self.datapath.registers.pc = match redirect {
+            Some(target) => target,
+            None if stall => fetch_pc,
+            None => {
+                let mut instruction = PipelineInstruction {
+                    pc: fetch_pc,
+                    ..Default::default()
+                };
+                self.run_stage(&mut instruction, MipsDatapath::stage_instruction_fetch);
+                self.if_id = Some(instruction);
+
+                fetch_pc.wrapping_add(4)
+            }
+        };
+
+        // If the FPU has halted, reflect this in the main unit.
+        if self.datapath.coprocessor.is_halted && !self.datapath.is_halted {
+            self.datapath.error_message = self.datapath.coprocessor.error_message.clone();
+            self.datapath.is_halted = true;
+        }
+
+        retired
+    }
+
+    /// Run the WB stage for an instruction, taking its exception if it raised
+    /// one. Returns the address to continue from if the instruction changes
+    /// the flow of execution.
+    fn writeback(&mut self, instruction: PipelineInstruction) -> Option<u64> {
+        self.load(&instruction);
+
+        if let Some((code, bad_vaddr)) = instruction.exception {
+            self.datapath.raise_exception(code, bad_vaddr);
+        }
+        self.datapath.system_coprocessor.exception_message = instruction.exception_message;
+        self.datapath.memory_fault = instruction.memory_fault;
+
+        self.datapath.stage_writeback();
+
+        if self.datapath.system_coprocessor.exception.is_some() {
+            return self.datapath.system_coprocessor.exception_handler;
+        }
+
+        match instruction.instruction {
+            Instruction::Cp0Type(c) if c.sub == SUB_CO => Some(self.datapath.state.new_pc),
+            _ => None,
+        }
+    }
+
+    /// Flush the instructions that have not yet reached the MEM stage.
+    fn flush(&mut self) {
+        self.if_id = None;
+        self.id_ex = None;
+        self.hazard_signals.flush = true;
+        self.hazard_signals.stall = false;
+    }
+
+    /// Run a stage of the inner datapath for an instruction.
+    ///
+    /// An exception raised by the instruction is recorded in the instruction
+    /// instead of being taken, as the instruction may still be flushed.
+    fn run_stage(
+        &mut self,
+        instruction: &mut PipelineInstruction,
+        stage: impl Fn(&mut MipsDatapath),
+    ) {
+        self.load(instruction);
+
+        let system_coprocessor = self.datapath.system_coprocessor.clone();
+        let is_halted = self.datapath.is_halted;
+        let memory_fault = self.datapath.memory_fault.clone();
+        self.datapath.system_coprocessor.exception = instruction.exception.map(|(code, _)| code);
+
+        stage(&mut self.datapath);
+
+        // The message and memory fault of an exception taken earlier are
+        // kept until the next instruction leaves the pipeline.
+        let message = std::mem::replace(
+            &mut self.datapath.system_coprocessor.exception_message,
+            system_coprocessor.exception_message.clone(),
+        );
+        let fault = std::mem::replace(&mut self.datapath.memory_fault, memory_fault);
+
+        if let (None, Some(code)) = (
+            instruction.exception,
+            self.datapath.system_coprocessor.exception,
+        ) {
+            let bad_vaddr = match code {
+                ExceptionCode::AddressErrorLoad
+                | ExceptionCode::AddressErrorStore
+                | ExceptionCode::TlbModified
+                | ExceptionCode::TlbReadInhibit
+                | ExceptionCode::TlbExecuteInhibit => {
+                    Some(self.datapath.system_coprocessor.bad_vaddr)
+                }
+                _ => None,
+            };
+            instruction.exception = Some((code, bad_vaddr));
+            instruction.exception_message = message;
+            instruction.memory_fault = fault;
+
+            self.datapath.system_coprocessor = system_coprocessor;
+            self.datapath.is_halted = is_halted;
+        }
+        self.datapath.system_coprocessor.exception = None;
+
+        self.store(instruction);
+    }
+
+    /// Load the contents of a pipeline register into the inner datapath.
+    fn load(&mut self, instruction: &PipelineInstruction) {
+        self.datapath.registers.pc = instruction.pc;
+        self.datapath.instruction = instruction.instruction.clone();
+        self.datapath.signals = instruction.signals.clone();
+        self.datapath.datapath_signals = instruction.datapath_signals.clone();
+        self.datapath.state = instruction.state.clone();
+
+        self.datapath.coprocessor.signals = instruction.fpu_signals.clone();
+        self.datapath.coprocessor.state = instruction.fpu_state.clone();
+        self.datapath
+            .coprocessor
+            .set_instruction(instruction.fpu_state.instruction);
+    }
+
+    /// Latch the contents of the inner datapath into a pipeline register.
+    fn store(&self, instruction: &mut PipelineInstruction) {
+        instruction.instruction = self.datapath.instruction.clone();
+        instruction.signals = self.datapath.signals.clone();
+        instruction.datapath_signals = self.datapath.datapath_signals.clone();
+        instruction.state = self.datapath.state.clone();
+        instruction.fpu_signals = self.datapath.coprocessor.signals.clone();
+        instruction.fpu_state = self.datapath.coprocessor.state.clone();
+    }
+
+    /// The value of a general-purpose register once the instruction in the
+    /// MEM/WB pipeline register has written its result.
+    fn register_after_writeback(&self, register: GpRegisterType) -> u64 {
+        let producer = self
+            .mem_wb
+            .as_ref()
+            .filter(|producer| producer.writes_to(register as u32));
+
+        match producer {
+            None => self.datapath.registers[register],
+            Some(producer) => match producer.instruction {
+                Instruction::Cp0Type(c) => {
+                    self.datapath.system_coprocessor.read_register(c.rd, c.sel) as i32 as u64
+                }
+                _ if producer.fpu_signals.data_write == DataWrite::YesWrite => {
+                    producer.fpu_state.data_writeback
+                }
+                _ => producer.state.data_result,
+            },
+        }
+    }
+
+    // ================== Hazard Detection Unit ==================
+    /// Determine whether the instruction in ID must stall this cycle.
+    fn detect_hazard(&self) -> bool {
+        let Some(consumer) = &self.if_id else {
+            return false;
+        };
+        let Ok(instruction) = Instruction::try_from(consumer.state.instruction) else {
+            return false;
+        };
+
+        if is_serializing(&instruction) {
+            return self.id_ex.is_some() || self.ex_mem.is_some();
+        }
+
+        source_registers(&instruction, self.datapath.release_6)
+            .into_iter()
+            .filter(|&register| register != 0)
+            .any(|register| {
+                // The youngest older instruction writing the register is the
+                // one its value will be forwarded from.
+                if let Some(producer) = self.id_ex.as_ref().filter(|p| p.writes_to(register)) {
+                    !self.forwarding || !producer.result_ready_after_execute()
+                } else if let Some(producer) =
+                    self.ex_mem.as_ref().filter(|p| p.writes_to(register))
+                {
+                    !self.forwarding || !producer.result_ready_after_memory()
+                } else {
+                    false
+                }
+            })
+    }
+
+    // ===================== Forwarding Unit =====================
+    /// Determine where the instruction in ID/EX should take the value of the
+    /// register selected by `register` from.
+    fn forwarding_path(&self, register: impl Fn(&PipelineInstruction) -> u32) -> ForwardingPath {
+        let Some(consumer) = &self.id_ex else {
+            return ForwardingPath::RegisterFile;
+        };
+        let register = register(consumer);
+
+        if !self.forwarding || register == 0 {
+            ForwardingPath::RegisterFile
+        } else if matches!(&self.ex_mem, Some(p) if p.writes_to(register)) {
+            ForwardingPath::ExMem
+        } else if matches!(&self.mem_wb, Some(p) if p.writes_to(register)) {
+            ForwardingPath::MemWb
+        } else {
+            ForwardingPath::RegisterFile
+        }
+    }
+
+    /// Get the value to forward along the given path, if any.
+    fn forwarded_value(&self, path: ForwardingPath) -> Option<u64> {
+        match path {
+            ForwardingPath::RegisterFile => None,
+            ForwardingPath::ExMem => self.ex_mem.as_ref().and_then(|p| p.execute_result()),
+            ForwardingPath::MemWb => self
+                .mem_wb
+                .as_ref()
+                .filter(|p| p.result_ready_after_memory())
+                .map(|p| p.state.data_result),
+        }
+    }
+}
+
+impl PipelineInstruction {
+    /// Returns whether the instruction will write to the general-purpose
+    /// register `register`. A conditional move is assumed to write until it
+    /// has finished the EX stage.
+    pub fn writes_to(&self, register: u32) -> bool {
+        if self.exception.is_some() || register == 0 {
+            return false;
+        }
+
+        if let Instruction::Cp0Type(c) = self.instruction {
+            return c.sub == SUB_MF && c.rt as u32 == register;
+        }
+
+        if self.signals.reg_write == RegWrite::NoWrite
+            || self.datapath_signals.cond_move == CondMove::NoMove
+        {
+            return false;
+        }
+
+        let destination = match self.signals.reg_dst {
+            RegDst::Reg1 => self.state.rs,
+            RegDst::Reg2 => self.state.rt,
+            RegDst::Reg3 => self.state.rd,
+            RegDst::ReturnRegister => 31,
+        };
+
+        destination == register
+    }
+
+    /// Returns whether the value this instruction writes to a register is
+    /// known once it has finished the EX stage.
+    fn result_ready_after_execute(&self) -> bool {
+        self.signals.mem_to_reg != MemToReg::UseMemory && self.result_ready_after_memory()
+    }
+
+    /// Returns whether the value this instruction writes to a register is
+    /// known once it has finished the MEM stage. Values from a coprocessor
+    /// are only known in WB.
+    fn result_ready_after_memory(&self) -> bool {
+        self.fpu_signals.data_write == DataWrite::NoWrite
+            && !matches!(self.instruction, Instruction::Cp0Type(_))
+    }
+
+    /// The value this instruction writes to a register, if it is known once
+    /// it has finished the EX stage.
+    fn execute_result(&self) -> Option<u64> {
+        if !self.result_ready_after_execute() {
+            return None;
+        }
+
+        match self.signals.mem_to_reg {
+            MemToReg::UseAlu => Some(self.state.alu_result),
+            MemToReg::UsePcPlusFour => Some(self.state.pc_plus_4),
+            MemToReg::UsePcPlusEight => Some(self.state.pc_plus_4 + 4),
+            MemToReg::UseMemory => None,
+        }
+    }
+}
+
+/// Describe a signal in a report.
+fn yes_or_no(value: bool) -> &'static str {
+    if value {
+        "yes"
+    } else {
+        "no"
+    }
+}
+
+/// Returns whether an instruction must wait in ID until all older
+/// instructions have left EX and MEM.
+fn is_serializing(instruction: &Instruction) -> bool {
+    match instruction {
+        Instruction::RType(r) => r.funct == FUNCT_MOVCI,
+        Instruction::IType(_) | Instruction::JType(_) | Instruction::CompactBranchType(_) => false,
+        _ => true,
+    }
+}
+
+/// The general-purpose registers read by an instruction that is not
+/// serializing. Unused entries are `0`. In release 6 mode, the opcodes of
+/// `addi` and `daddi` are compact branches reading both registers.
+fn source_registers(instruction: &Instruction, release_6: bool) -> [u32; 2] {
+    match instruction {
+        Instruction::RType(r) => [r.rs as u32, r.rt as u32],
+        Instruction::IType(i) => match i.op {
+            OPCODE_POP10 | OPCODE_POP30 if release_6 => [i.rs as u32, i.rt as u32],
+            OPCODE_SB | OPCODE_SH | OPCODE_SW | OPCODE_SD | OPCODE_BEQ | OPCODE_BNE
+            | OPCODE_POP26 | OPCODE_POP27 => [i.rs as u32, i.rt as u32],
+            _ => [i.rs as u32, 0],
+        },
+        Instruction::CompactBranchType(b) => [b.rs as u32, 0],
+        _ => [0, 0],
+    }
+}
@@ -0,0 +1,132 @@
+//! System call services and the interface used to perform their I/O.
+//!
+//! As in the SPIM and MARS simulators, the `syscall` instruction selects a
+//! service based on the value stored in `$v0`. Arguments are passed through
+//! `$a0`, `$a1`, and `$f12`, and results are returned in `$v0`.
+//!
+//! Any input or output performed by a service goes through a [`SyscallHost`].
+//! This allows the user interface and tests to each supply their own
+//! implementation.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+/// A system call service, identified by the value in `$v0`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SyscallService {
+    /// Print the integer stored in `$a0`.
+    PrintInt = 1,
+
+    /// Print the single-precision floating-point value stored in `$f12`.
+    PrintFloat = 2,
+
+    /// Print the double-precision floating-point value stored in `$f12`.
+    PrintDouble = 3,
+
+    /// Print the null-terminated string starting at the address in `$a0`.
+    PrintString = 4,
+
+    /// Read an integer into `$v0`.
+    ReadInt = 5,
+
+    /// Read a string into the buffer at the address in `$a0`, reading at
+    /// most `$a1 - 1` characters. The string is always null-terminated.
+    ReadString = 8,
+
+    /// Allocate `$a0` bytes on the heap, returning the address of the
+    /// allocated memory in `$v0`.
+    Sbrk = 9,
+
+    /// Halt the program.
+    Exit = 10,
+
+    /// Halt the program with the exit code stored in `$a0`.
+    Exit2 = 17,
+}
+
+impl SyscallService {
+    /// Get the service selected by the given value of `$v0`.
+    ///
+    /// Returns [`None`] if the service is not supported.
+    pub fn from_code(code: u64) -> Option<Self> {
+        match code {
+            1 => Some(Self::PrintInt),
+            2 => Some(Self::PrintFloat),
+            3 => Some(Self::PrintDouble),
+            4 => Some(Self::PrintString),
+            5 => Some(Self::ReadInt),
+            8 => Some(Self::ReadString),
+            9 => Some(Self::Sbrk),
+            10 => Some(Self::Exit),
+            17 => Some(Self::Exit2),
+            _ => None,
+        }
+    }
+}
+
+/// The environment that system call services perform input and output with.
+pub trait SyscallHost {
+    /// Display text written by one of the print services.
+    fn print(&mut self, text: &str);
+
+    /// Read an integer for the `read_int` service.
+    ///
+    /// Returns [`None`] if no valid input is available.
+    fn read_int(&mut self) -> Option<i64>;
+
+    /// Read a line of text for the `read_string` service. The returned
+    /// string should not contain the trailing newline.
+    ///
+    /// Returns [`None`] if no input is available.
+    fn read_string(&mut self) -> Option<String>;
+}
+
+/// A [`SyscallHost`] that collects output into a string and reads input
+/// from a queue of lines.
+///
+/// This is the host used by a datapath unless another is supplied.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BufferedSyscallHost {
+    /// Everything printed by the program so far.
+    pub output: String,
+
+    /// Lines of input that have yet to be read by the program.
+    pub input: VecDeque<String>,
+}
+
+impl SyscallHost for BufferedSyscallHost {
+    fn print(&mut self, text: &str) {
+        self.output.push_str(text);
+    }
+
+    fn read_int(&mut self) -> Option<i64> {
+        self.input.pop_front()?.trim().parse().ok()
+    }
+
+    fn read_string(&mut self) -> Option<String> {
+        self.input.pop_front()
+    }
+}
+
+/// A shared handle to the [`SyscallHost`] used by a datapath.
+///
+/// Two handles are considered equal if they refer to the same host.
+#[derive(Clone)]
+pub struct SyscallHostHandle(pub Rc<RefCell<dyn SyscallHost>>);
+
+impl Default for SyscallHostHandle {
+    fn default() -> Self {
+        Self(Rc::new(RefCell::new(BufferedSyscallHost::default())))
+    }
+}
+
+impl PartialEq for SyscallHostHandle {
+    fn eq(&self, other: &Self) -> bool {
+        // Only compare the addresses of the hosts, not their vtables.
+        std::ptr::eq(
+            Rc::as_ptr(&self.0) as *const u8,
+            Rc::as_ptr(&other.0) as *const u8,
+        )
+    }
+}
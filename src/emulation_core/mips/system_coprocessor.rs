@@ -0,0 +1,164 @@
+//! Implementation of a MIPS64 system control coprocessor (CP0).
+//!
+//! Only the registers needed to handle exceptions are implemented: `BadVAddr`,
+//! `Status`, `Cause`, and `EPC`. Reading any other CP0 register returns 0, and
+//! writing to one has no effect.
+
+/// Register number of the `BadVAddr` register.
+pub const CP0_BADVADDR: u8 = 8;
+/// Register number of the `Status` register.
+pub const CP0_STATUS: u8 = 12;
+/// Register number of the `Cause` register.
+pub const CP0_CAUSE: u8 = 13;
+/// Register number of the `EPC` register.
+pub const CP0_EPC: u8 = 14;
+
+/// The exception level (`EXL`) bit of the `Status` register. This is set
+/// while an exception is being handled.
+pub const STATUS_EXL: u64 = 1 << 1;
+
+/// The `ExcCode` field of the `Cause` register, in bits 6..2.
+pub const CAUSE_EXC_CODE_MASK: u64 = 0x1F << 2;
+
+/// The branch delay (`BD`) bit of the `Cause` register. This is set if the
+/// exception was raised by the instruction in a branch delay slot.
+pub const CAUSE_BD: u64 = 1 << 31;
+
+/// The cause of an exception, as stored in the `ExcCode` field of the
+/// `Cause` register.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ExceptionCode {
+    /// A store to memory that cannot be written to (`Mod`).
+    TlbModified = 1,
+
+    /// Address error on an instruction fetch or a load (`AdEL`).
+    AddressErrorLoad = 4,
+
+    /// Address error on a store (`AdES`).
+    AddressErrorStore = 5,
+
+    /// A `syscall` instruction requested a service that is not supported (`Sys`).
+    Syscall = 8,
+
+    /// A `break` instruction was executed (`Bp`).
+    Breakpoint = 9,
+
+    /// The instruction is not supported by this datapath (`RI`).
+    ReservedInstruction = 10,
+
+    /// An `add`, `addi`, `dadd`, `daddi`, `sub`, or `dsub` instruction
+    /// overflowed (`Ov`).
+    IntegerOverflow = 12,
+
+    /// A floating-point instruction raised an IEEE 754 exception that is
+    /// enabled in the FCSR (`FPE`).
+    FloatingPoint = 15,
+
+    /// A load from memory that cannot be read (`TLBRI`).
+    TlbReadInhibit = 19,
+
+    /// An instruction fetch from memory that cannot be executed (`TLBXI`).
+    TlbExecuteInhibit = 20,
+}
+
+/// An implementation of the system control coprocessor for the MIPS64 ISA.
+#[derive(Clone, Default, PartialEq)]
+pub struct MipsSystemCoprocessor {
+    /// The address that caused the most recent address error.
+    pub bad_vaddr: u64,
+    pub status: u64,
+    pub cause: u64,
+
+    /// The address of the instruction that caused the most recent exception.
+    pub epc: u64,
+
+    /// The address execution continues from when an exception is raised.
+    ///
+    /// If this is [`None`], an exception halts the datapath instead.
+    pub exception_handler: Option<u64>,
+
+    /// The exception raised by the instruction currently in the datapath,
+    /// if any.
+    pub exception: Option<ExceptionCode>,
+
+    /// A description of the exception raised by the instruction currently
+    /// in the datapath, if the datapath gave one.
+    pub exception_message: Option<String>,
+}
+
+impl MipsSystemCoprocessor {
+    /// Record an exception raised by the instruction at address `pc`.
+    ///
+    /// `bad_vaddr` should be provided for address errors. `in_delay_slot`
+    /// sets `Cause.BD`, where `pc` should then be the address of the branch.
+    /// Only the first exception raised by an instruction is recorded. If an
+    /// exception is already being handled (`Status.EXL` is set), `EPC` and
+    /// `Cause.BD` are left as-is.
+    pub fn raise_exception(
+        &mut self,
+        code: ExceptionCode,
+        pc: u64,
+        in_delay_slot: bool,
+        bad_vaddr: Option<u64>,
+    ) {
+        if self.exception.is_some() {
+            return;
+        }
+
+        self.exception = Some(code);
+        self.cause = (self.cause & !CAUSE_EXC_CODE_MASK) | ((code as u64) << 2);
+
+        if let Some(address) = bad_vaddr {
+            self.bad_vaddr = address;
+        }
+
+        if self.status & STATUS_EXL == 0 {
+            self.epc = pc;
+            if in_delay_slot {
+                self.cause |= CAUSE_BD;
+            } else {
+                self.cause &= !CAUSE_BD;
+            }
+        }
+
+        self.status |= STATUS_EXL;
+    }
+
+    /// Return from an exception, as done by `eret`. This clears `Status.EXL`
+    /// and returns the address execution should continue from.
+    pub fn exception_return(&mut self) -> u64 {
+        self.status &= !STATUS_EXL;
+        self.epc
+    }
+
+    /// Read the CP0 register selected by `register` and `sel`.
+    pub fn read_register(&self, register: u8, sel: u8) -> u64 {
+        if sel != 0 {
+            return 0;
+        }
+
+        match register {
+            CP0_BADVADDR => self.bad_vaddr,
+            CP0_STATUS => self.status,
+            CP0_CAUSE => self.cause,
+            CP0_EPC => self.epc,
+            _ => 0,
+        }
+    }
+
+    /// Write to the CP0 register selected by `register` and `sel`.
+    ///
+    /// `BadVAddr` and `Cause` are only set by the hardware, so writes to
+    /// them are ignored.
+    pub fn write_register(&mut self, register: u8, sel: u8, data: u64) {
+        if sel != 0 {
+            return;
+        }
+
+        match register {
+            CP0_STATUS => self.status = data,
+            CP0_EPC => self.epc = data,
+            _ => (),
+        }
+    }
+}
@@ -5,8 +5,24 @@
 pub mod ui;
 
 use emulation_core::datapath::Datapath;
+use emulation_core::mips::branch_predictor::{
+    BranchPredictor, BranchPredictorConfig, PredictorKind,
+};
+use emulation_core::mips::cache::{
+    CacheConfig, CacheHierarchy, ReplacementPolicy, WriteMissPolicy, WritePolicy,
+};
+use emulation_core::mips::coprocessor::FCR_FCSR;
 use emulation_core::mips::datapath::MipsDatapath;
-use emulation_core::mips::datapath::Stage;
+use emulation_core::mips::datapath_model::{DatapathKind, MipsDatapathModel, SelectedDatapath};
+use emulation_core::mips::debugger::{
+    halt_reason, Debugger, StepUnit, StopReason, WatchKind, Watchpoint, DEFAULT_INSTRUCTION_BUDGET,
+};
+use emulation_core::mips::history::ExecutionHistory;
+use emulation_core::mips::memory::{Endianness, MemoryFault, MemoryLayout};
+use emulation_core::mips::mmio::{
+    BitmapDisplay, KeyboardDisplay, BITMAP_DISPLAY_ADDRESS, KEYBOARD_DISPLAY_ADDRESS,
+};
+use emulation_core::mips::performance::{CycleCostModel, InstructionClass};
 use gloo::{dialogs::alert, file::FileList};
 use js_sys::Object;
 use monaco::{
@@ -20,9 +36,11 @@
     },
     yew::CodeEditor,
 };
-use parser::parser_assembler_main::parser;
+use parser::parser_assembler_main::{parser, parser_for_memory};
 use std::rc::Rc;
+use std::str::FromStr;
 use ui::console::component::Console;
+use ui::console::helper::BrowserSyscallHost;
 use ui::regview::component::Regview;
 use wasm_bindgen::{JsCast, JsValue};
 use wasm_bindgen_futures::spawn_local;
@@ -75,18 +93,75 @@ fn app() -> Html {
     let parser_text_output = use_state_eq(String::new);
     let memory_text_output = use_state_eq(String::new);
 
+    // The source line of each assembled instruction, used to show branch
+    // predictor statistics by line.
+    let address_to_line_number = use_state_eq(Vec::new);
+
     // Since we want the Datapath to be independent from all the
     // events within the app, we will create it when the app loads. This is also done
     // since the scope will be open across all events involved with it. To achieve this,
     // we use interior mutability to have the reference to the Datapath immutable, but
     // the ability to access and change its contents be mutable.
-    let datapath = use_mut_ref(MipsDatapath::default);
+    //
+    // Output from the program's system calls is collected by the host
+    // and shown in the console. The memory-mapped devices are shown in
+    // their own tabs.
+    let syscall_host = use_mut_ref(BrowserSyscallHost::default);
+    let keyboard_display = use_mut_ref(KeyboardDisplay::default);
+    let bitmap_display = use_mut_ref(BitmapDisplay::default);
+    let datapath = {
+        let syscall_host = Rc::clone(&syscall_host);
+        let keyboard_display = Rc::clone(&keyboard_display);
+        let bitmap_display = Rc::clone(&bitmap_display);
+        use_mut_ref(move || {
+            let mut datapath = MipsDatapath::default();
+            datapath.set_syscall_host(syscall_host);
+            datapath
+                .memory
+                .attach_device(KEYBOARD_DISPLAY_ADDRESS, keyboard_display)
+                .unwrap();
+            datapath
+                .memory
+                .attach_device(BITMAP_DISPLAY_ADDRESS, bitmap_display)
+                .unwrap();
+            SelectedDatapath::SingleCycle(datapath)
+        })
+    };
+
+    // The stages most recently run, so that they can be stepped back through.
+    // This is cleared whenever the datapath is assembled or reset.
+    let history = use_mut_ref(ExecutionHistory::<SelectedDatapath>::default);
+
+    // The breakpoints and watchpoints used when running the program. These
+    // are kept across resets.
+    let debugger = use_mut_ref(Debugger::default);
+
+    // The kind of memory access that new memory watchpoints stop on.
+    let watch_kind = use_state_eq(WatchKind::default);
+
+    // Whether the program is running continuously, and how many instructions
+    // or stages it runs per second while doing so.
+    let running = use_state_eq(|| false);
+    let run_speed = use_state_eq(|| 10u32);
+    let run_unit = use_state_eq(StepUnit::default);
+
+    // The policies of the caches turned on by the cache button, and whether
+    // instructions and data share one cache. Their sizes are read from the
+    // inputs beside the button.
+    let cache_replacement = use_state_eq(ReplacementPolicy::default);
+    let cache_write = use_state_eq(WritePolicy::default);
+    let cache_write_miss = use_state_eq(WriteMissPolicy::default);
+    let cache_unified = use_state_eq(|| false);
 
     // This is where code is assembled and loaded into the emulation core's memory.
     let on_assemble_clicked = {
         let text_model = Rc::clone(&text_model);
         let datapath = Rc::clone(&datapath);
+        let history = Rc::clone(&history);
+        let syscall_host = Rc::clone(&syscall_host);
         let parser_text_output = parser_text_output.clone();
+        let address_to_line_number = address_to_line_number.clone();
+        let running = running.clone();
         let trigger = use_force_update();
 
         let executed_line = executed_line.clone();
@@ -98,8 +173,14 @@ fn app() -> Html {
                 let text_model = text_model.borrow_mut();
 
                 // parses through the code to assemble the binary and retrieves programinfo for error marking and mouse hover
-                let (program_info, assembled) = parser(text_model.get_value());
+                let (program_info, assembled) = parser_for_memory(
+                    text_model.get_value(),
+                    datapath.mips_datapath().memory.layout,
+                    datapath.mips_datapath().memory.endianness,
+                    datapath.mips_datapath().release_6,
+                );
                 parser_text_output.set(program_info.console_out_post_assembly);
+                syscall_host.borrow_mut().output.clear();
 
                 let mut markers: Vec<IMarkerData> = vec![];
 
@@ -143,7 +224,10 @@ fn app() -> Html {
                 // Proceed with loading into memory and expand pseudo-instructions if there are no errors.
                 if marker_jsarray.length() == 0 {
                     // Load the binary into the datapath's memory
-                    match datapath.initialize(assembled) {
+                    running.set(false);
+                    history.borrow_mut().clear();
+                    let (text, data) = assembled.split_at(program_info.instructions.len());
+                    match datapath.initialize_program(text.to_vec(), data.to_vec()) {
                         Ok(_) => (),
                         Err(msg) => {
                             // In the case of an error, note this and stop early.
@@ -152,7 +236,11 @@ fn app() -> Html {
                     }
                     // log!(datapath.memory.to_string());
                     text_model.set_value(&program_info.updated_monaco_string); // Expands pseudo-instructions to their hardware counterpart.
-                    datapath.registers.pc = program_info.pc_starting_point as u64;
+                    let mips_datapath = datapath.mips_datapath_mut();
+                    mips_datapath.registers.pc = program_info.pc_starting_point as u64;
+                    mips_datapath.system_coprocessor.exception_handler =
+                        program_info.exception_handler.map(|address| address as u64);
+                    address_to_line_number.set(program_info.address_to_line_number);
                 }
 
                 trigger.force_update();
@@ -168,6 +256,8 @@ fn app() -> Html {
     let on_execute_clicked = {
         let text_model = Rc::clone(&text_model);
         let datapath = Rc::clone(&datapath);
+        let history = Rc::clone(&history);
+        let parser_text_output = parser_text_output.clone();
         let trigger = use_force_update();
 
         let executed_line = executed_line.clone();
@@ -185,7 +275,11 @@ fn app() -> Html {
 
                 // Get the current line and convert it to f64
                 let list_of_line_numbers = programinfo.address_to_line_number;
-                let index = datapath.registers.pc as usize / 4;
+                let text_address = datapath.mips_datapath().memory.layout.text_address();
+                let index = (datapath
+                    .next_instruction_address()
+                    .wrapping_sub(text_address)
+                    / 4) as usize;
                 let curr_line = *list_of_line_numbers.get(index).unwrap_or(&0) as f64 + 1.0; // add one to account for the editor's line numbers
 
                 // Setup the range
@@ -223,7 +317,20 @@ fn app() -> Html {
                 // log!(executed_line.at(0));
                 // log!(not_highlighted.at(0));
 
-                datapath.execute_instruction();
+                history.borrow_mut().execute_instruction(&mut datapath);
+
+                // Report the fault, error, or exception that halted the
+                // program, if any.
+                if datapath.is_halted() {
+                    let reason = halt_reason(datapath.mips_datapath());
+                    if reason != StopReason::Halted {
+                        parser_text_output.set(stop_message(
+                            &reason,
+                            &list_of_line_numbers,
+                            text_address,
+                        ));
+                    }
+                }
 
                 // done with the highlight, prepare for the next one.
                 executed_line.pop();
@@ -240,6 +347,7 @@ fn app() -> Html {
 
     let on_execute_stage_clicked = {
         let datapath = Rc::clone(&datapath);
+        let history = Rc::clone(&history);
         let text_model = Rc::clone(&text_model);
         let executed_line = executed_line.clone();
         let not_highlighted = not_highlighted.clone();
@@ -250,12 +358,13 @@ fn app() -> Html {
             move |_, _| {
                 let mut datapath = datapath.borrow_mut();
                 let highlight_decor = highlight_decor.borrow_mut();
-                if datapath.current_stage == Stage::InstructionDecode {
+                if let Some(address) = datapath.next_decode_address() {
                     // highlight on InstructionDecode since syscall stops at that stage.
                     let text_model = text_model.borrow_mut();
                     let (programinfo, _) = parser(text_model.get_value());
                     let list_of_line_numbers = programinfo.address_to_line_number;
-                    let index = datapath.registers.pc as usize / 4;
+                    let text_address = datapath.mips_datapath().memory.layout.text_address();
+                    let index = (address.wrapping_sub(text_address) / 4) as usize;
                     let curr_line = *list_of_line_numbers.get(index).unwrap_or(&0) as f64 + 1.0;
                     let curr_model = text_model.as_ref();
                     let curr_range = monaco::sys::Range::new(curr_line, 0.0, curr_line, 0.0);
@@ -276,10 +385,10 @@ fn app() -> Html {
                             .delta_decorations(&not_highlighted, &executed_line, None)
                             .into(),
                     );
-                    datapath.execute_stage();
+                    history.borrow_mut().execute_stage(&mut datapath);
                     executed_line.pop();
                 } else {
-                    datapath.execute_stage();
+                    history.borrow_mut().execute_stage(&mut datapath);
                 }
                 trigger.force_update();
             },
@@ -287,11 +396,316 @@ fn app() -> Html {
         )
     };
 
+    // Undoes the most recently run instruction, or the rest of the current
+    // instruction if it is partway through. The highlight on the editor is
+    // cleared, as it no longer matches the last instruction executed.
+    let on_step_back_clicked = {
+        let text_model = Rc::clone(&text_model);
+        let datapath = Rc::clone(&datapath);
+        let history = Rc::clone(&history);
+        let trigger = use_force_update();
+
+        let executed_line = executed_line.clone();
+        let not_highlighted = not_highlighted.clone();
+
+        use_callback(
+            move |_, _| {
+                let mut datapath = datapath.borrow_mut();
+                let text_model = text_model.borrow_mut();
+                let curr_model = text_model.as_ref();
+                executed_line.pop();
+                not_highlighted.set(
+                    0,
+                    curr_model
+                        .delta_decorations(&not_highlighted, &executed_line, None)
+                        .into(),
+                );
+                history.borrow_mut().step_back_instruction(&mut datapath);
+                trigger.force_update();
+            },
+            (),
+        )
+    };
+
+    // Undoes the most recently run stage.
+    let on_step_back_stage_clicked = {
+        let datapath = Rc::clone(&datapath);
+        let history = Rc::clone(&history);
+        let trigger = use_force_update();
+
+        use_callback(
+            move |_, _| {
+                let mut datapath = datapath.borrow_mut();
+                history.borrow_mut().step_back_stage(&mut datapath);
+                trigger.force_update();
+            },
+            (),
+        )
+    };
+
+    // Runs the program at once until it reaches a breakpoint, hits a
+    // watchpoint, halts, or runs out of its instruction budget, then reports
+    // why it stopped in the console.
+    let on_run_to_breakpoint_clicked = {
+        let text_model = Rc::clone(&text_model);
+        let datapath = Rc::clone(&datapath);
+        let history = Rc::clone(&history);
+        let debugger = Rc::clone(&debugger);
+        let parser_text_output = parser_text_output.clone();
+        let trigger = use_force_update();
+
+        let executed_line = executed_line.clone();
+        let not_highlighted = not_highlighted.clone();
+
+        use_callback(
+            move |_, address_to_line_number| {
+                let mut datapath = datapath.borrow_mut();
+                let text_model = text_model.borrow_mut();
+                let curr_model = text_model.as_ref();
+                executed_line.pop();
+                not_highlighted.set(
+                    0,
+                    curr_model
+                        .delta_decorations(&not_highlighted, &executed_line, None)
+                        .into(),
+                );
+
+                let reason = debugger.borrow().run(
+                    &mut *datapath,
+                    &mut history.borrow_mut(),
+                    DEFAULT_INSTRUCTION_BUDGET,
+                );
+                let text_address = datapath.mips_datapath().memory.layout.text_address();
+                parser_text_output.set(stop_message(&reason, address_to_line_number, text_address));
+                trigger.force_update();
+            },
+            (*address_to_line_number).clone(),
+        )
+    };
+
+    // Starts running the program continuously. The first instruction or
+    // stage is run at once, so that a breakpoint on the current instruction
+    // does not stop the program before it starts.
+    let on_run_clicked = {
+        let text_model = Rc::clone(&text_model);
+        let datapath = Rc::clone(&datapath);
+        let history = Rc::clone(&history);
+        let debugger = Rc::clone(&debugger);
+        let running = running.clone();
+        let parser_text_output = parser_text_output.clone();
+        let trigger = use_force_update();
+
+        let executed_line = executed_line.clone();
+        let not_highlighted = not_highlighted.clone();
+
+        use_callback(
+            move |_, (run_unit, address_to_line_number)| {
+                let mut datapath = datapath.borrow_mut();
+                let text_model = text_model.borrow_mut();
+                let curr_model = text_model.as_ref();
+                executed_line.pop();
+                not_highlighted.set(
+                    0,
+                    curr_model
+                        .delta_decorations(&not_highlighted, &executed_line, None)
+                        .into(),
+                );
+
+                let reason =
+                    debugger
+                        .borrow()
+                        .step(&mut *datapath, &mut history.borrow_mut(), *run_unit);
+                match reason {
+                    Some(reason) => {
+                        let text_address = datapath.mips_datapath().memory.layout.text_address();
+                        parser_text_output.set(stop_message(
+                            &reason,
+                            address_to_line_number,
+                            text_address,
+                        ))
+                    }
+                    None => running.set(true),
+                }
+                trigger.force_update();
+            },
+            (*run_unit, (*address_to_line_number).clone()),
+        )
+    };
+
+    let on_pause_clicked = {
+        let running = running.clone();
+        Callback::from(move |_| running.set(false))
+    };
+
+    // Switches between running by instruction and by stage.
+    let on_run_unit_clicked = {
+        let run_unit = run_unit.clone();
+        Callback::from(move |_| {
+            run_unit.set(match *run_unit {
+                StepUnit::Instruction => StepUnit::Stage,
+                StepUnit::Stage => StepUnit::Instruction,
+            })
+        })
+    };
+
+    let on_run_speed_input = {
+        let run_speed = run_speed.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Ok(speed) = input.value().parse::<u32>() {
+                run_speed.set(speed.clamp(1, 100));
+            }
+        })
+    };
+
+    // While running, runs one instruction or stage on each tick, stopping on
+    // a breakpoint, a watchpoint, or a halt. The register view, memory view,
+    // and visual datapath are updated after each tick.
+    {
+        let datapath = Rc::clone(&datapath);
+        let history = Rc::clone(&history);
+        let debugger = Rc::clone(&debugger);
+        let running = running.clone();
+        let run_unit = *run_unit;
+        let parser_text_output = parser_text_output.clone();
+        let address_to_line_number = address_to_line_number.clone();
+        let trigger = use_force_update();
+
+        // An interval of 0 milliseconds stops the interval.
+        let millis = if *running { 1000 / *run_speed } else { 0 };
+
+        use_interval(
+            move || {
+                let mut datapath = datapath.borrow_mut();
+                let debugger = debugger.borrow();
+
+                let reason = debugger
+                    .check_breakpoint(&*datapath)
+                    .or_else(|| debugger.step(&mut *datapath, &mut history.borrow_mut(), run_unit));
+                if let Some(reason) = reason {
+                    running.set(false);
+                    let text_address = datapath.mips_datapath().memory.layout.text_address();
+                    parser_text_output.set(stop_message(
+                        &reason,
+                        &address_to_line_number,
+                        text_address,
+                    ));
+                }
+                trigger.force_update();
+            },
+            millis,
+        );
+    }
+
+    // Sets or removes a breakpoint on the line entered next to the button.
+    let on_toggle_breakpoint_clicked = {
+        let datapath = Rc::clone(&datapath);
+        let debugger = Rc::clone(&debugger);
+        let parser_text_output = parser_text_output.clone();
+
+        use_callback(
+            move |_, address_to_line_number| {
+                let line = get_breakpoint_line_input()
+                    .value()
+                    .trim()
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|&line| line > 0);
+                let Some(line) = line else {
+                    parser_text_output.set(String::from("Enter a line number for the breakpoint."));
+                    return;
+                };
+
+                let text_address = datapath
+                    .borrow()
+                    .mips_datapath()
+                    .memory
+                    .layout
+                    .text_address();
+                let mut debugger = debugger.borrow_mut();
+                parser_text_output.set(
+                    match debugger.toggle_breakpoint_at_line(
+                        line - 1,
+                        address_to_line_number,
+                        text_address,
+                    ) {
+                        Ok((_, true)) => format!("Breakpoint set on line {line}."),
+                        Ok((_, false)) => format!("Breakpoint removed from line {line}."),
+                        Err(message) => format!("{message}. Assemble the program first, or choose a line with an instruction."),
+                    },
+                );
+            },
+            (*address_to_line_number).clone(),
+        )
+    };
+
+    // Adds a watchpoint on the register or memory entered next to the button.
+    let on_add_watchpoint_clicked = {
+        let debugger = Rc::clone(&debugger);
+        let parser_text_output = parser_text_output.clone();
+        let trigger = use_force_update();
+
+        use_callback(
+            move |_, watch_kind| {
+                let input = get_watchpoint_input();
+                let watchpoint = match Watchpoint::parse(&input.value(), *watch_kind) {
+                    Ok(watchpoint) => watchpoint,
+                    Err(message) => {
+                        parser_text_output.set(format!("{message}. Enter a register, such as `$t0`, or an address and an optional number of bytes, such as `0x100 8`."));
+                        return;
+                    }
+                };
+
+                let description = watchpoint_description(&watchpoint);
+                if debugger.borrow_mut().add_watchpoint(watchpoint) {
+                    input.set_value("");
+                    parser_text_output.set(format!("Watchpoint set on {description}."));
+                } else {
+                    parser_text_output
+                        .set(format!("A watchpoint is already set on {description}."));
+                }
+                trigger.force_update();
+            },
+            *watch_kind,
+        )
+    };
+
+    // Switches the kind of access new memory watchpoints stop on.
+    let on_watch_kind_clicked = {
+        let watch_kind = watch_kind.clone();
+        Callback::from(move |_| {
+            watch_kind.set(match *watch_kind {
+                WatchKind::Write => WatchKind::Read,
+                WatchKind::Read => WatchKind::Access,
+                WatchKind::Access => WatchKind::Write,
+            })
+        })
+    };
+
+    // Removes every watchpoint.
+    let on_clear_watchpoints_clicked = {
+        let debugger = Rc::clone(&debugger);
+        let parser_text_output = parser_text_output.clone();
+        let trigger = use_force_update();
+
+        use_callback(
+            move |_, _| {
+                debugger.borrow_mut().watchpoints.clear();
+                parser_text_output.set(String::from("Watchpoints cleared."));
+                trigger.force_update();
+            },
+            (),
+        )
+    };
+
     // This is how we will reset the datapath.
     // This will also clear any highlight on the editor.
     let on_reset_clicked = {
         let text_model = Rc::clone(&text_model);
         let datapath = Rc::clone(&datapath);
+        let history = Rc::clone(&history);
+        let running = running.clone();
+        let syscall_host = Rc::clone(&syscall_host);
         let trigger = use_force_update();
         let parser_text_output = parser_text_output.clone();
 
@@ -311,6 +725,9 @@ fn app() -> Html {
                         .into(),
                 );
                 parser_text_output.set("".to_string());
+                syscall_host.borrow_mut().output.clear();
+                running.set(false);
+                history.borrow_mut().clear();
                 datapath.reset();
                 trigger.force_update();
             },
@@ -318,6 +735,355 @@ fn app() -> Html {
         )
     };
 
+    // Toggles whether branches and jumps have a delay slot. This is kept
+    // across resets, so it applies to any program assembled afterward.
+    let on_delay_slots_clicked = {
+        let datapath = Rc::clone(&datapath);
+        let trigger = use_force_update();
+
+        use_callback(
+            move |_, _| {
+                let mut datapath = datapath.borrow_mut();
+                let datapath = datapath.mips_datapath_mut();
+                datapath.branch_delay_slots = !datapath.branch_delay_slots;
+                trigger.force_update();
+            },
+            (),
+        )
+    };
+
+    // Turns the cache simulator on with the configuration entered beside the
+    // button, or turns it off. A configuration that does not describe a valid
+    // cache is reported in the console, and the cache is left off. Like the
+    // delay slots, this is kept across resets.
+    let on_cache_clicked = {
+        let datapath = Rc::clone(&datapath);
+        let parser_text_output = parser_text_output.clone();
+        let trigger = use_force_update();
+
+        use_callback(
+            move |_, (replacement_policy, write_policy, write_miss_policy, unified)| {
+                let mut datapath = datapath.borrow_mut();
+                let datapath = datapath.mips_datapath_mut();
+                if datapath.cache.take().is_some() {
+                    trigger.force_update();
+                    return;
+                }
+
+                let config = CacheConfig {
+                    replacement_policy: *replacement_policy,
+                    write_policy: *write_policy,
+                    write_miss_policy: *write_miss_policy,
+                    ..Default::default()
+                };
+                let cache = get_cache_sizes(config)
+                    .and_then(|config| CacheHierarchy::new(config, *unified));
+
+                match cache {
+                    Ok(cache) => datapath.cache = Some(cache),
+                    Err(message) => {
+                        parser_text_output.set(format!("{message}. The cache was not turned on."))
+                    }
+                }
+                trigger.force_update();
+            },
+            (
+                *cache_replacement,
+                *cache_write,
+                *cache_write_miss,
+                *cache_unified,
+            ),
+        )
+    };
+
+    // Cycles the replacement policy of the caches turned on next.
+    let on_cache_replacement_clicked = {
+        let cache_replacement = cache_replacement.clone();
+        Callback::from(move |_| {
+            cache_replacement.set(match *cache_replacement {
+                ReplacementPolicy::Lru => ReplacementPolicy::Fifo,
+                ReplacementPolicy::Fifo => ReplacementPolicy::Random,
+                ReplacementPolicy::Random => ReplacementPolicy::Lru,
+            })
+        })
+    };
+
+    // Switches the write policy of the caches turned on next.
+    let on_cache_write_clicked = {
+        let cache_write = cache_write.clone();
+        Callback::from(move |_| {
+            cache_write.set(match *cache_write {
+                WritePolicy::WriteBack => WritePolicy::WriteThrough,
+                WritePolicy::WriteThrough => WritePolicy::WriteBack,
+            })
+        })
+    };
+
+    // Switches the write miss policy of the caches turned on next.
+    let on_cache_write_miss_clicked = {
+        let cache_write_miss = cache_write_miss.clone();
+        Callback::from(move |_| {
+            cache_write_miss.set(match *cache_write_miss {
+                WriteMissPolicy::WriteAllocate => WriteMissPolicy::NoWriteAllocate,
+                WriteMissPolicy::NoWriteAllocate => WriteMissPolicy::WriteAllocate,
+            })
+        })
+    };
+
+    // Switches the caches turned on next between separate instruction and
+    // data caches and a single unified cache.
+    let on_cache_unified_clicked = {
+        let cache_unified = cache_unified.clone();
+        Callback::from(move |_| cache_unified.set(!*cache_unified))
+    };
+
+    // Cycles the branch predictor through each kind of predictor, then off,
+    // with the sizes entered beside the button. Sizes that do not describe a
+    // valid predictor are reported in the console, and the predictor is
+    // turned off. Like the caches, this is kept across resets.
+    let on_branch_predictor_clicked = {
+        let datapath = Rc::clone(&datapath);
+        let parser_text_output = parser_text_output.clone();
+        let trigger = use_force_update();
+
+        use_callback(
+            move |_, _| {
+                let mut datapath = datapath.borrow_mut();
+                let datapath = datapath.mips_datapath_mut();
+                let next_kind = match datapath.branch_predictor.as_ref().map(|p| p.config().kind) {
+                    None => Some(PredictorKind::StaticNotTaken),
+                    Some(PredictorKind::StaticNotTaken) => Some(PredictorKind::StaticBtfn),
+                    Some(PredictorKind::StaticBtfn) => Some(PredictorKind::OneBit),
+                    Some(PredictorKind::OneBit) => Some(PredictorKind::TwoBit),
+                    Some(PredictorKind::TwoBit) => Some(PredictorKind::Gshare),
+                    Some(PredictorKind::Gshare) => None,
+                };
+
+                let predictor = next_kind
+                    .map(|kind| get_branch_predictor_sizes(kind).and_then(BranchPredictor::new))
+                    .transpose();
+
+                match predictor {
+                    Ok(predictor) => datapath.branch_predictor = predictor,
+                    Err(message) => {
+                        datapath.branch_predictor = None;
+                        parser_text_output
+                            .set(format!("{message}. The branch predictor was turned off."));
+                    }
+                }
+                trigger.force_update();
+            },
+            (),
+        )
+    };
+
+    // Switches the cost model used for the CPI in the performance summary
+    // between the multi-cycle and single-cycle datapaths. A model with costs
+    // entered by hand is replaced with the multi-cycle one.
+    let on_cost_model_clicked = {
+        let datapath = Rc::clone(&datapath);
+        let trigger = use_force_update();
+
+        use_callback(
+            move |_, _| {
+                let mut datapath = datapath.borrow_mut();
+                let datapath = datapath.mips_datapath_mut();
+                datapath.cost_model = if datapath.cost_model == CycleCostModel::default() {
+                    CycleCostModel::single_cycle()
+                } else {
+                    CycleCostModel::default()
+                };
+                trigger.force_update();
+            },
+            (),
+        )
+    };
+
+    // Sets the number of cycles one class of instructions takes in the cost
+    // model.
+    let on_cost_input = {
+        let datapath = Rc::clone(&datapath);
+        let trigger = use_force_update();
+
+        Callback::from(move |(class, e): (InstructionClass, InputEvent)| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Ok(cycles) = input.value().parse::<u64>() {
+                let mut datapath = datapath.borrow_mut();
+                datapath
+                    .mips_datapath_mut()
+                    .cost_model
+                    .set_cost(class, cycles);
+                trigger.force_update();
+            }
+        })
+    };
+
+    // Switches between the flat memory layout and the conventional MIPS
+    // layout. This resets the datapath, so the program has to be assembled
+    // again.
+    let on_memory_layout_clicked = {
+        let datapath = Rc::clone(&datapath);
+        let history = Rc::clone(&history);
+        let parser_text_output = parser_text_output.clone();
+        let running = running.clone();
+        let trigger = use_force_update();
+
+        use_callback(
+            move |_, _| {
+                let mut datapath = datapath.borrow_mut();
+                let layout = match datapath.mips_datapath().memory.layout {
+                    MemoryLayout::Flat => MemoryLayout::Segmented,
+                    MemoryLayout::Segmented => MemoryLayout::Flat,
+                };
+                running.set(false);
+                history.borrow_mut().clear();
+                datapath.set_memory_layout(layout);
+                parser_text_output.set(String::from(
+                    "Memory layout changed. Assemble the program again to load it.",
+                ));
+                trigger.force_update();
+            },
+            (),
+        )
+    };
+
+    // Switches the byte order of memory between big-endian and
+    // little-endian. Like changing the layout, this resets the datapath.
+    let on_endianness_clicked = {
+        let datapath = Rc::clone(&datapath);
+        let history = Rc::clone(&history);
+        let parser_text_output = parser_text_output.clone();
+        let running = running.clone();
+        let trigger = use_force_update();
+
+        use_callback(
+            move |_, _| {
+                let mut datapath = datapath.borrow_mut();
+                let endianness = match datapath.mips_datapath().memory.endianness {
+                    Endianness::Big => Endianness::Little,
+                    Endianness::Little => Endianness::Big,
+                };
+                running.set(false);
+                history.borrow_mut().clear();
+                datapath.set_endianness(endianness);
+                parser_text_output.set(String::from(
+                    "Byte order changed. Assemble the program again to load it.",
+                ));
+                trigger.force_update();
+            },
+            (),
+        )
+    };
+
+    // Allows or forbids programs from writing to their own instructions.
+    // This is kept when the program is assembled again.
+    let on_self_modifying_code_clicked = {
+        let datapath = Rc::clone(&datapath);
+        let trigger = use_force_update();
+
+        use_callback(
+            move |_, _| {
+                let mut datapath = datapath.borrow_mut();
+                let datapath = datapath.mips_datapath_mut();
+                datapath.memory.self_modifying_code = !datapath.memory.self_modifying_code;
+                trigger.force_update();
+            },
+            (),
+        )
+    };
+
+    // Toggles strict MIPS64 semantics for 32-bit instructions. Like the
+    // delay slots, this is kept across resets.
+    let on_strict_32_bit_clicked = {
+        let datapath = Rc::clone(&datapath);
+        let trigger = use_force_update();
+
+        use_callback(
+            move |_, _| {
+                let mut datapath = datapath.borrow_mut();
+                let datapath = datapath.mips_datapath_mut();
+                datapath.strict_32_bit = !datapath.strict_32_bit;
+                trigger.force_update();
+            },
+            (),
+        )
+    };
+
+    // Switches the opcodes of `addi` and `daddi` between those instructions
+    // and the release 6 compact branches that replace them. Programs are
+    // assembled for the chosen instructions, so this resets the datapath.
+    let on_release_6_clicked = {
+        let datapath = Rc::clone(&datapath);
+        let history = Rc::clone(&history);
+        let parser_text_output = parser_text_output.clone();
+        let running = running.clone();
+        let trigger = use_force_update();
+
+        use_callback(
+            move |_, _| {
+                let mut datapath = datapath.borrow_mut();
+                running.set(false);
+                history.borrow_mut().clear();
+                let mips_datapath = datapath.mips_datapath_mut();
+                mips_datapath.release_6 = !mips_datapath.release_6;
+                datapath.reset();
+                parser_text_output.set(String::from(
+                    "Instruction set changed. Assemble the program again to load it.",
+                ));
+                trigger.force_update();
+            },
+            (),
+        )
+    };
+
+    // Switches the model of the datapath that programs run on, between the
+    // single-cycle, multi-cycle, and pipelined datapaths. The settings are
+    // kept, but the datapath is reset, so the program has to be assembled
+    // again.
+    let on_datapath_kind_clicked = {
+        let datapath = Rc::clone(&datapath);
+        let history = Rc::clone(&history);
+        let parser_text_output = parser_text_output.clone();
+        let running = running.clone();
+        let trigger = use_force_update();
+
+        use_callback(
+            move |_, _| {
+                let mut datapath = datapath.borrow_mut();
+                let kind = match datapath.kind() {
+                    DatapathKind::SingleCycle => DatapathKind::MultiCycle,
+                    DatapathKind::MultiCycle => DatapathKind::Pipelined,
+                    DatapathKind::Pipelined => DatapathKind::SingleCycle,
+                };
+                running.set(false);
+                history.borrow_mut().clear();
+                datapath.set_kind(kind);
+                parser_text_output.set(String::from(
+                    "Datapath changed. Assemble the program again to load it.",
+                ));
+                trigger.force_update();
+            },
+            (),
+        )
+    };
+
+    // Toggles the forwarding unit of the pipelined datapath. Without it,
+    // instructions stall until the registers they read have been written.
+    let on_forwarding_clicked = {
+        let datapath = Rc::clone(&datapath);
+        let trigger = use_force_update();
+
+        use_callback(
+            move |_, _| {
+                if let SelectedDatapath::Pipelined(datapath) = &mut *datapath.borrow_mut() {
+                    datapath.forwarding = !datapath.forwarding;
+                }
+                trigger.force_update();
+            },
+            (),
+        )
+    };
+
     // Copies text to the user's clipboard
     let on_clipboard_clicked = {
         let text_model = Rc::clone(&text_model);
@@ -438,12 +1204,103 @@ fn app() -> Html {
                             <button class="button" onclick={on_assemble_clicked}>{ "Assemble " }<i class="fa-sharp fa-solid fa-hammer"></i></button>
                             <button class="button" onclick={on_execute_clicked} disabled={datapath.borrow().is_halted()}>{ "Execute " }<i class="fa-regular fa-circle-play"></i></button>
                             <button class="button" onclick={on_execute_stage_clicked} disabled={datapath.borrow().is_halted()}> { "Execute Stage " }<i class="fa-solid fa-play"></i></button>
+                            <button class="button" onclick={on_step_back_clicked} disabled={history.borrow().is_empty()}>{ "Step Back " }<i class="fa-solid fa-backward"></i></button>
+                            <button class="button" onclick={on_step_back_stage_clicked} disabled={history.borrow().is_empty()}>{ "Step Back Stage " }<i class="fa-solid fa-backward-step"></i></button>
+                            if *running {
+                                <button class="button" onclick={on_pause_clicked}>{ "Pause " }<i class="fa-solid fa-pause"></i></button>
+                            } else {
+                                <button class="button" onclick={on_run_clicked} disabled={datapath.borrow().is_halted()}>{ "Run " }<i class="fa-solid fa-forward"></i></button>
+                            }
+                            <button class="button" onclick={on_run_unit_clicked}>{ match *run_unit {
+                                StepUnit::Instruction => "Run By: Instruction",
+                                StepUnit::Stage => "Run By: Stage",
+                            } }</button>
+                            <input type="range" class="speed-slider" min="1" max="100" value={run_speed.to_string()} oninput={on_run_speed_input} />
+                            <span class="speed-label">{ format!("{} / s", *run_speed) }</span>
+                            <button class="button" onclick={on_run_to_breakpoint_clicked} disabled={datapath.borrow().is_halted()}>{ "Run to Breakpoint " }<i class="fa-solid fa-forward-fast"></i></button>
+                            <input type="number" id="breakpoint_line" class="line-input" min="1" placeholder="Line" />
+                            <button class="button" onclick={on_toggle_breakpoint_clicked}>{ "Toggle Breakpoint " }<i class="fa-solid fa-circle-dot"></i></button>
+                            <input type="text" id="watchpoint_target" class="watchpoint-input" placeholder="$t0 or 0x100 8" />
+                            <button class="button" onclick={on_watch_kind_clicked}>{ match *watch_kind {
+                                WatchKind::Write => "Watch Memory: Write",
+                                WatchKind::Read => "Watch Memory: Read",
+                                WatchKind::Access => "Watch Memory: Access",
+                            } }</button>
+                            <button class="button" onclick={on_add_watchpoint_clicked}>{ "Add Watchpoint " }<i class="fa-solid fa-eye"></i></button>
+                            <button class="button" onclick={on_clear_watchpoints_clicked} disabled={debugger.borrow().watchpoints.is_empty()}>{ "Clear Watchpoints " }<i class="fa-solid fa-eye-slash"></i></button>
                             <button class="button" onclick={on_reset_clicked}>{ "Reset " }<i class="fa-solid fa-arrow-rotate-left"></i></button>
+                            <button class="button" onclick={on_datapath_kind_clicked}>{ match datapath.borrow().kind() {
+                                DatapathKind::SingleCycle => "Datapath: Single-Cycle",
+                                DatapathKind::MultiCycle => "Datapath: Multi-Cycle",
+                                DatapathKind::Pipelined => "Datapath: Pipelined",
+                            } }</button>
+                            if let SelectedDatapath::Pipelined(pipelined) = &*datapath.borrow() {
+                                <button class="button" onclick={on_forwarding_clicked}>{ if pipelined.forwarding { "Forwarding: On" } else { "Forwarding: Off" } }</button>
+                            }
+                            // The pipelined datapath has no delay slots.
+                            <button class="button" onclick={on_delay_slots_clicked} disabled={datapath.borrow().kind() == DatapathKind::Pipelined}>{ if datapath.borrow().mips_datapath().branch_delay_slots { "Delay Slots: On" } else { "Delay Slots: Off" } }</button>
+                            <button class="button" onclick={on_cache_clicked}>{ if datapath.borrow().mips_datapath().cache.is_some() { "Cache: On" } else { "Cache: Off" } }</button>
+                            <input type="number" id="cache_size" class="config-input" min="8" value="1024" title="Cache size in bytes" />
+                            <input type="number" id="cache_block_size" class="config-input" min="8" value="16" title="Block size in bytes" />
+                            <input type="number" id="cache_associativity" class="config-input" min="1" value="1" title="Blocks per set" />
+                            <button class="button" onclick={on_cache_replacement_clicked}>{ match *cache_replacement {
+                                ReplacementPolicy::Lru => "Replacement: LRU",
+                                ReplacementPolicy::Fifo => "Replacement: FIFO",
+                                ReplacementPolicy::Random => "Replacement: Random",
+                            } }</button>
+                            <button class="button" onclick={on_cache_write_clicked}>{ match *cache_write {
+                                WritePolicy::WriteBack => "Write: Back",
+                                WritePolicy::WriteThrough => "Write: Through",
+                            } }</button>
+                            <button class="button" onclick={on_cache_write_miss_clicked}>{ match *cache_write_miss {
+                                WriteMissPolicy::WriteAllocate => "Write Miss: Allocate",
+                                WriteMissPolicy::NoWriteAllocate => "Write Miss: No Allocate",
+                            } }</button>
+                            <button class="button" onclick={on_cache_unified_clicked}>{ if *cache_unified { "Caches: Unified" } else { "Caches: Split" } }</button>
+                            <button class="button" onclick={on_branch_predictor_clicked}>{ match datapath.borrow().mips_datapath().branch_predictor.as_ref().map(|p| p.config().kind) {
+                                None => "Predictor: Off",
+                                Some(PredictorKind::StaticNotTaken) => "Predictor: Not Taken",
+                                Some(PredictorKind::StaticBtfn) => "Predictor: BTFN",
+                                Some(PredictorKind::OneBit) => "Predictor: 1-Bit",
+                                Some(PredictorKind::TwoBit) => "Predictor: 2-Bit",
+                                Some(PredictorKind::Gshare) => "Predictor: Gshare",
+                            } }</button>
+                            <input type="number" id="predictor_table_size" class="config-input" min="1" value="64" title="Prediction table entries" />
+                            <input type="number" id="predictor_history_bits" class="config-input" min="0" max="32" value="6" title="Global history bits (gshare)" />
+                            <input type="number" id="predictor_btb_size" class="config-input" min="0" value="16" title="Branch target buffer entries (0 for none)" />
+                            <button class="button" onclick={on_cost_model_clicked}>{ match &datapath.borrow().mips_datapath().cost_model {
+                                model if *model == CycleCostModel::default() => "CPI Model: Multi-Cycle",
+                                model if *model == CycleCostModel::single_cycle() => "CPI Model: Single-Cycle",
+                                _ => "CPI Model: Custom",
+                            } }</button>
+                            { for InstructionClass::ALL.into_iter().map(|class| html! {
+                                <label class="config-label">
+                                    { class.name() }
+                                    <input type="number" class="cost-input" min="0" value={datapath.borrow().mips_datapath().cost_model.cost(class).to_string()} oninput={on_cost_input.reform(move |e| (class, e))} />
+                                </label>
+                            }) }
+                            <button class="button" onclick={on_memory_layout_clicked}>{ match datapath.borrow().mips_datapath().memory.layout {
+                                MemoryLayout::Flat => "Layout: Flat",
+                                MemoryLayout::Segmented => "Layout: MIPS",
+                            } }</button>
+                            <button class="button" onclick={on_endianness_clicked}>{ match datapath.borrow().mips_datapath().memory.endianness {
+                                Endianness::Big => "Endian: Big",
+                                Endianness::Little => "Endian: Little",
+                            } }</button>
+                            <button class="button" onclick={on_self_modifying_code_clicked}>{ if datapath.borrow().mips_datapath().memory.self_modifying_code { "Self-Modifying Code: On" } else { "Self-Modifying Code: Off" } }</button>
+                            <button class="button" onclick={on_strict_32_bit_clicked}>{ if datapath.borrow().mips_datapath().strict_32_bit { "32-Bit Semantics: Strict" } else { "32-Bit Semantics: Relaxed" } }</button>
+                            <button class="button" onclick={on_release_6_clicked}>{ if datapath.borrow().mips_datapath().release_6 { "ADDI/DADDI Opcodes: Release 6 Branches" } else { "ADDI/DADDI Opcodes: ADDI, DADDI" } }</button>
                             //<input type="button" value="Load File" onclick={upload_clicked_callback} />
                             <button class="button" onclick={upload_clicked_callback}>{"Upload File "}<i class="fa-sharp fa-solid fa-upload"></i></button>
                             //<input type="button" value="Save to Clipboard" onclick={on_clipboard_clicked} />
                             <button class="button" onclick={on_clipboard_clicked}>{"Copy to Clipboard "}<i class="fa-regular fa-copy"></i></button>
                         </div>
+                        if !debugger.borrow().watchpoints.is_empty() {
+                            <div class="watchpoint-list">
+                                { "Watchpoints: " }
+                                { debugger.borrow().watchpoints.iter().map(watchpoint_description).collect::<Vec<_>>().join("; ") }
+                            </div>
+                        }
                     </div>
 
                     // Editor
@@ -453,11 +1310,13 @@ fn app() -> Html {
 
                     // Console
                     <Console parsermsg={(*parser_text_output).clone()} datapath={(*datapath.borrow()).clone()}
-                    memorymsg={(*memory_text_output).clone()}/>
+                    memorymsg={(*memory_text_output).clone()} programmsg={syscall_host.borrow().output.clone()}
+                    address_to_line_number={(*address_to_line_number).clone()}
+                    keyboard_display={Rc::clone(&keyboard_display)} bitmap_display={Rc::clone(&bitmap_display)}/>
                 </div>
 
                 // Right column
-                <Regview gp={datapath.borrow().registers} fp={datapath.borrow().coprocessor.fpr}/>
+                <Regview gp={datapath.borrow().mips_datapath().registers} fp={datapath.borrow().mips_datapath().coprocessor.fpr} fcsr={datapath.borrow().mips_datapath().coprocessor.read_control_register(FCR_FCSR)}/>
             </div>
         </>
     }
@@ -537,6 +1396,103 @@ pub fn on_upload_file_clicked() {
     // log!("After click");
 }
 
+/// Describe why running the program stopped, including the source line of
+/// the breakpoint it stopped at, if any.
+fn stop_message(
+    reason: &StopReason,
+    address_to_line_number: &[usize],
+    text_address: u64,
+) -> String {
+    let line = match reason {
+        StopReason::Breakpoint(address)
+        | StopReason::MemoryFault(MemoryFault { pc: address, .. }) => address
+            .checked_sub(text_address)
+            .and_then(|offset| address_to_line_number.get((offset / 4) as usize)),
+        _ => None,
+    };
+
+    match line {
+        Some(line) => format!("{reason} (line {}).", line + 1),
+        None => format!("{reason}."),
+    }
+}
+
+/// Describe a watchpoint, including the kind of access it stops on for
+/// memory.
+fn watchpoint_description(watchpoint: &Watchpoint) -> String {
+    match watchpoint {
+        Watchpoint::Memory { kind, .. } => format!("{watchpoint} on {kind}"),
+        Watchpoint::Register(_) => watchpoint.to_string(),
+    }
+}
+
+/// Get the input holding the line to set or remove a breakpoint on.
+fn get_breakpoint_line_input() -> HtmlInputElement {
+    let window = web_sys::window().expect("should have a window in this context");
+    let document = window.document().expect("window should have a document");
+
+    document
+        .get_element_by_id("breakpoint_line")
+        .expect("Breakpoint line input element with id \"breakpoint_line\" should exist.")
+        .dyn_into::<HtmlInputElement>()
+        .expect("Element should be an HtmlInputElement")
+}
+
+/// Get the input holding the register or memory to set a watchpoint on.
+fn get_watchpoint_input() -> HtmlInputElement {
+    let window = web_sys::window().expect("should have a window in this context");
+    let document = window.document().expect("window should have a document");
+
+    document
+        .get_element_by_id("watchpoint_target")
+        .expect("Watchpoint input element with id \"watchpoint_target\" should exist.")
+        .dyn_into::<HtmlInputElement>()
+        .expect("Element should be an HtmlInputElement")
+}
+
+/// Fill in the size, block size, and associativity of `config` from the
+/// inputs beside the cache button.
+fn get_cache_sizes(config: CacheConfig) -> Result<CacheConfig, String> {
+    Ok(CacheConfig {
+        size: get_number_input("cache_size", "Cache size")?,
+        block_size: get_number_input("cache_block_size", "Block size")?,
+        associativity: get_number_input("cache_associativity", "Associativity")?,
+        ..config
+    })
+}
+
+/// Create the configuration of a branch predictor of the given `kind` from
+/// the inputs beside the branch predictor button. A branch target buffer size
+/// of `0` means no branch target buffer.
+fn get_branch_predictor_sizes(kind: PredictorKind) -> Result<BranchPredictorConfig, String> {
+    let btb_size = get_number_input("predictor_btb_size", "Branch target buffer size")?;
+    Ok(BranchPredictorConfig {
+        kind,
+        table_size: get_number_input("predictor_table_size", "Prediction table size")?,
+        history_bits: get_number_input("predictor_history_bits", "Global history length")?,
+        btb_size: (btb_size != 0).then_some(btb_size),
+    })
+}
+
+/// Get the whole number entered in the input with the id `id`. If it is not
+/// a whole number, an [`Err`] naming the input as `name` is returned.
+fn get_number_input<T: FromStr>(id: &str, name: &str) -> Result<T, String> {
+    let window = web_sys::window().expect("should have a window in this context");
+    let document = window.document().expect("window should have a document");
+
+    let value = document
+        .get_element_by_id(id)
+        .unwrap_or_else(|| panic!("Input element with id \"{id}\" should exist."))
+        .dyn_into::<HtmlInputElement>()
+        .expect("Element should be an HtmlInputElement")
+        .value();
+
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("{name} `{value}` is not a whole number"))
+}
+
 fn main() {
     yew::Renderer::<App>::new().render();
 }
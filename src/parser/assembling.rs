@@ -1,3 +1,4 @@
+use crate::emulation_core::mips::memory::Endianness;
 use crate::parser::parser_assembler_main::append_binary;
 use crate::parser::parser_structs_and_enums::ErrorType::{
     ImmediateOutOfBounds, ImproperlyFormattedASCII, ImproperlyFormattedChar,
@@ -6,7 +7,8 @@
     NonIntImmediate, UnrecognizedDataType, UnrecognizedFPRegister, UnrecognizedGPRegister,
 };
 use crate::parser::parser_structs_and_enums::OperandType::{
-    Immediate, LabelAbsolute, LabelRelative, MemoryAddress, RegisterFP, RegisterGP,
+    Immediate, LabelAbsolute, LabelRelative, LabelRelative21, LabelRelative26, MemoryAddress,
+    RegisterFP, RegisterGP,
 };
 use crate::parser::parser_structs_and_enums::RegisterType::{FloatingPoint, GeneralPurpose};
 use crate::parser::parser_structs_and_enums::TokenType::{
@@ -137,10 +139,14 @@ pub fn read_operands(
                     instruction.errors.push(label_absolute_results.1.unwrap());
                 }
             }
-            LabelRelative => {
+            LabelRelative | LabelRelative21 | LabelRelative26 => {
                 instruction.operands[i].token_type = TokenType::LabelOperand;
 
-                bit_lengths.push(16);
+                bit_lengths.push(match operand_type {
+                    LabelRelative21 => 21,
+                    LabelRelative26 => 26,
+                    _ => 16,
+                });
                 let label_relative_results = read_label_relative(
                     &instruction.operands[i].token_name,
                     instruction.operands[i].start_end_columns,
@@ -167,6 +173,20 @@ pub fn read_operands(
                     instruction.errors.push(immediate_results.1.unwrap());
                 }
             }
+            OperandType::ConditionCode => {
+                instruction.operands[i].token_type = TokenType::Immediate;
+                bit_lengths.push(3);
+
+                let (condition_code, error) = read_condition_code(
+                    &instruction.operands[i].token_name,
+                    instruction.operands[i].start_end_columns,
+                );
+
+                binary_representation.push(condition_code);
+                if let Some(error) = error {
+                    instruction.errors.push(error);
+                }
+            }
         }
     }
     //once all operands are read, we can append them onto the instruction
@@ -438,10 +458,46 @@ pub fn read_immediate(
     (int_representation as u32, None)
 }
 
-///Takes the data list and finds the actual values for each data entry that will be put into memory
-pub fn assemble_data_binary(data_list: &mut [Data]) -> Vec<u8> {
+///Reads a floating-point condition code, which must be an integer from 0 to 7.
+pub fn read_condition_code(
+    given_text: &str,
+    start_end_columns: (usize, usize),
+) -> (u32, Option<Error>) {
+    let immediate_results = read_immediate(given_text, start_end_columns, 3);
+    if immediate_results.1.is_some() {
+        return immediate_results;
+    }
+
+    if immediate_results.0 > 7 {
+        return (
+            0,
+            Some(Error {
+                error_name: ImmediateOutOfBounds,
+                token_causing_error: given_text.to_string(),
+                start_end_columns,
+                message: "".to_string(),
+            }),
+        );
+    }
+
+    immediate_results
+}
+
+///Takes the data list and finds the actual values for each data entry that will be put into memory.
+/// `data_address` is the address the data will be placed at, which is used to align `.double` entries
+/// to a doubleword boundary so they can be loaded with `ld` and `ldc1`. Values of more than one byte are
+/// given in the byte order of `endianness`.
+pub fn assemble_data_binary(
+    data_list: &mut [Data],
+    data_address: usize,
+    endianness: Endianness,
+) -> Vec<u8> {
     let mut vec_of_data: Vec<u8> = Vec::new();
     for datum in data_list.iter_mut() {
+        if datum.data_type.token_name.to_lowercase() == ".double" {
+            let aligned_address = (data_address + vec_of_data.len() + 7) & !7;
+            vec_of_data.resize(aligned_address - data_address, 0);
+        }
         datum.data_number = vec_of_data.len();
         match &*datum.data_type.token_name.to_lowercase() {
             ".ascii" => {
@@ -540,14 +596,7 @@ pub fn assemble_data_binary(data_list: &mut [Data]) -> Vec<u8> {
                     let parse_results = value.token_name.parse::<f64>();
                     if let Ok(..) = parse_results {
                         let float_bits = parse_results.unwrap().to_bits();
-                        vec_of_data.push((float_bits >> 56) as u8);
-                        vec_of_data.push((float_bits >> 48) as u8);
-                        vec_of_data.push((float_bits >> 40) as u8);
-                        vec_of_data.push((float_bits >> 32) as u8);
-                        vec_of_data.push((float_bits >> 24) as u8);
-                        vec_of_data.push((float_bits >> 16) as u8);
-                        vec_of_data.push((float_bits >> 8) as u8);
-                        vec_of_data.push(float_bits as u8);
+                        vec_of_data.extend(endianness.to_bytes(float_bits, 8));
                     } else {
                         datum.errors.push(Error {
                             error_name: NonFloatImmediate,
@@ -565,10 +614,7 @@ pub fn assemble_data_binary(data_list: &mut [Data]) -> Vec<u8> {
                     let parse_results = value.token_name.parse::<f32>();
                     if let Ok(..) = parse_results {
                         let float_bits = parse_results.unwrap().to_bits();
-                        vec_of_data.push((float_bits >> 24) as u8);
-                        vec_of_data.push((float_bits >> 16) as u8);
-                        vec_of_data.push((float_bits >> 8) as u8);
-                        vec_of_data.push(float_bits as u8);
+                        vec_of_data.extend(endianness.to_bytes(float_bits as u64, 4));
                     } else {
                         datum.errors.push(Error {
                             error_name: NonFloatImmediate,
@@ -586,8 +632,7 @@ pub fn assemble_data_binary(data_list: &mut [Data]) -> Vec<u8> {
                     let immediate_results =
                         read_immediate(&value.token_name, value.start_end_columns, 16);
 
-                    vec_of_data.push((immediate_results.0 >> 8) as u8);
-                    vec_of_data.push(immediate_results.0 as u8);
+                    vec_of_data.extend(endianness.to_bytes(immediate_results.0 as u64, 2));
 
                     if immediate_results.1.is_some() {
                         datum.errors.push(immediate_results.1.unwrap());
@@ -620,10 +665,7 @@ pub fn assemble_data_binary(data_list: &mut [Data]) -> Vec<u8> {
                     }
 
                     //push all four bytes of the word to the vector
-                    vec_of_data.push((immediate_results.0 >> 24) as u8);
-                    vec_of_data.push((immediate_results.0 >> 16) as u8);
-                    vec_of_data.push((immediate_results.0 >> 8) as u8);
-                    vec_of_data.push(immediate_results.0 as u8);
+                    vec_of_data.extend(endianness.to_bytes(immediate_results.0 as u64, 4));
                 }
             }
             "" => {
@@ -1,3 +1,4 @@
+use crate::emulation_core::mips::memory::{Endianness, MemoryLayout};
 use crate::parser::assembling::{assemble_data_binary, read_operands};
 use crate::parser::parser_structs_and_enums::ErrorType::*;
 use crate::parser::parser_structs_and_enums::OperandType::*;
@@ -11,9 +12,28 @@
 
 ///Parser is the starting function of the parser / assembler process. It takes a string representation of a MIPS
 /// program and builds the binary of the instructions while cataloging any errors that are found.
+///
+/// The program is assembled for the flat memory layout and big-endian byte order, outside of release 6 mode. See
+/// [`parser_for_memory`].
 pub fn parser(file_string: String) -> (ProgramInfo, Vec<u32>) {
+    parser_for_memory(file_string, MemoryLayout::Flat, Endianness::Big, false)
+}
+
+///Assembles a program to be placed in memory as `layout` describes, with values stored in the byte order of
+/// `endianness`. The returned binary holds the instructions followed by the data, which are split at the number of
+/// instructions and loaded into their own segments.
+///
+/// In `release_6` mode, the program is assembled for a datapath with `MipsDatapath::release_6` set, which uses the
+/// opcodes of `addi` and `daddi` for compact branches.
+pub fn parser_for_memory(
+    file_string: String,
+    layout: MemoryLayout,
+    endianness: Endianness,
+    release_6: bool,
+) -> (ProgramInfo, Vec<u32>) {
     let mut program_info = ProgramInfo {
         monaco_line_info: tokenize_program(file_string),
+        text_address: layout.text_address() as usize,
         ..Default::default()
     };
 
@@ -26,10 +46,23 @@ pub fn parser(file_string: String) -> (ProgramInfo, Vec<u32>) {
         &mut program_info.monaco_line_info,
     );
 
-    let vec_of_data = assemble_data_binary(&mut program_info.data);
+    insert_delay_slot_nops(
+        &mut program_info.instructions,
+        &program_info.monaco_line_info,
+    );
+
+    check_release_6_instructions(&mut program_info.instructions, release_6);
+
+    let text_address = program_info.text_address;
+    let data_address = layout.data_address((program_info.instructions.len() << 2) as u64) as usize;
+    let vec_of_data = assemble_data_binary(&mut program_info.data, data_address, endianness);
 
-    let labels: HashMap<String, usize> =
-        create_label_map(&mut program_info.instructions, &mut program_info.data);
+    let labels: HashMap<String, usize> = create_label_map_at(
+        &mut program_info.instructions,
+        &mut program_info.data,
+        text_address,
+        data_address,
+    );
 
     complete_lw_sw_pseudo_instructions(
         &mut program_info.instructions,
@@ -37,12 +70,21 @@ pub fn parser(file_string: String) -> (ProgramInfo, Vec<u32>) {
         &mut program_info.monaco_line_info,
     );
 
+    //instructions are assembled as if the text started at address 0, so branch offsets are counted from the
+    //start of the text. Jump targets are then moved to where the text really is.
+    let text_labels: HashMap<String, usize> = labels
+        .iter()
+        .map(|(label, address)| (label.clone(), address.wrapping_sub(text_address)))
+        .collect();
+
     read_instructions(
         &mut program_info.instructions,
-        &labels,
+        &text_labels,
         &mut program_info.monaco_line_info,
     );
 
+    relocate_jumps(&mut program_info.instructions, text_address);
+
     program_info.console_out_post_assembly = suggest_error_corrections(
         &mut program_info.instructions,
         &mut program_info.data,
@@ -50,7 +92,7 @@ pub fn parser(file_string: String) -> (ProgramInfo, Vec<u32>) {
         &mut program_info.monaco_line_info,
     );
 
-    let binary = create_binary_vec(program_info.instructions.clone(), vec_of_data);
+    let binary = create_binary_vec(program_info.instructions.clone(), vec_of_data, endianness);
 
     for entry in &program_info.monaco_line_info {
         program_info
@@ -64,7 +106,8 @@ pub fn parser(file_string: String) -> (ProgramInfo, Vec<u32>) {
             .push(instruction.line_number);
     }
 
-    program_info.pc_starting_point = determine_pc_starting_point(labels);
+    program_info.exception_handler = determine_exception_handler(&labels);
+    program_info.pc_starting_point = determine_pc_starting_point(labels, text_address);
 
     (program_info.clone(), binary)
 }
@@ -99,7 +142,7 @@ pub fn read_instructions(
                 {
                     let info = InstructionDescription{
                         syntax: "add rd, rs, rt".to_string(),
-                        description: "Adds the 32-bit values in `rs` and `rt`, and places the result in `rd`.\n\nIn hardware implementations, the result is not placed in `rd` if adding `rs` and `rt` causes a 32-bit overflow. However, SWIM places the result in `rd` regardless since there is no exception handling.".to_string(),
+                        description: "Adds the 32-bit values in `rs` and `rt`, and places the result in `rd`.\n\nIf adding `rs` and `rt` causes a 32-bit overflow, the result is not placed in `rd`, and an integer overflow exception is raised instead.".to_string(),
                     };
                     monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
                 }
@@ -145,7 +188,7 @@ pub fn read_instructions(
                 {
                     let info = InstructionDescription{
                         syntax: "sub rd, rs, rt".to_string(),
-                        description: "Subtracts the 32-bit value in `rt` from the 32-bit value in `rd`, and places the result in `rd`.\n\nIn hardware implementations, the result is not placed in `rd` if subtracting `rs` and `rt` causes a 32-bit overflow. However, SWIM places the result in `rd` regardless since there is no exception handling.".to_string(),
+                        description: "Subtracts the 32-bit value in `rt` from the 32-bit value in `rd`, and places the result in `rd`.\n\nIf subtracting `rs` and `rt` causes a 32-bit overflow, the result is not placed in `rd`, and an integer overflow exception is raised instead.".to_string(),
                     };
                     monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
                 }
@@ -244,6 +287,182 @@ pub fn read_instructions(
                     monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
                 }
             }
+            "lb" => {
+                instruction.binary = append_binary(instruction.binary, 0b100000, 6);
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, MemoryAddress],
+                    vec![3, 1, 2],
+                    None,
+                );
+
+                //Pseudo-instructions already have text in mouse_hover_string so we check if there's text there already before adding in the blurb
+                if monaco_line_info[instruction.line_number]
+                    .mouse_hover_string
+                    .is_empty()
+                {
+                    let info = InstructionDescription{
+                        syntax: "lb rt, offset(base)".to_string(),
+                        description: "Loads the byte at the specified memory address into `rt`, sign-extending it to 64 bits.\n\nMemory address is calculated as the sum of `offset` and the contents of the `base` register.".to_string(),
+                    };
+                    monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+                }
+            }
+            "lbu" => {
+                instruction.binary = append_binary(instruction.binary, 0b100100, 6);
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, MemoryAddress],
+                    vec![3, 1, 2],
+                    None,
+                );
+
+                //Pseudo-instructions already have text in mouse_hover_string so we check if there's text there already before adding in the blurb
+                if monaco_line_info[instruction.line_number]
+                    .mouse_hover_string
+                    .is_empty()
+                {
+                    let info = InstructionDescription{
+                        syntax: "lbu rt, offset(base)".to_string(),
+                        description: "Loads the byte at the specified memory address into `rt`, zero-extending it to 64 bits.\n\nMemory address is calculated as the sum of `offset` and the contents of the `base` register.".to_string(),
+                    };
+                    monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+                }
+            }
+            "lh" => {
+                instruction.binary = append_binary(instruction.binary, 0b100001, 6);
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, MemoryAddress],
+                    vec![3, 1, 2],
+                    None,
+                );
+
+                //Pseudo-instructions already have text in mouse_hover_string so we check if there's text there already before adding in the blurb
+                if monaco_line_info[instruction.line_number]
+                    .mouse_hover_string
+                    .is_empty()
+                {
+                    let info = InstructionDescription{
+                        syntax: "lh rt, offset(base)".to_string(),
+                        description: "Loads the 16-bit halfword at the specified memory address into `rt`, sign-extending it to 64 bits. The address must be a multiple of 2.\n\nMemory address is calculated as the sum of `offset` and the contents of the `base` register.".to_string(),
+                    };
+                    monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+                }
+            }
+            "lhu" => {
+                instruction.binary = append_binary(instruction.binary, 0b100101, 6);
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, MemoryAddress],
+                    vec![3, 1, 2],
+                    None,
+                );
+
+                //Pseudo-instructions already have text in mouse_hover_string so we check if there's text there already before adding in the blurb
+                if monaco_line_info[instruction.line_number]
+                    .mouse_hover_string
+                    .is_empty()
+                {
+                    let info = InstructionDescription{
+                        syntax: "lhu rt, offset(base)".to_string(),
+                        description: "Loads the 16-bit halfword at the specified memory address into `rt`, zero-extending it to 64 bits. The address must be a multiple of 2.\n\nMemory address is calculated as the sum of `offset` and the contents of the `base` register.".to_string(),
+                    };
+                    monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+                }
+            }
+            "sb" => {
+                instruction.binary = append_binary(instruction.binary, 0b101000, 6);
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, MemoryAddress],
+                    vec![3, 1, 2],
+                    None,
+                );
+
+                //Pseudo-instructions already have text in mouse_hover_string so we check if there's text there already before adding in the blurb
+                if monaco_line_info[instruction.line_number]
+                    .mouse_hover_string
+                    .is_empty()
+                {
+                    let info = InstructionDescription{
+                        syntax: "sb rt, offset(base)".to_string(),
+                        description: "Stores the value of the lower 8-bits in `rt` at the specified memory address.\n\nMemory address is calculated as the sum of `offset` and the contents of the `base` register.".to_string(),
+                    };
+                    monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+                }
+            }
+            "sh" => {
+                instruction.binary = append_binary(instruction.binary, 0b101001, 6);
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, MemoryAddress],
+                    vec![3, 1, 2],
+                    None,
+                );
+
+                //Pseudo-instructions already have text in mouse_hover_string so we check if there's text there already before adding in the blurb
+                if monaco_line_info[instruction.line_number]
+                    .mouse_hover_string
+                    .is_empty()
+                {
+                    let info = InstructionDescription{
+                        syntax: "sh rt, offset(base)".to_string(),
+                        description: "Stores the value of the lower 16-bits in `rt` at the specified memory address. The address must be a multiple of 2.\n\nMemory address is calculated as the sum of `offset` and the contents of the `base` register.".to_string(),
+                    };
+                    monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+                }
+            }
+            "ld" => {
+                instruction.binary = append_binary(instruction.binary, 0b110111, 6);
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, MemoryAddress],
+                    vec![3, 1, 2],
+                    None,
+                );
+
+                //Pseudo-instructions already have text in mouse_hover_string so we check if there's text there already before adding in the blurb
+                if monaco_line_info[instruction.line_number]
+                    .mouse_hover_string
+                    .is_empty()
+                {
+                    let info = InstructionDescription{
+                        syntax: "ld rt, offset(base)".to_string(),
+                        description: "Loads the contents of the 64-bit doubleword at the specified memory address into `rt`. The address must be a multiple of 8.\n\nMemory address is calculated as the sum of `offset` and the contents of the `base` register.".to_string(),
+                    };
+                    monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+                }
+            }
+            "sd" => {
+                instruction.binary = append_binary(instruction.binary, 0b111111, 6);
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, MemoryAddress],
+                    vec![3, 1, 2],
+                    None,
+                );
+
+                //Pseudo-instructions already have text in mouse_hover_string so we check if there's text there already before adding in the blurb
+                if monaco_line_info[instruction.line_number]
+                    .mouse_hover_string
+                    .is_empty()
+                {
+                    let info = InstructionDescription{
+                        syntax: "sd rt, offset(base)".to_string(),
+                        description: "Stores the 64-bit contents of `rt` at the specified memory address. The address must be a multiple of 8.\n\nMemory address is calculated as the sum of `offset` and the contents of the `base` register.".to_string(),
+                    };
+                    monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+                }
+            }
             "lui" => {
                 instruction.binary = append_binary(instruction.binary, 0b001111, 6);
                 instruction.binary = append_binary(instruction.binary, 0b00000, 5);
@@ -325,6 +544,23 @@ pub fn read_instructions(
                     monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
                 }
             }
+            "xori" => {
+                instruction.binary = append_binary(instruction.binary, 0b001110, 6);
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, Immediate],
+                    vec![2, 1, 3],
+                    None,
+                );
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "xori rt, rs, immediate".to_string(),
+                    description: "Bitwise exclusive ors the contents of `rs` with the left zero-extended `immediate` value, and stores the result in `rt`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
             "addi" => {
                 instruction.binary = append_binary(instruction.binary, 0b001000, 6); //addi
 
@@ -342,7 +578,7 @@ pub fn read_instructions(
                 {
                     let info = InstructionDescription{
                         syntax: "addi rt, rs, immediate".to_string(),
-                        description: "Adds the 32-bit value in `rs` and the 16-bit `immediate`, and places the result in `rt`.\n\nIn hardware implementations, the result is not placed in `rt` if adding `rs` and the `immediate` causes a 32-bit overflow. However, SWIM places the result in `rd`, regardless since there is no exception handling.".to_string(),
+                        description: "Adds the 32-bit value in `rs` and the 16-bit `immediate`, and places the result in `rt`.\n\nIf adding `rs` and the `immediate` causes a 32-bit overflow, the result is not placed in `rt`, and an integer overflow exception is raised instead.".to_string(),
                     };
                     monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
                 }
@@ -384,7 +620,7 @@ pub fn read_instructions(
                 {
                     let info = InstructionDescription{
                         syntax: "dadd rd, rs, rt".to_string(),
-                        description: "Adds the 64-bit values in `rs` and `rt`, and places the result in `rd`.\n\nIn hardware implementations, the result is not placed in `rd` if adding `rs` and `rt` causes a 64-bit overflow. However, SWIM places the result in `rd`, regardless since there is no exception handling.".to_string(),
+                        description: "Adds the 64-bit values in `rs` and `rt`, and places the result in `rd`.\n\nIf adding `rs` and `rt` causes a 64-bit overflow, the result is not placed in `rd`, and an integer overflow exception is raised instead.".to_string(),
                     };
                     monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
                 }
@@ -409,7 +645,7 @@ pub fn read_instructions(
                 {
                     let info = InstructionDescription{
                         syntax: "dsub rd, rs, rt".to_string(),
-                        description: "Subtracts the 64-bit values in `rt` from the 64-bit value in `rs`, and places the result in `rd`.\n\nIn hardware implementations, the result is not placed in `rd` if subtracting `rs` and `rt` causes a 64-bit overflow. However, SWIM places the result in `rd`, regardless since there is no exception handling.".to_string(),
+                        description: "Subtracts the 64-bit values in `rt` from the 64-bit value in `rs`, and places the result in `rd`.\n\nIf subtracting `rs` and `rt` causes a 64-bit overflow, the result is not placed in `rd`, and an integer overflow exception is raised instead.".to_string(),
                     };
                     monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
                 }
@@ -509,6 +745,46 @@ pub fn read_instructions(
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
+            "xor" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6);
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![2, 3, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5);
+                instruction.binary = append_binary(instruction.binary, 0b100110, 6);
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "xor rd, rs, rt".to_string(),
+                    description: "Bitwise exclusive ors the contents of `rs` with the contents of `rt`, and stores the result in `rd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "nor" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6);
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![2, 3, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5);
+                instruction.binary = append_binary(instruction.binary, 0b100111, 6);
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "nor rd, rs, rt".to_string(),
+                    description: "Bitwise nors the contents of `rs` with the contents of `rt`, and stores the result in `rd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
             "add.s" => {
                 instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
                 instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
@@ -676,744 +952,3972 @@ pub fn read_instructions(
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "dahi" => {
-                instruction.binary = append_binary(instruction.binary, 0b000001, 6); //regimm
+            "abs.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
 
-                read_operands(instruction, vec![RegisterGP, Immediate], vec![1, 2], None);
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
 
-                instruction.binary =
-                    place_binary_in_middle_of_another(instruction.binary, 0b00110, 5, 15);
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b000101, 6);
+                //abs
 
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
-                let info = InstructionDescription{
-                    syntax: "dahi rs, immediate".to_string(),
-                    description: "Adds the sign-extended 16-bit `immediate` value shifted left by 32 to the contents of `rs`, and stores the result in `rs`.".to_string(),
+                let info = InstructionDescription {
+                    syntax: "abs.s fd, fs".to_string(),
+                    description:
+                        "Stores the absolute value of the single-precision value in `fs` into `fd`."
+                            .to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "dati" => {
-                instruction.binary = append_binary(instruction.binary, 0b000001, 6); //regimm
+            "abs.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
 
-                read_operands(instruction, vec![RegisterGP, Immediate], vec![1, 2], None);
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
 
-                instruction.binary =
-                    place_binary_in_middle_of_another(instruction.binary, 0b11110, 5, 15);
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b000101, 6);
+                //abs
 
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
-                let info = InstructionDescription{
-                    syntax: "dati rs, immediate".to_string(),
-                    description: "Adds the sign-extended 16-bit `immediate` value shifted left by 48 to the contents of `rs`, and stores the result in `rs`.".to_string(),
+                let info = InstructionDescription {
+                    syntax: "abs.d fd, fs".to_string(),
+                    description:
+                        "Stores the absolute value of the double-precision value in `fs` into `fd`."
+                            .to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "daddi" => {
-                instruction.binary = append_binary(instruction.binary, 0b011000, 6); //daddi
+            "neg.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
 
-                read_operands(
-                    instruction,
-                    vec![RegisterGP, RegisterGP, Immediate],
-                    vec![2, 1, 3],
-                    None,
-                );
-                let info = InstructionDescription{
-                    syntax: "daddi rt, rs, immediate".to_string(),
-                    description: "Adds the 64-bit value in `rs` and the 16-bit `immediate`, and places the result in `rt`.\n\nIn hardware implementations, the result is not placed in `rt` if adding `rs` and `immediate` causes a 64-bit overflow. However, SWIM places the result in `rt`, regardless since there is no exception handling.".to_string(),
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b000111, 6);
+                //neg
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription {
+                    syntax: "neg.s fd, fs".to_string(),
+                    description:
+                        "Negates the single-precision value in `fs` and stores the result in `fd`."
+                            .to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "daddiu" => {
-                instruction.binary = append_binary(instruction.binary, 0b011001, 6); //daddiu
+            "neg.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
 
-                read_operands(
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b000111, 6);
+                //neg
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription {
+                    syntax: "neg.d fd, fs".to_string(),
+                    description:
+                        "Negates the double-precision value in `fs` and stores the result in `fd`."
+                            .to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "mov.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b000110, 6);
+                //mov
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription {
+                    syntax: "mov.s fd, fs".to_string(),
+                    description: "Copies the single-precision value in `fs` into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "mov.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b000110, 6);
+                //mov
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription {
+                    syntax: "mov.d fd, fs".to_string(),
+                    description: "Copies the double-precision value in `fs` into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "sqrt.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b000100, 6);
+                //sqrt
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "sqrt.s fd, fs".to_string(),
+                    description: "Calculates the square root of the single-precision value in `fs` and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "sqrt.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b000100, 6);
+                //sqrt
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "sqrt.d fd, fs".to_string(),
+                    description: "Calculates the square root of the double-precision value in `fs` and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "recip.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b010101, 6);
+                //recip
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "recip.s fd, fs".to_string(),
+                    description: "Calculates the reciprocal (1 / `fs`) of the single-precision value in `fs` and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "recip.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b010101, 6);
+                //recip
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "recip.d fd, fs".to_string(),
+                    description: "Calculates the reciprocal (1 / `fs`) of the double-precision value in `fs` and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "rsqrt.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b010110, 6);
+                //rsqrt
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "rsqrt.s fd, fs".to_string(),
+                    description: "Calculates the reciprocal of the square root of the single-precision value in `fs` and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "rsqrt.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b010110, 6);
+                //rsqrt
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "rsqrt.d fd, fs".to_string(),
+                    description: "Calculates the reciprocal of the square root of the double-precision value in `fs` and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "min.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b011100, 6);
+                //min
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "min.s fd, fs, ft".to_string(),
+                    description: "Stores the smaller of the single-precision values in `fs` and `ft` into `fd`. If only one of them is NaN, the other value is stored.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "min.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b011100, 6);
+                //min
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "min.d fd, fs, ft".to_string(),
+                    description: "Stores the smaller of the double-precision values in `fs` and `ft` into `fd`. If only one of them is NaN, the other value is stored.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "max.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b011110, 6);
+                //max
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "max.s fd, fs, ft".to_string(),
+                    description: "Stores the larger of the single-precision values in `fs` and `ft` into `fd`. If only one of them is NaN, the other value is stored.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "max.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b011110, 6);
+                //max
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "max.d fd, fs, ft".to_string(),
+                    description: "Stores the larger of the double-precision values in `fs` and `ft` into `fd`. If only one of them is NaN, the other value is stored.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "movz.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterGP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b010010, 6);
+                //movz
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "movz.s fd, fs, rt".to_string(),
+                    description: "If the contents of the general-purpose register `rt` are zero, copies the single-precision value in `fs` into `fd`. Otherwise, `fd` is left unchanged.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "movn.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterGP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b010011, 6);
+                //movn
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "movn.s fd, fs, rt".to_string(),
+                    description: "If the contents of the general-purpose register `rt` are not zero, copies the single-precision value in `fs` into `fd`. Otherwise, `fd` is left unchanged.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "movf.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                if instruction.operands.len() == 3 {
+                    //the condition code is given after fs
+                    read_operands(
+                        instruction,
+                        vec![RegisterFP, RegisterFP, ConditionCode],
+                        vec![3, 2, 1],
+                        None,
+                    );
+
+                    instruction.binary =
+                        place_binary_in_middle_of_another(instruction.binary, 0b00, 2, 9);
+                    //0 TF
+                } else {
+                    instruction.binary = append_binary(instruction.binary, 0b00000, 5); //CC 0 TF
+
+                    read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+                }
+
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6);
+                //movcf
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "movf.s fd, fs` (`cc` = 0 implied) || `movf.s fd, fs, cc".to_string(),
+                    description: "If condition code `cc` is 0, copies the single-precision value in `fs` into `fd`. Otherwise, `fd` is left unchanged.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "movt.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                if instruction.operands.len() == 3 {
+                    //the condition code is given after fs
+                    read_operands(
+                        instruction,
+                        vec![RegisterFP, RegisterFP, ConditionCode],
+                        vec![3, 2, 1],
+                        None,
+                    );
+
+                    instruction.binary =
+                        place_binary_in_middle_of_another(instruction.binary, 0b01, 2, 9);
+                    //0 TF
+                } else {
+                    instruction.binary = append_binary(instruction.binary, 0b00001, 5); //CC 0 TF
+
+                    read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+                }
+
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6);
+                //movcf
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "movt.s fd, fs` (`cc` = 0 implied) || `movt.s fd, fs, cc".to_string(),
+                    description: "If condition code `cc` is 1, copies the single-precision value in `fs` into `fd`. Otherwise, `fd` is left unchanged.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "sel.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b010000, 6);
+                //sel
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "sel.s fd, fs, ft".to_string(),
+                    description: "If the lowest bit of `fd` is 1, copies the single-precision value in `ft` into `fd`. Otherwise, copies the single-precision value in `fs` into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "seleqz.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b010100, 6);
+                //seleqz
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "seleqz.s fd, fs, ft".to_string(),
+                    description: "If the lowest bit of `ft` is 0, copies the single-precision value in `fs` into `fd`. Otherwise, stores the value 0 in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "selnez.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b010111, 6);
+                //selnez
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "selnez.s fd, fs, ft".to_string(),
+                    description: "If the lowest bit of `ft` is 1, copies the single-precision value in `fs` into `fd`. Otherwise, stores the value 0 in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "movz.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterGP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b010010, 6);
+                //movz
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "movz.d fd, fs, rt".to_string(),
+                    description: "If the contents of the general-purpose register `rt` are zero, copies the double-precision value in `fs` into `fd`. Otherwise, `fd` is left unchanged.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "movn.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterGP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b010011, 6);
+                //movn
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "movn.d fd, fs, rt".to_string(),
+                    description: "If the contents of the general-purpose register `rt` are not zero, copies the double-precision value in `fs` into `fd`. Otherwise, `fd` is left unchanged.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "movf.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                if instruction.operands.len() == 3 {
+                    //the condition code is given after fs
+                    read_operands(
+                        instruction,
+                        vec![RegisterFP, RegisterFP, ConditionCode],
+                        vec![3, 2, 1],
+                        None,
+                    );
+
+                    instruction.binary =
+                        place_binary_in_middle_of_another(instruction.binary, 0b00, 2, 9);
+                    //0 TF
+                } else {
+                    instruction.binary = append_binary(instruction.binary, 0b00000, 5); //CC 0 TF
+
+                    read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+                }
+
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6);
+                //movcf
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "movf.d fd, fs` (`cc` = 0 implied) || `movf.d fd, fs, cc".to_string(),
+                    description: "If condition code `cc` is 0, copies the double-precision value in `fs` into `fd`. Otherwise, `fd` is left unchanged.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "movt.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                if instruction.operands.len() == 3 {
+                    //the condition code is given after fs
+                    read_operands(
+                        instruction,
+                        vec![RegisterFP, RegisterFP, ConditionCode],
+                        vec![3, 2, 1],
+                        None,
+                    );
+
+                    instruction.binary =
+                        place_binary_in_middle_of_another(instruction.binary, 0b01, 2, 9);
+                    //0 TF
+                } else {
+                    instruction.binary = append_binary(instruction.binary, 0b00001, 5); //CC 0 TF
+
+                    read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+                }
+
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6);
+                //movcf
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "movt.d fd, fs` (`cc` = 0 implied) || `movt.d fd, fs, cc".to_string(),
+                    description: "If condition code `cc` is 1, copies the double-precision value in `fs` into `fd`. Otherwise, `fd` is left unchanged.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "sel.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b010000, 6);
+                //sel
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "sel.d fd, fs, ft".to_string(),
+                    description: "If the lowest bit of `fd` is 1, copies the double-precision value in `ft` into `fd`. Otherwise, copies the double-precision value in `fs` into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "seleqz.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b010100, 6);
+                //seleqz
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "seleqz.d fd, fs, ft".to_string(),
+                    description: "If the lowest bit of `ft` is 0, copies the double-precision value in `fs` into `fd`. Otherwise, stores the value 0 in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "selnez.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b010111, 6);
+                //selnez
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "selnez.d fd, fs, ft".to_string(),
+                    description: "If the lowest bit of `ft` is 1, copies the double-precision value in `fs` into `fd`. Otherwise, stores the value 0 in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cvt.s.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b100000, 6);
+                //cvt.s
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cvt.s.d fd, fs".to_string(),
+                    description: "Converts the double-precision value in `fs` to a single-precision value and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cvt.s.w" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (20)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b100000, 6);
+                //cvt.s
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cvt.s.w fd, fs".to_string(),
+                    description: "Converts the 32-bit integer in `fs` to a single-precision value and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cvt.s.l" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (21)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b100000, 6);
+                //cvt.s
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cvt.s.l fd, fs".to_string(),
+                    description: "Converts the 64-bit integer in `fs` to a single-precision value and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cvt.d.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b100001, 6);
+                //cvt.d
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cvt.d.s fd, fs".to_string(),
+                    description: "Converts the single-precision value in `fs` to a double-precision value and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cvt.d.w" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (20)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b100001, 6);
+                //cvt.d
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cvt.d.w fd, fs".to_string(),
+                    description: "Converts the 32-bit integer in `fs` to a double-precision value and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cvt.d.l" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (21)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b100001, 6);
+                //cvt.d
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cvt.d.l fd, fs".to_string(),
+                    description: "Converts the 64-bit integer in `fs` to a double-precision value and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cvt.w.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b100100, 6);
+                //cvt.w
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cvt.w.s fd, fs".to_string(),
+                    description: "Converts the single-precision value in `fs` to a 32-bit integer, rounding to the nearest integer, and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cvt.w.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b100100, 6);
+                //cvt.w
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cvt.w.d fd, fs".to_string(),
+                    description: "Converts the double-precision value in `fs` to a 32-bit integer, rounding to the nearest integer, and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cvt.l.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b100101, 6);
+                //cvt.l
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cvt.l.s fd, fs".to_string(),
+                    description: "Converts the single-precision value in `fs` to a 64-bit integer, rounding to the nearest integer, and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cvt.l.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b100101, 6);
+                //cvt.l
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cvt.l.d fd, fs".to_string(),
+                    description: "Converts the double-precision value in `fs` to a 64-bit integer, rounding to the nearest integer, and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "trunc.w.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b001101, 6);
+                //trunc.w
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "trunc.w.s fd, fs".to_string(),
+                    description: "Converts the single-precision value in `fs` to a 32-bit integer, rounding toward zero, and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "trunc.w.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b001101, 6);
+                //trunc.w
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "trunc.w.d fd, fs".to_string(),
+                    description: "Converts the double-precision value in `fs` to a 32-bit integer, rounding toward zero, and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "trunc.l.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b001001, 6);
+                //trunc.l
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "trunc.l.s fd, fs".to_string(),
+                    description: "Converts the single-precision value in `fs` to a 64-bit integer, rounding toward zero, and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "trunc.l.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b001001, 6);
+                //trunc.l
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "trunc.l.d fd, fs".to_string(),
+                    description: "Converts the double-precision value in `fs` to a 64-bit integer, rounding toward zero, and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "round.w.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b001100, 6);
+                //round.w
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "round.w.s fd, fs".to_string(),
+                    description: "Converts the single-precision value in `fs` to a 32-bit integer, rounding to the nearest integer (ties go to the even integer), and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "round.w.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b001100, 6);
+                //round.w
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "round.w.d fd, fs".to_string(),
+                    description: "Converts the double-precision value in `fs` to a 32-bit integer, rounding to the nearest integer (ties go to the even integer), and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "round.l.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b001000, 6);
+                //round.l
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "round.l.s fd, fs".to_string(),
+                    description: "Converts the single-precision value in `fs` to a 64-bit integer, rounding to the nearest integer (ties go to the even integer), and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "round.l.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b001000, 6);
+                //round.l
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "round.l.d fd, fs".to_string(),
+                    description: "Converts the double-precision value in `fs` to a 64-bit integer, rounding to the nearest integer (ties go to the even integer), and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "ceil.w.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b001110, 6);
+                //ceil.w
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "ceil.w.s fd, fs".to_string(),
+                    description: "Converts the single-precision value in `fs` to a 32-bit integer, rounding up toward positive infinity, and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "ceil.w.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b001110, 6);
+                //ceil.w
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "ceil.w.d fd, fs".to_string(),
+                    description: "Converts the double-precision value in `fs` to a 32-bit integer, rounding up toward positive infinity, and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "ceil.l.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b001010, 6);
+                //ceil.l
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "ceil.l.s fd, fs".to_string(),
+                    description: "Converts the single-precision value in `fs` to a 64-bit integer, rounding up toward positive infinity, and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "ceil.l.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b001010, 6);
+                //ceil.l
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "ceil.l.d fd, fs".to_string(),
+                    description: "Converts the double-precision value in `fs` to a 64-bit integer, rounding up toward positive infinity, and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "floor.w.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b001111, 6);
+                //floor.w
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "floor.w.s fd, fs".to_string(),
+                    description: "Converts the single-precision value in `fs` to a 32-bit integer, rounding down toward negative infinity, and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "floor.w.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b001111, 6);
+                //floor.w
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "floor.w.d fd, fs".to_string(),
+                    description: "Converts the double-precision value in `fs` to a 32-bit integer, rounding down toward negative infinity, and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "floor.l.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s (16)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b001011, 6);
+                //floor.l
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "floor.l.s fd, fs".to_string(),
+                    description: "Converts the single-precision value in `fs` to a 64-bit integer, rounding down toward negative infinity, and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "floor.l.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d (17)
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //ft: 0
+
+                read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b001011, 6);
+                //floor.l
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "floor.l.d fd, fs".to_string(),
+                    description: "Converts the double-precision value in `fs` to a 64-bit integer, rounding down toward negative infinity, and stores the result in `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "dahi" => {
+                instruction.binary = append_binary(instruction.binary, 0b000001, 6); //regimm
+
+                read_operands(instruction, vec![RegisterGP, Immediate], vec![1, 2], None);
+
+                instruction.binary =
+                    place_binary_in_middle_of_another(instruction.binary, 0b00110, 5, 15);
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "dahi rs, immediate".to_string(),
+                    description: "Adds the sign-extended 16-bit `immediate` value shifted left by 32 to the contents of `rs`, and stores the result in `rs`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "dati" => {
+                instruction.binary = append_binary(instruction.binary, 0b000001, 6); //regimm
+
+                read_operands(instruction, vec![RegisterGP, Immediate], vec![1, 2], None);
+
+                instruction.binary =
+                    place_binary_in_middle_of_another(instruction.binary, 0b11110, 5, 15);
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "dati rs, immediate".to_string(),
+                    description: "Adds the sign-extended 16-bit `immediate` value shifted left by 48 to the contents of `rs`, and stores the result in `rs`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "daddi" => {
+                instruction.binary = append_binary(instruction.binary, 0b011000, 6); //daddi
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, Immediate],
+                    vec![2, 1, 3],
+                    None,
+                );
+                let info = InstructionDescription{
+                    syntax: "daddi rt, rs, immediate".to_string(),
+                    description: "Adds the 64-bit value in `rs` and the 16-bit `immediate`, and places the result in `rt`.\n\nIf adding `rs` and `immediate` causes a 64-bit overflow, the result is not placed in `rt`, and an integer overflow exception is raised instead.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "daddiu" => {
+                instruction.binary = append_binary(instruction.binary, 0b011001, 6); //daddiu
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, Immediate],
+                    vec![2, 1, 3],
+                    None,
+                );
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "daddiu rt, rs, immediate".to_string(),
+                    description: "Adds the 64-bit value in `rs` and the 16-bit `immediate`, and places the result in `rt`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "daddu" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![2, 3, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
+                instruction.binary = append_binary(instruction.binary, 0b101101, 6);
+                //daddu
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "daddu rd, rs, rt".to_string(),
+                    description: "Adds the 64-bit values in `rs` and `rt`, and places the result in `rd`.\n\nIgnores overflow.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "dsubu" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![2, 3, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
+                instruction.binary = append_binary(instruction.binary, 0b101111, 6);
+                //dsubu
+
+                //Pseudo-instructions already have text in mouse_hover_string so we check if there's text there already before adding in the blurb
+                if monaco_line_info[instruction.line_number]
+                    .mouse_hover_string
+                    .is_empty()
+                {
+                    let info = InstructionDescription{
+                        syntax: "dsubu rd, rs, rt".to_string(),
+                        description: "Subtracts the 64-bit values in `rt` from the 64-bit value in `rs`, and places the result in `rd`.\n\nIgnores overflow.".to_string(),
+                    };
+                    monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+                }
+            }
+            "dmulu" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![2, 3, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b00010, 5); //dmulu
+                instruction.binary = append_binary(instruction.binary, 0b011101, 6);
+                //sop35
+
+                //Pseudo-instructions already have text in mouse_hover_string so we check if there's text there already before adding in the blurb
+                if monaco_line_info[instruction.line_number]
+                    .mouse_hover_string
+                    .is_empty()
+                {
+                    let info = InstructionDescription{
+                        syntax: "dmulu rd, rs, rt".to_string(),
+                        description: "Multiplies the signed 64-bit values in `rs` and `rt`, and places the lower 64 bits of the result in `rd`.".to_string(),
+                    };
+                    monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+                }
+            }
+            "ddivu" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![2, 3, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b00010, 5); //ddivu
+                instruction.binary = append_binary(instruction.binary, 0b011111, 6);
+                //DDIVU
+
+                //Pseudo-instructions already have text in mouse_hover_string so we check if there's text there already before adding in the blurb
+                if monaco_line_info[instruction.line_number]
+                    .mouse_hover_string
+                    .is_empty()
+                {
+                    let info = InstructionDescription{
+                        syntax: "ddivu rd, rs, rt".to_string(),
+                        description: "Divides the unsigned 64-bit value in `rs` by the unsigned 64-bit value in `rt` and places the quotient into `rd`.".to_string(),
+                    };
+                    monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+                }
+            }
+            "muh" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![2, 3, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b00011, 5); //muh
+                instruction.binary = append_binary(instruction.binary, 0b011000, 6);
+                //sop30
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "muh rd, rs, rt".to_string(),
+                    description: "Multiplies the signed 32-bit values in `rs` and `rt`, and places the upper 32 bits of the result in `rd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "muhu" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![2, 3, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b00011, 5); //muhu
+                instruction.binary = append_binary(instruction.binary, 0b011001, 6);
+                //sop31
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "muhu rd, rs, rt".to_string(),
+                    description: "Multiplies the unsigned 32-bit values in `rs` and `rt`, and places the upper 32 bits of the result in `rd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "mod" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![2, 3, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b00011, 5); //mod
+                instruction.binary = append_binary(instruction.binary, 0b011010, 6);
+                //sop32
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "mod rd, rs, rt".to_string(),
+                    description: "Divides the 32-bit value in `rs` by the 32-bit value in `rt` and places the 32-bit remainder into `rd`.\n\nThe remainder has the same sign as `rs`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "modu" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![2, 3, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b00011, 5); //modu
+                instruction.binary = append_binary(instruction.binary, 0b011011, 6);
+                //sop33
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "modu rd, rs, rt".to_string(),
+                    description: "Divides the unsigned 32-bit value in `rs` by the unsigned 32-bit value in `rt` and places the 32-bit remainder into `rd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "dmuh" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![2, 3, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b00011, 5); //dmuh
+                instruction.binary = append_binary(instruction.binary, 0b011100, 6);
+                //sop34
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "dmuh rd, rs, rt".to_string(),
+                    description: "Multiplies the signed 64-bit values in `rs` and `rt`, and places the upper 64 bits of the result in `rd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "dmuhu" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![2, 3, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b00011, 5); //dmuhu
+                instruction.binary = append_binary(instruction.binary, 0b011101, 6);
+                //sop35
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "dmuhu rd, rs, rt".to_string(),
+                    description: "Multiplies the unsigned 64-bit values in `rs` and `rt`, and places the upper 64 bits of the result in `rd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "dmod" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![2, 3, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b00011, 5); //dmod
+                instruction.binary = append_binary(instruction.binary, 0b011110, 6);
+                //sop36
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "dmod rd, rs, rt".to_string(),
+                    description: "Divides the 64-bit value in `rs` by the 64-bit value in `rt` and places the remainder into `rd`.\n\nThe remainder has the same sign as `rs`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "dmodu" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![2, 3, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b00011, 5); //dmodu
+                instruction.binary = append_binary(instruction.binary, 0b011111, 6);
+                //sop37
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "dmodu rd, rs, rt".to_string(),
+                    description: "Divides the unsigned 64-bit value in `rs` by the unsigned 64-bit value in `rt` and places the remainder into `rd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "slt" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![2, 3, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
+                instruction.binary = append_binary(instruction.binary, 0b101010, 6);
+                //slt
+
+                //Pseudo-instructions already have text in mouse_hover_string so we check if there's text there already before adding in the blurb
+                if monaco_line_info[instruction.line_number]
+                    .mouse_hover_string
+                    .is_empty()
+                {
+                    let info = InstructionDescription{
+                        syntax: "slt rd, rs, rt".to_string(),
+                        description: "Compares the contents of `rs` and `rt` as signed integers and stores the value 1 in `rd` if `rs` is less than rt. Otherwise, stores the value 0 in `rd`.".to_string(),
+                    };
+                    monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+                }
+            }
+            "sltu" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![2, 3, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
+                instruction.binary = append_binary(instruction.binary, 0b101011, 6);
+                //sltu
+
+                //Pseudo-instructions already have text in mouse_hover_string so we check if there's text there already before adding in the blurb
+                if monaco_line_info[instruction.line_number]
+                    .mouse_hover_string
+                    .is_empty()
+                {
+                    let info = InstructionDescription{
+                        syntax: "sltu rd, rs, rt".to_string(),
+                        description: "Compares the contents of `rs` and `rt` as unsigned integers and stores the value 1 in `rd` if `rs` is less than `rt`. Otherwise, stores the value 0 in `rd`.".to_string(),
+                    };
+                    monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+                }
+            }
+            "movz" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![2, 3, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
+                instruction.binary = append_binary(instruction.binary, 0b001010, 6);
+                //movz
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "movz rd, rs, rt".to_string(),
+                    description: "If the contents of `rt` are zero, copies the contents of `rs` into `rd`. Otherwise, `rd` is left unchanged.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "movn" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![2, 3, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
+                instruction.binary = append_binary(instruction.binary, 0b001011, 6);
+                //movn
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "movn rd, rs, rt".to_string(),
+                    description: "If the contents of `rt` are not zero, copies the contents of `rs` into `rd`. Otherwise, `rd` is left unchanged.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "movf" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+
+                if instruction.operands.len() == 3 {
+                    //the condition code is given after rs
+                    read_operands(
+                        instruction,
+                        vec![RegisterGP, RegisterGP, ConditionCode],
+                        vec![2, 3, 1],
+                        None,
+                    );
+
+                    instruction.binary =
+                        place_binary_in_middle_of_another(instruction.binary, 0b00, 2, 4);
+                    //0 TF
+                } else {
+                    read_operands(instruction, vec![RegisterGP, RegisterGP], vec![2, 1], None);
+
+                    instruction.binary =
+                        place_binary_in_middle_of_another(instruction.binary, 0b00000, 5, 4);
+                    //CC 0 TF (cc = 0 implied)
+                }
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
+                instruction.binary = append_binary(instruction.binary, 0b000001, 6);
+                //movci
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "movf rd, rs` (`cc` = 0 implied) || `movf rd, rs, cc".to_string(),
+                    description: "If condition code `cc` is 0, copies the contents of `rs` into `rd`. Otherwise, `rd` is left unchanged.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "movt" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+
+                if instruction.operands.len() == 3 {
+                    //the condition code is given after rs
+                    read_operands(
+                        instruction,
+                        vec![RegisterGP, RegisterGP, ConditionCode],
+                        vec![2, 3, 1],
+                        None,
+                    );
+
+                    instruction.binary =
+                        place_binary_in_middle_of_another(instruction.binary, 0b01, 2, 4);
+                    //0 TF
+                } else {
+                    read_operands(instruction, vec![RegisterGP, RegisterGP], vec![2, 1], None);
+
+                    instruction.binary =
+                        place_binary_in_middle_of_another(instruction.binary, 0b00001, 5, 4);
+                    //CC 0 TF (cc = 0 implied)
+                }
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
+                instruction.binary = append_binary(instruction.binary, 0b000001, 6);
+                //movci
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "movt rd, rs` (`cc` = 0 implied) || `movt rd, rs, cc".to_string(),
+                    description: "If condition code `cc` is 1, copies the contents of `rs` into `rd`. Otherwise, `rd` is left unchanged.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "seleqz" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![2, 3, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
+                instruction.binary = append_binary(instruction.binary, 0b110101, 6);
+                //seleqz
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "seleqz rd, rs, rt".to_string(),
+                    description: "If the contents of `rt` are zero, copies the contents of `rs` into `rd`. Otherwise, stores the value 0 in `rd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "selnez" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![2, 3, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
+                instruction.binary = append_binary(instruction.binary, 0b110111, 6);
+                //selnez
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "selnez rd, rs, rt".to_string(),
+                    description: "If the contents of `rt` are not zero, copies the contents of `rs` into `rd`. Otherwise, stores the value 0 in `rd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "swc1" => {
+                instruction.binary = append_binary(instruction.binary, 0b111001, 6); //swc1
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, MemoryAddress],
+                    vec![3, 1, 2],
+                    None,
+                );
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "swc1 ft, offset(base)".to_string(),
+                    description: "Stores the value of the lower 32 bits in `ft` at the specified memory address.\n\nMemory address is calculated as the sum of `offset` and the contents of the `base` register.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "lwc1" => {
+                instruction.binary = append_binary(instruction.binary, 0b110001, 6); //lwc1
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, MemoryAddress],
+                    vec![3, 1, 2],
+                    None,
+                );
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "lwc1 ft offset(base)".to_string(),
+                    description: "Loads the contents of the 32-bit word at the specified memory address into `ft`.\n\nMemory address is calculated as the sum of `offset` and the contents of the `base` register.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "ldc1" => {
+                instruction.binary = append_binary(instruction.binary, 0b110101, 6);
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, MemoryAddress],
+                    vec![3, 1, 2],
+                    None,
+                );
+
+                //Pseudo-instructions already have text in mouse_hover_string so we check if there's text there already before adding in the blurb
+                if monaco_line_info[instruction.line_number]
+                    .mouse_hover_string
+                    .is_empty()
+                {
+                    let info = InstructionDescription{
+                        syntax: "ldc1 ft, offset(base)".to_string(),
+                        description: "Loads the contents of the 64-bit doubleword at the specified memory address into `ft`. The address must be a multiple of 8.\n\nMemory address is calculated as the sum of `offset` and the contents of the `base` register.".to_string(),
+                    };
+                    monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+                }
+            }
+            "sdc1" => {
+                instruction.binary = append_binary(instruction.binary, 0b111101, 6);
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, MemoryAddress],
+                    vec![3, 1, 2],
+                    None,
+                );
+
+                //Pseudo-instructions already have text in mouse_hover_string so we check if there's text there already before adding in the blurb
+                if monaco_line_info[instruction.line_number]
+                    .mouse_hover_string
+                    .is_empty()
+                {
+                    let info = InstructionDescription{
+                        syntax: "sdc1 ft, offset(base)".to_string(),
+                        description: "Stores the 64-bit contents of `ft` at the specified memory address. The address must be a multiple of 8.\n\nMemory address is calculated as the sum of `offset` and the contents of the `base` register.".to_string(),
+                    };
+                    monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+                }
+            }
+            "mtc1" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b00100, 5); //mt
+
+                read_operands(instruction, vec![RegisterGP, RegisterFP], vec![1, 2], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b00000000000, 11);
+                //0
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription {
+                    syntax: "mtc1 rt, fs".to_string(),
+                    description: "Moves the lower 32 bits in `rt` into the lower 32 bits in `fs`."
+                        .to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "dmtc1" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b00101, 5); //dmt
+
+                read_operands(instruction, vec![RegisterGP, RegisterFP], vec![1, 2], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b00000000000, 11);
+                //0
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription {
+                    syntax: "dmtc1 rt, fs".to_string(),
+                    description: "Moves the double-word contents in `rt` into `fs`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "mfc1" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //mf
+
+                read_operands(instruction, vec![RegisterGP, RegisterFP], vec![1, 2], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b00000000000, 11);
+                //0
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription {
+                    syntax: "mfc1 rt, fs".to_string(),
+                    description: "Sign-extends contents in `fs` and moves it into `rt`."
+                        .to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "dmfc1" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b00001, 5); //dmf
+
+                read_operands(instruction, vec![RegisterGP, RegisterFP], vec![1, 2], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b00000000000, 11);
+                //0
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription {
+                    syntax: "dmfc1 rt, fs".to_string(),
+                    description: "Moves the double-word contents in `fs` into `rt`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "ctc1" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b00110, 5); //ct
+
+                read_operands(instruction, vec![RegisterGP, RegisterGP], vec![1, 2], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b00000000000, 11);
+                //0
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription {
+                    syntax: "ctc1 rt, fs".to_string(),
+                    description: "Moves the lower 32 bits in `rt` into the floating-point control register `fs`.\n\nThe supported control registers are FIR (`$0`, read-only), FCCR (`$25`), FEXR (`$26`), FENR (`$28`), and FCSR (`$31`).".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cfc1" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b00010, 5); //cf
+
+                read_operands(instruction, vec![RegisterGP, RegisterGP], vec![1, 2], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b00000000000, 11);
+                //0
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription {
+                    syntax: "cfc1 rt, fs".to_string(),
+                    description: "Sign-extends the floating-point control register `fs` and moves it into `rt`.\n\nThe supported control registers are FIR (`$0`), FCCR (`$25`), FEXR (`$26`), FENR (`$28`), and FCSR (`$31`).".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "mtc0" => {
+                instruction.binary = append_binary(instruction.binary, 0b010000, 6); //cop0
+                instruction.binary = append_binary(instruction.binary, 0b00100, 5); //mt
+
+                read_operands(instruction, vec![RegisterGP, RegisterGP], vec![1, 2], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b00000000000, 11);
+                //0 and sel
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription {
+                    syntax: "mtc0 rt, rd".to_string(),
+                    description: "Moves the lower 32 bits in `rt` into the coprocessor 0 register `rd`.\n\nThe supported coprocessor 0 registers are BadVAddr (`$8`), Status (`$12`), Cause (`$13`), and EPC (`$14`). BadVAddr and Cause cannot be written to.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "mfc0" => {
+                instruction.binary = append_binary(instruction.binary, 0b010000, 6); //cop0
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //mf
+
+                read_operands(instruction, vec![RegisterGP, RegisterGP], vec![1, 2], None);
+
+                instruction.binary = append_binary(instruction.binary, 0b00000000000, 11);
+                //0 and sel
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription {
+                    syntax: "mfc0 rt, rd".to_string(),
+                    description: "Sign-extends the lower 32 bits of the coprocessor 0 register `rd` and moves it into `rt`.\n\nThe supported coprocessor 0 registers are BadVAddr (`$8`), Status (`$12`), Cause (`$13`), and EPC (`$14`).".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "eret" => {
+                instruction.binary = append_binary(instruction.binary, 0b010000, 6); //cop0
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //co
+                instruction.binary = append_binary(instruction.binary, 0b000000000000000, 15); //0
+                instruction.binary = append_binary(instruction.binary, 0b011000, 6); //eret
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription {
+                    syntax: "eret".to_string(),
+                    description: "Returns from an exception handler to the address in the EPC register, and clears the exception level bit of the Status register.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "break" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+                instruction.binary = append_binary(instruction.binary, 0b00000000000000000000, 20); //stub of code
+                instruction.binary = append_binary(instruction.binary, 0b001101, 6); //break
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription {
+                    syntax: "break".to_string(),
+                    description: "Raises a breakpoint exception.\n\nIf the program has an `exception_handler` label, execution continues from there. Otherwise, the program ends.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "j" => {
+                instruction.binary = append_binary(instruction.binary, 0b000010, 6); //j
+
+                read_operands(
+                    instruction,
+                    vec![LabelAbsolute],
+                    vec![1],
+                    Some(labels.clone()),
+                );
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription {
+                    syntax: "j target".to_string(),
+                    description:
+                        "Moves the program counter to point to the targeted instruction’s address."
+                            .to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "jr" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+
+                read_operands(instruction, vec![RegisterGP], vec![1], None);
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); // rt
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); // rd
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); // shift/hint
+
+                instruction.binary = append_binary(instruction.binary, 0b001001, 6); //jalr
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "jr rs".to_string(),
+                    description: "Reads the contents of the register as an address and moves the program counter to point to that instruction.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "jal" => {
+                instruction.binary = append_binary(instruction.binary, 0b000011, 6); //jal
+
+                read_operands(
+                    instruction,
+                    vec![LabelAbsolute],
+                    vec![1],
+                    Some(labels.clone()),
+                );
+
+                //this instruction is not used  in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "jal target".to_string(),
+                    description: "Execute a procedure call. Sets the $ra (\"return address\") register to the next instruction, then moves the program counter to point to the targeted instruction’s address.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "jalr" => {
+                //JALR is unique in a number of ways.
+                //if rd is left blank, rd = 31 is implied.
+                //in release 6, rd cannot be 00000
+                //it makes use of hint bits in some releases. In our implementation, we are not implementing hint bits.
+
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+
+                if instruction.operands.len() == 1 {
+                    //if len() == 1, rd is omitted and assumed to equal 31
+                    read_operands(instruction, vec![RegisterGP], vec![1], None);
+                    instruction.binary = append_binary(instruction.binary, 0b11111, 5);
+                    //rd = 31
+                } else {
+                    if !instruction.operands.is_empty()
+                        && instruction.operands[0].token_name == "$zero"
+                    {
+                        instruction.errors.push(Error {
+                            error_name: JALRRDRegisterZero,
+                            token_causing_error: "$zero".to_string(),
+                            start_end_columns: instruction.operands[0].start_end_columns,
+                            message: "".to_string(),
+                        })
+                    }
+                    read_operands(instruction, vec![RegisterGP, RegisterGP], vec![2, 1], None);
+                }
+
+                instruction.binary =
+                    place_binary_in_middle_of_another(instruction.binary, 0b00000, 5, 5); //0
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //hint
+                instruction.binary = append_binary(instruction.binary, 0b001001, 6);
+                //JALR
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "jalr rs` (`rd` = `$ra` implied) || `jalr rd, rs".to_string(),
+                    description: "Execute a procedure call. Sets the $ra (\"return address\") register to the next instruction, then moves the program counter to point to the address read from `rs`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "beq" => {
+                instruction.binary = append_binary(instruction.binary, 0b000100, 6); //beq
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, LabelRelative],
+                    vec![1, 2, 3],
+                    Some(labels.clone()),
+                );
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "beq rs, rt, target".to_string(),
+                    description: "Compares the contents of `rs` and `rt` and, if they are equal, moves the program counter to point to the targeted instruction’s address.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "b" => {
+                instruction.binary = append_binary(instruction.binary, 0b000100, 6); //beq
+
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
+
+                read_operands(
+                    instruction,
+                    vec![LabelRelative],
+                    vec![1],
+                    Some(labels.clone()),
+                );
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription {
+                    syntax: "b target".to_string(),
+                    description:
+                        "Moves the program counter to point to the targeted instruction’s address."
+                            .to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "bne" => {
+                instruction.binary = append_binary(instruction.binary, 0b000101, 6); //bne
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, LabelRelative],
+                    vec![1, 2, 3],
+                    Some(labels.clone()),
+                );
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "bne rs, rt, target".to_string(),
+                    description: "Compares the contents of `rs` and `rt` and, if they are not equal, moves the program counter to point to the targeted instruction’s address.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "blez" => {
+                instruction.binary = append_binary(instruction.binary, 0b000110, 6); //blez
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, LabelRelative],
+                    vec![1, 2],
+                    Some(labels.clone()),
+                );
+
+                instruction.binary =
+                    place_binary_in_middle_of_another(instruction.binary, 0b00000, 5, 15);
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "blez rs, target".to_string(),
+                    description: "If the contents of `rs` are less than or equal to zero, moves the program counter to point to the targeted instruction’s address.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "bgtz" => {
+                instruction.binary = append_binary(instruction.binary, 0b000111, 6); //bgtz
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, LabelRelative],
+                    vec![1, 2],
+                    Some(labels.clone()),
+                );
+
+                instruction.binary =
+                    place_binary_in_middle_of_another(instruction.binary, 0b00000, 5, 15);
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "bgtz rs, target".to_string(),
+                    description: "If the contents of `rs` are greater than zero, moves the program counter to point to the targeted instruction’s address.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "bltz" => {
+                instruction.binary = append_binary(instruction.binary, 0b000001, 6); //regimm
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, LabelRelative],
+                    vec![1, 2],
+                    Some(labels.clone()),
+                );
+
+                instruction.binary =
+                    place_binary_in_middle_of_another(instruction.binary, 0b00000, 5, 15);
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "bltz rs, target".to_string(),
+                    description: "If the contents of `rs` are less than zero, moves the program counter to point to the targeted instruction’s address.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "bgez" => {
+                instruction.binary = append_binary(instruction.binary, 0b000001, 6); //regimm
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, LabelRelative],
+                    vec![1, 2],
+                    Some(labels.clone()),
+                );
+
+                instruction.binary =
+                    place_binary_in_middle_of_another(instruction.binary, 0b00001, 5, 15);
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "bgez rs, target".to_string(),
+                    description: "If the contents of `rs` are greater than or equal to zero, moves the program counter to point to the targeted instruction’s address.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "bltzal" => {
+                instruction.binary = append_binary(instruction.binary, 0b000001, 6); //regimm
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, LabelRelative],
+                    vec![1, 2],
+                    Some(labels.clone()),
+                );
+
+                instruction.binary =
+                    place_binary_in_middle_of_another(instruction.binary, 0b10000, 5, 15);
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "bltzal rs, target".to_string(),
+                    description: "Sets the $ra (\"return address\") register to the next instruction. Then, if the contents of `rs` are less than zero, moves the program counter to point to the targeted instruction’s address.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "bgezal" => {
+                instruction.binary = append_binary(instruction.binary, 0b000001, 6); //regimm
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, LabelRelative],
+                    vec![1, 2],
+                    Some(labels.clone()),
+                );
+
+                instruction.binary =
+                    place_binary_in_middle_of_another(instruction.binary, 0b10001, 5, 15);
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "bgezal rs, target".to_string(),
+                    description: "Sets the $ra (\"return address\") register to the next instruction. Then, if the contents of `rs` are greater than or equal to zero, moves the program counter to point to the targeted instruction’s address.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "bltc" => {
+                instruction.binary = append_binary(instruction.binary, 0b010111, 6); //pop27
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, LabelRelative],
+                    vec![1, 2, 3],
+                    Some(labels.clone()),
+                );
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "bltc rs, rt, target".to_string(),
+                    description: "Compares the contents of `rs` and `rt` as signed integers and, if `rs` is less than `rt`, moves the program counter to point to the targeted instruction’s address. `rs` and `rt` must be different, non-zero registers.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "bgec" => {
+                instruction.binary = append_binary(instruction.binary, 0b010110, 6); //pop26
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, LabelRelative],
+                    vec![1, 2, 3],
+                    Some(labels.clone()),
+                );
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "bgec rs, rt, target".to_string(),
+                    description: "Compares the contents of `rs` and `rt` as signed integers and, if `rs` is greater than or equal to `rt`, moves the program counter to point to the targeted instruction’s address. `rs` and `rt` must be different, non-zero registers.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "beqzc" => {
+                instruction.binary = append_binary(instruction.binary, 0b110110, 6); //pop66
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, LabelRelative21],
+                    vec![1, 2],
+                    Some(labels.clone()),
+                );
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "beqzc rs, target".to_string(),
+                    description: "If the contents of `rs` are equal to zero, moves the program counter to point to the targeted instruction’s address. `rs` cannot be `$zero`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "bnezc" => {
+                instruction.binary = append_binary(instruction.binary, 0b111110, 6); //pop76
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, LabelRelative21],
+                    vec![1, 2],
+                    Some(labels.clone()),
+                );
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "bnezc rs, target".to_string(),
+                    description: "If the contents of `rs` are not equal to zero, moves the program counter to point to the targeted instruction’s address. `rs` cannot be `$zero`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "beqc" | "bnec" => {
+                //pop10 is shared with addi, and pop30 with daddi
+                let opcode = match &*instruction.operator.token_name.to_lowercase() {
+                    "beqc" => 0b001000,
+                    _ => 0b011000,
+                };
+                instruction.binary = append_binary(instruction.binary, opcode, 6);
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, LabelRelative],
+                    vec![1, 2, 3],
+                    Some(labels.clone()),
+                );
+                order_compact_branch_registers(instruction, true);
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = match opcode {
+                    0b001000 => InstructionDescription{
+                        syntax: "beqc rs, rt, target".to_string(),
+                        description: "If the contents of `rs` and `rt` are equal, moves the program counter to point to the targeted instruction’s address. `rs` and `rt` must be different, non-zero registers. Only available in Release 6 mode.".to_string(),
+                    },
+                    _ => InstructionDescription{
+                        syntax: "bnec rs, rt, target".to_string(),
+                        description: "If the contents of `rs` and `rt` are not equal, moves the program counter to point to the targeted instruction’s address. `rs` and `rt` must be different, non-zero registers. Only available in Release 6 mode.".to_string(),
+                    },
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "bovc" | "bnvc" => {
+                //pop10 is shared with addi, and pop30 with daddi
+                let opcode = match &*instruction.operator.token_name.to_lowercase() {
+                    "bovc" => 0b001000,
+                    _ => 0b011000,
+                };
+                instruction.binary = append_binary(instruction.binary, opcode, 6);
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, RegisterGP, LabelRelative],
+                    vec![1, 2, 3],
+                    Some(labels.clone()),
+                );
+                order_compact_branch_registers(instruction, false);
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = match opcode {
+                    0b001000 => InstructionDescription{
+                        syntax: "bovc rs, rt, target".to_string(),
+                        description: "Adds the contents of `rs` and `rt` as signed 32-bit integers and, if the addition overflows, moves the program counter to point to the targeted instruction’s address. A register that does not hold a sign-extended 32-bit value counts as overflowing. Only available in Release 6 mode.".to_string(),
+                    },
+                    _ => InstructionDescription{
+                        syntax: "bnvc rs, rt, target".to_string(),
+                        description: "Adds the contents of `rs` and `rt` as signed 32-bit integers and, if the addition does not overflow, moves the program counter to point to the targeted instruction’s address. A register that does not hold a sign-extended 32-bit value counts as overflowing. Only available in Release 6 mode.".to_string(),
+                    },
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "beqzalc" | "bnezalc" => {
+                //pop10 is shared with addi, and pop30 with daddi
+                let opcode = match &*instruction.operator.token_name.to_lowercase() {
+                    "beqzalc" => 0b001000,
+                    _ => 0b011000,
+                };
+                instruction.binary = append_binary(instruction.binary, opcode, 6);
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //rs = 0
+
+                read_operands(
+                    instruction,
+                    vec![RegisterGP, LabelRelative],
+                    vec![1, 2],
+                    Some(labels.clone()),
+                );
+
+                //with rt = 0, the encoding is instead bovc or bnvc
+                if instruction.errors.is_empty() && (instruction.binary >> 16) & 0b11111 == 0 {
+                    instruction.errors.push(Error {
+                        error_name: InvalidCompactBranchRegisters,
+                        token_causing_error: instruction.operands[0].token_name.clone(),
+                        start_end_columns: instruction.operands[0].start_end_columns,
+                        message: "".to_string(),
+                    });
+                }
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = match opcode {
+                    0b001000 => InstructionDescription{
+                        syntax: "beqzalc rt, target".to_string(),
+                        description: "Sets the $ra (\"return address\") register to the next instruction. Then, if the contents of `rt` are equal to zero, moves the program counter to point to the targeted instruction’s address. `rt` cannot be `$zero`. Only available in Release 6 mode.".to_string(),
+                    },
+                    _ => InstructionDescription{
+                        syntax: "bnezalc rt, target".to_string(),
+                        description: "Sets the $ra (\"return address\") register to the next instruction. Then, if the contents of `rt` are not equal to zero, moves the program counter to point to the targeted instruction’s address. `rt` cannot be `$zero`. Only available in Release 6 mode.".to_string(),
+                    },
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "bc" => {
+                instruction.binary = append_binary(instruction.binary, 0b110010, 6); //bc
+
+                read_operands(
+                    instruction,
+                    vec![LabelRelative26],
+                    vec![1],
+                    Some(labels.clone()),
+                );
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription {
+                    syntax: "bc target".to_string(),
+                    description:
+                        "Moves the program counter to point to the targeted instruction’s address."
+                            .to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "balc" => {
+                instruction.binary = append_binary(instruction.binary, 0b111010, 6); //balc
+
+                read_operands(
+                    instruction,
+                    vec![LabelRelative26],
+                    vec![1],
+                    Some(labels.clone()),
+                );
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "balc target".to_string(),
+                    description: "Execute a procedure call. Sets the $ra (\"return address\") register to the next instruction, then moves the program counter to point to the targeted instruction’s address.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "c.eq.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s
+
+                if instruction.operands.len() == 3 {
+                    //the condition code is given before fs and ft
+                    read_operands(
+                        instruction,
+                        vec![ConditionCode, RegisterFP, RegisterFP],
+                        vec![3, 2, 1],
+                        None,
+                    );
+                } else {
+                    read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+                    instruction.binary = append_binary(instruction.binary, 0b000, 3);
+                    //cc = 0 implied
+                }
+
+                instruction.binary = append_binary(instruction.binary, 0b0011, 4); //0, A, FC
+                instruction.binary = append_binary(instruction.binary, 0b0010, 4);
+                //EQ
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "c.eq.s fs, ft` (`cc` = 0 implied) || `c.eq.s cc, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if they are equal, stores the value 1 into condition code `cc`. Otherwise, stores the value 0 into condition code `cc`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "c.eq.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d
+
+                if instruction.operands.len() == 3 {
+                    //the condition code is given before fs and ft
+                    read_operands(
+                        instruction,
+                        vec![ConditionCode, RegisterFP, RegisterFP],
+                        vec![3, 2, 1],
+                        None,
+                    );
+                } else {
+                    read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+                    instruction.binary = append_binary(instruction.binary, 0b000, 3);
+                    //cc = 0 implied
+                }
+
+                instruction.binary = append_binary(instruction.binary, 0b0011, 4); //0, A, FC
+                instruction.binary = append_binary(instruction.binary, 0b0010, 4);
+                //EQ
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "c.eq.d fs, ft` (`cc` = 0 implied) || `c.eq.d cc, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if they are equal, stores the value 1 into condition code `cc`. Otherwise, stores the value 0 into condition code `cc`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "c.lt.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s
+
+                if instruction.operands.len() == 3 {
+                    //the condition code is given before fs and ft
+                    read_operands(
+                        instruction,
+                        vec![ConditionCode, RegisterFP, RegisterFP],
+                        vec![3, 2, 1],
+                        None,
+                    );
+                } else {
+                    read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+                    instruction.binary = append_binary(instruction.binary, 0b000, 3);
+                    //cc = 0 implied
+                }
+
+                instruction.binary = append_binary(instruction.binary, 0b0011, 4); //0, A, FC
+                instruction.binary = append_binary(instruction.binary, 0b1100, 4);
+                //lt
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "c.lt.s fs, ft` (`cc` = 0 implied) || `c.lt.s cc, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if the contents of `fs` is less than the contents of `ft`, stores the value 1 into condition code `cc`. Otherwise, stores the value 0 into condition code `cc`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "c.lt.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d
+
+                if instruction.operands.len() == 3 {
+                    //the condition code is given before fs and ft
+                    read_operands(
+                        instruction,
+                        vec![ConditionCode, RegisterFP, RegisterFP],
+                        vec![3, 2, 1],
+                        None,
+                    );
+                } else {
+                    read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+                    instruction.binary = append_binary(instruction.binary, 0b000, 3);
+                    //cc = 0 implied
+                }
+
+                instruction.binary = append_binary(instruction.binary, 0b0011, 4); //0, A, FC
+                instruction.binary = append_binary(instruction.binary, 0b1100, 4);
+                //lt
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "c.lt.d fs, ft` (`cc` = 0 implied) || `c.lt.d cc, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if the contents of `fs` is less than the contents of `ft`, stores the value 1 into condition code `cc`. Otherwise, stores the value 0 into condition code `cc`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "c.le.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s
+
+                if instruction.operands.len() == 3 {
+                    //the condition code is given before fs and ft
+                    read_operands(
+                        instruction,
+                        vec![ConditionCode, RegisterFP, RegisterFP],
+                        vec![3, 2, 1],
+                        None,
+                    );
+                } else {
+                    read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+                    instruction.binary = append_binary(instruction.binary, 0b000, 3);
+                    //cc = 0 implied
+                }
+
+                instruction.binary = append_binary(instruction.binary, 0b0011, 4); //0, A, FC
+                instruction.binary = append_binary(instruction.binary, 0b1110, 4);
+                //le
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "c.le.s fs, ft` (`cc` = 0 implied) || `c.le.s cc, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if the contents of `fs` is less than or equal to the contents of `ft`, stores the value 1 into condition code `cc`. Otherwise, stores the value 0 into condition code `cc`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "c.le.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d
+
+                if instruction.operands.len() == 3 {
+                    //the condition code is given before fs and ft
+                    read_operands(
+                        instruction,
+                        vec![ConditionCode, RegisterFP, RegisterFP],
+                        vec![3, 2, 1],
+                        None,
+                    );
+                } else {
+                    read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+                    instruction.binary = append_binary(instruction.binary, 0b000, 3);
+                    //cc = 0 implied
+                }
+
+                instruction.binary = append_binary(instruction.binary, 0b0011, 4); //0, A, FC
+                instruction.binary = append_binary(instruction.binary, 0b1110, 4);
+                //le
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "c.le.d fs, ft` (`cc` = 0 implied) || `c.le.d cc, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if the contents of `fs` is less than or equal to the contents of `ft`, stores the value 1 into condition code `cc`. Otherwise, stores the value 0 into condition code `cc`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "c.ngt.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s
+
+                if instruction.operands.len() == 3 {
+                    //the condition code is given before fs and ft
+                    read_operands(
+                        instruction,
+                        vec![ConditionCode, RegisterFP, RegisterFP],
+                        vec![3, 2, 1],
+                        None,
+                    );
+                } else {
+                    read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+                    instruction.binary = append_binary(instruction.binary, 0b000, 3);
+                    //cc = 0 implied
+                }
+
+                instruction.binary = append_binary(instruction.binary, 0b0011, 4); //0, A, FC
+                instruction.binary = append_binary(instruction.binary, 0b1111, 4);
+                //ngt
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "c.ngt.s fs, ft` (`cc` = 0 implied) || `c.ngt.s cc, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if the contents of `fs` not greater than the contents of `ft`, stores the value 1 into condition code `cc`. Otherwise, stores the value 0 into condition code `cc`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "c.ngt.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d
+
+                if instruction.operands.len() == 3 {
+                    //the condition code is given before fs and ft
+                    read_operands(
+                        instruction,
+                        vec![ConditionCode, RegisterFP, RegisterFP],
+                        vec![3, 2, 1],
+                        None,
+                    );
+                } else {
+                    read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+                    instruction.binary = append_binary(instruction.binary, 0b000, 3);
+                    //cc = 0 implied
+                }
+
+                instruction.binary = append_binary(instruction.binary, 0b0011, 4); //0, A, FC
+                instruction.binary = append_binary(instruction.binary, 0b1111, 4);
+                //ngt
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "c.ngt.d fs, ft` (`cc` = 0 implied) || `c.ngt.d cc, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if the contents of `fs` not greater than the contents of `ft`, stores the value 1 into condition code `cc`. Otherwise, stores the value 0 into condition code `cc`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "c.nge.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s
+
+                if instruction.operands.len() == 3 {
+                    //the condition code is given before fs and ft
+                    read_operands(
+                        instruction,
+                        vec![ConditionCode, RegisterFP, RegisterFP],
+                        vec![3, 2, 1],
+                        None,
+                    );
+                } else {
+                    read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+                    instruction.binary = append_binary(instruction.binary, 0b000, 3);
+                    //cc = 0 implied
+                }
+
+                instruction.binary = append_binary(instruction.binary, 0b0011, 4); //0, A, FC
+                instruction.binary = append_binary(instruction.binary, 0b1101, 4);
+                //nge
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "c.nge.s fs, ft` (`cc` = 0 implied) || `c.nge.s cc, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if the contents of `fs` not greater than or equal to the contents of `ft`, stores the value 1 into condition code `cc`. Otherwise, stores the value 0 into condition code `cc`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "c.nge.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d
+
+                if instruction.operands.len() == 3 {
+                    //the condition code is given before fs and ft
+                    read_operands(
+                        instruction,
+                        vec![ConditionCode, RegisterFP, RegisterFP],
+                        vec![3, 2, 1],
+                        None,
+                    );
+                } else {
+                    read_operands(instruction, vec![RegisterFP, RegisterFP], vec![2, 1], None);
+                    instruction.binary = append_binary(instruction.binary, 0b000, 3);
+                    //cc = 0 implied
+                }
+
+                instruction.binary = append_binary(instruction.binary, 0b0011, 4); //0, A, FC
+                instruction.binary = append_binary(instruction.binary, 0b1101, 4);
+                //nge
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "c.nge.d fs, ft` (`cc` = 0 implied) || `c.nge.d cc, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if the contents of `fs` not greater than or equal to the contents of `ft`, stores the value 1 into condition code `cc`. Otherwise, stores the value 0 into condition code `cc`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.af.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (s)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6);
+                //AF
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.af.s fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats, and always stores all zeros into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.af.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (d)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6);
+                //AF
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.af.d fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats, and always stores all zeros into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.un.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (s)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b000001, 6);
+                //UN
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.un.s fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if they are unordered (either is NaN), stores all ones into `fd`. Otherwise, stores all zeros into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.un.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (d)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b000001, 6);
+                //UN
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.un.d fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if they are unordered (either is NaN), stores all ones into `fd`. Otherwise, stores all zeros into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.eq.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (s)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b000010, 6);
+                //EQ
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.eq.s fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if they are equal, stores all ones into `fd`. Otherwise, stores all zeros into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.eq.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (d)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b000010, 6);
+                //EQ
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.eq.d fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if they are equal, stores all ones into `fd`. Otherwise, stores all zeros into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.ueq.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (s)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b000011, 6);
+                //UEQ
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.ueq.s fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if they are unordered or equal, stores all ones into `fd`. Otherwise, stores all zeros into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.ueq.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (d)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b000011, 6);
+                //UEQ
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.ueq.d fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if they are unordered or equal, stores all ones into `fd`. Otherwise, stores all zeros into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.lt.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (s)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b000100, 6);
+                //LT
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.lt.s fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if the contents of `fs` is less than the contents of `ft`, stores all ones into `fd`. Otherwise, stores all zeros into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.lt.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (d)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b000100, 6);
+                //LT
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.lt.d fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if the contents of `fs` is less than the contents of `ft`, stores all ones into `fd`. Otherwise, stores all zeros into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.ult.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (s)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b000101, 6);
+                //ULT
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.ult.s fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if they are unordered or the contents of `fs` is less than the contents of `ft`, stores all ones into `fd`. Otherwise, stores all zeros into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.ult.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (d)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b000101, 6);
+                //ULT
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.ult.d fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if they are unordered or the contents of `fs` is less than the contents of `ft`, stores all ones into `fd`. Otherwise, stores all zeros into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.le.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (s)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b000110, 6);
+                //LE
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.le.s fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if the contents of `fs` is less than or equal to the contents of `ft`, stores all ones into `fd`. Otherwise, stores all zeros into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.le.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (d)
+
+                read_operands(
                     instruction,
-                    vec![RegisterGP, RegisterGP, Immediate],
-                    vec![2, 1, 3],
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b000110, 6);
+                //LE
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.le.d fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if the contents of `fs` is less than or equal to the contents of `ft`, stores all ones into `fd`. Otherwise, stores all zeros into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.ule.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (s)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b000111, 6);
+                //ULE
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.ule.s fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if they are unordered or the contents of `fs` is less than or equal to the contents of `ft`, stores all ones into `fd`. Otherwise, stores all zeros into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.ule.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (d)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b000111, 6);
+                //ULE
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.ule.d fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if they are unordered or the contents of `fs` is less than or equal to the contents of `ft`, stores all ones into `fd`. Otherwise, stores all zeros into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.saf.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (s)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b001000, 6);
+                //SAF
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.saf.s fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats, and always stores all zeros into `fd`. Signals an invalid operation if either value is NaN.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.saf.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (d)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b001000, 6);
+                //SAF
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.saf.d fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats, and always stores all zeros into `fd`. Signals an invalid operation if either value is NaN.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.sun.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (s)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b001001, 6);
+                //SUN
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.sun.s fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if they are unordered (either is NaN), stores all ones into `fd`. Otherwise, stores all zeros into `fd`. Signals an invalid operation if either value is NaN.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.sun.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (d)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b001001, 6);
+                //SUN
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.sun.d fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if they are unordered (either is NaN), stores all ones into `fd`. Otherwise, stores all zeros into `fd`. Signals an invalid operation if either value is NaN.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.seq.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (s)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b001010, 6);
+                //SEQ
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.seq.s fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if they are equal, stores all ones into `fd`. Otherwise, stores all zeros into `fd`. Signals an invalid operation if either value is NaN.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.seq.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (d)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b001010, 6);
+                //SEQ
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.seq.d fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if they are equal, stores all ones into `fd`. Otherwise, stores all zeros into `fd`. Signals an invalid operation if either value is NaN.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.sueq.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (s)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b001011, 6);
+                //SUEQ
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.sueq.s fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if they are unordered or equal, stores all ones into `fd`. Otherwise, stores all zeros into `fd`. Signals an invalid operation if either value is NaN.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.sueq.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (d)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b001011, 6);
+                //SUEQ
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.sueq.d fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if they are unordered or equal, stores all ones into `fd`. Otherwise, stores all zeros into `fd`. Signals an invalid operation if either value is NaN.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.slt.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (s)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b001100, 6);
+                //SLT
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.slt.s fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if the contents of `fs` is less than the contents of `ft`, stores all ones into `fd`. Otherwise, stores all zeros into `fd`. Signals an invalid operation if either value is NaN.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.slt.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (d)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b001100, 6);
+                //SLT
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.slt.d fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if the contents of `fs` is less than the contents of `ft`, stores all ones into `fd`. Otherwise, stores all zeros into `fd`. Signals an invalid operation if either value is NaN.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.sult.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (s)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b001101, 6);
+                //SULT
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.sult.s fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if they are unordered or the contents of `fs` is less than the contents of `ft`, stores all ones into `fd`. Otherwise, stores all zeros into `fd`. Signals an invalid operation if either value is NaN.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.sult.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (d)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b001101, 6);
+                //SULT
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.sult.d fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if they are unordered or the contents of `fs` is less than the contents of `ft`, stores all ones into `fd`. Otherwise, stores all zeros into `fd`. Signals an invalid operation if either value is NaN.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.sle.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (s)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
+
+                instruction.binary = append_binary(instruction.binary, 0b001110, 6);
+                //SLE
+
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.sle.s fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if the contents of `fs` is less than or equal to the contents of `ft`, stores all ones into `fd`. Otherwise, stores all zeros into `fd`. Signals an invalid operation if either value is NaN.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "cmp.sle.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (d)
+
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
                     None,
                 );
 
+                instruction.binary = append_binary(instruction.binary, 0b001110, 6);
+                //SLE
+
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
                 let info = InstructionDescription{
-                    syntax: "daddiu rt, rs, immediate".to_string(),
-                    description: "Adds the 64-bit value in `rs` and the 16-bit `immediate`, and places the result in `rt`.".to_string(),
+                    syntax: "cmp.sle.d fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if the contents of `fs` is less than or equal to the contents of `ft`, stores all ones into `fd`. Otherwise, stores all zeros into `fd`. Signals an invalid operation if either value is NaN.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "daddu" => {
-                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+            "cmp.sule.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (s)
 
                 read_operands(
                     instruction,
-                    vec![RegisterGP, RegisterGP, RegisterGP],
-                    vec![2, 3, 1],
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
                     None,
                 );
 
-                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
-                instruction.binary = append_binary(instruction.binary, 0b101101, 6);
-                //daddu
+                instruction.binary = append_binary(instruction.binary, 0b001111, 6);
+                //SULE
 
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
                 let info = InstructionDescription{
-                    syntax: "daddu rd, rs, rt".to_string(),
-                    description: "Adds the 64-bit values in `rs` and `rt`, and places the result in `rd`.\n\nIgnores overflow.".to_string(),
+                    syntax: "cmp.sule.s fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if they are unordered or the contents of `fs` is less than or equal to the contents of `ft`, stores all ones into `fd`. Otherwise, stores all zeros into `fd`. Signals an invalid operation if either value is NaN.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "dsubu" => {
-                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+            "cmp.sule.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (d)
 
                 read_operands(
                     instruction,
-                    vec![RegisterGP, RegisterGP, RegisterGP],
-                    vec![2, 3, 1],
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
                     None,
                 );
 
-                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
-                instruction.binary = append_binary(instruction.binary, 0b101111, 6);
-                //dsubu
+                instruction.binary = append_binary(instruction.binary, 0b001111, 6);
+                //SULE
 
-                //Pseudo-instructions already have text in mouse_hover_string so we check if there's text there already before adding in the blurb
-                if monaco_line_info[instruction.line_number]
-                    .mouse_hover_string
-                    .is_empty()
-                {
-                    let info = InstructionDescription{
-                        syntax: "dsubu rd, rs, rt".to_string(),
-                        description: "Subtracts the 64-bit values in `rt` from the 64-bit value in `rs`, and places the result in `rd`.\n\nIgnores overflow.".to_string(),
-                    };
-                    monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
-                }
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.sule.d fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if they are unordered or the contents of `fs` is less than or equal to the contents of `ft`, stores all ones into `fd`. Otherwise, stores all zeros into `fd`. Signals an invalid operation if either value is NaN.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "dmulu" => {
-                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+            "cmp.or.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (s)
 
                 read_operands(
                     instruction,
-                    vec![RegisterGP, RegisterGP, RegisterGP],
-                    vec![2, 3, 1],
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
                     None,
                 );
 
-                instruction.binary = append_binary(instruction.binary, 0b00010, 5); //dmulu
-                instruction.binary = append_binary(instruction.binary, 0b011101, 6);
-                //sop35
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6);
+                //OR
 
-                //Pseudo-instructions already have text in mouse_hover_string so we check if there's text there already before adding in the blurb
-                if monaco_line_info[instruction.line_number]
-                    .mouse_hover_string
-                    .is_empty()
-                {
-                    let info = InstructionDescription{
-                        syntax: "dmulu rd, rs, rt".to_string(),
-                        description: "Multiplies the signed 64-bit values in `rs` and `rt`, and places the lower 64 bits of the result in `rd`.".to_string(),
-                    };
-                    monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
-                }
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.or.s fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if they are ordered (neither is NaN), stores all ones into `fd`. Otherwise, stores all zeros into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "ddivu" => {
-                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+            "cmp.or.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (d)
 
                 read_operands(
                     instruction,
-                    vec![RegisterGP, RegisterGP, RegisterGP],
-                    vec![2, 3, 1],
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
                     None,
                 );
 
-                instruction.binary = append_binary(instruction.binary, 0b00010, 5); //ddivu
-                instruction.binary = append_binary(instruction.binary, 0b011111, 6);
-                //DDIVU
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6);
+                //OR
 
-                //Pseudo-instructions already have text in mouse_hover_string so we check if there's text there already before adding in the blurb
-                if monaco_line_info[instruction.line_number]
-                    .mouse_hover_string
-                    .is_empty()
-                {
-                    let info = InstructionDescription{
-                        syntax: "ddivu rd, rs, rt".to_string(),
-                        description: "Divides the unsigned 64-bit value in `rs` by the unsigned 64-bit value in `rt` and places the quotient into `rd`.".to_string(),
-                    };
-                    monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
-                }
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.or.d fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if they are ordered (neither is NaN), stores all ones into `fd`. Otherwise, stores all zeros into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "slt" => {
-                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+            "cmp.une.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (s)
 
                 read_operands(
                     instruction,
-                    vec![RegisterGP, RegisterGP, RegisterGP],
-                    vec![2, 3, 1],
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
                     None,
                 );
 
-                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
-                instruction.binary = append_binary(instruction.binary, 0b101010, 6);
-                //slt
+                instruction.binary = append_binary(instruction.binary, 0b010010, 6);
+                //UNE
 
-                //Pseudo-instructions already have text in mouse_hover_string so we check if there's text there already before adding in the blurb
-                if monaco_line_info[instruction.line_number]
-                    .mouse_hover_string
-                    .is_empty()
-                {
-                    let info = InstructionDescription{
-                        syntax: "slt rd, rs, rt".to_string(),
-                        description: "Compares the contents of `rs` and `rt` as signed integers and stores the value 1 in `rd` if `rs` is less than rt. Otherwise, stores the value 0 in `rd`.".to_string(),
-                    };
-                    monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
-                }
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.une.s fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if they are unordered or not equal, stores all ones into `fd`. Otherwise, stores all zeros into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "sltu" => {
-                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+            "cmp.une.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (d)
 
                 read_operands(
                     instruction,
-                    vec![RegisterGP, RegisterGP, RegisterGP],
-                    vec![2, 3, 1],
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
                     None,
                 );
 
-                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
-                instruction.binary = append_binary(instruction.binary, 0b101011, 6);
-                //sltu
+                instruction.binary = append_binary(instruction.binary, 0b010010, 6);
+                //UNE
 
-                //Pseudo-instructions already have text in mouse_hover_string so we check if there's text there already before adding in the blurb
-                if monaco_line_info[instruction.line_number]
-                    .mouse_hover_string
-                    .is_empty()
-                {
-                    let info = InstructionDescription{
-                        syntax: "sltu rd, rs, rt".to_string(),
-                        description: "Compares the contents of `rs` and `rt` as unsigned integers and stores the value 1 in `rd` if `rs` is less than `rt`. Otherwise, stores the value 0 in `rd`.".to_string(),
-                    };
-                    monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
-                }
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "cmp.une.d fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if they are unordered or not equal, stores all ones into `fd`. Otherwise, stores all zeros into `fd`.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "swc1" => {
-                instruction.binary = append_binary(instruction.binary, 0b111001, 6); //swc1
+            "cmp.ne.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (s)
 
                 read_operands(
                     instruction,
-                    vec![RegisterFP, MemoryAddress],
-                    vec![3, 1, 2],
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
                     None,
                 );
 
+                instruction.binary = append_binary(instruction.binary, 0b010011, 6);
+                //NE
+
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
                 let info = InstructionDescription{
-                    syntax: "swc1 ft, offset(base)".to_string(),
-                    description: "Stores the value of the lower 32 bits in `ft` at the specified memory address.\n\nMemory address is calculated as the sum of `offset` and the contents of the `base` register.".to_string(),
+                    syntax: "cmp.ne.s fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if they are ordered and not equal, stores all ones into `fd`. Otherwise, stores all zeros into `fd`.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "lwc1" => {
-                instruction.binary = append_binary(instruction.binary, 0b110001, 6); //lwc1
+            "cmp.ne.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (d)
 
                 read_operands(
                     instruction,
-                    vec![RegisterFP, MemoryAddress],
-                    vec![3, 1, 2],
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
                     None,
                 );
 
+                instruction.binary = append_binary(instruction.binary, 0b010011, 6);
+                //NE
+
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
                 let info = InstructionDescription{
-                    syntax: "lwc1 ft offset(base)".to_string(),
-                    description: "Loads the contents of the 32-bit word at the specified memory address into `ft`.\n\nMemory address is calculated as the sum of `offset` and the contents of the `base` register.".to_string(),
+                    syntax: "cmp.ne.d fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if they are ordered and not equal, stores all ones into `fd`. Otherwise, stores all zeros into `fd`.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "mtc1" => {
+            "cmp.sor.s" => {
                 instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
-                instruction.binary = append_binary(instruction.binary, 0b00100, 5); //mt
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (s)
 
-                read_operands(instruction, vec![RegisterGP, RegisterFP], vec![1, 2], None);
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
 
-                instruction.binary = append_binary(instruction.binary, 0b00000000000, 11);
-                //0
+                instruction.binary = append_binary(instruction.binary, 0b011001, 6);
+                //SOR
 
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
-                let info = InstructionDescription {
-                    syntax: "mtc1 rt, fs".to_string(),
-                    description: "Moves the lower 32 bits in `rt` into the lower 32 bits in `fs`."
-                        .to_string(),
+                let info = InstructionDescription{
+                    syntax: "cmp.sor.s fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if they are ordered (neither is NaN), stores all ones into `fd`. Otherwise, stores all zeros into `fd`. Signals an invalid operation if either value is NaN.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "dmtc1" => {
+            "cmp.sor.d" => {
                 instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
-                instruction.binary = append_binary(instruction.binary, 0b00101, 5); //dmt
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (d)
 
-                read_operands(instruction, vec![RegisterGP, RegisterFP], vec![1, 2], None);
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
 
-                instruction.binary = append_binary(instruction.binary, 0b00000000000, 11);
-                //0
+                instruction.binary = append_binary(instruction.binary, 0b011001, 6);
+                //SOR
 
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
-                let info = InstructionDescription {
-                    syntax: "dmtc1 rt, fs".to_string(),
-                    description: "Moves the double-word contents in `rt` into `fs`.".to_string(),
+                let info = InstructionDescription{
+                    syntax: "cmp.sor.d fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if they are ordered (neither is NaN), stores all ones into `fd`. Otherwise, stores all zeros into `fd`. Signals an invalid operation if either value is NaN.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "mfc1" => {
+            "cmp.sune.s" => {
                 instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
-                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //mf
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (s)
 
-                read_operands(instruction, vec![RegisterGP, RegisterFP], vec![1, 2], None);
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
 
-                instruction.binary = append_binary(instruction.binary, 0b00000000000, 11);
-                //0
+                instruction.binary = append_binary(instruction.binary, 0b011010, 6);
+                //SUNE
 
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
-                let info = InstructionDescription {
-                    syntax: "mfc1 rt, fs".to_string(),
-                    description: "Sign-extends contents in `fs` and moves it into `rt`."
-                        .to_string(),
+                let info = InstructionDescription{
+                    syntax: "cmp.sune.s fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if they are unordered or not equal, stores all ones into `fd`. Otherwise, stores all zeros into `fd`. Signals an invalid operation if either value is NaN.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "dmfc1" => {
+            "cmp.sune.d" => {
                 instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
-                instruction.binary = append_binary(instruction.binary, 0b00001, 5); //dmf
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (d)
 
-                read_operands(instruction, vec![RegisterGP, RegisterFP], vec![1, 2], None);
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
 
-                instruction.binary = append_binary(instruction.binary, 0b00000000000, 11);
-                //0
+                instruction.binary = append_binary(instruction.binary, 0b011010, 6);
+                //SUNE
 
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
-                let info = InstructionDescription {
-                    syntax: "dmfc1 rt, fs".to_string(),
-                    description: "Moves the double-word contents in `fs` into `rt`.".to_string(),
+                let info = InstructionDescription{
+                    syntax: "cmp.sune.d fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if they are unordered or not equal, stores all ones into `fd`. Otherwise, stores all zeros into `fd`. Signals an invalid operation if either value is NaN.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "j" => {
-                instruction.binary = append_binary(instruction.binary, 0b000010, 6); //j
+            "cmp.sne.s" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10100, 5); //fmt: w (s)
 
                 read_operands(
                     instruction,
-                    vec![LabelAbsolute],
-                    vec![1],
-                    Some(labels.clone()),
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
                 );
 
+                instruction.binary = append_binary(instruction.binary, 0b011011, 6);
+                //SNE
+
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
-                let info = InstructionDescription {
-                    syntax: "j target".to_string(),
-                    description:
-                        "Moves the program counter to point to the targeted instruction’s address."
-                            .to_string(),
+                let info = InstructionDescription{
+                    syntax: "cmp.sne.s fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if they are ordered and not equal, stores all ones into `fd`. Otherwise, stores all zeros into `fd`. Signals an invalid operation if either value is NaN.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "jr" => {
-                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+            "cmp.sne.d" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b10101, 5); //fmt: l (d)
 
-                read_operands(instruction, vec![RegisterGP], vec![1], None);
-                instruction.binary = append_binary(instruction.binary, 0b00000, 5); // rt
-                instruction.binary = append_binary(instruction.binary, 0b00000, 5); // rd
-                instruction.binary = append_binary(instruction.binary, 0b00000, 5); // shift/hint
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, RegisterFP, RegisterFP],
+                    vec![3, 2, 1],
+                    None,
+                );
 
-                instruction.binary = append_binary(instruction.binary, 0b001001, 6); //jalr
+                instruction.binary = append_binary(instruction.binary, 0b011011, 6);
+                //SNE
 
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
                 let info = InstructionDescription{
-                    syntax: "jr rs".to_string(),
-                    description: "Reads the contents of the register as an address and moves the program counter to point to that instruction.".to_string(),
+                    syntax: "cmp.sne.d fd, fs, ft".to_string(),
+                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if they are ordered and not equal, stores all ones into `fd`. Otherwise, stores all zeros into `fd`. Signals an invalid operation if either value is NaN.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "jal" => {
-                instruction.binary = append_binary(instruction.binary, 0b000011, 6); //jal
+            "bc1t" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b01000, 5); //BC
 
-                read_operands(
-                    instruction,
-                    vec![LabelAbsolute],
-                    vec![1],
-                    Some(labels.clone()),
-                );
+                if instruction.operands.len() == 2 {
+                    //the condition code is given before the target
+                    read_operands(
+                        instruction,
+                        vec![ConditionCode, LabelRelative],
+                        vec![1, 2],
+                        Some(labels.clone()),
+                    );
+
+                    instruction.binary =
+                        place_binary_in_middle_of_another(instruction.binary, 0b01, 2, 15);
+                    //ND TF
+                } else {
+                    instruction.binary = append_binary(instruction.binary, 0b00001, 5); //CC ND TF
+
+                    read_operands(
+                        instruction,
+                        vec![LabelRelative],
+                        vec![1],
+                        Some(labels.clone()),
+                    );
+                }
 
-                //this instruction is not used  in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
                 let info = InstructionDescription{
-                    syntax: "jal target".to_string(),
-                    description: "Execute a procedure call. Sets the $ra (\"return address\") register to the next instruction, then moves the program counter to point to the targeted instruction’s address.".to_string(),
+                    syntax: "bc1t target` (`cc` = 0 implied) || `bc1t cc, target".to_string(),
+                    description: "If condition code `cc` is 1, moves the program counter to point to the targeted instruction’s address.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "jalr" => {
-                //JALR is unique in a number of ways.
-                //if rd is left blank, rd = 31 is implied.
-                //in release 6, rd cannot be 00000
-                //it makes use of hint bits in some releases. In our implementation, we are not implementing hint bits.
-
-                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+            "bc1f" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b01000, 5); //BC
 
-                if instruction.operands.len() == 1 {
-                    //if len() == 1, rd is omitted and assumed to equal 31
-                    read_operands(instruction, vec![RegisterGP], vec![1], None);
-                    instruction.binary = append_binary(instruction.binary, 0b11111, 5);
-                    //rd = 31
+                if instruction.operands.len() == 2 {
+                    //the condition code is given before the target
+                    read_operands(
+                        instruction,
+                        vec![ConditionCode, LabelRelative],
+                        vec![1, 2],
+                        Some(labels.clone()),
+                    );
+
+                    instruction.binary =
+                        place_binary_in_middle_of_another(instruction.binary, 0b00, 2, 15);
+                    //ND TF
                 } else {
-                    if !instruction.operands.is_empty()
-                        && instruction.operands[0].token_name == "$zero"
-                    {
-                        instruction.errors.push(Error {
-                            error_name: JALRRDRegisterZero,
-                            token_causing_error: "$zero".to_string(),
-                            start_end_columns: instruction.operands[0].start_end_columns,
-                            message: "".to_string(),
-                        })
-                    }
-                    read_operands(instruction, vec![RegisterGP, RegisterGP], vec![2, 1], None);
+                    instruction.binary = append_binary(instruction.binary, 0b00000, 5); //CC ND TF
+
+                    read_operands(
+                        instruction,
+                        vec![LabelRelative],
+                        vec![1],
+                        Some(labels.clone()),
+                    );
                 }
 
-                instruction.binary =
-                    place_binary_in_middle_of_another(instruction.binary, 0b00000, 5, 5); //0
+                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
+                let info = InstructionDescription{
+                    syntax: "bc1f target` (`cc` = 0 implied) || `bc1f cc, target".to_string(),
+                    description: "If condition code `cc` is 0, moves the program counter to point to the targeted instruction’s address.".to_string(),
+                };
+                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+            }
+            "bc1eqz" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b01001, 5); //BC1EQZ
 
-                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //hint
-                instruction.binary = append_binary(instruction.binary, 0b001001, 6);
-                //JALR
+                read_operands(
+                    instruction,
+                    vec![RegisterFP, LabelRelative],
+                    vec![1, 2],
+                    Some(labels.clone()),
+                );
 
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
                 let info = InstructionDescription{
-                    syntax: "jalr rs` (`rd` = `$ra` implied) || `jalr rd, rs".to_string(),
-                    description: "Execute a procedure call. Sets the $ra (\"return address\") register to the next instruction, then moves the program counter to point to the address read from `rs`.".to_string(),
+                    syntax: "bc1eqz ft, target".to_string(),
+                    description: "If the lowest bit of `ft` is 0, moves the program counter to point to the targeted instruction’s address. Used with the mask stored by a `cmp.cond.fmt` instruction.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "beq" => {
-                instruction.binary = append_binary(instruction.binary, 0b000100, 6); //beq
+            "bc1nez" => {
+                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
+                instruction.binary = append_binary(instruction.binary, 0b01101, 5); //BC1NEZ
 
                 read_operands(
                     instruction,
-                    vec![RegisterGP, RegisterGP, LabelRelative],
-                    vec![1, 2, 3],
+                    vec![RegisterFP, LabelRelative],
+                    vec![1, 2],
                     Some(labels.clone()),
                 );
 
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
                 let info = InstructionDescription{
-                    syntax: "beq rs, rt, target".to_string(),
-                    description: "Compares the contents of `rs` and `rt` and, if they are equal, moves the program counter to point to the targeted instruction’s address.".to_string(),
+                    syntax: "bc1nez ft, target".to_string(),
+                    description: "If the lowest bit of `ft` is 1, moves the program counter to point to the targeted instruction’s address. Used with the mask stored by a `cmp.cond.fmt` instruction.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "b" => {
-                instruction.binary = append_binary(instruction.binary, 0b000100, 6); //beq
-
-                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
+            "sll" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
                 instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
 
                 read_operands(
                     instruction,
-                    vec![LabelRelative],
-                    vec![1],
+                    vec![RegisterGP, RegisterGP, ShiftAmount],
+                    vec![2, 1, 3],
                     Some(labels.clone()),
                 );
 
+                instruction.binary = append_binary(instruction.binary, 0b00000, 6); //sll
+
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
-                let info = InstructionDescription {
-                    syntax: "b target".to_string(),
-                    description:
-                        "Moves the program counter to point to the targeted instruction’s address."
-                            .to_string(),
+                let info = InstructionDescription{
+                    syntax: "sll rt, rs, sa".to_string(),
+                    description: "Shifts the lower 32-bit word in `rs` to the left by sa number of bits and placing the sign-extended result into `rt`.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "bne" => {
-                instruction.binary = append_binary(instruction.binary, 0b000101, 6); //bne
+            "srl" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
 
                 read_operands(
                     instruction,
-                    vec![RegisterGP, RegisterGP, LabelRelative],
-                    vec![1, 2, 3],
+                    vec![RegisterGP, RegisterGP, ShiftAmount],
+                    vec![2, 1, 3],
                     Some(labels.clone()),
                 );
 
+                instruction.binary = append_binary(instruction.binary, 0b000010, 6); //srl
+
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
                 let info = InstructionDescription{
-                    syntax: "bne rs, rt, target".to_string(),
-                    description: "Compares the contents of `rs` and `rt` and, if they are not equal, moves the program counter to point to the targeted instruction’s address.".to_string(),
+                    syntax: "srl rd, rt, sa".to_string(),
+                    description: "Shifts the lower 32-bit word in `rt` to the right by sa number of bits, filling the upper bits with zeros, and places the sign-extended result into `rd`.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "c.eq.s" => {
-                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
-                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s
+            "sra" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
 
                 read_operands(
                     instruction,
-                    vec![RegisterFP, RegisterFP],
-                    vec![2, 1],
+                    vec![RegisterGP, RegisterGP, ShiftAmount],
+                    vec![2, 1, 3],
                     Some(labels.clone()),
                 );
 
-                instruction.binary = append_binary(instruction.binary, 0b0000011, 7); //cc, 0, A, FC
-                instruction.binary = append_binary(instruction.binary, 0b0010, 4);
-                //EQ
+                instruction.binary = append_binary(instruction.binary, 0b000011, 6); //sra
 
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
                 let info = InstructionDescription{
-                    syntax: "c.eq.s fs, ft".to_string(),
-                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if they are equal, stores the value 1 into FPConditionCode. Otherwise, stores the value 0 into FPConditionCode.".to_string(),
+                    syntax: "sra rd, rt, sa".to_string(),
+                    description: "Shifts the lower 32-bit word in `rt` to the right by sa number of bits, duplicating the sign bit into the upper bits, and places the sign-extended result into `rd`.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "c.eq.d" => {
-                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
-                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d
+            "sllv" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
 
                 read_operands(
                     instruction,
-                    vec![RegisterFP, RegisterFP],
-                    vec![2, 1],
-                    Some(labels.clone()),
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![3, 2, 1],
+                    None,
                 );
 
-                instruction.binary = append_binary(instruction.binary, 0b0000011, 7); //cc, 0, A, FC
-                instruction.binary = append_binary(instruction.binary, 0b0010, 4);
-                //EQ
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
+                instruction.binary = append_binary(instruction.binary, 0b000100, 6); //sllv
 
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
                 let info = InstructionDescription{
-                    syntax: "c.eq.d fs, ft".to_string(),
-                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if they are equal, stores the value 1 into FPConditionCode. Otherwise, stores the value 0 into FPConditionCode.".to_string(),
+                    syntax: "sllv rd, rt, rs".to_string(),
+                    description: "Shifts the lower 32-bit word in `rt` to the left by the number of bits given by the lower 5 bits of `rs` and places the sign-extended result into `rd`.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "c.lt.s" => {
-                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
-                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s
+            "srlv" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
 
                 read_operands(
                     instruction,
-                    vec![RegisterFP, RegisterFP],
-                    vec![2, 1],
-                    Some(labels.clone()),
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![3, 2, 1],
+                    None,
                 );
 
-                instruction.binary = append_binary(instruction.binary, 0b0000011, 7); //cc, 0, A, FC
-                instruction.binary = append_binary(instruction.binary, 0b1100, 4);
-                //lt
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
+                instruction.binary = append_binary(instruction.binary, 0b000110, 6); //srlv
 
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
                 let info = InstructionDescription{
-                    syntax: "c.lt.s fs, ft".to_string(),
-                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if the contents of `fs` is less than the contents of `ft`, stores the value 1 into FPConditionCode. Otherwise, stores the value 0 into FPConditionCode.".to_string(),
+                    syntax: "srlv rd, rt, rs".to_string(),
+                    description: "Shifts the lower 32-bit word in `rt` to the right by the number of bits given by the lower 5 bits of `rs`, filling the upper bits with zeros, and places the sign-extended result into `rd`.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "c.lt.d" => {
-                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
-                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d
+            "srav" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
 
                 read_operands(
                     instruction,
-                    vec![RegisterFP, RegisterFP],
-                    vec![2, 1],
-                    Some(labels.clone()),
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![3, 2, 1],
+                    None,
                 );
 
-                instruction.binary = append_binary(instruction.binary, 0b0000011, 7); //cc, 0, A, FC
-                instruction.binary = append_binary(instruction.binary, 0b1100, 4);
-                //lt
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
+                instruction.binary = append_binary(instruction.binary, 0b000111, 6); //srav
 
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
                 let info = InstructionDescription{
-                    syntax: "c.lt.d fs, ft".to_string(),
-                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if the contents of `fs` is less than the contents of `ft`, stores the value 1 into FPConditionCode. Otherwise, stores the value 0 into FPConditionCode.".to_string(),
+                    syntax: "srav rd, rt, rs".to_string(),
+                    description: "Shifts the lower 32-bit word in `rt` to the right by the number of bits given by the lower 5 bits of `rs`, duplicating the sign bit into the upper bits, and places the sign-extended result into `rd`.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "c.le.s" => {
-                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
-                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s
+            "dsll" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
 
                 read_operands(
                     instruction,
-                    vec![RegisterFP, RegisterFP],
-                    vec![2, 1],
+                    vec![RegisterGP, RegisterGP, ShiftAmount],
+                    vec![2, 1, 3],
                     Some(labels.clone()),
                 );
 
-                instruction.binary = append_binary(instruction.binary, 0b0000011, 7); //cc, 0, A, FC
-                instruction.binary = append_binary(instruction.binary, 0b1110, 4);
-                //le
+                instruction.binary = append_binary(instruction.binary, 0b111000, 6); //dsll
 
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
                 let info = InstructionDescription{
-                    syntax: "c.le.s fs, ft".to_string(),
-                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if the contents of `fs` is less than or equal to the contents of `ft`, stores the value 1 into FPConditionCode. Otherwise, stores the value 0 into FPConditionCode.".to_string(),
+                    syntax: "dsll rd, rt, sa".to_string(),
+                    description: "Shifts the 64-bit doubleword in `rt` to the left by sa number of bits and places the result into `rd`.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "c.le.d" => {
-                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
-                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d
+            "dsrl" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
 
                 read_operands(
                     instruction,
-                    vec![RegisterFP, RegisterFP],
-                    vec![2, 1],
+                    vec![RegisterGP, RegisterGP, ShiftAmount],
+                    vec![2, 1, 3],
                     Some(labels.clone()),
                 );
 
-                instruction.binary = append_binary(instruction.binary, 0b0000011, 7); //cc, 0, A, FC
-                instruction.binary = append_binary(instruction.binary, 0b1110, 4);
-                //le
+                instruction.binary = append_binary(instruction.binary, 0b111010, 6); //dsrl
 
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
                 let info = InstructionDescription{
-                    syntax: "c.le.d fs, ft".to_string(),
-                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if the contents of `fs` is less than or equal to the contents of `ft`, stores the value 1 into FPConditionCode. Otherwise, stores the value 0 into FPConditionCode.".to_string(),
+                    syntax: "dsrl rd, rt, sa".to_string(),
+                    description: "Shifts the 64-bit doubleword in `rt` to the right by sa number of bits, filling the upper bits with zeros, and places the result into `rd`.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "c.ngt.s" => {
-                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
-                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s
+            "dsra" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
 
                 read_operands(
                     instruction,
-                    vec![RegisterFP, RegisterFP],
-                    vec![2, 1],
+                    vec![RegisterGP, RegisterGP, ShiftAmount],
+                    vec![2, 1, 3],
                     Some(labels.clone()),
                 );
 
-                instruction.binary = append_binary(instruction.binary, 0b0000011, 7); //cc, 0, A, FC
-                instruction.binary = append_binary(instruction.binary, 0b1111, 4);
-                //ngt
+                instruction.binary = append_binary(instruction.binary, 0b111011, 6); //dsra
 
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
                 let info = InstructionDescription{
-                    syntax: "c.ngt.s fs, ft".to_string(),
-                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if the contents of `fs` not greater than the contents of `ft`, stores the value 1 into FPConditionCode. Otherwise, stores the value 0 into FPConditionCode.".to_string(),
+                    syntax: "dsra rd, rt, sa".to_string(),
+                    description: "Shifts the 64-bit doubleword in `rt` to the right by sa number of bits, duplicating the sign bit into the upper bits, and places the result into `rd`.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "c.ngt.d" => {
-                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
-                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d
+            "dsll32" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
 
                 read_operands(
                     instruction,
-                    vec![RegisterFP, RegisterFP],
-                    vec![2, 1],
+                    vec![RegisterGP, RegisterGP, ShiftAmount],
+                    vec![2, 1, 3],
                     Some(labels.clone()),
                 );
 
-                instruction.binary = append_binary(instruction.binary, 0b0000011, 7); //cc, 0, A, FC
-                instruction.binary = append_binary(instruction.binary, 0b1111, 4);
-                //ngt
+                instruction.binary = append_binary(instruction.binary, 0b111100, 6); //dsll32
 
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
                 let info = InstructionDescription{
-                    syntax: "c.ngt.d fs, ft".to_string(),
-                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if the contents of `fs` not greater than the contents of `ft`, stores the value 1 into FPConditionCode. Otherwise, stores the value 0 into FPConditionCode.".to_string(),
+                    syntax: "dsll32 rd, rt, sa".to_string(),
+                    description: "Shifts the 64-bit doubleword in `rt` to the left by (sa + 32) number of bits and places the result into `rd`.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "c.nge.s" => {
-                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
-                instruction.binary = append_binary(instruction.binary, 0b10000, 5); //fmt: s
+            "dsrl32" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
 
                 read_operands(
                     instruction,
-                    vec![RegisterFP, RegisterFP],
-                    vec![2, 1],
+                    vec![RegisterGP, RegisterGP, ShiftAmount],
+                    vec![2, 1, 3],
                     Some(labels.clone()),
                 );
 
-                instruction.binary = append_binary(instruction.binary, 0b0000011, 7); //cc, 0, A, FC
-                instruction.binary = append_binary(instruction.binary, 0b1101, 4);
-                //nge
+                instruction.binary = append_binary(instruction.binary, 0b111110, 6); //dsrl32
 
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
                 let info = InstructionDescription{
-                    syntax: "c.nge.d fs, ft".to_string(),
-                    description: "Compares the contents of `fs` and `ft` as single-precision floats and, if the contents of `fs` not greater than or equal to the contents of `ft`, stores the value 1 into FPConditionCode. Otherwise, stores the value 0 into FPConditionCode.".to_string(),
+                    syntax: "dsrl32 rd, rt, sa".to_string(),
+                    description: "Shifts the 64-bit doubleword in `rt` to the right by (sa + 32) number of bits, filling the upper bits with zeros, and places the result into `rd`.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "c.nge.d" => {
-                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
-                instruction.binary = append_binary(instruction.binary, 0b10001, 5); //fmt: d
+            "dsra32" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
 
                 read_operands(
                     instruction,
-                    vec![RegisterFP, RegisterFP],
-                    vec![2, 1],
+                    vec![RegisterGP, RegisterGP, ShiftAmount],
+                    vec![2, 1, 3],
                     Some(labels.clone()),
                 );
 
-                instruction.binary = append_binary(instruction.binary, 0b0000011, 7); //cc, 0, A, FC
-                instruction.binary = append_binary(instruction.binary, 0b1101, 4);
-                //nge
+                instruction.binary = append_binary(instruction.binary, 0b111111, 6); //dsra32
 
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
                 let info = InstructionDescription{
-                    syntax: "c.nge.d fs, ft".to_string(),
-                    description: "Compares the contents of `fs` and `ft` as double-precision floats and, if the contents of `fs` not greater than or equal to the contents of `ft`, stores the value 1 into FPConditionCode. Otherwise, stores the value 0 into FPConditionCode.".to_string(),
+                    syntax: "dsra32 rd, rt, sa".to_string(),
+                    description: "Shifts the 64-bit doubleword in `rt` to the right by (sa + 32) number of bits, duplicating the sign bit into the upper bits, and places the result into `rd`.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "bc1t" => {
-                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
-                instruction.binary = append_binary(instruction.binary, 0b01000, 5); //BC
-                instruction.binary = append_binary(instruction.binary, 0b00001, 5); //CC ND TF
+            "dsllv" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
 
                 read_operands(
                     instruction,
-                    vec![LabelRelative],
-                    vec![1],
-                    Some(labels.clone()),
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![3, 2, 1],
+                    None,
                 );
 
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
+                instruction.binary = append_binary(instruction.binary, 0b010100, 6); //dsllv
+
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
                 let info = InstructionDescription{
-                    syntax: "bc1t target".to_string(),
-                    description: "If FPConditionCode is 1, moves the program counter to point to the targeted instruction’s address.".to_string(),
+                    syntax: "dsllv rd, rt, rs".to_string(),
+                    description: "Shifts the 64-bit doubleword in `rt` to the left by the number of bits given by the lower 6 bits of `rs` and places the result into `rd`.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "bc1f" => {
-                instruction.binary = append_binary(instruction.binary, 0b010001, 6); //cop1
-                instruction.binary = append_binary(instruction.binary, 0b01000, 5); //BC
-                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //CC ND TF
+            "dsrlv" => {
+                instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
 
                 read_operands(
                     instruction,
-                    vec![LabelRelative],
-                    vec![1],
-                    Some(labels.clone()),
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![3, 2, 1],
+                    None,
                 );
 
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
+                instruction.binary = append_binary(instruction.binary, 0b010110, 6); //dsrlv
+
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
                 let info = InstructionDescription{
-                    syntax: "bc1f target".to_string(),
-                    description: "If FPConditionCode is 0, moves the program counter to point to the targeted instruction’s address.".to_string(),
+                    syntax: "dsrlv rd, rt, rs".to_string(),
+                    description: "Shifts the 64-bit doubleword in `rt` to the right by the number of bits given by the lower 6 bits of `rs`, filling the upper bits with zeros, and places the result into `rd`.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
-            "sll" => {
+            "dsrav" => {
                 instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
-                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
 
                 read_operands(
                     instruction,
-                    vec![RegisterGP, RegisterGP, ShiftAmount],
-                    vec![2, 1, 3],
-                    Some(labels.clone()),
+                    vec![RegisterGP, RegisterGP, RegisterGP],
+                    vec![3, 2, 1],
+                    None,
                 );
 
-                instruction.binary = append_binary(instruction.binary, 0b00000, 6); //sll
+                instruction.binary = append_binary(instruction.binary, 0b00000, 5); //0
+                instruction.binary = append_binary(instruction.binary, 0b010111, 6); //dsrav
 
                 //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
                 let info = InstructionDescription{
-                    syntax: "sll rt, rs, sa".to_string(),
-                    description: "Shifts the lower 32-bit word in `rs` to the left by sa number of bits and placing the sign-extended result into `rt`.".to_string(),
+                    syntax: "dsrav rd, rt, rs".to_string(),
+                    description: "Shifts the 64-bit doubleword in `rt` to the right by the number of bits given by the lower 6 bits of `rs`, duplicating the sign bit into the upper bits, and places the result into `rd`.".to_string(),
                 };
                 monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
             }
@@ -1427,15 +4931,17 @@ pub fn read_instructions(
 
                 instruction.binary = append_binary(instruction.binary, 0b00000, 6); //sll
 
-                //this instruction is not used in pseudo-instructions so we can push it to mouse_hover_string without checking if mouse_hover_string is empty
-                let info = InstructionDescription {
-                    syntax: "nop".to_string(),
-                    description: "This instruction does not do anything when it is run".to_string(),
-                };
-                monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+                //only adds mouse hover for nop if the nop was actually there already and not just inserted into a delay slot by the parser
+                if instruction.operator.start_end_columns != (0, 0) {
+                    let info = InstructionDescription {
+                        syntax: "nop".to_string(),
+                        description: "This instruction does not do anything when it is run"
+                            .to_string(),
+                    };
+                    monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
+                }
             }
             "syscall" => {
-                //our support for syscall is limited. It is simply there to end emulation
                 instruction.binary = append_binary(instruction.binary, 0b000000, 6); //special
                 instruction.binary = append_binary(instruction.binary, 0b00000000000000000000, 20); //stub of code
                 instruction.binary = append_binary(instruction.binary, 0b001100, 6);
@@ -1453,7 +4959,7 @@ pub fn read_instructions(
                 {
                     let info = InstructionDescription{
                         syntax: "syscall".to_string(),
-                        description: "This function is currently stubbed in SWIM. Normally, it reverts control back to the OS. SWIM uses it to effectively end the program.".to_string(),
+                        description: "Performs the system call service selected by the value in `$v0`. Arguments are passed in `$a0`, `$a1`, and `$f12`, and results are returned in `$v0`.\n\nSupported services are print_int (1), print_float (2), print_double (3), print_string (4), read_int (5), read_string (8), sbrk (9), exit (10), and exit2 (17). Any other value in `$v0` raises a system call exception, which ends the program unless it has an `exception_handler` label.".to_string(),
                     };
                     monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
                 }
@@ -1480,6 +4986,34 @@ pub fn read_instructions(
     }
 }
 
+///The compact branches sharing the opcodes of `addi` and `daddi` are told apart by the order of their rs and rt fields,
+/// so their registers are swapped if needed once read: `beqc` and `bnec` need rs to be less than rt (`rs_first`),
+/// and `bovc` and `bnvc` need rs to be greater than or equal to rt. As the comparisons are symmetric, swapping does
+/// not change what the instruction does. `beqc` and `bnec` cannot compare a register with itself or with $zero.
+fn order_compact_branch_registers(instruction: &mut Instruction, rs_first: bool) {
+    if !instruction.errors.is_empty() {
+        return;
+    }
+
+    let rs = (instruction.binary >> 21) & 0b11111;
+    let rt = (instruction.binary >> 16) & 0b11111;
+
+    if rs_first && (rs == 0 || rt == 0 || rs == rt) {
+        instruction.errors.push(Error {
+            error_name: InvalidCompactBranchRegisters,
+            token_causing_error: instruction.operator.token_name.clone(),
+            start_end_columns: instruction.operator.start_end_columns,
+            message: "".to_string(),
+        });
+        return;
+    }
+
+    if (rs_first && rs > rt) || (!rs_first && rs < rt) {
+        instruction.binary =
+            (instruction.binary & !(0b11111_11111 << 16)) | (rt << 21) | (rs << 16);
+    }
+}
+
 ///This function takes two numbers and inserts the binary of the second at a given index in the binary of the first.
 ///All binary values at and past the insertion index of the original string will be moved to the end of the resultant string.
 ///Since binary is sign extended on the left to 32 bits, insertion index must be the index from the end of the string.
@@ -1518,20 +5052,44 @@ pub fn append_binary(mut first: u32, mut second: u32, shift_amount: u8) -> u32 {
     first
 }
 
+///Adds the address of the text to the target of each `j` and `jal`, which were assembled as if the text started at
+/// address 0.
+pub fn relocate_jumps(instructions: &mut [Instruction], text_address: usize) {
+    for instruction in instructions.iter_mut() {
+        if matches!(
+            &*instruction.operator.token_name.to_lowercase(),
+            "j" | "jal"
+        ) {
+            instruction.binary += (text_address >> 2) as u32;
+        }
+    }
+}
+
 ///returns the address of the labelled main instruction. If none exists, returns address of labelled start instruction.
-///Otherwise returns 0.
-pub fn determine_pc_starting_point(labels: HashMap<String, usize>) -> usize {
+///Otherwise returns the address of the first instruction, `text_address`.
+pub fn determine_pc_starting_point(labels: HashMap<String, usize>, text_address: usize) -> usize {
     return match labels.get("main") {
         Some(main_address) => *main_address,
         None => match labels.get("start") {
             Some(start_address) => *start_address,
-            None => 0,
+            None => text_address,
         },
     };
 }
 
-///Creates a vector of u32 from the data found in the parser / assembler to put into memory.
-pub fn create_binary_vec(instructions: Vec<Instruction>, mut vec_of_data: Vec<u8>) -> Vec<u32> {
+///returns the address of the instruction labelled exception_handler, if one exists.
+pub fn determine_exception_handler(labels: &HashMap<String, usize>) -> Option<usize> {
+    labels.get("exception_handler").copied()
+}
+
+///Creates a vector of u32 from the data found in the parser / assembler to put into memory. The bytes of data are
+/// packed into words in the byte order of `endianness`, so that storing the words in memory of that byte order puts
+/// each byte back at its own address.
+pub fn create_binary_vec(
+    instructions: Vec<Instruction>,
+    mut vec_of_data: Vec<u8>,
+    endianness: Endianness,
+) -> Vec<u32> {
     //push all instructions
     let mut binary: Vec<u32> = Vec::new();
     for instruction in instructions {
@@ -1545,22 +5103,9 @@ pub fn create_binary_vec(instructions: Vec<Instruction>, mut vec_of_data: Vec<u8
     }
     vec_of_data.resize(vec_of_data.len() + mod4, 0);
 
-    //push the .data
-    let mut i = 0;
-    while i < vec_of_data.len() {
-        //create a word from 4 bytes and then push it to the vec
-        let mut word = vec_of_data[i] as u32;
-        word <<= 8;
-        i += 1;
-        word |= vec_of_data[i] as u32;
-        word <<= 8;
-        i += 1;
-        word |= vec_of_data[i] as u32;
-        word <<= 8;
-        i += 1;
-        word |= vec_of_data[i] as u32;
-        binary.push(word);
-        i += 1;
+    //push the .data, creating a word from every 4 bytes
+    for bytes in vec_of_data.chunks(4) {
+        binary.push(endianness.from_bytes(bytes) as u32);
     }
 
     binary
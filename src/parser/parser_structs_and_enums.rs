@@ -13,6 +13,9 @@ pub struct ProgramInfo {
     pub instructions: Vec<Instruction>,
     pub data: Vec<Data>,
     pub pc_starting_point: usize,
+    pub exception_handler: Option<usize>,
+    ///The address the first instruction is placed at.
+    pub text_address: usize,
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -215,6 +218,9 @@ pub enum ErrorType {
     NonASCIIString, //One or multiple characters within the given string cannot be represented in ASCII
     NonASCIIChar,   //The given char cannot be represented in ASCII
     JALRRDRegisterZero, //The destination address for JALR cannot be the zero register
+    InvalidCompactBranchRegisters, //The registers given to beqc, bnec, beqzalc, or bnezalc cannot be encoded
+    RequiresRelease6, //The instruction shares its opcode with addi or daddi and is only assembled in release 6 mode
+    RetiredInRelease6, //addi and daddi are retired in release 6 mode
 }
 
 impl fmt::Display for ErrorType {
@@ -231,33 +237,270 @@ pub enum OperandType {
     MemoryAddress,
     LabelAbsolute,
     LabelRelative,
+    //wider relative offsets used by the compact branches beqzc/bnezc (21 bits) and bc/balc (26 bits)
+    LabelRelative21,
+    LabelRelative26,
     ShiftAmount,
+    //floating-point condition code (0-7) used by c.cond.fmt, bc1t, and bc1f
+    ConditionCode,
 }
 
-pub const SUPPORTED_INSTRUCTIONS: [&str; 64] = [
-    "add", "add.d", "add.s", "addi", "addiu", "addu", "and", "andi", "aui", "b", "bc1f", "bc1t",
-    "beq", "bne", "c.eq.d", "c.eq.s", "c.le.d", "c.le.s", "c.lt.d", "c.lt.s", "c.nge.d", "c.nge.s",
-    "c.ngt.d", "c.ngt.s", "dadd", "daddi", "daddiu", "daddu", "dahi", "dati", "ddiv", "ddivu",
-    "div", "div.d", "div.s", "dmfc1", "dmtc1", "dmul", "dmulu", "dsub", "dsubu", "j", "jal",
-    "jalr", "jr", "lui", "lw", "lwc1", "mfc1", "mtc1", "mul", "mul.d", "mul.s", "nop", "or", "ori",
-    "sll", "slt", "sltu", "sub", "sub.d", "sub.s", "sw", "swc1",
+//compact branches only assembled in release 6 mode, as they share their opcodes with addi and daddi
+pub const RELEASE_6_INSTRUCTIONS: [&str; 6] =
+    ["beqc", "beqzalc", "bnec", "bnezalc", "bnvc", "bovc"];
+
+//instructions retired in release 6 mode, where their opcodes are used by RELEASE_6_INSTRUCTIONS
+pub const RETIRED_IN_RELEASE_6: [&str; 2] = ["addi", "daddi"];
+
+//branches and jumps that have a delay slot filled by the assembler under `.set reorder`. Compact branches are not included.
+pub const DELAY_SLOT_INSTRUCTIONS: [&str; 17] = [
+    "b", "bc1eqz", "bc1f", "bc1nez", "bc1t", "beq", "bgez", "bgezal", "bgtz", "blez", "bltz",
+    "bltzal", "bne", "j", "jal", "jalr", "jr",
 ];
 
-pub const UNSUPPORTED_INSTRUCTIONS: [&str; 408] = [
+pub const SUPPORTED_INSTRUCTIONS: [&str; 231] = [
     "abs.d",
-    "abs.ps",
     "abs.s",
+    "add",
+    "add.d",
+    "add.s",
+    "addi",
+    "addiu",
+    "addu",
+    "and",
+    "andi",
+    "aui",
+    "b",
+    "balc",
+    "bc",
+    "bc1eqz",
+    "bc1f",
+    "bc1nez",
+    "bc1t",
+    "beq",
+    "beqc",
+    "beqzalc",
+    "beqzc",
+    "bgec",
+    "bgez",
+    "bgezal",
+    "bgtz",
+    "blez",
+    "bltc",
+    "bltz",
+    "bltzal",
+    "bne",
+    "bnec",
+    "bnezalc",
+    "bnezc",
+    "bnvc",
+    "bovc",
+    "break",
+    "c.eq.d",
+    "c.eq.s",
+    "c.le.d",
+    "c.le.s",
+    "c.lt.d",
+    "c.lt.s",
+    "c.nge.d",
+    "c.nge.s",
+    "c.ngt.d",
+    "c.ngt.s",
+    "ceil.l.d",
+    "ceil.l.s",
+    "ceil.w.d",
+    "ceil.w.s",
+    "cfc1",
+    "cmp.af.d",
+    "cmp.af.s",
+    "cmp.eq.d",
+    "cmp.eq.s",
+    "cmp.le.d",
+    "cmp.le.s",
+    "cmp.lt.d",
+    "cmp.lt.s",
+    "cmp.ne.d",
+    "cmp.ne.s",
+    "cmp.or.d",
+    "cmp.or.s",
+    "cmp.saf.d",
+    "cmp.saf.s",
+    "cmp.seq.d",
+    "cmp.seq.s",
+    "cmp.sle.d",
+    "cmp.sle.s",
+    "cmp.slt.d",
+    "cmp.slt.s",
+    "cmp.sne.d",
+    "cmp.sne.s",
+    "cmp.sor.d",
+    "cmp.sor.s",
+    "cmp.sueq.d",
+    "cmp.sueq.s",
+    "cmp.sule.d",
+    "cmp.sule.s",
+    "cmp.sult.d",
+    "cmp.sult.s",
+    "cmp.sun.d",
+    "cmp.sun.s",
+    "cmp.sune.d",
+    "cmp.sune.s",
+    "cmp.ueq.d",
+    "cmp.ueq.s",
+    "cmp.ule.d",
+    "cmp.ule.s",
+    "cmp.ult.d",
+    "cmp.ult.s",
+    "cmp.un.d",
+    "cmp.un.s",
+    "cmp.une.d",
+    "cmp.une.s",
+    "ctc1",
+    "cvt.d.l",
+    "cvt.d.s",
+    "cvt.d.w",
+    "cvt.l.d",
+    "cvt.l.s",
+    "cvt.s.d",
+    "cvt.s.l",
+    "cvt.s.w",
+    "cvt.w.d",
+    "cvt.w.s",
+    "dadd",
+    "daddi",
+    "daddiu",
+    "daddu",
+    "dahi",
+    "dati",
+    "ddiv",
+    "ddivu",
+    "div",
+    "div.d",
+    "div.s",
+    "dmfc1",
+    "dmod",
+    "dmodu",
+    "dmtc1",
+    "dmuh",
+    "dmuhu",
+    "dmul",
+    "dmulu",
+    "dsll",
+    "dsll32",
+    "dsllv",
+    "dsra",
+    "dsra32",
+    "dsrav",
+    "dsrl",
+    "dsrl32",
+    "dsrlv",
+    "dsub",
+    "dsubu",
+    "eret",
+    "floor.l.d",
+    "floor.l.s",
+    "floor.w.d",
+    "floor.w.s",
+    "j",
+    "jal",
+    "jalr",
+    "jr",
+    "lb",
+    "lbu",
+    "ld",
+    "ldc1",
+    "lh",
+    "lhu",
+    "lui",
+    "lw",
+    "lwc1",
+    "max.d",
+    "max.s",
+    "mfc0",
+    "mfc1",
+    "min.d",
+    "min.s",
+    "mod",
+    "modu",
+    "mov.d",
+    "mov.s",
+    "movf",
+    "movf.d",
+    "movf.s",
+    "movn",
+    "movn.d",
+    "movn.s",
+    "movt",
+    "movt.d",
+    "movt.s",
+    "movz",
+    "movz.d",
+    "movz.s",
+    "mtc0",
+    "mtc1",
+    "muh",
+    "muhu",
+    "mul",
+    "mul.d",
+    "mul.s",
+    "neg.d",
+    "neg.s",
+    "nop",
+    "nor",
+    "or",
+    "ori",
+    "recip.d",
+    "recip.s",
+    "round.l.d",
+    "round.l.s",
+    "round.w.d",
+    "round.w.s",
+    "rsqrt.d",
+    "rsqrt.s",
+    "sb",
+    "sd",
+    "sdc1",
+    "sel.d",
+    "sel.s",
+    "seleqz",
+    "seleqz.d",
+    "seleqz.s",
+    "selnez",
+    "selnez.d",
+    "selnez.s",
+    "sh",
+    "sll",
+    "sllv",
+    "slt",
+    "sltu",
+    "sqrt.d",
+    "sqrt.s",
+    "sra",
+    "srav",
+    "srl",
+    "srlv",
+    "sub",
+    "sub.d",
+    "sub.s",
+    "sw",
+    "swc1",
+    "trunc.l.d",
+    "trunc.l.s",
+    "trunc.w.d",
+    "trunc.w.s",
+    "xor",
+    "xori",
+];
+
+pub const UNSUPPORTED_INSTRUCTIONS: [&str; 273] = [
+    "abs.ps",
     "addiupc",
     "align",
     "alnv.ps",
     "aluipc",
     "auipc",
     "bal",
-    "balc",
-    "bc",
-    "bc1eqz",
     "bc1fl",
-    "bc1nez",
     "bc1tl",
     "bc2eqz",
     "bc2f",
@@ -265,46 +508,29 @@ pub enum OperandType {
     "bc2nez",
     "bc2t",
     "bc2tl",
-    "beqc",
     "beql",
-    "beqzalc",
-    "beqzc",
-    "bgec",
     "bgeuc",
-    "bgez",
-    "bgezal",
     "bgezalc",
     "bgezall",
     "bgezc",
     "bgezl",
     "bgtc",
     "bgtuc",
-    "bgtz",
     "bgtzalc",
     "bgtzc",
     "bgtzl",
     "bitswap",
     "blec",
     "bleuc",
-    "blez",
     "blezalc",
     "blezc",
     "blezl",
-    "bltc",
     "bltuc",
-    "bltz",
-    "bltzal",
     "bltzalc",
     "bltzall",
     "bltzc",
     "bltzl",
-    "bnec",
     "bnel",
-    "bnezalc",
-    "bnezc",
-    "bnvc",
-    "bovc",
-    "break",
     "c.f.d",
     "c.f.s",
     "c.ngl.d",
@@ -329,24 +555,13 @@ pub enum OperandType {
     "c.un.s",
     "cache",
     "cachee",
-    "ceil.l.d",
-    "ceil.l.s",
-    "ceil.w.d",
-    "ceil.w.s",
-    "cfc1",
     "cfc2",
     "class.d",
     "class.s",
     "clo",
     "clz",
-    "cmp.eq.d",
-    "cmp.eq.s",
     "cmp.f.d",
     "cmp.f.s",
-    "cmp.le.d",
-    "cmp.le.s",
-    "cmp.lt.d",
-    "cmp.lt.s",
     "cmp.nge.d",
     "cmp.nge.s",
     "cmp.ngl.d",
@@ -359,18 +574,8 @@ pub enum OperandType {
     "cmp.ole.s",
     "cmp.olt.d",
     "cmp.olt.s",
-    "cmp.seq.d",
-    "cmp.seq.s",
     "cmp.sf.d",
     "cmp.sf.s",
-    "cmp.ueq.d",
-    "cmp.ueq.s",
-    "cmp.ule.d",
-    "cmp.ule.s",
-    "cmp.ult.d",
-    "cmp.ult.s",
-    "cmp.un.d",
-    "cmp.un.s",
     "cop2",
     "crc32b",
     "crc32cb",
@@ -380,21 +585,10 @@ pub enum OperandType {
     "crc32d",
     "crc32h",
     "crc32w",
-    "ctc1",
     "ctc2",
-    "cvt.d.l",
-    "cvt.d.s",
-    "cvt.d.w",
-    "cvt.l.d",
-    "cvt.l.s",
     "cvt.ps.s",
-    "cvt.s.d",
-    "cvt.s.l",
     "cvt.s.pl",
     "cvt.s.pu",
-    "cvt.s.w",
-    "cvt.w.d",
-    "cvt.w.s",
     "dalign",
     "daui",
     "dbitswap",
@@ -411,12 +605,8 @@ pub enum OperandType {
     "divu",
     "dlsa",
     "dmfc0",
-    "dmod",
-    "dmodu",
     "dmtc0",
     "dmtc2",
-    "dmuh",
-    "dmuhu",
     "dmult",
     "dmultu",
     "drotr",
@@ -424,26 +614,12 @@ pub enum OperandType {
     "drotrv",
     "dsbh",
     "dshd",
-    "dsll",
-    "dsll32",
-    "dsllv",
-    "dsra",
-    "dsra32",
-    "dsrav",
-    "dsrl",
-    "dsrl32",
-    "dsrlv",
     "dvp",
     "ehb",
     "ei",
-    "eret",
     "eretnc",
     "evp",
     "ext",
-    "floor.l.d",
-    "floor.l.s",
-    "floor.w.d",
-    "floor.w.s",
     "ginvi",
     "ginvt",
     "ins",
@@ -452,19 +628,14 @@ pub enum OperandType {
     "jialc",
     "jic",
     "jr.hb",
-    "lb",
     "lbe",
-    "lbu",
     "lbue",
-    "ldc1",
     "ldc2",
     "ldl",
     "ldpc",
     "ldr",
     "ldxc1",
-    "lh",
     "lhe",
-    "lhu",
     "lhue",
     "ll",
     "lld",
@@ -492,8 +663,6 @@ pub enum OperandType {
     "maddf.s",
     "maddf.s",
     "maddu",
-    "max.d",
-    "max.s",
     "maxa.d",
     "maxa.s",
     "mcf0",
@@ -501,29 +670,12 @@ pub enum OperandType {
     "mfc2",
     "mfhi",
     "mflo",
-    "min.s",
     "mina.d",
-    "mod",
-    "modu",
-    "mov.d",
     "mov.ps",
-    "mov.s",
-    "movf",
-    "movf.d",
     "movf.ps",
-    "movf.s",
-    "movn",
-    "movn.d",
     "movn.ps",
-    "movn.s",
-    "movt",
-    "movt.d",
     "movt.ps",
-    "movt.s",
-    "movz",
-    "movz.d",
     "movz.ps",
-    "movz.s",
     "msub",
     "msub.d",
     "msub.ps",
@@ -531,30 +683,24 @@ pub enum OperandType {
     "msubf.d",
     "msubf.s",
     "msubu",
-    "mtc0",
     "mtc2",
     "mthc0",
     "mthc1",
     "mthc2",
     "mthi",
     "mtlo",
-    "muh",
-    "muhu",
     "mul.ps",
     "mult",
     "multu",
     "mulu",
     "nal",
-    "neg.d",
     "neg.ps",
-    "neg.s",
     "nmadd.d",
     "nmadd.ps",
     "nmadd.s",
     "nmsub.d",
     "nmsub.ps",
     "nmsub.s",
-    "nor",
     "pause",
     "pll.ps",
     "plu.ps",
@@ -565,19 +711,10 @@ pub enum OperandType {
     "puu.ps",
     "rdhwr",
     "rdpgpr",
-    "recip.d",
-    "recip.s",
     "rint.d",
     "rint.s",
     "rotrv",
     "rotzr",
-    "round.l.d",
-    "round.l.s",
-    "round.w.d",
-    "round.w.s",
-    "rsqrt.d",
-    "rsqrt.s",
-    "sb",
     "sbe",
     "sc",
     "scd",
@@ -586,33 +723,16 @@ pub enum OperandType {
     "scwp",
     "scwpe",
     "sdbbp",
-    "sdc1",
     "sdc2",
     "sdl",
     "sdr",
     "sdxc1",
     "seb",
     "seh",
-    "sel.d",
-    "sel.s",
-    "seleqz",
-    "seleqz.d",
-    "seleqz.s",
-    "selneqz.d",
-    "selneqz.s",
-    "selnez",
-    "sh",
     "she",
     "sigrie",
-    "sllv",
     "slti",
     "sltiu",
-    "sqrt.d",
-    "sqrt.s",
-    "sra",
-    "srav",
-    "srl",
-    "srlv",
     "ssnop",
     "sub.ps",
     "subu",
@@ -644,14 +764,8 @@ pub enum OperandType {
     "tltu",
     "tne",
     "tnei",
-    "trunc.l.d",
-    "trunc.l.s",
-    "trunc.w.d",
-    "trunc.w.s",
     "wait",
     "wrpgpr",
-    "xor",
-    "xori",
 ];
 
 ///Contains every general purpose register's binary value and the various names they are recognized as. Any reference to gp registers throughout the parser/assembler should reference this array
@@ -1,7 +1,8 @@
 use crate::parser::parser_structs_and_enums::ErrorType::*;
 use crate::parser::parser_structs_and_enums::TokenType::{Directive, Label, Operator, Unknown};
 use crate::parser::parser_structs_and_enums::{
-    Data, Error, Instruction, LabelInstance, MonacoLineInfo, Token, FP_REGISTERS, GP_REGISTERS,
+    Data, Error, Instruction, LabelInstance, MonacoLineInfo, Token, DELAY_SLOT_INSTRUCTIONS,
+    FP_REGISTERS, GP_REGISTERS, RELEASE_6_INSTRUCTIONS, RETIRED_IN_RELEASE_6,
     SUPPORTED_INSTRUCTIONS,
 };
 use levenshtein::levenshtein;
@@ -225,6 +226,13 @@ pub fn separate_data_and_text(lines: &mut Vec<MonacoLineInfo>) -> (Vec<Instructi
             i += 1;
             continue;
         }
+        //.set directives only change how the assembler treats the instructions after them,
+        //which is handled by insert_delay_slot_nops
+        if lines[i].tokens[0].token_name.to_lowercase() == ".set" {
+            lines[i].tokens[0].token_type = Directive;
+            i += 1;
+            continue;
+        }
         let mut j = 0;
         //add all labels to the label stack
         while lines[i].tokens.len() > j && lines[i].tokens[j].token_name.ends_with(':') {
@@ -338,10 +346,103 @@ pub fn separate_data_and_text(lines: &mut Vec<MonacoLineInfo>) -> (Vec<Instructi
     (instruction_list, data_list)
 }
 
-///Create_label_map builds a hashmap of addresses for labels in memory
+///The opcodes of `addi` and `daddi` are shared with the compact branches `beqc`, `bnec`, `beqzalc`, `bnezalc`, `bovc`,
+/// and `bnvc`, so only one or the other can be assembled. In release 6 mode, `addi` and `daddi` are retired and
+/// reported as errors. Otherwise, the compact branches are reported as errors.
+pub fn check_release_6_instructions(instructions: &mut [Instruction], release_6: bool) {
+    for instruction in instructions {
+        let operator = instruction.operator.token_name.to_lowercase();
+        let error_name = if release_6 && RETIRED_IN_RELEASE_6.contains(&&*operator) {
+            RetiredInRelease6
+        } else if !release_6 && RELEASE_6_INSTRUCTIONS.contains(&&*operator) {
+            RequiresRelease6
+        } else {
+            continue;
+        };
+
+        instruction.errors.push(Error {
+            error_name,
+            token_causing_error: instruction.operator.token_name.clone(),
+            start_end_columns: instruction.operator.start_end_columns,
+            message: "".to_string(),
+        });
+    }
+}
+
+///Fills the branch delay slot of every branch and jump assembled under `.set reorder` with a nop,
+/// then reassigns instruction numbers. The assembler starts in noreorder mode since the datapath
+/// does not use delay slots by default. Compact branches have no delay slot so they are not affected.
+pub fn insert_delay_slot_nops(
+    instructions: &mut Vec<Instruction>,
+    monaco_line_info: &[MonacoLineInfo],
+) {
+    //find which lines are assembled in reorder mode
+    let mut reorder = false;
+    let mut reorder_by_line: Vec<bool> = Vec::new();
+    for line in monaco_line_info {
+        if line.tokens.len() > 1 && line.tokens[0].token_name.to_lowercase() == ".set" {
+            match &*line.tokens[1].token_name.to_lowercase() {
+                "reorder" => reorder = true,
+                "noreorder" => reorder = false,
+                //other .set options are not supported and have no effect
+                _ => {}
+            }
+        }
+        reorder_by_line.push(reorder);
+    }
+
+    let mut i = 0;
+    while i < instructions.len() {
+        let operator = instructions[i].operator.token_name.to_lowercase();
+        if reorder_by_line[instructions[i].line_number]
+            && DELAY_SLOT_INSTRUCTIONS.contains(&&*operator)
+        {
+            //the nop belongs to the same line as the branch so it is highlighted along with it
+            let nop = Instruction {
+                operator: Token {
+                    token_name: "nop".to_string(),
+                    start_end_columns: (0, 0),
+                    token_type: Operator,
+                },
+                operands: vec![],
+                binary: 0,
+                instruction_number: 0,
+                line_number: instructions[i].line_number,
+                errors: vec![],
+                labels: Vec::new(),
+            };
+            instructions.insert(i + 1, nop);
+            i += 1;
+        }
+        i += 1;
+    }
+
+    for (i, instruction) in instructions.iter_mut().enumerate() {
+        instruction.instruction_number = i;
+    }
+}
+
+///Create_label_map builds a hashmap of addresses for labels in memory, with the instructions starting at address 0
+/// and the data right after them.
 pub fn create_label_map(
     instruction_list: &mut Vec<Instruction>,
     data_list: &mut [Data],
+) -> HashMap<String, usize> {
+    let data_address = match instruction_list.last() {
+        Some(last_instruction) => (last_instruction.instruction_number + 1) << 2,
+        None => 0,
+    };
+
+    create_label_map_at(instruction_list, data_list, 0, data_address)
+}
+
+///Builds a hashmap of addresses for labels in memory, with the instructions starting at `text_address` and the data
+/// starting at `data_address`.
+pub fn create_label_map_at(
+    instruction_list: &mut Vec<Instruction>,
+    data_list: &mut [Data],
+    text_address: usize,
+    data_address: usize,
 ) -> HashMap<String, usize> {
     let mut labels: HashMap<String, usize> = HashMap::new();
     //iterate through every instance of instruction and try to add the label to the map
@@ -359,20 +460,12 @@ pub fn create_label_map(
             } else {
                 labels.insert(
                     label.token.token_name,
-                    instruction.clone().instruction_number << 2,
+                    text_address + (instruction.instruction_number << 2),
                 );
             }
         }
     }
 
-    let last_instruction = instruction_list.last();
-
-    let offset_for_instructions: u32 = if let Some(..) = last_instruction {
-        (last_instruction.unwrap().instruction_number + 1) << 2
-    } else {
-        0
-    } as u32;
-
     for (_i, data) in data_list.iter_mut().enumerate() {
         //if the given label name is already used, an error is generated
         if labels.contains_key(&*data.label.clone().token_name) {
@@ -386,7 +479,7 @@ pub fn create_label_map(
         } else {
             labels.insert(
                 data.label.token_name.clone(),
-                data.data_number + offset_for_instructions as usize,
+                data_address + data.data_number,
             );
         }
     }
@@ -574,6 +667,15 @@ pub fn suggest_error_corrections(
                             "The destination address for JALR cannot be the zero register\n"
                                 .to_string();
                     }
+                    InvalidCompactBranchRegisters => {
+                        error.message = "BEQC and BNEC must compare two different registers, and neither these nor BEQZALC and BNEZALC can use the zero register\n".to_string();
+                    }
+                    RequiresRelease6 => {
+                        error.message = "This instruction shares its opcode with ADDI or DADDI, so it is only available in Release 6 mode\n".to_string();
+                    }
+                    RetiredInRelease6 => {
+                        error.message = "This instruction is retired in Release 6 mode, where its opcode is used by compact branches. ADDIU and DADDIU can be used instead\n".to_string();
+                    }
                     UnnecessaryComma => {
                         error.message = "The given token should not end with a comma\n".to_string()
                     }
@@ -220,7 +220,7 @@ pub fn expand_pseudo_instructions_and_assign_instruction_numbers(
                     syntax: "sle rd, rs, rt".to_string(),
                     translation_lines: vec![
                         "slt rd, rt, rs".to_string(),
-                        "addi rd, rd, 1".to_string(),
+                        "addiu rd, rd, 1".to_string(),
                         "andi rd, rd, 1".to_string(),
                     ],
                 };
@@ -248,10 +248,10 @@ pub fn expand_pseudo_instructions_and_assign_instruction_numbers(
 
                 instruction.labels = Vec::new(); //if the pseudo-instruction had a label, remove it so it's only on the first expanded instruction
 
-                //addi
+                //addiu
                 let mut extra_instruction_2 = Instruction {
                     operator: Token {
-                        token_name: "addi".to_string(),
+                        token_name: "addiu".to_string(),
                         start_end_columns: (0, 0),
                         token_type: Operator,
                     },
@@ -292,7 +292,7 @@ pub fn expand_pseudo_instructions_and_assign_instruction_numbers(
                     syntax: "sle rd, rs, rt".to_string(),
                     translation_lines: vec![
                         "sltu rd, rt, rs".to_string(),
-                        "addi rd, rd, 1".to_string(),
+                        "addiu rd, rd, 1".to_string(),
                         "andi rd, rd, 1".to_string(),
                     ],
                 };
@@ -320,10 +320,10 @@ pub fn expand_pseudo_instructions_and_assign_instruction_numbers(
 
                 instruction.labels = Vec::new(); //if the pseudo-instruction had a label, remove it so it's only on the first expanded instruction
 
-                //addi
+                //addiu
                 let mut extra_instruction_2 = Instruction {
                     operator: Token {
-                        token_name: "addi".to_string(),
+                        token_name: "addiu".to_string(),
                         start_end_columns: (0, 0),
                         token_type: Operator,
                     },
@@ -416,7 +416,7 @@ pub fn expand_pseudo_instructions_and_assign_instruction_numbers(
                     syntax: "sge rd, rs, rt".to_string(),
                     translation_lines: vec![
                         "slt rd, rs, rt".to_string(),
-                        "addi rd, rd, 1".to_string(),
+                        "addiu rd, rd, 1".to_string(),
                         "andi rd, rd, 1".to_string(),
                     ],
                 };
@@ -441,10 +441,10 @@ pub fn expand_pseudo_instructions_and_assign_instruction_numbers(
 
                 instruction.labels = Vec::new(); //if the pseudo-instruction had a label, remove it so it's only on the first expanded instruction
 
-                //addi
+                //addiu
                 let mut extra_instruction_2 = Instruction {
                     operator: Token {
-                        token_name: "addi".to_string(),
+                        token_name: "addiu".to_string(),
                         start_end_columns: (0, 0),
                         token_type: Operator,
                     },
@@ -485,7 +485,7 @@ pub fn expand_pseudo_instructions_and_assign_instruction_numbers(
                     syntax: "sgeu rd, rs, rt".to_string(),
                     translation_lines: vec![
                         "sltu rd, rs, rt".to_string(),
-                        "addi rd, rd, 1".to_string(),
+                        "addiu rd, rd, 1".to_string(),
                         "andi rd, rd, 1".to_string(),
                     ],
                 };
@@ -510,10 +510,10 @@ pub fn expand_pseudo_instructions_and_assign_instruction_numbers(
 
                 instruction.labels = Vec::new(); //if the pseudo-instruction had a label, remove it so it's only on the first expanded instruction
 
-                //addi
+                //addiu
                 let mut extra_instruction_2 = Instruction {
                     operator: Token {
-                        token_name: "addi".to_string(),
+                        token_name: "addiu".to_string(),
                         start_end_columns: (0, 0),
                         token_type: Operator,
                     },
@@ -548,10 +548,12 @@ pub fn expand_pseudo_instructions_and_assign_instruction_numbers(
                     instruction,
                 ]);
             }
-            "lw" | "sw" => {
+            "lb" | "lbu" | "lh" | "lhu" | "lw" | "ld" | "sb" | "sh" | "sw" | "sd" | "ldc1"
+            | "sdc1" => {
                 //lw $regA, label is translated to:
                 //lui $at, label
                 //lw $regA, lower16($at)
+                //The other loads and stores are translated the same way.
 
                 if instruction.operands.len() > 1
                     && list_of_labels.contains(&instruction.operands[1].token_name)
@@ -567,30 +569,18 @@ pub fn expand_pseudo_instructions_and_assign_instruction_numbers(
                         continue;
                     }
 
-                    //create mouse hover message dependent on lw / sw
-                    if instruction.operator.token_name == "lw" {
-                        let info = PseudoDescription {
-                            name: "lw rt target".to_string(),
-                            syntax: "lw rt target".to_string(),
-                            translation_lines: vec![
-                                "lui $at, upper48".to_string(),
-                                "lw rt, lower16($at)".to_string(),
-                            ],
-                        };
-                        monaco_line_info[instruction.line_number].mouse_hover_string =
-                            info.to_string();
-                    } else {
-                        let info = PseudoDescription {
-                            name: "sw rt target".to_string(),
-                            syntax: "sw rt target".to_string(),
-                            translation_lines: vec![
-                                "lui $at, upper48".to_string(),
-                                "sw rt, lower16($at)".to_string(),
-                            ],
-                        };
-                        monaco_line_info[instruction.line_number].mouse_hover_string =
-                            info.to_string();
-                    }
+                    //create mouse hover message dependent on the load / store
+                    let operator = &instruction.operator.token_name;
+                    let register = if operator.ends_with("c1") { "ft" } else { "rt" };
+                    let info = PseudoDescription {
+                        name: format!("{operator} {register} target"),
+                        syntax: format!("{operator} {register} target"),
+                        translation_lines: vec![
+                            "lui $at, upper48".to_string(),
+                            format!("{operator} {register}, lower16($at)"),
+                        ],
+                    };
+                    monaco_line_info[instruction.line_number].mouse_hover_string = info.to_string();
                     monaco_line_info[instruction.line_number]
                         .mouse_hover_string
                         .push_str(
@@ -1237,7 +1227,7 @@ pub fn expand_pseudo_instructions_and_assign_instruction_numbers(
     }
 }
 
-///the second part of completing pseudo-instructions. LW and SW with labels requires the address of the label to be known,
+///the second part of completing pseudo-instructions. Loads and stores with labels require the address of the label to be known,
 /// the second part of this must occur after the label hashmap is completed.
 pub fn complete_lw_sw_pseudo_instructions(
     instructions: &mut Vec<Instruction>,
@@ -1252,14 +1242,27 @@ pub fn complete_lw_sw_pseudo_instructions(
         if instructions[index].operator.token_name == "lui"
             && instructions[index].operands.len() > 1
             && labels.contains_key(&*instructions[index].operands[1].token_name)
-            && (instructions[index + 1].operator.token_name == "sw"
-                || instructions[index + 1].operator.token_name == "lw")
+            && matches!(
+                &*instructions[index + 1].operator.token_name,
+                "lb" | "lbu"
+                    | "lh"
+                    | "lhu"
+                    | "lw"
+                    | "ld"
+                    | "sb"
+                    | "sh"
+                    | "sw"
+                    | "sd"
+                    | "ldc1"
+                    | "sdc1"
+            )
         {
-            //upper 16 bits are stored in $at using lui
+            //upper 16 bits are stored in $at using lui. The offset is sign-extended by the load/store, so the
+            //upper bits are rounded up when bit 15 of the address is set.
             let address = *labels
                 .get(&*instructions[index].operands[1].token_name)
                 .unwrap();
-            instructions[index].operands[1].token_name = (address >> 16).to_string();
+            instructions[index].operands[1].token_name = ((address + 0x8000) >> 16).to_string();
             instructions[index].operands[1].start_end_columns = (0, 0);
 
             index += 1;
@@ -1,3 +1,13 @@
+pub mod branch_predictor;
+pub mod cache;
+pub mod datapath_model;
+pub mod debugger;
+pub mod floating_point;
+pub mod history;
 pub mod memory;
 pub mod mips;
+pub mod mmio;
+pub mod multi_cycle_datapath;
+pub mod performance;
+pub mod pipelined_datapath;
 pub mod registers;
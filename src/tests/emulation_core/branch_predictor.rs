@@ -0,0 +1,162 @@
+use crate::emulation_core::mips::branch_predictor::*;
+
+/// Create a branch predictor of the given kind, with the default table sizes.
+fn predictor(kind: PredictorKind) -> BranchPredictor {
+    BranchPredictor::new(BranchPredictorConfig {
+        kind,
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+/// Record a branch at address `0x40` with the given outcomes, which goes back
+/// to address `0x20` if taken. Returns the number of correct predictions.
+fn run_branch(predictor: &mut BranchPredictor, outcomes: &[bool]) -> usize {
+    outcomes
+        .iter()
+        .filter(|&&taken| predictor.update_branch(0x40, 0x20, taken))
+        .count()
+}
+
+/// The outcomes of the branch at the end of a loop run four times, twice.
+const LOOP_TWICE: [bool; 8] = [true, true, true, false, true, true, true, false];
+
+#[test]
+fn invalid_configurations() {
+    let invalid_configs = [
+        BranchPredictorConfig {
+            table_size: 48,
+            ..Default::default()
+        },
+        BranchPredictorConfig {
+            history_bits: 33,
+            ..Default::default()
+        },
+        BranchPredictorConfig {
+            btb_size: Some(0),
+            ..Default::default()
+        },
+    ];
+
+    for config in invalid_configs {
+        assert!(BranchPredictor::new(config).is_err());
+    }
+
+    assert!(BranchPredictor::new(BranchPredictorConfig {
+        btb_size: None,
+        ..Default::default()
+    })
+    .is_ok());
+}
+
+#[test]
+fn static_not_taken() {
+    let mut predictor = predictor(PredictorKind::StaticNotTaken);
+
+    assert_eq!(run_branch(&mut predictor, &LOOP_TWICE), 2);
+    assert_eq!(predictor.statistics.branches, 8);
+    assert_eq!(predictor.statistics.taken, 6);
+    assert_eq!(predictor.statistics.accuracy(), 0.25);
+}
+
+#[test]
+fn static_backward_taken_forward_not_taken() {
+    let predictor = predictor(PredictorKind::StaticBtfn);
+
+    assert!(predictor.predict(0x40, 0x20));
+    assert!(!predictor.predict(0x40, 0x60));
+}
+
+#[test]
+fn one_bit_repeats_last_outcome() {
+    let mut predictor = predictor(PredictorKind::OneBit);
+
+    // The first branch of each run of the loop and its exit are mispredicted.
+    assert_eq!(run_branch(&mut predictor, &LOOP_TWICE), 4);
+}
+
+#[test]
+fn two_bit_tolerates_a_single_misprediction() {
+    let mut predictor = predictor(PredictorKind::TwoBit);
+
+    // Only the exit of the loop is mispredicted once the counter has warmed up.
+    assert_eq!(run_branch(&mut predictor, &LOOP_TWICE), 5);
+}
+
+#[test]
+fn gshare_learns_alternating_pattern() {
+    let outcomes: Vec<bool> = (0..100).map(|i| i % 2 == 0).collect();
+
+    let mut two_bit = predictor(PredictorKind::TwoBit);
+    let mut gshare = predictor(PredictorKind::Gshare);
+
+    assert!(run_branch(&mut two_bit, &outcomes) <= 50);
+    assert!(run_branch(&mut gshare, &outcomes) >= 95);
+}
+
+#[test]
+fn branch_target_buffer() {
+    let mut predictor = BranchPredictor::new(BranchPredictorConfig {
+        btb_size: Some(4),
+        ..Default::default()
+    })
+    .unwrap();
+
+    predictor.update_jump(0x00, 0x80);
+    predictor.update_jump(0x00, 0x80);
+    assert_eq!(predictor.statistics.btb_lookups, 2);
+    assert_eq!(predictor.statistics.btb_hits, 1);
+
+    // Address 0x10 uses the same entry, replacing the first jump.
+    predictor.update_jump(0x10, 0x80);
+    predictor.update_jump(0x00, 0x80);
+    assert_eq!(predictor.statistics.btb_hits, 1);
+    assert_eq!(
+        predictor.btb()[0],
+        Some(BtbEntry {
+            address: 0x00,
+            target: 0x80
+        })
+    );
+
+    // Branches that are not taken are not looked up.
+    predictor.update_branch(0x04, 0x20, false);
+    assert_eq!(predictor.statistics.btb_lookups, 4);
+
+    // Jumps are not counted as conditional branches.
+    assert_eq!(predictor.statistics.branches, 1);
+}
+
+#[test]
+fn statistics_by_site_and_line() {
+    let mut predictor = predictor(PredictorKind::StaticNotTaken);
+
+    predictor.update_branch(0x00, 0x20, false);
+    predictor.update_branch(0x04, 0x20, true);
+    predictor.update_branch(0x08, 0x20, true);
+
+    assert_eq!(predictor.site_statistics.len(), 3);
+    assert_eq!(predictor.site_statistics[&0x04].taken, 1);
+
+    // The last two instructions come from the same source line, such as the
+    // expansion of a pseudo-instruction.
+    let lines = predictor.line_statistics(&[3, 5, 5], 0);
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[&3].correct, 1);
+    assert_eq!(lines[&5].branches, 2);
+    assert_eq!(lines[&5].correct, 0);
+}
+
+#[test]
+fn reset_forgets_history() {
+    let mut predictor = predictor(PredictorKind::TwoBit);
+    run_branch(&mut predictor, &[true, true]);
+    assert!(predictor.predict(0x40, 0x20));
+
+    predictor.reset();
+
+    assert!(!predictor.predict(0x40, 0x20));
+    assert_eq!(predictor.statistics, BranchStatistics::default());
+    assert!(predictor.site_statistics.is_empty());
+    assert!(predictor.btb().iter().all(Option::is_none));
+}
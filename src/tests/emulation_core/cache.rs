@@ -0,0 +1,263 @@
+use crate::emulation_core::mips::cache::*;
+
+/// Create a cache with 16-byte blocks, given the total size and associativity.
+fn cache(size: usize, associativity: usize, replacement_policy: ReplacementPolicy) -> Cache {
+    Cache::new(CacheConfig {
+        size,
+        block_size: 16,
+        associativity,
+        replacement_policy,
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+#[test]
+fn invalid_configurations() {
+    let invalid_configs = [
+        // Block size is not a power of two.
+        CacheConfig {
+            block_size: 12,
+            ..Default::default()
+        },
+        // Block size is smaller than a doubleword.
+        CacheConfig {
+            block_size: 4,
+            ..Default::default()
+        },
+        // No blocks per set.
+        CacheConfig {
+            associativity: 0,
+            ..Default::default()
+        },
+        // Size is not a multiple of the set size.
+        CacheConfig {
+            size: 1000,
+            ..Default::default()
+        },
+        // Number of sets is not a power of two.
+        CacheConfig {
+            size: 48,
+            ..Default::default()
+        },
+    ];
+
+    for config in invalid_configs {
+        assert!(Cache::new(config).is_err());
+    }
+
+    assert!(Cache::new(CacheConfig::default()).is_ok());
+}
+
+#[test]
+fn address_fields() {
+    let mut cache = cache(1024, 1, ReplacementPolicy::Lru);
+
+    // 64 sets of 16 bytes: the offset is 4 bits and the index is 6 bits.
+    let access = cache.access(0x1234, AccessKind::Read);
+    assert_eq!(access.set, 0x23);
+    assert_eq!(access.tag, 0x4);
+    assert!(!access.hit);
+
+    // Another byte of the same block hits.
+    assert!(cache.access(0x123C, AccessKind::Read).hit);
+}
+
+#[test]
+fn direct_mapped_conflict_misses() {
+    let mut cache = cache(1024, 1, ReplacementPolicy::Lru);
+
+    // 0x0000 and 0x0400 map to the same set.
+    for _ in 0..3 {
+        cache.access(0x0000, AccessKind::Read);
+        cache.access(0x0400, AccessKind::Read);
+    }
+
+    assert_eq!(cache.statistics.hits, 0);
+    assert_eq!(cache.statistics.misses, 6);
+    assert_eq!(cache.statistics.evictions, 5);
+    assert_eq!(cache.log.back().unwrap().evicted, Some(0x0000));
+}
+
+#[test]
+fn two_way_avoids_conflict_misses() {
+    let mut cache = cache(1024, 2, ReplacementPolicy::Lru);
+
+    for _ in 0..3 {
+        cache.access(0x0000, AccessKind::Read);
+        cache.access(0x0400, AccessKind::Read);
+    }
+
+    assert_eq!(cache.statistics.hits, 4);
+    assert_eq!(cache.statistics.misses, 2);
+    assert_eq!(cache.statistics.evictions, 0);
+    assert_eq!(cache.statistics.hit_rate(), 4.0 / 6.0);
+}
+
+#[test]
+fn lru_and_fifo_replacement() {
+    // A single set of two blocks.
+    let mut lru = cache(32, 2, ReplacementPolicy::Lru);
+    let mut fifo = cache(32, 2, ReplacementPolicy::Fifo);
+
+    for cache in [&mut lru, &mut fifo] {
+        cache.access(0x00, AccessKind::Read);
+        cache.access(0x10, AccessKind::Read);
+        cache.access(0x00, AccessKind::Read);
+    }
+
+    // LRU evicts the block at 0x10, which was used least recently. FIFO
+    // evicts the block at 0x00, which was brought in first.
+    assert_eq!(lru.access(0x20, AccessKind::Read).evicted, Some(0x10));
+    assert_eq!(fifo.access(0x20, AccessKind::Read).evicted, Some(0x00));
+}
+
+#[test]
+fn random_replacement_is_repeatable() {
+    let mut first = cache(64, 4, ReplacementPolicy::Random);
+    let mut second = cache(64, 4, ReplacementPolicy::Random);
+
+    for address in (0..64).map(|block| block * 16) {
+        first.access(address, AccessKind::Read);
+        second.access(address, AccessKind::Read);
+    }
+
+    assert_eq!(first.log, second.log);
+    assert_eq!(first.statistics.evictions, 60);
+}
+
+#[test]
+fn write_back_counts_dirty_evictions() {
+    let mut cache = cache(1024, 1, ReplacementPolicy::Lru);
+
+    cache.access(0x0000, AccessKind::Write);
+    cache.access(0x0004, AccessKind::Write);
+    assert!(cache.sets()[0][0].dirty);
+
+    cache.access(0x0400, AccessKind::Read);
+    assert_eq!(cache.statistics.write_backs, 1);
+    assert!(!cache.sets()[0][0].dirty);
+}
+
+#[test]
+fn write_through_never_writes_back() {
+    let mut cache = Cache::new(CacheConfig {
+        write_policy: WritePolicy::WriteThrough,
+        ..Default::default()
+    })
+    .unwrap();
+
+    cache.access(0x0000, AccessKind::Write);
+    cache.access(0x0400, AccessKind::Write);
+
+    assert!(!cache.sets()[0][0].dirty);
+    assert_eq!(cache.statistics.evictions, 1);
+    assert_eq!(cache.statistics.write_backs, 0);
+}
+
+#[test]
+fn no_write_allocate() {
+    let mut cache = Cache::new(CacheConfig {
+        write_policy: WritePolicy::WriteThrough,
+        write_miss_policy: WriteMissPolicy::NoWriteAllocate,
+        ..Default::default()
+    })
+    .unwrap();
+
+    assert!(!cache.access(0x0100, AccessKind::Write).hit);
+    assert!(!cache.access(0x0100, AccessKind::Read).hit);
+    assert!(cache.access(0x0100, AccessKind::Write).hit);
+
+    assert_eq!(cache.statistics.writes, 2);
+    assert_eq!(cache.statistics.reads, 1);
+}
+
+#[test]
+fn log_keeps_most_recent_accesses() {
+    let mut cache = cache(1024, 1, ReplacementPolicy::Lru);
+
+    for address in 0..(LOG_CAPACITY as u64 + 10) {
+        cache.access(address * 4, AccessKind::Read);
+    }
+
+    assert_eq!(cache.log.len(), LOG_CAPACITY);
+    assert_eq!(cache.log.front().unwrap().address, 40);
+}
+
+#[test]
+fn reset_clears_contents_and_statistics() {
+    let mut cache = cache(1024, 1, ReplacementPolicy::Lru);
+    cache.access(0x0000, AccessKind::Read);
+
+    cache.reset();
+
+    assert_eq!(cache.statistics, CacheStatistics::default());
+    assert!(cache.log.is_empty());
+    assert!(!cache.access(0x0000, AccessKind::Read).hit);
+}
+
+#[test]
+fn split_and_unified_hierarchies() {
+    let mut split = CacheHierarchy::default();
+    split.access(0x0000, AccessKind::InstructionFetch);
+    split.access(0x0000, AccessKind::Read);
+
+    // The instruction and data caches are separate, so both accesses miss.
+    match &split {
+        CacheHierarchy::Split {
+            instruction: Some(instruction),
+            data: Some(data),
+        } => {
+            assert_eq!(instruction.statistics.misses, 1);
+            assert_eq!(data.statistics.misses, 1);
+        }
+        _ => panic!("expected split instruction and data caches"),
+    }
+
+    let mut unified = CacheHierarchy::Unified(Cache::new(CacheConfig::default()).unwrap());
+    unified.access(0x0000, AccessKind::InstructionFetch);
+    assert!(unified.access(0x0000, AccessKind::Read).unwrap().hit);
+
+    // Accesses with no matching cache are not recorded.
+    let mut data_only = CacheHierarchy::Split {
+        instruction: None,
+        data: Some(Cache::new(CacheConfig::default()).unwrap()),
+    };
+    assert_eq!(data_only.access(0x0000, AccessKind::InstructionFetch), None);
+}
+
+#[test]
+fn hierarchy_from_config() {
+    let config = CacheConfig {
+        size: 256,
+        associativity: 2,
+        write_policy: WritePolicy::WriteThrough,
+        ..Default::default()
+    };
+
+    match CacheHierarchy::new(config.clone(), false).unwrap() {
+        CacheHierarchy::Split {
+            instruction: Some(instruction),
+            data: Some(data),
+        } => {
+            assert_eq!(instruction.config(), &config);
+            assert_eq!(data.config(), &config);
+        }
+        _ => panic!("expected split instruction and data caches"),
+    }
+
+    match CacheHierarchy::new(config.clone(), true).unwrap() {
+        CacheHierarchy::Unified(cache) => assert_eq!(cache.config(), &config),
+        _ => panic!("expected a unified cache"),
+    }
+
+    // The configuration is checked the same way as for a single cache.
+    let invalid = CacheConfig {
+        block_size: 12,
+        ..Default::default()
+    };
+    assert_eq!(
+        CacheHierarchy::new(invalid.clone(), true).err(),
+        Cache::new(invalid).err()
+    );
+}
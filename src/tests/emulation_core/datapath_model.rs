@@ -0,0 +1,188 @@
+use crate::emulation_core::datapath::Datapath;
+use crate::emulation_core::mips::cache::CacheHierarchy;
+use crate::emulation_core::mips::datapath_model::{
+    DatapathKind, MipsDatapathModel, SelectedDatapath,
+};
+use crate::emulation_core::mips::debugger::{Debugger, StopReason};
+use crate::emulation_core::mips::history::ExecutionHistory;
+use crate::emulation_core::mips::memory::{Endianness, MemoryLayout};
+use crate::emulation_core::mips::registers::GpRegisterType;
+use crate::parser::parser_assembler_main::parser_for_memory;
+
+const PROGRAM: &str = r#"ori $t0, $zero, 5
+daddu $t1, $t0, $t0
+sw $t1, 256($zero)
+lw $t2, 256($zero)
+daddu $t3, $t2, $t0"#;
+
+/// Create a datapath of the given model with `program` loaded, the way the
+/// interface does.
+fn load_program(kind: DatapathKind, program: &str) -> Result<SelectedDatapath, String> {
+    let mut datapath = SelectedDatapath::default();
+    datapath.set_kind(kind);
+
+    let (program_info, assembled) = parser_for_memory(
+        program.to_string(),
+        MemoryLayout::Flat,
+        Endianness::Big,
+        false,
+    );
+    let (text, data) = assembled.split_at(program_info.instructions.len());
+    datapath.initialize_program(text.to_vec(), data.to_vec())?;
+
+    Ok(datapath)
+}
+
+pub mod selection {
+    use super::*;
+
+    #[test]
+    fn set_kind_keeps_settings() {
+        let mut datapath = SelectedDatapath::default();
+        assert_eq!(datapath.kind(), DatapathKind::SingleCycle);
+
+        datapath.mips_datapath_mut().cache = Some(CacheHierarchy::default());
+        datapath.set_memory_layout(MemoryLayout::Segmented);
+
+        datapath.set_kind(DatapathKind::Pipelined);
+        assert_eq!(datapath.kind(), DatapathKind::Pipelined);
+        assert!(datapath.mips_datapath().cache.is_some());
+        assert_eq!(
+            datapath.mips_datapath().memory.layout,
+            MemoryLayout::Segmented
+        );
+
+        datapath.set_kind(DatapathKind::MultiCycle);
+        assert_eq!(datapath.kind(), DatapathKind::MultiCycle);
+        assert!(datapath.mips_datapath().cache.is_some());
+    }
+
+    #[test]
+    fn set_kind_resets_the_datapath() -> Result<(), String> {
+        let mut datapath = load_program(DatapathKind::SingleCycle, PROGRAM)?;
+        datapath.execute_instruction();
+
+        datapath.set_kind(DatapathKind::MultiCycle);
+
+        assert!(datapath.is_halted());
+        assert_eq!(datapath.get_register_by_enum(GpRegisterType::T0), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn every_model_runs_the_program() -> Result<(), String> {
+        for kind in [
+            DatapathKind::SingleCycle,
+            DatapathKind::MultiCycle,
+            DatapathKind::Pipelined,
+        ] {
+            let mut datapath = load_program(kind, PROGRAM)?;
+
+            while !datapath.is_halted() {
+                datapath.execute_instruction();
+            }
+
+            assert_eq!(datapath.get_register_by_enum(GpRegisterType::T3), 15);
+        }
+
+        Ok(())
+    }
+    #[test]
+    fn cycles_are_measured_by_the_clocked_models() -> Result<(), String> {
+        let mut datapath = load_program(DatapathKind::SingleCycle, PROGRAM)?;
+        datapath.execute_instruction();
+        assert_eq!(datapath.cycles(), None);
+
+        for kind in [DatapathKind::MultiCycle, DatapathKind::Pipelined] {
+            let mut datapath = load_program(kind, PROGRAM)?;
+            datapath.execute_stage();
+            datapath.execute_stage();
+            assert_eq!(datapath.cycles(), Some(2), "{kind:?}");
+        }
+
+        Ok(())
+    }
+}
+
+pub mod addresses {
+    use super::*;
+
+    #[test]
+    fn next_instruction_address() -> Result<(), String> {
+        let mut datapath = load_program(DatapathKind::MultiCycle, PROGRAM)?;
+        assert_eq!(datapath.next_instruction_address(), 0);
+
+        // The PC has moved on to the next instruction, but the first has
+        // not finished.
+        datapath.execute_stage();
+        assert_eq!(datapath.mips_datapath().registers.pc, 4);
+        assert_eq!(datapath.next_instruction_address(), 0);
+
+        datapath.execute_instruction();
+        assert_eq!(datapath.next_instruction_address(), 4);
+
+        // The oldest instruction in the pipeline finishes next.
+        let mut datapath = load_program(DatapathKind::Pipelined, PROGRAM)?;
+        for _ in 0..3 {
+            datapath.execute_stage();
+        }
+        assert_eq!(datapath.next_instruction_address(), 0);
+
+        datapath.execute_instruction();
+        datapath.execute_instruction();
+        assert_eq!(datapath.next_instruction_address(), 8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn next_decode_address() -> Result<(), String> {
+        for kind in [
+            DatapathKind::SingleCycle,
+            DatapathKind::MultiCycle,
+            DatapathKind::Pipelined,
+        ] {
+            let mut datapath = load_program(kind, PROGRAM)?;
+            assert_eq!(datapath.next_decode_address(), None);
+
+            datapath.execute_stage();
+            assert_eq!(datapath.next_decode_address(), Some(0));
+        }
+
+        Ok(())
+    }
+}
+
+pub mod debugging {
+    use super::*;
+
+    #[test]
+    fn run_to_breakpoint_and_step_back_on_every_model() -> Result<(), String> {
+        for kind in [
+            DatapathKind::SingleCycle,
+            DatapathKind::MultiCycle,
+            DatapathKind::Pipelined,
+        ] {
+            let mut datapath = load_program(kind, PROGRAM)?;
+            let mut history = ExecutionHistory::default();
+            let mut debugger = Debugger::default();
+            debugger.breakpoints.insert(16);
+
+            let reason = debugger.run(&mut datapath, &mut history, 100);
+
+            assert_eq!(reason, StopReason::Breakpoint(16));
+            assert_eq!(datapath.next_fetch_address(), Some(16));
+
+            // Running again continues past the breakpoint.
+            let reason = debugger.run(&mut datapath, &mut history, 100);
+            assert_eq!(reason, StopReason::Halted, "{kind:?}");
+            assert_eq!(datapath.get_register_by_enum(GpRegisterType::T3), 15);
+
+            history.step_back_instruction(&mut datapath);
+            assert!(!datapath.is_halted());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,421 @@
+use crate::emulation_core::mips::datapath::MipsDatapath;
+use crate::emulation_core::mips::debugger::*;
+use crate::emulation_core::mips::history::ExecutionHistory;
+use crate::emulation_core::mips::multi_cycle_datapath::MipsMultiCycleDatapath;
+use crate::emulation_core::mips::pipelined_datapath::MipsPipelinedDatapath;
+use crate::emulation_core::mips::registers::GpRegisterType;
+use crate::parser::parser_assembler_main::parser;
+
+/// Stores the numbers from 1 to 4 to consecutive words starting at 256,
+/// then loads the first of them back.
+const STORE_LOOP: &str = r#"ori $t0, $zero, 256
+ori $t1, $zero, 0
+ori $t2, $zero, 4
+loop: daddiu $t1, $t1, 1
+sw $t1, 0($t0)
+daddiu $t0, $t0, 4
+bne $t1, $t2, loop
+lw $t3, 256($zero)
+ori $v0, $zero, 10
+syscall"#;
+
+/// Create a datapath with the given program loaded, along with the line of
+/// each instruction.
+fn load_program(program: &str) -> Result<(MipsDatapath, Vec<usize>), String> {
+    let mut datapath = MipsDatapath::default();
+
+    let (program_info, instruction_bits) = parser(program.to_string());
+    datapath.initialize(instruction_bits)?;
+
+    Ok((datapath, program_info.address_to_line_number))
+}
+
+#[test]
+fn run_until_halted() -> Result<(), String> {
+    let (mut datapath, _) = load_program(STORE_LOOP)?;
+    let debugger = Debugger::default();
+    let mut history = ExecutionHistory::default();
+
+    let reason = debugger.run(&mut datapath, &mut history, DEFAULT_INSTRUCTION_BUDGET);
+
+    assert_eq!(reason, StopReason::Halted);
+    assert_eq!(datapath.registers[GpRegisterType::T3], 1);
+
+    Ok(())
+}
+
+#[test]
+fn run_until_instruction_budget() -> Result<(), String> {
+    let (mut datapath, _) = load_program(STORE_LOOP)?;
+    let debugger = Debugger::default();
+    let mut history = ExecutionHistory::default();
+
+    let reason = debugger.run(&mut datapath, &mut history, 3);
+
+    assert_eq!(reason, StopReason::InstructionBudget);
+    assert_eq!(datapath.registers.pc, 12);
+
+    // Each stage run can be stepped back through.
+    assert_eq!(history.len(), 15);
+
+    Ok(())
+}
+
+#[test]
+fn breakpoint_by_line() -> Result<(), String> {
+    let (mut datapath, lines) = load_program(STORE_LOOP)?;
+    let mut debugger = Debugger::default();
+    let mut history = ExecutionHistory::default();
+
+    // Line 5 is `sw $t1, 0($t0)`.
+    assert_eq!(
+        debugger.toggle_breakpoint_at_line(4, &lines, 0),
+        Ok((16, true))
+    );
+    assert_eq!(debugger.breakpoint_lines(&lines, 0), vec![4]);
+
+    let reason = debugger.run(&mut datapath, &mut history, DEFAULT_INSTRUCTION_BUDGET);
+    assert_eq!(reason, StopReason::Breakpoint(16));
+    assert_eq!(datapath.registers[GpRegisterType::T1], 1);
+
+    // Running again continues past the breakpoint, to the next time around
+    // the loop.
+    let reason = debugger.run(&mut datapath, &mut history, DEFAULT_INSTRUCTION_BUDGET);
+    assert_eq!(reason, StopReason::Breakpoint(16));
+    assert_eq!(datapath.registers[GpRegisterType::T1], 2);
+
+    // Toggling the line again removes the breakpoint.
+    assert_eq!(
+        debugger.toggle_breakpoint_at_line(4, &lines, 0),
+        Ok((16, false))
+    );
+    let reason = debugger.run(&mut datapath, &mut history, DEFAULT_INSTRUCTION_BUDGET);
+    assert_eq!(reason, StopReason::Halted);
+
+    Ok(())
+}
+
+#[test]
+fn breakpoint_on_line_without_instruction() -> Result<(), String> {
+    let (_, lines) = load_program("ori $t0, $zero, 1\n\nori $t1, $zero, 2")?;
+    let mut debugger = Debugger::default();
+
+    assert!(debugger.toggle_breakpoint_at_line(1, &lines, 0).is_err());
+    assert!(debugger.breakpoints.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn register_watchpoint() -> Result<(), String> {
+    let (mut datapath, _) = load_program(STORE_LOOP)?;
+    let mut debugger = Debugger::default();
+    let mut history = ExecutionHistory::default();
+    debugger
+        .watchpoints
+        .push(Watchpoint::Register(GpRegisterType::T2));
+
+    let reason = debugger.run(&mut datapath, &mut history, DEFAULT_INSTRUCTION_BUDGET);
+
+    assert_eq!(
+        reason,
+        StopReason::Watchpoint(Watchpoint::Register(GpRegisterType::T2))
+    );
+    assert_eq!(datapath.registers.pc, 12);
+
+    Ok(())
+}
+
+#[test]
+fn memory_write_watchpoint() -> Result<(), String> {
+    let (mut datapath, _) = load_program(STORE_LOOP)?;
+    let mut debugger = Debugger::default();
+    let mut history = ExecutionHistory::default();
+    let watchpoint = Watchpoint::Memory {
+        address: 264,
+        length: 4,
+        kind: WatchKind::Write,
+    };
+    debugger.watchpoints.push(watchpoint.clone());
+
+    let reason = debugger.run(&mut datapath, &mut history, DEFAULT_INSTRUCTION_BUDGET);
+
+    // The third store writes to address 264.
+    assert_eq!(reason, StopReason::Watchpoint(watchpoint));
+    assert_eq!(datapath.memory.load_word(264)?, 3);
+    assert_eq!(datapath.registers.pc, 20);
+
+    Ok(())
+}
+
+#[test]
+fn memory_read_watchpoint() -> Result<(), String> {
+    let (mut datapath, _) = load_program(STORE_LOOP)?;
+    let mut debugger = Debugger::default();
+    let mut history = ExecutionHistory::default();
+    debugger.watchpoints.push(Watchpoint::Memory {
+        address: 256,
+        length: 1,
+        kind: WatchKind::Read,
+    });
+
+    let reason = debugger.run(&mut datapath, &mut history, DEFAULT_INSTRUCTION_BUDGET);
+
+    // The store to address 256 does not stop the run, but the load does.
+    assert!(matches!(reason, StopReason::Watchpoint(_)));
+    assert_eq!(datapath.registers[GpRegisterType::T3], 1);
+    assert_eq!(datapath.registers.pc, 32);
+
+    Ok(())
+}
+
+#[test]
+fn stop_reason_messages() {
+    assert_eq!(
+        StopReason::Breakpoint(16).to_string(),
+        "Stopped at breakpoint at address 0x10"
+    );
+    assert_eq!(
+        StopReason::Watchpoint(Watchpoint::Register(GpRegisterType::T2)).to_string(),
+        "Stopped at watchpoint on register $t2"
+    );
+    assert_eq!(
+        StopReason::Watchpoint(Watchpoint::Memory {
+            address: 256,
+            length: 8,
+            kind: WatchKind::Access,
+        })
+        .to_string(),
+        "Stopped at watchpoint on memory 0x100 to 0x107"
+    );
+}
+
+#[test]
+fn step_by_stage_stops_on_watchpoint() -> Result<(), String> {
+    let (mut datapath, _) = load_program(STORE_LOOP)?;
+    let mut debugger = Debugger::default();
+    let mut history = ExecutionHistory::default();
+    debugger
+        .watchpoints
+        .push(Watchpoint::Register(GpRegisterType::T0));
+
+    // The first instruction writes to `$t0` in its fifth stage.
+    for _ in 0..4 {
+        let reason = debugger.step(&mut datapath, &mut history, StepUnit::Stage);
+        assert_eq!(reason, None);
+    }
+
+    let reason = debugger.step(&mut datapath, &mut history, StepUnit::Stage);
+    assert_eq!(
+        reason,
+        Some(StopReason::Watchpoint(Watchpoint::Register(
+            GpRegisterType::T0
+        )))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn check_breakpoint_before_stepping() -> Result<(), String> {
+    let (mut datapath, _) = load_program(STORE_LOOP)?;
+    let mut debugger = Debugger::default();
+    let mut history = ExecutionHistory::default();
+    debugger.breakpoints.insert(4);
+
+    assert_eq!(debugger.check_breakpoint(&datapath), None);
+    debugger.step(&mut datapath, &mut history, StepUnit::Instruction);
+    assert_eq!(
+        debugger.check_breakpoint(&datapath),
+        Some(StopReason::Breakpoint(4))
+    );
+
+    // Breakpoints only apply before the instruction is fetched.
+    debugger.step(&mut datapath, &mut history, StepUnit::Stage);
+    assert_eq!(debugger.check_breakpoint(&datapath), None);
+
+    Ok(())
+}
+
+#[test]
+fn run_until_halted_by_error() -> Result<(), String> {
+    // `read_int` with no input available.
+    let (mut datapath, _) = load_program("ori $v0, $zero, 5\nsyscall")?;
+    let debugger = Debugger::default();
+    let mut history = ExecutionHistory::default();
+
+    let reason = debugger.run(&mut datapath, &mut history, DEFAULT_INSTRUCTION_BUDGET);
+
+    assert_eq!(
+        reason,
+        StopReason::Error(String::from("No integer was available for `read_int`"))
+    );
+    assert_eq!(
+        reason.to_string(),
+        "Stopped by an error. No integer was available for `read_int`"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn breakpoint_in_pipelined_datapath() -> Result<(), String> {
+    let mut datapath = MipsPipelinedDatapath::default();
+    let (_, instruction_bits) = parser(STORE_LOOP.to_string());
+    datapath.initialize(instruction_bits)?;
+    let mut debugger = Debugger::default();
+    let mut history = ExecutionHistory::default();
+    debugger.breakpoints.insert(16);
+
+    // The pipeline stops before `sw $t1, 0($t0)` is fetched, while the
+    // instructions before it are still in flight.
+    let reason = debugger.run(&mut datapath, &mut history, DEFAULT_INSTRUCTION_BUDGET);
+    assert_eq!(reason, StopReason::Breakpoint(16));
+    assert_eq!(datapath.datapath.registers.pc, 16);
+    assert!(datapath.if_id.is_some());
+
+    // The instructions fetched after the loop's branch are flushed, so the
+    // next stop is the next time around the loop.
+    let reason = debugger.run(&mut datapath, &mut history, DEFAULT_INSTRUCTION_BUDGET);
+    assert_eq!(reason, StopReason::Breakpoint(16));
+    assert_eq!(datapath.datapath.memory.load_word(256)?, 1);
+
+    debugger.breakpoints.clear();
+    let reason = debugger.run(&mut datapath, &mut history, DEFAULT_INSTRUCTION_BUDGET);
+    assert_eq!(reason, StopReason::Halted);
+    assert_eq!(datapath.datapath.registers[GpRegisterType::T3], 1);
+
+    Ok(())
+}
+
+#[test]
+fn register_watchpoint_in_pipelined_datapath() -> Result<(), String> {
+    let mut datapath = MipsPipelinedDatapath::default();
+    let (_, instruction_bits) = parser(STORE_LOOP.to_string());
+    datapath.initialize(instruction_bits)?;
+    let mut debugger = Debugger::default();
+    let mut history = ExecutionHistory::default();
+    debugger
+        .watchpoints
+        .push(Watchpoint::Register(GpRegisterType::T2));
+
+    let reason = debugger.run(&mut datapath, &mut history, DEFAULT_INSTRUCTION_BUDGET);
+
+    // `ori $t2, $zero, 4` writes to `$t2` as it leaves WB.
+    assert_eq!(
+        reason,
+        StopReason::Watchpoint(Watchpoint::Register(GpRegisterType::T2))
+    );
+    assert_eq!(datapath.datapath.registers[GpRegisterType::T2], 4);
+    assert!(datapath.retired);
+
+    Ok(())
+}
+
+#[test]
+fn memory_watchpoints_in_multi_cycle_datapath() -> Result<(), String> {
+    let mut datapath = MipsMultiCycleDatapath::default();
+    let (_, instruction_bits) = parser(STORE_LOOP.to_string());
+    datapath.initialize(instruction_bits)?;
+    let mut debugger = Debugger::default();
+    let mut history = ExecutionHistory::default();
+    let write_watchpoint = Watchpoint::Memory {
+        address: 264,
+        length: 4,
+        kind: WatchKind::Write,
+    };
+    debugger.watchpoints.push(write_watchpoint.clone());
+
+    // The third store writes to address 264 in the memory write state, and
+    // the run stops once it has finished.
+    let reason = debugger.run(&mut datapath, &mut history, DEFAULT_INSTRUCTION_BUDGET);
+    assert_eq!(reason, StopReason::Watchpoint(write_watchpoint));
+    assert_eq!(datapath.datapath.memory.load_word(264)?, 3);
+    assert_eq!(datapath.datapath.registers.pc, 20);
+
+    debugger.watchpoints = vec![Watchpoint::Memory {
+        address: 256,
+        length: 1,
+        kind: WatchKind::Read,
+    }];
+    let reason = debugger.run(&mut datapath, &mut history, DEFAULT_INSTRUCTION_BUDGET);
+    assert!(matches!(reason, StopReason::Watchpoint(_)));
+    assert_eq!(datapath.datapath.registers[GpRegisterType::T3], 1);
+
+    Ok(())
+}
+
+#[test]
+fn add_watchpoint_ignores_duplicates() {
+    let mut debugger = Debugger::default();
+
+    assert!(debugger.add_watchpoint(Watchpoint::Register(GpRegisterType::T0)));
+    assert!(!debugger.add_watchpoint(Watchpoint::Register(GpRegisterType::T0)));
+    assert_eq!(debugger.watchpoints.len(), 1);
+}
+
+#[test]
+fn parse_watchpoints() {
+    assert_eq!(
+        Watchpoint::parse("$t0", WatchKind::Write),
+        Ok(Watchpoint::Register(GpRegisterType::T0))
+    );
+    assert_eq!(
+        Watchpoint::parse(" SP ", WatchKind::Read),
+        Ok(Watchpoint::Register(GpRegisterType::Sp))
+    );
+    assert_eq!(
+        Watchpoint::parse("0x100", WatchKind::Read),
+        Ok(Watchpoint::Memory {
+            address: 0x100,
+            length: 4,
+            kind: WatchKind::Read,
+        })
+    );
+    assert_eq!(
+        Watchpoint::parse("256 0x10", WatchKind::Access),
+        Ok(Watchpoint::Memory {
+            address: 256,
+            length: 16,
+            kind: WatchKind::Access,
+        })
+    );
+
+    assert!(Watchpoint::parse("$t10", WatchKind::Write).is_err());
+    assert!(Watchpoint::parse("0x100 0", WatchKind::Write).is_err());
+    assert!(Watchpoint::parse("0x100 4 4", WatchKind::Write).is_err());
+    assert!(Watchpoint::parse("", WatchKind::Write).is_err());
+}
+
+#[test]
+fn parse_watchpoint_length_limit() {
+    assert_eq!(
+        Watchpoint::parse("0 4096", WatchKind::Write),
+        Ok(Watchpoint::Memory {
+            address: 0,
+            length: MAX_WATCHPOINT_LENGTH,
+            kind: WatchKind::Write,
+        })
+    );
+
+    assert!(Watchpoint::parse("0 4097", WatchKind::Write).is_err());
+    assert!(Watchpoint::parse("0 0xffffffffffffffff", WatchKind::Write).is_err());
+}
+
+#[test]
+fn watchpoint_descriptions() {
+    assert_eq!(
+        Watchpoint::Register(GpRegisterType::T2).to_string(),
+        "register $t2"
+    );
+    assert_eq!(
+        Watchpoint::Memory {
+            address: 256,
+            length: 4,
+            kind: WatchKind::Write,
+        }
+        .to_string(),
+        "memory 0x100 to 0x103"
+    );
+    assert_eq!(WatchKind::Access.to_string(), "access");
+}
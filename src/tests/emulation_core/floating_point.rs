@@ -0,0 +1,358 @@
+use crate::emulation_core::mips::floating_point::{
+    self, RoundingMode, CONDITION_EQUAL, CONDITION_LESS, CONDITION_NEGATED, CONDITION_SIGNALING,
+    CONDITION_UNORDERED, FLAG_DIVIDE_BY_ZERO, FLAG_INEXACT, FLAG_INVALID, FLAG_OVERFLOW,
+    FLAG_UNDERFLOW,
+};
+
+// 1 + 2^-30 lies between two single-precision values, so every rounding
+// mode picks one of them.
+#[test]
+fn add_respects_rounding_mode() {
+    let tiny = f32::powi(2.0, -30);
+    let above = f32::from_bits(1.0f32.to_bits() + 1);
+
+    assert_eq!(
+        floating_point::add(1.0f32, tiny, RoundingMode::Nearest),
+        (1.0, FLAG_INEXACT)
+    );
+    assert_eq!(
+        floating_point::add(1.0f32, tiny, RoundingMode::Zero),
+        (1.0, FLAG_INEXACT)
+    );
+    assert_eq!(
+        floating_point::add(1.0f32, tiny, RoundingMode::PositiveInfinity),
+        (above, FLAG_INEXACT)
+    );
+    assert_eq!(
+        floating_point::add(1.0f32, tiny, RoundingMode::NegativeInfinity),
+        (1.0, FLAG_INEXACT)
+    );
+    assert_eq!(
+        floating_point::add(-1.0f32, -tiny, RoundingMode::NegativeInfinity),
+        (-above, FLAG_INEXACT)
+    );
+}
+
+#[test]
+fn exact_results_are_not_inexact() {
+    assert_eq!(
+        floating_point::add(1.5f64, 2.25, RoundingMode::PositiveInfinity),
+        (3.75, 0)
+    );
+    assert_eq!(
+        floating_point::mul(1.5f64, -4.0, RoundingMode::Zero),
+        (-6.0, 0)
+    );
+    assert_eq!(
+        floating_point::sqrt(6.25f64, RoundingMode::Nearest),
+        (2.5, 0)
+    );
+}
+
+#[test]
+fn exact_zero_sum_is_negative_when_rounding_down() {
+    let (value, flags) = floating_point::add(1.0f64, -1.0, RoundingMode::NegativeInfinity);
+    assert!(value == 0.0 && value.is_sign_negative());
+    assert_eq!(flags, 0);
+
+    let (value, _) = floating_point::add(1.0f64, -1.0, RoundingMode::Nearest);
+    assert!(value == 0.0 && value.is_sign_positive());
+}
+
+// 1/3 is rounded down to the nearest value, so only rounding toward
+// positive infinity moves it.
+#[test]
+fn div_respects_rounding_mode() {
+    let nearest = 1.0f64 / 3.0;
+    let above = f64::from_bits(nearest.to_bits() + 1);
+
+    assert_eq!(
+        floating_point::div(1.0f64, 3.0, RoundingMode::Nearest),
+        (nearest, FLAG_INEXACT)
+    );
+    assert_eq!(
+        floating_point::div(1.0f64, 3.0, RoundingMode::Zero),
+        (nearest, FLAG_INEXACT)
+    );
+    assert_eq!(
+        floating_point::div(1.0f64, 3.0, RoundingMode::PositiveInfinity),
+        (above, FLAG_INEXACT)
+    );
+    assert_eq!(
+        floating_point::div(-1.0f64, 3.0, RoundingMode::NegativeInfinity),
+        (-above, FLAG_INEXACT)
+    );
+}
+
+#[test]
+fn div_by_zero_is_infinite() {
+    assert_eq!(
+        floating_point::div(-2.0f32, 0.0, RoundingMode::Nearest),
+        (f32::NEG_INFINITY, FLAG_DIVIDE_BY_ZERO)
+    );
+
+    let (value, flags) = floating_point::div(0.0f32, 0.0, RoundingMode::Nearest);
+    assert!(value.is_nan());
+    assert_eq!(flags, FLAG_INVALID);
+}
+
+#[test]
+fn sqrt_of_negative_is_invalid() {
+    let (value, flags) = floating_point::sqrt(-4.0f64, RoundingMode::Nearest);
+    assert!(value.is_nan());
+    assert_eq!(flags, FLAG_INVALID);
+}
+
+#[test]
+fn nan_operands_are_quiet() {
+    let (value, flags) = floating_point::mul(f64::NAN, 2.0, RoundingMode::Nearest);
+    assert!(value.is_nan());
+    assert_eq!(flags, 0);
+}
+
+// An overflowing result is infinity or the largest finite value, depending on
+// which way the rounding mode goes.
+#[test]
+fn overflow_respects_rounding_mode() {
+    let flags = FLAG_OVERFLOW | FLAG_INEXACT;
+
+    assert_eq!(
+        floating_point::mul(f32::MAX, 2.0, RoundingMode::Nearest),
+        (f32::INFINITY, flags)
+    );
+    assert_eq!(
+        floating_point::mul(f32::MAX, 2.0, RoundingMode::Zero),
+        (f32::MAX, flags)
+    );
+    assert_eq!(
+        floating_point::mul(f32::MAX, -2.0, RoundingMode::PositiveInfinity),
+        (f32::MIN, flags)
+    );
+    assert_eq!(
+        floating_point::mul(f32::MAX, -2.0, RoundingMode::NegativeInfinity),
+        (f32::NEG_INFINITY, flags)
+    );
+}
+
+#[test]
+fn tiny_inexact_result_underflows() {
+    let (value, flags) = floating_point::mul(f64::MIN_POSITIVE, 0.3, RoundingMode::Nearest);
+    assert!(value < f64::MIN_POSITIVE);
+    assert_eq!(flags, FLAG_UNDERFLOW | FLAG_INEXACT);
+}
+
+#[test]
+fn to_single_respects_rounding_mode() {
+    let value = 0.1f64;
+    let nearest = value as f32;
+    let other = if (nearest as f64) < value {
+        f32::from_bits(nearest.to_bits() + 1)
+    } else {
+        f32::from_bits(nearest.to_bits() - 1)
+    };
+    let (below, above) = if nearest < other {
+        (nearest, other)
+    } else {
+        (other, nearest)
+    };
+
+    assert_eq!(
+        floating_point::to_single(value, RoundingMode::Nearest),
+        (nearest, FLAG_INEXACT)
+    );
+    assert_eq!(
+        floating_point::to_single(value, RoundingMode::Zero),
+        (below, FLAG_INEXACT)
+    );
+    assert_eq!(
+        floating_point::to_single(value, RoundingMode::PositiveInfinity),
+        (above, FLAG_INEXACT)
+    );
+    assert_eq!(
+        floating_point::to_single(1e300, RoundingMode::Zero),
+        (f32::MAX, FLAG_OVERFLOW | FLAG_INEXACT)
+    );
+}
+
+#[test]
+fn from_integer_respects_rounding_mode() {
+    // 2^24 + 1 is the first integer a single cannot hold.
+    let value = (1 << 24) + 1;
+
+    assert_eq!(
+        floating_point::from_integer::<f32>(value, RoundingMode::Nearest),
+        (16777216.0, FLAG_INEXACT)
+    );
+    assert_eq!(
+        floating_point::from_integer::<f32>(value, RoundingMode::PositiveInfinity),
+        (16777218.0, FLAG_INEXACT)
+    );
+    assert_eq!(
+        floating_point::from_integer::<f64>(value, RoundingMode::PositiveInfinity),
+        (16777217.0, 0)
+    );
+}
+
+#[test]
+fn to_integer_respects_rounding_mode() {
+    let (min, max) = (i32::MIN as i64, i32::MAX as i64);
+
+    assert_eq!(
+        floating_point::to_integer(-2.5, RoundingMode::Nearest, min, max),
+        (-2, FLAG_INEXACT)
+    );
+    assert_eq!(
+        floating_point::to_integer(-2.5, RoundingMode::Zero, min, max),
+        (-2, FLAG_INEXACT)
+    );
+    assert_eq!(
+        floating_point::to_integer(-2.5, RoundingMode::PositiveInfinity, min, max),
+        (-2, FLAG_INEXACT)
+    );
+    assert_eq!(
+        floating_point::to_integer(-2.5, RoundingMode::NegativeInfinity, min, max),
+        (-3, FLAG_INEXACT)
+    );
+    assert_eq!(
+        floating_point::to_integer(7.0, RoundingMode::Nearest, min, max),
+        (7, 0)
+    );
+}
+
+#[test]
+fn to_integer_out_of_range_is_invalid() {
+    let (min, max) = (i32::MIN as i64, i32::MAX as i64);
+
+    assert_eq!(
+        floating_point::to_integer(3e9, RoundingMode::Nearest, min, max),
+        (max, FLAG_INVALID)
+    );
+    assert_eq!(
+        floating_point::to_integer(-3e9, RoundingMode::Nearest, min, max),
+        (min, FLAG_INVALID)
+    );
+    assert_eq!(
+        floating_point::to_integer(f64::NAN, RoundingMode::Nearest, min, max),
+        (0, FLAG_INVALID)
+    );
+}
+
+#[test]
+fn rounding_mode_from_bits() {
+    assert_eq!(RoundingMode::from_bits(0), RoundingMode::Nearest);
+    assert_eq!(RoundingMode::from_bits(1), RoundingMode::Zero);
+    assert_eq!(RoundingMode::from_bits(2), RoundingMode::PositiveInfinity);
+    assert_eq!(
+        RoundingMode::from_bits(0b111),
+        RoundingMode::NegativeInfinity
+    );
+    assert_eq!(RoundingMode::NegativeInfinity.to_string(), "RM");
+}
+
+/// The least single-precision value greater than `value`.
+fn next_up(value: f32) -> f32 {
+    if value == 0.0 {
+        f32::from_bits(1)
+    } else if value > 0.0 {
+        f32::from_bits(value.to_bits() + 1)
+    } else {
+        f32::from_bits(value.to_bits() - 1)
+    }
+}
+
+// A single-precision product is exact in double precision, so the results of
+// rounding down and up can be checked to be the neighbours of the exact value,
+// including for results in the subnormal range.
+#[test]
+fn mul_rounds_to_neighbours_of_exact_product() {
+    let values = [
+        1.1f32, -3.7, 0.3, 1e-20, -2.5e-25, 7.1e-30, 1e20, 3.0, 1e-40, -5e-42,
+    ];
+
+    for &a in &values {
+        for &b in &values {
+            let exact = a as f64 * b as f64;
+            let (down, _) = floating_point::mul(a, b, RoundingMode::NegativeInfinity);
+            let (up, flags) = floating_point::mul(a, b, RoundingMode::PositiveInfinity);
+
+            assert!(down as f64 <= exact && exact <= up as f64, "{a} * {b}");
+            if down == up {
+                assert_eq!(flags & FLAG_INEXACT, 0, "{a} * {b}");
+            } else {
+                assert_eq!(next_up(down), up, "{a} * {b}");
+                assert_ne!(flags & FLAG_INEXACT, 0, "{a} * {b}");
+            }
+        }
+    }
+}
+
+#[test]
+fn small_results_underflow() {
+    assert_eq!(
+        floating_point::mul(1e-160f64, 1e-160, RoundingMode::Nearest).1,
+        FLAG_UNDERFLOW | FLAG_INEXACT
+    );
+    assert_eq!(
+        floating_point::div(1e-300f64, 3e20, RoundingMode::Nearest).1,
+        FLAG_UNDERFLOW | FLAG_INEXACT
+    );
+}
+
+#[test]
+fn compare_evaluates_condition() {
+    let less_or_equal = CONDITION_LESS | CONDITION_EQUAL;
+    assert_eq!(
+        floating_point::compare(1.0f64, 2.0, less_or_equal),
+        (true, 0)
+    );
+    assert_eq!(
+        floating_point::compare(2.0f64, 2.0, less_or_equal),
+        (true, 0)
+    );
+    assert_eq!(
+        floating_point::compare(3.0f64, 2.0, less_or_equal),
+        (false, 0)
+    );
+    assert_eq!(
+        floating_point::compare(f64::NAN, 2.0, less_or_equal),
+        (false, 0)
+    );
+
+    // Not equal, but ordered.
+    let not_equal = CONDITION_NEGATED | CONDITION_UNORDERED | CONDITION_EQUAL;
+    assert_eq!(floating_point::compare(1.0f32, 2.0, not_equal), (true, 0));
+    assert_eq!(floating_point::compare(2.0f32, 2.0, not_equal), (false, 0));
+    assert_eq!(
+        floating_point::compare(f32::NAN, 2.0, not_equal),
+        (false, 0)
+    );
+}
+
+#[test]
+fn compare_signals_unordered_operands() {
+    let signaling_less = CONDITION_SIGNALING | CONDITION_LESS;
+    assert_eq!(
+        floating_point::compare(2.0f64, f64::NAN, signaling_less),
+        (false, FLAG_INVALID)
+    );
+    assert_eq!(
+        floating_point::compare(2.0f64, f64::NAN, CONDITION_LESS),
+        (false, 0)
+    );
+    assert_eq!(
+        floating_point::compare(1.0f64, 2.0, signaling_less),
+        (true, 0)
+    );
+}
+
+#[test]
+fn cmp_conditions() {
+    let conditions: Vec<u8> = (0..32)
+        .filter(|&condition| floating_point::is_cmp_condition(condition))
+        .collect();
+
+    assert_eq!(conditions.len(), 22);
+    assert!(!floating_point::is_cmp_condition(16));
+    assert!(floating_point::is_cmp_condition(27));
+    assert!(!floating_point::is_cmp_condition(28));
+}
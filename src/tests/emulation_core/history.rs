@@ -0,0 +1,202 @@
+use crate::emulation_core::datapath::Datapath;
+use crate::emulation_core::mips::datapath::{MipsDatapath, Stage};
+use crate::emulation_core::mips::history::ExecutionHistory;
+use crate::emulation_core::mips::pipelined_datapath::MipsPipelinedDatapath;
+use crate::emulation_core::mips::registers::GpRegisterType;
+use crate::emulation_core::mips::syscall::BufferedSyscallHost;
+use crate::parser::parser_assembler_main::parser;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Create a datapath with the given program loaded.
+fn load_program(program: &str) -> Result<MipsDatapath, String> {
+    let mut datapath = MipsDatapath::default();
+
+    let (_, instruction_bits) = parser(program.to_string());
+    datapath.initialize(instruction_bits)?;
+
+    Ok(datapath)
+}
+
+const STORE_PROGRAM: &str = r#"ori $t0, $zero, 300
+sw $t0, 256($zero)
+ori $t1, $zero, 1
+sw $t1, 256($zero)"#;
+
+#[test]
+fn step_back_stage_restores_previous_state() -> Result<(), String> {
+    let mut datapath = load_program(STORE_PROGRAM)?;
+    let mut history = ExecutionHistory::default();
+
+    history.execute_stage(&mut datapath);
+    history.execute_stage(&mut datapath);
+    let expected = datapath.clone();
+
+    history.execute_stage(&mut datapath);
+    assert!(datapath != expected);
+
+    assert!(history.step_back_stage(&mut datapath));
+    assert!(datapath == expected);
+    assert_eq!(history.len(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn step_back_instruction_undoes_memory_writes() -> Result<(), String> {
+    let mut datapath = load_program(STORE_PROGRAM)?;
+    let mut history = ExecutionHistory::default();
+
+    history.execute_instruction(&mut datapath);
+    history.execute_instruction(&mut datapath);
+    let expected = datapath.clone();
+
+    history.execute_instruction(&mut datapath);
+    history.execute_instruction(&mut datapath);
+    assert_eq!(datapath.memory.load_word(256)?, 1);
+
+    assert!(history.step_back_instruction(&mut datapath));
+    assert!(history.step_back_instruction(&mut datapath));
+
+    assert!(datapath == expected);
+    assert_eq!(datapath.memory.load_word(256)?, 300);
+    assert_eq!(datapath.registers[GpRegisterType::T1], 0);
+    assert_eq!(datapath.registers.pc, 8);
+
+    Ok(())
+}
+
+#[test]
+fn step_back_instruction_from_middle_of_instruction() -> Result<(), String> {
+    let mut datapath = load_program(STORE_PROGRAM)?;
+    let mut history = ExecutionHistory::default();
+
+    history.execute_instruction(&mut datapath);
+    history.execute_stage(&mut datapath);
+    history.execute_stage(&mut datapath);
+    assert_eq!(datapath.current_stage, Stage::Execute);
+
+    // This returns to the start of the partly-run instruction.
+    assert!(history.step_back_instruction(&mut datapath));
+    assert_eq!(datapath.current_stage, Stage::InstructionFetch);
+    assert_eq!(datapath.registers.pc, 4);
+    assert_eq!(history.len(), 5);
+
+    Ok(())
+}
+
+#[test]
+fn step_back_after_halting() -> Result<(), String> {
+    let mut datapath = load_program("ori $t0, $zero, 10\nsyscall")?;
+    let mut history = ExecutionHistory::default();
+
+    while !datapath.is_halted() {
+        history.execute_instruction(&mut datapath);
+    }
+
+    assert!(history.step_back_instruction(&mut datapath));
+    assert!(!datapath.is_halted());
+    assert_eq!(datapath.registers.pc, 4);
+
+    Ok(())
+}
+
+#[test]
+fn history_is_bounded() -> Result<(), String> {
+    let mut datapath = load_program(STORE_PROGRAM)?;
+    let mut history = ExecutionHistory::new(7);
+
+    for _ in 0..3 {
+        history.execute_instruction(&mut datapath);
+    }
+    assert_eq!(history.len(), 7);
+
+    // Only the last instruction and the last two stages of the one before it
+    // are kept.
+    assert!(history.step_back_instruction(&mut datapath));
+    assert_eq!(datapath.registers.pc, 8);
+    assert!(history.step_back_instruction(&mut datapath));
+    assert_eq!(datapath.current_stage, Stage::Memory);
+    assert!(!history.step_back_instruction(&mut datapath));
+
+    Ok(())
+}
+
+#[test]
+fn empty_history_still_executes() -> Result<(), String> {
+    let mut datapath = load_program(STORE_PROGRAM)?;
+    let mut history = ExecutionHistory::new(0);
+
+    history.execute_instruction(&mut datapath);
+
+    assert_eq!(datapath.registers[GpRegisterType::T0], 300);
+    assert!(history.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn nothing_to_step_back() -> Result<(), String> {
+    let mut datapath = load_program(STORE_PROGRAM)?;
+    let mut history = ExecutionHistory::default();
+    let expected = datapath.clone();
+
+    assert!(!history.step_back_stage(&mut datapath));
+    assert!(!history.step_back_instruction(&mut datapath));
+    assert!(datapath == expected);
+
+    Ok(())
+}
+
+#[test]
+fn step_back_pipelined_datapath() -> Result<(), String> {
+    let mut datapath = MipsPipelinedDatapath::default();
+    let (_, instruction_bits) = parser(STORE_PROGRAM.to_string());
+    datapath.initialize(instruction_bits)?;
+    let mut history = ExecutionHistory::default();
+
+    history.execute_instruction(&mut datapath);
+    history.execute_instruction(&mut datapath);
+    let expected = datapath.clone();
+
+    history.execute_instruction(&mut datapath);
+    history.execute_instruction(&mut datapath);
+    assert_eq!(datapath.datapath.memory.load_word(256)?, 1);
+
+    // Each instruction leaves WB one clock cycle after the one before it.
+    assert!(history.step_back_instruction(&mut datapath));
+    assert!(history.step_back_instruction(&mut datapath));
+
+    assert!(datapath == expected);
+    assert_eq!(datapath.cycles, 6);
+    assert_eq!(datapath.datapath.memory.load_word(256)?, 300);
+    assert_eq!(datapath.datapath.registers[GpRegisterType::T1], 0);
+
+    Ok(())
+}
+
+#[test]
+fn step_back_read_string_in_pipelined_datapath() -> Result<(), String> {
+    let mut datapath = MipsPipelinedDatapath::default();
+    let host = Rc::new(RefCell::new(BufferedSyscallHost::default()));
+    host.borrow_mut().input.push_back(String::from("abc"));
+    datapath.datapath.set_syscall_host(host);
+
+    // `$a0` is written back in the same clock cycle as `syscall` is decoded.
+    let (_, instruction_bits) = parser(String::from(
+        "ori $v0, $zero, 8\nori $a1, $zero, 16\nori $a0, $zero, 256\nsyscall",
+    ));
+    datapath.initialize(instruction_bits)?;
+    let mut history = ExecutionHistory::default();
+
+    while datapath.datapath.memory.load_byte(256)? == 0 {
+        assert!(datapath.cycles < 20);
+        history.execute_stage(&mut datapath);
+    }
+    assert_eq!(datapath.datapath.memory.load_word(256)?, 0x6162630a);
+
+    assert!(history.step_back_stage(&mut datapath));
+    assert_eq!(datapath.datapath.memory.load_word(256)?, 0);
+
+    Ok(())
+}
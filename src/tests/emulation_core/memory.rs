@@ -1,4 +1,7 @@
-use crate::emulation_core::mips::memory::{Memory, CAPACITY_BYTES};
+use crate::emulation_core::mips::memory::{
+    Endianness, Memory, MemoryLayout, CAPACITY_BYTES, DATA_ADDRESS, PAGE_SIZE, STACK_POINTER,
+    TEXT_ADDRESS,
+};
 
 // Attempt to read at an address not byte-aligned.
 #[test]
@@ -89,3 +92,201 @@ fn store_and_load_double_word() -> Result<(), String> {
         ))
     }
 }
+
+#[test]
+fn store_and_load_byte() -> Result<(), String> {
+    let mut memory = Memory::default();
+
+    // Bytes do not need to be aligned.
+    let address = 3;
+    memory.store_byte(address, 0xAB)?;
+
+    assert_eq!(memory.load_byte(address)?, 0xAB);
+    assert_eq!(memory.load_word(0)?, 0xAB);
+
+    Ok(())
+}
+
+// Stores a 16-bit halfword in memory and checks that it is big-endian.
+#[test]
+fn store_and_load_half_word() -> Result<(), String> {
+    let mut memory = Memory::default();
+
+    let address = 2;
+    memory.store_half_word(address, 0xBEEF)?;
+
+    assert_eq!(memory.load_half_word(address)?, 0xBEEF);
+    assert_eq!(memory.load_byte(2)?, 0xBE);
+    assert_eq!(memory.load_byte(3)?, 0xEF);
+
+    Ok(())
+}
+
+// Attempt to read a halfword at an address not aligned to 2 bytes.
+#[test]
+fn read_non_aligned_half_word() {
+    let memory = Memory::default();
+
+    assert!(match memory.load_half_word(1) {
+        Err(e) => e.contains("align"),
+        _ => false,
+    });
+}
+
+// Attempt to read a doubleword at an address aligned to 4 bytes, but not 8.
+#[test]
+fn read_non_aligned_double_word() {
+    let memory = Memory::default();
+
+    assert!(match memory.load_double_word(4) {
+        Err(e) => e.contains("align"),
+        _ => false,
+    });
+}
+
+// In the segmented layout, only the pages written to take up space.
+#[test]
+fn segmented_memory_allocates_pages_on_write() -> Result<(), String> {
+    let mut memory = Memory::new(MemoryLayout::Segmented);
+
+    assert!(memory.memory.is_empty());
+    assert_eq!(memory.load_word(DATA_ADDRESS)?, 0);
+    assert!(memory.pages.is_empty());
+
+    memory.store_word(DATA_ADDRESS + 4, 0x1234_5678)?;
+    memory.store_double_word(STACK_POINTER - 4, 0xDEAD_BEEF_CAFE_F00D)?;
+
+    assert_eq!(memory.pages.len(), 2);
+    assert!(memory.pages.contains_key(&DATA_ADDRESS));
+    assert!(memory
+        .pages
+        .contains_key(&(STACK_POINTER - STACK_POINTER % PAGE_SIZE)));
+    assert_eq!(memory.load_word(DATA_ADDRESS + 4)?, 0x1234_5678);
+    assert_eq!(memory.get_byte(DATA_ADDRESS + 7), Some(0x78));
+    assert_eq!(
+        memory.load_double_word(STACK_POINTER - 4)?,
+        0xDEAD_BEEF_CAFE_F00D
+    );
+
+    Ok(())
+}
+
+// A doubleword may span two pages.
+#[test]
+fn segmented_memory_access_across_pages() -> Result<(), String> {
+    let mut memory = Memory::new(MemoryLayout::Segmented);
+    let address = DATA_ADDRESS + PAGE_SIZE - 2;
+
+    memory.store_word(address - 2, 0xAABB_CCDD)?;
+    memory.store_half_word(address + 2, 0xEEFF)?;
+
+    assert_eq!(memory.pages.len(), 2);
+    assert_eq!(memory.load_word(address - 2)?, 0xAABB_CCDD);
+    assert_eq!(memory.load_byte(address + 3)?, 0xFF);
+
+    Ok(())
+}
+
+// Addresses below the text segment or above the stack are out of bounds.
+#[test]
+fn segmented_memory_out_of_bounds() {
+    let mut memory = Memory::new(MemoryLayout::Segmented);
+
+    assert!(memory.load_word(0).unwrap_err().contains("bounds"));
+    assert!(memory.store_word(TEXT_ADDRESS - 4, 0).is_err());
+    assert!(memory.store_word(0x8000_0000, 0).is_err());
+    assert_eq!(memory.get_byte(0x8000_0000), None);
+    assert!(memory.set_byte(0x3F_FFFF, 1).is_err());
+    assert!(memory.pages.is_empty());
+}
+
+// Once a program is protected, its text can be read but not written to, and
+// the rest of memory cannot be executed.
+#[test]
+fn protected_program_permissions() -> Result<(), String> {
+    let mut memory = Memory::default();
+    memory.store_word(0, 0x1234_5678)?;
+    memory.protect_program(16);
+
+    assert_eq!(memory.load_word(0)?, 0x1234_5678);
+    assert_eq!(memory.fetch_word(0)?, 0x1234_5678);
+    assert!(memory.store_word(12, 0).unwrap_err().contains("text"));
+    assert!(memory.store_byte(15, 0).is_err());
+    assert!(memory.fetch_word(16).unwrap_err().contains("executed"));
+    memory.store_word(16, 1)?;
+
+    // The loader and the execution history can still change the text.
+    memory.set_byte(0, 0xAB)?;
+    assert_eq!(memory.load_byte(0)?, 0xAB);
+
+    Ok(())
+}
+
+// Allowing self-modifying code lets the text be written to.
+#[test]
+fn self_modifying_code_allows_writing_text() -> Result<(), String> {
+    let mut memory = Memory::new(MemoryLayout::Segmented);
+    memory.protect_program(TEXT_ADDRESS + 16);
+
+    assert!(memory.store_word(TEXT_ADDRESS + 0x1000, 0).is_err());
+    assert!(memory.fetch_word(DATA_ADDRESS).is_err());
+
+    memory.self_modifying_code = true;
+    memory.store_word(TEXT_ADDRESS + 0x1000, 0x1234_5678)?;
+    assert_eq!(memory.fetch_word(TEXT_ADDRESS + 0x1000)?, 0x1234_5678);
+    assert!(memory.fetch_word(DATA_ADDRESS).is_err());
+
+    Ok(())
+}
+
+// Little-endian memory stores the least significant byte first.
+#[test]
+fn little_endian_byte_order() -> Result<(), String> {
+    let mut memory = Memory {
+        endianness: Endianness::Little,
+        ..Default::default()
+    };
+
+    memory.store_word(0, 0x1234_5678)?;
+    assert_eq!(memory.memory[0..4], [0x78, 0x56, 0x34, 0x12]);
+    assert_eq!(memory.load_word(0)?, 0x1234_5678);
+    assert_eq!(memory.load_half_word(0)?, 0x5678);
+    assert_eq!(memory.load_byte(0)?, 0x78);
+
+    memory.store_double_word(8, 0x0102_0304_0506_0708)?;
+    assert_eq!(memory.memory[8], 0x08);
+    assert_eq!(memory.memory[15], 0x01);
+    assert_eq!(memory.load_word(8)?, 0x0506_0708);
+
+    Ok(())
+}
+
+// The memory viewer shows words by value, and their bytes as text in the
+// order of their addresses.
+#[test]
+fn formatted_hex_follows_endianness() -> Result<(), String> {
+    let mut memory = Memory {
+        endianness: Endianness::Little,
+        ..Default::default()
+    };
+    memory.memory[0..4].copy_from_slice(b"abcd");
+
+    let first_row = memory.generate_formatted_hex();
+    let first_row = first_row.lines().next().unwrap();
+    assert!(first_row.starts_with("0x0000:\t\t64636261\t"));
+    assert!(first_row.ends_with("abcd............"));
+
+    memory.endianness = Endianness::Big;
+    let first_row = memory.generate_formatted_hex();
+    assert!(first_row.starts_with("0x0000:\t\t61626364\t"));
+
+    Ok(())
+}
+
+#[test]
+fn endianness_bytes_round_trip() {
+    assert_eq!(Endianness::Big.to_bytes(0x1234, 2), vec![0x12, 0x34]);
+    assert_eq!(Endianness::Little.to_bytes(0x1234, 2), vec![0x34, 0x12]);
+    assert_eq!(Endianness::Big.from_bytes(&[0x12, 0x34]), 0x1234);
+    assert_eq!(Endianness::Little.from_bytes(&[0x12, 0x34]), 0x3412);
+}
@@ -37,6 +37,7 @@ fn reset_datapath() -> Result<(), String> {
 
 pub mod add {
     use super::*;
+    use crate::emulation_core::mips::system_coprocessor::ExceptionCode;
     #[test]
     fn add_register_to_itself() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
@@ -97,10 +98,9 @@ fn add_to_register_zero() -> Result<(), String> {
     }
 
     #[test]
-    // NOTE: This test falls under our initial project design that there are no
-    // handled exceptions. Therefore, we would expect to see an updated value in
-    // register T1, rather than having the register unmodified per the MIPS64v6
-    // specification.
+    // Per the MIPS64v6 specification, register T1 is left unmodified and an
+    // integer overflow exception is raised. With no exception handler, this
+    // halts the datapath.
     fn add_32_bit_with_overflow() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
@@ -111,22 +111,23 @@ fn add_32_bit_with_overflow() -> Result<(), String> {
 
         // Assume register $t4 contains 2,454,267,026, a 32-bit integer.
         datapath.registers.gpr[12] = 0b10010010_01001001_00100100_10010010;
+        datapath.registers.gpr[9] = 123;
 
         datapath.execute_instruction();
 
         // Disregarding overflow, register $t4 would contain 4,908,534,052, or
-        // 1_00100100_10010010_01001001_00100100 in binary. The result
-        // should be truncated. Thus, we should expect the register to
-        // contain 613,566,756, or 00100100_10010010_01001001_00100100 in binary.
-        assert_eq!(datapath.registers.gpr[9], 613566756);
+        // 1_00100100_10010010_01001001_00100100 in binary. As a sum of two
+        // signed 32-bit integers, this overflows.
+        assert_eq!(datapath.registers.gpr[9], 123);
+        assert_eq!(
+            datapath.system_coprocessor.exception,
+            Some(ExceptionCode::IntegerOverflow)
+        );
+        assert!(datapath.is_halted());
         Ok(())
     }
 
     #[test]
-    // NOTE: This test falls under our initial project design that there are no
-    // handled exceptions. Therefore, we would expect to see an updated value in
-    // register T1, rather than having the register unmodified per the MIPS64v6
-    // specification.
     fn add_32_bit_with_overflow_sign_extend() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
@@ -297,10 +298,6 @@ fn sub_32_bit_negative_result() -> Result<(), String> {
     }
 
     #[test]
-    // NOTE: This test falls under our initial project design that there are no
-    // handled exceptions. Therefore, we would expect to see an updated value in
-    // register $s0, rather than having the register unmodified per the MIPS64v6
-    // specification.
     fn sub_32_bit_underflow() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
@@ -427,6 +424,147 @@ fn div_negative_result() -> Result<(), String> {
     }
 }
 
+pub mod muh_muhu {
+    use super::*;
+
+    #[test]
+    fn muh_positive_result() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // $s4 = hi($t6 * $t5)
+        //                                  R-type  t6    t5    s4    MUH   SOP30
+        let instructions: Vec<u32> = vec![0b000000_01110_01101_10100_00011_011000];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[14] = 0x7FFF_FFFF; // $t6
+        datapath.registers.gpr[13] = 4; // $t5
+
+        datapath.execute_instruction();
+
+        // The product, 0x1_FFFF_FFFC, has an upper half of 1.
+        assert_eq!(datapath.registers.gpr[20], 1); // $s4
+        Ok(())
+    }
+
+    #[test]
+    fn muh_negative_result() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // $s4 = hi($t6 * $t5)
+        //                                  R-type  t6    t5    s4    MUH   SOP30
+        let instructions: Vec<u32> = vec![0b000000_01110_01101_10100_00011_011000];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[14] = -5_i64 as u64; // $t6
+        datapath.registers.gpr[13] = 3; // $t5
+
+        datapath.execute_instruction();
+
+        // The upper half of -15 is all ones.
+        assert_eq!(datapath.registers.gpr[20] as i64, -1); // $s4
+        Ok(())
+    }
+
+    #[test]
+    fn muhu_result_sign_extended() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // $s4 = hi($t6 * $t5)
+        //                                  R-type  t6    t5    s4    MUHU  SOP31
+        let instructions: Vec<u32> = vec![0b000000_01110_01101_10100_00011_011001];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[14] = 0xFFFF_FFFF_FFFF_FFFF; // $t6
+        datapath.registers.gpr[13] = 0xFFFF_FFFF_FFFF_FFFF; // $t5
+
+        datapath.execute_instruction();
+
+        // 0xFFFF_FFFF * 0xFFFF_FFFF = 0xFFFF_FFFE_0000_0001.
+        // The upper half is sign-extended into the register.
+        assert_eq!(datapath.registers.gpr[20], 0xFFFF_FFFF_FFFF_FFFE); // $s4
+        Ok(())
+    }
+}
+
+pub mod mod_modu {
+    use super::*;
+
+    #[test]
+    fn mod_positive_dividend() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // $s4 = $t6 % $t5
+        //                                  R-type  t6    t5    s4    MOD   SOP32
+        let instructions: Vec<u32> = vec![0b000000_01110_01101_10100_00011_011010];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[14] = 20; // $t6
+        datapath.registers.gpr[13] = -6_i64 as u64; // $t5
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.gpr[20], 2); // $s4
+        Ok(())
+    }
+
+    #[test]
+    fn mod_negative_dividend() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // $s4 = $t6 % $t5
+        //                                  R-type  t6    t5    s4    MOD   SOP32
+        let instructions: Vec<u32> = vec![0b000000_01110_01101_10100_00011_011010];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[14] = -20_i64 as u64; // $t6
+        datapath.registers.gpr[13] = 6; // $t5
+
+        datapath.execute_instruction();
+
+        // The remainder takes the sign of the dividend.
+        assert_eq!(datapath.registers.gpr[20] as i64, -2); // $s4
+        Ok(())
+    }
+
+    #[test]
+    fn mod_by_zero() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // $s4 = $t6 % $t5
+        //                                  R-type  t6    t5    s4    MOD   SOP32
+        let instructions: Vec<u32> = vec![0b000000_01110_01101_10100_00011_011010];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[14] = 20; // $t6
+        datapath.registers.gpr[13] = 0; // $t5
+        datapath.registers.gpr[20] = 1234; // $s4
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.gpr[20], 0); // $s4
+        Ok(())
+    }
+
+    #[test]
+    fn modu_unsigned_dividend() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // $s4 = $t6 % $t5
+        //                                  R-type  t6    t5    s4    MODU  SOP33
+        let instructions: Vec<u32> = vec![0b000000_01110_01101_10100_00011_011011];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[14] = 0xFFFF_FFFF_FFFF_FFFF; // $t6
+        datapath.registers.gpr[13] = 10; // $t5
+
+        datapath.execute_instruction();
+
+        // 4,294,967,295 % 10
+        assert_eq!(datapath.registers.gpr[20], 5); // $s4
+        Ok(())
+    }
+}
+
 pub mod or {
     use super::*;
 
@@ -684,2467 +822,4620 @@ fn hard_test_sign_extension() -> Result<(), String> {
     }
 }
 
-pub mod slt {
+pub mod shift_right {
     use super::*;
 
     #[test]
-    fn easy_rs_less_than_rt_test() -> Result<(), String> {
+    fn srl_fills_with_zeros() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s2 = $s0 < $s1
-        //                                  R-type  s0    s1    s2  (shamt)  SLT
-        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_101010];
+        //                                R-type        s1    s2  (shamt) SRL
+        let instructions: Vec<u32> = vec![0b000000_00000_10001_10010_00100_000010];
         datapath.initialize(instructions)?;
 
-        datapath.registers[GpRegisterType::S0] = 1;
-        datapath.registers[GpRegisterType::S1] = 123;
+        datapath.registers.gpr[0b10001] = 0x8000_0000;
 
         datapath.execute_instruction();
-
-        assert_eq!(datapath.registers[GpRegisterType::S2], 1);
+        assert_eq!(datapath.registers.gpr[0b10010], 0x0800_0000);
         Ok(())
     }
 
     #[test]
-    fn easy_rs_greater_than_rt_test() -> Result<(), String> {
+    fn srl_ignores_upper_word() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s2 = $s0 < $s1
-        //                                  R-type  s0    s1    s2  (shamt)  SLT
-        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_101010];
+        //                                R-type        s1    s2  (shamt) SRL
+        let instructions: Vec<u32> = vec![0b000000_00000_10001_10010_00001_000010];
         datapath.initialize(instructions)?;
 
-        datapath.registers[GpRegisterType::S0] = 124;
-        datapath.registers[GpRegisterType::S1] = 123;
+        datapath.registers.gpr[0b10001] = 0xFFFF_FFFF_0000_0010;
 
         datapath.execute_instruction();
-
-        assert_eq!(datapath.registers[GpRegisterType::S2], 0);
+        assert_eq!(datapath.registers.gpr[0b10010], 0x8);
         Ok(())
     }
 
     #[test]
-    fn easy_signed_test() -> Result<(), String> {
+    fn sra_duplicates_sign_bit() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s2 = $s0 < $s1
-        //                                  R-type  s0    s1    s2  (shamt)  SLT
-        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_101010];
+        //                                R-type        s1    s2  (shamt) SRA
+        let instructions: Vec<u32> = vec![0b000000_00000_10001_10010_00100_000011];
         datapath.initialize(instructions)?;
 
-        datapath.registers[GpRegisterType::S0] = -124_i64 as u64;
-        datapath.registers[GpRegisterType::S1] = 123;
+        datapath.registers.gpr[0b10001] = 0x8000_0000;
 
         datapath.execute_instruction();
-
-        assert_eq!(datapath.registers[GpRegisterType::S2], 1);
+        assert_eq!(datapath.registers.gpr[0b10010], 0xFFFF_FFFF_F800_0000);
         Ok(())
     }
 }
 
-pub mod sltu {
+pub mod shift_variable {
     use super::*;
 
     #[test]
-    fn easy_rs_less_than_rt_test() -> Result<(), String> {
+    fn sllv_uses_lower_5_bits_of_rs() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s2 = $s0 < $s1
-        //                                  R-type  s0    s1    s2  (shamt)  SLTU
-        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_101011];
+        //                                R-type  t0    s1    s2          SLLV
+        let instructions: Vec<u32> = vec![0b000000_01000_10001_10010_00000_000100];
         datapath.initialize(instructions)?;
 
-        datapath.registers[GpRegisterType::S0] = 1;
-        datapath.registers[GpRegisterType::S1] = 123;
+        datapath.registers.gpr[8] = 0b100100; // Only 0b00100 is used.
+        datapath.registers.gpr[0b10001] = 0x3;
 
         datapath.execute_instruction();
-
-        assert_eq!(datapath.registers[GpRegisterType::S2], 1);
+        assert_eq!(datapath.registers.gpr[0b10010], 0x30);
         Ok(())
     }
 
     #[test]
-    fn easy_rs_greater_than_rt_test() -> Result<(), String> {
+    fn srlv_basic() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s2 = $s0 < $s1
-        //                                  R-type  s0    s1    s2  (shamt)  SLTU
-        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_101011];
+        //                                R-type  t0    s1    s2          SRLV
+        let instructions: Vec<u32> = vec![0b000000_01000_10001_10010_00000_000110];
         datapath.initialize(instructions)?;
 
-        datapath.registers[GpRegisterType::S0] = 124;
-        datapath.registers[GpRegisterType::S1] = 123;
+        datapath.registers.gpr[8] = 31;
+        datapath.registers.gpr[0b10001] = 0x8000_0000;
 
         datapath.execute_instruction();
-
-        assert_eq!(datapath.registers[GpRegisterType::S2], 0);
+        assert_eq!(datapath.registers.gpr[0b10010], 1);
         Ok(())
     }
 
     #[test]
-    fn easy_signed_test() -> Result<(), String> {
+    fn srav_basic() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s2 = $s0 < $s1
-        //                                  R-type  s0    s1    s2  (shamt)  SLTU
-        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_101011];
+        //                                R-type  t0    s1    s2          SRAV
+        let instructions: Vec<u32> = vec![0b000000_01000_10001_10010_00000_000111];
         datapath.initialize(instructions)?;
 
-        datapath.registers[GpRegisterType::S0] = -124_i64 as u64;
-        datapath.registers[GpRegisterType::S1] = 123;
+        datapath.registers.gpr[8] = 8;
+        datapath.registers.gpr[0b10001] = 0xFFFF_FF00; // -256 as a 32-bit word
 
         datapath.execute_instruction();
-
-        assert_eq!(datapath.registers[GpRegisterType::S2], 0);
+        assert_eq!(datapath.registers.gpr[0b10010], u64::MAX);
         Ok(())
     }
-}
 
-pub mod andi {
-    use super::*;
     #[test]
-    fn and_immediate_with_zero() -> Result<(), String> {
+    fn dsllv_uses_lower_6_bits_of_rs() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s0 = $zero & 12345
-        //                                  andi    $zero  $s0   12345
-        let instructions: Vec<u32> = vec![0b001100_00000_10000_0011000000111001];
+        //                                R-type  t0    s1    s2          DSLLV
+        let instructions: Vec<u32> = vec![0b000000_01000_10001_10010_00000_010100];
         datapath.initialize(instructions)?;
 
-        datapath.execute_instruction();
+        datapath.registers.gpr[8] = 0b1_101000; // Only 40 is used.
+        datapath.registers.gpr[0b10001] = 0xAB;
 
-        assert_eq!(datapath.registers.gpr[16], 0); // $s0
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[0b10010], 0xAB_0000_0000_00);
         Ok(())
     }
 
     #[test]
-    fn andi_immediate_with_value() -> Result<(), String> {
+    fn dsrlv_basic() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s0 = $t0 & 12345
-        //                                  andi     $t0   $s0   12345
-        let instructions: Vec<u32> = vec![0b001100_01000_10000_0011000000111001];
+        //                                R-type  t0    s1    s2          DSRLV
+        let instructions: Vec<u32> = vec![0b000000_01000_10001_10010_00000_010110];
         datapath.initialize(instructions)?;
 
-        // In binary: 00111010 11011110 01101000 10110001
-        datapath.registers.gpr[8] = 987654321; // $t0
+        datapath.registers.gpr[8] = 60;
+        datapath.registers.gpr[0b10001] = 0xF000_0000_0000_0000;
 
         datapath.execute_instruction();
-
-        // The result should be as follows:
-        //         $t0:  00111010 11011110 01101000 10110001
-        // AND  12,345:                    00110000 00111001
-        // =================================================
-        //       8,241:  00000000 00000000 00100000 00110001
-
-        assert_eq!(datapath.registers.gpr[16], 0x2031); // $s0
+        assert_eq!(datapath.registers.gpr[0b10010], 0xF);
         Ok(())
     }
-}
 
-pub mod addi_addiu {
-    use super::*;
     #[test]
-    fn addi_simple_test() -> Result<(), String> {
+    fn dsrav_basic() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s0 = $t0 + 0x4
-        //                                  addi    $t0   $s0          4
-        let instructions: Vec<u32> = vec![0b001000_01000_10000_0000000000000100];
+        //                                R-type  t0    s1    s2          DSRAV
+        let instructions: Vec<u32> = vec![0b000000_01000_10001_10010_00000_010111];
         datapath.initialize(instructions)?;
-        datapath.registers[GpRegisterType::T0] = 1;
-        datapath.registers[GpRegisterType::S0] = 123;
-        datapath.execute_instruction();
 
-        assert_eq!(datapath.registers[GpRegisterType::S0], 5);
+        datapath.registers.gpr[8] = 60;
+        datapath.registers.gpr[0b10001] = 0x8000_0000_0000_0000;
+
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[0b10010], 0xFFFF_FFFF_FFFF_FFF8);
         Ok(())
     }
+}
+
+pub mod double_shift {
+    use super::*;
 
     #[test]
-    // NOTE: This test falls under our initial project design that there are no
-    // handled exceptions. Therefore, we would expect to see an updated value in
-    // register S0, rather than having the register unmodified per the MIPS64v6
-    // specification.
-    fn addi_overflow_test() -> Result<(), String> {
+    fn dsll_basic() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s0 = $t0 + 0x4
-        //                                  addi    $t0   $s0          4
-        let instructions: Vec<u32> = vec![0b001000_01000_10000_0000000000000100];
+        //                                R-type        s1    s2  (shamt) DSLL
+        let instructions: Vec<u32> = vec![0b000000_00000_10001_10010_11111_111000];
         datapath.initialize(instructions)?;
-        datapath.registers[GpRegisterType::T0] = 0xffffffff;
-        datapath.registers[GpRegisterType::S0] = 123;
-        datapath.execute_instruction();
 
-        // If there is an overflow on addi, $s0 should not change.
-        assert_eq!(datapath.registers[GpRegisterType::S0], 3);
+        datapath.registers.gpr[0b10001] = 0x3;
+
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[0b10010], 0x1_8000_0000);
         Ok(())
     }
 
     #[test]
-    fn addi_sign_extend_test() -> Result<(), String> {
+    fn dsrl_basic() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s0 = $t0 + 0x1
-        //                                  addi    $t0   $s0          1
-        let instructions: Vec<u32> = vec![0b001000_01000_10000_0000000000000001];
+        //                                R-type        s1    s2  (shamt) DSRL
+        let instructions: Vec<u32> = vec![0b000000_00000_10001_10010_00100_111010];
         datapath.initialize(instructions)?;
-        datapath.registers[GpRegisterType::T0] = 0xfffffff1;
-        datapath.execute_instruction();
 
-        assert_eq!(datapath.registers[GpRegisterType::S0], 0xfffffffffffffff2);
+        datapath.registers.gpr[0b10001] = 0xF000_0000_0000_0000;
+
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[0b10010], 0x0F00_0000_0000_0000);
         Ok(())
     }
 
     #[test]
-    fn addi_sign_extend_test2() -> Result<(), String> {
+    fn dsra_basic() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s0 = $t0 + 0x1
-        //                                  addi    $t0   $s0          1
-        let instructions: Vec<u32> = vec![0b001000_01000_10000_0000000000000001];
+        //                                R-type        s1    s2  (shamt) DSRA
+        let instructions: Vec<u32> = vec![0b000000_00000_10001_10010_00100_111011];
         datapath.initialize(instructions)?;
-        datapath.registers[GpRegisterType::T0] = 0xfffffffe;
-        datapath.execute_instruction();
 
-        assert_eq!(datapath.registers[GpRegisterType::S0], 0xffffffffffffffff);
+        datapath.registers.gpr[0b10001] = 0xF000_0000_0000_0000;
+
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[0b10010], 0xFF00_0000_0000_0000);
         Ok(())
     }
 
     #[test]
-    fn addiu_simple_test() -> Result<(), String> {
+    fn dsll32_basic() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s0 = $t0 + 0x4
-        //                                  addiu    $t0   $s0          4
-        let instructions: Vec<u32> = vec![0b001001_01000_10000_0000000000000100];
+        //                                R-type        s1    s2  (shamt) DSLL32
+        let instructions: Vec<u32> = vec![0b000000_00000_10001_10010_00100_111100];
         datapath.initialize(instructions)?;
-        datapath.registers[GpRegisterType::T0] = 1;
-        datapath.registers[GpRegisterType::S0] = 123;
-        datapath.execute_instruction();
 
-        assert_eq!(datapath.registers[GpRegisterType::S0], 5);
+        datapath.registers.gpr[0b10001] = 0xFFFF_FFFF_1234_5678;
+
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[0b10010], 0x2345_6780_0000_0000);
         Ok(())
     }
 
     #[test]
-    fn addiu_overflow_test() -> Result<(), String> {
+    fn dsrl32_basic() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s0 = $t0 + 0x4
-        //                                  addiu    $t0   $s0          4
-        let instructions: Vec<u32> = vec![0b001001_01000_10000_0000000000000100];
+        //                                R-type        s1    s2  (shamt) DSRL32
+        let instructions: Vec<u32> = vec![0b000000_00000_10001_10010_00000_111110];
         datapath.initialize(instructions)?;
-        datapath.registers[GpRegisterType::T0] = 0xffffffff;
-        datapath.registers[GpRegisterType::S0] = 123;
-        datapath.execute_instruction();
 
-        // For the addiu instruction, $s0 would change on overflow, it would become 3.
-        assert_eq!(datapath.registers[GpRegisterType::S0], 3);
+        datapath.registers.gpr[0b10001] = 0x8765_4321_0000_0000;
+
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[0b10010], 0x8765_4321);
         Ok(())
     }
 
     #[test]
-    fn addiu_sign_extend_test() -> Result<(), String> {
+    fn dsra32_basic() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s0 = $t0 + 0x1
-        //                                  addi    $t0   $s0          1
-        let instructions: Vec<u32> = vec![0b001000_01000_10000_0000000000000001];
+        //                                R-type        s1    s2  (shamt) DSRA32
+        let instructions: Vec<u32> = vec![0b000000_00000_10001_10010_00000_111111];
         datapath.initialize(instructions)?;
-        datapath.registers[GpRegisterType::T0] = 0xfffffff1;
-        datapath.execute_instruction();
 
-        assert_eq!(datapath.registers[GpRegisterType::S0], 0xfffffffffffffff2);
+        datapath.registers.gpr[0b10001] = 0x8765_4321_0000_0000;
+
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[0b10010], 0xFFFF_FFFF_8765_4321);
         Ok(())
     }
 }
 
-pub mod daddi_and_daddiu {
+pub mod xor_nor {
     use super::*;
+
     #[test]
-    fn daddi_simple_test() -> Result<(), String> {
+    fn xor_basic() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s0 = $t0 + 0x4
-        //                                  daddi    $t0   $s0          4
-        let instructions: Vec<u32> = vec![0b011000_01000_10000_0000000000000100];
+        //                                R-type  s0    s1    s2          XOR
+        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_100110];
         datapath.initialize(instructions)?;
-        datapath.registers[GpRegisterType::T0] = 1;
-        datapath.registers[GpRegisterType::S0] = 123;
-        datapath.execute_instruction();
 
-        assert_eq!(datapath.registers[GpRegisterType::S0], 5);
+        datapath.registers.gpr[16] = 0b1100;
+        datapath.registers.gpr[17] = 0b1010;
+
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[18], 0b0110);
         Ok(())
     }
 
     #[test]
-    // NOTE: This test falls under our initial project design that there are no
-    // handled exceptions. Therefore, we would expect to see an updated value in
-    // register T1, rather than having the register unmodified per the MIPS64v6
-    // specification.
-    fn daddi_overflow_test() -> Result<(), String> {
+    fn nor_basic() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s0 = $t0 + 0x1
-        //                                  daddi    $t0   $s0          1
-        let instructions: Vec<u32> = vec![0b011000_01000_10000_0000000000000001];
+        //                                R-type  s0    s1    s2          NOR
+        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_100111];
         datapath.initialize(instructions)?;
-        datapath.registers[GpRegisterType::T0] = 0xffffffffffffffff;
-        datapath.registers[GpRegisterType::S0] = 123;
-        datapath.execute_instruction();
 
-        assert_eq!(datapath.registers[GpRegisterType::S0], 0);
+        datapath.registers.gpr[16] = 0b1100;
+        datapath.registers.gpr[17] = 0b1010;
+
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[18], !0b1110);
         Ok(())
     }
 
     #[test]
-    fn daddi_sign_extend_test() -> Result<(), String> {
+    fn xori_zero_extends_immediate() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s0 = $t0 + 0x1
-        //                                  daddi    $t0   $s0          1
-        let instructions: Vec<u32> = vec![0b011000_01000_10000_0000000000000001];
+        //                                XORI   s0    s1    immediate
+        let instructions: Vec<u32> = vec![0b001110_10000_10001_1111111111111111];
         datapath.initialize(instructions)?;
-        datapath.registers[GpRegisterType::T0] = 0xfffffffffffffff1;
-        datapath.execute_instruction();
 
-        assert_eq!(datapath.registers[GpRegisterType::S0], 0xfffffffffffffff2);
+        datapath.registers.gpr[16] = 0xFFFF_0000_0000_FF00;
+
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[17], 0xFFFF_0000_0000_00FF);
         Ok(())
     }
+}
+
+pub mod conditional_move {
+    use super::*;
+    use crate::emulation_core::mips::datapath_signals::CondMove;
+    use crate::emulation_core::mips::system_coprocessor::ExceptionCode;
 
     #[test]
-    fn daddi_sign_extend_test2() -> Result<(), String> {
+    fn movz_moves_if_zero() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s0 = $t0 + 0x1
-        //                                  daddi    $t0   $s0          1
-        let instructions: Vec<u32> = vec![0b011000_01000_10000_0000000000000001];
+        //                                R-type  s0    s1    s2          MOVZ
+        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_001010];
         datapath.initialize(instructions)?;
-        datapath.registers[GpRegisterType::T0] = 0xfffffffffffffffe;
-        datapath.execute_instruction();
 
-        assert_eq!(datapath.registers[GpRegisterType::S0], 0xffffffffffffffff);
+        datapath.registers.gpr[16] = 1234;
+        datapath.registers.gpr[17] = 0;
+        datapath.registers.gpr[18] = 5678;
+
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[18], 1234);
         Ok(())
     }
 
     #[test]
-    fn daddiu_simple_test() -> Result<(), String> {
+    fn movz_keeps_destination_if_not_zero() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s0 = $t0 + 0x4
-        //                                  daddiu    $t0   $s0          4
-        let instructions: Vec<u32> = vec![0b011001_01000_10000_0000000000000100];
+        //                                R-type  s0    s1    s2          MOVZ
+        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_001010];
         datapath.initialize(instructions)?;
-        datapath.registers[GpRegisterType::T0] = 1;
-        datapath.registers[GpRegisterType::S0] = 123;
-        datapath.execute_instruction();
 
-        assert_eq!(datapath.registers[GpRegisterType::S0], 5);
+        datapath.registers.gpr[16] = 1234;
+        // Only the upper word is set, which still counts as not zero.
+        datapath.registers.gpr[17] = 1 << 40;
+        datapath.registers.gpr[18] = 5678;
+
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[18], 5678);
+        assert!(datapath.datapath_signals.cond_move == CondMove::NoMove);
         Ok(())
     }
 
     #[test]
-    fn daddiu_overflow_test() -> Result<(), String> {
+    fn movn_moves_if_not_zero() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s0 = $t0 + 0x4
-        //                                  daddiu    $t0   $s0          4
-        let instructions: Vec<u32> = vec![0b011001_01000_10000_0000000000000100];
+        //                                R-type  s0    s1    s2          MOVN
+        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_001011];
         datapath.initialize(instructions)?;
-        datapath.registers[GpRegisterType::T0] = 0xffffffffffffffff;
-        datapath.registers[GpRegisterType::S0] = 123;
-        datapath.execute_instruction();
 
-        // if there is an overflow, $s0 should not change.
-        // For the daddiu instruction, $s0 would change on overflow, it would become 3.
-        assert_eq!(datapath.registers[GpRegisterType::S0], 3);
+        datapath.registers.gpr[16] = 0xFFFF_0000_0000_1234;
+        datapath.registers.gpr[17] = 7;
+
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[18], 0xFFFF_0000_0000_1234);
         Ok(())
     }
 
     #[test]
-    fn daddiu_sign_extend_test() -> Result<(), String> {
+    fn movt_and_movf_use_condition_code() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s0 = $t0 + 0x1
-        //                                  daddiu    $t0   $s0          1
-        let instructions: Vec<u32> = vec![0b011001_01000_10000_0000000000000001];
+        let instructions: Vec<u32> = vec![
+            //  R-type  s0    cc  0 tf  s2          MOVCI
+            0b000000_10000_011_0_1_10010_00000_000001, // movt $s2, $s0, 3
+            0b000000_10000_011_0_0_10011_00000_000001, // movf $s3, $s0, 3
+        ];
         datapath.initialize(instructions)?;
-        datapath.registers[GpRegisterType::T0] = 0xfffffffffffffff1;
-        datapath.execute_instruction();
 
-        assert_eq!(datapath.registers[GpRegisterType::S0], 0xfffffffffffffff2);
+        datapath.registers.gpr[16] = 99;
+        datapath.coprocessor.condition_code = 0b1000;
+
+        datapath.execute_instruction();
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[18], 99);
+        assert_eq!(datapath.registers.gpr[19], 0);
         Ok(())
     }
-}
 
-pub mod ori {
-    use super::*;
     #[test]
-    fn or_immediate_with_zero() -> Result<(), String> {
+    fn movci_with_bit_17_set_is_reserved() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s0 = $zero | 12345
-        //                                  ori    $zero  $s0   12345
-        let instructions: Vec<u32> = vec![0b001101_00000_10000_0011000000111001];
+        //                                R-type  s0    cc  1 tf  s2          MOVCI
+        let instructions: Vec<u32> = vec![0b000000_10000_000_1_1_10010_00000_000001];
         datapath.initialize(instructions)?;
 
         datapath.execute_instruction();
-
-        assert_eq!(datapath.registers.gpr[16], 12345); // $s0
+        assert_eq!(
+            datapath.system_coprocessor.exception,
+            Some(ExceptionCode::ReservedInstruction)
+        );
         Ok(())
     }
 
     #[test]
-    fn or_immediate_with_value() -> Result<(), String> {
+    fn seleqz_and_selnez() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // $s0 = $t0 | 12345
-        //                                  ori     $t0   $s0   12345
-        let instructions: Vec<u32> = vec![0b001101_01000_10000_0011000000111001];
+        let instructions: Vec<u32> = vec![
+            //  R-type  s0    s1    s2          SELEQZ
+            0b000000_10000_10001_10010_00000_110101,
+            //  R-type  s0    s1    s3          SELNEZ
+            0b000000_10000_10001_10011_00000_110111,
+        ];
         datapath.initialize(instructions)?;
 
-        // In binary: 00111010 11011110 01101000 10110001
-        datapath.registers.gpr[8] = 987654321; // $t0
+        datapath.registers.gpr[16] = 42;
+        datapath.registers.gpr[17] = 0;
+        datapath.registers.gpr[18] = 5678;
+        datapath.registers.gpr[19] = 5678;
 
         datapath.execute_instruction();
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[18], 42);
+        assert_eq!(datapath.registers.gpr[19], 0);
+        Ok(())
+    }
 
-        // The result should be as follows:
-        //         $t0:  00111010 11011110 01101000 10110001
-        // OR   12,345:                    00110000 00111001
-        // =================================================
-        // 987,658,425:  00111010 11011110 01111000 10111001
+    #[test]
+    fn movz_d_and_movn_d_test_general_purpose_register() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
 
-        assert_eq!(datapath.registers.gpr[16], 987658425); // $s0
+        let instructions: Vec<u32> = vec![
+            //  COP1   fmt   rt    fs    fd    function
+            //         d     $s1   $f1   $f2   MOVZ
+            0b010001_10001_10001_00001_00010_010010,
+            //         d     $s1   $f1   $f3   MOVN
+            0b010001_10001_10001_00001_00011_010011,
+        ];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[17] = 0;
+        datapath.coprocessor.fpr[1] = f64::to_bits(2.5);
+        datapath.coprocessor.fpr[3] = f64::to_bits(-1.0);
+
+        datapath.execute_instruction();
+        datapath.execute_instruction();
+        assert_eq!(f64::from_bits(datapath.coprocessor.fpr[2]), 2.5);
+        assert_eq!(f64::from_bits(datapath.coprocessor.fpr[3]), -1.0);
         Ok(())
     }
-}
-
-pub mod dadd_daddu {
-    use super::*;
 
     #[test]
-    fn dadd_register_to_itself() -> Result<(), String> {
+    fn movt_s_uses_condition_code() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // dadd rd, rs, rt
-        // dadd $v0, $t5, $t5
-        // GPR[2] <- GPR[13] + GPR[13]
-        //                                 SPECIAL rs    rt    rd    0     DADD
-        //                                         13    13    2
-        let instructions: Vec<u32> = vec![0b000000_01101_01101_00010_00000_101100];
+        let instructions: Vec<u32> = vec![
+            //  COP1   fmt   cc  0 tf  fs    fd    function
+            //         s     1     T   $f1   $f2   MOVCF
+            0b010001_10000_001_0_1_00001_00010_010001,
+            //         s     2     T   $f1   $f3   MOVCF
+            0b010001_10000_010_0_1_00001_00011_010001,
+        ];
         datapath.initialize(instructions)?;
 
-        // Assume register $t5 contains 969,093,589,304, which is an integer
-        // that takes up 39 bits.
-        datapath.registers.gpr[13] = 969_093_589_304; // $t5
+        datapath.coprocessor.condition_code = 0b010;
+        datapath.coprocessor.fpr[1] = f32::to_bits(3.5) as u64;
 
         datapath.execute_instruction();
-
-        assert_eq!(datapath.registers.gpr[2], 1_938_187_178_608); // $v0
+        datapath.execute_instruction();
+        assert_eq!(f32::from_bits(datapath.coprocessor.fpr[2] as u32), 3.5);
+        assert_eq!(datapath.coprocessor.fpr[3], 0);
         Ok(())
     }
 
-    // NOTE: This test falls under our initial project design that there are no
-    // handled exceptions. Therefore, we would expect to see an updated value in
-    // register $v0, rather than having the register unmodified per the MIPS64v6
-    // specification.
     #[test]
-    fn dadd_positive_overflow() -> Result<(), String> {
+    fn sel_d_uses_lowest_bit_of_destination() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // dadd rd, rs, rt
-        // dadd $v0, $t5, $t5
-        // GPR[2] <- GPR[13] + GPR[13]
-        //                                 SPECIAL rs    rt    rd    0     DADD
-        //                                         13    13    2
-        let instructions: Vec<u32> = vec![0b000000_01101_01101_00010_00000_101100];
+        let instructions: Vec<u32> = vec![
+            //  COP1   fmt   ft    fs    fd    function
+            //         d     $f3   $f1   $f2   SEL
+            0b010001_10001_00011_00001_00010_010000,
+            //         d     $f3   $f1   $f4   SEL
+            0b010001_10001_00011_00001_00100_010000,
+        ];
         datapath.initialize(instructions)?;
 
-        // Assume register $t5 contains 18,134,889,837,812,767,690, which is an integer
-        // that takes up 64 bits.
-        datapath.registers.gpr[13] = 18_134_889_837_812_767_690; // $t5
+        datapath.coprocessor.fpr[1] = f64::to_bits(1.0);
+        datapath.coprocessor.fpr[3] = f64::to_bits(3.0);
+        datapath.coprocessor.fpr[2] = 1;
+        datapath.coprocessor.fpr[4] = 0;
 
         datapath.execute_instruction();
-
-        // The result is truncated to give a 64-bit value.
-        assert_eq!(datapath.registers.gpr[2], 17_823_035_601_915_983_764); // $v0
+        datapath.execute_instruction();
+        assert_eq!(f64::from_bits(datapath.coprocessor.fpr[2]), 3.0);
+        assert_eq!(f64::from_bits(datapath.coprocessor.fpr[4]), 1.0);
         Ok(())
     }
 
     #[test]
-    fn daddu_positive_result() -> Result<(), String> {
+    fn seleqz_s_and_selnez_s() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // daddu rd, rs, rt
-        // daddu $s2, $s0, $s1
-        // GPR[18] <- GPR[16] + GPR[17]
-        //                                 SPECIAL rs    rt    rd    0     DADDU
-        //                                         16    17    18
-        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_101101];
+        let instructions: Vec<u32> = vec![
+            //  COP1   fmt   ft    fs    fd    function
+            //         s     $f3   $f1   $f2   SELEQZ
+            0b010001_10000_00011_00001_00010_010100,
+            //         s     $f3   $f1   $f4   SELNEZ
+            0b010001_10000_00011_00001_00100_010111,
+        ];
         datapath.initialize(instructions)?;
 
-        datapath.registers.gpr[16] = 1_069_193_590_294; // $s0
-        datapath.registers.gpr[17] = 34_359_738_368; // $s1
+        datapath.coprocessor.fpr[1] = f32::to_bits(-6.0) as u64;
+        // Only the lowest bit of `ft` is tested.
+        datapath.coprocessor.fpr[3] = 0b10;
+        datapath.coprocessor.fpr[4] = f32::to_bits(8.0) as u64;
 
         datapath.execute_instruction();
-
-        assert_eq!(datapath.registers.gpr[18], 1_103_553_328_662); // $s2
+        datapath.execute_instruction();
+        assert_eq!(f32::from_bits(datapath.coprocessor.fpr[2] as u32), -6.0);
+        assert_eq!(datapath.coprocessor.fpr[4], 0);
         Ok(())
     }
 }
 
-pub mod dsub_dsubu {
+pub mod slt {
     use super::*;
 
     #[test]
-    fn dsub_registers_positive_result() -> Result<(), String> {
+    fn easy_rs_less_than_rt_test() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // dsub rd, rs, rt
-        // dsub $s5, $s4, $s3
-        // GPR[rd] <- GPR[rs] - GPR[rt]
-        // GPR[$s5] <- GPR[$s4] - GPR[$s3]
-        // GPR[19] <- GPR[18] - GPR[17]
-        //                                 SPECIAL rs    rt    rd    0     funct
-        //                                         $s4   $s3   $s5         DSUB
-        //                                         18    17    19
-        let instructions: Vec<u32> = vec![0b000000_10010_10001_10011_00000_101110];
+        // $s2 = $s0 < $s1
+        //                                  R-type  s0    s1    s2  (shamt)  SLT
+        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_101010];
         datapath.initialize(instructions)?;
 
-        // Assume registers $s3 and $s4 contain numbers larger than 32 bits,
-        // but smaller than 64 bits.
-        datapath.registers.gpr[18] = 4_833_323_886_298_794; // $s4
-        datapath.registers.gpr[17] = 163_643_849_115_304; // $s3
+        datapath.registers[GpRegisterType::S0] = 1;
+        datapath.registers[GpRegisterType::S1] = 123;
 
         datapath.execute_instruction();
 
-        assert_eq!(datapath.registers.gpr[19], 4_669_680_037_183_490); // $s5
+        assert_eq!(datapath.registers[GpRegisterType::S2], 1);
         Ok(())
     }
 
-    // NOTE: This test falls under our initial project design that there are no
-    // handled exceptions. Therefore, we would expect to see an updated value in
-    // register $s5, rather than having the register unmodified per the MIPS64v6
-    // specification.
     #[test]
-    fn dsub_negative_integer_underflow() -> Result<(), String> {
+    fn easy_rs_greater_than_rt_test() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // dsub rd, rs, rt
-        // dsub $s5, $s4, $s3
-        // GPR[rd] <- GPR[rs] - GPR[rt]
-        // GPR[$s5] <- GPR[$s4] - GPR[$s3]
-        // GPR[19] <- GPR[18] - GPR[17]
-        //                                 SPECIAL rs    rt    rd    0     funct
-        //                                         $s4   $s3   $s5         DSUB
-        //                                         18    17    19
-        let instructions: Vec<u32> = vec![0b000000_10010_10001_10011_00000_101110];
+        // $s2 = $s0 < $s1
+        //                                  R-type  s0    s1    s2  (shamt)  SLT
+        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_101010];
         datapath.initialize(instructions)?;
 
-        // Assume registers $s4 is the minimum possible integer and $s4 is 1.
-        datapath.registers.gpr[18] = 0; // $s4
-        datapath.registers.gpr[17] = 1; // $s3
+        datapath.registers[GpRegisterType::S0] = 124;
+        datapath.registers[GpRegisterType::S1] = 123;
 
         datapath.execute_instruction();
 
-        // Given a negative integer overflow, this should become the maximum possible unsigned integer.
-        assert_eq!(datapath.registers.gpr[19], 0xffff_ffff_ffff_ffff); // $s5
+        assert_eq!(datapath.registers[GpRegisterType::S2], 0);
         Ok(())
     }
 
     #[test]
-    fn dsubu_positive_result() -> Result<(), String> {
+    fn easy_signed_test() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // dsubu rd, rs, rt
-        // dsubu $s2, $s0, $s1
-        // GPR[18] <- GPR[16] - GPR[17]
-        //                                 SPECIAL rs    rt    rd    0     DSUBU
-        //                                         16    17    18
-        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_101111];
+        // $s2 = $s0 < $s1
+        //                                  R-type  s0    s1    s2  (shamt)  SLT
+        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_101010];
         datapath.initialize(instructions)?;
 
-        datapath.registers.gpr[16] = 92_975_612_771_919; // $s0
-        datapath.registers.gpr[17] = 13_810_775_572_047; // $s1
+        datapath.registers[GpRegisterType::S0] = -124_i64 as u64;
+        datapath.registers[GpRegisterType::S1] = 123;
 
         datapath.execute_instruction();
 
-        assert_eq!(datapath.registers.gpr[18], 79_164_837_199_872); // $s2
+        assert_eq!(datapath.registers[GpRegisterType::S2], 1);
         Ok(())
     }
 }
 
-pub mod dmul_dmulu {
+pub mod sltu {
     use super::*;
 
     #[test]
-    fn dmul_positive_result() -> Result<(), String> {
+    fn easy_rs_less_than_rt_test() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // dmul rd, rs, rt
-        // dmul $a0, $t8, $t9
-        // dmul 4, 24, 25
-        // GPR[rd] <- lo_doubleword(multiply.signed(GPR[rs] * GPR[rt]))
-        //                                 opcode  rs    rt    rd          funct
-        //                                 SPECIAL $t8   $t9   $a0   DMUL  SOP34
-        //                                         24    25    4
-        let instructions: Vec<u32> = vec![0b000000_11000_11001_00100_00010_011100];
+        // $s2 = $s0 < $s1
+        //                                  R-type  s0    s1    s2  (shamt)  SLTU
+        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_101011];
         datapath.initialize(instructions)?;
 
-        // Assume register $t8 contains a number larger than 32 bits,
-        // but smaller than 64 bits.
-        datapath.registers.gpr[24] = 5_861_036_283_017; // $t8
-        datapath.registers.gpr[25] = 5; // $t9
+        datapath.registers[GpRegisterType::S0] = 1;
+        datapath.registers[GpRegisterType::S1] = 123;
 
         datapath.execute_instruction();
 
-        assert_eq!(datapath.registers.gpr[4], 29_305_181_415_085); // $a0
+        assert_eq!(datapath.registers[GpRegisterType::S2], 1);
         Ok(())
     }
 
     #[test]
-    fn dmul_negative_result() -> Result<(), String> {
+    fn easy_rs_greater_than_rt_test() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // dmul rd, rs, rt
-        // dmul $s7, $t7, $t6
-        // dmul 23, 15, 14
-        // GPR[rd] <- lo_doubleword(multiply.signed(GPR[rs] * GPR[rt]))
-        //                                 opcode  rs    rt    rd          funct
-        //                                 SPECIAL $t7   $t6   $s7   DMUL  SOP34
-        //                                         15    14    23
-        let instructions: Vec<u32> = vec![0b000000_01111_01110_10111_00010_011100];
+        // $s2 = $s0 < $s1
+        //                                  R-type  s0    s1    s2  (shamt)  SLTU
+        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_101011];
         datapath.initialize(instructions)?;
 
-        // Assume register $t7 contains a number larger than 32 bits,
-        // but smaller than 64 bits.
-        datapath.registers.gpr[15] = 363_251_152_978_005; // $t7
-        datapath.registers.gpr[14] = -19_i64 as u64; // $t6
+        datapath.registers[GpRegisterType::S0] = 124;
+        datapath.registers[GpRegisterType::S1] = 123;
 
         datapath.execute_instruction();
 
-        assert_eq!(datapath.registers.gpr[23] as i64, -6_901_771_906_582_095); // $s7
+        assert_eq!(datapath.registers[GpRegisterType::S2], 0);
         Ok(())
     }
 
     #[test]
-    fn dmul_result_truncate() -> Result<(), String> {
+    fn easy_signed_test() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // dmul rd, rs, rt
-        // dmul $s2, $s4, $s3
-        // dmul 18, 20, 19
-        // GPR[rd] <- lo_doubleword(multiply.signed(GPR[rs] * GPR[rt]))
-        //                                 opcode  rs    rt    rd          funct
-        //                                 SPECIAL $s4   $s3   $s2   DMUL  SOP34
-        //                                         20    19    18
-        let instructions: Vec<u32> = vec![0b000000_10100_10011_10010_00010_011100];
+        // $s2 = $s0 < $s1
+        //                                  R-type  s0    s1    s2  (shamt)  SLTU
+        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_101011];
         datapath.initialize(instructions)?;
 
-        // Assume registers $s4 and $s3 contain numbers larger than 32 bits,
-        // but smaller than 64 bits.
-        datapath.registers.gpr[20] = 191_893_548_893_556_856; // $s4
-        datapath.registers.gpr[19] = 2_799_316_838_897; // $s3
+        datapath.registers[GpRegisterType::S0] = -124_i64 as u64;
+        datapath.registers[GpRegisterType::S1] = 123;
 
         datapath.execute_instruction();
 
-        // The result, 537,170,842,693,438,490,068,661,827,832, is too large for
-        // a 64-bit integer.
-        // (110 11000111 10110001 01001110 10000100 [00110100 01101011 00001011 00010110 11011010 00010011 11111000 11111000])
-        // The result should instead truncate to the lower 64 bits.
-        assert_eq!(datapath.registers.gpr[18], 3_777_124_905_256_220_920); // $s2
+        assert_eq!(datapath.registers[GpRegisterType::S2], 0);
         Ok(())
     }
+}
 
+pub mod andi {
+    use super::*;
     #[test]
-    fn dmulu_positive_result() -> Result<(), String> {
+    fn and_immediate_with_zero() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // dmulu rd, rs, rt
-        // dmulu $s2, $s0, $s1
-        // dmulu 18, 16, 17
-        // GPR[rd] <- lo_doubleword(multiply.unsigned(GPR[rs] * GPR[rt]))
-        //                                 opcode  rs    rt    rd          funct
-        //                                 SPECIAL $s0   $s1   $s2   DMULU SOP35
-        //                                         16    17    18
-        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00010_011101];
+        // $s0 = $zero & 12345
+        //                                  andi    $zero  $s0   12345
+        let instructions: Vec<u32> = vec![0b001100_00000_10000_0011000000111001];
         datapath.initialize(instructions)?;
 
-        datapath.registers.gpr[16] = 17_592_186_044_416; // $s0
-        datapath.registers.gpr[17] = 1_000; // $s1
-
         datapath.execute_instruction();
 
-        assert_eq!(datapath.registers.gpr[18], 17_592_186_044_416_000); // $s2
+        assert_eq!(datapath.registers.gpr[16], 0); // $s0
         Ok(())
     }
-}
-
-pub mod ddiv_ddivu {
-    use super::*;
 
     #[test]
-    fn ddiv_positive_result() -> Result<(), String> {
+    fn andi_immediate_with_value() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // ddiv rd, rs, rt
-        // ddiv $s0, $s1, $s2
-        // ddiv 16, 17, 18
-        // GPR[rd] <- divide.signed(GPR[rs], GPR[rt])
-        //                                 opcode  rs    rt    rd          funct
-        //                                 SPECIAL $s1   $s2   $s0   DDIV  SOP36
-        //                                         17    18    16
-        let instructions: Vec<u32> = vec![0b000000_10001_10010_10000_00010_011110];
-
+        // $s0 = $t0 & 12345
+        //                                  andi     $t0   $s0   12345
+        let instructions: Vec<u32> = vec![0b001100_01000_10000_0011000000111001];
         datapath.initialize(instructions)?;
 
-        // Assume register $s1 contains a number larger than 32 bits,
-        // but smaller than 64 bits.
-        datapath.registers.gpr[17] = 1_284_064_531_192; // $s1
-        datapath.registers.gpr[18] = 7; // $s2
+        // In binary: 00111010 11011110 01101000 10110001
+        datapath.registers.gpr[8] = 987654321; // $t0
 
         datapath.execute_instruction();
 
-        // While the actual result is 183,437,790,170.285714....
-        // the decimal portion is truncated.
-        assert_eq!(datapath.registers.gpr[16], 183_437_790_170); // $s0
+        // The result should be as follows:
+        //         $t0:  00111010 11011110 01101000 10110001
+        // AND  12,345:                    00110000 00111001
+        // =================================================
+        //       8,241:  00000000 00000000 00100000 00110001
+
+        assert_eq!(datapath.registers.gpr[16], 0x2031); // $s0
         Ok(())
     }
+}
 
+pub mod addi_addiu {
+    use super::*;
     #[test]
-    fn ddiv_negative_result() -> Result<(), String> {
+    fn addi_simple_test() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // ddiv rd, rs, rt
-        // ddiv $a3, $a2, $a1
-        // ddiv 7, 6, 5
-        // GPR[rd] <- divide.signed(GPR[rs], GPR[rt])
-        //                                 opcode  rs    rt    rd          funct
-        //                                 SPECIAL $a2   $a1   $a3   DDIV  SOP36
-        //                                         6     5     7
-        let instructions: Vec<u32> = vec![0b000000_00110_00101_00111_00010_011110];
-
+        // $s0 = $t0 + 0x4
+        //                                  addi    $t0   $s0          4
+        let instructions: Vec<u32> = vec![0b001000_01000_10000_0000000000000100];
         datapath.initialize(instructions)?;
-
-        // Assume register $a2 contains a number larger than 32 bits,
-        // but smaller than 64 bits.
-        datapath.registers.gpr[6] = -6_245_352_518_120_328_878_i64 as u64; // $a2
-        datapath.registers.gpr[5] = 123; // $a1
-
+        datapath.registers[GpRegisterType::T0] = 1;
+        datapath.registers[GpRegisterType::S0] = 123;
         datapath.execute_instruction();
 
-        // While the actual result is -50,775,223,724,555,519.333333....
-        // the decimal portion is truncated.
-        assert_eq!(datapath.registers.gpr[7] as i64, -50_775_223_724_555_519); // $a3
+        assert_eq!(datapath.registers[GpRegisterType::S0], 5);
         Ok(())
     }
 
     #[test]
-    fn ddivu_positive_result() -> Result<(), String> {
+    fn addi_overflow_test() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // ddivu rd, rs, rt
-        // ddivu $s2, $s0, $s1
-        // ddivu 18, 16, 17
-        // GPR[rd] <- divide.unsigned(GPR[rs], GPR[rt])
-        //                                 opcode  rs    rt    rd          funct
-        //                                 SPECIAL $s0   $s1   $s2   DDIVU SOP37
-        //                                         16    17    18
-        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00010_011111];
-
+        // $s0 = $t0 + 0x4
+        //                                  addi    $t0   $s0          4
+        let instructions: Vec<u32> = vec![0b001000_01000_10000_0000000000000100];
         datapath.initialize(instructions)?;
-
-        datapath.registers.gpr[16] = 10_213_202_487_240; // $s0
-        datapath.registers.gpr[17] = 11; // $s1
-
+        datapath.registers[GpRegisterType::T0] = 0xffffffff;
+        datapath.registers[GpRegisterType::S0] = 123;
         datapath.execute_instruction();
 
-        assert_eq!(datapath.registers.gpr[18], 928_472_953_385); // $s2
+        // If there is an overflow on addi, $s0 should not change.
+        assert_eq!(datapath.registers[GpRegisterType::S0], 3);
         Ok(())
     }
-}
-
-pub mod dahi_dati {
-    use super::*;
 
     #[test]
-    fn dahi_basic_add() -> Result<(), String> {
+    fn addi_sign_extend_test() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // dahi rs, immediate
-        // dahi $a0, 1
-        // GPR[rs] <- GPR[rs] + sign_extend(immediate << 32)
-        // GPR[4] <- GPR[4] + sign_extend(1 << 32)
-        //                                  op     rs    rt     immediate
-        //                                  REGIMM $a0   DAHI   1
-        let instructions: Vec<u32> = vec![0b000001_00100_00110_0000000000000001];
+        // $s0 = $t0 + 0x1
+        //                                  addi    $t0   $s0          1
+        let instructions: Vec<u32> = vec![0b001000_01000_10000_0000000000000001];
         datapath.initialize(instructions)?;
-
-        datapath.registers.gpr[4] = 0xABCD; // $a0
-
+        datapath.registers[GpRegisterType::T0] = 0xfffffff1;
         datapath.execute_instruction();
 
-        assert_eq!(datapath.registers.gpr[4], 0x0000_0001_0000_ABCD);
-
+        assert_eq!(datapath.registers[GpRegisterType::S0], 0xfffffffffffffff2);
         Ok(())
     }
 
     #[test]
-    fn dati_basic_add() -> Result<(), String> {
+    fn addi_sign_extend_test2() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // dati rs, immediate
-        // dati $a1, 1
-        // GPR[rs] <- GPR[rs] + sign_extend(immediate << 48)
-        // GPR[5] <- GPR[5] + sign_extend(1 << 48)
-        //                                  op     rs    rt     immediate
-        //                                  REGIMM $a1   DATI   1
-        let instructions: Vec<u32> = vec![0b000001_00101_11110_0000000000000001];
+        // $s0 = $t0 + 0x1
+        //                                  addi    $t0   $s0          1
+        let instructions: Vec<u32> = vec![0b001000_01000_10000_0000000000000001];
         datapath.initialize(instructions)?;
-
-        datapath.registers.gpr[5] = 0xABCD; // $a1
-
+        datapath.registers[GpRegisterType::T0] = 0xfffffffe;
         datapath.execute_instruction();
 
-        assert_eq!(datapath.registers.gpr[5], 0x0001_0000_0000_ABCD);
-
+        assert_eq!(datapath.registers[GpRegisterType::S0], 0xffffffffffffffff);
         Ok(())
     }
-}
 
-pub mod load_word {
-    use super::*;
     #[test]
-    fn lw_zero_offset_test() -> Result<(), String> {
-        // for this test the lw instruction will load itself from
-        // memory
+    fn addiu_simple_test() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        //                                  lw     $t0   $s0      offset = 0
-        let instructions: Vec<u32> = vec![0b100011_01000_10000_0000000000000000];
-        datapath.initialize(instructions.clone())?;
+        // $s0 = $t0 + 0x4
+        //                                  addiu    $t0   $s0          4
+        let instructions: Vec<u32> = vec![0b001001_01000_10000_0000000000000100];
+        datapath.initialize(instructions)?;
+        datapath.registers[GpRegisterType::T0] = 1;
+        datapath.registers[GpRegisterType::S0] = 123;
         datapath.execute_instruction();
-        assert_eq!(datapath.registers.gpr[16], instructions[0] as u64);
+
+        assert_eq!(datapath.registers[GpRegisterType::S0], 5);
         Ok(())
     }
 
     #[test]
-    fn lw_offset_at_4_test() -> Result<(), String> {
-        // For this test the lw instruction will load 0x4 from memory
-        // by using the offset address plus zero
+    fn addiu_overflow_test() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        //                                  lw     $t0   $s0      offset = 4
-        let instructions: Vec<u32> = vec![0b100011_01000_10000_0000000000000100];
+        // $s0 = $t0 + 0x4
+        //                                  addiu    $t0   $s0          4
+        let instructions: Vec<u32> = vec![0b001001_01000_10000_0000000000000100];
         datapath.initialize(instructions)?;
-
-        // place data at address
-        datapath.memory.store_word(0b100, 0x10000)?;
-
-        datapath.registers.gpr[8] = 0;
+        datapath.registers[GpRegisterType::T0] = 0xffffffff;
+        datapath.registers[GpRegisterType::S0] = 123;
         datapath.execute_instruction();
-        assert_eq!(datapath.registers.gpr[16], 0x10000);
+
+        // For the addiu instruction, $s0 would change on overflow, it would become 3.
+        assert_eq!(datapath.registers[GpRegisterType::S0], 3);
         Ok(())
     }
 
     #[test]
-    fn lw_gpr_8_at_4_offset_at_0_test() -> Result<(), String> {
-        // for this test the lw instruction will load 0x4 from memory
-        // by using (offset = 0) + (gpr[8] = 4)
+    fn addiu_sign_extend_test() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        //                                  lw     $t0   $s0      offset = 0
-        let instructions: Vec<u32> = vec![0b100011_01000_10000_0000000000000000];
+        // $s0 = $t0 + 0x1
+        //                                  addi    $t0   $s0          1
+        let instructions: Vec<u32> = vec![0b001000_01000_10000_0000000000000001];
         datapath.initialize(instructions)?;
-
-        // place data at address
-        datapath.memory.store_word(0b100, 0x10000)?;
-
-        datapath.registers.gpr[8] = 4;
+        datapath.registers[GpRegisterType::T0] = 0xfffffff1;
         datapath.execute_instruction();
-        assert_eq!(datapath.registers.gpr[16], 0x10000);
+
+        assert_eq!(datapath.registers[GpRegisterType::S0], 0xfffffffffffffff2);
         Ok(())
     }
+}
 
+pub mod daddi_and_daddiu {
+    use super::*;
     #[test]
-    fn lw_gpr_8_at_4_offset_at_4_test() -> Result<(), String> {
-        // for this test the lw instruction will load 0x8 from memory
-        // by adding the offset to gpr[8]
+    fn daddi_simple_test() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        //                                  lw     $t0   $s0      offset = 0
-        let instructions: Vec<u32> = vec![0b100011_01000_10000_0000000000000100];
+        // $s0 = $t0 + 0x4
+        //                                  daddi    $t0   $s0          4
+        let instructions: Vec<u32> = vec![0b011000_01000_10000_0000000000000100];
         datapath.initialize(instructions)?;
-
-        // place data at address
-        datapath.memory.store_word(0b1000, 0x10000)?;
-
-        datapath.registers.gpr[8] = 4;
+        datapath.registers[GpRegisterType::T0] = 1;
+        datapath.registers[GpRegisterType::S0] = 123;
         datapath.execute_instruction();
-        assert_eq!(datapath.registers.gpr[16], 0x10000);
+
+        assert_eq!(datapath.registers[GpRegisterType::S0], 5);
         Ok(())
     }
 
     #[test]
-    fn lw_gpr_8_at_12_offset_at_neg_4_test() -> Result<(), String> {
-        // for this test the lw instruction will load 0x8 from memory
-        // by adding the offset to gpr[8]
+    fn daddi_overflow_test() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        //                                  lw     $t0   $s0      offset = 0
-        let instructions: Vec<u32> = vec![0b100011_01000_10000_1111111111111100];
+        // $s0 = $t0 + 0x1
+        //                                  daddi    $t0   $s0          1
+        let instructions: Vec<u32> = vec![0b011000_01000_10000_0000000000000001];
         datapath.initialize(instructions)?;
+        datapath.registers[GpRegisterType::T0] = 0xffffffffffffffff;
+        datapath.registers[GpRegisterType::S0] = 123;
+        datapath.execute_instruction();
 
-        // place data at address
-        datapath.memory.store_word(0b1000, 0x10000)?;
+        assert_eq!(datapath.registers[GpRegisterType::S0], 0);
+        Ok(())
+    }
 
-        datapath.registers.gpr[8] = 12;
+    #[test]
+    fn daddi_sign_extend_test() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // $s0 = $t0 + 0x1
+        //                                  daddi    $t0   $s0          1
+        let instructions: Vec<u32> = vec![0b011000_01000_10000_0000000000000001];
+        datapath.initialize(instructions)?;
+        datapath.registers[GpRegisterType::T0] = 0xfffffffffffffff1;
         datapath.execute_instruction();
-        assert_eq!(datapath.registers.gpr[16], 0x10000);
+
+        assert_eq!(datapath.registers[GpRegisterType::S0], 0xfffffffffffffff2);
         Ok(())
     }
-}
-
-pub mod load_upper_imm {
-    use super::*;
 
     #[test]
-    fn basic_load_upper_imm_test() -> Result<(), String> {
+    fn daddi_sign_extend_test2() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        //                                  lui    $t0   $s0      offset = 42
-        let instructions: Vec<u32> = vec![0b001111_01000_10000_0010101010101010];
+        // $s0 = $t0 + 0x1
+        //                                  daddi    $t0   $s0          1
+        let instructions: Vec<u32> = vec![0b011000_01000_10000_0000000000000001];
         datapath.initialize(instructions)?;
+        datapath.registers[GpRegisterType::T0] = 0xfffffffffffffffe;
         datapath.execute_instruction();
 
-        let t = datapath.registers[GpRegisterType::S0];
-        assert_eq!(t, 0x2aaa_0000);
+        assert_eq!(datapath.registers[GpRegisterType::S0], 0xffffffffffffffff);
         Ok(())
     }
 
     #[test]
-    fn sign_extend_load_upper_imm_test() -> Result<(), String> {
+    fn daddiu_simple_test() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        //                                  lui    $t0   $s0      offset = 42
-        let instructions: Vec<u32> = vec![0b001111_01000_10000_1010101010101010];
+        // $s0 = $t0 + 0x4
+        //                                  daddiu    $t0   $s0          4
+        let instructions: Vec<u32> = vec![0b011001_01000_10000_0000000000000100];
         datapath.initialize(instructions)?;
+        datapath.registers[GpRegisterType::T0] = 1;
+        datapath.registers[GpRegisterType::S0] = 123;
         datapath.execute_instruction();
 
-        let t = datapath.registers[GpRegisterType::S0];
-        assert_eq!(t, 0xffff_ffff_aaaa_0000);
+        assert_eq!(datapath.registers[GpRegisterType::S0], 5);
         Ok(())
     }
-}
-pub mod store_word {
-    use super::*;
+
     #[test]
-    fn sw_zero_offset_test() -> Result<(), String> {
+    fn daddiu_overflow_test() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        //                                  sw     $t0   $s0      offset = 0
-        let instructions: Vec<u32> = vec![0b101011_01000_10000_0000000000000000];
+        // $s0 = $t0 + 0x4
+        //                                  daddiu    $t0   $s0          4
+        let instructions: Vec<u32> = vec![0b011001_01000_10000_0000000000000100];
         datapath.initialize(instructions)?;
+        datapath.registers[GpRegisterType::T0] = 0xffffffffffffffff;
+        datapath.registers[GpRegisterType::S0] = 123;
         datapath.execute_instruction();
 
-        let t = datapath.memory.load_word(0)?;
-        assert_eq!(t, 0);
+        // if there is an overflow, $s0 should not change.
+        // For the daddiu instruction, $s0 would change on overflow, it would become 3.
+        assert_eq!(datapath.registers[GpRegisterType::S0], 3);
         Ok(())
     }
 
     #[test]
-    fn sw_offset_at_4_test() -> Result<(), String> {
+    fn daddiu_sign_extend_test() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        //                                  sw     $t0   $s0      offset = 4
-        let instructions: Vec<u32> = vec![0b101011_01000_10000_0000000000000100];
+        // $s0 = $t0 + 0x1
+        //                                  daddiu    $t0   $s0          1
+        let instructions: Vec<u32> = vec![0b011001_01000_10000_0000000000000001];
         datapath.initialize(instructions)?;
-
-        datapath.registers.gpr[8] = 0;
-        datapath.registers.gpr[16] = 0xff;
+        datapath.registers[GpRegisterType::T0] = 0xfffffffffffffff1;
         datapath.execute_instruction();
 
-        let t = datapath.memory.load_word(4)?;
-        assert_eq!(t, 0xff);
+        assert_eq!(datapath.registers[GpRegisterType::S0], 0xfffffffffffffff2);
         Ok(())
     }
+}
 
+pub mod ori {
+    use super::*;
     #[test]
-    fn lw_gpr_8_at_4_offset_at_4_test() -> Result<(), String> {
+    fn or_immediate_with_zero() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        //                                  sw     $t0   $s0      offset = 4
-        let instructions: Vec<u32> = vec![0b101011_01000_10000_0000000000000100];
+        // $s0 = $zero | 12345
+        //                                  ori    $zero  $s0   12345
+        let instructions: Vec<u32> = vec![0b001101_00000_10000_0011000000111001];
         datapath.initialize(instructions)?;
 
-        datapath.registers.gpr[8] = 4;
-        datapath.registers.gpr[16] = 0xff;
         datapath.execute_instruction();
 
-        let t = datapath.memory.load_word(8)?;
-        assert_eq!(t, 0xff);
+        assert_eq!(datapath.registers.gpr[16], 12345); // $s0
         Ok(())
     }
 
     #[test]
-    fn lw_gpr_8_at_4_offset_at_neg_4_test() -> Result<(), String> {
+    fn or_immediate_with_value() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        //                                  sw     $t0   $s0      offset = -4
-        let instructions: Vec<u32> = vec![0b101011_01000_10000_1111111111111100];
+        // $s0 = $t0 | 12345
+        //                                  ori     $t0   $s0   12345
+        let instructions: Vec<u32> = vec![0b001101_01000_10000_0011000000111001];
         datapath.initialize(instructions)?;
 
-        datapath.registers.gpr[8] = 12;
-        datapath.registers.gpr[16] = 0xff;
+        // In binary: 00111010 11011110 01101000 10110001
+        datapath.registers.gpr[8] = 987654321; // $t0
+
         datapath.execute_instruction();
 
-        let t = datapath.memory.load_word(8)?;
-        assert_eq!(t, 0xff);
+        // The result should be as follows:
+        //         $t0:  00111010 11011110 01101000 10110001
+        // OR   12,345:                    00110000 00111001
+        // =================================================
+        // 987,658,425:  00111010 11011110 01111000 10111001
+
+        assert_eq!(datapath.registers.gpr[16], 987658425); // $s0
         Ok(())
     }
 }
 
-pub mod coprocessor {
-    use crate::emulation_core::datapath::Datapath;
-    use crate::emulation_core::mips::datapath::MipsDatapath;
+pub mod dadd_daddu {
+    use super::*;
 
     #[test]
-    pub fn add_float_single_precision() -> Result<(), String> {
+    fn dadd_register_to_itself() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // add.s fd, fs, ft
-        // add.s $f2, $f1, $f0
-        // FPR[2] = FPR[1] + FPR[0]
-        //                                  COP1   fmt   ft    fs    fd    function
-        //                                         s     $f0   $f1   $f2   ADD
-        let instructions: Vec<u32> = vec![0b010001_10000_00000_00001_00010_000000];
+        // dadd rd, rs, rt
+        // dadd $v0, $t5, $t5
+        // GPR[2] <- GPR[13] + GPR[13]
+        //                                 SPECIAL rs    rt    rd    0     DADD
+        //                                         13    13    2
+        let instructions: Vec<u32> = vec![0b000000_01101_01101_00010_00000_101100];
         datapath.initialize(instructions)?;
 
-        datapath.coprocessor.fpr[0] = f32::to_bits(0.25f32) as u64;
-        datapath.coprocessor.fpr[1] = f32::to_bits(0.5f32) as u64;
+        // Assume register $t5 contains 969,093,589,304, which is an integer
+        // that takes up 39 bits.
+        datapath.registers.gpr[13] = 969_093_589_304; // $t5
 
         datapath.execute_instruction();
 
-        // The result should be 0.75, represented in a 32-bit value as per the
-        // IEEE 754 single-precision floating-point specification.
-        assert_eq!(f32::from_bits(datapath.coprocessor.fpr[2] as u32), 0.75);
+        assert_eq!(datapath.registers.gpr[2], 1_938_187_178_608); // $v0
         Ok(())
     }
 
     #[test]
-    pub fn add_float_double_precision() -> Result<(), String> {
+    fn dadd_positive_overflow() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // add.d fd, fs, ft
-        // add.d $f2, $f1, $f0
-        // FPR[2] = FPR[1] + FPR[0]
-        //                                  COP1   fmt   ft    fs    fd    function
-        //                                         d     $f0   $f1   $f2   ADD
-        let instructions: Vec<u32> = vec![0b010001_10001_00000_00001_00010_000000];
+        // dadd rd, rs, rt
+        // dadd $v0, $t5, $t5
+        // GPR[2] <- GPR[13] + GPR[13]
+        //                                 SPECIAL rs    rt    rd    0     DADD
+        //                                         13    13    2
+        let instructions: Vec<u32> = vec![0b000000_01101_01101_00010_00000_101100];
         datapath.initialize(instructions)?;
 
-        datapath.coprocessor.fpr[0] = f64::to_bits(123.125);
-        datapath.coprocessor.fpr[1] = f64::to_bits(0.5);
+        // Assume register $t5 contains 18,134,889,837,812,767,690, which is an integer
+        // that takes up 64 bits.
+        datapath.registers.gpr[13] = 18_134_889_837_812_767_690; // $t5
 
         datapath.execute_instruction();
 
-        // The result should be 123.625, represented in a 64-bit value as per the
-        // IEEE 754 double-precision floating-point specification.
-        assert_eq!(f64::from_bits(datapath.coprocessor.fpr[2]), 123.625);
+        // The result is truncated to give a 64-bit value.
+        assert_eq!(datapath.registers.gpr[2], 17_823_035_601_915_983_764); // $v0
         Ok(())
     }
 
     #[test]
-    pub fn sub_float_single_precision() -> Result<(), String> {
+    fn daddu_positive_result() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // sub.s fd, fs, ft
-        // sub.s $f2, $f1, $f0
-        // FPR[fd] = FPR[fs] - FPR[ft]
-        // FPR[2] = FPR[1] - FPR[0]
-        //                                  COP1   fmt   ft    fs    fd    function
-        //                                         s     $f0   $f1   $f2   SUB
-        let instructions: Vec<u32> = vec![0b010001_10000_00000_00001_00010_000001];
+        // daddu rd, rs, rt
+        // daddu $s2, $s0, $s1
+        // GPR[18] <- GPR[16] + GPR[17]
+        //                                 SPECIAL rs    rt    rd    0     DADDU
+        //                                         16    17    18
+        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_101101];
         datapath.initialize(instructions)?;
 
-        datapath.coprocessor.fpr[0] = f32::to_bits(5.625f32) as u64;
-        datapath.coprocessor.fpr[1] = f32::to_bits(3.125f32) as u64;
+        datapath.registers.gpr[16] = 1_069_193_590_294; // $s0
+        datapath.registers.gpr[17] = 34_359_738_368; // $s1
 
         datapath.execute_instruction();
 
-        assert_eq!(f32::from_bits(datapath.coprocessor.fpr[2] as u32), -2.5);
+        assert_eq!(datapath.registers.gpr[18], 1_103_553_328_662); // $s2
         Ok(())
     }
+}
+
+pub mod dsub_dsubu {
+    use super::*;
 
     #[test]
-    pub fn sub_float_double_precision() -> Result<(), String> {
+    fn dsub_registers_positive_result() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // sub.d fd, fs, ft
-        // sub.d $f2, $f1, $f0
-        // FPR[fd] = FPR[fs] - FPR[ft]
-        // FPR[2] = FPR[1] - FPR[0]
-        //                                  COP1   fmt   ft    fs    fd    function
-        //                                         d     $f0   $f1   $f2   SUB
-        let instructions: Vec<u32> = vec![0b010001_10001_00000_00001_00010_000001];
+        // dsub rd, rs, rt
+        // dsub $s5, $s4, $s3
+        // GPR[rd] <- GPR[rs] - GPR[rt]
+        // GPR[$s5] <- GPR[$s4] - GPR[$s3]
+        // GPR[19] <- GPR[18] - GPR[17]
+        //                                 SPECIAL rs    rt    rd    0     funct
+        //                                         $s4   $s3   $s5         DSUB
+        //                                         18    17    19
+        let instructions: Vec<u32> = vec![0b000000_10010_10001_10011_00000_101110];
         datapath.initialize(instructions)?;
 
-        datapath.coprocessor.fpr[0] = f64::to_bits(438.125);
-        datapath.coprocessor.fpr[1] = f64::to_bits(98765.5);
+        // Assume registers $s3 and $s4 contain numbers larger than 32 bits,
+        // but smaller than 64 bits.
+        datapath.registers.gpr[18] = 4_833_323_886_298_794; // $s4
+        datapath.registers.gpr[17] = 163_643_849_115_304; // $s3
 
         datapath.execute_instruction();
 
-        assert_eq!(f64::from_bits(datapath.coprocessor.fpr[2]), 98327.375);
+        assert_eq!(datapath.registers.gpr[19], 4_669_680_037_183_490); // $s5
         Ok(())
     }
 
     #[test]
-    pub fn mul_float_single_precision() -> Result<(), String> {
+    fn dsub_negative_integer_underflow() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // mul.s fd, fs, ft
-        // mul.s $f9, $f5, $f4
-        // FPR[fd] = FPR[fs] * FPR[ft]
-        // FPR[9] = FPR[5] * FPR[4]
-        //                                  COP1   fmt   ft    fs    fd    function
-        //                                         s     $f4   $f5   $f9   MUL
-        let instructions: Vec<u32> = vec![0b010001_10000_00100_00101_01001_000010];
+        // dsub rd, rs, rt
+        // dsub $s5, $s4, $s3
+        // GPR[rd] <- GPR[rs] - GPR[rt]
+        // GPR[$s5] <- GPR[$s4] - GPR[$s3]
+        // GPR[19] <- GPR[18] - GPR[17]
+        //                                 SPECIAL rs    rt    rd    0     funct
+        //                                         $s4   $s3   $s5         DSUB
+        //                                         18    17    19
+        let instructions: Vec<u32> = vec![0b000000_10010_10001_10011_00000_101110];
         datapath.initialize(instructions)?;
 
-        datapath.coprocessor.fpr[5] = f32::to_bits(24.5f32) as u64;
-        datapath.coprocessor.fpr[4] = f32::to_bits(0.5f32) as u64;
+        // Assume registers $s4 is the minimum possible integer and $s4 is 1.
+        datapath.registers.gpr[18] = 0; // $s4
+        datapath.registers.gpr[17] = 1; // $s3
 
         datapath.execute_instruction();
 
-        assert_eq!(f32::from_bits(datapath.coprocessor.fpr[9] as u32), 12.25f32);
+        // Given a negative integer overflow, this should become the maximum possible unsigned integer.
+        assert_eq!(datapath.registers.gpr[19], 0xffff_ffff_ffff_ffff); // $s5
         Ok(())
     }
 
     #[test]
-    pub fn mul_float_double_precision() -> Result<(), String> {
+    fn dsubu_positive_result() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // mul.d fd, fs, ft
-        // mul.d $f4, $f6, $f9
-        // FPR[fd] = FPR[fs] * FPR[ft]
+        // dsubu rd, rs, rt
+        // dsubu $s2, $s0, $s1
+        // GPR[18] <- GPR[16] - GPR[17]
+        //                                 SPECIAL rs    rt    rd    0     DSUBU
+        //                                         16    17    18
+        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_101111];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[16] = 92_975_612_771_919; // $s0
+        datapath.registers.gpr[17] = 13_810_775_572_047; // $s1
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.gpr[18], 79_164_837_199_872); // $s2
+        Ok(())
+    }
+}
+
+pub mod dmul_dmulu {
+    use super::*;
+
+    #[test]
+    fn dmul_positive_result() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // dmul rd, rs, rt
+        // dmul $a0, $t8, $t9
+        // dmul 4, 24, 25
+        // GPR[rd] <- lo_doubleword(multiply.signed(GPR[rs] * GPR[rt]))
+        //                                 opcode  rs    rt    rd          funct
+        //                                 SPECIAL $t8   $t9   $a0   DMUL  SOP34
+        //                                         24    25    4
+        let instructions: Vec<u32> = vec![0b000000_11000_11001_00100_00010_011100];
+        datapath.initialize(instructions)?;
+
+        // Assume register $t8 contains a number larger than 32 bits,
+        // but smaller than 64 bits.
+        datapath.registers.gpr[24] = 5_861_036_283_017; // $t8
+        datapath.registers.gpr[25] = 5; // $t9
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.gpr[4], 29_305_181_415_085); // $a0
+        Ok(())
+    }
+
+    #[test]
+    fn dmul_negative_result() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // dmul rd, rs, rt
+        // dmul $s7, $t7, $t6
+        // dmul 23, 15, 14
+        // GPR[rd] <- lo_doubleword(multiply.signed(GPR[rs] * GPR[rt]))
+        //                                 opcode  rs    rt    rd          funct
+        //                                 SPECIAL $t7   $t6   $s7   DMUL  SOP34
+        //                                         15    14    23
+        let instructions: Vec<u32> = vec![0b000000_01111_01110_10111_00010_011100];
+        datapath.initialize(instructions)?;
+
+        // Assume register $t7 contains a number larger than 32 bits,
+        // but smaller than 64 bits.
+        datapath.registers.gpr[15] = 363_251_152_978_005; // $t7
+        datapath.registers.gpr[14] = -19_i64 as u64; // $t6
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.gpr[23] as i64, -6_901_771_906_582_095); // $s7
+        Ok(())
+    }
+
+    #[test]
+    fn dmul_result_truncate() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // dmul rd, rs, rt
+        // dmul $s2, $s4, $s3
+        // dmul 18, 20, 19
+        // GPR[rd] <- lo_doubleword(multiply.signed(GPR[rs] * GPR[rt]))
+        //                                 opcode  rs    rt    rd          funct
+        //                                 SPECIAL $s4   $s3   $s2   DMUL  SOP34
+        //                                         20    19    18
+        let instructions: Vec<u32> = vec![0b000000_10100_10011_10010_00010_011100];
+        datapath.initialize(instructions)?;
+
+        // Assume registers $s4 and $s3 contain numbers larger than 32 bits,
+        // but smaller than 64 bits.
+        datapath.registers.gpr[20] = 191_893_548_893_556_856; // $s4
+        datapath.registers.gpr[19] = 2_799_316_838_897; // $s3
+
+        datapath.execute_instruction();
+
+        // The result, 537,170,842,693,438,490,068,661,827,832, is too large for
+        // a 64-bit integer.
+        // (110 11000111 10110001 01001110 10000100 [00110100 01101011 00001011 00010110 11011010 00010011 11111000 11111000])
+        // The result should instead truncate to the lower 64 bits.
+        assert_eq!(datapath.registers.gpr[18], 3_777_124_905_256_220_920); // $s2
+        Ok(())
+    }
+
+    #[test]
+    fn dmulu_positive_result() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // dmulu rd, rs, rt
+        // dmulu $s2, $s0, $s1
+        // dmulu 18, 16, 17
+        // GPR[rd] <- lo_doubleword(multiply.unsigned(GPR[rs] * GPR[rt]))
+        //                                 opcode  rs    rt    rd          funct
+        //                                 SPECIAL $s0   $s1   $s2   DMULU SOP35
+        //                                         16    17    18
+        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00010_011101];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[16] = 17_592_186_044_416; // $s0
+        datapath.registers.gpr[17] = 1_000; // $s1
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.gpr[18], 17_592_186_044_416_000); // $s2
+        Ok(())
+    }
+}
+
+pub mod ddiv_ddivu {
+    use super::*;
+
+    #[test]
+    fn ddiv_positive_result() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // ddiv rd, rs, rt
+        // ddiv $s0, $s1, $s2
+        // ddiv 16, 17, 18
+        // GPR[rd] <- divide.signed(GPR[rs], GPR[rt])
+        //                                 opcode  rs    rt    rd          funct
+        //                                 SPECIAL $s1   $s2   $s0   DDIV  SOP36
+        //                                         17    18    16
+        let instructions: Vec<u32> = vec![0b000000_10001_10010_10000_00010_011110];
+
+        datapath.initialize(instructions)?;
+
+        // Assume register $s1 contains a number larger than 32 bits,
+        // but smaller than 64 bits.
+        datapath.registers.gpr[17] = 1_284_064_531_192; // $s1
+        datapath.registers.gpr[18] = 7; // $s2
+
+        datapath.execute_instruction();
+
+        // While the actual result is 183,437,790,170.285714....
+        // the decimal portion is truncated.
+        assert_eq!(datapath.registers.gpr[16], 183_437_790_170); // $s0
+        Ok(())
+    }
+
+    #[test]
+    fn ddiv_negative_result() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // ddiv rd, rs, rt
+        // ddiv $a3, $a2, $a1
+        // ddiv 7, 6, 5
+        // GPR[rd] <- divide.signed(GPR[rs], GPR[rt])
+        //                                 opcode  rs    rt    rd          funct
+        //                                 SPECIAL $a2   $a1   $a3   DDIV  SOP36
+        //                                         6     5     7
+        let instructions: Vec<u32> = vec![0b000000_00110_00101_00111_00010_011110];
+
+        datapath.initialize(instructions)?;
+
+        // Assume register $a2 contains a number larger than 32 bits,
+        // but smaller than 64 bits.
+        datapath.registers.gpr[6] = -6_245_352_518_120_328_878_i64 as u64; // $a2
+        datapath.registers.gpr[5] = 123; // $a1
+
+        datapath.execute_instruction();
+
+        // While the actual result is -50,775,223,724,555,519.333333....
+        // the decimal portion is truncated.
+        assert_eq!(datapath.registers.gpr[7] as i64, -50_775_223_724_555_519); // $a3
+        Ok(())
+    }
+
+    #[test]
+    fn ddivu_positive_result() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // ddivu rd, rs, rt
+        // ddivu $s2, $s0, $s1
+        // ddivu 18, 16, 17
+        // GPR[rd] <- divide.unsigned(GPR[rs], GPR[rt])
+        //                                 opcode  rs    rt    rd          funct
+        //                                 SPECIAL $s0   $s1   $s2   DDIVU SOP37
+        //                                         16    17    18
+        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00010_011111];
+
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[16] = 10_213_202_487_240; // $s0
+        datapath.registers.gpr[17] = 11; // $s1
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.gpr[18], 928_472_953_385); // $s2
+        Ok(())
+    }
+}
+
+pub mod dmuh_dmuhu {
+    use super::*;
+
+    #[test]
+    fn dmuh_positive_result() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // $s0 = hi($s1 * $s2)
+        //                                  R-type  s1    s2    s0    DMUH  SOP34
+        let instructions: Vec<u32> = vec![0b000000_10001_10010_10000_00011_011100];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[17] = i64::MAX as u64; // $s1
+        datapath.registers.gpr[18] = 4; // $s2
+
+        datapath.execute_instruction();
+
+        // The 128-bit product, 2^65 - 4, has an upper half of 1.
+        assert_eq!(datapath.registers.gpr[16], 1); // $s0
+        Ok(())
+    }
+
+    #[test]
+    fn dmuh_negative_result() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // $s0 = hi($s1 * $s2)
+        //                                  R-type  s1    s2    s0    DMUH  SOP34
+        let instructions: Vec<u32> = vec![0b000000_10001_10010_10000_00011_011100];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[17] = -1_i64 as u64; // $s1
+        datapath.registers.gpr[18] = 5; // $s2
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.gpr[16] as i64, -1); // $s0
+        Ok(())
+    }
+
+    #[test]
+    fn dmuhu_result() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // $s0 = hi($s1 * $s2)
+        //                                  R-type  s1    s2    s0    DMUHU SOP35
+        let instructions: Vec<u32> = vec![0b000000_10001_10010_10000_00011_011101];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[17] = u64::MAX; // $s1
+        datapath.registers.gpr[18] = u64::MAX; // $s2
+
+        datapath.execute_instruction();
+
+        // (2^64 - 1)^2 = 2^128 - 2^65 + 1, with an upper half of 2^64 - 2.
+        assert_eq!(datapath.registers.gpr[16], 0xFFFF_FFFF_FFFF_FFFE); // $s0
+        Ok(())
+    }
+}
+
+pub mod dmod_dmodu {
+    use super::*;
+
+    #[test]
+    fn dmod_negative_result() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // $s0 = $s1 % $s2
+        //                                  R-type  s1    s2    s0    DMOD  SOP36
+        let instructions: Vec<u32> = vec![0b000000_10001_10010_10000_00011_011110];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[17] = -1_284_064_531_193_i64 as u64; // $s1
+        datapath.registers.gpr[18] = 7; // $s2
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.gpr[16] as i64, -3); // $s0
+        Ok(())
+    }
+
+    #[test]
+    fn dmod_overflow() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // $s0 = $s1 % $s2
+        //                                  R-type  s1    s2    s0    DMOD  SOP36
+        let instructions: Vec<u32> = vec![0b000000_10001_10010_10000_00011_011110];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[17] = i64::MIN as u64; // $s1
+        datapath.registers.gpr[18] = -1_i64 as u64; // $s2
+
+        datapath.execute_instruction();
+
+        // The quotient would overflow, but the remainder is still 0.
+        assert_eq!(datapath.registers.gpr[16], 0); // $s0
+        Ok(())
+    }
+
+    #[test]
+    fn dmodu_positive_result() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // $s0 = $s1 % $s2
+        //                                  R-type  s1    s2    s0    DMODU SOP37
+        let instructions: Vec<u32> = vec![0b000000_10001_10010_10000_00011_011111];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[17] = 10_213_202_487_240; // $s1
+        datapath.registers.gpr[18] = 11; // $s2
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.gpr[16], 5); // $s0
+        Ok(())
+    }
+}
+
+pub mod dahi_dati {
+    use super::*;
+
+    #[test]
+    fn dahi_basic_add() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // dahi rs, immediate
+        // dahi $a0, 1
+        // GPR[rs] <- GPR[rs] + sign_extend(immediate << 32)
+        // GPR[4] <- GPR[4] + sign_extend(1 << 32)
+        //                                  op     rs    rt     immediate
+        //                                  REGIMM $a0   DAHI   1
+        let instructions: Vec<u32> = vec![0b000001_00100_00110_0000000000000001];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[4] = 0xABCD; // $a0
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.gpr[4], 0x0000_0001_0000_ABCD);
+
+        Ok(())
+    }
+
+    #[test]
+    fn dati_basic_add() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // dati rs, immediate
+        // dati $a1, 1
+        // GPR[rs] <- GPR[rs] + sign_extend(immediate << 48)
+        // GPR[5] <- GPR[5] + sign_extend(1 << 48)
+        //                                  op     rs    rt     immediate
+        //                                  REGIMM $a1   DATI   1
+        let instructions: Vec<u32> = vec![0b000001_00101_11110_0000000000000001];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[5] = 0xABCD; // $a1
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.gpr[5], 0x0001_0000_0000_ABCD);
+
+        Ok(())
+    }
+}
+
+pub mod load_word {
+    use super::*;
+    #[test]
+    fn lw_zero_offset_test() -> Result<(), String> {
+        // for this test the lw instruction will load itself from
+        // memory
+        let mut datapath = MipsDatapath::default();
+
+        //                                  lw     $t0   $s0      offset = 0
+        let instructions: Vec<u32> = vec![0b100011_01000_10000_0000000000000000];
+        datapath.initialize(instructions.clone())?;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[16], instructions[0] as u64);
+        Ok(())
+    }
+
+    #[test]
+    fn lw_offset_at_4_test() -> Result<(), String> {
+        // For this test the lw instruction will load 0x4 from memory
+        // by using the offset address plus zero
+        let mut datapath = MipsDatapath::default();
+
+        //                                  lw     $t0   $s0      offset = 4
+        let instructions: Vec<u32> = vec![0b100011_01000_10000_0000000000000100];
+        datapath.initialize(instructions)?;
+
+        // place data at address
+        datapath.memory.store_word(0b100, 0x10000)?;
+
+        datapath.registers.gpr[8] = 0;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[16], 0x10000);
+        Ok(())
+    }
+
+    #[test]
+    fn lw_gpr_8_at_4_offset_at_0_test() -> Result<(), String> {
+        // for this test the lw instruction will load 0x4 from memory
+        // by using (offset = 0) + (gpr[8] = 4)
+        let mut datapath = MipsDatapath::default();
+
+        //                                  lw     $t0   $s0      offset = 0
+        let instructions: Vec<u32> = vec![0b100011_01000_10000_0000000000000000];
+        datapath.initialize(instructions)?;
+
+        // place data at address
+        datapath.memory.store_word(0b100, 0x10000)?;
+
+        datapath.registers.gpr[8] = 4;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[16], 0x10000);
+        Ok(())
+    }
+
+    #[test]
+    fn lw_gpr_8_at_4_offset_at_4_test() -> Result<(), String> {
+        // for this test the lw instruction will load 0x8 from memory
+        // by adding the offset to gpr[8]
+        let mut datapath = MipsDatapath::default();
+
+        //                                  lw     $t0   $s0      offset = 0
+        let instructions: Vec<u32> = vec![0b100011_01000_10000_0000000000000100];
+        datapath.initialize(instructions)?;
+
+        // place data at address
+        datapath.memory.store_word(0b1000, 0x10000)?;
+
+        datapath.registers.gpr[8] = 4;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[16], 0x10000);
+        Ok(())
+    }
+
+    #[test]
+    fn lw_gpr_8_at_12_offset_at_neg_4_test() -> Result<(), String> {
+        // for this test the lw instruction will load 0x8 from memory
+        // by adding the offset to gpr[8]
+        let mut datapath = MipsDatapath::default();
+
+        //                                  lw     $t0   $s0      offset = 0
+        let instructions: Vec<u32> = vec![0b100011_01000_10000_1111111111111100];
+        datapath.initialize(instructions)?;
+
+        // place data at address
+        datapath.memory.store_word(0b1000, 0x10000)?;
+
+        datapath.registers.gpr[8] = 12;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[16], 0x10000);
+        Ok(())
+    }
+}
+
+pub mod load_upper_imm {
+    use super::*;
+
+    #[test]
+    fn basic_load_upper_imm_test() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        //                                  lui    $t0   $s0      offset = 42
+        let instructions: Vec<u32> = vec![0b001111_01000_10000_0010101010101010];
+        datapath.initialize(instructions)?;
+        datapath.execute_instruction();
+
+        let t = datapath.registers[GpRegisterType::S0];
+        assert_eq!(t, 0x2aaa_0000);
+        Ok(())
+    }
+
+    #[test]
+    fn sign_extend_load_upper_imm_test() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        //                                  lui    $t0   $s0      offset = 42
+        let instructions: Vec<u32> = vec![0b001111_01000_10000_1010101010101010];
+        datapath.initialize(instructions)?;
+        datapath.execute_instruction();
+
+        let t = datapath.registers[GpRegisterType::S0];
+        assert_eq!(t, 0xffff_ffff_aaaa_0000);
+        Ok(())
+    }
+}
+pub mod store_word {
+    use super::*;
+    #[test]
+    fn sw_zero_offset_test() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        //                                  sw     $t0   $s0      offset = 0
+        let instructions: Vec<u32> = vec![0b101011_01000_10000_0000000000000000];
+        datapath.initialize(instructions)?;
+        datapath.execute_instruction();
+
+        let t = datapath.memory.load_word(0)?;
+        assert_eq!(t, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn sw_offset_at_4_test() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        //                                  sw     $t0   $s0      offset = 4
+        let instructions: Vec<u32> = vec![0b101011_01000_10000_0000000000000100];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[8] = 0;
+        datapath.registers.gpr[16] = 0xff;
+        datapath.execute_instruction();
+
+        let t = datapath.memory.load_word(4)?;
+        assert_eq!(t, 0xff);
+        Ok(())
+    }
+
+    #[test]
+    fn lw_gpr_8_at_4_offset_at_4_test() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        //                                  sw     $t0   $s0      offset = 4
+        let instructions: Vec<u32> = vec![0b101011_01000_10000_0000000000000100];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[8] = 4;
+        datapath.registers.gpr[16] = 0xff;
+        datapath.execute_instruction();
+
+        let t = datapath.memory.load_word(8)?;
+        assert_eq!(t, 0xff);
+        Ok(())
+    }
+
+    #[test]
+    fn lw_gpr_8_at_4_offset_at_neg_4_test() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        //                                  sw     $t0   $s0      offset = -4
+        let instructions: Vec<u32> = vec![0b101011_01000_10000_1111111111111100];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[8] = 12;
+        datapath.registers.gpr[16] = 0xff;
+        datapath.execute_instruction();
+
+        let t = datapath.memory.load_word(8)?;
+        assert_eq!(t, 0xff);
+        Ok(())
+    }
+}
+
+pub mod load_store_byte_half {
+    use super::*;
+
+    #[test]
+    fn lb_sign_extends() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        //                                  lb     $t0   $s0   offset = 5
+        let instructions: Vec<u32> = vec![0b100000_01000_10000_0000000000000101];
+        datapath.initialize(instructions)?;
+
+        datapath.memory.store_byte(0x105, 0x80)?;
+
+        datapath.registers.gpr[8] = 0x100;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[16], 0xFFFF_FFFF_FFFF_FF80);
+        Ok(())
+    }
+
+    #[test]
+    fn lbu_zero_extends() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        //                                  lbu    $t0   $s0   offset = 5
+        let instructions: Vec<u32> = vec![0b100100_01000_10000_0000000000000101];
+        datapath.initialize(instructions)?;
+
+        datapath.memory.store_byte(0x105, 0x80)?;
+
+        datapath.registers.gpr[8] = 0x100;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[16], 0x80);
+        Ok(())
+    }
+
+    #[test]
+    fn lh_sign_extends() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        //                                  lh     $t0   $s0   offset = 2
+        let instructions: Vec<u32> = vec![0b100001_01000_10000_0000000000000010];
+        datapath.initialize(instructions)?;
+
+        datapath.memory.store_half_word(0x102, 0xBEEF)?;
+
+        datapath.registers.gpr[8] = 0x100;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[16], 0xFFFF_FFFF_FFFF_BEEF);
+        Ok(())
+    }
+
+    #[test]
+    fn lhu_zero_extends() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        //                                  lhu    $t0   $s0   offset = 2
+        let instructions: Vec<u32> = vec![0b100101_01000_10000_0000000000000010];
+        datapath.initialize(instructions)?;
+
+        datapath.memory.store_half_word(0x102, 0xBEEF)?;
+
+        datapath.registers.gpr[8] = 0x100;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[16], 0xBEEF);
+        Ok(())
+    }
+
+    #[test]
+    fn lh_unaligned_address_raises_exception() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        //                                  lh     $t0   $s0   offset = 1
+        let instructions: Vec<u32> = vec![0b100001_01000_10000_0000000000000001];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[8] = 0x100;
+        datapath.registers.gpr[16] = 1234;
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.gpr[16], 1234);
+        assert_eq!(datapath.system_coprocessor.bad_vaddr, 0x101);
+        assert!(datapath.is_halted());
+        Ok(())
+    }
+
+    #[test]
+    fn sb_stores_lowest_byte() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        //                                  sb     $t0   $s0   offset = 3
+        let instructions: Vec<u32> = vec![0b101000_01000_10000_0000000000000011];
+        datapath.initialize(instructions)?;
+
+        datapath.memory.store_word(0x100, 0x1111_1111)?;
+
+        datapath.registers.gpr[8] = 0x100;
+        datapath.registers.gpr[16] = 0xABCD;
+        datapath.execute_instruction();
+
+        // Only the addressed byte changes.
+        assert_eq!(datapath.memory.load_word(0x100)?, 0x1111_11CD);
+        Ok(())
+    }
+
+    #[test]
+    fn sh_stores_lowest_half_word() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        //                                  sh     $t0   $s0   offset = 2
+        let instructions: Vec<u32> = vec![0b101001_01000_10000_0000000000000010];
+        datapath.initialize(instructions)?;
+
+        datapath.memory.store_word(0x100, 0x1111_1111)?;
+
+        datapath.registers.gpr[8] = 0x100;
+        datapath.registers.gpr[16] = 0x1234_ABCD;
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.memory.load_word(0x100)?, 0x1111_ABCD);
+        Ok(())
+    }
+}
+
+pub mod load_store_double_word {
+    use super::*;
+
+    #[test]
+    fn ld_loads_full_double_word() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        //                                  ld     $t0   $s0   offset = 8
+        let instructions: Vec<u32> = vec![0b110111_01000_10000_0000000000001000];
+        datapath.initialize(instructions)?;
+
+        datapath
+            .memory
+            .store_double_word(0x108, 0xFEDC_BA98_7654_3210)?;
+
+        datapath.registers.gpr[8] = 0x100;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[16], 0xFEDC_BA98_7654_3210);
+        Ok(())
+    }
+
+    #[test]
+    fn sd_stores_full_double_word() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        //                                  sd     $t0   $s0   offset = -8
+        let instructions: Vec<u32> = vec![0b111111_01000_10000_1111111111111000];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[8] = 0x108;
+        datapath.registers.gpr[16] = 0x0123_4567_89AB_CDEF;
+        datapath.execute_instruction();
+
+        assert_eq!(
+            datapath.memory.load_double_word(0x100)?,
+            0x0123_4567_89AB_CDEF
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn sd_unaligned_address_raises_exception() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        //                                  sd     $t0   $s0   offset = 4
+        let instructions: Vec<u32> = vec![0b111111_01000_10000_0000000000000100];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[8] = 0x100;
+        datapath.registers.gpr[16] = u64::MAX;
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.memory.load_double_word(0x100)?, 0);
+        assert_eq!(datapath.system_coprocessor.bad_vaddr, 0x104);
+        assert!(datapath.is_halted());
+        Ok(())
+    }
+}
+
+pub mod coprocessor {
+    use crate::emulation_core::datapath::Datapath;
+    use crate::emulation_core::mips::datapath::MipsDatapath;
+    use crate::emulation_core::mips::system_coprocessor::ExceptionCode;
+
+    #[test]
+    pub fn add_float_single_precision() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // add.s fd, fs, ft
+        // add.s $f2, $f1, $f0
+        // FPR[2] = FPR[1] + FPR[0]
+        //                                  COP1   fmt   ft    fs    fd    function
+        //                                         s     $f0   $f1   $f2   ADD
+        let instructions: Vec<u32> = vec![0b010001_10000_00000_00001_00010_000000];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[0] = f32::to_bits(0.25f32) as u64;
+        datapath.coprocessor.fpr[1] = f32::to_bits(0.5f32) as u64;
+
+        datapath.execute_instruction();
+
+        // The result should be 0.75, represented in a 32-bit value as per the
+        // IEEE 754 single-precision floating-point specification.
+        assert_eq!(f32::from_bits(datapath.coprocessor.fpr[2] as u32), 0.75);
+        Ok(())
+    }
+
+    #[test]
+    pub fn add_float_double_precision() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // add.d fd, fs, ft
+        // add.d $f2, $f1, $f0
+        // FPR[2] = FPR[1] + FPR[0]
+        //                                  COP1   fmt   ft    fs    fd    function
+        //                                         d     $f0   $f1   $f2   ADD
+        let instructions: Vec<u32> = vec![0b010001_10001_00000_00001_00010_000000];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[0] = f64::to_bits(123.125);
+        datapath.coprocessor.fpr[1] = f64::to_bits(0.5);
+
+        datapath.execute_instruction();
+
+        // The result should be 123.625, represented in a 64-bit value as per the
+        // IEEE 754 double-precision floating-point specification.
+        assert_eq!(f64::from_bits(datapath.coprocessor.fpr[2]), 123.625);
+        Ok(())
+    }
+
+    #[test]
+    pub fn sub_float_single_precision() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // sub.s fd, fs, ft
+        // sub.s $f2, $f1, $f0
+        // FPR[fd] = FPR[fs] - FPR[ft]
+        // FPR[2] = FPR[1] - FPR[0]
+        //                                  COP1   fmt   ft    fs    fd    function
+        //                                         s     $f0   $f1   $f2   SUB
+        let instructions: Vec<u32> = vec![0b010001_10000_00000_00001_00010_000001];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[0] = f32::to_bits(5.625f32) as u64;
+        datapath.coprocessor.fpr[1] = f32::to_bits(3.125f32) as u64;
+
+        datapath.execute_instruction();
+
+        assert_eq!(f32::from_bits(datapath.coprocessor.fpr[2] as u32), -2.5);
+        Ok(())
+    }
+
+    #[test]
+    pub fn sub_float_double_precision() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // sub.d fd, fs, ft
+        // sub.d $f2, $f1, $f0
+        // FPR[fd] = FPR[fs] - FPR[ft]
+        // FPR[2] = FPR[1] - FPR[0]
+        //                                  COP1   fmt   ft    fs    fd    function
+        //                                         d     $f0   $f1   $f2   SUB
+        let instructions: Vec<u32> = vec![0b010001_10001_00000_00001_00010_000001];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[0] = f64::to_bits(438.125);
+        datapath.coprocessor.fpr[1] = f64::to_bits(98765.5);
+
+        datapath.execute_instruction();
+
+        assert_eq!(f64::from_bits(datapath.coprocessor.fpr[2]), 98327.375);
+        Ok(())
+    }
+
+    #[test]
+    pub fn mul_float_single_precision() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // mul.s fd, fs, ft
+        // mul.s $f9, $f5, $f4
+        // FPR[fd] = FPR[fs] * FPR[ft]
+        // FPR[9] = FPR[5] * FPR[4]
+        //                                  COP1   fmt   ft    fs    fd    function
+        //                                         s     $f4   $f5   $f9   MUL
+        let instructions: Vec<u32> = vec![0b010001_10000_00100_00101_01001_000010];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[5] = f32::to_bits(24.5f32) as u64;
+        datapath.coprocessor.fpr[4] = f32::to_bits(0.5f32) as u64;
+
+        datapath.execute_instruction();
+
+        assert_eq!(f32::from_bits(datapath.coprocessor.fpr[9] as u32), 12.25f32);
+        Ok(())
+    }
+
+    #[test]
+    pub fn mul_float_double_precision() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // mul.d fd, fs, ft
+        // mul.d $f4, $f6, $f9
+        // FPR[fd] = FPR[fs] * FPR[ft]
         // FPR[4] = FPR[6] * FPR[9]
         //                                  COP1   fmt   ft    fs    fd    function
         //                                         d     $f9   $f6   $f4   MUL
         let instructions: Vec<u32> = vec![0b010001_10001_01001_00110_00100_000010];
         datapath.initialize(instructions)?;
 
-        datapath.coprocessor.fpr[6] = f64::to_bits(-150.0625);
-        datapath.coprocessor.fpr[9] = f64::to_bits(9.5);
+        datapath.coprocessor.fpr[6] = f64::to_bits(-150.0625);
+        datapath.coprocessor.fpr[9] = f64::to_bits(9.5);
+
+        datapath.execute_instruction();
+
+        assert_eq!(f64::from_bits(datapath.coprocessor.fpr[4]), -1425.59375);
+        Ok(())
+    }
+
+    #[test]
+    pub fn div_float_single_precision() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // div.s fd, fs, ft
+        // div.s $f15, $f16, $f17
+        // FPR[fd] = FPR[fs] / FPR[ft]
+        // FPR[15] = FPR[16] / FPR[17]
+        //                                  COP1   fmt   ft    fs    fd    function
+        //                                         s     $f17  $f16  $f15  DIV
+        let instructions: Vec<u32> = vec![0b010001_10000_10001_10000_01111_000011];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[16] = f32::to_bits(901f32) as u64;
+        datapath.coprocessor.fpr[17] = f32::to_bits(2f32) as u64;
+
+        datapath.execute_instruction();
+
+        assert_eq!(
+            f32::from_bits(datapath.coprocessor.fpr[15] as u32),
+            450.5f32
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn div_float_double_precision() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // div.d fd, fs, ft
+        // div.d $f1, $f10, $f20
+        // FPR[fd] = FPR[fs] / FPR[ft]
+        // FPR[1] = FPR[10] / FPR[20]
+        //                                  COP1   fmt   ft    fs    fd    function
+        //                                         d     $f20  $f10  $f1   DIV
+        let instructions: Vec<u32> = vec![0b010001_10001_10100_01010_00001_000011];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[10] = f64::to_bits(95405.375);
+        datapath.coprocessor.fpr[20] = f64::to_bits(2.0);
+
+        datapath.execute_instruction();
+
+        assert_eq!(f64::from_bits(datapath.coprocessor.fpr[1]), 47702.6875);
+        Ok(())
+    }
+
+    #[test]
+    pub fn sqrt_float_single_precision() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // sqrt.s fd, fs
+        // sqrt.s $f2, $f1
+        // FPR[2] = sqrt(FPR[1])
+        //                                  COP1   fmt   ft    fs    fd    function
+        //                                         s     0     $f1   $f2   SQRT
+        let instructions: Vec<u32> = vec![0b010001_10000_00000_00001_00010_000100];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[1] = f32::to_bits(2.25f32) as u64;
+
+        datapath.execute_instruction();
+
+        assert_eq!(f32::from_bits(datapath.coprocessor.fpr[2] as u32), 1.5);
+        Ok(())
+    }
+
+    #[test]
+    pub fn cvt_d_w_converts_signed_word() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // cvt.d.w fd, fs
+        // cvt.d.w $f2, $f1
+        // FPR[2] = (double) FPR[1]
+        //                                  COP1   fmt   ft    fs    fd    function
+        //                                         w     0     $f1   $f2   CVT.D
+        let instructions: Vec<u32> = vec![0b010001_10100_00000_00001_00010_100001];
+        datapath.initialize(instructions)?;
+
+        // Only the lower 32 bits are used as the source word.
+        datapath.coprocessor.fpr[1] = 0xDEAD_BEEF_FFFF_FFF6;
+
+        datapath.execute_instruction();
+
+        assert_eq!(f64::from_bits(datapath.coprocessor.fpr[2]), -10.0);
+        Ok(())
+    }
+
+    #[test]
+    pub fn cvt_s_s_is_reserved() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        //                                  COP1   fmt   ft    fs    fd    function
+        //                                         s     0     $f1   $f2   CVT.S
+        let instructions: Vec<u32> = vec![0b010001_10000_00000_00001_00010_100000];
+        datapath.initialize(instructions)?;
+
+        datapath.execute_instruction();
+
+        assert_eq!(
+            datapath.system_coprocessor.exception,
+            Some(ExceptionCode::ReservedInstruction)
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn swc1_basic_store_no_offset() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // swc1 ft, offset(base)
+        // swc1 $f3, 0($s1)
+        // memory[GPR[base] + offset] <- FPR[ft]
+        // memory[GPR[17] + 0] <- FPR[3]
+        //                                  SWC1   base  ft    offset
+        //                                         $s1   $f3   0
+        let instructions: Vec<u32> = vec![0b111001_10001_00011_0000000000000000];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[17] = 1028; // $s1
+        datapath.coprocessor.fpr[3] = f32::to_bits(1.0625f32) as u64;
+
+        datapath.execute_instruction();
+
+        // The single-precision float 1.0625 should be stored at address 1028.
+        assert_eq!(
+            f32::from_bits(datapath.memory.load_word(1028).unwrap()),
+            1.0625f32
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn swc1_basic_store_with_offset() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // swc1 ft, offset(base)
+        // swc1 $f5, 32($s0)
+        // memory[GPR[base] + offset] <- FPR[ft]
+        // memory[GPR[16] + 32] <- FPR[5]
+        //                                  SWC1   base  ft    offset
+        //                                         $s0   $f5   32
+        let instructions: Vec<u32> = vec![0b111001_10000_00101_0000000000100000];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[16] = 2000; // $s0
+        datapath.coprocessor.fpr[5] = f32::to_bits(3.5f32) as u64;
+
+        datapath.execute_instruction();
+
+        // The single-precision float 3.5 should be stored at address 2032.
+        assert_eq!(
+            f32::from_bits(datapath.memory.load_word(2032).unwrap()),
+            3.5f32
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn swc1_basic_store_64_bit_cutoff() -> Result<(), String> {
+        // This test ensures that if there is 64-bit data in a floating-point
+        // register, only the bottom 32 bits are stored in memory with this
+        // instruction.
+
+        let mut datapath = MipsDatapath::default();
+
+        // swc1 ft, offset(base)
+        // swc1 $f0, 0($s2)
+        // memory[GPR[base] + offset] <- FPR[ft]
+        // memory[GPR[18] + 0] <- FPR[0]
+        //                                  SWC1   base  ft    offset
+        //                                         $s2   $f0   0
+        let instructions: Vec<u32> = vec![0b111001_10010_00000_0000000000000000];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[18] = 1000; // $s2
+        datapath.coprocessor.fpr[0] = f64::to_bits(9853114.625);
+
+        datapath.execute_instruction();
+
+        // The double-precision float 9853114.625 is represented in hexadecimal as
+        // 4162 CB17 5400 0000. When storing the 32-bit word, the bottom 32 bits
+        // should be stored, in this case meaning 5400 0000 in hexadecimal.
+        assert_eq!(datapath.memory.load_word(1000).unwrap(), 0x5400_0000);
+        Ok(())
+    }
+
+    #[test]
+    fn lwc1_basic_load_no_offset() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // lwc1 ft, offset(base)
+        // lwc1 $f10, 0($t0)
+        // FPR[ft] <- memory[GPR[base] + offset]
+        // FPR[10] <- memory[GPR[8] + 0]
+        //                                  LWC1   base  ft    offset
+        //                                         $t0   $f10  0
+        let instructions: Vec<u32> = vec![0b110001_01000_01010_0000000000000000];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[8] = 500; // $t0
+
+        // Data is put into memory this way (rather than using load_word()) to
+        // demonstrate no reliance on API calls.
+        let data = f32::to_bits(413.125f32).to_be_bytes();
+        for (i, byte) in data.iter().enumerate() {
+            datapath.memory.memory[500 + i] = *byte;
+        }
+
+        datapath.execute_instruction();
+
+        assert_eq!(
+            f32::from_bits(datapath.coprocessor.fpr[10] as u32),
+            413.125f32
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn lwc1_basic_load_with_offset() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // lwc1 ft, offset(base)
+        // lwc1 $f11, 200($t1)
+        // FPR[ft] <- memory[GPR[base] + offset]
+        // FPR[11] <- memory[GPR[9] + 200]
+        //                                  LWC1   base  ft    offset
+        //                                         $t1   $f11  200
+        let instructions: Vec<u32> = vec![0b110001_01001_01011_0000000011001000];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[9] = 1000; // $t1
+
+        // Data is put into memory this way (rather than using load_word()) to
+        // demonstrate no reliance on API calls.
+        let data = f32::to_bits(6.1875f32).to_be_bytes();
+        for (i, byte) in data.iter().enumerate() {
+            datapath.memory.memory[1200 + i] = *byte;
+        }
+
+        datapath.execute_instruction();
+
+        assert_eq!(
+            f32::from_bits(datapath.coprocessor.fpr[11] as u32),
+            6.1875f32
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn sdc1_basic_store_with_offset() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // sdc1 ft, offset(base)
+        // sdc1 $f5, 32($s0)
+        // memory[GPR[base] + offset] <- FPR[ft]
+        // memory[GPR[16] + 32] <- FPR[5]
+        //                                  SDC1   base  ft    offset
+        //                                         $s0   $f5   32
+        let instructions: Vec<u32> = vec![0b111101_10000_00101_0000000000100000];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[16] = 2000; // $s0
+        datapath.coprocessor.fpr[5] = f64::to_bits(-1234.5678f64);
+
+        datapath.execute_instruction();
+
+        // The whole double-precision float should be stored at address 2032.
+        assert_eq!(
+            f64::from_bits(datapath.memory.load_double_word(2032).unwrap()),
+            -1234.5678f64
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn ldc1_basic_load_with_offset() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // ldc1 ft, offset(base)
+        // ldc1 $f11, 200($t1)
+        // FPR[ft] <- memory[GPR[base] + offset]
+        // FPR[11] <- memory[GPR[9] + 200]
+        //                                  LDC1   base  ft    offset
+        //                                         $t1   $f11  200
+        let instructions: Vec<u32> = vec![0b110101_01001_01011_0000000011001000];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[9] = 1000; // $t1
+
+        let data = f64::to_bits(6.0000000001f64).to_be_bytes();
+        for (i, byte) in data.iter().enumerate() {
+            datapath.memory.memory[1200 + i] = *byte;
+        }
+
+        datapath.execute_instruction();
+
+        assert_eq!(
+            f64::from_bits(datapath.coprocessor.fpr[11]),
+            6.0000000001f64
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn ldc1_unaligned_address_raises_exception() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        //                                  LDC1   base  ft    offset
+        //                                         $t1   $f11  4
+        let instructions: Vec<u32> = vec![0b110101_01001_01011_0000000000000100];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[9] = 1000; // $t1
+        datapath.coprocessor.fpr[11] = 1234;
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.fpr[11], 1234);
+        assert_eq!(datapath.system_coprocessor.bad_vaddr, 1004);
+        assert!(datapath.is_halted());
+        Ok(())
+    }
+
+    #[test]
+    fn c_eq_s_should_be_true() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // c.eq.s fs, ft
+        // c.eq.s $f1, $f2
+        // CC[0] <- FPR[fs] == FPR[ft]
+        // CC[0] <- FPR[1] == FPR[2]
+        //                                  COP1   fmt   ft    fs    cc     __cond
+        //                                         s     $f2   $f1   0        EQ
+        let instructions: Vec<u32> = vec![0b010001_10000_00010_00001_000_00_110010];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[1] = f32::to_bits(15.5f32) as u64;
+        datapath.coprocessor.fpr[2] = f32::to_bits(15.5f32) as u64;
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.condition_code, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn c_eq_s_should_be_false() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // c.eq.s fs, ft
+        // c.eq.s $f14, $f3
+        // CC[0] <- FPR[fs] == FPR[ft]
+        // CC[0] <- FPR[14] == FPR[3]
+        //                                  COP1   fmt   ft    fs    cc     __cond
+        //                                         s     $f3   $f14  0        EQ
+        let instructions: Vec<u32> = vec![0b010001_10000_00011_01110_000_00_110010];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[14] = f32::to_bits(20.125f32) as u64;
+        datapath.coprocessor.fpr[3] = f32::to_bits(100f32) as u64;
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.condition_code, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn c_eq_d_should_be_true() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // c.eq.d fs, ft
+        // c.eq.d $f5, $f9
+        // CC[0] <- FPR[fs] == FPR[ft]
+        // CC[0] <- FPR[5] == FPR[9]
+        //                                  COP1   fmt   ft    fs    cc     __cond
+        //                                         d     $f9   $f5   0        EQ
+        let instructions: Vec<u32> = vec![0b010001_10001_01001_00101_000_00_110010];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[5] = f64::to_bits(12951.625);
+        datapath.coprocessor.fpr[9] = f64::to_bits(12951.625);
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.condition_code, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn c_eq_d_should_be_false() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // c.eq.d fs, ft
+        // c.eq.d $f15, $f19
+        // CC[0] <- FPR[fs] == FPR[ft]
+        // CC[0] <- FPR[15] == FPR[19]
+        //                                  COP1   fmt   ft    fs    cc     __cond
+        //                                         d     $f19  $f15  0        EQ
+        let instructions: Vec<u32> = vec![0b010001_10001_10011_01111_000_00_110010];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[15] = f64::to_bits(6016.25);
+        datapath.coprocessor.fpr[19] = f64::to_bits(820.43);
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.condition_code, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn c_lt_s_should_be_true() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // c.lt.s fs, ft
+        // c.lt.s $f19, $f0
+        // CC[0] <- FPR[fs] < FPR[ft]
+        // CC[0] <- FPR[19] < FPR[0]
+        //                                  COP1   fmt   ft    fs    cc     __cond
+        //                                         s     $f0   $f19  0        LT
+        let instructions: Vec<u32> = vec![0b010001_10000_00000_10011_000_00_111100];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[19] = f32::to_bits(2.875f32) as u64;
+        datapath.coprocessor.fpr[0] = f32::to_bits(70.6f32) as u64;
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.condition_code, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn c_lt_s_should_be_false() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // c.lt.s fs, ft
+        // c.lt.s $f30, $f31
+        // CC[0] <- FPR[fs] < FPR[ft]
+        // CC[0] <- FPR[30] < FPR[31]
+        //                                  COP1   fmt   ft    fs    cc     __cond
+        //                                         s     $f31  $f30  0        LT
+        let instructions: Vec<u32> = vec![0b010001_10000_11111_11110_000_00_111100];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[30] = f32::to_bits(90.7f32) as u64;
+        datapath.coprocessor.fpr[31] = f32::to_bits(-87.44f32) as u64;
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.condition_code, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn c_lt_d_should_be_true() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // c.lt.d fs, ft
+        // c.lt.d $f12, $f29
+        // CC[0] <- FPR[fs] < FPR[ft]
+        // CC[0] <- FPR[12] < FPR[29]
+        //                                  COP1   fmt   ft    fs    cc     __cond
+        //                                         d     $f29  $f12  0        LT
+        let instructions: Vec<u32> = vec![0b010001_10001_11101_01100_000_00_111100];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[12] = f64::to_bits(4.0);
+        datapath.coprocessor.fpr[29] = f64::to_bits(30000.6);
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.condition_code, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn c_lt_d_should_be_false() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // c.lt.d fs, ft
+        // c.lt.d $f4, $f5
+        // CC[0] <- FPR[fs] < FPR[ft]
+        // CC[0] <- FPR[4] < FPR[5]
+        //                                  COP1   fmt   ft    fs    cc     __cond
+        //                                         d     $f5   $f4  0        LT
+        let instructions: Vec<u32> = vec![0b010001_10001_00101_00100_000_00_111100];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[4] = f64::to_bits(413.420);
+        datapath.coprocessor.fpr[5] = f64::to_bits(-6600.9);
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.condition_code, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn c_le_s_should_be_true_less() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // c.le.s fs, ft
+        // c.le.s $f0, $f1
+        // CC[0] <- FPR[fs] <= FPR[ft]
+        // CC[0] <- FPR[0] <= FPR[1]
+        //                                  COP1   fmt   ft    fs    cc     __cond
+        //                                         s     $f1   $f0   0        LE
+        let instructions: Vec<u32> = vec![0b010001_10000_00001_00000_000_00_111110];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[0] = f32::to_bits(171.937f32) as u64;
+        datapath.coprocessor.fpr[1] = f32::to_bits(9930.829f32) as u64;
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.condition_code, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn c_le_s_should_be_true_equal() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // c.le.s fs, ft
+        // c.le.s $f2, $f3
+        // CC[0] <- FPR[fs] <= FPR[ft]
+        // CC[0] <- FPR[2] <= FPR[3]
+        //                                  COP1   fmt   ft    fs    cc     __cond
+        //                                         s     $f3   $f2   0        LE
+        let instructions: Vec<u32> = vec![0b010001_10000_00011_00010_000_00_111110];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[2] = f32::to_bits(6.5f32) as u64;
+        datapath.coprocessor.fpr[3] = f32::to_bits(6.5f32) as u64;
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.condition_code, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn c_le_s_should_be_false() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // c.le.s fs, ft
+        // c.le.s $f4, $f5
+        // CC[0] <- FPR[fs] <= FPR[ft]
+        // CC[0] <- FPR[4] <= FPR[5]
+        //                                  COP1   fmt   ft    fs    cc     __cond
+        //                                         s     $f5   $f4   0        LE
+        let instructions: Vec<u32> = vec![0b010001_10000_00101_00100_000_00_111110];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[4] = f32::to_bits(5742.006f32) as u64;
+        datapath.coprocessor.fpr[5] = f32::to_bits(1336.568f32) as u64;
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.condition_code, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn c_le_d_should_be_true_less() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // c.le.d fs, ft
+        // c.le.d $f6, $f7
+        // CC[0] <- FPR[fs] <= FPR[ft]
+        // CC[0] <- FPR[6] <= FPR[7]
+        //                                  COP1   fmt   ft    fs    cc     __cond
+        //                                         d     $f7   $f6   0        LE
+        let instructions: Vec<u32> = vec![0b010001_10001_00111_00110_000_00_111110];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[6] = f64::to_bits(3483.70216);
+        datapath.coprocessor.fpr[7] = f64::to_bits(7201.56625);
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.condition_code, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn c_le_d_should_be_true_equal() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // c.le.d fs, ft
+        // c.le.d $f8, $f9
+        // CC[0] <- FPR[fs] <= FPR[ft]
+        // CC[0] <- FPR[8] <= FPR[9]
+        //                                  COP1   fmt   ft    fs    cc     __cond
+        //                                         d     $f9   $f8   0        LE
+        let instructions: Vec<u32> = vec![0b010001_10001_01001_01000_000_00_111110];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[8] = f64::to_bits(77.4009);
+        datapath.coprocessor.fpr[9] = f64::to_bits(77.4009);
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.condition_code, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn c_le_d_should_be_false() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // c.le.d fs, ft
+        // c.le.d $f10, $f11
+        // CC[0] <- FPR[fs] <= FPR[ft]
+        // CC[0] <- FPR[10] <= FPR[11]
+        //                                  COP1   fmt   ft    fs    cc     __cond
+        //                                         d     $f11  $f10  0        LE
+        let instructions: Vec<u32> = vec![0b010001_10001_01011_01010_000_00_111110];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[10] = f64::to_bits(9190.43309);
+        datapath.coprocessor.fpr[11] = f64::to_bits(2869.57622);
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.condition_code, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn c_ngt_s_should_be_true() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // c.ngt.s fs, ft
+        // c.ngt.s $f12, $f13
+        // CC[0] <- !(FPR[fs] > FPR[ft])
+        // CC[0] <- !(FPR[12] > FPR[13])
+        //                                  COP1   fmt   ft    fs    cc     __cond
+        //                                         s     $f13  $f12  0        NGT
+        let instructions: Vec<u32> = vec![0b010001_10000_01101_01100_000_00_111111];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[12] = f32::to_bits(2469.465f32) as u64;
+        datapath.coprocessor.fpr[13] = f32::to_bits(3505.57f32) as u64;
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.condition_code, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn c_ngt_s_should_be_false() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // c.ngt.s fs, ft
+        // c.ngt.s $f14, $f15
+        // CC[0] <- !(FPR[fs] > FPR[ft])
+        // CC[0] <- !(FPR[14] > FPR[15])
+        //                                  COP1   fmt   ft    fs    cc     __cond
+        //                                         s     $f15  $f14  0        NGT
+        let instructions: Vec<u32> = vec![0b010001_10000_01111_01110_000_00_111111];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[14] = f32::to_bits(7099.472f32) as u64;
+        datapath.coprocessor.fpr[15] = f32::to_bits(87.198f32) as u64;
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.condition_code, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn c_ngt_d_should_be_true() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // c.ngt.d fs, ft
+        // c.ngt.d $f16, $f17
+        // CC[0] <- !(FPR[fs] > FPR[ft])
+        // CC[0] <- !(FPR[16] > FPR[17])
+        //                                  COP1   fmt   ft    fs    cc     __cond
+        //                                         d     $f17  $f16  0        NGT
+        let instructions: Vec<u32> = vec![0b010001_10001_10001_10000_000_00_111111];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[16] = f64::to_bits(7726.4794015);
+        datapath.coprocessor.fpr[17] = f64::to_bits(9345.7753943);
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.condition_code, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn c_ngt_d_should_be_false() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // c.ngt.d fs, ft
+        // c.ngt.d $f18, $f19
+        // CC[0] <- !(FPR[fs] > FPR[ft])
+        // CC[0] <- !(FPR[18] > FPR[19])
+        //                                  COP1   fmt   ft    fs    cc     __cond
+        //                                         d     $f19  $f18  0        NGT
+        let instructions: Vec<u32> = vec![0b010001_10001_10011_10010_000_00_111111];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[18] = f64::to_bits(4688.2854359);
+        datapath.coprocessor.fpr[19] = f64::to_bits(819.7956308);
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.condition_code, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn c_nge_s_should_be_true() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // c.nge.s fs, ft
+        // c.nge.s $f20, $f21
+        // CC[0] <- !(FPR[fs] >= FPR[ft])
+        // CC[0] <- !(FPR[20] >= FPR[21])
+        //                                  COP1   fmt   ft    fs    cc     __cond
+        //                                         s     $f21  $f20  0        NGE
+        let instructions: Vec<u32> = vec![0b010001_10000_10101_10100_000_00_111101];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[20] = f32::to_bits(3090.244f32) as u64;
+        datapath.coprocessor.fpr[21] = f32::to_bits(7396.444f32) as u64;
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.condition_code, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn c_nge_s_should_be_false() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // c.nge.s fs, ft
+        // c.nge.s $f22, $f23
+        // CC[0] <- !(FPR[fs] >= FPR[ft])
+        // CC[0] <- !(FPR[22] >= FPR[23])
+        //                                  COP1   fmt   ft    fs    cc     __cond
+        //                                         s     $f23  $f22  0        NGE
+        let instructions: Vec<u32> = vec![0b010001_10000_10111_10110_000_00_111101];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[22] = f32::to_bits(6269.823f32) as u64;
+        datapath.coprocessor.fpr[23] = f32::to_bits(3089.393f32) as u64;
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.condition_code, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn c_nge_d_should_be_true() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // c.nge.d fs, ft
+        // c.nge.d $f24, $f25
+        // CC[0] <- !(FPR[fs] >= FPR[ft])
+        // CC[0] <- !(FPR[24] >= FPR[25])
+        //                                  COP1   fmt   ft    fs    cc     __cond
+        //                                         d     $f25  $f24  0        NGE
+        let instructions: Vec<u32> = vec![0b010001_10001_11001_11000_000_00_111101];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[24] = f64::to_bits(819.7956308);
+        datapath.coprocessor.fpr[25] = f64::to_bits(4688.2854359);
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.condition_code, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn c_nge_d_should_be_false() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // c.nge.d fs, ft
+        // c.nge.d $f26, $f27
+        // CC[0] <- !(FPR[fs] >= FPR[ft])
+        // CC[0] <- !(FPR[26] >= FPR[27])
+        //                                  COP1   fmt   ft    fs    cc     __cond
+        //                                         d     $f27  $f26  0        NGE
+        let instructions: Vec<u32> = vec![0b010001_10001_11011_11010_000_00_111101];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[26] = f64::to_bits(9776.3465875);
+        datapath.coprocessor.fpr[27] = f64::to_bits(1549.8268716);
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.condition_code, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bc1t_should_branch() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        let instructions: Vec<u32> = vec![
+            // c.eq.d fs, ft
+            // c.eq.d $f5, $f9
+            // CC[0] <- FPR[fs] == FPR[ft]
+            // CC[0] <- FPR[5] == FPR[9]
+            // COP1  fmt   ft    fs    cc     __cond
+            //       d     $f9   $f5   0        EQ
+            0b010001_10001_01001_00101_000_00_110010,
+            // bc1t 0
+            // Branch to address 0 if true
+            // COP1  BC    cc nd t  offset
+            //             0        -1 (which becomes -4)
+            0b010001_01000_000_0_1_1111111111111110,
+        ];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[5] = f64::to_bits(12951.625);
+        datapath.coprocessor.fpr[9] = f64::to_bits(12951.625);
+
+        datapath.execute_instruction();
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.pc, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bc1t_should_not_branch() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        let instructions: Vec<u32> = vec![
+            // c.eq.d fs, ft
+            // c.eq.d $f5, $f9
+            // CC[0] <- FPR[fs] == FPR[ft]
+            // CC[0] <- FPR[5] == FPR[9]
+            // COP1  fmt   ft    fs    cc     __cond
+            //       d     $f9   $f5   0        EQ
+            0b010001_10001_01001_00101_000_00_110010,
+            // bc1t 0
+            // Branch to address 0 if true
+            // COP1  BC    cc nd t  offset
+            //             0        -1 (which becomes -4)
+            0b010001_01000_000_0_1_1111111111111110,
+        ];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[5] = f64::to_bits(12952.625);
+        datapath.coprocessor.fpr[9] = f64::to_bits(12951.625);
+
+        datapath.execute_instruction();
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.pc, 8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bc1f_should_branch() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        let instructions: Vec<u32> = vec![
+            // c.le.s fs, ft
+            // c.le.s $f4, $f5
+            // CC[0] <- FPR[fs] <= FPR[ft]
+            // CC[0] <- FPR[4] <= FPR[5]
+            // COP1  fmt   ft    fs    cc     __cond
+            //       s     $f5   $f4   0        LE
+            0b010001_10000_00101_00100_000_00_111110,
+            // bc1f 0
+            // Branch to address 0 if false
+            // COP1  BC    cc nd f  offset
+            //             0        -1 (which becomes -4)
+            0b010001_01000_000_0_0_1111111111111110,
+        ];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[4] = f32::to_bits(5742.006f32) as u64;
+        datapath.coprocessor.fpr[5] = f32::to_bits(1336.568f32) as u64;
+
+        datapath.execute_instruction();
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.pc, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bc1f_should_not_branch() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        let instructions: Vec<u32> = vec![
+            // c.le.s fs, ft
+            // c.le.s $f4, $f5
+            // CC[0] <- FPR[fs] <= FPR[ft]
+            // CC[0] <- FPR[4] <= FPR[5]
+            // COP1  fmt   ft    fs    cc     __cond
+            //       s     $f5   $f4   0        LE
+            0b010001_10000_00101_00100_000_00_111110,
+            // bc1f 0
+            // Branch to address 0 if false
+            // COP1  BC    cc nd f  offset
+            //             0        -1 (which becomes -4)
+            0b010001_01000_000_0_0_1111111111111110,
+        ];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[4] = f32::to_bits(742.006f32) as u64;
+        datapath.coprocessor.fpr[5] = f32::to_bits(1336.568f32) as u64;
+
+        datapath.execute_instruction();
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.pc, 8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mtc1_basic_move() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // mtc1 rt, fs
+        // mtc1 $s0, $f0
+        // FPR[fs] <- GPR[rt]
+        // FPR[0] <- GPR[16]
+        //                                  COP1   sub   rt    fs    0
+        //                                         MT    $s0   $f0
+        let instructions: Vec<u32> = vec![0b010001_00100_10000_00000_00000000000];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[16] = 25;
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.fpr[0], 25);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mtc1_truncate() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // mtc1 rt, fs
+        // mtc1 $s1, $f1
+        // FPR[fs] <- GPR[rt]
+        // FPR[1] <- GPR[17]
+        //                                  COP1   sub   rt    fs    0
+        //                                         MT    $s1   $f1
+        let instructions: Vec<u32> = vec![0b010001_00100_10001_00001_00000000000];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[17] = 0x1234_5678_ABCD_BEEF;
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.fpr[1], 0xABCD_BEEF);
+
+        Ok(())
+    }
+
+    #[test]
+    fn dmtc1_basic_move() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // dmtc1 rt, fs
+        // dmtc1 $t0, $f30
+        // FPR[fs] <- GPR[rt]
+        // FPR[30] <- GPR[8]
+        //                                  COP1   sub   rt    fs    0
+        //                                         DMT   $t0   $f30
+        let instructions: Vec<u32> = vec![0b010001_00101_01000_11110_00000000000];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[8] = 0xDEAD_BEEF_FEED_DEED;
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.coprocessor.fpr[30], 0xDEAD_BEEF_FEED_DEED);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mfc1_basic_move() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // mfc1 rt, fs
+        // mfc1 $s5, $f18
+        // GPR[rt] <- FPR[fs]
+        // GPR[21] <- FPR[18]
+        //                                  COP1   sub   rt    fs    0
+        //                                         MF    $s5   $f18
+        let instructions: Vec<u32> = vec![0b010001_00000_10101_10010_00000000000];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[18] = 123;
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.gpr[21], 123);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mfc1_truncate() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // mfc1 rt, fs
+        // mfc1 $s6, $f19
+        // GPR[rt] <- FPR[fs]
+        // GPR[22] <- FPR[19]
+        //                                  COP1   sub   rt    fs    0
+        //                                         MF    $s6   $f19
+        let instructions: Vec<u32> = vec![0b010001_00000_10110_10011_00000000000];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[19] = 0xABBA_BABB_3ABA_4444;
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.gpr[22], 0x3ABA_4444);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mfc1_sign_extend() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // mfc1 rt, fs
+        // mfc1 $s7, $f20
+        // GPR[rt] <- FPR[fs]
+        // GPR[23] <- FPR[20]
+        //                                  COP1   sub   rt    fs    0
+        //                                         MF    $s7   $f20
+        let instructions: Vec<u32> = vec![0b010001_00000_10111_10100_00000000000];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[20] = 0xBADA_BEEF_BADA_B00E;
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.gpr[23], 0xFFFF_FFFF_BADA_B00E);
+
+        Ok(())
+    }
+
+    #[test]
+    fn dmfc1_basic_move() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // dmfc1 rt, fs
+        // dmfc1 $t8, $f21
+        // GPR[rt] <- FPR[fs]
+        // GPR[24] <- FPR[21]
+        //                                  COP1   sub   rt    fs    0
+        //                                         DMF   $t8   $f21
+        let instructions: Vec<u32> = vec![0b010001_00001_11000_10101_00000000000];
+        datapath.initialize(instructions)?;
+
+        datapath.coprocessor.fpr[21] = 0xADDA_DADD_1BAA_CAFE;
+
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.gpr[24], 0xADDA_DADD_1BAA_CAFE);
+
+        Ok(())
+    }
+}
+
+pub mod jump_tests {
+    use super::*;
+    #[test]
+    fn jump_test_basic() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        //                                  J
+        let instructions: Vec<u32> = vec![0b000010_00_00000000_00000000_00000010];
+        datapath.initialize(instructions)?;
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.pc, 8);
+        Ok(())
+    }
+
+    #[test]
+    fn jump_test_mid() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        //                                  J
+        let instructions: Vec<u32> = vec![0x0800_0fff];
+        datapath.initialize(instructions)?;
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.pc, 0x3ffc);
+        Ok(())
+    }
+
+    #[test]
+    fn jump_test_hard() -> Result<(), String> {
+        // Jump to address 0xfff_fffc
+        let mut datapath = MipsDatapath::default();
+
+        //                                  J             low_26
+        let instructions: Vec<u32> = vec![0x0800_0000 | 0x03ff_ffff];
+        datapath.initialize(instructions)?;
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.pc, 0x0fff_fffc);
+        Ok(())
+    }
+}
+
+pub mod jump_and_link_tests {
+    use super::*;
+    #[test]
+    fn test_basic() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+        let old_pc = datapath.registers.pc;
+
+        //                                  J
+        let instructions: Vec<u32> = vec![0b000011_00_00000000_00000000_00000010];
+        datapath.initialize(instructions)?;
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.pc, 8);
+        assert_eq!(datapath.registers.gpr[31], old_pc + 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_mid() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+        let old_pc = datapath.registers.pc;
+
+        //                                  J
+        let instructions: Vec<u32> = vec![0x0c00_0fff];
+        datapath.initialize(instructions)?;
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.registers.pc, 0x3ffc);
+        assert_eq!(datapath.registers.gpr[31], old_pc + 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hard() -> Result<(), String> {
+        // Jump to address 0xfff_fffc
+        let mut datapath = MipsDatapath::default();
+        let old_pc = datapath.registers.pc;
 
+        //                                  J             low_26
+        let instructions: Vec<u32> = vec![0x0c00_0000 | 0x03ff_ffff];
+        datapath.initialize(instructions)?;
         datapath.execute_instruction();
 
-        assert_eq!(f64::from_bits(datapath.coprocessor.fpr[4]), -1425.59375);
+        assert_eq!(datapath.registers.pc, 0x0fff_fffc);
+        assert_eq!(datapath.registers.gpr[31], old_pc + 4);
         Ok(())
     }
+}
 
+pub mod jr_and_jalr_tests {
+    use super::*;
     #[test]
-    pub fn div_float_single_precision() -> Result<(), String> {
+    fn test_basic_jr() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // div.s fd, fs, ft
-        // div.s $f15, $f16, $f17
-        // FPR[fd] = FPR[fs] / FPR[ft]
-        // FPR[15] = FPR[16] / FPR[17]
-        //                                  COP1   fmt   ft    fs    fd    function
-        //                                         s     $f17  $f16  $f15  DIV
-        let instructions: Vec<u32> = vec![0b010001_10000_10001_10000_01111_000011];
+        // JR $r8
+        //                                  Special $r8  $zero $zero        JALR
+        let instructions: Vec<u32> = vec![0b000000_01000_00000_00000_00000_001001];
         datapath.initialize(instructions)?;
+        datapath.registers.gpr[0b01000] = 24;
+        datapath.execute_instruction();
 
-        datapath.coprocessor.fpr[16] = f32::to_bits(901f32) as u64;
-        datapath.coprocessor.fpr[17] = f32::to_bits(2f32) as u64;
+        assert_eq!(datapath.registers.pc, 24);
+        assert_eq!(datapath.registers.gpr[8], 24);
+        Ok(())
+    }
 
+    #[test]
+    fn test_basic_jalr() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // JALR $r8
+        //                                     Special  $r8  $zero $ra          JALR
+        let instructions: Vec<u32> = vec![0, 0, 0b000000_01000_00000_11111_00000_001001];
+        datapath.initialize(instructions)?;
+        datapath.registers.pc = 8;
+        let initial_pc = datapath.registers.pc;
+        datapath.registers.gpr[0b01000] = 24;
         datapath.execute_instruction();
 
-        assert_eq!(
-            f32::from_bits(datapath.coprocessor.fpr[15] as u32),
-            450.5f32
-        );
+        assert_eq!(datapath.registers.pc, 24);
+        assert_eq!(datapath.registers.gpr[31], initial_pc + 4);
         Ok(())
     }
+}
 
+pub mod beq_tests {
+    use super::*;
     #[test]
-    pub fn div_float_double_precision() -> Result<(), String> {
+    fn beq_test_basic_registers_are_equal() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // div.d fd, fs, ft
-        // div.d $f1, $f10, $f20
-        // FPR[fd] = FPR[fs] / FPR[ft]
-        // FPR[1] = FPR[10] / FPR[20]
-        //                                  COP1   fmt   ft    fs    fd    function
-        //                                         d     $f20  $f10  $f1   DIV
-        let instructions: Vec<u32> = vec![0b010001_10001_10100_01010_00001_000011];
+        //                                  beq
+        let instructions: Vec<u32> = vec![0b000100_01000_10000_0000000000000001];
         datapath.initialize(instructions)?;
 
-        datapath.coprocessor.fpr[10] = f64::to_bits(95405.375);
-        datapath.coprocessor.fpr[20] = f64::to_bits(2.0);
+        let initial_pc = datapath.registers.pc;
+        datapath.execute_instruction();
+        let expt_result = (0b0000_0000_0000_0001 << 2) + initial_pc + 4;
+        assert_eq!(datapath.registers.pc, expt_result);
+        Ok(())
+    }
+
+    #[test]
+    fn beq_test_basic_register_are_not_equal() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        //                                  beq
+        let instructions: Vec<u32> = vec![
+            0b000100_01000_10000_0000000000000001,
+            0b000100_01000_10000_0000000000000001,
+        ];
+        datapath.initialize(instructions)?;
+
+        datapath.registers.gpr[0b01000] = 1234;
+        datapath.registers.gpr[0b10000] = 4321;
 
         datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 4);
 
-        assert_eq!(f64::from_bits(datapath.coprocessor.fpr[1]), 47702.6875);
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 8);
         Ok(())
     }
 
     #[test]
-    pub fn swc1_basic_store_no_offset() -> Result<(), String> {
+    fn beq_test_basic_branch_backwards() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // swc1 ft, offset(base)
-        // swc1 $f3, 0($s1)
-        // memory[GPR[base] + offset] <- FPR[ft]
-        // memory[GPR[17] + 0] <- FPR[3]
-        //                                  SWC1   base  ft    offset
-        //                                         $s1   $f3   0
-        let instructions: Vec<u32> = vec![0b111001_10001_00011_0000000000000000];
+        let instructions: Vec<u32> = vec![
+            0b000100_01000_10000_0000000000000011, // 0x00, Branch to 0x10
+            0,                                     // 0x04
+            0,                                     // 0x08
+            0,                                     // 0x0c
+            0b000100_01000_10000_1111111111111011, // 0x10, Branch to 0x00
+        ];
         datapath.initialize(instructions)?;
+        datapath.registers.gpr[0b01000] = 1234;
+        datapath.registers.gpr[0b10000] = 1234;
 
-        datapath.registers.gpr[17] = 1028; // $s1
-        datapath.coprocessor.fpr[3] = f32::to_bits(1.0625f32) as u64;
+        let initial_pc = datapath.registers.pc;
+        let offset = 0b0000_0000_0000_0011;
+        // 0x10, aka 16
+        let expt_result = ((offset as i16 as i64 as u64) << 2)
+            .wrapping_add(initial_pc)
+            .wrapping_add(4);
+
+        datapath.execute_instruction(); // branch to address 16 from address 0
+        assert_eq!(datapath.registers.pc, expt_result);
+        assert_eq!(datapath.registers.gpr[0b01000], 1234);
+        assert_eq!(datapath.registers.gpr[0b10000], 1234);
+
+        let initial_pc = datapath.registers.pc;
+        let offset = 0b1111_1111_1111_1011;
+        // 0x00
+        let expt_result = ((offset as i16 as i64 as u64) << 2)
+            .wrapping_add(initial_pc)
+            .wrapping_add(4);
 
         datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, expt_result);
 
-        // The single-precision float 1.0625 should be stored at address 1028.
-        assert_eq!(
-            f32::from_bits(datapath.memory.load_word(1028).unwrap()),
-            1.0625f32
-        );
+        // Some loop stuff:
+        datapath.execute_instruction(); // Branch to 0x10
+        datapath.execute_instruction(); // Branch to 0x00
+        datapath.execute_instruction(); // Branch to 0x10
+        datapath.execute_instruction(); // Branch to 0x00
+        datapath.execute_instruction(); // Branch to 0x10
+        datapath.execute_instruction(); // Branch to 0x00
+        assert_eq!(datapath.registers.pc, expt_result);
+        Ok(())
+    }
+}
+
+pub mod bne_tests {
+    use super::*;
+    #[test]
+    fn bne_test_basic_registers_are_equal() -> Result<(), String> {
+        // There should be no branching, the rs and rt are equal
+
+        let mut datapath = MipsDatapath::default();
+        //                                  bne                         1 word
+        let instructions: Vec<u32> = vec![0b000101_01000_10000_0000000000000001];
+        datapath.registers.gpr[0b01000] = 1234;
+        datapath.registers.gpr[0b10000] = 1234;
+        datapath.initialize(instructions)?;
+        datapath.execute_instruction();
+        let expt_result = 4; // PC + 4, PC starts at 0 with the bne instruction at address 0, no branch acures
+        assert_eq!(datapath.registers.pc, expt_result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bne_test_loop() -> Result<(), String> {
+        // This test starts with Branching from 0x0 to 0x8.
+        // then from 0x8, at branch to 0x20.
+        // then from 0x20 back to 0x8.
+        // then from 0x8 to 0x20
+        // backcally we have a loop of branching forever
+
+        let mut datapath = MipsDatapath::default();
+        let instructions: Vec<u32> = vec![
+            0b000101_01000_10000_0000000000000001, // 0x00, Branch to 0x8
+            0,                                     // 0x04
+            0b000101_01000_10000_0000000000000101, // 0x08, Branch to 0x20
+            0,                                     // 0x0c
+            0,                                     // 0x10
+            0,                                     // 0x14
+            0,                                     // 0x18
+            0,                                     // 0x1c
+            0b000101_01000_10000_1111111111111001, // 0x20, bne r8, r16, -24, (branch -28 relative to next addres), branch to 0x08
+        ];
+        datapath.initialize(instructions)?;
+        datapath.registers.gpr[0b01000] = 1234;
+        datapath.registers.gpr[0b10000] = 4321;
+
+        // test beq going from pc = 0 to next_pc + 4, 0x0 to 0x8
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 8);
+
+        // Branch from 0x8 to 0x20, aka from 8 to 32, branch by 24
+        let initial_pc = datapath.registers.pc;
+        datapath.execute_instruction();
+        let expt_result = (0b0000000000000101 << 2) + initial_pc + 4; // 32
+        assert_eq!(datapath.registers.pc, expt_result);
+
+        // Branch back to 0x8 from 0x20, aka 32 to 8
+        // The next_pc after 32 it 36, thus our branch offset will be 8 - 36 = -28
+        //
+        // destination_addr = SOME_LABEL
+        // Branch offset = (destination_addr - next_pc)
+        let initial_pc = datapath.registers.pc;
+        let offset = 0b1111_1111_1111_1001; // -28
+                                            // 0x8
+        let expt_result = ((offset as i16 as i64 as u64) << 2)
+            .wrapping_add(initial_pc)
+            .wrapping_add(4);
+
+        datapath.execute_instruction(); // branch to 0x08
+        assert_eq!(datapath.registers.pc as i64, expt_result as i64);
+
+        // loop around a few times
+        datapath.execute_instruction(); // branch to 0x20
+        datapath.execute_instruction(); // branch to 0x08
+        datapath.execute_instruction(); // branch to 0x20
+        datapath.execute_instruction(); // branch to 0x08
+        assert_eq!(datapath.registers.pc as i64, expt_result as i64);
+
+        Ok(())
+    }
+}
+
+pub mod compare_with_zero_branch_tests {
+    use super::*;
+    use crate::emulation_core::mips::system_coprocessor::ExceptionCode;
+    #[test]
+    fn blez_branches_on_zero_and_negative() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        // blez $t0, 1
+        //                                  blez   $t0   0     offset
+        let instructions: Vec<u32> = vec![0b000110_01000_00000_0000000000000001];
+        datapath.initialize(instructions.clone())?;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 8);
+
+        datapath.reset();
+        datapath.initialize(instructions.clone())?;
+        datapath.registers.gpr[8] = -5_i64 as u64;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 8);
+
+        datapath.reset();
+        datapath.initialize(instructions)?;
+        datapath.registers.gpr[8] = 5;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 4);
         Ok(())
     }
 
     #[test]
-    pub fn swc1_basic_store_with_offset() -> Result<(), String> {
+    fn bgtz_branches_on_positive() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // swc1 ft, offset(base)
-        // swc1 $f5, 32($s0)
-        // memory[GPR[base] + offset] <- FPR[ft]
-        // memory[GPR[16] + 32] <- FPR[5]
-        //                                  SWC1   base  ft    offset
-        //                                         $s0   $f5   32
-        let instructions: Vec<u32> = vec![0b111001_10000_00101_0000000000100000];
+        // bgtz $t0, 1
+        //                                  bgtz   $t0   0     offset
+        let instructions: Vec<u32> = vec![0b000111_01000_00000_0000000000000001];
+        datapath.initialize(instructions.clone())?;
+        datapath.registers.gpr[8] = 1;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 8);
+
+        datapath.reset();
         datapath.initialize(instructions)?;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 4);
+        Ok(())
+    }
 
-        datapath.registers.gpr[16] = 2000; // $s0
-        datapath.coprocessor.fpr[5] = f32::to_bits(3.5f32) as u64;
+    #[test]
+    fn bltz_compares_all_64_bits() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
 
+        // bltz $t0, 1
+        //                                  regimm $t0   bltz  offset
+        let instructions: Vec<u32> = vec![0b000001_01000_00000_0000000000000001];
+        datapath.initialize(instructions.clone())?;
+        datapath.registers.gpr[8] = 0x8000_0000_0000_0000;
         datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 8);
 
-        // The single-precision float 3.5 should be stored at address 2032.
-        assert_eq!(
-            f32::from_bits(datapath.memory.load_word(2032).unwrap()),
-            3.5f32
-        );
+        // Only the lower 32 bits are negative.
+        datapath.reset();
+        datapath.initialize(instructions)?;
+        datapath.registers.gpr[8] = 0x0000_0000_8000_0000;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 4);
         Ok(())
     }
 
     #[test]
-    pub fn swc1_basic_store_64_bit_cutoff() -> Result<(), String> {
-        // This test ensures that if there is 64-bit data in a floating-point
-        // register, only the bottom 32 bits are stored in memory with this
-        // instruction.
-
+    fn bgez_branches_backwards() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // swc1 ft, offset(base)
-        // swc1 $f0, 0($s2)
-        // memory[GPR[base] + offset] <- FPR[ft]
-        // memory[GPR[18] + 0] <- FPR[0]
-        //                                  SWC1   base  ft    offset
-        //                                         $s2   $f0   0
-        let instructions: Vec<u32> = vec![0b111001_10010_00000_0000000000000000];
+        let instructions: Vec<u32> = vec![
+            0,                                     // 0x00
+            0b000001_01000_00001_1111111111111110, // 0x04, bgez $t0, branch to 0x00
+        ];
         datapath.initialize(instructions)?;
+        datapath.registers.pc = 4;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 0);
+        Ok(())
+    }
 
-        datapath.registers.gpr[18] = 1000; // $s2
-        datapath.coprocessor.fpr[0] = f64::to_bits(9853114.625);
+    #[test]
+    fn bltzal_always_links() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
 
+        // bltzal $t0, 3
+        //                                  regimm $t0   bltzal offset
+        let instructions: Vec<u32> = vec![0b000001_01000_10000_0000000000000011];
+        datapath.initialize(instructions.clone())?;
+        datapath.registers.gpr[8] = -1_i64 as u64;
         datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 16);
+        assert_eq!(datapath.registers.gpr[31], 4);
 
-        // The double-precision float 9853114.625 is represented in hexadecimal as
-        // 4162 CB17 5400 0000. When storing the 32-bit word, the bottom 32 bits
-        // should be stored, in this case meaning 5400 0000 in hexadecimal.
-        assert_eq!(datapath.memory.load_word(1000).unwrap(), 0x5400_0000);
+        // Not taken, but the return address is still written.
+        datapath.reset();
+        datapath.initialize(instructions)?;
+        datapath.registers.gpr[8] = 1;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 4);
+        assert_eq!(datapath.registers.gpr[31], 4);
         Ok(())
     }
 
     #[test]
-    fn lwc1_basic_load_no_offset() -> Result<(), String> {
+    fn bgezal_branches_and_links() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // lwc1 ft, offset(base)
-        // lwc1 $f10, 0($t0)
-        // FPR[ft] <- memory[GPR[base] + offset]
-        // FPR[10] <- memory[GPR[8] + 0]
-        //                                  LWC1   base  ft    offset
-        //                                         $t0   $f10  0
-        let instructions: Vec<u32> = vec![0b110001_01000_01010_0000000000000000];
+        // bgezal $zero, 2
+        //                                     regimm $zero bgezal offset
+        let instructions: Vec<u32> = vec![0, 0b000001_00000_10001_0000000000000010];
         datapath.initialize(instructions)?;
-
-        datapath.registers.gpr[8] = 500; // $t0
-
-        // Data is put into memory this way (rather than using load_word()) to
-        // demonstrate no reliance on API calls.
-        let data = f32::to_bits(413.125f32).to_be_bytes();
-        for (i, byte) in data.iter().enumerate() {
-            datapath.memory.memory[500 + i] = *byte;
-        }
-
+        datapath.registers.pc = 4;
         datapath.execute_instruction();
-
-        assert_eq!(
-            f32::from_bits(datapath.coprocessor.fpr[10] as u32),
-            413.125f32
-        );
+        assert_eq!(datapath.registers.pc, 16);
+        assert_eq!(datapath.registers.gpr[31], 8);
         Ok(())
     }
 
     #[test]
-    fn lwc1_basic_load_with_offset() -> Result<(), String> {
+    fn blez_with_nonzero_rt_is_reserved() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // lwc1 ft, offset(base)
-        // lwc1 $f11, 200($t1)
-        // FPR[ft] <- memory[GPR[base] + offset]
-        // FPR[11] <- memory[GPR[9] + 200]
-        //                                  LWC1   base  ft    offset
-        //                                         $t1   $f11  200
-        let instructions: Vec<u32> = vec![0b110001_01001_01011_0000000011001000];
+        // This encoding is `blezalc`, which is not supported.
+        //                                  blez   $t0   $t0   offset
+        let instructions: Vec<u32> = vec![0b000110_01000_01000_0000000000000001];
         datapath.initialize(instructions)?;
-
-        datapath.registers.gpr[9] = 1000; // $t1
-
-        // Data is put into memory this way (rather than using load_word()) to
-        // demonstrate no reliance on API calls.
-        let data = f32::to_bits(6.1875f32).to_be_bytes();
-        for (i, byte) in data.iter().enumerate() {
-            datapath.memory.memory[1200 + i] = *byte;
-        }
-
         datapath.execute_instruction();
-
         assert_eq!(
-            f32::from_bits(datapath.coprocessor.fpr[11] as u32),
-            6.1875f32
+            datapath.system_coprocessor.exception,
+            Some(ExceptionCode::ReservedInstruction)
         );
         Ok(())
     }
+}
 
+pub mod compact_branch_tests {
+    use super::*;
+    use crate::emulation_core::mips::system_coprocessor::ExceptionCode;
     #[test]
-    fn c_eq_s_should_be_true() -> Result<(), String> {
+    fn bltc_signed_comparison() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // c.eq.s fs, ft
-        // c.eq.s $f1, $f2
-        // CC[0] <- FPR[fs] == FPR[ft]
-        // CC[0] <- FPR[1] == FPR[2]
-        //                                  COP1   fmt   ft    fs    cc     __cond
-        //                                         s     $f2   $f1   0        EQ
-        let instructions: Vec<u32> = vec![0b010001_10000_00010_00001_000_00_110010];
+        // bltc $t0, $t1, 1
+        //                                  pop27  $t0   $t1   offset
+        let instructions: Vec<u32> = vec![0b010111_01000_01001_0000000000000001];
+        datapath.initialize(instructions.clone())?;
+        datapath.registers.gpr[8] = -2_i64 as u64;
+        datapath.registers.gpr[9] = 1;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 8);
+
+        datapath.reset();
         datapath.initialize(instructions)?;
+        datapath.registers.gpr[8] = 1;
+        datapath.registers.gpr[9] = 1;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 4);
+        Ok(())
+    }
 
-        datapath.coprocessor.fpr[1] = f32::to_bits(15.5f32) as u64;
-        datapath.coprocessor.fpr[2] = f32::to_bits(15.5f32) as u64;
+    #[test]
+    fn bgec_signed_comparison() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
 
+        // bgec $t0, $t1, 1
+        //                                  pop26  $t0   $t1   offset
+        let instructions: Vec<u32> = vec![0b010110_01000_01001_0000000000000001];
+        datapath.initialize(instructions.clone())?;
+        datapath.registers.gpr[8] = 1;
+        datapath.registers.gpr[9] = 1;
         datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 8);
 
-        assert_eq!(datapath.coprocessor.condition_code, 1);
-
+        datapath.reset();
+        datapath.initialize(instructions)?;
+        datapath.registers.gpr[8] = -2_i64 as u64;
+        datapath.registers.gpr[9] = 1;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 4);
         Ok(())
     }
 
     #[test]
-    fn c_eq_s_should_be_false() -> Result<(), String> {
+    fn beqzc_and_bnezc() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // c.eq.s fs, ft
-        // c.eq.s $f14, $f3
-        // CC[0] <- FPR[fs] == FPR[ft]
-        // CC[0] <- FPR[14] == FPR[3]
-        //                                  COP1   fmt   ft    fs    cc     __cond
-        //                                         s     $f3   $f14  0        EQ
-        let instructions: Vec<u32> = vec![0b010001_10000_00011_01110_000_00_110010];
+        let instructions: Vec<u32> = vec![
+            0b110110_01000_000000000000000000001, // 0x00, beqzc $t0, branch to 0x08
+            0,                                    // 0x04
+            0b111110_01001_111111111111111111101, // 0x08, bnezc $t1, branch to 0x00
+        ];
         datapath.initialize(instructions)?;
-
-        datapath.coprocessor.fpr[14] = f32::to_bits(20.125f32) as u64;
-        datapath.coprocessor.fpr[3] = f32::to_bits(100f32) as u64;
+        datapath.registers.gpr[9] = 1;
 
         datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 8);
 
-        assert_eq!(datapath.coprocessor.condition_code, 0);
-
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 0);
         Ok(())
     }
 
     #[test]
-    fn c_eq_d_should_be_true() -> Result<(), String> {
+    fn beqzc_with_zero_rs_is_reserved() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // c.eq.d fs, ft
-        // c.eq.d $f5, $f9
-        // CC[0] <- FPR[fs] == FPR[ft]
-        // CC[0] <- FPR[5] == FPR[9]
-        //                                  COP1   fmt   ft    fs    cc     __cond
-        //                                         d     $f9   $f5   0        EQ
-        let instructions: Vec<u32> = vec![0b010001_10001_01001_00101_000_00_110010];
+        // This encoding is `jic`, which is not supported.
+        let instructions: Vec<u32> = vec![0b110110_00000_000000000000000000001];
         datapath.initialize(instructions)?;
-
-        datapath.coprocessor.fpr[5] = f64::to_bits(12951.625);
-        datapath.coprocessor.fpr[9] = f64::to_bits(12951.625);
-
         datapath.execute_instruction();
-
-        assert_eq!(datapath.coprocessor.condition_code, 1);
-
+        assert_eq!(
+            datapath.system_coprocessor.exception,
+            Some(ExceptionCode::ReservedInstruction)
+        );
         Ok(())
     }
 
     #[test]
-    fn c_eq_d_should_be_false() -> Result<(), String> {
+    fn bc_uses_26_bit_offset() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // c.eq.d fs, ft
-        // c.eq.d $f15, $f19
-        // CC[0] <- FPR[fs] == FPR[ft]
-        // CC[0] <- FPR[15] == FPR[19]
-        //                                  COP1   fmt   ft    fs    cc     __cond
-        //                                         d     $f19  $f15  0        EQ
-        let instructions: Vec<u32> = vec![0b010001_10001_10011_01111_000_00_110010];
+        // An offset of 0x8000 words would be negative as a 16-bit offset.
+        //                                  bc     offset
+        let instructions: Vec<u32> = vec![0b110010_00000000001000000000000000];
         datapath.initialize(instructions)?;
-
-        datapath.coprocessor.fpr[15] = f64::to_bits(6016.25);
-        datapath.coprocessor.fpr[19] = f64::to_bits(820.43);
-
         datapath.execute_instruction();
-
-        assert_eq!(datapath.coprocessor.condition_code, 0);
-
+        assert_eq!(datapath.registers.pc, 4 + (0x8000 << 2));
         Ok(())
     }
 
     #[test]
-    fn c_lt_s_should_be_true() -> Result<(), String> {
+    fn balc_links_and_branches_backwards() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // c.lt.s fs, ft
-        // c.lt.s $f19, $f0
-        // CC[0] <- FPR[fs] < FPR[ft]
-        // CC[0] <- FPR[19] < FPR[0]
-        //                                  COP1   fmt   ft    fs    cc     __cond
-        //                                         s     $f0   $f19  0        LT
-        let instructions: Vec<u32> = vec![0b010001_10000_00000_10011_000_00_111100];
+        let instructions: Vec<u32> = vec![
+            0,                                   // 0x00
+            0,                                   // 0x04
+            0b111010_11111111111111111111111101, // 0x08, balc, branch to 0x00
+        ];
         datapath.initialize(instructions)?;
-
-        datapath.coprocessor.fpr[19] = f32::to_bits(2.875f32) as u64;
-        datapath.coprocessor.fpr[0] = f32::to_bits(70.6f32) as u64;
-
+        datapath.registers.pc = 8;
         datapath.execute_instruction();
-
-        assert_eq!(datapath.coprocessor.condition_code, 1);
-
+        assert_eq!(datapath.registers.pc, 0);
+        assert_eq!(datapath.registers.gpr[31], 12);
         Ok(())
     }
 
     #[test]
-    fn c_lt_s_should_be_false() -> Result<(), String> {
+    fn addi_opcode_is_beqc_in_release_6_mode() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
+        datapath.release_6 = true;
 
-        // c.lt.s fs, ft
-        // c.lt.s $f30, $f31
-        // CC[0] <- FPR[fs] < FPR[ft]
-        // CC[0] <- FPR[30] < FPR[31]
-        //                                  COP1   fmt   ft    fs    cc     __cond
-        //                                         s     $f31  $f30  0        LT
-        let instructions: Vec<u32> = vec![0b010001_10000_11111_11110_000_00_111100];
+        // beqc $t0, $t1, 1
+        //                                  pop10  $t0   $t1   offset
+        let instructions: Vec<u32> = vec![0b001000_01000_01001_0000000000000001];
+        datapath.initialize(instructions.clone())?;
+        datapath.registers.gpr[8] = 5;
+        datapath.registers.gpr[9] = 5;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 8);
+        assert_eq!(datapath.registers.gpr[9], 5);
+
+        // Release 6 mode is kept across resets.
+        datapath.reset();
+        assert!(datapath.release_6);
         datapath.initialize(instructions)?;
+        datapath.registers.gpr[8] = 5;
+        datapath.registers.gpr[9] = 6;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 4);
+        Ok(())
+    }
 
-        datapath.coprocessor.fpr[30] = f32::to_bits(90.7f32) as u64;
-        datapath.coprocessor.fpr[31] = f32::to_bits(-87.44f32) as u64;
+    #[test]
+    fn bnec() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+        datapath.release_6 = true;
 
+        // bnec $t0, $t1, 1
+        //                                  pop30  $t0   $t1   offset
+        let instructions: Vec<u32> = vec![0b011000_01000_01001_0000000000000001];
+        datapath.initialize(instructions.clone())?;
+        datapath.registers.gpr[8] = 5;
+        datapath.registers.gpr[9] = 6;
         datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 8);
 
-        assert_eq!(datapath.coprocessor.condition_code, 0);
-
+        datapath.reset();
+        datapath.initialize(instructions)?;
+        datapath.registers.gpr[8] = 5;
+        datapath.registers.gpr[9] = 5;
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 4);
         Ok(())
     }
 
     #[test]
-    fn c_lt_d_should_be_true() -> Result<(), String> {
+    fn beqzalc_and_bnezalc_always_link() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
+        datapath.release_6 = true;
 
-        // c.lt.d fs, ft
-        // c.lt.d $f12, $f29
-        // CC[0] <- FPR[fs] < FPR[ft]
-        // CC[0] <- FPR[12] < FPR[29]
-        //                                  COP1   fmt   ft    fs    cc     __cond
-        //                                         d     $f29  $f12  0        LT
-        let instructions: Vec<u32> = vec![0b010001_10001_11101_01100_000_00_111100];
+        let instructions: Vec<u32> = vec![
+            0b001000_00000_01000_0000000000000001, // 0x00, beqzalc $t0, branch to 0x08
+            0,                                     // 0x04
+            0b011000_00000_01000_1111111111111101, // 0x08, bnezalc $t0, branch to 0x00
+            0,                                     // 0x0c
+        ];
         datapath.initialize(instructions)?;
 
-        datapath.coprocessor.fpr[12] = f64::to_bits(4.0);
-        datapath.coprocessor.fpr[29] = f64::to_bits(30000.6);
-
+        // $t0 is zero, so `beqzalc` is taken and `bnezalc` is not. Both
+        // store the return address.
         datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 8);
+        assert_eq!(datapath.registers.gpr[31], 4);
 
-        assert_eq!(datapath.coprocessor.condition_code, 1);
-
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 12);
+        assert_eq!(datapath.registers.gpr[31], 12);
         Ok(())
     }
 
     #[test]
-    fn c_lt_d_should_be_false() -> Result<(), String> {
+    fn bovc_and_bnvc() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
+        datapath.release_6 = true;
 
-        // c.lt.d fs, ft
-        // c.lt.d $f4, $f5
-        // CC[0] <- FPR[fs] < FPR[ft]
-        // CC[0] <- FPR[4] < FPR[5]
-        //                                  COP1   fmt   ft    fs    cc     __cond
-        //                                         d     $f5   $f4  0        LT
-        let instructions: Vec<u32> = vec![0b010001_10001_00101_00100_000_00_111100];
-        datapath.initialize(instructions)?;
-
-        datapath.coprocessor.fpr[4] = f64::to_bits(413.420);
-        datapath.coprocessor.fpr[5] = f64::to_bits(-6600.9);
-
-        datapath.execute_instruction();
+        // bovc $t1, $t0, 1
+        //                                  pop10  $t1   $t0   offset
+        let bovc: Vec<u32> = vec![0b001000_01001_01000_0000000000000001];
+        // bnvc $t1, $t0, 1
+        //                                  pop30  $t1   $t0   offset
+        let bnvc: Vec<u32> = vec![0b011000_01001_01000_0000000000000001];
 
-        assert_eq!(datapath.coprocessor.condition_code, 0);
+        // The sum of each pair, as 32-bit values, and whether it overflows.
+        // A value that is not a sign-extended word counts as overflowing.
+        let cases = [
+            (1_u64, 2_u64, false),
+            (0x7fff_ffff, 1, true),
+            (0xffff_ffff_8000_0000, 0xffff_ffff_ffff_ffff, true),
+            (0xffff_ffff_ffff_fffe, 1, false),
+            (0x1_0000_0000, 0, true),
+        ];
 
+        for (t0, t1, overflows) in cases {
+            for (instructions, branches) in [(&bovc, overflows), (&bnvc, !overflows)] {
+                datapath.reset();
+                datapath.initialize(instructions.clone())?;
+                datapath.registers.gpr[8] = t0;
+                datapath.registers.gpr[9] = t1;
+
+                datapath.execute_instruction();
+                assert_eq!(datapath.registers.pc, if branches { 8 } else { 4 });
+                assert_eq!(datapath.system_coprocessor.exception, None);
+                assert_eq!(datapath.registers.gpr[31], 0);
+            }
+        }
         Ok(())
     }
 
     #[test]
-    fn c_le_s_should_be_true_less() -> Result<(), String> {
+    fn daddi_opcode_does_not_trap_in_release_6_mode() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
+        datapath.release_6 = true;
 
-        // c.le.s fs, ft
-        // c.le.s $f0, $f1
-        // CC[0] <- FPR[fs] <= FPR[ft]
-        // CC[0] <- FPR[0] <= FPR[1]
-        //                                  COP1   fmt   ft    fs    cc     __cond
-        //                                         s     $f1   $f0   0        LE
-        let instructions: Vec<u32> = vec![0b010001_10000_00001_00000_000_00_111110];
+        // With `rs` greater than `rt`, this is `bnvc $t1, $t0, 1` rather
+        // than `daddi $t0, $t1, 1`, which would overflow.
+        //                                  pop30  $t1   $t0   offset
+        let instructions: Vec<u32> = vec![0b011000_01001_01000_0000000000000001];
         datapath.initialize(instructions)?;
-
-        datapath.coprocessor.fpr[0] = f32::to_bits(171.937f32) as u64;
-        datapath.coprocessor.fpr[1] = f32::to_bits(9930.829f32) as u64;
+        datapath.registers.gpr[9] = i64::MAX as u64;
 
         datapath.execute_instruction();
-
-        assert_eq!(datapath.coprocessor.condition_code, 1);
-
+        assert_eq!(datapath.system_coprocessor.exception, None);
+        assert_eq!(datapath.registers.gpr[8], 0);
+        assert_eq!(datapath.registers.pc, 4);
         Ok(())
     }
+}
+
+pub mod branch_delay_slots {
+    use super::*;
+    use crate::emulation_core::mips::system_coprocessor::{ExceptionCode, CAUSE_BD};
+
+    // ori $t0, $t0, 1
+    // ORI    t0    t0    immediate
+    const ORI_T0: u32 = 0b001101_01000_01000_0000000000000001;
 
     #[test]
-    fn c_le_s_should_be_true_equal() -> Result<(), String> {
+    fn delay_slot_executes_before_jump() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
+        datapath.branch_delay_slots = true;
 
-        // c.le.s fs, ft
-        // c.le.s $f2, $f3
-        // CC[0] <- FPR[fs] <= FPR[ft]
-        // CC[0] <- FPR[2] <= FPR[3]
-        //                                  COP1   fmt   ft    fs    cc     __cond
-        //                                         s     $f3   $f2   0        LE
-        let instructions: Vec<u32> = vec![0b010001_10000_00011_00010_000_00_111110];
+        let instructions: Vec<u32> = vec![
+            0b000010_00000000000000000000000011, // 0x00, j 0x0c
+            ORI_T0,                              // 0x04, delay slot
+            0,                                   // 0x08
+            0,                                   // 0x0c
+        ];
         datapath.initialize(instructions)?;
 
-        datapath.coprocessor.fpr[2] = f32::to_bits(6.5f32) as u64;
-        datapath.coprocessor.fpr[3] = f32::to_bits(6.5f32) as u64;
-
         datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 4);
+        assert_eq!(datapath.delay_slot_target, Some(12));
 
-        assert_eq!(datapath.coprocessor.condition_code, 1);
-
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[8], 1);
+        assert_eq!(datapath.registers.pc, 12);
+        assert_eq!(datapath.delay_slot_target, None);
         Ok(())
     }
 
     #[test]
-    fn c_le_s_should_be_false() -> Result<(), String> {
+    fn jal_stores_pc_plus_8() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
+        datapath.branch_delay_slots = true;
 
-        // c.le.s fs, ft
-        // c.le.s $f4, $f5
-        // CC[0] <- FPR[fs] <= FPR[ft]
-        // CC[0] <- FPR[4] <= FPR[5]
-        //                                  COP1   fmt   ft    fs    cc     __cond
-        //                                         s     $f5   $f4   0        LE
-        let instructions: Vec<u32> = vec![0b010001_10000_00101_00100_000_00_111110];
+        //                                  JAL    address
+        let instructions: Vec<u32> = vec![0b000011_00000000000000000000000011, 0, 0, 0];
         datapath.initialize(instructions)?;
-
-        datapath.coprocessor.fpr[4] = f32::to_bits(5742.006f32) as u64;
-        datapath.coprocessor.fpr[5] = f32::to_bits(1336.568f32) as u64;
-
+        datapath.execute_instruction();
         datapath.execute_instruction();
 
-        assert_eq!(datapath.coprocessor.condition_code, 0);
-
+        assert_eq!(datapath.registers.gpr[31], 8);
+        assert_eq!(datapath.registers.pc, 12);
         Ok(())
     }
 
     #[test]
-    fn c_le_d_should_be_true_less() -> Result<(), String> {
+    fn untaken_branch_continues_normally() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
+        datapath.branch_delay_slots = true;
 
-        // c.le.d fs, ft
-        // c.le.d $f6, $f7
-        // CC[0] <- FPR[fs] <= FPR[ft]
-        // CC[0] <- FPR[6] <= FPR[7]
-        //                                  COP1   fmt   ft    fs    cc     __cond
-        //                                         d     $f7   $f6   0        LE
-        let instructions: Vec<u32> = vec![0b010001_10001_00111_00110_000_00_111110];
+        let instructions: Vec<u32> = vec![
+            0b000101_00000_00000_0000000000000010, // 0x00, bne $zero, $zero, 0x0c
+            ORI_T0,                                // 0x04
+            0,                                     // 0x08
+        ];
         datapath.initialize(instructions)?;
 
-        datapath.coprocessor.fpr[6] = f64::to_bits(3483.70216);
-        datapath.coprocessor.fpr[7] = f64::to_bits(7201.56625);
-
         datapath.execute_instruction();
+        assert_eq!(datapath.registers.pc, 4);
+        assert_eq!(datapath.delay_slot_target, None);
 
-        assert_eq!(datapath.coprocessor.condition_code, 1);
-
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[8], 1);
+        assert_eq!(datapath.registers.pc, 8);
         Ok(())
     }
 
     #[test]
-    fn c_le_d_should_be_true_equal() -> Result<(), String> {
+    fn compact_branch_has_no_delay_slot() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
+        datapath.branch_delay_slots = true;
 
-        // c.le.d fs, ft
-        // c.le.d $f8, $f9
-        // CC[0] <- FPR[fs] <= FPR[ft]
-        // CC[0] <- FPR[8] <= FPR[9]
-        //                                  COP1   fmt   ft    fs    cc     __cond
-        //                                         d     $f9   $f8   0        LE
-        let instructions: Vec<u32> = vec![0b010001_10001_01001_01000_000_00_111110];
+        let instructions: Vec<u32> = vec![
+            0b111010_00000000000000000000000001, // 0x00, balc 0x08
+            ORI_T0,                              // 0x04
+            0,                                   // 0x08
+        ];
         datapath.initialize(instructions)?;
 
-        datapath.coprocessor.fpr[8] = f64::to_bits(77.4009);
-        datapath.coprocessor.fpr[9] = f64::to_bits(77.4009);
-
         datapath.execute_instruction();
-
-        assert_eq!(datapath.coprocessor.condition_code, 1);
-
+        assert_eq!(datapath.registers.pc, 8);
+        assert_eq!(datapath.registers.gpr[31], 4);
+        assert_eq!(datapath.registers.gpr[8], 0);
         Ok(())
     }
 
     #[test]
-    fn c_le_d_should_be_false() -> Result<(), String> {
+    fn branch_in_delay_slot_is_reserved() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
+        datapath.branch_delay_slots = true;
 
-        // c.le.d fs, ft
-        // c.le.d $f10, $f11
-        // CC[0] <- FPR[fs] <= FPR[ft]
-        // CC[0] <- FPR[10] <= FPR[11]
-        //                                  COP1   fmt   ft    fs    cc     __cond
-        //                                         d     $f11  $f10  0        LE
-        let instructions: Vec<u32> = vec![0b010001_10001_01011_01010_000_00_111110];
+        let instructions: Vec<u32> = vec![
+            0b000010_00000000000000000000000011, // 0x00, j 0x0c
+            0b000010_00000000000000000000000011, // 0x04, j 0x0c
+        ];
         datapath.initialize(instructions)?;
 
-        datapath.coprocessor.fpr[10] = f64::to_bits(9190.43309);
-        datapath.coprocessor.fpr[11] = f64::to_bits(2869.57622);
-
         datapath.execute_instruction();
-
-        assert_eq!(datapath.coprocessor.condition_code, 0);
-
+        datapath.execute_instruction();
+        assert_eq!(
+            datapath.system_coprocessor.exception,
+            Some(ExceptionCode::ReservedInstruction)
+        );
+        assert!(datapath.is_halted());
         Ok(())
     }
 
     #[test]
-    fn c_ngt_s_should_be_true() -> Result<(), String> {
+    fn exception_in_delay_slot_records_branch() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
+        datapath.branch_delay_slots = true;
 
-        // c.ngt.s fs, ft
-        // c.ngt.s $f12, $f13
-        // CC[0] <- !(FPR[fs] > FPR[ft])
-        // CC[0] <- !(FPR[12] > FPR[13])
-        //                                  COP1   fmt   ft    fs    cc     __cond
-        //                                         s     $f13  $f12  0        NGT
-        let instructions: Vec<u32> = vec![0b010001_10000_01101_01100_000_00_111111];
+        let instructions: Vec<u32> = vec![
+            0,                                     // 0x00
+            0b000100_00000_00000_0000000000000010, // 0x04, beq $zero, $zero, 0x10
+            // SPECIAL t4    t4    t1  (shamt) ADD
+            0b000000_01100_01100_01001_00000_100000, // 0x08, add $t1, $t4, $t4
+            0,                                       // 0x0c, exception handler
+        ];
         datapath.initialize(instructions)?;
+        datapath.system_coprocessor.exception_handler = Some(12);
+        datapath.registers.gpr[12] = 0x7fff_ffff; // $t4
+        datapath.registers.pc = 4;
 
-        datapath.coprocessor.fpr[12] = f32::to_bits(2469.465f32) as u64;
-        datapath.coprocessor.fpr[13] = f32::to_bits(3505.57f32) as u64;
-
+        datapath.execute_instruction();
         datapath.execute_instruction();
 
-        assert_eq!(datapath.coprocessor.condition_code, 1);
+        assert_eq!(datapath.registers.pc, 12);
+        assert_eq!(datapath.delay_slot_target, None);
+        assert_eq!(datapath.system_coprocessor.epc, 4);
+        assert_ne!(datapath.system_coprocessor.cause & CAUSE_BD, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn setting_is_kept_across_initialize() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+        datapath.branch_delay_slots = true;
 
+        datapath.initialize(vec![0])?;
+        assert!(datapath.branch_delay_slots);
         Ok(())
     }
+}
+
+pub mod syscall {
+    use super::*;
+    use crate::emulation_core::mips::syscall::BufferedSyscallHost;
+    use std::cell::RefCell;
+    use std::rc::Rc;
 
     #[test]
-    fn c_ngt_s_should_be_false() -> Result<(), String> {
+    fn halts_on_syscall() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // c.ngt.s fs, ft
-        // c.ngt.s $f14, $f15
-        // CC[0] <- !(FPR[fs] > FPR[ft])
-        // CC[0] <- !(FPR[14] > FPR[15])
-        //                                  COP1   fmt   ft    fs    cc     __cond
-        //                                         s     $f15  $f14  0        NGT
-        let instructions: Vec<u32> = vec![0b010001_10000_01111_01110_000_00_111111];
-        datapath.initialize(instructions)?;
+        assert!(datapath.is_halted());
 
-        datapath.coprocessor.fpr[14] = f32::to_bits(7099.472f32) as u64;
-        datapath.coprocessor.fpr[15] = f32::to_bits(87.198f32) as u64;
+        // This program doubles the value in $t1 and stops.
 
-        datapath.execute_instruction();
+        let instructions: Vec<u32> = vec![
+            // $t1 = $t1 + $t1
+            // SPECIAL t1   t1    t1  (shamt)  ADD
+            0b000000_01001_01001_01001_00000_100000,
+            // syscall
+            // SPECIAL     (code)        SYSCALL
+            0b000000_00000000000000000000_001100,
+        ];
+        datapath.initialize(instructions)?;
+        assert!(!datapath.is_halted());
 
-        assert_eq!(datapath.coprocessor.condition_code, 0);
+        datapath.registers.gpr[9] = 5; // $t1
+
+        // Execute 2 instructions.
+        for _ in 0..2 {
+            datapath.execute_instruction();
+        }
 
+        assert_eq!(datapath.registers.gpr[9], 10); // $t1
+        assert!(datapath.is_halted());
         Ok(())
     }
 
-    #[test]
-    fn c_ngt_d_should_be_true() -> Result<(), String> {
+    // syscall
+    // SPECIAL     (code)        SYSCALL
+    const SYSCALL: u32 = 0b000000_00000000000000000000_001100;
+
+    /// Create a datapath that runs a single `syscall` instruction with the
+    /// given service in `$v0`, with its output going to a new [`BufferedSyscallHost`].
+    fn syscall_datapath(
+        service: u64,
+    ) -> Result<(MipsDatapath, Rc<RefCell<BufferedSyscallHost>>), String> {
         let mut datapath = MipsDatapath::default();
+        let host = Rc::new(RefCell::new(BufferedSyscallHost::default()));
+        datapath.set_syscall_host(host.clone());
 
-        // c.ngt.d fs, ft
-        // c.ngt.d $f16, $f17
-        // CC[0] <- !(FPR[fs] > FPR[ft])
-        // CC[0] <- !(FPR[16] > FPR[17])
-        //                                  COP1   fmt   ft    fs    cc     __cond
-        //                                         d     $f17  $f16  0        NGT
-        let instructions: Vec<u32> = vec![0b010001_10001_10001_10000_000_00_111111];
-        datapath.initialize(instructions)?;
+        datapath.initialize(vec![SYSCALL, SYSCALL])?;
+        datapath.registers[GpRegisterType::V0] = service;
 
-        datapath.coprocessor.fpr[16] = f64::to_bits(7726.4794015);
-        datapath.coprocessor.fpr[17] = f64::to_bits(9345.7753943);
+        Ok((datapath, host))
+    }
 
-        datapath.execute_instruction();
+    #[test]
+    fn print_int() -> Result<(), String> {
+        let (mut datapath, host) = syscall_datapath(1)?;
+        datapath.registers[GpRegisterType::A0] = -1234_i64 as u64;
 
-        assert_eq!(datapath.coprocessor.condition_code, 1);
+        datapath.execute_instruction();
 
+        assert_eq!(host.borrow().output, "-1234");
+        assert!(!datapath.is_halted());
+        assert_eq!(datapath.registers.pc, 4);
         Ok(())
     }
 
     #[test]
-    fn c_ngt_d_should_be_false() -> Result<(), String> {
-        let mut datapath = MipsDatapath::default();
+    fn print_float_and_double() -> Result<(), String> {
+        let (mut datapath, host) = syscall_datapath(2)?;
+        datapath.coprocessor.fpr[12] = f32::to_bits(2.5) as u64;
 
-        // c.ngt.d fs, ft
-        // c.ngt.d $f18, $f19
-        // CC[0] <- !(FPR[fs] > FPR[ft])
-        // CC[0] <- !(FPR[18] > FPR[19])
-        //                                  COP1   fmt   ft    fs    cc     __cond
-        //                                         d     $f19  $f18  0        NGT
-        let instructions: Vec<u32> = vec![0b010001_10001_10011_10010_000_00_111111];
-        datapath.initialize(instructions)?;
+        datapath.execute_instruction();
 
-        datapath.coprocessor.fpr[18] = f64::to_bits(4688.2854359);
-        datapath.coprocessor.fpr[19] = f64::to_bits(819.7956308);
+        datapath.registers[GpRegisterType::V0] = 3;
+        datapath.coprocessor.fpr[12] = f64::to_bits(-0.125);
 
         datapath.execute_instruction();
 
-        assert_eq!(datapath.coprocessor.condition_code, 0);
-
+        assert_eq!(host.borrow().output, "2.5-0.125");
         Ok(())
     }
 
     #[test]
-    fn c_nge_s_should_be_true() -> Result<(), String> {
-        let mut datapath = MipsDatapath::default();
-
-        // c.nge.s fs, ft
-        // c.nge.s $f20, $f21
-        // CC[0] <- !(FPR[fs] >= FPR[ft])
-        // CC[0] <- !(FPR[20] >= FPR[21])
-        //                                  COP1   fmt   ft    fs    cc     __cond
-        //                                         s     $f21  $f20  0        NGE
-        let instructions: Vec<u32> = vec![0b010001_10000_10101_10100_000_00_111101];
-        datapath.initialize(instructions)?;
+    fn print_string() -> Result<(), String> {
+        let (mut datapath, host) = syscall_datapath(4)?;
 
-        datapath.coprocessor.fpr[20] = f32::to_bits(3090.244f32) as u64;
-        datapath.coprocessor.fpr[21] = f32::to_bits(7396.444f32) as u64;
+        let address = 0x100;
+        for (i, byte) in b"Hello, world!\n\0".iter().enumerate() {
+            datapath.memory.memory[address + i] = *byte;
+        }
+        datapath.registers[GpRegisterType::A0] = address as u64;
 
         datapath.execute_instruction();
 
-        assert_eq!(datapath.coprocessor.condition_code, 1);
-
+        assert_eq!(host.borrow().output, "Hello, world!\n");
         Ok(())
     }
 
     #[test]
-    fn c_nge_s_should_be_false() -> Result<(), String> {
-        let mut datapath = MipsDatapath::default();
+    fn read_int() -> Result<(), String> {
+        let (mut datapath, host) = syscall_datapath(5)?;
+        host.borrow_mut().input.push_back(String::from(" -42 "));
 
-        // c.nge.s fs, ft
-        // c.nge.s $f22, $f23
-        // CC[0] <- !(FPR[fs] >= FPR[ft])
-        // CC[0] <- !(FPR[22] >= FPR[23])
-        //                                  COP1   fmt   ft    fs    cc     __cond
-        //                                         s     $f23  $f22  0        NGE
-        let instructions: Vec<u32> = vec![0b010001_10000_10111_10110_000_00_111101];
-        datapath.initialize(instructions)?;
+        datapath.execute_instruction();
 
-        datapath.coprocessor.fpr[22] = f32::to_bits(6269.823f32) as u64;
-        datapath.coprocessor.fpr[23] = f32::to_bits(3089.393f32) as u64;
+        assert_eq!(datapath.registers[GpRegisterType::V0] as i64, -42);
+        assert!(!datapath.is_halted());
+        Ok(())
+    }
 
-        datapath.execute_instruction();
+    #[test]
+    fn read_int_without_input_halts() -> Result<(), String> {
+        let (mut datapath, _) = syscall_datapath(5)?;
 
-        assert_eq!(datapath.coprocessor.condition_code, 0);
+        datapath.execute_instruction();
 
+        assert!(datapath.is_halted());
+        assert_eq!(
+            datapath.error_message.as_deref(),
+            Some("No integer was available for `read_int`")
+        );
         Ok(())
     }
 
     #[test]
-    fn c_nge_d_should_be_true() -> Result<(), String> {
-        let mut datapath = MipsDatapath::default();
-
-        // c.nge.d fs, ft
-        // c.nge.d $f24, $f25
-        // CC[0] <- !(FPR[fs] >= FPR[ft])
-        // CC[0] <- !(FPR[24] >= FPR[25])
-        //                                  COP1   fmt   ft    fs    cc     __cond
-        //                                         d     $f25  $f24  0        NGE
-        let instructions: Vec<u32> = vec![0b010001_10001_11001_11000_000_00_111101];
-        datapath.initialize(instructions)?;
+    fn read_string() -> Result<(), String> {
+        let (mut datapath, host) = syscall_datapath(8)?;
+        host.borrow_mut().input.push_back(String::from("abc"));
 
-        datapath.coprocessor.fpr[24] = f64::to_bits(819.7956308);
-        datapath.coprocessor.fpr[25] = f64::to_bits(4688.2854359);
+        let address = 0x100;
+        datapath.registers[GpRegisterType::A0] = address as u64;
+        datapath.registers[GpRegisterType::A1] = 16;
 
         datapath.execute_instruction();
 
-        assert_eq!(datapath.coprocessor.condition_code, 1);
-
+        assert_eq!(&datapath.memory.memory[address..address + 5], b"abc\n\0");
         Ok(())
     }
 
     #[test]
-    fn c_nge_d_should_be_false() -> Result<(), String> {
-        let mut datapath = MipsDatapath::default();
-
-        // c.nge.d fs, ft
-        // c.nge.d $f26, $f27
-        // CC[0] <- !(FPR[fs] >= FPR[ft])
-        // CC[0] <- !(FPR[26] >= FPR[27])
-        //                                  COP1   fmt   ft    fs    cc     __cond
-        //                                         d     $f27  $f26  0        NGE
-        let instructions: Vec<u32> = vec![0b010001_10001_11011_11010_000_00_111101];
-        datapath.initialize(instructions)?;
+    fn read_string_truncates_to_buffer_length() -> Result<(), String> {
+        let (mut datapath, host) = syscall_datapath(8)?;
+        host.borrow_mut().input.push_back(String::from("abcdef"));
 
-        datapath.coprocessor.fpr[26] = f64::to_bits(9776.3465875);
-        datapath.coprocessor.fpr[27] = f64::to_bits(1549.8268716);
+        let address = 0x100;
+        datapath.memory.memory[address + 4] = 0xff;
+        datapath.registers[GpRegisterType::A0] = address as u64;
+        datapath.registers[GpRegisterType::A1] = 4;
 
         datapath.execute_instruction();
 
-        assert_eq!(datapath.coprocessor.condition_code, 0);
-
+        // Only 3 characters fit before the null terminator. The rest of
+        // memory is left untouched.
+        assert_eq!(&datapath.memory.memory[address..address + 5], b"abc\0\xff");
         Ok(())
     }
 
     #[test]
-    fn bc1t_should_branch() -> Result<(), String> {
-        let mut datapath = MipsDatapath::default();
+    fn sbrk_allocates_after_program() -> Result<(), String> {
+        let (mut datapath, _) = syscall_datapath(9)?;
+        datapath.registers[GpRegisterType::A0] = 10;
 
-        let instructions: Vec<u32> = vec![
-            // c.eq.d fs, ft
-            // c.eq.d $f5, $f9
-            // CC[0] <- FPR[fs] == FPR[ft]
-            // CC[0] <- FPR[5] == FPR[9]
-            // COP1  fmt   ft    fs    cc     __cond
-            //       d     $f9   $f5   0        EQ
-            0b010001_10001_01001_00101_000_00_110010,
-            // bc1t 0
-            // Branch to address 0 if true
-            // COP1  BC    cc nd t  offset
-            //             0        -1 (which becomes -4)
-            0b010001_01000_000_0_1_1111111111111110,
-        ];
-        datapath.initialize(instructions)?;
+        datapath.execute_instruction();
 
-        datapath.coprocessor.fpr[5] = f64::to_bits(12951.625);
-        datapath.coprocessor.fpr[9] = f64::to_bits(12951.625);
+        // The program is two instructions long, so the heap starts at address 8.
+        assert_eq!(datapath.registers[GpRegisterType::V0], 8);
 
+        datapath.registers[GpRegisterType::V0] = 9;
         datapath.execute_instruction();
-        datapath.execute_instruction();
-
-        assert_eq!(datapath.registers.pc, 0);
 
+        // The previous allocation is rounded up to a multiple of 4 bytes.
+        assert_eq!(datapath.registers[GpRegisterType::V0], 20);
         Ok(())
     }
 
     #[test]
-    fn bc1t_should_not_branch() -> Result<(), String> {
-        let mut datapath = MipsDatapath::default();
+    fn exit_halts() -> Result<(), String> {
+        let (mut datapath, _) = syscall_datapath(10)?;
 
-        let instructions: Vec<u32> = vec![
-            // c.eq.d fs, ft
-            // c.eq.d $f5, $f9
-            // CC[0] <- FPR[fs] == FPR[ft]
-            // CC[0] <- FPR[5] == FPR[9]
-            // COP1  fmt   ft    fs    cc     __cond
-            //       d     $f9   $f5   0        EQ
-            0b010001_10001_01001_00101_000_00_110010,
-            // bc1t 0
-            // Branch to address 0 if true
-            // COP1  BC    cc nd t  offset
-            //             0        -1 (which becomes -4)
-            0b010001_01000_000_0_1_1111111111111110,
-        ];
-        datapath.initialize(instructions)?;
+        datapath.execute_instruction();
 
-        datapath.coprocessor.fpr[5] = f64::to_bits(12952.625);
-        datapath.coprocessor.fpr[9] = f64::to_bits(12951.625);
+        assert!(datapath.is_halted());
+        assert_eq!(datapath.exit_code, None);
+        Ok(())
+    }
 
-        datapath.execute_instruction();
-        datapath.execute_instruction();
+    #[test]
+    fn exit2_halts_with_exit_code() -> Result<(), String> {
+        let (mut datapath, _) = syscall_datapath(17)?;
+        datapath.registers[GpRegisterType::A0] = 3;
 
-        assert_eq!(datapath.registers.pc, 8);
+        datapath.execute_instruction();
 
+        assert!(datapath.is_halted());
+        assert_eq!(datapath.exit_code, Some(3));
         Ok(())
     }
 
     #[test]
-    fn bc1f_should_branch() -> Result<(), String> {
-        let mut datapath = MipsDatapath::default();
-
-        let instructions: Vec<u32> = vec![
-            // c.le.s fs, ft
-            // c.le.s $f4, $f5
-            // CC[0] <- FPR[fs] <= FPR[ft]
-            // CC[0] <- FPR[4] <= FPR[5]
-            // COP1  fmt   ft    fs    cc     __cond
-            //       s     $f5   $f4   0        LE
-            0b010001_10000_00101_00100_000_00_111110,
-            // bc1f 0
-            // Branch to address 0 if false
-            // COP1  BC    cc nd f  offset
-            //             0        -1 (which becomes -4)
-            0b010001_01000_000_0_0_1111111111111110,
-        ];
-        datapath.initialize(instructions)?;
+    fn host_is_kept_after_reset() -> Result<(), String> {
+        let (mut datapath, host) = syscall_datapath(1)?;
 
-        datapath.coprocessor.fpr[4] = f32::to_bits(5742.006f32) as u64;
-        datapath.coprocessor.fpr[5] = f32::to_bits(1336.568f32) as u64;
+        datapath.initialize(vec![SYSCALL])?;
+        datapath.registers[GpRegisterType::V0] = 1;
+        datapath.registers[GpRegisterType::A0] = 7;
 
         datapath.execute_instruction();
-        datapath.execute_instruction();
-
-        assert_eq!(datapath.registers.pc, 0);
 
+        assert_eq!(host.borrow().output, "7");
         Ok(())
     }
+}
+
+pub mod exceptions {
+    use super::*;
+    use crate::emulation_core::mips::system_coprocessor::{ExceptionCode, STATUS_EXL};
+
+    // break
+    // SPECIAL     (code)        BREAK
+    const BREAK: u32 = 0b000000_00000000000000000000_001101;
 
     #[test]
-    fn bc1f_should_not_branch() -> Result<(), String> {
+    fn overflow_vectors_to_exception_handler() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
         let instructions: Vec<u32> = vec![
-            // c.le.s fs, ft
-            // c.le.s $f4, $f5
-            // CC[0] <- FPR[fs] <= FPR[ft]
-            // CC[0] <- FPR[4] <= FPR[5]
-            // COP1  fmt   ft    fs    cc     __cond
-            //       s     $f5   $f4   0        LE
-            0b010001_10000_00101_00100_000_00_111110,
-            // bc1f 0
-            // Branch to address 0 if false
-            // COP1  BC    cc nd f  offset
-            //             0        -1 (which becomes -4)
-            0b010001_01000_000_0_0_1111111111111110,
+            // $t1 = $t4 + $t4
+            // SPECIAL t4    t4    t1  (shamt) ADD
+            0b000000_01100_01100_01001_00000_100000,
+            // nop
+            0,
+            // Exception handler:
+            // nop
+            0,
         ];
         datapath.initialize(instructions)?;
+        datapath.system_coprocessor.exception_handler = Some(8);
 
-        datapath.coprocessor.fpr[4] = f32::to_bits(742.006f32) as u64;
-        datapath.coprocessor.fpr[5] = f32::to_bits(1336.568f32) as u64;
+        datapath.registers.gpr[12] = 0x7fff_ffff; // $t4
+        datapath.registers.gpr[9] = 123; // $t1
 
-        datapath.execute_instruction();
         datapath.execute_instruction();
 
+        // $t1 is left unmodified, and the datapath continues from the handler.
+        assert_eq!(datapath.registers.gpr[9], 123);
         assert_eq!(datapath.registers.pc, 8);
+        assert!(!datapath.is_halted());
 
+        assert_eq!(datapath.system_coprocessor.epc, 0);
+        assert_eq!(
+            (datapath.system_coprocessor.cause >> 2) & 0x1f,
+            ExceptionCode::IntegerOverflow as u64
+        );
+        assert_ne!(datapath.system_coprocessor.status & STATUS_EXL, 0);
         Ok(())
     }
 
     #[test]
-    fn mtc1_basic_move() -> Result<(), String> {
+    fn addi_overflow_raises_exception() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // mtc1 rt, fs
-        // mtc1 $s0, $f0
-        // FPR[fs] <- GPR[rt]
-        // FPR[0] <- GPR[16]
-        //                                  COP1   sub   rt    fs    0
-        //                                         MT    $s0   $f0
-        let instructions: Vec<u32> = vec![0b010001_00100_10000_00000_00000000000];
+        // $s0 = $t0 + 1
+        //                                  addi    $t0   $s0          1
+        let instructions: Vec<u32> = vec![0b001000_01000_10000_0000000000000001];
         datapath.initialize(instructions)?;
-
-        datapath.registers.gpr[16] = 25;
+        datapath.registers[GpRegisterType::T0] = 0x7fff_ffff;
+        datapath.registers[GpRegisterType::S0] = 123;
 
         datapath.execute_instruction();
 
-        assert_eq!(datapath.coprocessor.fpr[0], 25);
-
+        assert_eq!(datapath.registers[GpRegisterType::S0], 123);
+        assert_eq!(
+            datapath.system_coprocessor.exception,
+            Some(ExceptionCode::IntegerOverflow)
+        );
+        assert!(datapath.is_halted());
         Ok(())
     }
 
     #[test]
-    fn mtc1_truncate() -> Result<(), String> {
+    fn addiu_does_not_raise_exception() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // mtc1 rt, fs
-        // mtc1 $s1, $f1
-        // FPR[fs] <- GPR[rt]
-        // FPR[1] <- GPR[17]
-        //                                  COP1   sub   rt    fs    0
-        //                                         MT    $s1   $f1
-        let instructions: Vec<u32> = vec![0b010001_00100_10001_00001_00000000000];
+        // $s0 = $t0 + 1
+        //                                  addiu   $t0   $s0          1
+        let instructions: Vec<u32> = vec![0b001001_01000_10000_0000000000000001];
         datapath.initialize(instructions)?;
-
-        datapath.registers.gpr[17] = 0x1234_5678_ABCD_BEEF;
+        datapath.registers[GpRegisterType::T0] = 0x7fff_ffff;
 
         datapath.execute_instruction();
 
-        assert_eq!(datapath.coprocessor.fpr[1], 0xABCD_BEEF);
-
+        assert_eq!(
+            datapath.registers[GpRegisterType::S0],
+            0xffff_ffff_8000_0000
+        );
+        assert_eq!(datapath.system_coprocessor.exception, None);
+        assert!(!datapath.is_halted());
         Ok(())
     }
 
     #[test]
-    fn dmtc1_basic_move() -> Result<(), String> {
+    fn dsub_overflow_raises_exception() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // dmtc1 rt, fs
-        // dmtc1 $t0, $f30
-        // FPR[fs] <- GPR[rt]
-        // FPR[30] <- GPR[8]
-        //                                  COP1   sub   rt    fs    0
-        //                                         DMT   $t0   $f30
-        let instructions: Vec<u32> = vec![0b010001_00101_01000_11110_00000000000];
+        // $s5 = $s4 - $s3
+        //                                 SPECIAL $s4   $s3   $s5         DSUB
+        let instructions: Vec<u32> = vec![0b000000_10010_10001_10011_00000_101110];
         datapath.initialize(instructions)?;
-
-        datapath.registers.gpr[8] = 0xDEAD_BEEF_FEED_DEED;
+        datapath.registers.gpr[18] = i64::MIN as u64; // $s4
+        datapath.registers.gpr[17] = 1; // $s3
 
         datapath.execute_instruction();
 
-        assert_eq!(datapath.coprocessor.fpr[30], 0xDEAD_BEEF_FEED_DEED);
-
+        assert_eq!(datapath.registers.gpr[19], 0); // $s5
+        assert_eq!(
+            datapath.system_coprocessor.exception,
+            Some(ExceptionCode::IntegerOverflow)
+        );
         Ok(())
     }
 
     #[test]
-    fn mfc1_basic_move() -> Result<(), String> {
+    fn unaligned_load_raises_address_error() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // mfc1 rt, fs
-        // mfc1 $s5, $f18
-        // GPR[rt] <- FPR[fs]
-        // GPR[21] <- FPR[18]
-        //                                  COP1   sub   rt    fs    0
-        //                                         MF    $s5   $f18
-        let instructions: Vec<u32> = vec![0b010001_00000_10101_10010_00000000000];
+        // lw $t0, 2($zero)
+        //                                  lw     $zero  $t0          2
+        let instructions: Vec<u32> = vec![0b100011_00000_01000_0000000000000010];
         datapath.initialize(instructions)?;
-
-        datapath.coprocessor.fpr[18] = 123;
+        datapath.registers[GpRegisterType::T0] = 123;
 
         datapath.execute_instruction();
 
-        assert_eq!(datapath.registers.gpr[21], 123);
-
+        assert_eq!(datapath.registers[GpRegisterType::T0], 123);
+        assert_eq!(
+            datapath.system_coprocessor.exception,
+            Some(ExceptionCode::AddressErrorLoad)
+        );
+        assert_eq!(datapath.system_coprocessor.bad_vaddr, 2);
+        assert!(datapath.is_halted());
         Ok(())
     }
 
     #[test]
-    fn mfc1_truncate() -> Result<(), String> {
+    fn out_of_bounds_store_raises_address_error() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // mfc1 rt, fs
-        // mfc1 $s6, $f19
-        // GPR[rt] <- FPR[fs]
-        // GPR[22] <- FPR[19]
-        //                                  COP1   sub   rt    fs    0
-        //                                         MF    $s6   $f19
-        let instructions: Vec<u32> = vec![0b010001_00000_10110_10011_00000000000];
+        // sw $t0, 0($t1)
+        //                                  sw      $t1   $t0          0
+        let instructions: Vec<u32> = vec![0b101011_01001_01000_0000000000000000];
         datapath.initialize(instructions)?;
-
-        datapath.coprocessor.fpr[19] = 0xABBA_BABB_3ABA_4444;
+        datapath.registers[GpRegisterType::T1] = 0x1_0000;
 
         datapath.execute_instruction();
 
-        assert_eq!(datapath.registers.gpr[22], 0x3ABA_4444);
-
+        assert_eq!(
+            datapath.system_coprocessor.exception,
+            Some(ExceptionCode::AddressErrorStore)
+        );
+        assert_eq!(datapath.system_coprocessor.bad_vaddr, 0x1_0000);
         Ok(())
     }
 
     #[test]
-    fn mfc1_sign_extend() -> Result<(), String> {
+    fn fetch_out_of_bounds_raises_address_error() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // mfc1 rt, fs
-        // mfc1 $s7, $f20
-        // GPR[rt] <- FPR[fs]
-        // GPR[23] <- FPR[20]
-        //                                  COP1   sub   rt    fs    0
-        //                                         MF    $s7   $f20
-        let instructions: Vec<u32> = vec![0b010001_00000_10111_10100_00000000000];
-        datapath.initialize(instructions)?;
-
-        datapath.coprocessor.fpr[20] = 0xBADA_BEEF_BADA_B00E;
+        datapath.initialize(vec![0])?;
+        datapath.registers.pc = 0x1_0000;
 
         datapath.execute_instruction();
 
-        assert_eq!(datapath.registers.gpr[23], 0xFFFF_FFFF_BADA_B00E);
-
+        assert_eq!(
+            datapath.system_coprocessor.exception,
+            Some(ExceptionCode::AddressErrorLoad)
+        );
+        assert_eq!(datapath.system_coprocessor.bad_vaddr, 0x1_0000);
+        assert_eq!(datapath.system_coprocessor.epc, 0x1_0000);
         Ok(())
     }
 
     #[test]
-    fn dmfc1_basic_move() -> Result<(), String> {
+    fn unsupported_instruction_raises_reserved_instruction() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // dmfc1 rt, fs
-        // dmfc1 $t8, $f21
-        // GPR[rt] <- FPR[fs]
-        // GPR[24] <- FPR[21]
-        //                                  COP1   sub   rt    fs    0
-        //                                         DMF   $t8   $f21
-        let instructions: Vec<u32> = vec![0b010001_00001_11000_10101_00000000000];
+        // This opcode is not supported.
+        let instructions: Vec<u32> = vec![0b011111_00000_00000_0000000000000000];
         datapath.initialize(instructions)?;
 
-        datapath.coprocessor.fpr[21] = 0xADDA_DADD_1BAA_CAFE;
-
         datapath.execute_instruction();
 
-        assert_eq!(datapath.registers.gpr[24], 0xADDA_DADD_1BAA_CAFE);
-
+        assert_eq!(
+            datapath.system_coprocessor.exception,
+            Some(ExceptionCode::ReservedInstruction)
+        );
+        assert!(datapath.is_halted());
         Ok(())
     }
-}
 
-pub mod jump_tests {
-    use super::*;
     #[test]
-    fn jump_test_basic() -> Result<(), String> {
+    fn break_raises_breakpoint() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        //                                  J
-        let instructions: Vec<u32> = vec![0b000010_00_00000000_00000000_00000010];
-        datapath.initialize(instructions)?;
+        datapath.initialize(vec![BREAK])?;
+
         datapath.execute_instruction();
 
-        assert_eq!(datapath.registers.pc, 8);
+        assert_eq!(
+            datapath.system_coprocessor.exception,
+            Some(ExceptionCode::Breakpoint)
+        );
+        assert!(datapath.is_halted());
         Ok(())
     }
 
     #[test]
-    fn jump_test_mid() -> Result<(), String> {
+    fn unsupported_syscall_vectors_to_exception_handler() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        //                                  J
-        let instructions: Vec<u32> = vec![0x0800_0fff];
+        let instructions: Vec<u32> = vec![
+            // syscall
+            // SPECIAL     (code)        SYSCALL
+            0b000000_00000000000000000000_001100,
+            // Exception handler:
+            // nop
+            0,
+        ];
         datapath.initialize(instructions)?;
+        datapath.system_coprocessor.exception_handler = Some(4);
+
         datapath.execute_instruction();
 
-        assert_eq!(datapath.registers.pc, 0x3ffc);
+        assert_eq!(
+            datapath.system_coprocessor.exception,
+            Some(ExceptionCode::Syscall)
+        );
+        assert_eq!(datapath.registers.pc, 4);
+        assert!(!datapath.is_halted());
         Ok(())
     }
 
     #[test]
-    fn jump_test_hard() -> Result<(), String> {
-        // Jump to address 0xfff_fffc
+    fn eret_returns_past_faulting_instruction() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        //                                  J             low_26
-        let instructions: Vec<u32> = vec![0x0800_0000 | 0x03ff_ffff];
+        let instructions: Vec<u32> = vec![
+            BREAK,
+            // nop
+            0,
+            // Exception handler:
+            // mfc0 $t0, $14 (EPC)
+            // COP0   MF    $t0   $14     0      sel
+            0b010000_00000_01000_01110_00000000_000,
+            // $t0 = $t0 + 4
+            // addiu  $t0   $t0          4
+            0b001001_01000_01000_0000000000000100,
+            // mtc0 $t0, $14 (EPC)
+            // COP0   MT    $t0   $14     0      sel
+            0b010000_00100_01000_01110_00000000_000,
+            // eret
+            // COP0  CO         0            ERET
+            0b010000_1_0000000000000000000_011000,
+        ];
         datapath.initialize(instructions)?;
-        datapath.execute_instruction();
+        datapath.system_coprocessor.exception_handler = Some(8);
 
-        assert_eq!(datapath.registers.pc, 0x0fff_fffc);
+        // Execute the break, then the 4 instructions in the handler.
+        for _ in 0..5 {
+            datapath.execute_instruction();
+        }
+
+        assert_eq!(datapath.registers[GpRegisterType::T0], 4);
+        assert_eq!(datapath.system_coprocessor.epc, 4);
+        assert_eq!(datapath.registers.pc, 4);
+        assert_eq!(datapath.system_coprocessor.status & STATUS_EXL, 0);
         Ok(())
     }
-}
 
-pub mod jump_and_link_tests {
-    use super::*;
     #[test]
-    fn test_basic() -> Result<(), String> {
+    fn mfc0_reads_cause() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
-        let old_pc = datapath.registers.pc;
 
-        //                                  J
-        let instructions: Vec<u32> = vec![0b000011_00_00000000_00000000_00000010];
+        let instructions: Vec<u32> = vec![
+            BREAK,
+            // Exception handler:
+            // mfc0 $t0, $13 (Cause)
+            // COP0   MF    $t0   $13     0      sel
+            0b010000_00000_01000_01101_00000000_000,
+        ];
         datapath.initialize(instructions)?;
-        datapath.execute_instruction();
+        datapath.system_coprocessor.exception_handler = Some(4);
 
-        assert_eq!(datapath.registers.pc, 8);
-        assert_eq!(datapath.registers.gpr[31], old_pc + 4);
+        for _ in 0..2 {
+            datapath.execute_instruction();
+        }
+
+        assert_eq!(
+            datapath.registers[GpRegisterType::T0],
+            (ExceptionCode::Breakpoint as u64) << 2
+        );
         Ok(())
     }
 
     #[test]
-    fn test_mid() -> Result<(), String> {
+    fn nested_exception_keeps_epc() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
-        let old_pc = datapath.registers.pc;
 
-        //                                  J
-        let instructions: Vec<u32> = vec![0x0c00_0fff];
+        // The exception handler itself raises another exception.
+        let instructions: Vec<u32> = vec![0, BREAK];
         datapath.initialize(instructions)?;
-        datapath.execute_instruction();
+        datapath.system_coprocessor.exception_handler = Some(4);
 
-        assert_eq!(datapath.registers.pc, 0x3ffc);
-        assert_eq!(datapath.registers.gpr[31], old_pc + 4);
+        // nop, break, break
+        for _ in 0..3 {
+            datapath.execute_instruction();
+        }
+
+        assert_eq!(datapath.system_coprocessor.epc, 4);
+        assert_eq!(datapath.registers.pc, 4);
         Ok(())
     }
 
     #[test]
-    fn test_hard() -> Result<(), String> {
-        // Jump to address 0xfff_fffc
+    fn reserved_instruction_keeps_message() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
-        let old_pc = datapath.registers.pc;
 
-        //                                  J             low_26
-        let instructions: Vec<u32> = vec![0x0c00_0000 | 0x03ff_ffff];
+        //                                 SPECIAL3
+        let instructions: Vec<u32> = vec![0b011111_00000_00000_0000000000000000, 0];
         datapath.initialize(instructions)?;
+        datapath.system_coprocessor.exception_handler = Some(4);
+
         datapath.execute_instruction();
+        assert_eq!(
+            datapath.system_coprocessor.exception,
+            Some(ExceptionCode::ReservedInstruction)
+        );
+        assert_eq!(
+            datapath.system_coprocessor.exception_message.as_deref(),
+            Some("opcode `31` not supported")
+        );
 
-        assert_eq!(datapath.registers.pc, 0x0fff_fffc);
-        assert_eq!(datapath.registers.gpr[31], old_pc + 4);
+        // The message belongs to the instruction that raised the exception.
+        datapath.execute_instruction();
+        assert_eq!(datapath.system_coprocessor.exception_message, None);
         Ok(())
     }
 }
 
-pub mod jr_and_jalr_tests {
+pub mod strict_32_bit {
     use super::*;
+    use crate::emulation_core::mips::datapath::UnpredictableOperand;
+    use crate::emulation_core::mips::system_coprocessor::ExceptionCode;
+
     #[test]
-    fn test_basic_jr() -> Result<(), String> {
+    fn lw_zero_extends_by_default() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
 
-        // JR $r8
-        //                                  Special $r8  $zero $zero        JALR
-        let instructions: Vec<u32> = vec![0b000000_01000_00000_00000_00000_001001];
+        //                                  lw     $zero $s0      offset = 4
+        let instructions: Vec<u32> = vec![0b100011_00000_10000_0000000000000100];
         datapath.initialize(instructions)?;
-        datapath.registers.gpr[0b01000] = 24;
-        datapath.execute_instruction();
+        datapath.memory.store_word(0b100, 0x8000_0000)?;
 
-        assert_eq!(datapath.registers.pc, 24);
-        assert_eq!(datapath.registers.gpr[8], 24);
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[16], 0x8000_0000);
         Ok(())
     }
 
     #[test]
-    fn test_basic_jalr() -> Result<(), String> {
+    fn lw_sign_extends_in_strict_mode() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
+        datapath.strict_32_bit = true;
 
-        // JALR $r8
-        //                                     Special  $r8  $zero $ra          JALR
-        let instructions: Vec<u32> = vec![0, 0, 0b000000_01000_00000_11111_00000_001001];
+        //                                  lw     $zero $s0      offset = 4
+        let instructions: Vec<u32> = vec![0b100011_00000_10000_0000000000000100];
         datapath.initialize(instructions)?;
-        datapath.registers.pc = 8;
-        let initial_pc = datapath.registers.pc;
-        datapath.registers.gpr[0b01000] = 24;
-        datapath.execute_instruction();
+        datapath.memory.store_word(0b100, 0x8000_0000)?;
 
-        assert_eq!(datapath.registers.pc, 24);
-        assert_eq!(datapath.registers.gpr[31], initial_pc + 4);
+        datapath.execute_instruction();
+        assert_eq!(datapath.registers.gpr[16], 0xFFFF_FFFF_8000_0000);
         Ok(())
     }
-}
 
-pub mod beq_tests {
-    use super::*;
     #[test]
-    fn beq_test_basic_registers_are_equal() -> Result<(), String> {
+    fn strict_mode_kept_across_initialize() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
+        datapath.strict_32_bit = true;
 
-        //                                  beq
-        let instructions: Vec<u32> = vec![0b000100_01000_10000_0000000000000001];
-        datapath.initialize(instructions)?;
-
-        let initial_pc = datapath.registers.pc;
-        datapath.execute_instruction();
-        let expt_result = (0b0000_0000_0000_0001 << 2) + initial_pc + 4;
-        assert_eq!(datapath.registers.pc, expt_result);
+        datapath.initialize(vec![])?;
+        assert!(datapath.strict_32_bit);
         Ok(())
     }
 
     #[test]
-    fn beq_test_basic_register_are_not_equal() -> Result<(), String> {
+    fn addu_records_unpredictable_operand() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
+        datapath.strict_32_bit = true;
 
-        //                                  beq
-        let instructions: Vec<u32> = vec![
-            0b000100_01000_10000_0000000000000001,
-            0b000100_01000_10000_0000000000000001,
-        ];
+        // $s2 = $s0 + $s1
+        //                                  R-type  s0    s1    s2          ADDU
+        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_100001];
         datapath.initialize(instructions)?;
 
-        datapath.registers.gpr[0b01000] = 1234;
-        datapath.registers.gpr[0b10000] = 4321;
+        datapath.registers.gpr[16] = 5; // $s0
+        datapath.registers.gpr[17] = 0x1_0000_0003; // $s1
 
         datapath.execute_instruction();
-        assert_eq!(datapath.registers.pc, 4);
 
-        datapath.execute_instruction();
-        assert_eq!(datapath.registers.pc, 8);
+        // The upper 32 bits are still ignored in computing the result.
+        assert_eq!(datapath.registers.gpr[18], 8); // $s2
+        assert_eq!(
+            datapath.unpredictable_operands,
+            vec![UnpredictableOperand {
+                pc: 0,
+                register: 17,
+                value: 0x1_0000_0003,
+            }]
+        );
         Ok(())
     }
 
     #[test]
-    fn beq_test_basic_branch_backwards() -> Result<(), String> {
-        let mut datapath = MipsDatapath::default();
+    fn unpredictable_operand_display() {
+        let operand = UnpredictableOperand {
+            pc: 0,
+            register: 17,
+            value: 0x1_0000_0003,
+        };
 
-        let instructions: Vec<u32> = vec![
-            0b000100_01000_10000_0000000000000011, // 0x00, Branch to 0x10
-            0,                                     // 0x04
-            0,                                     // 0x08
-            0,                                     // 0x0c
-            0b000100_01000_10000_1111111111111011, // 0x10, Branch to 0x00
-        ];
-        datapath.initialize(instructions)?;
-        datapath.registers.gpr[0b01000] = 1234;
-        datapath.registers.gpr[0b10000] = 1234;
+        assert_eq!(
+            operand.to_string(),
+            "$s1 held 0x100000003, which is not a sign-extended 32-bit value"
+        );
+    }
 
-        let initial_pc = datapath.registers.pc;
-        let offset = 0b0000_0000_0000_0011;
-        // 0x10, aka 16
-        let expt_result = ((offset as i16 as i64 as u64) << 2)
-            .wrapping_add(initial_pc)
-            .wrapping_add(4);
+    #[test]
+    fn sign_extended_operands_not_recorded() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+        datapath.strict_32_bit = true;
 
-        datapath.execute_instruction(); // branch to address 16 from address 0
-        assert_eq!(datapath.registers.pc, expt_result);
-        assert_eq!(datapath.registers.gpr[0b01000], 1234);
-        assert_eq!(datapath.registers.gpr[0b10000], 1234);
+        // $s2 = $s0 + $s1
+        //                                  R-type  s0    s1    s2          ADDU
+        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_100001];
+        datapath.initialize(instructions)?;
 
-        let initial_pc = datapath.registers.pc;
-        let offset = 0b1111_1111_1111_1011;
-        // 0x00
-        let expt_result = ((offset as i16 as i64 as u64) << 2)
-            .wrapping_add(initial_pc)
-            .wrapping_add(4);
+        datapath.registers.gpr[16] = -5_i64 as u64; // $s0
+        datapath.registers.gpr[17] = 0x7FFF_FFFF; // $s1
 
         datapath.execute_instruction();
-        assert_eq!(datapath.registers.pc, expt_result);
-
-        // Some loop stuff:
-        datapath.execute_instruction(); // Branch to 0x10
-        datapath.execute_instruction(); // Branch to 0x00
-        datapath.execute_instruction(); // Branch to 0x10
-        datapath.execute_instruction(); // Branch to 0x00
-        datapath.execute_instruction(); // Branch to 0x10
-        datapath.execute_instruction(); // Branch to 0x00
-        assert_eq!(datapath.registers.pc, expt_result);
+        assert!(datapath.unpredictable_operands.is_empty());
         Ok(())
     }
-}
 
-pub mod bne_tests {
-    use super::*;
     #[test]
-    fn bne_test_basic_registers_are_equal() -> Result<(), String> {
-        // There should be no branching, the rs and rt are equal
-
+    fn unpredictable_operand_not_recorded_by_default() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
-        //                                  bne                         1 word
-        let instructions: Vec<u32> = vec![0b000101_01000_10000_0000000000000001];
-        datapath.registers.gpr[0b01000] = 1234;
-        datapath.registers.gpr[0b10000] = 1234;
+
+        // $s2 = $s0 + $s1
+        //                                  R-type  s0    s1    s2          ADDU
+        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_100001];
         datapath.initialize(instructions)?;
-        datapath.execute_instruction();
-        let expt_result = 4; // PC + 4, PC starts at 0 with the bne instruction at address 0, no branch acures
-        assert_eq!(datapath.registers.pc, expt_result);
 
+        datapath.registers.gpr[16] = 0x8000_0000; // $s0
+
+        datapath.execute_instruction();
+        assert!(datapath.unpredictable_operands.is_empty());
         Ok(())
     }
 
     #[test]
-    fn bne_test_loop() -> Result<(), String> {
-        // This test starts with Branching from 0x0 to 0x8.
-        // then from 0x8, at branch to 0x20.
-        // then from 0x20 back to 0x8.
-        // then from 0x8 to 0x20
-        // backcally we have a loop of branching forever
-
+    fn shift_only_checks_rt() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
+        datapath.strict_32_bit = true;
+
         let instructions: Vec<u32> = vec![
-            0b000101_01000_10000_0000000000000001, // 0x00, Branch to 0x8
-            0,                                     // 0x04
-            0b000101_01000_10000_0000000000000101, // 0x08, Branch to 0x20
-            0,                                     // 0x0c
-            0,                                     // 0x10
-            0,                                     // 0x14
-            0,                                     // 0x18
-            0,                                     // 0x1c
-            0b000101_01000_10000_1111111111111001, // 0x20, bne r8, r16, -24, (branch -28 relative to next addres), branch to 0x08
+            // $s2 = $s0 >> $s1, where only the low bits of $s1 are used
+            //  R-type  s1    s0    s2          SRLV
+            0b000000_10001_10000_10010_00000_000110,
+            // $s3 = $s1 >> $s0
+            //  R-type  s0    s1    s3          SRLV
+            0b000000_10000_10001_10011_00000_000110,
         ];
         datapath.initialize(instructions)?;
-        datapath.registers.gpr[0b01000] = 1234;
-        datapath.registers.gpr[0b10000] = 4321;
 
-        // test beq going from pc = 0 to next_pc + 4, 0x0 to 0x8
-        datapath.execute_instruction();
-        assert_eq!(datapath.registers.pc, 8);
+        datapath.registers.gpr[16] = 3; // $s0
+        datapath.registers.gpr[17] = 0x1_0000_0004; // $s1
 
-        // Branch from 0x8 to 0x20, aka from 8 to 32, branch by 24
-        let initial_pc = datapath.registers.pc;
         datapath.execute_instruction();
-        let expt_result = (0b0000000000000101 << 2) + initial_pc + 4; // 32
-        assert_eq!(datapath.registers.pc, expt_result);
-
-        // Branch back to 0x8 from 0x20, aka 32 to 8
-        // The next_pc after 32 it 36, thus our branch offset will be 8 - 36 = -28
-        //
-        // destination_addr = SOME_LABEL
-        // Branch offset = (destination_addr - next_pc)
-        let initial_pc = datapath.registers.pc;
-        let offset = 0b1111_1111_1111_1001; // -28
-                                            // 0x8
-        let expt_result = ((offset as i16 as i64 as u64) << 2)
-            .wrapping_add(initial_pc)
-            .wrapping_add(4);
-
-        datapath.execute_instruction(); // branch to 0x08
-        assert_eq!(datapath.registers.pc as i64, expt_result as i64);
-
-        // loop around a few times
-        datapath.execute_instruction(); // branch to 0x20
-        datapath.execute_instruction(); // branch to 0x08
-        datapath.execute_instruction(); // branch to 0x20
-        datapath.execute_instruction(); // branch to 0x08
-        assert_eq!(datapath.registers.pc as i64, expt_result as i64);
+        assert!(datapath.unpredictable_operands.is_empty());
 
+        datapath.execute_instruction();
+        assert_eq!(
+            datapath.unpredictable_operands,
+            vec![UnpredictableOperand {
+                pc: 4,
+                register: 17,
+                value: 0x1_0000_0004,
+            }]
+        );
         Ok(())
     }
-}
-
-pub mod syscall {
-    use super::*;
 
     #[test]
-    fn halts_on_syscall() -> Result<(), String> {
+    fn overflow_does_not_write_in_strict_mode() -> Result<(), String> {
         let mut datapath = MipsDatapath::default();
+        datapath.strict_32_bit = true;
 
-        assert!(datapath.is_halted());
-
-        // This program doubles the value in $t1 and stops.
-
-        let instructions: Vec<u32> = vec![
-            // $t1 = $t1 + $t1
-            // SPECIAL t1   t1    t1  (shamt)  ADD
-            0b000000_01001_01001_01001_00000_100000,
-            // syscall
-            // SPECIAL     (code)        SYSCALL
-            0b000000_00000000000000000000_001100,
-        ];
+        // $s2 = $s0 + $s1
+        //                                  R-type  s0    s1    s2          ADD
+        let instructions: Vec<u32> = vec![0b000000_10000_10001_10010_00000_100000];
         datapath.initialize(instructions)?;
-        assert!(!datapath.is_halted());
-
-        datapath.registers.gpr[9] = 5; // $t1
 
-        // Execute 2 instructions.
-        for _ in 0..2 {
-            datapath.execute_instruction();
-        }
+        datapath.registers.gpr[16] = 0x7FFF_FFFF; // $s0
+        datapath.registers.gpr[17] = 1; // $s1
+        datapath.registers.gpr[18] = 1234; // $s2
 
-        assert_eq!(datapath.registers.gpr[9], 10); // $t1
-        assert!(datapath.is_halted());
+        datapath.execute_instruction();
+        assert_eq!(
+            datapath.system_coprocessor.exception,
+            Some(ExceptionCode::IntegerOverflow)
+        );
+        assert_eq!(datapath.registers.gpr[18], 1234); // $s2
         Ok(())
     }
 }
@@ -0,0 +1,166 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::emulation_core::mips::memory::{Endianness, Memory};
+use crate::emulation_core::mips::mmio::*;
+
+/// Create memory with a keyboard and display and a 4x2 bitmap display
+/// attached at their usual addresses.
+fn memory_with_devices() -> (
+    Memory,
+    Rc<RefCell<KeyboardDisplay>>,
+    Rc<RefCell<BitmapDisplay>>,
+) {
+    let keyboard_display = Rc::new(RefCell::new(KeyboardDisplay::default()));
+    let bitmap_display = Rc::new(RefCell::new(BitmapDisplay::new(4, 2).unwrap()));
+
+    let mut memory = Memory::default();
+    memory
+        .attach_device(KEYBOARD_DISPLAY_ADDRESS, keyboard_display.clone())
+        .unwrap();
+    memory
+        .attach_device(BITMAP_DISPLAY_ADDRESS, bitmap_display.clone())
+        .unwrap();
+
+    (memory, keyboard_display, bitmap_display)
+}
+
+#[test]
+fn keyboard_receiver_reads_keys_in_order() -> Result<(), String> {
+    let (memory, keyboard_display, _) = memory_with_devices();
+    let control = KEYBOARD_DISPLAY_ADDRESS + KeyboardDisplay::RECEIVER_CONTROL;
+    let data = KEYBOARD_DISPLAY_ADDRESS + KeyboardDisplay::RECEIVER_DATA;
+
+    assert_eq!(memory.load_word(control)?, 0);
+
+    keyboard_display.borrow_mut().type_key(b'h');
+    keyboard_display.borrow_mut().type_key(b'i');
+
+    assert_eq!(memory.load_word(control)?, 1);
+    assert_eq!(memory.load_byte(data)?, b'h');
+    assert_eq!(memory.load_word(control)?, 1);
+    assert_eq!(memory.load_word(data)?, b'i' as u32);
+    assert_eq!(memory.load_word(control)?, 0);
+
+    // The data register keeps the last key read.
+    assert_eq!(memory.load_word(data)?, b'i' as u32);
+
+    Ok(())
+}
+
+#[test]
+fn display_transmitter_shows_low_byte() -> Result<(), String> {
+    let (mut memory, keyboard_display, _) = memory_with_devices();
+    let control = KEYBOARD_DISPLAY_ADDRESS + KeyboardDisplay::TRANSMITTER_CONTROL;
+    let data = KEYBOARD_DISPLAY_ADDRESS + KeyboardDisplay::TRANSMITTER_DATA;
+
+    assert_eq!(memory.load_word(control)?, 1);
+
+    memory.store_word(data, 0x1234_5600 | b'o' as u32)?;
+    memory.store_byte(data, b'k')?;
+
+    assert_eq!(keyboard_display.borrow().output, "ok");
+
+    // Interrupt enable bits are kept, but the ready bit cannot be changed.
+    memory.store_word(control, 0b10)?;
+    assert_eq!(memory.load_word(control)?, 0b11);
+
+    Ok(())
+}
+
+#[test]
+fn bitmap_display_pixels() -> Result<(), String> {
+    let (mut memory, _, bitmap_display) = memory_with_devices();
+
+    // Pixel (1, 1) is the sixth pixel.
+    memory.store_word(BITMAP_DISPLAY_ADDRESS + 5 * 4, 0x00FF_8000)?;
+    memory.store_double_word(BITMAP_DISPLAY_ADDRESS, 0x0000_00FF_0000_FF00)?;
+    memory.store_byte(BITMAP_DISPLAY_ADDRESS + 7 * 4 + 1, 0x12)?;
+
+    {
+        let bitmap_display = bitmap_display.borrow();
+        assert_eq!(bitmap_display.pixel(1, 1), Some(0x00FF_8000));
+        assert_eq!(bitmap_display.pixel(0, 0), Some(0x0000_00FF));
+        assert_eq!(bitmap_display.pixel(1, 0), Some(0x0000_FF00));
+        assert_eq!(bitmap_display.pixel(3, 1), Some(0x0012_0000));
+        assert_eq!(bitmap_display.pixel(4, 0), None);
+    }
+
+    assert_eq!(
+        memory.load_word(BITMAP_DISPLAY_ADDRESS + 5 * 4)?,
+        0x00FF_8000
+    );
+    assert_eq!(
+        memory.load_half_word(BITMAP_DISPLAY_ADDRESS + 5 * 4 + 2)?,
+        0x8000
+    );
+
+    Ok(())
+}
+
+#[test]
+fn bitmap_display_follows_little_endian_memory() -> Result<(), String> {
+    let (mut memory, _, bitmap_display) = memory_with_devices();
+    memory.endianness = Endianness::Little;
+
+    // The lowest address of a little-endian word holds its least
+    // significant byte, which is the blue channel.
+    memory.store_byte(BITMAP_DISPLAY_ADDRESS, 0x12)?;
+    memory.store_half_word(BITMAP_DISPLAY_ADDRESS + 4 + 2, 0x00FF)?;
+    memory.store_word(BITMAP_DISPLAY_ADDRESS + 8, 0x00FF_8000)?;
+
+    {
+        let bitmap_display = bitmap_display.borrow();
+        assert_eq!(bitmap_display.pixel(0, 0), Some(0x0000_0012));
+        assert_eq!(bitmap_display.pixel(1, 0), Some(0x00FF_0000));
+        assert_eq!(bitmap_display.pixel(2, 0), Some(0x00FF_8000));
+    }
+
+    assert_eq!(memory.load_byte(BITMAP_DISPLAY_ADDRESS)?, 0x12);
+    assert_eq!(
+        memory.load_half_word(BITMAP_DISPLAY_ADDRESS + 4 + 2)?,
+        0x00FF
+    );
+    assert_eq!(memory.load_byte(BITMAP_DISPLAY_ADDRESS + 8 + 1)?, 0x80);
+
+    Ok(())
+}
+
+#[test]
+fn device_accesses_must_be_aligned() {
+    let (memory, _, _) = memory_with_devices();
+
+    assert!(match memory.load_word(BITMAP_DISPLAY_ADDRESS + 2) {
+        Err(e) => e.contains("align"),
+        _ => false,
+    });
+}
+
+#[test]
+fn accesses_past_devices_are_out_of_bounds() {
+    let (mut memory, _, _) = memory_with_devices();
+
+    // Between the keyboard and display and the bitmap display.
+    assert!(match memory.store_word(KEYBOARD_DISPLAY_ADDRESS + 16, 0) {
+        Err(e) => e.contains("bounds"),
+        _ => false,
+    });
+
+    // Past the end of the bitmap display.
+    assert!(memory.load_word(BITMAP_DISPLAY_ADDRESS + 4 * 8).is_err());
+}
+
+#[test]
+fn devices_cannot_overlap() {
+    let (mut memory, _, _) = memory_with_devices();
+
+    let device = Rc::new(RefCell::new(KeyboardDisplay::default()));
+    assert!(memory.attach_device(0x100, device.clone()).is_err());
+    assert!(memory
+        .attach_device(KEYBOARD_DISPLAY_ADDRESS + 8, device.clone())
+        .is_err());
+    assert!(memory.attach_device(u64::MAX - 4, device.clone()).is_err());
+    assert!(memory
+        .attach_device(KEYBOARD_DISPLAY_ADDRESS + 16, device)
+        .is_ok());
+}
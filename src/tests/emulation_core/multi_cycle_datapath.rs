@@ -0,0 +1,328 @@
+use crate::emulation_core::datapath::Datapath;
+use crate::emulation_core::mips::multi_cycle_datapath::{MipsMultiCycleDatapath, MultiCycleState};
+use crate::emulation_core::mips::registers::GpRegisterType;
+use crate::parser::parser_assembler_main::parser;
+
+/// Create a multi-cycle datapath with the given program loaded.
+fn load_program(program: &str) -> Result<MipsMultiCycleDatapath, String> {
+    let mut datapath = MipsMultiCycleDatapath::default();
+
+    let (_, instruction_bits) = parser(program.to_string());
+    datapath.initialize(instruction_bits)?;
+
+    Ok(datapath)
+}
+
+/// Execute one instruction, returning the states it went through.
+fn execute_recording_states(datapath: &mut MipsMultiCycleDatapath) -> Vec<MultiCycleState> {
+    let mut states = vec![];
+
+    loop {
+        datapath.execute_stage();
+        states.extend(datapath.previous_state);
+
+        if datapath.control_state == MultiCycleState::InstructionFetch {
+            break states;
+        }
+    }
+}
+
+pub mod control_unit {
+    use super::*;
+    use MultiCycleState::*;
+
+    #[test]
+    fn instruction_classes_take_different_numbers_of_cycles() -> Result<(), String> {
+        let mut datapath = load_program(
+            r#"ori $t0, $zero, 64
+sw $t0, 0($t0)
+lw $t1, 0($t0)
+beq $t0, $t1, next
+next: j end
+end: nop"#,
+        )?;
+
+        let expected_cycles = [4, 4, 5, 3, 3];
+        for cycles in expected_cycles {
+            let before = datapath.cycles;
+            datapath.execute_instruction();
+            assert_eq!(datapath.cycles - before, cycles);
+        }
+
+        assert_eq!(datapath.cycles, 19);
+
+        Ok(())
+    }
+
+    #[test]
+    fn state_sequences() -> Result<(), String> {
+        let mut datapath = load_program(
+            r#"ori $t0, $zero, 64
+sw $t0, 0($t0)
+lw $t1, 0($t0)
+bne $t0, $t1, end
+jal end
+end: nop"#,
+        )?;
+
+        let expected_states = [
+            vec![
+                InstructionFetch,
+                InstructionDecode,
+                Execution,
+                AluCompletion,
+            ],
+            vec![
+                InstructionFetch,
+                InstructionDecode,
+                MemoryAddressComputation,
+                MemoryWrite,
+            ],
+            vec![
+                InstructionFetch,
+                InstructionDecode,
+                MemoryAddressComputation,
+                MemoryRead,
+                MemoryReadCompletion,
+            ],
+            vec![InstructionFetch, InstructionDecode, BranchCompletion],
+            vec![InstructionFetch, InstructionDecode, JumpCompletion],
+        ];
+
+        for states in expected_states {
+            assert_eq!(execute_recording_states(&mut datapath), states);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn state_numbers_match_textbook() {
+        assert_eq!(InstructionFetch as u8, 0);
+        assert_eq!(MemoryReadCompletion as u8, 4);
+        assert_eq!(JumpCompletion as u8, 9);
+    }
+}
+
+pub mod registers {
+    use super::*;
+
+    #[test]
+    fn load_fills_intermediate_registers() -> Result<(), String> {
+        let mut datapath = load_program(
+            r#"ori $t0, $zero, 64
+ori $t1, $zero, 1234
+sw $t1, 8($t0)
+lw $t2, 8($t0)"#,
+        )?;
+
+        for _ in 0..3 {
+            datapath.execute_instruction();
+        }
+
+        // Instruction fetch: the PC moves on to the next instruction.
+        datapath.execute_stage();
+        assert_eq!(datapath.ir, datapath.datapath.memory.load_word(12)?);
+        assert_eq!(datapath.datapath.registers.pc, 16);
+
+        // Instruction decode: `A` holds `$t0`.
+        datapath.execute_stage();
+        assert_eq!(datapath.a, 64);
+
+        // Memory address computation.
+        datapath.execute_stage();
+        assert_eq!(datapath.alu_out, 72);
+
+        // Memory read.
+        datapath.execute_stage();
+        assert_eq!(datapath.mdr, 1234);
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T2], 0);
+
+        // Memory read completion.
+        datapath.execute_stage();
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T2], 1234);
+        assert_eq!(datapath.control_state, MultiCycleState::InstructionFetch);
+
+        Ok(())
+    }
+
+    #[test]
+    fn decode_computes_branch_target() -> Result<(), String> {
+        let mut datapath = load_program(
+            r#"ori $t0, $zero, 1
+beq $t0, $zero, target
+nop
+target: ori $t1, $zero, 2"#,
+        )?;
+
+        datapath.execute_instruction();
+        datapath.execute_stage();
+        datapath.execute_stage();
+        assert_eq!(datapath.a, 1);
+        assert_eq!(datapath.b, 0);
+        assert_eq!(datapath.alu_out, 12);
+
+        // The branch is not taken.
+        datapath.execute_stage();
+        assert_eq!(datapath.datapath.registers.pc, 8);
+
+        Ok(())
+    }
+
+    /// Run clock cycles until the control unit is in `state`.
+    fn run_until(datapath: &mut MipsMultiCycleDatapath, state: MultiCycleState) {
+        while datapath.control_state != state {
+            datapath.execute_stage();
+        }
+    }
+
+    #[test]
+    fn decode_reads_ir() -> Result<(), String> {
+        let mut datapath = load_program(
+            r#"ori $t0, $zero, 1
+ori $t1, $zero, 2"#,
+        )?;
+
+        // Replace the fetched instruction with the one after it.
+        datapath.execute_stage();
+        datapath.ir = datapath.datapath.memory.load_word(4)?;
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T0], 0);
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T1], 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn execution_reads_a_and_b() -> Result<(), String> {
+        let mut datapath = load_program("daddu $t2, $t0, $t1")?;
+
+        run_until(&mut datapath, MultiCycleState::Execution);
+        datapath.a = 30;
+        datapath.b = 12;
+        datapath.execute_stage();
+        assert_eq!(datapath.alu_out, 42);
+
+        datapath.execute_stage();
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T2], 42);
+
+        Ok(())
+    }
+
+    #[test]
+    fn alu_completion_writes_alu_out() -> Result<(), String> {
+        let mut datapath = load_program("ori $t0, $zero, 1")?;
+
+        run_until(&mut datapath, MultiCycleState::AluCompletion);
+        datapath.alu_out = 99;
+        datapath.execute_stage();
+
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T0], 99);
+
+        Ok(())
+    }
+
+    #[test]
+    fn load_reads_alu_out_and_mdr() -> Result<(), String> {
+        let mut datapath = load_program(
+            r#"ori $t0, $zero, 1234
+sw $t0, 64($zero)
+lw $t1, 0($zero)
+lw $t2, 64($zero)"#,
+        )?;
+
+        for _ in 0..2 {
+            datapath.execute_instruction();
+        }
+
+        // The address of the first load is replaced before memory is read.
+        run_until(&mut datapath, MultiCycleState::MemoryRead);
+        datapath.alu_out = 64;
+        datapath.execute_instruction();
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T1], 1234);
+
+        // The data of the second load is replaced before it is written.
+        run_until(&mut datapath, MultiCycleState::MemoryReadCompletion);
+        datapath.mdr = 5;
+        datapath.execute_stage();
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T2], 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn store_reads_alu_out_and_b() -> Result<(), String> {
+        let mut datapath = load_program("sw $t0, 0($zero)")?;
+
+        run_until(&mut datapath, MultiCycleState::MemoryWrite);
+        datapath.alu_out = 64;
+        datapath.b = 77;
+        datapath.execute_stage();
+
+        assert_eq!(datapath.datapath.memory.load_word(64)?, 77);
+        assert_eq!(datapath.datapath.memory.load_word(0)?, 0xAC08_0000);
+
+        Ok(())
+    }
+
+    #[test]
+    fn branch_reads_a_b_and_alu_out() -> Result<(), String> {
+        let mut datapath = load_program(
+            r#"ori $t0, $zero, 1
+beq $t0, $zero, target
+ori $t1, $zero, 1
+ori $t2, $zero, 2
+target: ori $t3, $zero, 3"#,
+        )?;
+
+        datapath.execute_instruction();
+
+        // The branch is taken to the replaced target once `A` is changed.
+        run_until(&mut datapath, MultiCycleState::BranchCompletion);
+        datapath.a = 0;
+        datapath.alu_out = 12;
+        datapath.execute_stage();
+
+        assert_eq!(datapath.datapath.registers.pc, 12);
+
+        Ok(())
+    }
+
+    #[test]
+    fn report_shows_state_and_registers() -> Result<(), String> {
+        let mut datapath = load_program("ori $t0, $zero, 64")?;
+        datapath.execute_stage();
+        datapath.execute_stage();
+        datapath.execute_stage();
+
+        assert_eq!(
+            datapath.generate_report(),
+            "Control unit after cycle 3:
+Last state: 6 (execution)\tALUOut <= A op (B or sign-extend(IR[15:0]))
+Next state: 7 (ALU completion)\tReg[IR[15:11] or IR[20:16]] <= ALUOut
+
+IR: 0x34080040  MDR: 0x0  A: 0x0  B: 0x0  ALUOut: 0x40
+"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn reset_datapath() -> Result<(), String> {
+        let mut datapath = load_program("ori $t0, $zero, 1")?;
+        datapath.execute_stage();
+        datapath.execute_stage();
+
+        datapath.reset();
+
+        assert_eq!(datapath.control_state, MultiCycleState::InstructionFetch);
+        assert_eq!(datapath.previous_state, None);
+        assert_eq!(datapath.ir, 0);
+        assert_eq!(datapath.cycles, 0);
+        assert_eq!(datapath.datapath.memory.memory[0], 0);
+
+        Ok(())
+    }
+}
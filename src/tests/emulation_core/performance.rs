@@ -0,0 +1,85 @@
+use crate::emulation_core::mips::datapath::Stage;
+use crate::emulation_core::mips::performance::*;
+
+#[test]
+fn cycles_and_cpi_follow_cost_model() {
+    let mut counters = PerformanceCounters::default();
+    for _ in 0..6 {
+        counters.record_instruction(InstructionClass::Alu);
+    }
+    for _ in 0..2 {
+        counters.record_instruction(InstructionClass::Load);
+    }
+    counters.record_instruction(InstructionClass::Store);
+    counters.record_instruction(InstructionClass::BranchTaken);
+
+    assert_eq!(counters.total_instructions(), 10);
+    assert_eq!(counters.instructions(InstructionClass::Load), 2);
+
+    // 6 * 4 + 2 * 5 + 1 * 4 + 1 * 3
+    let model = CycleCostModel::default();
+    assert_eq!(counters.cycles(&model), 41);
+    assert_eq!(counters.cpi(&model), 4.1);
+
+    assert_eq!(counters.cpi(&CycleCostModel::single_cycle()), 1.0);
+}
+
+#[test]
+fn no_instructions() {
+    let counters = PerformanceCounters::default();
+
+    assert_eq!(counters.cycles(&CycleCostModel::default()), 0);
+    assert_eq!(counters.cpi(&CycleCostModel::default()), 0.0);
+}
+
+#[test]
+fn stage_counts() {
+    let mut counters = PerformanceCounters::default();
+    counters.record_stage(Stage::InstructionFetch);
+    counters.record_stage(Stage::InstructionFetch);
+    counters.record_stage(Stage::WriteBack);
+
+    assert_eq!(counters.stages(Stage::InstructionFetch), 2);
+    assert_eq!(counters.stages(Stage::Execute), 0);
+    assert_eq!(counters.total_stages(), 3);
+}
+
+#[test]
+fn report_lists_mix_and_cpi() {
+    let mut counters = PerformanceCounters::default();
+    counters.record_instruction(InstructionClass::Jump);
+    counters.record_instruction(InstructionClass::Fpu);
+
+    let report = counters.generate_report(&CycleCostModel::default(), None);
+
+    assert!(report.contains("Jump"));
+    assert!(report.contains("50.0%"));
+    assert!(report.contains("Instructions: 2  Cycles: 7  CPI: 3.50"));
+}
+
+#[test]
+fn report_prefers_measured_cycles() {
+    let mut counters = PerformanceCounters::default();
+    counters.record_instruction(InstructionClass::Alu);
+    counters.record_instruction(InstructionClass::Load);
+
+    let report = counters.generate_report(&CycleCostModel::default(), Some(6));
+
+    assert!(report.contains("Instructions: 2  Cycles: 6  CPI: 3.00"));
+    assert!(report.contains("Under the cost model: Cycles: 9  CPI: 4.50"));
+}
+
+#[test]
+fn set_cost_changes_one_class() {
+    let mut model = CycleCostModel::default();
+    model.set_cost(InstructionClass::Fpu, 12);
+
+    assert_eq!(model.cost(InstructionClass::Fpu), 12);
+    assert_eq!(
+        model,
+        CycleCostModel {
+            fpu: 12,
+            ..Default::default()
+        }
+    );
+}
@@ -0,0 +1,493 @@
+#![allow(clippy::unusual_byte_groupings)]
+
+use crate::emulation_core::datapath::Datapath;
+use crate::emulation_core::mips::memory::{Endianness, MemoryLayout};
+use crate::emulation_core::mips::pipelined_datapath::{ForwardingPath, MipsPipelinedDatapath};
+use crate::emulation_core::mips::registers::GpRegisterType;
+use crate::emulation_core::mips::system_coprocessor::ExceptionCode;
+use crate::parser::parser_assembler_main::{parser, parser_for_memory};
+
+/// Create a pipelined datapath with the given program loaded.
+fn load_program(program: &str, forwarding: bool) -> Result<MipsPipelinedDatapath, String> {
+    let mut datapath = MipsPipelinedDatapath {
+        forwarding,
+        ..Default::default()
+    };
+
+    let (_, instruction_bits) = parser(program.to_string());
+    datapath.initialize(instruction_bits)?;
+
+    Ok(datapath)
+}
+
+pub mod api {
+    use super::*;
+
+    #[test]
+    fn instructions_overlap_in_the_pipeline() -> Result<(), String> {
+        let mut datapath = load_program(
+            r#"ori $t0, $zero, 1
+ori $t1, $zero, 2
+ori $t2, $zero, 3
+ori $t3, $zero, 4
+ori $t4, $zero, 5"#,
+            true,
+        )?;
+
+        // After four cycles, the first four instructions are all in flight.
+        for _ in 0..4 {
+            datapath.execute_stage();
+        }
+
+        assert_eq!(datapath.if_id.as_ref().map(|i| i.pc), Some(12));
+        assert_eq!(datapath.id_ex.as_ref().map(|i| i.pc), Some(8));
+        assert_eq!(datapath.ex_mem.as_ref().map(|i| i.pc), Some(4));
+        assert_eq!(datapath.mem_wb.as_ref().map(|i| i.pc), Some(0));
+        assert_eq!(datapath.registers_written(), 0);
+
+        // Each following cycle finishes one instruction.
+        datapath.execute_stage();
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T0], 1);
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T1], 0);
+
+        datapath.execute_stage();
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T1], 2);
+        assert_eq!(datapath.cycles, 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn execute_instruction_runs_until_an_instruction_finishes() -> Result<(), String> {
+        let mut datapath = load_program("ori $t0, $zero, 1\nori $t1, $zero, 2", true)?;
+
+        datapath.execute_instruction();
+        assert_eq!(datapath.cycles, 5);
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T0], 1);
+
+        datapath.execute_instruction();
+        assert_eq!(datapath.cycles, 6);
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T1], 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn reset_keeps_forwarding_setting() -> Result<(), String> {
+        let mut datapath = load_program("ori $t0, $zero, 1", false)?;
+        datapath.execute_stage();
+
+        datapath.reset();
+
+        assert!(!datapath.forwarding);
+        assert!(datapath.if_id.is_none());
+        assert_eq!(datapath.cycles, 0);
+        assert_eq!(datapath.datapath.memory.memory[0], 0);
+
+        Ok(())
+    }
+
+    impl MipsPipelinedDatapath {
+        /// Count the general-purpose registers that are set, other than `$sp`.
+        fn registers_written(&self) -> usize {
+            let registers = &self.datapath.registers.gpr;
+            (0..32).filter(|&i| i != 29 && registers[i] != 0).count()
+        }
+    }
+}
+
+pub mod forwarding {
+    use super::*;
+
+    const DEPENDENT_INSTRUCTIONS: &str = r#"ori $t0, $zero, 5
+daddu $t1, $t0, $t0
+daddu $t2, $t1, $t0"#;
+
+    #[test]
+    fn forwarding_avoids_stalls() -> Result<(), String> {
+        let mut datapath = load_program(DEPENDENT_INSTRUCTIONS, true)?;
+
+        for _ in 0..3 {
+            datapath.execute_instruction();
+        }
+
+        assert_eq!(datapath.cycles, 7);
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T1], 10);
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T2], 15);
+
+        Ok(())
+    }
+
+    #[test]
+    fn forwarding_paths() -> Result<(), String> {
+        let mut datapath = load_program(DEPENDENT_INSTRUCTIONS, true)?;
+
+        // Cycle 5: `daddu $t2, $t1, $t0` is in EX. `$t1` comes from the
+        // instruction just before it, and `$t0` from the one before that.
+        for _ in 0..5 {
+            datapath.execute_stage();
+        }
+
+        assert_eq!(datapath.hazard_signals.forward_a, ForwardingPath::ExMem);
+        assert_eq!(datapath.hazard_signals.forward_b, ForwardingPath::MemWb);
+
+        Ok(())
+    }
+
+    #[test]
+    fn report_shows_pipeline_registers() -> Result<(), String> {
+        let mut datapath = load_program(DEPENDENT_INSTRUCTIONS, true)?;
+
+        for _ in 0..5 {
+            datapath.execute_stage();
+        }
+
+        // The `syscall` appended to the program waits in ID for the
+        // instructions ahead of it.
+        assert_eq!(
+            datapath.generate_report(),
+            "Pipeline registers after cycle 5:
+IF/ID\tPC: 0x0000000c  Instruction: 0x0000000c
+ID/EX\t(empty)
+EX/MEM\tPC: 0x00000008  Instruction: 0x0128502d  ALU result: 0xf  Write data: 0x5
+MEM/WB\tPC: 0x00000004  Instruction: 0x0108482d  ALU result: 0xa  Memory data: 0x0
+
+Stall: yes  Flush: no
+Forward A: EX/MEM  Forward B: MEM/WB
+"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_forwarding_stalls_until_writeback() -> Result<(), String> {
+        let mut datapath = load_program(DEPENDENT_INSTRUCTIONS, false)?;
+
+        for _ in 0..3 {
+            datapath.execute_instruction();
+        }
+
+        // Each dependent instruction waits two cycles for its operand.
+        assert_eq!(datapath.cycles, 11);
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T1], 10);
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T2], 15);
+
+        Ok(())
+    }
+
+    #[test]
+    fn load_use_stalls_once() -> Result<(), String> {
+        let mut datapath = load_program(
+            r#"ori $t0, $zero, 64
+sw $t0, 0($t0)
+lw $t1, 0($t0)
+daddu $t2, $t1, $t1"#,
+            true,
+        )?;
+
+        for _ in 0..3 {
+            datapath.execute_instruction();
+        }
+        assert_eq!(datapath.cycles, 7);
+
+        datapath.execute_instruction();
+        assert_eq!(datapath.cycles, 9);
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T2], 128);
+
+        Ok(())
+    }
+
+    #[test]
+    fn variable_shift_amount_is_forwarded() -> Result<(), String> {
+        let mut datapath = load_program(
+            r#"ori $t0, $zero, 3
+ori $t1, $zero, 1
+sllv $t2, $t1, $t0"#,
+            true,
+        )?;
+
+        for _ in 0..3 {
+            datapath.execute_instruction();
+        }
+
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T2], 8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn release_6_compact_branch_waits_for_rt() -> Result<(), String> {
+        for forwarding in [false, true] {
+            let mut datapath = MipsPipelinedDatapath {
+                forwarding,
+                ..Default::default()
+            };
+            datapath.datapath.release_6 = true;
+
+            let (_, instruction_bits) = parser_for_memory(
+                r#"ori $t0, $zero, 5
+ori $t1, $zero, 5
+beqc $t0, $t1, equal
+ori $s0, $zero, 1
+equal: nop"#
+                    .to_string(),
+                MemoryLayout::Flat,
+                Endianness::Big,
+                true,
+            );
+            datapath.initialize(instruction_bits)?;
+
+            while !datapath.is_halted() {
+                datapath.execute_stage();
+            }
+
+            assert_eq!(datapath.datapath.registers[GpRegisterType::S0], 0);
+        }
+
+        Ok(())
+    }
+}
+
+pub mod control_hazards {
+    use super::*;
+
+    #[test]
+    fn taken_branch_flushes_three_instructions() -> Result<(), String> {
+        let mut datapath = load_program(
+            r#"beq $zero, $zero, target
+ori $t0, $zero, 1
+ori $t1, $zero, 2
+ori $t2, $zero, 3
+target: ori $t3, $zero, 4"#,
+            true,
+        )?;
+
+        // The branch is resolved in MEM on cycle 4.
+        for _ in 0..4 {
+            datapath.execute_stage();
+        }
+        assert!(datapath.hazard_signals.flush);
+        assert!(datapath.if_id.is_none());
+        assert!(datapath.id_ex.is_none());
+        assert!(datapath.ex_mem.is_none());
+        assert_eq!(datapath.datapath.registers.pc, 16);
+
+        datapath.execute_instruction();
+        datapath.execute_instruction();
+
+        assert_eq!(datapath.cycles, 9);
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T0], 0);
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T1], 0);
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T2], 0);
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T3], 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn jump_and_return() -> Result<(), String> {
+        let mut datapath = load_program(
+            r#"jal function
+ori $t0, $zero, 1
+j done
+function: daddu $t1, $ra, $zero
+jr $ra
+done: ori $t2, $zero, 3"#,
+            true,
+        )?;
+
+        while !datapath.is_halted() {
+            datapath.execute_instruction();
+        }
+
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T0], 1);
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T1], 4);
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T2], 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn untaken_branch_does_not_flush() -> Result<(), String> {
+        let mut datapath = load_program(
+            r#"bne $zero, $zero, target
+ori $t0, $zero, 1
+target: ori $t1, $zero, 2"#,
+            true,
+        )?;
+
+        for _ in 0..3 {
+            datapath.execute_instruction();
+        }
+
+        assert_eq!(datapath.cycles, 7);
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T0], 1);
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T1], 2);
+
+        Ok(())
+    }
+}
+
+pub mod exceptions {
+    use super::*;
+
+    #[test]
+    fn fetch_past_the_text_is_not_reported() -> Result<(), String> {
+        let mut datapath = MipsPipelinedDatapath::default();
+
+        // Instructions after the final `syscall` are fetched from the data
+        // region, which cannot be executed, before the `syscall` halts.
+        let (program_info, assembled) = parser_for_memory(
+            "ori $t0, $zero, 1".to_string(),
+            MemoryLayout::Flat,
+            Endianness::Big,
+            false,
+        );
+        let (text, data) = assembled.split_at(program_info.instructions.len());
+        datapath.initialize_program(text.to_vec(), data.to_vec())?;
+
+        while !datapath.is_halted() {
+            datapath.execute_stage();
+        }
+
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T0], 1);
+        assert_eq!(datapath.datapath.memory_fault, None);
+        assert_eq!(datapath.datapath.system_coprocessor.exception_message, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn memory_fault_is_reported_when_taken() -> Result<(), String> {
+        let mut datapath = load_program("lw $t0, 2($zero)", true)?;
+
+        while !datapath.is_halted() {
+            datapath.execute_stage();
+        }
+
+        let fault = datapath
+            .datapath
+            .memory_fault
+            .as_ref()
+            .map(|f| (f.pc, f.address));
+        assert_eq!(fault, Some((0, 2)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn exception_is_precise() -> Result<(), String> {
+        let mut datapath = load_program(
+            r#"lui $t0, 0x7FFF
+add $t1, $t0, $t0
+ori $t2, $zero, 1
+ori $t3, $zero, 2"#,
+            true,
+        )?;
+
+        while !datapath.is_halted() {
+            datapath.execute_stage();
+        }
+
+        // No instruction after the overflowing `add` may finish.
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T1], 0);
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T2], 0);
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T3], 0);
+        assert_eq!(datapath.datapath.system_coprocessor.epc, 4);
+        assert_eq!(
+            datapath.datapath.system_coprocessor.exception,
+            Some(ExceptionCode::IntegerOverflow)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn exception_handler_discards_younger_instructions() -> Result<(), String> {
+        let mut datapath = MipsPipelinedDatapath::default();
+
+        let (program_info, instruction_bits) = parser(
+            r#"lui $t0, 0x7FFF
+add $t1, $t0, $t0
+sw $t0, 256($zero)
+ori $t2, $zero, 1
+exception_handler: ori $v0, $zero, 10
+syscall"#
+                .to_string(),
+        );
+        datapath.initialize(instruction_bits)?;
+        datapath.datapath.system_coprocessor.exception_handler =
+            program_info.exception_handler.map(|address| address as u64);
+
+        while !datapath.is_halted() {
+            datapath.execute_stage();
+        }
+
+        // The `sw` was about to access memory when the overflowing `add`
+        // redirected to the handler, so neither it nor the `ori` may finish.
+        assert_eq!(datapath.datapath.memory.load_word(256)?, 0);
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T2], 0);
+        assert_eq!(datapath.datapath.registers[GpRegisterType::V0], 10);
+        assert_eq!(datapath.datapath.system_coprocessor.epc, 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn reserved_instruction_keeps_message() -> Result<(), String> {
+        let mut datapath = MipsPipelinedDatapath::default();
+
+        let instructions: Vec<u32> = vec![
+            0b001101_00000_01000_0000000000000111, // ori $t0, $zero, 7
+            0b011111_00000_00000_0000000000000000, // (reserved instruction)
+        ];
+        datapath.initialize(instructions)?;
+
+        while !datapath.is_halted() {
+            datapath.execute_stage();
+        }
+
+        assert_eq!(
+            datapath.datapath.system_coprocessor.exception,
+            Some(ExceptionCode::ReservedInstruction)
+        );
+        assert_eq!(
+            datapath
+                .datapath
+                .system_coprocessor
+                .exception_message
+                .as_deref(),
+            Some("opcode `31` not supported")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn flushed_instruction_does_not_raise_exception() -> Result<(), String> {
+        let mut datapath = MipsPipelinedDatapath::default();
+
+        let instructions: Vec<u32> = vec![
+            0b000010_00000000000000000000000010,     // j 8
+            0b011111_00000_00000_0000000000000000,   // (reserved instruction)
+            0b001101_00000_01000_0000000000000111,   // ori $t0, $zero, 7
+            0b000000_00000_00000_00000_00000_001100, // syscall
+        ];
+        datapath.initialize(instructions)?;
+
+        while !datapath.is_halted() {
+            datapath.execute_stage();
+        }
+
+        assert_eq!(datapath.datapath.registers[GpRegisterType::T0], 7);
+        assert_eq!(datapath.datapath.system_coprocessor.epc, 12);
+        assert_eq!(
+            datapath.datapath.system_coprocessor.exception,
+            Some(ExceptionCode::Syscall)
+        );
+        assert_eq!(datapath.datapath.system_coprocessor.exception_message, None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+//! Covering the shift and logical instructions.
+
+use super::*;
+
+#[test]
+// Counts the number of set bits in a doubleword.
+fn population_count() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    let instructions = String::from(
+        r#"lui $t0, 61680
+dsll32 $t0, $t0, 0
+ori $t0, $t0, 4369
+li $s0, 0
+loop: beq $t0, $zero, done
+andi $t1, $t0, 1
+daddu $s0, $s0, $t1
+dsrl $t0, $t0, 1
+j loop
+done: nop"#,
+    );
+
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    // 0xF0F0_0000_0000_1111 has 12 set bits.
+    assert_eq!(datapath.registers.gpr[16], 12); // $s0
+
+    Ok(())
+}
+
+#[test]
+fn swap_with_xor() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    let instructions = String::from(
+        r#"li $s0, 1234
+li $s1, 5678
+xor $s0, $s0, $s1
+xor $s1, $s0, $s1
+xor $s0, $s0, $s1
+nor $s2, $zero, $zero
+xori $s3, $s2, 255"#,
+    );
+
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.registers.gpr[16], 5678); // $s0
+    assert_eq!(datapath.registers.gpr[17], 1234); // $s1
+    assert_eq!(datapath.registers.gpr[18], u64::MAX); // $s2
+    assert_eq!(datapath.registers.gpr[19], 0xFFFF_FFFF_FFFF_FF00); // $s3
+
+    Ok(())
+}
+
+#[test]
+fn variable_shifts() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    let instructions = String::from(
+        r#"li $t0, 4
+li $s0, 1
+sllv $s1, $s0, $t0
+srav $s2, $s1, $t0
+li $t1, 65535
+sll $t1, $t1, 16
+sra $s3, $t1, 16
+srl $s4, $t1, 16"#,
+    );
+
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.registers.gpr[17], 16); // $s1
+    assert_eq!(datapath.registers.gpr[18], 1); // $s2
+    assert_eq!(datapath.registers.gpr[19], u64::MAX); // $s3
+    assert_eq!(datapath.registers.gpr[20], 0xFFFF); // $s4
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+//! Tests for running programs with branch delay slots enabled.
+
+use super::*;
+
+#[test]
+// Sums 1 through 10, filling the delay slots by hand.
+fn filled_delay_slots() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+    datapath.branch_delay_slots = true;
+
+    let instructions = String::from(
+        r#".set noreorder
+li $t0, 10
+li $s0, 0
+loop: daddu $s0, $s0, $t0
+bgtz $t0, loop
+daddiu $t0, $t0, -1
+jal function
+li $s1, 5
+j done
+nop
+function: jr $ra
+daddiu $s1, $s1, 1
+done: nop"#,
+    );
+
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.registers.gpr[16], 55); // $s0
+    assert_eq!(datapath.registers.gpr[17], 6); // $s1
+    assert_eq!(datapath.registers.gpr[8], u64::MAX); // $t0
+
+    Ok(())
+}
+
+#[test]
+// The same program gives the same result with or without delay slots
+// when the assembler fills them.
+fn reorder_matches_no_delay_slots() -> Result<(), String> {
+    let instructions = String::from(
+        r#".set reorder
+li $t0, 10
+li $s0, 0
+loop: daddu $s0, $s0, $t0
+daddiu $t0, $t0, -1
+bgtz $t0, loop
+jal function
+j done
+function: daddiu $s1, $s0, 1
+jr $ra
+done: nop"#,
+    );
+
+    for branch_delay_slots in [false, true] {
+        let mut datapath = MipsDatapath::default();
+        datapath.branch_delay_slots = branch_delay_slots;
+
+        let (_, instruction_bits) = parser(instructions.clone());
+        datapath.initialize(instruction_bits)?;
+
+        while !datapath.is_halted() {
+            datapath.execute_instruction();
+        }
+
+        assert_eq!(datapath.registers.gpr[16], 55); // $s0
+        assert_eq!(datapath.registers.gpr[17], 56); // $s1
+    }
+
+    Ok(())
+}
@@ -1,4 +1,8 @@
-//! Tests for the branch and jump instructions: j, jr, jal, jalr, beq, bne
+//! Tests for the branch and jump instructions: j, jr, jal, jalr, beq, bne, the
+//! branches comparing with zero, and the compact branches.
+
+use crate::emulation_core::mips::memory::{Endianness, MemoryLayout};
+use crate::parser::parser_assembler_main::parser_for_memory;
 
 use super::*;
 
@@ -238,3 +242,204 @@ fn basic_bne() -> Result<(), String> {
 
     Ok(())
 }
+
+#[test]
+// Computes the absolute value of each of a few signed values.
+fn abs_with_bgez() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    let instructions = String::from(
+        r#"daddiu $a0, $zero, -42
+jal abs
+move $s0, $v0
+li $a0, 17
+jal abs
+move $s1, $v0
+j done
+abs: move $v0, $a0
+bgez $a0, return
+dsub $v0, $zero, $a0
+return: jr $ra
+done: nop"#,
+    );
+
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.registers.gpr[16], 42); // $s0
+    assert_eq!(datapath.registers.gpr[17], 17); // $s1
+
+    Ok(())
+}
+
+#[test]
+// Counts down from 5 to 0 with blez and bgtz.
+fn countdown_with_blez_bgtz() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    let instructions = String::from(
+        r#"li $t0, 5
+li $s0, 0
+loop: blez $t0, done
+daddiu $s0, $s0, 1
+daddiu $t0, $t0, -1
+bgtz $t0, loop
+done: nop"#,
+    );
+
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.registers.gpr[16], 5); // $s0
+    assert_eq!(datapath.registers.gpr[8], 0); // $t0
+
+    Ok(())
+}
+
+#[test]
+fn bltzal_and_bgezal_link() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    let instructions = String::from(
+        r#"daddiu $t0, $zero, -1
+bltzal $t0, negative
+li $s1, 2
+bgezal $zero, done
+negative: li $s0, 1
+jr $ra
+done: nop"#,
+    );
+
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.registers.gpr[16], 1); // $s0
+    assert_eq!(datapath.registers.gpr[17], 2); // $s1
+
+    Ok(())
+}
+
+#[test]
+// Finds the maximum of three signed values using the compact branches.
+fn compact_branches() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    let instructions = String::from(
+        r#"daddiu $t0, $zero, -7
+li $t1, 12
+li $t2, 3
+move $s0, $t0
+bgec $s0, $t1, skip1
+move $s0, $t1
+skip1: bltc $t2, $s0, skip2
+move $s0, $t2
+skip2: li $t3, 3
+li $s1, 0
+loop: daddiu $s1, $s1, 10
+daddiu $t3, $t3, -1
+bnezc $t3, loop
+beqzc $t3, call
+li $s1, 0
+call: balc function
+bc done
+function: li $s2, 99
+jr $ra
+done: nop"#,
+    );
+
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    let mut iterations = 0;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+        iterations += 1;
+
+        // Catch an infinite loop. This program should not cause over 300 instructions to run.
+        if iterations > 300 {
+            return Err(String::from(
+                "Infinite loop detected: {iterations} instructions executed.",
+            ));
+        }
+    }
+
+    assert_eq!(datapath.registers.gpr[16], 12); // $s0
+    assert_eq!(datapath.registers.gpr[17], 30); // $s1
+    assert_eq!(datapath.registers.gpr[18], 99); // $s2
+
+    Ok(())
+}
+
+#[test]
+// Counts how many times a value can be added before the sum overflows a
+// word, then checks and links using the compact branches of release 6 mode.
+fn release_6_compact_branches() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+    datapath.release_6 = true;
+
+    let instructions = String::from(
+        r#"lui $t0, 0x1000
+move $t1, $zero
+loop: daddiu $s0, $s0, 1
+addu $t1, $t1, $t0
+bnvc $t1, $t0, loop
+li $t2, 7
+beqc $s0, $t2, equal
+li $s1, 1
+equal: bnec $t2, $s0, done
+move $t3, $zero
+beqzalc $t3, function
+bovc $s0, $t2, done
+li $s3, 5
+done: bnezalc $t3, function
+j end
+function: li $s2, 99
+jr $ra
+end: nop"#,
+    );
+
+    let (program_info, instruction_bits) =
+        parser_for_memory(instructions, MemoryLayout::Flat, Endianness::Big, true);
+    assert!(program_info
+        .instructions
+        .iter()
+        .all(|instruction| instruction.errors.is_empty()));
+    datapath.initialize(instruction_bits)?;
+
+    let mut iterations = 0;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+        iterations += 1;
+
+        // Catch an infinite loop. This program should not cause over 100 instructions to run.
+        if iterations > 100 {
+            return Err(String::from(
+                "Infinite loop detected: {iterations} instructions executed.",
+            ));
+        }
+    }
+
+    assert_eq!(datapath.registers.gpr[16], 7); // $s0
+    assert_eq!(datapath.registers.gpr[17], 0); // $s1
+    assert_eq!(datapath.registers.gpr[18], 99); // $s2
+    assert_eq!(datapath.registers.gpr[19], 5); // $s3
+
+    // `bnezalc` links even though it is not taken.
+    assert_eq!(datapath.registers.gpr[31], 14 * 4); // $ra
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+//! Tests of the branch predictor attached to the datapath, running whole
+//! programs.
+
+use crate::emulation_core::mips::branch_predictor::{
+    BranchPredictor, BranchPredictorConfig, PredictorKind,
+};
+use crate::emulation_core::mips::multi_cycle_datapath::MipsMultiCycleDatapath;
+use crate::emulation_core::mips::registers::GpRegisterType;
+
+use super::*;
+
+/// Counts to eight in a loop, then jumps over an instruction.
+const COUNTING_LOOP: &str = r#"ori $t1, $zero, 0
+ori $t2, $zero, 8
+loop: daddiu $t1, $t1, 1
+bne $t1, $t2, loop
+j done
+ori $t3, $zero, 1
+done: ori $t4, $zero, 1"#;
+
+fn predictor(kind: PredictorKind) -> BranchPredictor {
+    BranchPredictor::new(BranchPredictorConfig {
+        kind,
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+#[test]
+fn predictions_are_recorded_by_source_line() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+    datapath.branch_predictor = Some(predictor(PredictorKind::TwoBit));
+
+    let (program_info, instruction_bits) = parser(COUNTING_LOOP.to_string());
+    datapath.initialize(instruction_bits)?;
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    // The predictor does not change the results of the program.
+    assert_eq!(datapath.registers[GpRegisterType::T1], 8);
+    assert_eq!(datapath.registers[GpRegisterType::T3], 0);
+
+    let predictor = datapath.branch_predictor.as_ref().unwrap();
+
+    // The first and last outcomes of the loop branch are mispredicted.
+    assert_eq!(predictor.statistics.branches, 8);
+    assert_eq!(predictor.statistics.taken, 7);
+    assert_eq!(predictor.statistics.correct, 6);
+
+    let lines = predictor.line_statistics(&program_info.address_to_line_number, 0);
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[&3].branches, 8);
+    assert_eq!(lines[&4].branches, 0);
+    assert_eq!(lines[&4].btb_lookups, 1);
+
+    // The loop branch misses in the branch target buffer once.
+    assert_eq!(predictor.statistics.btb_lookups, 8);
+    assert_eq!(predictor.statistics.btb_hits, 6);
+
+    Ok(())
+}
+
+#[test]
+fn multi_cycle_datapath_checks_predictions() -> Result<(), String> {
+    let mut datapath = MipsMultiCycleDatapath::default();
+    datapath.datapath.branch_predictor = Some(predictor(PredictorKind::StaticNotTaken));
+
+    let (_, instruction_bits) = parser(COUNTING_LOOP.to_string());
+    datapath.initialize(instruction_bits)?;
+    while !datapath.is_halted() {
+        datapath.execute_stage();
+    }
+
+    let predictor = datapath.datapath.branch_predictor.as_ref().unwrap();
+    assert_eq!(predictor.statistics.branches, 8);
+    assert_eq!(predictor.statistics.correct, 1);
+
+    Ok(())
+}
+
+#[test]
+fn reset_clears_statistics_but_keeps_predictor() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+    datapath.branch_predictor = Some(predictor(PredictorKind::Gshare));
+
+    let (_, instruction_bits) = parser(COUNTING_LOOP.to_string());
+    datapath.initialize(instruction_bits)?;
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    datapath.reset();
+
+    assert_eq!(
+        datapath.branch_predictor,
+        Some(predictor(PredictorKind::Gshare))
+    );
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+//! Tests of the caches placed in front of memory, running whole programs.
+
+use crate::emulation_core::mips::cache::{Cache, CacheConfig, CacheHierarchy};
+use crate::emulation_core::mips::registers::GpRegisterType;
+
+use super::*;
+
+/// Stores to and loads from eight consecutive words, two 16-byte blocks.
+const WORD_LOOP: &str = r#"ori $t0, $zero, 256
+ori $t1, $zero, 0
+ori $t2, $zero, 8
+loop: sw $t1, 0($t0)
+lw $t3, 0($t0)
+daddiu $t0, $t0, 4
+daddiu $t1, $t1, 1
+bne $t1, $t2, loop"#;
+
+/// Run a program to completion with the given caches.
+fn run_with_cache(program: &str, cache: CacheHierarchy) -> Result<MipsDatapath, String> {
+    let mut datapath = MipsDatapath::default();
+    datapath.cache = Some(cache);
+
+    let (_, instruction_bits) = parser(program.to_string());
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    Ok(datapath)
+}
+
+#[test]
+fn split_caches_record_fetches_and_data_accesses() -> Result<(), String> {
+    let datapath = run_with_cache(WORD_LOOP, CacheHierarchy::default())?;
+
+    // The caches do not change the results of the program.
+    assert_eq!(datapath.registers[GpRegisterType::T3], 7);
+    assert_eq!(datapath.memory.load_word(284)?, 7);
+
+    let Some(CacheHierarchy::Split {
+        instruction: Some(instruction),
+        data: Some(data),
+    }) = &datapath.cache
+    else {
+        panic!("expected split instruction and data caches");
+    };
+
+    // Each of the two blocks misses once, on its first store.
+    assert_eq!(data.statistics.writes, 8);
+    assert_eq!(data.statistics.reads, 8);
+    assert_eq!(data.statistics.misses, 2);
+    assert_eq!(data.statistics.hits, 14);
+
+    // The loop body is fetched eight times, but each block of it misses once.
+    assert_eq!(instruction.statistics.misses, 3);
+    assert!(instruction.statistics.hits >= 40);
+    assert_eq!(instruction.statistics.writes, 0);
+
+    Ok(())
+}
+
+#[test]
+fn unified_cache_records_all_accesses() -> Result<(), String> {
+    let cache = Cache::new(CacheConfig::default())?;
+    let datapath = run_with_cache(WORD_LOOP, CacheHierarchy::Unified(cache))?;
+
+    let Some(CacheHierarchy::Unified(cache)) = &datapath.cache else {
+        panic!("expected a unified cache");
+    };
+
+    assert_eq!(cache.statistics.writes, 8);
+    assert_eq!(cache.statistics.misses, 5);
+    assert_eq!(
+        cache.statistics.reads,
+        cache.statistics.hits + cache.statistics.misses - 8
+    );
+
+    Ok(())
+}
+
+#[test]
+fn reset_clears_statistics_but_keeps_caches() -> Result<(), String> {
+    let mut datapath = run_with_cache(WORD_LOOP, CacheHierarchy::default())?;
+
+    datapath.reset();
+
+    assert_eq!(datapath.cache, Some(CacheHierarchy::default()));
+
+    Ok(())
+}
@@ -0,0 +1,154 @@
+//! Tests for the conditional move and select instructions: movz, movn, movf, movt,
+//! seleqz, selnez, and their floating-point counterparts.
+
+use super::*;
+
+fn run(program: &str) -> Result<MipsDatapath, String> {
+    let mut datapath = MipsDatapath::default();
+
+    let (_, instruction_bits) = parser(program.to_string());
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    Ok(datapath)
+}
+
+#[test]
+fn branch_free_max() -> Result<(), String> {
+    // $s0 = max($t0, $t1), without a single branch.
+    let datapath = run(r#"ori $t0, $zero, 17
+ori $t1, $zero, 42
+move $s0, $t0
+slt $t2, $t0, $t1
+movn $s0, $t1, $t2"#)?;
+
+    assert_eq!(datapath.registers.gpr[16], 42); // $s0
+
+    Ok(())
+}
+
+#[test]
+fn movz_and_movn_leave_destination_alone() -> Result<(), String> {
+    let datapath = run(r#"ori $t0, $zero, 5
+ori $t1, $zero, 1
+ori $s0, $zero, 77
+ori $s1, $zero, 88
+movz $s0, $t0, $t1
+movn $s1, $t0, $zero"#)?;
+
+    assert_eq!(datapath.registers.gpr[16], 77); // $s0
+    assert_eq!(datapath.registers.gpr[17], 88); // $s1
+
+    Ok(())
+}
+
+#[test]
+fn movt_and_movf_follow_comparison() -> Result<(), String> {
+    // 1.0 < 5.0 sets cc 0 and cc 2. Only the moves matching each code happen.
+    let datapath = run(r#"lui $s0, 0x3F80
+lui $s1, 0x40A0
+mtc1 $s0, $f1
+mtc1 $s1, $f2
+c.lt.s $f1, $f2
+c.lt.s 2, $f1, $f2
+ori $t0, $zero, 9
+movt $t1, $t0
+movf $t2, $t0
+movt $t3, $t0, 2
+movf $t4, $t0, 5"#)?;
+
+    assert_eq!(datapath.registers.gpr[9], 9); // $t1
+    assert_eq!(datapath.registers.gpr[10], 0); // $t2
+    assert_eq!(datapath.registers.gpr[11], 9); // $t3
+    assert_eq!(datapath.registers.gpr[12], 9); // $t4
+
+    Ok(())
+}
+
+#[test]
+fn seleqz_and_selnez() -> Result<(), String> {
+    // Selecting with both and OR-ing the results gives a branch-free ternary.
+    let datapath = run(r#"ori $t0, $zero, 3
+ori $t1, $zero, 4
+ori $t2, $zero, 0
+seleqz $s0, $t0, $t2
+selnez $s1, $t1, $t2
+or $s2, $s0, $s1"#)?;
+
+    assert_eq!(datapath.registers.gpr[16], 3); // $s0
+    assert_eq!(datapath.registers.gpr[17], 0); // $s1
+    assert_eq!(datapath.registers.gpr[18], 3); // $s2
+
+    Ok(())
+}
+
+#[test]
+fn movz_s_and_movn_d_use_general_purpose_register() -> Result<(), String> {
+    let datapath = run(r#"lui $s0, 0x3F80
+dmtc1 $s0, $f1
+ori $t0, $zero, 1
+movz.s $f2, $f1, $zero
+movn.d $f3, $f1, $t0
+movn.s $f4, $f1, $zero"#)?;
+
+    assert_eq!(f32::from_bits(datapath.coprocessor.fpr[2] as u32), 1.0);
+    assert_eq!(datapath.coprocessor.fpr[3], 0x3F80_0000);
+    assert_eq!(datapath.coprocessor.fpr[4], 0);
+
+    Ok(())
+}
+
+#[test]
+fn movt_d_and_movf_s_with_condition_code() -> Result<(), String> {
+    let datapath = run(r#"lui $s0, 0x3F80
+mtc1 $s0, $f1
+cvt.d.s $f6, $f1
+c.eq.s 4, $f1, $f1
+movt.d $f2, $f6, 4
+movf.s $f3, $f1, 4
+movf.s $f4, $f1, 1"#)?;
+
+    assert_eq!(f64::from_bits(datapath.coprocessor.fpr[2]), 1.0);
+    assert_eq!(datapath.coprocessor.fpr[3], 0);
+    assert_eq!(f32::from_bits(datapath.coprocessor.fpr[4] as u32), 1.0);
+
+    Ok(())
+}
+
+#[test]
+fn sel_d_picks_by_destination_bit() -> Result<(), String> {
+    // cmp.lt.d writes all ones or all zeros to its destination, which sel.d consumes.
+    let datapath = run(r#"lui $s0, 0x3FF0
+lui $s1, 0x4014
+dsll32 $s0, $s0, 0
+dsll32 $s1, $s1, 0
+dmtc1 $s0, $f1
+dmtc1 $s1, $f2
+cmp.lt.d $f3, $f1, $f2
+sel.d $f3, $f1, $f2
+cmp.lt.d $f4, $f2, $f1
+sel.d $f4, $f1, $f2"#)?;
+
+    assert_eq!(f64::from_bits(datapath.coprocessor.fpr[3]), 5.0);
+    assert_eq!(f64::from_bits(datapath.coprocessor.fpr[4]), 1.0);
+
+    Ok(())
+}
+
+#[test]
+fn seleqz_s_and_selnez_s() -> Result<(), String> {
+    let datapath = run(r#"lui $s0, 0x4040
+ori $t0, $zero, 1
+mtc1 $s0, $f1
+mtc1 $t0, $f2
+seleqz.s $f3, $f1, $f2
+selnez.s $f4, $f1, $f2"#)?;
+
+    assert_eq!(datapath.coprocessor.fpr[3], 0);
+    assert_eq!(f32::from_bits(datapath.coprocessor.fpr[4] as u32), 3.0);
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use crate::emulation_core::mips::registers::GpRegisterType;
+use crate::emulation_core::mips::system_coprocessor::ExceptionCode;
+
+use super::*;
+
+#[test]
+fn handler_skips_overflowing_instruction() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    // The exception handler saves the cause of the exception in $s0, then
+    // resumes execution after the instruction that raised it.
+    let instructions = String::from(
+        r#"lui $t0, 32767
+add $t1, $t0, $t0
+ori $s1, $zero, 1
+ori $v0, $zero, 10
+syscall
+exception_handler: mfc0 $s0, $13
+mfc0 $k0, $14
+addiu $k0, $k0, 4
+mtc0 $k0, $14
+eret"#,
+    );
+
+    let (program_info, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+    datapath.system_coprocessor.exception_handler =
+        program_info.exception_handler.map(|address| address as u64);
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.registers[GpRegisterType::T1], 0);
+    assert_eq!(datapath.registers[GpRegisterType::S1], 1);
+    assert_eq!(
+        datapath.registers[GpRegisterType::S0],
+        (ExceptionCode::IntegerOverflow as u64) << 2
+    );
+
+    Ok(())
+}
+
+#[test]
+fn unhandled_exception_halts() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    let instructions = String::from(
+        r#"break
+ori $s1, $zero, 1"#,
+    );
+
+    let (program_info, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+    assert_eq!(program_info.exception_handler, None);
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.registers[GpRegisterType::S1], 0);
+    assert_eq!(datapath.system_coprocessor.epc, 0);
+
+    Ok(())
+}
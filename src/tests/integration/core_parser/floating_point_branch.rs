@@ -1,4 +1,4 @@
-//! Tests for the floating-point branch instructions: bc1t, bc1f
+//! Tests for the floating-point branch instructions: bc1t, bc1f, bc1eqz, bc1nez
 
 use super::*;
 
@@ -87,3 +87,141 @@ fn bc1f_branch() -> Result<(), String> {
 
     Ok(())
 }
+
+#[test]
+// Branch on condition code 2 while condition code 0 holds a different result.
+fn bc1t_branch_on_condition_code() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    // Count up $f0 by 1.0 to 5.0, adding 7 to $s2 on each iteration.
+    // Condition code 0 is set by a comparison that is always false.
+    let instructions = String::from(
+        r#"lui $s0, 0x3F80
+lui $s1, 0x40A0
+ori $s2, $zero, 0
+mtc1 $zero, $f0
+mtc1 $s0, $f1
+mtc1 $s1, $f2
+
+loop:
+add.s $f0, $f0, $f1
+addiu $s2, $s2, 7
+c.lt.s 2, $f0, $f2
+c.lt.s $f2, $f0
+bc1t 2, loop
+bc1t loop"#,
+    );
+
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.registers.gpr[18], 35); // $s2
+    assert_eq!(f32::from_bits(datapath.coprocessor.fpr[0] as u32), 5.0); // $f0
+    assert_eq!(datapath.coprocessor.condition_code, 0b000);
+
+    Ok(())
+}
+
+#[test]
+fn bc1f_branch_on_condition_code() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    // Condition code 6 is false, so the ori is skipped. Condition code 0
+    // is true, so bc1f without a condition code does not branch.
+    let instructions = String::from(
+        r#"c.eq.d 6, $f1, $f2
+c.eq.d $f1, $f1
+bc1f 6, skip
+ori $s0, $zero, 1
+skip: bc1f end
+ori $s1, $zero, 1
+end: ori $s2, $zero, 1"#,
+    );
+
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    datapath.coprocessor.fpr[1] = f64::to_bits(1.0);
+    datapath.coprocessor.fpr[2] = f64::to_bits(2.0);
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.registers.gpr[16], 0); // $s0
+    assert_eq!(datapath.registers.gpr[17], 1); // $s1
+    assert_eq!(datapath.registers.gpr[18], 1); // $s2
+
+    Ok(())
+}
+
+#[test]
+fn bc1nez_branch() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    // The same loop as bc1t_branch, using the mask written by cmp.lt.s.
+    let instructions = String::from(
+        r#"lui $s0, 0x3F80
+lui $s1, 0x40A0
+ori $s2, $zero, 0
+mtc1 $zero, $f0
+mtc1 $s0, $f1
+mtc1 $s1, $f2
+
+loop:
+add.s $f0, $f0, $f1
+addiu $s2, $s2, 7
+cmp.lt.s $f3, $f0, $f2
+bc1nez $f3, loop"#,
+    );
+
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.registers.gpr[18], 35); // $s2
+    assert_eq!(f32::from_bits(datapath.coprocessor.fpr[0] as u32), 5.0); // $f0
+    assert_eq!(datapath.coprocessor.fpr[3], 0);
+
+    Ok(())
+}
+
+#[test]
+fn bc1eqz_branch() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    // Repeat until $f0 is no longer less than $f2.
+    let instructions = String::from(
+        r#"lui $s0, 0x3F80
+lui $s1, 0x40A0
+ori $s2, $zero, 0
+mtc1 $zero, $f0
+mtc1 $s0, $f1
+mtc1 $s1, $f2
+
+loop:
+add.s $f0, $f0, $f1
+addiu $s2, $s2, 7
+cmp.le.s $f3, $f2, $f0
+bc1eqz $f3, loop"#,
+    );
+
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.registers.gpr[18], 35); // $s2
+    assert_eq!(datapath.coprocessor.fpr[3], u64::MAX);
+
+    Ok(())
+}
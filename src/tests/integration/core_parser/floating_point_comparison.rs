@@ -1,4 +1,8 @@
-//! Tests for the floating-point comparison instructions: c.eq.s, c.eq.d, c.lt.s, c.lt.d, c.le.s, c.le.d, c.ngt.s, c.ngt.d, c.nge.s, c.nge.d
+//! Tests for the floating-point comparison instructions: c.eq.s, c.eq.d, c.lt.s, c.lt.d, c.le.s, c.le.d, c.ngt.s, c.ngt.d, c.nge.s, c.nge.d,
+//! cmp.cond.s, cmp.cond.d
+
+use crate::emulation_core::mips::coprocessor::{FCR_FCCR, FCR_FCSR, FCSR_FLAGS_SHIFT};
+use crate::emulation_core::mips::floating_point::FLAG_INVALID;
 
 use super::*;
 
@@ -59,3 +63,111 @@ fn basic_~*instruction_name() -> Result<(), String> {
         Ok(())
     }
 }
+
+#[test]
+// Each comparison writes only the condition code it names, which is 0 if
+// none is given.
+fn condition_codes_are_independent() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    let instructions = String::from(
+        r#"c.lt.s 3, $f15, $f16
+c.eq.s 5, $f15, $f16
+c.le.s 7, $f16, $f15
+c.eq.s $f15, $f15"#,
+    );
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    datapath.coprocessor.fpr[15] = f32::to_bits(1.5) as u64;
+    datapath.coprocessor.fpr[16] = f32::to_bits(2.5) as u64;
+    // Condition code 7 starts out set and is cleared by the comparison.
+    datapath.coprocessor.condition_code = 0b1000_0000;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.coprocessor.condition_code, 0b0000_1001);
+    assert_eq!(
+        datapath.coprocessor.read_control_register(FCR_FCCR),
+        0b0000_1001
+    );
+    // FCC0 is in bit 23, and FCC1 to FCC7 are in bits 25 to 31.
+    assert_eq!(
+        datapath.coprocessor.read_control_register(FCR_FCSR) & 0xFE80_0000,
+        (1 << 23) | (1 << 27)
+    );
+    Ok(())
+}
+
+akin! {
+    let &condition = [af,    un,    eq,    ueq,   lt,    ult,   le,    ule,   or,    une,   ne,    slt];
+    // The results comparing 1.0 with 2.0, 2.0 with 2.0, and 1.0 with NaN.
+    let &less =      [false, false, false, false, true,  true,  true,  true,  true,  true,  true,  true];
+    let &equal =     [false, false, true,  true,  false, false, true,  true,  true,  false, false, false];
+    let &unordered = [false, true,  false, true,  false, true,  false, true,  false, true,  false, false];
+
+    #[test]
+    fn cmp_~*condition() -> Result<(), String> {
+        for (format, one, two, nan) in [
+            ("s", f32::to_bits(1.0) as u64, f32::to_bits(2.0) as u64, f32::to_bits(f32::NAN) as u64),
+            ("d", f64::to_bits(1.0), f64::to_bits(2.0), f64::to_bits(f64::NAN)),
+        ] {
+            let mut datapath = MipsDatapath::default();
+
+            let operator = format!("cmp.{}.{}", stringify!(*condition), format);
+            let instructions = format!(
+                "{0} $f20, $f1, $f2\n{0} $f21, $f2, $f2\n{0} $f22, $f1, $f3",
+                operator
+            );
+            let (_, instruction_bits) = parser(instructions);
+            datapath.initialize(instruction_bits)?;
+
+            datapath.coprocessor.fpr[1] = one;
+            datapath.coprocessor.fpr[2] = two;
+            datapath.coprocessor.fpr[3] = nan;
+
+            while !datapath.is_halted() {
+                datapath.execute_instruction();
+            }
+
+            let mask = |holds: bool| if holds { u64::MAX } else { 0 };
+            assert_eq!(datapath.coprocessor.fpr[20], mask(*less));
+            assert_eq!(datapath.coprocessor.fpr[21], mask(*equal));
+            assert_eq!(datapath.coprocessor.fpr[22], mask(*unordered));
+            // cmp.cond.fmt does not use the condition codes.
+            assert_eq!(datapath.coprocessor.condition_code, 0);
+        }
+        Ok(())
+    }
+}
+
+#[test]
+// Only the signaling conditions raise an invalid operation for NaN.
+fn cmp_signaling_condition_with_nan_is_invalid() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    let instructions =
+        String::from("cmp.lt.d $f20, $f1, $f2\ncfc1 $t0, $31\ncmp.slt.d $f21, $f1, $f2");
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    datapath.coprocessor.fpr[1] = f64::to_bits(f64::NAN);
+    datapath.coprocessor.fpr[2] = f64::to_bits(1.0);
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(
+        datapath.registers.gpr[8] as u32 >> FCSR_FLAGS_SHIFT & 0x1F,
+        0
+    ); // $t0
+    assert_eq!(
+        datapath.coprocessor.fcsr >> FCSR_FLAGS_SHIFT & 0x1F,
+        FLAG_INVALID
+    );
+    assert_eq!(datapath.coprocessor.fpr[21], 0);
+    Ok(())
+}
@@ -0,0 +1,240 @@
+//! Tests for the floating-point unary, min/max, and conversion instructions: abs.fmt, neg.fmt,
+//! mov.fmt, sqrt.fmt, recip.fmt, rsqrt.fmt, min.fmt, max.fmt, cvt.*.fmt, trunc.*.fmt,
+//! round.*.fmt, ceil.*.fmt, floor.*.fmt
+
+use super::*;
+
+akin! {
+    let &instruction_name = [abs_s,                 neg_s,                 mov_s,                 sqrt_s,                 recip_s,                 rsqrt_s];
+    let &instruction =      ["abs.s $f20, $f15",    "neg.s $f21, $f15",    "mov.s $f22, $f15",    "sqrt.s $f23, $f16",    "recip.s $f24, $f16",    "rsqrt.s $f25, $f16"];
+    let &value1 =           [-2.5f32,               -2.5f32,               -2.5f32,               16.0f32,                16.0f32,                 16.0f32];
+    let &result_register =  [20,                    21,                    22,                    23,                     24,                      25];
+    let &expected_result =  [2.5f32,                2.5f32,                -2.5f32,               4.0f32,                 0.0625f32,               0.25f32];
+
+    #[test]
+    fn basic_~*instruction_name() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        let instructions = String::from(*instruction);
+        let (_, instruction_bits) = parser(instructions);
+        datapath.initialize(instruction_bits)?;
+
+        datapath.coprocessor.fpr[15] = f32::to_bits(*value1) as u64;
+        datapath.coprocessor.fpr[16] = f32::to_bits(*value1) as u64;
+
+        while !datapath.is_halted() {
+            datapath.execute_instruction();
+        }
+
+        assert_eq!(datapath.coprocessor.fpr[*result_register], f32::to_bits(*expected_result) as u64);
+        Ok(())
+    }
+}
+
+akin! {
+    let &instruction_name = [abs_d,                 neg_d,                 mov_d,                 sqrt_d,                 recip_d,                 rsqrt_d];
+    let &instruction =      ["abs.d $f20, $f15",    "neg.d $f21, $f15",    "mov.d $f22, $f15",    "sqrt.d $f23, $f16",    "recip.d $f24, $f16",    "rsqrt.d $f25, $f16"];
+    let &value1 =           [-120.125,              120.125,               -120.125,              6.25,                   0.5,                     0.25];
+    let &result_register =  [20,                    21,                    22,                    23,                     24,                      25];
+    let &expected_result =  [120.125,               -120.125,              -120.125,              2.5,                    2.0,                     2.0];
+
+    #[test]
+    fn basic_~*instruction_name() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        let instructions = String::from(*instruction);
+        let (_, instruction_bits) = parser(instructions);
+        datapath.initialize(instruction_bits)?;
+
+        datapath.coprocessor.fpr[15] = f64::to_bits(*value1);
+        datapath.coprocessor.fpr[16] = f64::to_bits(*value1);
+
+        while !datapath.is_halted() {
+            datapath.execute_instruction();
+        }
+
+        assert_eq!(datapath.coprocessor.fpr[*result_register], f64::to_bits(*expected_result));
+        Ok(())
+    }
+}
+
+akin! {
+    let &instruction_name = [min_s,                    max_s,                    min_d,                    max_d];
+    let &instruction =      ["min.s $f20, $f15, $f16", "max.s $f21, $f15, $f16", "min.d $f22, $f17, $f18", "max.d $f23, $f17, $f18"];
+    let &result_register =  [20,                       21,                       22,                       23];
+    //                       -3.5 (single)             1.25 (single)             -3.5 (double)             1.25 (double)
+    let &expected_result =  [0xC060_0000,              0x3FA0_0000,              0xC00C_0000_0000_0000,    0x3FF4_0000_0000_0000];
+
+    #[test]
+    fn basic_~*instruction_name() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        let instructions = String::from(*instruction);
+        let (_, instruction_bits) = parser(instructions);
+        datapath.initialize(instruction_bits)?;
+
+        datapath.coprocessor.fpr[15] = f32::to_bits(1.25) as u64;
+        datapath.coprocessor.fpr[16] = f32::to_bits(-3.5) as u64;
+        datapath.coprocessor.fpr[17] = f64::to_bits(1.25);
+        datapath.coprocessor.fpr[18] = f64::to_bits(-3.5);
+
+        while !datapath.is_halted() {
+            datapath.execute_instruction();
+        }
+
+        assert_eq!(datapath.coprocessor.fpr[*result_register], *expected_result);
+        Ok(())
+    }
+}
+
+#[test]
+fn min_ignores_a_single_nan() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    let instructions = String::from("min.d $f20, $f15, $f16");
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    datapath.coprocessor.fpr[15] = f64::to_bits(f64::NAN);
+    datapath.coprocessor.fpr[16] = f64::to_bits(7.5);
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.coprocessor.fpr[20], f64::to_bits(7.5));
+    Ok(())
+}
+
+akin! {
+    let &instruction_name = [cvt_s_d,                 cvt_d_s,                 cvt_s_w,                 cvt_d_w,                 cvt_s_l,                 cvt_d_l];
+    let &instruction =      ["cvt.s.d $f20, $f15",    "cvt.d.s $f20, $f15",    "cvt.s.w $f20, $f15",    "cvt.d.w $f20, $f15",    "cvt.s.l $f20, $f15",    "cvt.d.l $f20, $f15"];
+    //                       -6.75 (double)           -6.75 (single)           -42 (word)               -42 (word)               -2^40 (long)             -2^40 (long)
+    let &value1 =           [0xC01B_0000_0000_0000,   0xC0D8_0000,             0xFFFF_FFD6,             0xFFFF_FFD6,             0xFFFF_FF00_0000_0000,   0xFFFF_FF00_0000_0000];
+    //                       -6.75 (single)           -6.75 (double)           -42.0 (single)           -42.0 (double)           -2^40 (single)           -2^40 (double)
+    let &expected_result =  [0xC0D8_0000,             0xC01B_0000_0000_0000,   0xC228_0000,             0xC045_0000_0000_0000,   0xD380_0000,             0xC270_0000_0000_0000];
+
+    #[test]
+    fn basic_~*instruction_name() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        let instructions = String::from(*instruction);
+        let (_, instruction_bits) = parser(instructions);
+        datapath.initialize(instruction_bits)?;
+
+        datapath.coprocessor.fpr[15] = *value1;
+
+        while !datapath.is_halted() {
+            datapath.execute_instruction();
+        }
+
+        assert_eq!(datapath.coprocessor.fpr[20], *expected_result);
+        Ok(())
+    }
+}
+
+akin! {
+    let &instruction_name = [cvt_w_s, trunc_w_s, round_w_s, ceil_w_s, floor_w_s];
+    let &operator =         ["cvt.w.s", "trunc.w.s", "round.w.s", "ceil.w.s", "floor.w.s"];
+    //                       Round to nearest with ties to even, toward zero, nearest, up, down.
+    let &expected_positive = [2,       2,           2,           3,          2];
+    let &expected_negative = [-4,      -3,          -4,          -3,         -4];
+
+    #[test]
+    fn rounding_~*instruction_name() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        let instructions = format!("{} $f20, $f15\n{} $f21, $f16", *operator, *operator);
+        let (_, instruction_bits) = parser(instructions);
+        datapath.initialize(instruction_bits)?;
+
+        datapath.coprocessor.fpr[15] = f32::to_bits(2.5) as u64;
+        datapath.coprocessor.fpr[16] = f32::to_bits(-3.5) as u64;
+
+        while !datapath.is_halted() {
+            datapath.execute_instruction();
+        }
+
+        assert_eq!(datapath.coprocessor.fpr[20], *expected_positive as i32 as u32 as u64);
+        assert_eq!(datapath.coprocessor.fpr[21], *expected_negative as i32 as u32 as u64);
+        Ok(())
+    }
+}
+
+akin! {
+    let &instruction_name = [cvt_l_d, trunc_l_d, round_l_d, ceil_l_d, floor_l_d];
+    let &operator =         ["cvt.l.d", "trunc.l.d", "round.l.d", "ceil.l.d", "floor.l.d"];
+    //                       Round to nearest with ties to even, toward zero, nearest, up, down.
+    let &expected_positive = [5000000000, 5000000000, 5000000000, 5000000001, 5000000000];
+    let &expected_negative = [-8,         -7,         -8,         -7,         -8];
+
+    #[test]
+    fn rounding_~*instruction_name() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        let instructions = format!("{} $f20, $f15\n{} $f21, $f16", *operator, *operator);
+        let (_, instruction_bits) = parser(instructions);
+        datapath.initialize(instruction_bits)?;
+
+        datapath.coprocessor.fpr[15] = f64::to_bits(5000000000.25);
+        datapath.coprocessor.fpr[16] = f64::to_bits(-7.5);
+
+        while !datapath.is_halted() {
+            datapath.execute_instruction();
+        }
+
+        assert_eq!(datapath.coprocessor.fpr[20], *expected_positive as i64 as u64);
+        assert_eq!(datapath.coprocessor.fpr[21], *expected_negative as i64 as u64);
+        Ok(())
+    }
+}
+
+#[test]
+fn out_of_range_conversion_saturates() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    let instructions = String::from("cvt.w.d $f20, $f15\ntrunc.w.s $f21, $f16");
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    datapath.coprocessor.fpr[15] = f64::to_bits(1e12);
+    datapath.coprocessor.fpr[16] = f32::to_bits(f32::NAN) as u64;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.coprocessor.fpr[20], i32::MAX as u32 as u64);
+    assert_eq!(datapath.coprocessor.fpr[21], 0);
+    Ok(())
+}
+
+#[test]
+// Convert an integer from a general-purpose register to a double, halve it,
+// and bring the rounded result back into a general-purpose register.
+fn integer_round_trip() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    let instructions = String::from(
+        r#"addi $t0, $zero, -7
+ori $t1, $zero, 2
+mtc1 $t0, $f0
+mtc1 $t1, $f1
+cvt.d.w $f2, $f0
+cvt.d.w $f3, $f1
+div.d $f4, $f2, $f3
+round.w.d $f5, $f4
+mfc1 $t2, $f5"#,
+    );
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    // -7 / 2 = -3.5, which rounds to the even value -4.
+    assert_eq!(f64::from_bits(datapath.coprocessor.fpr[4]), -3.5);
+    assert_eq!(datapath.registers.gpr[10], -4i64 as u64); // $t2
+    Ok(())
+}
@@ -0,0 +1,193 @@
+//! Tests for the FCSR, rounding modes, and IEEE 754 exceptions: cfc1, ctc1
+
+use crate::emulation_core::mips::coprocessor::{
+    FCR_FCSR, FCSR_2008, FCSR_CAUSE_SHIFT, FCSR_FLAGS_SHIFT, FIR_VALUE,
+};
+use crate::emulation_core::mips::floating_point::{
+    FLAG_DIVIDE_BY_ZERO, FLAG_INEXACT, FLAG_INVALID,
+};
+use crate::emulation_core::mips::registers::GpRegisterType;
+use crate::emulation_core::mips::system_coprocessor::ExceptionCode;
+
+use super::*;
+
+#[test]
+fn control_register_round_trip() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    let instructions = String::from(
+        r#"ori $t0, $zero, 3
+ctc1 $t0, $31
+cfc1 $t1, $31
+cfc1 $t2, $0"#,
+    );
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(
+        datapath.registers[GpRegisterType::T1],
+        (3 | FCSR_2008) as u64
+    );
+    assert_eq!(datapath.registers[GpRegisterType::T2], FIR_VALUE as u64);
+    assert_eq!(datapath.coprocessor.fcsr, 3);
+    Ok(())
+}
+
+akin! {
+    let &mode_name = [nearest,    zero,       positive_infinity, negative_infinity];
+    let &mode =      [0,          1,          2,                 3];
+    //                1/3 is rounded up to the nearest single-precision value.
+    let &expected =  [0x3EAA_AAAB, 0x3EAA_AAAA, 0x3EAA_AAAB,     0x3EAA_AAAA];
+
+    #[test]
+    fn div_s_rounds_~*mode_name() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        let instructions = format!("ori $t0, $zero, {}\nctc1 $t0, $31\ndiv.s $f2, $f0, $f1", *mode);
+        let (_, instruction_bits) = parser(instructions);
+        datapath.initialize(instruction_bits)?;
+
+        datapath.coprocessor.fpr[0] = f32::to_bits(1.0) as u64;
+        datapath.coprocessor.fpr[1] = f32::to_bits(3.0) as u64;
+
+        while !datapath.is_halted() {
+            datapath.execute_instruction();
+        }
+
+        assert_eq!(datapath.coprocessor.fpr[2], *expected);
+        assert_eq!(datapath.coprocessor.fcsr >> FCSR_FLAGS_SHIFT & 0x1F, FLAG_INEXACT);
+        Ok(())
+    }
+}
+
+#[test]
+// Flags accumulate over several instructions, while the cause only shows the
+// exceptions of the last one.
+fn flags_are_sticky() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    let instructions = String::from(
+        r#"div.d $f2, $f0, $f1
+div.d $f3, $f1, $f1
+add.d $f4, $f0, $f0"#,
+    );
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    datapath.coprocessor.fpr[0] = f64::to_bits(-1.0);
+    datapath.coprocessor.fpr[1] = f64::to_bits(0.0);
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.coprocessor.fpr[2], f64::to_bits(f64::NEG_INFINITY));
+    assert!(f64::from_bits(datapath.coprocessor.fpr[3]).is_nan());
+
+    let fcsr = datapath.coprocessor.read_control_register(FCR_FCSR);
+    assert_eq!(
+        fcsr >> FCSR_FLAGS_SHIFT & 0x1F,
+        FLAG_DIVIDE_BY_ZERO | FLAG_INVALID
+    );
+    assert_eq!(fcsr >> FCSR_CAUSE_SHIFT & 0x1F, 0);
+    Ok(())
+}
+
+#[test]
+// With the divide-by-zero exception enabled (bit 10), dividing by zero raises a
+// floating-point exception instead of writing a result.
+fn enabled_exception_traps() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    let instructions = String::from(
+        r#"ori $t0, $zero, 1024
+ctc1 $t0, $31
+div.s $f2, $f0, $f1
+ori $s1, $zero, 1"#,
+    );
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    datapath.coprocessor.fpr[0] = f32::to_bits(1.0) as u64;
+    datapath.coprocessor.fpr[1] = f32::to_bits(0.0) as u64;
+    datapath.coprocessor.fpr[2] = 1234;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(
+        datapath.system_coprocessor.exception,
+        Some(ExceptionCode::FloatingPoint)
+    );
+    assert_eq!(datapath.coprocessor.fpr[2], 1234);
+    assert_eq!(datapath.registers[GpRegisterType::S1], 0);
+
+    // The cause shows the exception, but it is not added to the flags.
+    let fcsr = datapath.coprocessor.fcsr;
+    assert_eq!(fcsr >> FCSR_CAUSE_SHIFT & 0x1F, FLAG_DIVIDE_BY_ZERO);
+    assert_eq!(fcsr >> FCSR_FLAGS_SHIFT & 0x1F, 0);
+    Ok(())
+}
+
+akin! {
+    let &mode_name = [nearest, zero, positive_infinity, negative_infinity];
+    let &mode =      [0,       1,    2,                 3];
+    let &expected =  [2,       2,    3,                 2];
+
+    #[test]
+    fn cvt_w_s_rounds_~*mode_name() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        let instructions = format!(
+            "ori $t0, $zero, {}\nctc1 $t0, $31\ncvt.w.s $f2, $f0\ntrunc.w.s $f3, $f1",
+            *mode
+        );
+        let (_, instruction_bits) = parser(instructions);
+        datapath.initialize(instruction_bits)?;
+
+        datapath.coprocessor.fpr[0] = f32::to_bits(2.5) as u64;
+        datapath.coprocessor.fpr[1] = f32::to_bits(2.5) as u64;
+
+        while !datapath.is_halted() {
+            datapath.execute_instruction();
+        }
+
+        assert_eq!(datapath.coprocessor.fpr[2], *expected);
+        // trunc.w.s always rounds toward zero.
+        assert_eq!(datapath.coprocessor.fpr[3], 2);
+        Ok(())
+    }
+}
+
+#[test]
+// Ordered comparisons signal an invalid operation for NaN, while c.eq does not.
+fn ordered_comparison_with_nan_is_invalid() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    let instructions = String::from("c.eq.d $f0, $f1\ncfc1 $t0, $31\nc.lt.d $f0, $f1");
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    datapath.coprocessor.fpr[0] = f64::to_bits(f64::NAN);
+    datapath.coprocessor.fpr[1] = f64::to_bits(1.0);
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(
+        datapath.registers[GpRegisterType::T0] as u32 >> FCSR_FLAGS_SHIFT & 0x1F,
+        0
+    );
+    assert_eq!(
+        datapath.coprocessor.fcsr >> FCSR_FLAGS_SHIFT & 0x1F,
+        FLAG_INVALID
+    );
+    assert_eq!(datapath.coprocessor.condition_code, 0);
+    Ok(())
+}
@@ -0,0 +1,89 @@
+//! Tests for the high multiply and modulo instructions: muh, muhu, mod, modu, dmuh, dmuhu, dmod, dmodu.
+
+use super::*;
+
+akin! {
+    let &instruction_name = [muh,                  muhu,                 mod,                  modu];
+    let &instruction =      ["muh r15, r16, r17",  "muhu r18, r16, r17", "mod r19, r16, r17",  "modu r20, r16, r17"];
+    let &value1 =           [2000000000,           4000000000,           18446744073709551516, 4000000000];
+    let &value2 =           [3,                    4000000000,           7,                    7];
+    let &result_register =  [15,                   18,                   19,                   20];
+    //                                             0xDE0B6B3A            -100 % 7 = -2
+    let &expected_result =  [1,                    18446744073139874618, 18446744073709551614, 3];
+
+    #[test]
+    fn basic_~*instruction_name() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        let instructions = String::from(*instruction);
+        let (_, instruction_bits) = parser(instructions);
+        datapath.initialize(instruction_bits)?;
+
+        datapath.registers.gpr[16] = *value1;
+        datapath.registers.gpr[17] = *value2;
+
+        while !datapath.is_halted() {
+            datapath.execute_instruction();
+        }
+
+        assert_eq!(datapath.registers.gpr[*result_register], *expected_result);
+        Ok(())
+    }
+}
+
+akin! {
+    let &instruction_name = [dmuh,                 dmuhu,                 dmod,                  dmodu];
+    let &instruction =      ["dmuh r10, r25, r26", "dmuhu r11, r25, r26", "dmod r12, r25, r26",  "dmodu r13, r25, r26"];
+    let &value1 =           [187650270761524,      12519072089974610290,  18446503577641103360,  6100876364229782140];
+    let &value2 =           [78198451644,          10630297190,           5432,                  1221];
+    let &result_register =  [10,                   11,                    12,                    13];
+    //                                                                    -240496068448256 % 5432 = -1328
+    let &expected_result =  [795476,               7214360232,            18446744073709550288,  610];
+
+    #[test]
+    fn basic_~*instruction_name() -> Result<(), String> {
+        let mut datapath = MipsDatapath::default();
+
+        let instructions = String::from(*instruction);
+        let (_, instruction_bits) = parser(instructions);
+        datapath.initialize(instruction_bits)?;
+
+        datapath.registers.gpr[25] = *value1;
+        datapath.registers.gpr[26] = *value2;
+
+        while !datapath.is_halted() {
+            datapath.execute_instruction();
+        }
+
+        assert_eq!(datapath.registers.gpr[*result_register], *expected_result);
+        Ok(())
+    }
+}
+
+#[test]
+fn greatest_common_divisor() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    // Euclid's algorithm, taking the remainder directly with mod.
+    let instructions = String::from(
+        r#"ori $a0, $zero, 1071
+ori $a1, $zero, 462
+loop: beq $a1, $zero, done
+mod $t0, $a0, $a1
+move $a0, $a1
+move $a1, $t0
+j loop
+done: move $v0, $a0"#,
+    );
+
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.registers.gpr[2], 21); // $v0
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+//! Tests of programs assembled for and run in the segmented memory layout.
+
+use crate::emulation_core::mips::memory::{
+    Endianness, MemoryLayout, DATA_ADDRESS, GLOBAL_POINTER, STACK_POINTER, TEXT_ADDRESS,
+};
+use crate::emulation_core::mips::registers::GpRegisterType;
+use crate::parser::parser_assembler_main::parser_for_memory;
+use crate::parser::parser_structs_and_enums::ProgramInfo;
+
+use super::*;
+
+/// Assemble `program` for the segmented layout and load it into a new
+/// datapath, starting from its `main` label.
+fn load_segmented(program: &str) -> Result<(MipsDatapath, ProgramInfo), String> {
+    let mut datapath = MipsDatapath::default();
+    datapath.set_memory_layout(MemoryLayout::Segmented);
+
+    let (program_info, binary) = parser_for_memory(
+        program.to_string(),
+        MemoryLayout::Segmented,
+        Endianness::Big,
+        false,
+    );
+    let (text, data) = binary.split_at(program_info.instructions.len());
+    datapath.initialize_program(text.to_vec(), data.to_vec())?;
+    datapath.registers.pc = program_info.pc_starting_point as u64;
+
+    Ok((datapath, program_info))
+}
+
+#[test]
+fn registers_start_at_segment_addresses() -> Result<(), String> {
+    let (datapath, program_info) = load_segmented("ori $t0, $zero, 1")?;
+
+    assert_eq!(program_info.text_address as u64, TEXT_ADDRESS);
+    assert_eq!(datapath.registers.pc, TEXT_ADDRESS);
+    assert_eq!(datapath.registers[GpRegisterType::Sp], STACK_POINTER);
+    assert_eq!(datapath.registers[GpRegisterType::Gp], GLOBAL_POINTER);
+    assert_eq!(datapath.memory.load_word(TEXT_ADDRESS)?, 0x3408_0001);
+
+    Ok(())
+}
+
+#[test]
+fn data_is_placed_at_data_address() -> Result<(), String> {
+    let (mut datapath, _) = load_segmented(
+        r#".data
+first: .word 42
+second: .word 0
+.text
+lw $t0, first
+addi $t0, $t0, 1
+sw $t0, second
+li $v0, 10
+syscall"#,
+    )?;
+
+    assert_eq!(datapath.memory.load_word(DATA_ADDRESS)?, 42);
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.registers[GpRegisterType::T0], 43);
+    assert_eq!(datapath.memory.load_word(DATA_ADDRESS + 4)?, 43);
+
+    Ok(())
+}
+
+#[test]
+fn jumps_and_branches_in_text_segment() -> Result<(), String> {
+    let (mut datapath, program_info) = load_segmented(
+        r#"double: add $v1, $a0, $a0
+jr $ra
+main: ori $a0, $zero, 3
+ori $t1, $zero, 0
+loop: addi $t1, $t1, 1
+bne $t1, $a0, loop
+jal double
+addi $sp, $sp, -4
+sw $v1, 0($sp)
+lw $t2, 0($sp)
+j done
+ori $t2, $zero, 0
+done: li $v0, 10
+syscall"#,
+    )?;
+
+    // `main` is the third instruction.
+    assert_eq!(program_info.pc_starting_point as u64, TEXT_ADDRESS + 8);
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.registers[GpRegisterType::T1], 3);
+    assert_eq!(datapath.registers[GpRegisterType::V1], 6);
+    assert_eq!(datapath.registers[GpRegisterType::T2], 6);
+    assert_eq!(datapath.registers[GpRegisterType::Sp], STACK_POINTER - 4);
+    assert_eq!(datapath.memory.load_word(STACK_POINTER - 4)?, 6);
+
+    Ok(())
+}
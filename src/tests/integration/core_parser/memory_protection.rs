@@ -0,0 +1,116 @@
+//! Tests of programs that access memory their permissions do not allow.
+
+use crate::emulation_core::mips::cache::AccessKind;
+use crate::emulation_core::mips::debugger::{Debugger, StopReason, DEFAULT_INSTRUCTION_BUDGET};
+use crate::emulation_core::mips::history::ExecutionHistory;
+use crate::emulation_core::mips::registers::GpRegisterType;
+use crate::emulation_core::mips::system_coprocessor::ExceptionCode;
+
+use super::*;
+
+/// Overwrites the instruction at address 16, `ori $t1, $zero, 1`, with
+/// `ori $t1, $zero, 7` before running it.
+const SELF_MODIFYING: &str = r#"lui $t0, 0x3409
+ori $t0, $t0, 7
+sw $t0, 16($zero)
+ori $t2, $zero, 1
+ori $t1, $zero, 1"#;
+
+/// Assemble `program` and load it into `datapath` with its text protected.
+fn load_protected(datapath: &mut MipsDatapath, program: &str) -> Result<(), String> {
+    let (program_info, binary) = parser(program.to_string());
+    let (text, data) = binary.split_at(program_info.instructions.len());
+    datapath.initialize_program(text.to_vec(), data.to_vec())
+}
+
+#[test]
+fn store_to_text_faults() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+    load_protected(&mut datapath, SELF_MODIFYING)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    let fault = datapath.memory_fault.clone().unwrap();
+    assert_eq!(fault.pc, 8);
+    assert_eq!(fault.address, 16);
+    assert_eq!(fault.kind, AccessKind::Write);
+    assert!(fault.message.contains("text"));
+    assert_eq!(
+        datapath.system_coprocessor.exception,
+        Some(ExceptionCode::TlbModified)
+    );
+    assert_eq!(datapath.system_coprocessor.bad_vaddr, 16);
+
+    // The program stopped before changing its code.
+    assert_eq!(datapath.memory.load_word(16)?, 0x3409_0001);
+    assert_eq!(datapath.registers[GpRegisterType::T2], 0);
+
+    Ok(())
+}
+
+#[test]
+fn self_modifying_code_when_allowed() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+    datapath.memory.self_modifying_code = true;
+    load_protected(&mut datapath, SELF_MODIFYING)?;
+
+    // The setting is kept when the program is loaded.
+    assert!(datapath.memory.self_modifying_code);
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.memory_fault, None);
+    assert_eq!(datapath.registers[GpRegisterType::T1], 7);
+
+    Ok(())
+}
+
+#[test]
+fn fetch_from_data_faults() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+    load_protected(
+        &mut datapath,
+        r#".data
+value: .word 0x34090007
+.text
+j value"#,
+    )?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    let fault = datapath.memory_fault.clone().unwrap();
+    assert_eq!(fault.kind, AccessKind::InstructionFetch);
+    assert_eq!(fault.pc, fault.address);
+    assert_eq!(
+        datapath.system_coprocessor.exception,
+        Some(ExceptionCode::TlbExecuteInhibit)
+    );
+    assert_eq!(datapath.registers[GpRegisterType::T1], 0);
+
+    Ok(())
+}
+
+#[test]
+fn debugger_stops_on_fault() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+    load_protected(&mut datapath, SELF_MODIFYING)?;
+
+    let reason = Debugger::default().run(
+        &mut datapath,
+        &mut ExecutionHistory::default(),
+        DEFAULT_INSTRUCTION_BUDGET,
+    );
+
+    match reason {
+        StopReason::MemoryFault(fault) => assert_eq!(fault.pc, 8),
+        reason => panic!("expected a memory fault, got {reason:?}"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+//! Tests of programs using memory-mapped devices.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::emulation_core::mips::mmio::{
+    BitmapDisplay, KeyboardDisplay, BITMAP_DISPLAY_ADDRESS, KEYBOARD_DISPLAY_ADDRESS,
+};
+
+use super::*;
+
+#[test]
+fn echo_keys_by_polling() -> Result<(), String> {
+    let keyboard_display = Rc::new(RefCell::new(KeyboardDisplay::default()));
+
+    let mut datapath = MipsDatapath::default();
+    datapath
+        .memory
+        .attach_device(KEYBOARD_DISPLAY_ADDRESS, keyboard_display.clone())?;
+
+    // Echo each key typed until a newline, waiting for the receiver and
+    // transmitter to be ready.
+    let instructions = String::from(
+        r#"lui $t0, 0xffff
+ori $t4, $zero, 10
+poll: lw $t1, 0($t0)
+andi $t1, $t1, 1
+beq $t1, $zero, poll
+lw $t2, 4($t0)
+wait: lw $t3, 8($t0)
+andi $t3, $t3, 1
+beq $t3, $zero, wait
+sw $t2, 12($t0)
+bne $t2, $t4, poll
+li $v0, 10
+syscall"#,
+    );
+
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    // Keys typed before initializing would be cleared along with the device.
+    for key in b"hi\n" {
+        keyboard_display.borrow_mut().type_key(*key);
+    }
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(keyboard_display.borrow().output, "hi\n");
+    assert!(keyboard_display.borrow().input.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn draw_on_bitmap_display() -> Result<(), String> {
+    let bitmap_display = Rc::new(RefCell::new(BitmapDisplay::default()));
+
+    let mut datapath = MipsDatapath::default();
+    datapath
+        .memory
+        .attach_device(BITMAP_DISPLAY_ADDRESS, bitmap_display.clone())?;
+
+    // Draw a red pixel in the top left corner, and a green one to its right.
+    let instructions = String::from(
+        r#"lui $t0, 0xffff
+ori $t0, $t0, 0x1000
+lui $t1, 0xff
+sw $t1, 0($t0)
+ori $t1, $zero, 0xff00
+sw $t1, 4($t0)"#,
+    );
+
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(bitmap_display.borrow().pixel(0, 0), Some(0x00FF_0000));
+    assert_eq!(bitmap_display.borrow().pixel(1, 0), Some(0x0000_FF00));
+    assert_eq!(bitmap_display.borrow().pixel(2, 0), Some(0));
+
+    // Resetting the datapath keeps the device attached, but clears it.
+    datapath.reset();
+    assert_eq!(datapath.memory.devices.len(), 1);
+    assert_eq!(bitmap_display.borrow().pixel(0, 0), Some(0));
+
+    Ok(())
+}
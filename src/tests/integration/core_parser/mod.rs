@@ -7,16 +7,34 @@
 pub mod arithmetic;
 pub mod basic_immediate;
 pub mod basic_operations;
+pub mod bit_manipulation;
+pub mod branch_delay_slots;
 pub mod branch_jump;
+pub mod branch_predictor;
+pub mod cache;
+pub mod conditional_move;
 pub mod conditions;
 pub mod coprocessor_move;
 pub mod double_arithmetic;
 pub mod double_immediate;
+pub mod exceptions;
 pub mod fibonacci;
 pub mod floating_point_arithmetic;
 pub mod floating_point_branch;
 pub mod floating_point_comparison;
+pub mod floating_point_conversion;
+pub mod floating_point_exceptions;
+pub mod high_multiply_modulo;
+pub mod memory_layout;
+pub mod memory_protection;
+pub mod mmio;
+pub mod multi_cycle_datapath;
+pub mod performance;
+pub mod pipelined_datapath;
+pub mod store_load_byte_half;
+pub mod store_load_double_word;
 pub mod store_load_word;
+pub mod syscall;
 
 #[test]
 fn add_register_plus_itself() -> Result<(), String> {
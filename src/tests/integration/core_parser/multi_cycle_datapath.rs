@@ -0,0 +1,123 @@
+//! Tests that programs give the same results on the multi-cycle datapath as on
+//! the pseudo-single-cycle datapath.
+
+use crate::emulation_core::mips::multi_cycle_datapath::MipsMultiCycleDatapath;
+
+use super::*;
+
+/// Run a program to completion on both datapaths and compare the registers
+/// and memory they end with. Returns the multi-cycle datapath.
+fn assert_same_results(
+    program: &str,
+    branch_delay_slots: bool,
+) -> Result<MipsMultiCycleDatapath, String> {
+    let (program_info, instruction_bits) = parser(program.to_string());
+    let exception_handler = program_info.exception_handler.map(|address| address as u64);
+
+    let mut expected = MipsDatapath::default();
+    expected.branch_delay_slots = branch_delay_slots;
+    expected.initialize(instruction_bits.clone())?;
+    expected.system_coprocessor.exception_handler = exception_handler;
+    while !expected.is_halted() {
+        expected.execute_instruction();
+    }
+
+    let mut datapath = MipsMultiCycleDatapath::default();
+    datapath.datapath.branch_delay_slots = branch_delay_slots;
+    datapath.initialize(instruction_bits)?;
+    datapath.datapath.system_coprocessor.exception_handler = exception_handler;
+    while !datapath.is_halted() {
+        datapath.execute_stage();
+    }
+
+    assert_eq!(datapath.datapath.registers.gpr, expected.registers.gpr);
+    assert_eq!(datapath.datapath.coprocessor.fpr, expected.coprocessor.fpr);
+    assert_eq!(datapath.datapath.memory, expected.memory);
+
+    Ok(datapath)
+}
+
+#[test]
+fn function_calls() -> Result<(), String> {
+    let datapath = assert_same_results(
+        r#"ori $a0, $zero, 6
+jal factorial
+move $s0, $v0
+ori $t0, $zero, 256
+sd $s0, 8($t0)
+ld $s1, 8($t0)
+j done
+factorial: ori $v0, $zero, 1
+factorial_loop: blez $a0, factorial_done
+dmul $v0, $v0, $a0
+daddiu $a0, $a0, -1
+b factorial_loop
+factorial_done: jr $ra
+done: nop"#,
+        false,
+    )?;
+
+    assert_eq!(datapath.datapath.registers.gpr[17], 720); // $s1
+
+    Ok(())
+}
+
+#[test]
+fn floating_point_loop() -> Result<(), String> {
+    let datapath = assert_same_results(
+        r#"lui $s0, 0x3F80
+lui $s1, 0x40A0
+mtc1 $zero, $f0
+mtc1 $s0, $f1
+mtc1 $s1, $f2
+loop: add.s $f0, $f0, $f1
+addiu $s2, $s2, 7
+c.lt.s $f0, $f2
+bc1t loop"#,
+        false,
+    )?;
+
+    assert_eq!(datapath.datapath.registers.gpr[18], 35); // $s2
+
+    Ok(())
+}
+
+#[test]
+fn exception_handler() -> Result<(), String> {
+    let datapath = assert_same_results(
+        r#"lui $t0, 32767
+add $t1, $t0, $t0
+ori $s1, $zero, 1
+ori $v0, $zero, 10
+syscall
+exception_handler: mfc0 $s0, $13
+mfc0 $k0, $14
+addiu $k0, $k0, 4
+mtc0 $k0, $14
+eret"#,
+        false,
+    )?;
+
+    assert_eq!(datapath.datapath.registers.gpr[17], 1); // $s1
+    assert_ne!(datapath.datapath.registers.gpr[16], 0); // $s0
+
+    Ok(())
+}
+
+#[test]
+fn branch_delay_slots() -> Result<(), String> {
+    let datapath = assert_same_results(
+        r#".set noreorder
+li $t0, 10
+li $s0, 0
+loop: daddu $s0, $s0, $t0
+bgtz $t0, loop
+daddiu $t0, $t0, -1
+nop"#,
+        true,
+    )?;
+
+    assert_eq!(datapath.datapath.registers.gpr[16], 55); // $s0
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+//! Tests of the performance counters, running whole programs.
+
+use crate::emulation_core::mips::datapath::Stage;
+use crate::emulation_core::mips::performance::{CycleCostModel, InstructionClass};
+use crate::emulation_core::mips::pipelined_datapath::MipsPipelinedDatapath;
+
+use super::*;
+
+/// Stores and loads four words in a loop, then calls a function.
+const MIXED_PROGRAM: &str = r#"ori $t0, $zero, 256
+ori $t2, $zero, 4
+loop: sw $t2, 0($t0)
+lw $t3, 0($t0)
+daddiu $t2, $t2, -1
+bne $t2, $zero, loop
+jal function
+ori $v0, $zero, 10
+syscall
+function: mtc1 $t3, $f0
+jr $ra"#;
+
+#[test]
+fn instruction_mix_is_counted_by_class() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+    let (_, instruction_bits) = parser(MIXED_PROGRAM.to_string());
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    let counters = &datapath.performance;
+    assert_eq!(counters.instructions(InstructionClass::Alu), 7);
+    assert_eq!(counters.instructions(InstructionClass::Load), 4);
+    assert_eq!(counters.instructions(InstructionClass::Store), 4);
+    assert_eq!(counters.instructions(InstructionClass::BranchTaken), 3);
+    assert_eq!(counters.instructions(InstructionClass::BranchNotTaken), 1);
+    assert_eq!(counters.instructions(InstructionClass::Jump), 2);
+    assert_eq!(counters.instructions(InstructionClass::Fpu), 1);
+    assert_eq!(counters.total_instructions(), 22);
+
+    // The `syscall` that exits halts the datapath in its ID stage, so it is
+    // never counted as finished.
+    assert_eq!(counters.instructions(InstructionClass::System), 0);
+    assert_eq!(counters.stages(Stage::InstructionFetch), 23);
+    assert_eq!(counters.stages(Stage::InstructionDecode), 23);
+    assert_eq!(counters.stages(Stage::WriteBack), 22);
+    assert_eq!(counters.total_stages(), 112);
+
+    // 7 * 4 + 4 * 5 + 4 * 4 + 4 * 3 + 2 * 3 + 1 * 4
+    assert_eq!(counters.cycles(&datapath.cost_model), 86);
+
+    Ok(())
+}
+
+#[test]
+fn reset_clears_counters_but_keeps_cost_model() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+    datapath.cost_model.load = 10;
+
+    let (_, instruction_bits) = parser(MIXED_PROGRAM.to_string());
+    datapath.initialize(instruction_bits)?;
+    datapath.execute_instruction();
+    assert_eq!(datapath.performance.total_instructions(), 1);
+
+    datapath.reset();
+
+    assert_eq!(datapath.performance.total_instructions(), 0);
+    assert_eq!(
+        datapath.cost_model,
+        CycleCostModel {
+            load: 10,
+            ..Default::default()
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn pipeline_counts_only_finished_instructions() -> Result<(), String> {
+    let mut datapath = MipsPipelinedDatapath::default();
+    let (_, instruction_bits) = parser(MIXED_PROGRAM.to_string());
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_stage();
+    }
+
+    // Instructions fetched behind taken branches are flushed before they
+    // finish, so the instruction mix matches the other datapaths.
+    let counters = &datapath.datapath.performance;
+    assert_eq!(counters.total_instructions(), 22);
+    assert_eq!(counters.instructions(InstructionClass::BranchTaken), 3);
+
+    Ok(())
+}
@@ -0,0 +1,183 @@
+//! Tests that programs give the same results on the pipelined datapath as on
+//! the pseudo-single-cycle datapath, with and without forwarding.
+
+use crate::emulation_core::mips::pipelined_datapath::MipsPipelinedDatapath;
+
+use super::*;
+
+/// Run a program to completion on both datapaths and compare the registers
+/// and memory they end with. Returns the pseudo-single-cycle datapath.
+fn assert_same_results(program: &str) -> Result<MipsDatapath, String> {
+    let (_, instruction_bits) = parser(program.to_string());
+
+    let mut expected = MipsDatapath::default();
+    expected.initialize(instruction_bits.clone())?;
+    while !expected.is_halted() {
+        expected.execute_instruction();
+    }
+
+    for forwarding in [true, false] {
+        let mut datapath = MipsPipelinedDatapath {
+            forwarding,
+            ..Default::default()
+        };
+        datapath.initialize(instruction_bits.clone())?;
+        while !datapath.is_halted() {
+            datapath.execute_stage();
+        }
+
+        assert_eq!(datapath.datapath.registers.gpr, expected.registers.gpr);
+        assert_eq!(datapath.datapath.coprocessor.fpr, expected.coprocessor.fpr);
+        assert_eq!(datapath.datapath.memory, expected.memory);
+    }
+
+    Ok(expected)
+}
+
+#[test]
+fn sum_array() -> Result<(), String> {
+    let datapath = assert_same_results(
+        r#"ori $t0, $zero, 256
+ori $t1, $zero, 8
+fill: sw $t1, 0($t0)
+daddiu $t0, $t0, 4
+daddiu $t1, $t1, -1
+bgtz $t1, fill
+ori $t0, $zero, 256
+ori $t1, $zero, 8
+ori $s0, $zero, 0
+sum: lw $t2, 0($t0)
+daddu $s0, $s0, $t2
+sw $s0, 0($t0)
+daddiu $t0, $t0, 4
+daddiu $t1, $t1, -1
+bgtz $t1, sum"#,
+    )?;
+
+    assert_eq!(datapath.registers.gpr[16], 36); // $s0
+
+    Ok(())
+}
+
+#[test]
+fn function_calls() -> Result<(), String> {
+    let datapath = assert_same_results(
+        r#"ori $a0, $zero, 6
+jal factorial
+move $s0, $v0
+j done
+factorial: ori $v0, $zero, 1
+factorial_loop: blez $a0, factorial_done
+dmul $v0, $v0, $a0
+daddiu $a0, $a0, -1
+b factorial_loop
+factorial_done: jr $ra
+done: nop"#,
+    )?;
+
+    assert_eq!(datapath.registers.gpr[16], 720); // $s0
+
+    Ok(())
+}
+
+#[test]
+fn floating_point_loop() -> Result<(), String> {
+    let datapath = assert_same_results(
+        r#"lui $s0, 0x3F80
+lui $s1, 0x40A0
+ori $s2, $zero, 0
+mtc1 $zero, $f0
+mtc1 $s0, $f1
+mtc1 $s1, $f2
+loop: add.s $f0, $f0, $f1
+addiu $s2, $s2, 7
+c.lt.s $f0, $f2
+bc1t loop
+mfc1 $s3, $f0
+daddu $s4, $s3, $s2"#,
+    )?;
+
+    assert_eq!(datapath.registers.gpr[18], 35); // $s2
+    assert_eq!(datapath.registers.gpr[20], 0x40A0_0000 + 35); // $s4
+
+    Ok(())
+}
+
+#[test]
+fn conditional_moves() -> Result<(), String> {
+    // The movz that doesn't move must not forward a stale value to the daddu after it.
+    let datapath = assert_same_results(
+        r#"ori $t0, $zero, 5
+ori $t1, $zero, 1
+ori $s0, $zero, 10
+movz $s0, $t0, $t1
+daddu $s1, $s0, $s0
+movn $s0, $t0, $t1
+daddu $s2, $s0, $s0
+lui $t2, 0x3F80
+mtc1 $t2, $f1
+c.eq.s $f1, $f1
+movt $s3, $t0
+movt.s $f2, $f1
+movf.s $f3, $f1
+add.s $f4, $f2, $f3"#,
+    )?;
+
+    assert_eq!(datapath.registers.gpr[17], 20); // $s1
+    assert_eq!(datapath.registers.gpr[18], 10); // $s2
+    assert_eq!(datapath.registers.gpr[19], 5); // $s3
+    assert_eq!(f32::from_bits(datapath.coprocessor.fpr[4] as u32), 1.0);
+
+    Ok(())
+}
+
+#[test]
+fn strict_32_bit_operands() -> Result<(), String> {
+    // $t1 is only known not to be a word once it is forwarded to the first
+    // addu. The addu after the branch is flushed, so it is not recorded.
+    let (_, instruction_bits) = parser(
+        r#"ori $t0, $zero, 1
+dsll32 $t1, $t0, 0
+addu $t2, $t1, $t0
+lui $t5, 0x8000
+sw $t5, 256($zero)
+lw $t6, 256($zero)
+addu $t7, $t6, $zero
+beq $zero, $zero, done
+addu $t3, $t1, $t0
+done: nop"#
+            .to_string(),
+    );
+
+    let mut expected = MipsDatapath::default();
+    expected.strict_32_bit = true;
+    expected.initialize(instruction_bits.clone())?;
+    while !expected.is_halted() {
+        expected.execute_instruction();
+    }
+
+    assert_eq!(expected.registers.gpr[14], 0xFFFF_FFFF_8000_0000); // $t6
+    assert_eq!(expected.unpredictable_operands.len(), 1);
+    assert_eq!(expected.unpredictable_operands[0].pc, 8);
+    assert_eq!(expected.unpredictable_operands[0].register, 9); // $t1
+
+    for forwarding in [true, false] {
+        let mut datapath = MipsPipelinedDatapath {
+            forwarding,
+            ..Default::default()
+        };
+        datapath.datapath.strict_32_bit = true;
+        datapath.initialize(instruction_bits.clone())?;
+        while !datapath.is_halted() {
+            datapath.execute_stage();
+        }
+
+        assert_eq!(datapath.datapath.registers.gpr, expected.registers.gpr);
+        assert_eq!(
+            datapath.datapath.unpredictable_operands,
+            expected.unpredictable_operands
+        );
+    }
+
+    Ok(())
+}
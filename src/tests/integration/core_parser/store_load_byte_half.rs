@@ -0,0 +1,95 @@
+//! Covering the byte and halfword load and store instructions: lb, lbu, lh, lhu, sb, sh.
+
+use super::*;
+
+#[test]
+fn load_byte_data_from_label() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    let instructions = String::from(
+        r#".data
+values: .byte -128
+
+.text
+lb $s0, values
+lbu $s1, values"#,
+    );
+
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.registers.gpr[16], 0xFFFF_FFFF_FFFF_FF80); // $s0
+    assert_eq!(datapath.registers.gpr[17], 0x80); // $s1
+
+    Ok(())
+}
+
+#[test]
+fn store_half_word_then_load_it_back() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    let instructions = String::from(
+        r#"li $t0, 600
+li $t1, 65535
+sh $t1, 2($t0)
+lh $s0, 2($t0)
+lhu $s1, 2($t0)"#,
+    );
+
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.memory.load_word(600)?, 0xFFFF);
+    assert_eq!(datapath.registers.gpr[16], u64::MAX); // $s0
+    assert_eq!(datapath.registers.gpr[17], 0xFFFF); // $s1
+
+    Ok(())
+}
+
+#[test]
+// Copies a null-terminated string one byte at a time, counting its length.
+fn copy_string() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    let instructions = String::from(
+        r#"li $t0, 1000
+li $t1, 2000
+li $s0, 0
+loop: lbu $t2, 0($t0)
+sb $t2, 0($t1)
+beq $t2, $zero, done
+daddiu $t0, $t0, 1
+daddiu $t1, $t1, 1
+daddiu $s0, $s0, 1
+j loop
+done: nop"#,
+    );
+
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    for (i, byte) in b"SWIM\0".iter().enumerate() {
+        datapath.memory.store_byte(1000 + i as u64, *byte)?;
+    }
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.registers.gpr[16], 4); // $s0
+    assert_eq!(
+        datapath.memory.load_word(2000)?,
+        u32::from_be_bytes(*b"SWIM")
+    );
+    assert_eq!(datapath.memory.load_byte(2004)?, 0);
+
+    Ok(())
+}
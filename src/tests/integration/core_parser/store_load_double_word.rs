@@ -0,0 +1,60 @@
+//! Covering the doubleword load and store instructions: ld, sd, ldc1, sdc1.
+
+use super::*;
+
+#[test]
+fn sd_then_ld() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    let instructions = String::from(
+        r#"li r14, 800
+lui r25, 4660
+dahi r25, 22136
+sd r25, 8(r14)
+ld r26, 8(r14)"#,
+    );
+
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.memory.load_double_word(808)?, 0x5678_1234_0000);
+    assert_eq!(datapath.registers.gpr[26], 0x5678_1234_0000);
+
+    Ok(())
+}
+
+#[test]
+// The program has an odd number of instructions, so the `.double` data must be
+// padded to be loaded.
+fn ldc1_sdc1_label() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+
+    let instructions = String::from(
+        r#".data
+radius: .double 2.5
+area: .double 0
+
+.text
+ldc1 $f1, radius
+mul.d $f2, $f1, $f1
+sdc1 $f2, area
+ld $s0, area"#,
+    );
+
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(f64::from_bits(datapath.coprocessor.fpr[1]), 2.5);
+    assert_eq!(f64::from_bits(datapath.coprocessor.fpr[2]), 6.25);
+    assert_eq!(f64::from_bits(datapath.registers.gpr[16]), 6.25);
+
+    Ok(())
+}
@@ -1,5 +1,9 @@
 //! Covering the store and load word instructions: sw, lw, swc1, lwc1.
 
+use crate::emulation_core::mips::memory::{Endianness, MemoryLayout};
+use crate::emulation_core::mips::registers::GpRegisterType;
+use crate::parser::parser_assembler_main::parser_for_memory;
+
 use super::*;
 
 #[test]
@@ -122,3 +126,43 @@ fn basic_lwc1() -> Result<(), String> {
 
     Ok(())
 }
+
+#[test]
+fn little_endian_program() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+    datapath.set_endianness(Endianness::Little);
+
+    let instructions = String::from(
+        r#".data
+value: .word 0x12345678
+text: .ascii "abcd"
+.text
+lw $t0, value
+lbu $t1, value
+lbu $t2, text
+ori $t3, $zero, 0x4142
+sh $t3, text"#,
+    );
+
+    let (program_info, binary) =
+        parser_for_memory(instructions, MemoryLayout::Flat, Endianness::Little, false);
+    let (text, data) = binary.split_at(program_info.instructions.len());
+    datapath.initialize_program(text.to_vec(), data.to_vec())?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(datapath.registers[GpRegisterType::T0], 0x1234_5678);
+    // The least significant byte is at the address of the word.
+    assert_eq!(datapath.registers[GpRegisterType::T1], 0x78);
+    assert_eq!(datapath.registers[GpRegisterType::T2], b'a' as u64);
+
+    let string_address = program_info.data[1].data_number + text.len() * 4;
+    assert_eq!(
+        datapath.memory.memory[string_address..string_address + 4],
+        *b"BAcd"
+    );
+
+    Ok(())
+}
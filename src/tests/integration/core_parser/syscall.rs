@@ -0,0 +1,66 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::emulation_core::mips::registers::GpRegisterType;
+use crate::emulation_core::mips::syscall::BufferedSyscallHost;
+
+use super::*;
+
+#[test]
+fn print_int_then_exit() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+    let host = Rc::new(RefCell::new(BufferedSyscallHost::default()));
+    datapath.set_syscall_host(host.clone());
+
+    let instructions = String::from(
+        r#"li $v0, 1
+li $a0, 42
+syscall
+li $v0, 10
+syscall
+li $t0, 5"#,
+    );
+
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(host.borrow().output, "42");
+
+    // The program should have stopped before reaching the last instruction.
+    assert_eq!(datapath.registers[GpRegisterType::T0], 0);
+
+    Ok(())
+}
+
+#[test]
+fn read_int_and_print_double_of_it() -> Result<(), String> {
+    let mut datapath = MipsDatapath::default();
+    let host = Rc::new(RefCell::new(BufferedSyscallHost::default()));
+    host.borrow_mut().input.push_back(String::from("21"));
+    datapath.set_syscall_host(host.clone());
+
+    let instructions = String::from(
+        r#"li $v0, 5
+syscall
+add $a0, $v0, $v0
+li $v0, 1
+syscall
+li $v0, 10
+syscall"#,
+    );
+
+    let (_, instruction_bits) = parser(instructions);
+    datapath.initialize(instruction_bits)?;
+
+    while !datapath.is_halted() {
+        datapath.execute_instruction();
+    }
+
+    assert_eq!(host.borrow().output, "42");
+
+    Ok(())
+}
@@ -1,3 +1,4 @@
+use crate::emulation_core::mips::memory::Endianness;
 use crate::parser::assembling::assemble_data_binary;
 use crate::parser::parser_assembler_main::parser;
 use crate::parser::parser_structs_and_enums::ErrorType::{NonASCIIChar, NonASCIIString};
@@ -268,7 +269,7 @@ fn read_label_relative_returns_correct_value_for_instruction_below_current() {
 fn assemble_data_binary_works_one_word() {
     let mut lines = tokenize_program(".data\nlabel: .word 200".to_string());
     let mut modified_data = separate_data_and_text(&mut lines).1;
-    let result = assemble_data_binary(&mut modified_data);
+    let result = assemble_data_binary(&mut modified_data, 0, Endianness::Big);
 
     assert_eq!(result[0], 0);
     assert_eq!(result[1], 0);
@@ -280,7 +281,7 @@ fn assemble_data_binary_works_one_word() {
 fn assemble_data_binary_works_multiple_words() {
     let mut lines = tokenize_program(".data\nlabel: .word 200, 45, -12".to_string());
     let mut modified_data = separate_data_and_text(&mut lines).1;
-    let result = assemble_data_binary(&mut modified_data);
+    let result = assemble_data_binary(&mut modified_data, 0, Endianness::Big);
 
     assert_eq!(result[0], 0);
     assert_eq!(result[1], 0);
@@ -300,7 +301,7 @@ fn assemble_data_binary_works_multiple_words() {
 fn assemble_data_binary_works_half_words() {
     let mut lines = tokenize_program(".data\nlabel: .half 200, 45, -12".to_string());
     let mut modified_data = separate_data_and_text(&mut lines).1;
-    let result = assemble_data_binary(&mut modified_data);
+    let result = assemble_data_binary(&mut modified_data, 0, Endianness::Big);
 
     assert_eq!(result[0], 0);
     assert_eq!(result[1], 200);
@@ -315,7 +316,7 @@ fn assemble_data_binary_works_half_words() {
 fn assemble_data_binary_works_for_spaces() {
     let mut lines = tokenize_program(".data\nlabel: .space 3, 1".to_string());
     let mut modified_data = separate_data_and_text(&mut lines).1;
-    let result = assemble_data_binary(&mut modified_data);
+    let result = assemble_data_binary(&mut modified_data, 0, Endianness::Big);
 
     assert_eq!(result[0], 0);
     assert_eq!(result[1], 0);
@@ -328,7 +329,7 @@ fn assemble_data_binary_works_for_spaces() {
 fn assemble_data_binary_works_for_int_bytes() {
     let mut lines = tokenize_program(".data\nlabel: .byte 255, -128".to_string());
     let mut modified_data = separate_data_and_text(&mut lines).1;
-    let result = assemble_data_binary(&mut modified_data);
+    let result = assemble_data_binary(&mut modified_data, 0, Endianness::Big);
 
     assert_eq!(result[0], 255);
     assert_eq!(result[1], 128);
@@ -338,7 +339,7 @@ fn assemble_data_binary_works_for_int_bytes() {
 fn assemble_data_binary_works_for_char_bytes() {
     let mut lines = tokenize_program(".data\nlabel: .byte 'a', '?'".to_string());
     let mut modified_data = separate_data_and_text(&mut lines).1;
-    let result = assemble_data_binary(&mut modified_data);
+    let result = assemble_data_binary(&mut modified_data, 0, Endianness::Big);
 
     assert_eq!(result[0], 97);
     assert_eq!(result[1], 63);
@@ -348,7 +349,7 @@ fn assemble_data_binary_works_for_char_bytes() {
 fn assemble_data_binary_works_for_ascii() {
     let mut lines = tokenize_program(".data\nlabel: .ascii \"abc de\"".to_string());
     let mut modified_data = separate_data_and_text(&mut lines).1;
-    let result = assemble_data_binary(&mut modified_data);
+    let result = assemble_data_binary(&mut modified_data, 0, Endianness::Big);
 
     assert_eq!(result[0], 97);
     assert_eq!(result[1], 98);
@@ -362,7 +363,7 @@ fn assemble_data_binary_works_for_ascii() {
 fn assemble_data_binary_works_for_asciiz() {
     let mut lines = tokenize_program(".data\nlabel: .asciiz \"abcde\"".to_string());
     let mut modified_data = separate_data_and_text(&mut lines).1;
-    let result = assemble_data_binary(&mut modified_data);
+    let result = assemble_data_binary(&mut modified_data, 0, Endianness::Big);
 
     assert_eq!(result[0], 97);
     assert_eq!(result[1], 98);
@@ -398,7 +399,7 @@ fn assemble_data_binary_gives_errors_on_non_ascii_characters_for_ascii_asciiz_an
 fn assemble_data_binary_works_for_float() {
     let mut lines = tokenize_program(".data\nlabel: .float 0.234, -121.8, 20".to_string());
     let mut modified_data = separate_data_and_text(&mut lines).1;
-    let result = assemble_data_binary(&mut modified_data);
+    let result = assemble_data_binary(&mut modified_data, 0, Endianness::Big);
 
     assert_eq!(result[0], 62);
     assert_eq!(result[1], 111);
@@ -418,7 +419,7 @@ fn assemble_data_binary_works_for_float() {
 fn assemble_data_binary_works_for_double() {
     let mut lines = tokenize_program(".data\nlabel: .double 0.234, -121.8, 20".to_string());
     let mut modified_data = separate_data_and_text(&mut lines).1;
-    let result = assemble_data_binary(&mut modified_data);
+    let result = assemble_data_binary(&mut modified_data, 0, Endianness::Big);
 
     assert_eq!(result[0], 0b00111111);
     assert_eq!(result[1], 0b11001101);
@@ -448,11 +449,26 @@ fn assemble_data_binary_works_for_double() {
     assert_eq!(result[23], 0b00000000);
 }
 
+#[test]
+fn assemble_data_binary_aligns_double_to_double_word_boundary() {
+    let mut lines = tokenize_program(".data\nchar: .byte 1\nlabel: .double 20".to_string());
+    let mut modified_data = separate_data_and_text(&mut lines).1;
+    let result = assemble_data_binary(&mut modified_data, 4, Endianness::Big);
+
+    // The data starts at address 4, so the double is placed at address 8.
+    assert_eq!(modified_data[1].data_number, 4);
+    assert_eq!(result.len(), 12);
+    assert_eq!(result[0], 1);
+    assert_eq!(&result[1..4], &[0, 0, 0]);
+    assert_eq!(result[4], 0b01000000);
+    assert_eq!(result[5], 0b00110100);
+}
+
 #[test]
 fn assemble_data_binary_word_recognizes_hex() {
     let mut lines = tokenize_program(".data\nlabel: .word 0xfa".to_string());
     let mut modified_data = separate_data_and_text(&mut lines).1;
-    let result = assemble_data_binary(&mut modified_data);
+    let result = assemble_data_binary(&mut modified_data, 0, Endianness::Big);
 
     assert_eq!(result[0], 0);
     assert_eq!(result[1], 0);
@@ -464,7 +480,7 @@ fn assemble_data_binary_word_recognizes_hex() {
 fn assemble_data_binary_defaults_unfinished_labels_to_be_empty_words() {
     let mut lines = tokenize_program(".data\nlabel: \nsecond: .ascii \"ABC\"".to_string());
     let mut modified_data = separate_data_and_text(&mut lines).1;
-    let result = assemble_data_binary(&mut modified_data);
+    let result = assemble_data_binary(&mut modified_data, 0, Endianness::Big);
 
     assert_eq!(result[0], 0);
     assert_eq!(result[1], 0);
@@ -474,3 +490,17 @@ fn assemble_data_binary_defaults_unfinished_labels_to_be_empty_words() {
     assert_eq!(result[5], 0x42);
     assert_eq!(result[6], 0x43);
 }
+
+#[test]
+fn assemble_data_binary_little_endian() {
+    let mut lines = tokenize_program(
+        ".data\nw: .word 0x12345678\nh: .half 0x1234\nb: .byte 7\ns: .ascii \"ab\"".to_string(),
+    );
+    let mut modified_data = separate_data_and_text(&mut lines).1;
+    let result = assemble_data_binary(&mut modified_data, 0, Endianness::Little);
+
+    assert_eq!(
+        result,
+        vec![0x78, 0x56, 0x34, 0x12, 0x34, 0x12, 7, b'a', b'b']
+    );
+}
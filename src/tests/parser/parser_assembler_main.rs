@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod parser_main_function_tests {
+    use crate::emulation_core::mips::memory::{Endianness, MemoryLayout};
     use crate::parser::parser_assembler_main::*;
+    use crate::parser::parser_structs_and_enums::ErrorType::{RequiresRelease6, RetiredInRelease6};
 
     #[test]
     fn parser_takes_string_and_returns_vec_of_instructions() {
@@ -20,10 +22,67 @@ fn parser_takes_string_and_returns_vec_of_instructions() {
             0b00100001010010011010101010101010
         );
     }
+
+    #[test]
+    fn release_6_mode_retires_addi_and_daddi() {
+        let (program_info, _) = parser_for_memory(
+            "addi $t1, $t2, 1\ndaddi $t1, $t2, 1\naddiu $t1, $t2, 1".to_string(),
+            MemoryLayout::Flat,
+            Endianness::Big,
+            true,
+        );
+
+        assert_eq!(
+            program_info.instructions[0].errors[0].error_name,
+            RetiredInRelease6
+        );
+        assert_eq!(
+            program_info.instructions[1].errors[0].error_name,
+            RetiredInRelease6
+        );
+        assert!(program_info.instructions[2].errors.is_empty());
+    }
+
+    #[test]
+    fn release_6_branches_require_release_6_mode() {
+        let program = "label: beqc $t1, $t2, label\nbovc $t1, $t2, label".to_string();
+
+        let (program_info, _) = parser(program.clone());
+        assert_eq!(
+            program_info.instructions[0].errors[0].error_name,
+            RequiresRelease6
+        );
+        assert_eq!(
+            program_info.instructions[1].errors[0].error_name,
+            RequiresRelease6
+        );
+
+        let (program_info, _) =
+            parser_for_memory(program, MemoryLayout::Flat, Endianness::Big, true);
+        assert!(program_info.instructions[0].errors.is_empty());
+        assert!(program_info.instructions[1].errors.is_empty());
+    }
+
+    #[test]
+    fn set_pseudo_instructions_assemble_in_release_6_mode() {
+        let (program_info, _) = parser_for_memory(
+            "sle $t1, $t2, $t3\nsgeu $t1, $t2, $t3".to_string(),
+            MemoryLayout::Flat,
+            Endianness::Big,
+            true,
+        );
+
+        assert!(program_info
+            .instructions
+            .iter()
+            .all(|instruction| instruction.errors.is_empty()));
+    }
 }
 
 mod read_instructions_tests {
-    use crate::parser::parser_structs_and_enums::ErrorType::JALRRDRegisterZero;
+    use crate::parser::parser_structs_and_enums::ErrorType::{
+        ImmediateOutOfBounds, InvalidCompactBranchRegisters, JALRRDRegisterZero,
+    };
     use crate::tests::parser::parser_assembler_main::helper_functions::instruction_parser;
 
     #[test]
@@ -110,6 +169,126 @@ fn read_instructions_sw() {
         );
     }
 
+    #[test]
+    fn read_instructions_lb() {
+        let file_string = "lb $t1, 512($t1)".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b10000001001010010000001000000000
+        );
+    }
+
+    #[test]
+    fn read_instructions_lbu() {
+        let file_string = "lbu $t1, 512($t1)".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b10010001001010010000001000000000
+        );
+    }
+
+    #[test]
+    fn read_instructions_lh() {
+        let file_string = "lh $t1, 512($t1)".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b10000101001010010000001000000000
+        );
+    }
+
+    #[test]
+    fn read_instructions_lhu() {
+        let file_string = "lhu $t1, 512($t1)".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b10010101001010010000001000000000
+        );
+    }
+
+    #[test]
+    fn read_instructions_sb() {
+        let file_string = "sb $t1, 512($t1)".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b10100001001010010000001000000000
+        );
+    }
+
+    #[test]
+    fn read_instructions_sh() {
+        let file_string = "sh $t1, 512($t1)".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b10100101001010010000001000000000
+        );
+    }
+
+    #[test]
+    fn read_instructions_ld() {
+        let file_string = "ld $t1, 512($t1)".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b11011101001010010000001000000000
+        );
+    }
+
+    #[test]
+    fn read_instructions_sd() {
+        let file_string = "sd $t1, 512($t1)".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b11111101001010010000001000000000
+        );
+    }
+
+    #[test]
+    fn read_instructions_ldc1() {
+        let file_string = "ldc1 $f9, 43690($t2)".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b11010101010010011010101010101010
+        );
+    }
+
+    #[test]
+    fn read_instructions_sdc1() {
+        let file_string = "sdc1 $f9, 43690($t2)".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b11110101010010011010101010101010
+        );
+    }
+
     #[test]
     fn read_instructions_lui() {
         let file_string = "lui $t1, 43690".to_string();
@@ -179,6 +358,102 @@ fn read_instructions_or() {
         );
     }
 
+    #[test]
+    fn read_instructions_xor() {
+        let file_string = "xor $t1, $s6, $t2".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b00000010110010100100100000100110
+        );
+    }
+
+    #[test]
+    fn read_instructions_nor() {
+        let file_string = "nor $t1, $s6, $t2".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b00000010110010100100100000100111
+        );
+    }
+
+    #[test]
+    fn read_instructions_xori() {
+        let file_string = "xori $t1, $t2, 43690".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b00111001010010011010101010101010
+        );
+    }
+
+    #[test]
+    fn read_instructions_srl() {
+        let file_string = "srl $t1, $t2, 5".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b00000000000010100100100101000010
+        );
+    }
+
+    #[test]
+    fn read_instructions_sra() {
+        let file_string = "sra $t1, $t2, 5".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b00000000000010100100100101000011
+        );
+    }
+
+    #[test]
+    fn read_instructions_dsll32() {
+        let file_string = "dsll32 $t1, $t2, 5".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b00000000000010100100100101111100
+        );
+    }
+
+    #[test]
+    fn read_instructions_srav() {
+        let file_string = "srav $t1, $t2, $s6".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b00000010110010100100100000000111
+        );
+    }
+
+    #[test]
+    fn read_instructions_dsllv() {
+        let file_string = "dsllv $t1, $t2, $s6".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b00000010110010100100100000010100
+        );
+    }
+
     #[test]
     fn read_instructions_ori() {
         let file_string = "ori $t1, $t2, 43690".to_string();
@@ -347,6 +622,90 @@ fn read_instructions_div_d() {
         );
     }
 
+    #[test]
+    fn read_instructions_abs_s() {
+        let file_string = "abs.s $f9, $f10".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b01000110000000000101001001000101
+        );
+    }
+
+    #[test]
+    fn read_instructions_sqrt_d() {
+        let file_string = "sqrt.d $f9, $f10".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b01000110001000000101001001000100
+        );
+    }
+
+    #[test]
+    fn read_instructions_min_d() {
+        let file_string = "min.d $f9, $f10, $f22".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b01000110001101100101001001011100
+        );
+    }
+
+    #[test]
+    fn read_instructions_max_s() {
+        let file_string = "max.s $f9, $f10, $f22".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b01000110000101100101001001011110
+        );
+    }
+
+    #[test]
+    fn read_instructions_cvt_s_w() {
+        let file_string = "cvt.s.w $f9, $f10".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b01000110100000000101001001100000
+        );
+    }
+
+    #[test]
+    fn read_instructions_cvt_l_d() {
+        let file_string = "cvt.l.d $f9, $f10".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b01000110001000000101001001100101
+        );
+    }
+
+    #[test]
+    fn read_instructions_floor_w_s() {
+        let file_string = "floor.w.s $f9, $f10".to_string();
+
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b01000110000000000101001001001111
+        );
+    }
+
     #[test]
     fn read_instructions_dahi() {
         let file_string = "dahi $t1, 43690".to_string();
@@ -590,13 +949,174 @@ fn read_instructions_bne() {
     }
 
     #[test]
-    fn read_instructions_c_eq_s() {
-        let instruction_list = instruction_parser("c.eq.s $f9, $f22".to_string());
+    fn read_instructions_blez_bgtz() {
+        let file_string =
+            "Add $t1, $t2, $t3\nAddress: add $t1, #t2, $t3\nblez $t1, address\nbgtz $t1, address"
+                .to_string();
+        let instruction_list = instruction_parser(file_string);
 
         assert_eq!(
-            instruction_list[0].binary,
-            0b01000110000101100100100000110010
-        )
+            instruction_list[2].binary,
+            0b00011001001000001111111111111110
+        );
+        assert_eq!(
+            instruction_list[3].binary,
+            0b00011101001000001111111111111101
+        );
+    }
+
+    #[test]
+    fn read_instructions_bltz_bgez() {
+        let file_string = "bltz $t1, end\nbgez $t2, end\nend: add $t1, $t2, $t3".to_string();
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b00000101001000000000000000000001
+        );
+        assert_eq!(
+            instruction_list[1].binary,
+            0b00000101010000010000000000000000
+        );
+    }
+
+    #[test]
+    fn read_instructions_bltzal_bgezal() {
+        let file_string = "bltzal $t1, end\nbgezal $t2, end\nend: add $t1, $t2, $t3".to_string();
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b00000101001100000000000000000001
+        );
+        assert_eq!(
+            instruction_list[1].binary,
+            0b00000101010100010000000000000000
+        );
+    }
+
+    #[test]
+    fn read_instructions_bltc_bgec() {
+        let file_string = "Add $t1, $t2, $t3\nAddress: add $t1, #t2, $t3\nbltc $t1, $t2, address\nbgec $t1, $t2, address".to_string();
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[2].binary,
+            0b01011101001010101111111111111110
+        );
+        assert_eq!(
+            instruction_list[3].binary,
+            0b01011001001010101111111111111101
+        );
+    }
+
+    #[test]
+    fn read_instructions_beqzc_bnezc() {
+        let file_string =
+            "Address: add $t1, $t2, $t3\nbeqzc $t1, address\nbnezc $t2, address".to_string();
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[1].binary,
+            0b11011001001111111111111111111110
+        );
+        assert_eq!(
+            instruction_list[2].binary,
+            0b11111001010111111111111111111101
+        );
+    }
+
+    #[test]
+    fn read_instructions_bc_balc() {
+        let file_string = "Address: add $t1, $t2, $t3\nbc address\nbalc address".to_string();
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[1].binary,
+            0b11001011111111111111111111111110
+        );
+        assert_eq!(
+            instruction_list[2].binary,
+            0b11101011111111111111111111111101
+        );
+    }
+
+    #[test]
+    fn read_instructions_beqc_bnec() {
+        // `rs` must be the lower register, whichever order they are given in.
+        let file_string =
+            "Address: add $t1, $t2, $t3\nbeqc $t2, $t1, address\nbnec $t1, $t2, address"
+                .to_string();
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[1].binary,
+            0b00100001001010101111111111111110
+        );
+        assert_eq!(
+            instruction_list[2].binary,
+            0b01100001001010101111111111111101
+        );
+    }
+
+    #[test]
+    fn read_instructions_bovc_bnvc() {
+        // `rs` must not be the lower register.
+        let file_string =
+            "Address: add $t1, $t2, $t3\nbovc $t1, $t2, address\nbnvc $t2, $t1, address"
+                .to_string();
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[1].binary,
+            0b00100001010010011111111111111110
+        );
+        assert_eq!(
+            instruction_list[2].binary,
+            0b01100001010010011111111111111101
+        );
+    }
+
+    #[test]
+    fn read_instructions_beqzalc_bnezalc() {
+        let file_string =
+            "Address: add $t1, $t2, $t3\nbeqzalc $t1, address\nbnezalc $t2, address".to_string();
+        let instruction_list = instruction_parser(file_string);
+
+        assert_eq!(
+            instruction_list[1].binary,
+            0b00100000000010011111111111111110
+        );
+        assert_eq!(
+            instruction_list[2].binary,
+            0b01100000000010101111111111111101
+        );
+    }
+
+    #[test]
+    fn read_instructions_compact_branches_create_error_with_invalid_registers() {
+        let instruction_list = instruction_parser(
+            "address: beqc $t1, $t1, address\nbnec $zero, $t1, address\nbeqzalc $zero, address"
+                .to_string(),
+        );
+
+        // The program ends with a `syscall`.
+        for instruction in &instruction_list[..3] {
+            assert_eq!(
+                instruction.errors[0].error_name,
+                InvalidCompactBranchRegisters
+            );
+        }
+    }
+
+    #[test]
+    fn read_instructions_c_eq_s() {
+        let instruction_list = instruction_parser("c.eq.s $f9, $f22".to_string());
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b01000110000101100100100000110010
+        )
     }
 
     #[test]
@@ -711,6 +1231,203 @@ fn read_instruction_bc1f() {
         );
     }
 
+    #[test]
+    fn read_instructions_c_eq_s_with_condition_code() {
+        let instruction_list = instruction_parser("c.eq.s 3, $f9, $f22".to_string());
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b01000110000101100100101100110010
+        )
+    }
+
+    #[test]
+    fn read_instruction_bc1t_with_condition_code() {
+        let instruction_list =
+            instruction_parser("instruction: add $t1, $t2, $t3\nbc1t 2, instruction".to_string());
+
+        assert_eq!(
+            instruction_list[1].binary,
+            0b01000101000010011111111111111110
+        );
+    }
+
+    #[test]
+    fn read_instruction_bc1f_with_condition_code() {
+        let instruction_list =
+            instruction_parser("instruction: add $t1, $t2, $t3\nbc1f 7, instruction".to_string());
+
+        assert_eq!(
+            instruction_list[1].binary,
+            0b01000101000111001111111111111110
+        );
+    }
+
+    #[test]
+    fn read_instruction_condition_code_out_of_range() {
+        let instruction_list =
+            instruction_parser("instruction: add $t1, $t2, $t3\nbc1t 8, instruction".to_string());
+
+        assert_eq!(
+            instruction_list[1].errors[0].error_name,
+            ImmediateOutOfBounds
+        );
+    }
+
+    #[test]
+    fn read_instructions_muh() {
+        let instruction_list = instruction_parser("muh $t0, $t1, $t2".to_string());
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b00000001001010100100000011011000
+        );
+    }
+
+    #[test]
+    fn read_instructions_modu() {
+        let instruction_list = instruction_parser("modu $t0, $t1, $t2".to_string());
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b00000001001010100100000011011011
+        );
+    }
+
+    #[test]
+    fn read_instructions_dmod() {
+        let instruction_list = instruction_parser("dmod $s0, $s1, $s2".to_string());
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b00000010001100101000000011011110
+        );
+    }
+
+    #[test]
+    fn read_instructions_cmp_lt_d() {
+        let instruction_list = instruction_parser("cmp.lt.d $f1, $f2, $f3".to_string());
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b01000110101000110001000001000100
+        );
+    }
+
+    #[test]
+    fn read_instructions_cmp_sune_s() {
+        let instruction_list = instruction_parser("cmp.sune.s $f1, $f2, $f3".to_string());
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b01000110100000110001000001011010
+        );
+    }
+
+    #[test]
+    fn read_instructions_movz() {
+        let instruction_list = instruction_parser("movz $t0, $t1, $t2".to_string());
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b00000001001010100100000000001010
+        );
+    }
+
+    #[test]
+    fn read_instructions_selnez() {
+        let instruction_list = instruction_parser("selnez $t0, $t1, $t2".to_string());
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b00000001001010100100000000110111
+        );
+    }
+
+    #[test]
+    fn read_instructions_movt_with_condition_code() {
+        let instruction_list = instruction_parser("movt $t0, $t1, 3".to_string());
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b00000001001011010100000000000001
+        );
+    }
+
+    #[test]
+    fn read_instructions_movf_implied_condition_code() {
+        let instruction_list = instruction_parser("movf $t0, $t1".to_string());
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b00000001001000000100000000000001
+        );
+    }
+
+    #[test]
+    fn read_instructions_movf_d_with_condition_code() {
+        let instruction_list = instruction_parser("movf.d $f1, $f2, 6".to_string());
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b01000110001110000001000001010001
+        );
+    }
+
+    #[test]
+    fn read_instructions_movt_s_implied_condition_code() {
+        let instruction_list = instruction_parser("movt.s $f1, $f2".to_string());
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b01000110000000010001000001010001
+        );
+    }
+
+    #[test]
+    fn read_instructions_movn_s() {
+        let instruction_list = instruction_parser("movn.s $f1, $f2, $t0".to_string());
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b01000110000010000001000001010011
+        );
+    }
+
+    #[test]
+    fn read_instructions_sel_s() {
+        let instruction_list = instruction_parser("sel.s $f1, $f2, $f3".to_string());
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b01000110000000110001000001010000
+        );
+    }
+
+    #[test]
+    fn read_instruction_bc1eqz() {
+        let instruction_list = instruction_parser(
+            "instruction: add $t1, $t2, $t3\nbc1eqz $f4, instruction".to_string(),
+        );
+
+        assert_eq!(
+            instruction_list[1].binary,
+            0b01000101001001001111111111111110
+        );
+    }
+
+    #[test]
+    fn read_instruction_bc1nez() {
+        let instruction_list = instruction_parser(
+            "instruction: add $t1, $t2, $t3\nbc1nez $f4, instruction".to_string(),
+        );
+
+        assert_eq!(
+            instruction_list[1].binary,
+            0b01000101101001001111111111111110
+        );
+    }
+
     #[test]
     fn read_instruction_jalr_with_rd() {
         let instruction_list = instruction_parser("jalr $t1, $t2".to_string());
@@ -787,8 +1504,69 @@ fn read_instructions_recognizes_nop() {
 
         assert_eq!(instruction_list[0].binary, 0);
     }
+
+    #[test]
+    fn read_instructions_mfc0() {
+        let instruction_list = instruction_parser("mfc0 $t1, $13".to_string());
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b01000000000010010110100000000000
+        );
+    }
+
+    #[test]
+    fn read_instructions_mtc0() {
+        let instruction_list = instruction_parser("mtc0 $t1, $14".to_string());
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b01000000100010010111000000000000
+        );
+    }
+
+    #[test]
+    fn read_instructions_cfc1() {
+        let instruction_list = instruction_parser("cfc1 $t1, $31".to_string());
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b01000100010010011111100000000000
+        );
+    }
+
+    #[test]
+    fn read_instructions_ctc1() {
+        let instruction_list = instruction_parser("ctc1 $t1, $31".to_string());
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b01000100110010011111100000000000
+        );
+    }
+
+    #[test]
+    fn read_instructions_recognizes_eret() {
+        let instruction_list = instruction_parser(".text\neret".to_string());
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b01000010000000000000000000011000
+        );
+    }
+
+    #[test]
+    fn read_instructions_recognizes_break() {
+        let instruction_list = instruction_parser(".text\nbreak".to_string());
+
+        assert_eq!(
+            instruction_list[0].binary,
+            0b00000000000000000000000000001101
+        );
+    }
 }
 
+use crate::emulation_core::mips::memory::Endianness;
 use crate::parser::assembling::assemble_data_binary;
 use crate::parser::parser_assembler_main::{
     create_binary_vec, parser, place_binary_in_middle_of_another, read_instructions,
@@ -828,6 +1606,7 @@ fn place_binary_works_dahi() {
 }
 
 mod helper_functions {
+    use crate::emulation_core::mips::memory::Endianness;
     use crate::parser::assembling::assemble_data_binary;
     use crate::parser::parser_assembler_main::read_instructions;
     use crate::parser::parser_structs_and_enums::Instruction;
@@ -846,7 +1625,7 @@ pub fn instruction_parser(mut file_string: String) -> Vec<Instruction> {
             &data,
             &mut monaco_line_info_vec,
         );
-        assemble_data_binary(&mut data);
+        assemble_data_binary(&mut data, 0, Endianness::Big);
 
         let labels: HashMap<String, usize> = create_label_map(&mut instruction_list, &mut data);
 
@@ -870,7 +1649,7 @@ fn create_binary_vec_works_with_data() {
         &program_info.data,
         &mut program_info.monaco_line_info,
     );
-    let vec_of_data = assemble_data_binary(&mut program_info.data);
+    let vec_of_data = assemble_data_binary(&mut program_info.data, 0, Endianness::Big);
 
     let labels: HashMap<String, usize> =
         create_label_map(&mut program_info.instructions, &mut program_info.data);
@@ -886,7 +1665,11 @@ fn create_binary_vec_works_with_data() {
         &mut program_info.monaco_line_info,
     );
 
-    let result = create_binary_vec(program_info.instructions.clone(), vec_of_data);
+    let result = create_binary_vec(
+        program_info.instructions.clone(),
+        vec_of_data,
+        Endianness::Big,
+    );
 
     assert_eq!(result[3], 0b01110100011010000110100101110011);
     assert_eq!(result[4], 0b00100000011010010111001100100000);
@@ -896,7 +1679,7 @@ fn create_binary_vec_works_with_data() {
 
 #[test]
 fn read_instructions_recognizes_valid_but_unsupported_instructions() {
-    let program_info = parser("nor $t1, $t2, $t3\ndsrav $t1, $t2, $t3\n".to_string()).0;
+    let program_info = parser("rotrv $t1, $t2, $t3\ndrotrv $t1, $t2, $t3\n".to_string()).0;
 
     assert_eq!(
         program_info.instructions[0].errors[0].error_name,
@@ -966,7 +1749,7 @@ fn syscall_message_and_binary_does_not_go_in_mouse_hover_if_the_syscall_was_adde
     assert_eq!(monaco_line_info[0].mouse_hover_string, "");
     assert_eq!(monaco_line_info[1].mouse_hover_string, "**Syntax:** `ori rt, rs, immediate`\n\nBitwise ors the contents of `rs` with the left zero-extended `immediate` value, and stores the result in `rt`.\n\n\n\n**Binary:** `0b00110101010010010000000001100100`");
     assert_eq!(monaco_line_info[2].mouse_hover_string, "`subi` is a pseudo-instruction.\n\n```\nsubi rt, rs, immediate =>\nori $at, $zero, immediate\nsub rt, rs, $at\n\n```\n\n\n\n**Binary:** `0b00110100000000010000000001100100`\n\n**Binary:** `0b00000001010000010100100000100010`");
-    assert_eq!(monaco_line_info[3].mouse_hover_string, "**Syntax:** `add rd, rs, rt`\n\nAdds the 32-bit values in `rs` and `rt`, and places the result in `rd`.\n\nIn hardware implementations, the result is not placed in `rd` if adding `rs` and `rt` causes a 32-bit overflow. However, SWIM places the result in `rd` regardless since there is no exception handling.\n\n**Binary:** `0b00000001010010110100100000100000`\n\n");
+    assert_eq!(monaco_line_info[3].mouse_hover_string, "**Syntax:** `add rd, rs, rt`\n\nAdds the 32-bit values in `rs` and `rt`, and places the result in `rd`.\n\nIf adding `rs` and `rt` causes a 32-bit overflow, the result is not placed in `rd`, and an integer overflow exception is raised instead.\n\n**Binary:** `0b00000001010010110100100000100000`\n\n");
 
     let monaco_line_info = parser(".text".to_string()).0.monaco_line_info;
     assert_eq!(monaco_line_info[0].mouse_hover_string, "\n\n");
@@ -983,8 +1766,8 @@ fn mouse_hover_holds_information_info_for_various_instruction_types() {
     assert_eq!(program_info.monaco_line_info[0].mouse_hover_string, "");
     assert_eq!(program_info.monaco_line_info[1].mouse_hover_string, "**Syntax:** `ori rt, rs, immediate`\n\nBitwise ors the contents of `rs` with the left zero-extended `immediate` value, and stores the result in `rt`.\n\n\n\n**Binary:** `0b00110101010010010000000001100100`");
     assert_eq!(program_info.monaco_line_info[2].mouse_hover_string, "`subi` is a pseudo-instruction.\n\n```\nsubi rt, rs, immediate =>\nori $at, $zero, immediate\nsub rt, rs, $at\n\n```\n\n\n\n**Binary:** `0b00110100000000010000000001100100`\n\n**Binary:** `0b00000001010000010100100000100010`");
-    assert_eq!(program_info.monaco_line_info[3].mouse_hover_string, "**Syntax:** `add rd, rs, rt`\n\nAdds the 32-bit values in `rs` and `rt`, and places the result in `rd`.\n\nIn hardware implementations, the result is not placed in `rd` if adding `rs` and `rt` causes a 32-bit overflow. However, SWIM places the result in `rd` regardless since there is no exception handling.\n\n**Binary:** `0b00000001010010110100100000100000`");
-    assert_eq!(program_info.monaco_line_info[4].mouse_hover_string, "**Syntax:** `syscall`\n\nThis function is currently stubbed in SWIM. Normally, it reverts control back to the OS. SWIM uses it to effectively end the program.\n\n**Binary:** `0b00000000000000000000000000001100`");
+    assert_eq!(program_info.monaco_line_info[3].mouse_hover_string, "**Syntax:** `add rd, rs, rt`\n\nAdds the 32-bit values in `rs` and `rt`, and places the result in `rd`.\n\nIf adding `rs` and `rt` causes a 32-bit overflow, the result is not placed in `rd`, and an integer overflow exception is raised instead.\n\n**Binary:** `0b00000001010010110100100000100000`");
+    assert_eq!(program_info.monaco_line_info[4].mouse_hover_string, "**Syntax:** `syscall`\n\nPerforms the system call service selected by the value in `$v0`. Arguments are passed in `$a0`, `$a1`, and `$f12`, and results are returned in `$v0`.\n\nSupported services are print_int (1), print_float (2), print_double (3), print_string (4), read_int (5), read_string (8), sbrk (9), exit (10), and exit2 (17). Any other value in `$v0` raises a system call exception, which ends the program unless it has an `exception_handler` label.\n\n**Binary:** `0b00000000000000000000000000001100`");
 }
 
 #[test]
@@ -1099,3 +1882,17 @@ fn main_and_start_labelled_instructions_change_program_info_pc_starting_point()
     .pc_starting_point;
     assert_eq!(result, 8);
 }
+
+#[test]
+fn exception_handler_labelled_instruction_changes_program_info_exception_handler() {
+    let result = parser("addi $t1, $t2, 100\nsw $t1, 400($zero)".to_string())
+        .0
+        .exception_handler;
+    assert_eq!(result, None);
+
+    let result =
+        parser("addi $t1, $t2, 100\nsw $t1, 400($zero)\nexception_handler: eret".to_string())
+            .0
+            .exception_handler;
+    assert_eq!(result, Some(8));
+}
@@ -1,3 +1,4 @@
+use crate::emulation_core::mips::memory::Endianness;
 use crate::parser::assembling::assemble_data_binary;
 use crate::parser::parser_assembler_main::parser;
 use crate::parser::parser_structs_and_enums::ErrorType::{
@@ -768,7 +769,7 @@ fn create_label_map_recognizes_data_labels() {
     let mut monaco_line_info_vec = tokenize_program(".data\nlabel: .byte 'a'\nlabel2: .float 200\nlabel3: .word 200\n.text\nadd $t1, $t2, $t3\n".to_string());
     let (mut instruction_list, mut data) =
         separate_data_and_text(&mut monaco_line_info_vec.clone());
-    assemble_data_binary(&mut data);
+    assemble_data_binary(&mut data, 0, Endianness::Big);
     expand_pseudo_instructions_and_assign_instruction_numbers(
         &mut instruction_list,
         &data,
@@ -790,7 +791,7 @@ fn create_label_map_recognizes_data_labels_and_text_together() {
     let mut monaco_line_info_vec = tokenize_program(".data\nlabel: .byte 'a'\nlabel2: .float 200\nlabel3: .word 200\n.text\nadd $t1, $t2, $t3\ninstruction: sub $t1, $t2, $t3\n".to_string());
     let (mut instruction_list, mut data) =
         separate_data_and_text(&mut monaco_line_info_vec.clone());
-    assemble_data_binary(&mut data);
+    assemble_data_binary(&mut data, 0, Endianness::Big);
     expand_pseudo_instructions_and_assign_instruction_numbers(
         &mut instruction_list,
         &data,
@@ -1003,3 +1004,54 @@ fn operators_with_commas_cause_error() {
         }
     }
 }
+
+#[test]
+fn separate_data_and_text_skips_set_directives() {
+    let lines = tokenize_program(".set noreorder\nadd $t1, $t2, $t3\n".to_string());
+    let result = separate_data_and_text(&mut lines.clone());
+
+    assert_eq!(result.0.len(), 1);
+    assert_eq!(result.0[0].line_number, 1);
+}
+
+#[test]
+fn set_reorder_fills_delay_slots_with_nops() {
+    let (program_info, binary) = parser(
+        ".set reorder\nloop: beq $t1, $t2, end\nadd $t1, $t2, $t3\nj loop\nend: bc end".to_string(),
+    );
+
+    assert_eq!(
+        binary,
+        vec![
+            0b00010001001010100000000000000100, // beq $t1, $t2, end
+            0,                                  // nop
+            0b00000001010010110100100000100000, // add $t1, $t2, $t3
+            0b00001000000000000000000000000000, // j loop
+            0,                                  // nop
+            0b11001011111111111111111111111111, // bc end
+            0b00000000000000000000000000001100, // syscall
+        ]
+    );
+
+    // The inserted nops are highlighted along with their branches.
+    assert_eq!(
+        program_info.address_to_line_number,
+        vec![1, 1, 2, 3, 3, 4, 4]
+    );
+}
+
+#[test]
+fn set_noreorder_leaves_delay_slots_alone() {
+    let (_, binary) = parser(
+        ".set reorder\n.set noreorder\nloop: beq $t1, $t2, loop\nadd $t1, $t2, $t3".to_string(),
+    );
+
+    assert_eq!(
+        binary,
+        vec![
+            0b00010001001010101111111111111111, // beq $t1, $t2, loop
+            0b00000001010010110100100000100000, // add $t1, $t2, $t3
+            0b00000000000000000000000000001100, // syscall
+        ]
+    );
+}
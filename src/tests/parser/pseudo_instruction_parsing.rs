@@ -1,3 +1,4 @@
+use crate::emulation_core::mips::memory::Endianness;
 use crate::parser::assembling::assemble_data_binary;
 use crate::parser::parser_assembler_main::parser;
 use crate::parser::parser_structs_and_enums::TokenType::Operator;
@@ -1246,7 +1247,7 @@ fn expand_pseudo_instructions_and_assign_instruction_numbers_works_sle() {
         program_info.instructions[1],
         Instruction {
             operator: Token {
-                token_name: "addi".to_string(),
+                token_name: "addiu".to_string(),
                 start_end_columns: (0, 0),
                 token_type: Operator,
             },
@@ -1360,7 +1361,7 @@ fn expand_pseudo_instructions_and_assign_instruction_numbers_works_sleu() {
         program_info.instructions[1],
         Instruction {
             operator: Token {
-                token_name: "addi".to_string(),
+                token_name: "addiu".to_string(),
                 start_end_columns: (0, 0),
                 token_type: Operator,
             },
@@ -1474,7 +1475,7 @@ fn expand_pseudo_instructions_and_assign_instruction_numbers_works_sge() {
         program_info.instructions[1],
         Instruction {
             operator: Token {
-                token_name: "addi".to_string(),
+                token_name: "addiu".to_string(),
                 start_end_columns: (0, 0),
                 token_type: Operator,
             },
@@ -1552,7 +1553,7 @@ fn expand_pseudo_instructions_and_assign_instruction_numbers_works_sgeu() {
 
     let mut correct_program_info = ProgramInfo::default();
     let correct_string =
-        "sltu $t1, $t2, $t3\naddi $t1, $t1, 1\nandi $t1, $t1, 1\nsw $t1, label".to_string();
+        "sltu $t1, $t2, $t3\naddiu $t1, $t1, 1\nandi $t1, $t1, 1\nsw $t1, label".to_string();
     let mut monaco_line_info_vec = tokenize_program(correct_string);
     (correct_program_info.instructions, correct_program_info.data) =
         separate_data_and_text(&mut monaco_line_info_vec.clone());
@@ -1600,7 +1601,7 @@ fn expand_pseudo_instructions_and_assign_instruction_numbers_works_sgeu() {
         program_info.instructions[1],
         Instruction {
             operator: Token {
-                token_name: "addi".to_string(),
+                token_name: "addiu".to_string(),
                 start_end_columns: (0, 0),
                 token_type: Operator,
             },
@@ -1678,7 +1679,7 @@ fn complete_lw_sw_pseudo_instructions_works_multiple_using_same_label() {
         &program_info.data,
         &mut program_info.monaco_line_info,
     );
-    let _ = assemble_data_binary(&mut program_info.data);
+    let _ = assemble_data_binary(&mut program_info.data, 0, Endianness::Big);
     let labels: HashMap<String, usize> =
         create_label_map(&mut program_info.instructions, &mut program_info.data);
 
@@ -1812,7 +1813,7 @@ fn complete_lw_sw_pseudo_instructions_works() {
         &program_info.data,
         &mut program_info.monaco_line_info,
     );
-    let _vec_of_data = assemble_data_binary(&mut program_info.data);
+    let _vec_of_data = assemble_data_binary(&mut program_info.data, 0, Endianness::Big);
     let labels: HashMap<String, usize> =
         create_label_map(&mut program_info.instructions, &mut program_info.data);
 
@@ -1946,7 +1947,7 @@ fn complete_lw_sw_pseudo_instructions_doesnt_break_with_empty_instruction_list()
         &program_info.data,
         &mut program_info.monaco_line_info,
     );
-    let _ = assemble_data_binary(&mut program_info.data);
+    let _ = assemble_data_binary(&mut program_info.data, 0, Endianness::Big);
     let labels: HashMap<String, usize> =
         create_label_map(&mut program_info.instructions, &mut program_info.data);
 
@@ -1965,7 +1966,7 @@ fn expanded_pseudo_instructions_are_added_into_updated_monaco_string() {
     )
     .0.updated_monaco_string;
 
-    assert_eq!(result, ".text\n#Pseudo-Instruction: li $t1, 100\nori $t1, $zero, 100 #Pseudo-Instruction Translation\n#Pseudo-Instruction: seq $t1, $t2, $t3\nsub $t1, $t2, $t3 #Pseudo-Instruction Translation\nori $at, $zero, 1 #Pseudo-Instruction Translation\nsltu $t1, $t1, $at #Pseudo-Instruction Translation\n#Pseudo-Instruction: sne $t1, $t2, $t3\nsub $t1, $t2, $t3 #Pseudo-Instruction Translation\nsltu $t1, $zero, $t1 #Pseudo-Instruction Translation\n#Pseudo-Instruction: sle $t1, $t2, $t3\nslt $t1, $t3, $t2 #Pseudo-Instruction Translation\naddi $t1, $t1, 1 #Pseudo-Instruction Translation\nandi $t1, $t1, 1 #Pseudo-Instruction Translation\n#Pseudo-Instruction: sleu $t1, $t2, $t3\nsltu $t1, $t3, $t2 #Pseudo-Instruction Translation\naddi $t1, $t1, 1 #Pseudo-Instruction Translation\nandi $t1, $t1, 1 #Pseudo-Instruction Translation\n#Pseudo-Instruction: sgt $t1, $t2, $t3\nslt $t1, $t3, $t2 #Pseudo-Instruction Translation\n#Pseudo-Instruction: sgtu $t1, $t2, $t3\nsltu $t1, $t3, $t2 #Pseudo-Instruction Translation\n#Pseudo-Instruction: sge $t1, $t2, $t3\nslt $t1, $t2, $t3 #Pseudo-Instruction Translation\naddi $t1, $t1, 1 #Pseudo-Instruction Translation\nandi $t1, $t1, 1 #Pseudo-Instruction Translation\n#Pseudo-Instruction: sgeu $t1, $t2, $t3\nsltu $t1, $t2, $t3 #Pseudo-Instruction Translation\naddi $t1, $t1, 1 #Pseudo-Instruction Translation\nandi $t1, $t1, 1 #Pseudo-Instruction Translation\n#Pseudo-Instruction: subi $t1, $t2, 100\nori $at, $zero, 100 #Pseudo-Instruction Translation\nsub $t1, $t2, $at #Pseudo-Instruction Translation\n#Pseudo-Instruction: dsubi $t1, $t2, 100\nori $at, $zero, 100 #Pseudo-Instruction Translation\ndsub $t1, $t2, $at #Pseudo-Instruction Translation\n#Pseudo-Instruction: dsubiu $t1, $t2, 100\nori $at, $zero, 100 #Pseudo-Instruction Translation\ndsubu $t1, $t2, $at #Pseudo-Instruction Translation\n#Pseudo-Instruction: muli $t1, $t2, 100\nori $at, $zero, 100 #Pseudo-Instruction Translation\nmul $t1, $t2, $at #Pseudo-Instruction Translation\n#Pseudo-Instruction: dmuli $t1, $t2, 100\nori $at, $zero, 100 #Pseudo-Instruction Translation\ndmul $t1, $t2, $at #Pseudo-Instruction Translation\n#Pseudo-Instruction: dmuliu $t1, $t2, 100\nori $at, $zero, 100 #Pseudo-Instruction Translation\ndmulu $t1, $t2, $at #Pseudo-Instruction Translation\ndivi $t1, 100\nddivi $t1, 100\nddiviu $t1, 100\n#Pseudo-Instruction: lw $t1, memory\nlui $at, 0 #Pseudo-Instruction Translation\nlw $t1, 152($at) #Pseudo-Instruction Translation\nsyscall\n.data\nmemory: .word 200\n")
+    assert_eq!(result, ".text\n#Pseudo-Instruction: li $t1, 100\nori $t1, $zero, 100 #Pseudo-Instruction Translation\n#Pseudo-Instruction: seq $t1, $t2, $t3\nsub $t1, $t2, $t3 #Pseudo-Instruction Translation\nori $at, $zero, 1 #Pseudo-Instruction Translation\nsltu $t1, $t1, $at #Pseudo-Instruction Translation\n#Pseudo-Instruction: sne $t1, $t2, $t3\nsub $t1, $t2, $t3 #Pseudo-Instruction Translation\nsltu $t1, $zero, $t1 #Pseudo-Instruction Translation\n#Pseudo-Instruction: sle $t1, $t2, $t3\nslt $t1, $t3, $t2 #Pseudo-Instruction Translation\naddiu $t1, $t1, 1 #Pseudo-Instruction Translation\nandi $t1, $t1, 1 #Pseudo-Instruction Translation\n#Pseudo-Instruction: sleu $t1, $t2, $t3\nsltu $t1, $t3, $t2 #Pseudo-Instruction Translation\naddiu $t1, $t1, 1 #Pseudo-Instruction Translation\nandi $t1, $t1, 1 #Pseudo-Instruction Translation\n#Pseudo-Instruction: sgt $t1, $t2, $t3\nslt $t1, $t3, $t2 #Pseudo-Instruction Translation\n#Pseudo-Instruction: sgtu $t1, $t2, $t3\nsltu $t1, $t3, $t2 #Pseudo-Instruction Translation\n#Pseudo-Instruction: sge $t1, $t2, $t3\nslt $t1, $t2, $t3 #Pseudo-Instruction Translation\naddiu $t1, $t1, 1 #Pseudo-Instruction Translation\nandi $t1, $t1, 1 #Pseudo-Instruction Translation\n#Pseudo-Instruction: sgeu $t1, $t2, $t3\nsltu $t1, $t2, $t3 #Pseudo-Instruction Translation\naddiu $t1, $t1, 1 #Pseudo-Instruction Translation\nandi $t1, $t1, 1 #Pseudo-Instruction Translation\n#Pseudo-Instruction: subi $t1, $t2, 100\nori $at, $zero, 100 #Pseudo-Instruction Translation\nsub $t1, $t2, $at #Pseudo-Instruction Translation\n#Pseudo-Instruction: dsubi $t1, $t2, 100\nori $at, $zero, 100 #Pseudo-Instruction Translation\ndsub $t1, $t2, $at #Pseudo-Instruction Translation\n#Pseudo-Instruction: dsubiu $t1, $t2, 100\nori $at, $zero, 100 #Pseudo-Instruction Translation\ndsubu $t1, $t2, $at #Pseudo-Instruction Translation\n#Pseudo-Instruction: muli $t1, $t2, 100\nori $at, $zero, 100 #Pseudo-Instruction Translation\nmul $t1, $t2, $at #Pseudo-Instruction Translation\n#Pseudo-Instruction: dmuli $t1, $t2, 100\nori $at, $zero, 100 #Pseudo-Instruction Translation\ndmul $t1, $t2, $at #Pseudo-Instruction Translation\n#Pseudo-Instruction: dmuliu $t1, $t2, 100\nori $at, $zero, 100 #Pseudo-Instruction Translation\ndmulu $t1, $t2, $at #Pseudo-Instruction Translation\ndivi $t1, 100\nddivi $t1, 100\nddiviu $t1, 100\n#Pseudo-Instruction: lw $t1, memory\nlui $at, 0 #Pseudo-Instruction Translation\nlw $t1, 152($at) #Pseudo-Instruction Translation\nsyscall\n.data\nmemory: .word 200\n")
 }
 
 #[test]
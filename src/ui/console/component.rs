@@ -1,18 +1,28 @@
 //use crate::parser::parser_structs_and_enums::instruction_tokenization::ProgramInfo;
 //use monaco::api::TextModel;
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use wasm_bindgen::JsCast;
 use web_sys::HtmlElement;
 use yew::prelude::*;
 use yew_hooks::prelude::*;
 
-use crate::emulation_core::mips::datapath::MipsDatapath;
+use crate::emulation_core::mips::datapath::{MipsDatapath, Stage};
+use crate::emulation_core::mips::datapath_model::{MipsDatapathModel, SelectedDatapath};
+use crate::emulation_core::mips::mmio::{BitmapDisplay, KeyboardDisplay};
+use crate::emulation_core::mips::multi_cycle_datapath::MultiCycleState;
 use crate::ui::visual_datapath::{DatapathSize, VisualDatapath};
 
 #[derive(PartialEq, Properties)]
 pub struct Consoleprops {
-    pub datapath: MipsDatapath,
+    pub datapath: SelectedDatapath,
     pub parsermsg: String,
     pub memorymsg: String,
+    pub programmsg: String,
+    pub address_to_line_number: Vec<usize>,
+    pub keyboard_display: Rc<RefCell<KeyboardDisplay>>,
+    pub bitmap_display: Rc<RefCell<BitmapDisplay>>,
 }
 
 #[derive(Default, PartialEq)]
@@ -21,6 +31,10 @@ enum TabState {
     Console,
     Datapath,
     Memory,
+    Cache,
+    Branches,
+    Display,
+    Bitmap,
 }
 
 #[function_component(Console)]
@@ -40,6 +54,10 @@ pub fn console(props: &Consoleprops) -> Html {
                 "console" => TabState::Console,
                 "datapath" => TabState::Datapath,
                 "memory" => TabState::Memory,
+                "cache" => TabState::Cache,
+                "branches" => TabState::Branches,
+                "display" => TabState::Display,
+                "bitmap" => TabState::Bitmap,
                 _ => TabState::default(),
             };
 
@@ -78,21 +96,105 @@ pub fn console(props: &Consoleprops) -> Html {
         false => "Switch to Full Datapath",
     };
 
+    // Keys typed into the display tab are sent to the keyboard.
+    let on_key_typed = {
+        let keyboard_display = Rc::clone(&props.keyboard_display);
+
+        Callback::from(move |event: KeyboardEvent| {
+            let key = event.key();
+            let key = match key.as_str() {
+                "Enter" => Some(b'\n'),
+                _ if key.len() == 1 => key.bytes().next(),
+                _ => None,
+            };
+
+            if let Some(key) = key {
+                keyboard_display.borrow_mut().type_key(key);
+            }
+        })
+    };
+
+    let datapath = props.datapath.mips_datapath();
+
+    // The state of the multi-cycle control unit and the registers held
+    // between clock cycles are shown alongside the other output.
+    let registers_report = match &props.datapath {
+        SelectedDatapath::SingleCycle(_) => None,
+        SelectedDatapath::MultiCycle(multi_cycle) => Some(multi_cycle.generate_report()),
+        SelectedDatapath::Pipelined(pipelined) => Some(pipelined.generate_report()),
+    };
+
     html! {
     <>
             // Console buttons
             if *active_tab == TabState::Console {
                 <pre class="console">
                     { props.parsermsg.clone() }
+                    { props.programmsg.clone() }
+                    if let Some(message) = &datapath.system_coprocessor.exception_message {
+                        { "\n\nException:\n" }
+                        { message.clone() }
+                    }
+                    if let Some(message) = &datapath.error_message {
+                        { "\n\nError:\n" }
+                        { message.clone() }
+                    }
+                    if let Some(report) = &registers_report {
+                        { "\n\n" }
+                        { report.clone() }
+                    }
+                    if datapath.performance.total_instructions() > 0 {
+                        { "\n\nPerformance summary:\n" }
+                        { datapath.performance.generate_report(&datapath.cost_model, props.datapath.cycles()) }
+                    }
+                    if !datapath.unpredictable_operands.is_empty() {
+                        { "\n\nUnpredictable operands:\n" }
+                        { unpredictable_operands_report(datapath, &props.address_to_line_number) }
+                    }
                 </pre>
             } else if *active_tab == TabState::Datapath {
                 <div class="datapath-wrapper">
-                    <VisualDatapath datapath={props.datapath.clone()} svg_path={svg_path} size={datapath_size} />
+                    if let Some(report) = &registers_report {
+                        <pre class="datapath-registers">{ report.clone() }</pre>
+                    }
+                    <VisualDatapath datapath={datapath.clone()} stage={highlighted_stage(&props.datapath)} svg_path={svg_path} size={datapath_size} />
+                </div>
+            } else if *active_tab == TabState::Cache {
+                <div class="console">
+                    <pre class="memory-view">
+                        { match &datapath.cache {
+                            Some(cache) => cache.generate_report(),
+                            None => String::from("The cache simulator is off."),
+                        } }
+                    </pre>
+                </div>
+            } else if *active_tab == TabState::Branches {
+                <div class="console">
+                    <pre class="memory-view">
+                        { match &datapath.branch_predictor {
+                            Some(predictor) => predictor.generate_report(
+                                &props.address_to_line_number,
+                                datapath.memory.layout.text_address(),
+                            ),
+                            None => String::from("The branch predictor is off."),
+                        } }
+                    </pre>
+                </div>
+            } else if *active_tab == TabState::Display {
+                <div class="console">
+                    <pre class="memory-view">
+                        { props.keyboard_display.borrow().output.clone() }
+                    </pre>
+                    <input type="text" class="keyboard-input" placeholder="Type here to send keys to the program" onkeypress={on_key_typed} />
+                </div>
+            } else if *active_tab == TabState::Bitmap {
+                <div class="console">
+                    { render_bitmap(&props.bitmap_display.borrow()) }
                 </div>
             } else {
                 <div class="console">
                     <pre class = "memory-view">
-                        {props.datapath.memory.generate_formatted_hex() }
+                        {datapath.memory.generate_formatted_hex() }
                     </pre>
                 </div>
             }
@@ -110,6 +212,30 @@ pub fn console(props: &Consoleprops) -> Html {
                         <button class="tab" label="memory" onclick={change_tab.clone()}>{"Memory"}</button>
                     }
 
+                    if *active_tab == TabState::Cache {
+                        <button class={classes!("tab", "pressed")} label="cache" onclick={change_tab.clone()}>{"Cache"}</button>
+                    } else {
+                        <button class="tab" label="cache" onclick={change_tab.clone()}>{"Cache"}</button>
+                    }
+
+                    if *active_tab == TabState::Branches {
+                        <button class={classes!("tab", "pressed")} label="branches" onclick={change_tab.clone()}>{"Branches"}</button>
+                    } else {
+                        <button class="tab" label="branches" onclick={change_tab.clone()}>{"Branches"}</button>
+                    }
+
+                    if *active_tab == TabState::Display {
+                        <button class={classes!("tab", "pressed")} label="display" onclick={change_tab.clone()}>{"Display"}</button>
+                    } else {
+                        <button class="tab" label="display" onclick={change_tab.clone()}>{"Display"}</button>
+                    }
+
+                    if *active_tab == TabState::Bitmap {
+                        <button class={classes!("tab", "pressed")} label="bitmap" onclick={change_tab.clone()}>{"Bitmap"}</button>
+                    } else {
+                        <button class="tab" label="bitmap" onclick={change_tab.clone()}>{"Bitmap"}</button>
+                    }
+
                     if *active_tab == TabState::Datapath {
                         <button class={classes!("tab", "pressed")} label="datapath" onclick={change_tab.clone()}>{"Datapath"}</button>
                     } else {
@@ -127,3 +253,87 @@ pub fn console(props: &Consoleprops) -> Html {
         </>
     }
 }
+
+/// Draw the bitmap display as an SVG image, with one square per pixel.
+fn render_bitmap(bitmap_display: &BitmapDisplay) -> Html {
+    let width = bitmap_display.width();
+    let height = bitmap_display.height();
+
+    // The background is black, so only the other pixels need to be drawn.
+    let pixels = bitmap_display
+        .pixels()
+        .iter()
+        .enumerate()
+        .filter(|(_, &color)| color & 0xFF_FFFF != 0)
+        .map(|(index, color)| {
+            html! {
+                <rect x={(index % width).to_string()} y={(index / width).to_string()}
+                    width="1" height="1" fill={format!("#{:06x}", color & 0xFF_FFFF)} />
+            }
+        });
+
+    html! {
+        <svg class="bitmap-display" viewBox={format!("0 0 {width} {height}")} shape-rendering="crispEdges">
+            <rect width={width.to_string()} height={height.to_string()} fill="black" />
+            { for pixels }
+        </svg>
+    }
+}
+
+/// The stage whose lines are highlighted in the visual datapath, which is
+/// the stage most recently run.
+///
+/// Each state of the multi-cycle control unit does the work of one stage.
+/// Every stage runs on each clock cycle of the pipelined datapath, with the
+/// instruction fetch stage last, so that is the stage shown for it. The
+/// other stages are shown by the pipeline registers.
+fn highlighted_stage(datapath: &SelectedDatapath) -> Stage {
+    match datapath {
+        SelectedDatapath::SingleCycle(datapath) => match datapath.current_stage {
+            Stage::InstructionFetch => Stage::WriteBack,
+            Stage::InstructionDecode => Stage::InstructionFetch,
+            Stage::Execute => Stage::InstructionDecode,
+            Stage::Memory => Stage::Execute,
+            Stage::WriteBack => Stage::Memory,
+        },
+        SelectedDatapath::MultiCycle(datapath) => match datapath.previous_state {
+            Some(MultiCycleState::InstructionFetch) => Stage::InstructionFetch,
+            Some(MultiCycleState::InstructionDecode) => Stage::InstructionDecode,
+            Some(
+                MultiCycleState::MemoryAddressComputation
+                | MultiCycleState::Execution
+                | MultiCycleState::BranchCompletion
+                | MultiCycleState::JumpCompletion,
+            ) => Stage::Execute,
+            Some(MultiCycleState::MemoryRead | MultiCycleState::MemoryWrite) => Stage::Memory,
+            Some(MultiCycleState::MemoryReadCompletion | MultiCycleState::AluCompletion) | None => {
+                Stage::WriteBack
+            }
+        },
+        SelectedDatapath::Pipelined(_) => Stage::InstructionFetch,
+    }
+}
+
+/// List the operands recorded in strict mode as unpredictable, by the source
+/// line of the instruction that read them.
+fn unpredictable_operands_report(
+    datapath: &MipsDatapath,
+    address_to_line_number: &[usize],
+) -> String {
+    let text_address = datapath.memory.layout.text_address();
+
+    datapath
+        .unpredictable_operands
+        .iter()
+        .map(|operand| {
+            let line = operand
+                .pc
+                .checked_sub(text_address)
+                .and_then(|offset| address_to_line_number.get((offset / 4) as usize));
+            match line {
+                Some(line) => format!("Line {}: {operand}.\n", line + 1),
+                None => format!("Address {:#x}: {operand}.\n", operand.pc),
+            }
+        })
+        .collect()
+}
@@ -1 +1,29 @@
+use gloo::dialogs::prompt;
 
+use crate::emulation_core::mips::syscall::SyscallHost;
+
+/// The [`SyscallHost`] used in the browser.
+///
+/// Program output is collected to be shown in the console, and input is
+/// requested from the user through a prompt dialog.
+#[derive(Default)]
+pub struct BrowserSyscallHost {
+    pub output: String,
+}
+
+impl SyscallHost for BrowserSyscallHost {
+    fn print(&mut self, text: &str) {
+        self.output.push_str(text);
+    }
+
+    fn read_int(&mut self) -> Option<i64> {
+        prompt("The program is requesting an integer:", None)?
+            .trim()
+            .parse()
+            .ok()
+    }
+
+    fn read_string(&mut self) -> Option<String> {
+        prompt("The program is requesting a string:", None)
+    }
+}
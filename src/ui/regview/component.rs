@@ -1,3 +1,8 @@
+use crate::emulation_core::mips::coprocessor::{
+    FCSR_CAUSE_SHIFT, FCSR_ENABLES_SHIFT, FCSR_EXCEPTIONS_MASK, FCSR_FCC0, FCSR_FCC_SHIFT,
+    FCSR_FLAGS_SHIFT, FCSR_RM_MASK,
+};
+use crate::emulation_core::mips::floating_point::{RoundingMode, FLAG_NAMES};
 use crate::emulation_core::mips::registers::{GpRegisterType, GpRegisters};
 //use gloo::console::log;
 use wasm_bindgen::JsCast;
@@ -9,6 +14,7 @@
 pub struct Regviewprops {
     pub gp: GpRegisters,
     pub fp: [u64; 32],
+    pub fcsr: u32,
 }
 
 #[derive(PartialEq, Properties)]
@@ -131,6 +137,66 @@ pub fn generate_gpr_rows_bin(gp: GpRegisters) -> Html {
         .collect::<Html>()
 }
 
+/// Returns the rows for the FCSR, shown after the floating-point registers.
+/// The whole register is shown in the current view, followed by each of
+/// its fields.
+fn generate_fcsr_rows(fcsr: u32, view: &UnitState) -> Html {
+    let value = match view {
+        UnitState::Dec => fcsr.to_string(),
+        UnitState::Bin => format!("{fcsr:#b}"),
+        UnitState::Hex | UnitState::Float | UnitState::Double => format!("{fcsr:#010x}"),
+    };
+    let fields = [
+        ("FCSR", value),
+        (
+            "FCSR.RM",
+            RoundingMode::from_bits(fcsr & FCSR_RM_MASK).to_string(),
+        ),
+        ("FCSR.Flags", describe_exceptions(fcsr >> FCSR_FLAGS_SHIFT)),
+        (
+            "FCSR.Enables",
+            describe_exceptions(fcsr >> FCSR_ENABLES_SHIFT),
+        ),
+        ("FCSR.Cause", describe_exceptions(fcsr >> FCSR_CAUSE_SHIFT)),
+        // The condition codes, from FCC7 down to FCC0.
+        (
+            "FCSR.FCC",
+            format!(
+                "{:08b}",
+                ((fcsr >> FCSR_FCC_SHIFT) << 1) | (fcsr & FCSR_FCC0 != 0) as u32
+            ),
+        ),
+    ];
+
+    fields
+        .into_iter()
+        .map(|(name, data)| {
+            html! {
+                <tr>
+                    <td>{name}</td>
+                    <td>{data}</td>
+                </tr>
+            }
+        })
+        .collect::<Html>()
+}
+
+/// Returns the letters of the IEEE 754 exceptions set in the lowest five
+/// bits of `bits`, such as "V Z", or "-" if there are none.
+fn describe_exceptions(bits: u32) -> String {
+    let names: Vec<String> = FLAG_NAMES
+        .iter()
+        .filter(|(flag, _)| bits & FCSR_EXCEPTIONS_MASK & flag != 0)
+        .map(|(_, name)| name.to_string())
+        .collect();
+
+    if names.is_empty() {
+        "-".to_string()
+    } else {
+        names.join(" ")
+    }
+}
+
 /// Returns the text to be shown for a general-purpose register.
 pub fn get_gpr_name(register: GpRegisterType) -> String {
     if register == GpRegisterType::Pc {
@@ -262,6 +328,7 @@ pub fn regview(props: &Regviewprops) -> Html {
                             } else if *active_view == UnitState::Dec {
                                 {generate_fpr_rows(props.fp)}
                             }
+                            {generate_fcsr_rows(props.fcsr, &active_view)}
                         }
                     </tbody>
                 </table>
@@ -40,6 +40,9 @@
 pub struct VisualDatapathProps {
     pub datapath: MipsDatapath,
 
+    /// The stage most recently run, whose lines are highlighted.
+    pub stage: Stage,
+
     /// A path to the location of the datapath SVG file. This path should be
     /// relative to the project root.
     ///
@@ -101,15 +104,13 @@ fn view(&self, ctx: &Context<Self>) -> Html {
 
     fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
         // The diagram views the lines *after* the stage has executed. This is so
-        // there is actual data to view. A better way to see this is "when stage X is
-        // set on the datapath, highlight the lines for stage Y." The datapath stage
-        // tells where it will start the next time "execute" is pressed.
-        let current_stage = String::from(match ctx.props().datapath.current_stage {
-            Stage::InstructionFetch => "writeback",
-            Stage::InstructionDecode => "instruction_fetch",
-            Stage::Execute => "instruction_decode",
-            Stage::Memory => "execute",
-            Stage::WriteBack => "memory",
+        // there is actual data to view.
+        let current_stage = String::from(match ctx.props().stage {
+            Stage::InstructionFetch => "instruction_fetch",
+            Stage::InstructionDecode => "instruction_decode",
+            Stage::Execute => "execute",
+            Stage::Memory => "memory",
+            Stage::WriteBack => "writeback",
         });
 
         if first_render || self.should_reinitialize {